@@ -4,7 +4,7 @@ mod network_test;
 
 use super::*;
 use crate::utils::{compare_floats, parallel_into_collect, Noise, Random};
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use rand::prelude::SliceRandom;
 use std::cmp::Ordering;
 use std::ops::Deref;
@@ -26,9 +26,11 @@ where
     learning_rate: f64,
     time: usize,
     rebalance_memory: usize,
+    merging: Option<NodeMerging>,
     min_max_weights: MinMaxWeights,
     nodes: HashMap<Coordinate, NodeLink<I, S>>,
     storage_factory: F,
+    random: Arc<dyn Random + Send + Sync>,
 }
 
 /// GSOM network configuration.
@@ -43,6 +45,17 @@ pub struct NetworkConfig {
     pub rebalance_memory: usize,
     /// If set to true, initial nodes have error set to the value equal to growing threshold.
     pub has_initial_error: bool,
+    /// An optional node merging which is applied on smoothing phase to bound network size.
+    pub merging: Option<NodeMerging>,
+}
+
+/// Specifies settings of merging adjacent nodes.
+#[derive(Clone, Debug)]
+pub struct NodeMerging {
+    /// A max distance between weights of adjacent nodes to consider them as near identical.
+    pub max_distance: f64,
+    /// A max amount of last hits of the node to be merged into its neighbour.
+    pub max_hits: usize,
 }
 
 /// Specifies min max weights type.
@@ -69,7 +82,7 @@ where
 
         let growing_threshold = -1. * dimension as f64 * config.spread_factor.log2();
         let initial_error = if config.has_initial_error { growing_threshold } else { 0. };
-        let noise = Noise::new(1., (0.75, 1.25), random);
+        let noise = Noise::new(1., (0.75, 1.25), random.clone());
 
         let (nodes, min_max_weights) =
             Self::create_initial_nodes(roots, initial_error, config.rebalance_memory, &noise, &storage_factory);
@@ -81,9 +94,11 @@ where
             learning_rate: config.learning_rate,
             time: 0,
             rebalance_memory: config.rebalance_memory,
+            merging: config.merging,
             min_max_weights,
             nodes,
             storage_factory,
+            random,
         }
     }
 
@@ -111,15 +126,17 @@ where
         self.train_batch(nodes_data, true);
     }
 
-    /// Performs smoothing phase.
+    /// Performs smoothing phase. Merges similar nodes first, if node merging is configured.
     pub fn smooth(&mut self, rebalance_count: usize) {
+        self.merge();
+
         (0..rebalance_count).for_each(|_| {
             let mut data = self
                 .nodes
                 .iter_mut()
                 .flat_map(|(_, node)| node.write().unwrap().storage.drain(0..))
                 .collect::<Vec<_>>();
-            data.shuffle(&mut rand::thread_rng());
+            data.shuffle(&mut self.random.get_rng());
 
             let nodes_data = parallel_into_collect(data, |input| {
                 let bmu = self.find_bmu(&input);
@@ -156,6 +173,66 @@ where
         });
     }
 
+    /// Merges adjacent nodes with near identical weights and low amount of last hits.
+    fn merge(&mut self) {
+        let (max_distance, max_hits) = match self.merging.as_ref() {
+            Some(merging) => (merging.max_distance, merging.max_hits),
+            None => return,
+        };
+
+        // NOTE sort coordinates to have deterministic merge order
+        let mut coordinates = self.nodes.keys().cloned().collect::<Vec<_>>();
+        coordinates.sort_by_key(|coordinate| (coordinate.0, coordinate.1));
+
+        let mut affected = HashSet::new();
+        let mut removed = vec![];
+
+        for coordinate in coordinates {
+            // NOTE: prevent network to be less than 4 nodes
+            if self.nodes.len() - removed.len() <= 4 {
+                break;
+            }
+
+            if affected.contains(&coordinate) {
+                continue;
+            }
+
+            let mut source = self.nodes.get(&coordinate).unwrap().write().unwrap();
+            if source.get_last_hits(self.time) > max_hits {
+                continue;
+            }
+
+            let target = source
+                .neighbours(self, 1)
+                .filter(|(_, (x, y))| x.abs() + y.abs() < 2)
+                .filter_map(|(node, _)| node)
+                .filter(|node| !affected.contains(&node.read().unwrap().coordinate))
+                .map(|node| (node, source.distance(node.read().unwrap().weights.as_slice())))
+                .filter(|(_, distance)| compare_floats(*distance, max_distance) != Ordering::Greater)
+                .min_by(|(_, a), (_, b)| compare_floats(*a, *b))
+                .map(|(node, _)| node.clone());
+
+            if let Some(target) = target {
+                let mut target = target.write().unwrap();
+
+                let source_ratio = (source.total_hits + 1) as f64 / (source.total_hits + target.total_hits + 2) as f64;
+                target.weights.iter_mut().zip(source.weights.iter()).for_each(|(target, source)| {
+                    *target += source_ratio * (*source - *target);
+                });
+                target.total_hits += source.total_hits;
+                source.storage.drain(0..).into_iter().for_each(|input| target.storage.add(input));
+
+                affected.insert(source.coordinate);
+                affected.insert(target.coordinate);
+                removed.push(source.coordinate);
+            }
+        }
+
+        removed.iter().for_each(|coordinate| {
+            self.nodes.remove(coordinate);
+        });
+    }
+
     /// Finds node by its coordinate.
     pub fn find(&self, coordinate: &Coordinate) -> Option<&NodeLink<I, S>> {
         self.nodes.get(coordinate)
@@ -49,7 +49,8 @@ pub fn assign_crowding_distance<'a, S>(
             }
 
             // the distance between the "best" and "worst" solution according to "objective"
-            let spread = objective.distance(a.first().unwrap().solution, a.last().unwrap().solution).abs();
+            let solutions = a.iter().map(|a| a.solution).collect::<Vec<_>>();
+            let spread = objective.spread(solutions.as_slice());
             debug_assert!(spread >= 0.0);
 
             if spread > 0.0 {
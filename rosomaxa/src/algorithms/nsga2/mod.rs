@@ -26,6 +26,9 @@ use self::crowding_distance::*;
 mod non_dominated_sort;
 use self::non_dominated_sort::*;
 
+mod normalization;
+pub use self::normalization::*;
+
 mod nsga2_sort;
 pub use self::nsga2_sort::select_and_rank;
 
@@ -0,0 +1,110 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/nsga2/normalization_test.rs"]
+mod normalization_test;
+
+use super::*;
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+use std::sync::{Arc, RwLock};
+
+/// Specifies how objective distances are normalized when crowding distance is estimated.
+#[derive(Clone, Debug)]
+pub enum ObjectiveNormalization {
+    /// Distances are scaled by spread between the best and the worst solution in the same front.
+    /// This is a default NSGA-II behavior.
+    Spread,
+    /// Distances are scaled by the range between minimum and maximum fitness values observed so far.
+    RunningMinMax,
+    /// Distances are scaled by the range between lower and upper quantiles of fitness values in the
+    /// same front, which makes it robust against outliers. Quantiles are expected in `[0, 1]` range.
+    Quantile {
+        /// A lower quantile, e.g. 0.05.
+        lower: f64,
+        /// An upper quantile, e.g. 0.95.
+        upper: f64,
+    },
+}
+
+/// An objective which wraps another one and normalizes its distances when crowding distance is
+/// estimated. It keeps total order of the original objective, so dominance relation between
+/// solutions is preserved. Normalization assumes that distance is a difference between fitness values.
+pub struct NormalizedObjective<S> {
+    inner: Arc<dyn Objective<Solution = S> + Send + Sync>,
+    normalization: ObjectiveNormalization,
+    bounds: RwLock<Option<(f64, f64)>>,
+}
+
+impl<S> NormalizedObjective<S> {
+    /// Creates a new instance of `NormalizedObjective`.
+    pub fn new(inner: Arc<dyn Objective<Solution = S> + Send + Sync>, normalization: ObjectiveNormalization) -> Self {
+        if let ObjectiveNormalization::Quantile { lower, upper } = &normalization {
+            assert!(*lower >= 0. && *lower < *upper && *upper <= 1.);
+        }
+
+        Self { inner, normalization, bounds: RwLock::new(None) }
+    }
+
+    fn get_running_spread(&self, fitness: &[f64]) -> f64 {
+        let mut bounds = self.bounds.write().unwrap();
+
+        let (min, max) = fitness
+            .iter()
+            .fold(bounds.unwrap_or((f64::MAX, f64::MIN)), |(min, max), &value| (min.min(value), max.max(value)));
+
+        if min <= max {
+            *bounds = Some((min, max));
+            max - min
+        } else {
+            0.
+        }
+    }
+}
+
+impl<S> Objective for NormalizedObjective<S> {
+    type Solution = S;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        self.inner.total_order(a, b)
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.inner.distance(a, b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        self.inner.fitness(solution)
+    }
+
+    fn spread(&self, solutions: &[&Self::Solution]) -> f64 {
+        let get_fitness = || solutions.iter().map(|solution| self.inner.fitness(solution)).collect::<Vec<_>>();
+
+        match &self.normalization {
+            ObjectiveNormalization::Spread => self.inner.spread(solutions),
+            ObjectiveNormalization::RunningMinMax => self.get_running_spread(get_fitness().as_slice()),
+            ObjectiveNormalization::Quantile { lower, upper } => {
+                let mut fitness = get_fitness();
+                fitness.sort_by(|a, b| compare_floats(*a, *b));
+
+                let spread = get_quantile(fitness.as_slice(), *upper) - get_quantile(fitness.as_slice(), *lower);
+
+                // NOTE fallback to default spread when most of the values are the same
+                if spread > 0. {
+                    spread
+                } else {
+                    self.inner.spread(solutions)
+                }
+            }
+        }
+    }
+}
+
+/// Returns a quantile of sorted values using nearest rank method.
+fn get_quantile(values: &[f64], quantile: f64) -> f64 {
+    if values.is_empty() {
+        return 0.;
+    }
+
+    let rank = (quantile * values.len() as f64).ceil() as usize;
+
+    values[rank.clamp(1, values.len()) - 1]
+}
@@ -3,6 +3,7 @@
 mod nsga2_sort_test;
 
 use super::*;
+use std::cmp::Ordering;
 
 /// Select `n` solutions using the approach taken by NSGA2.
 ///
@@ -12,6 +13,9 @@ use super::*;
 /// that does not completely fit into the result set, we sort it's solutions according to their
 /// crowding distance (higher crowding distance is "better"), and prefer those solutions with the
 /// higher crowding distance until we have exactly `n` solutions in the result set.
+///
+/// When multi objective reports that solutions are totally ordered, pareto fronts and crowding
+/// distance are not needed: solutions are just sorted instead.
 pub fn select_and_rank<'a, S: 'a>(
     solutions: &'a [S],
     n: usize,
@@ -21,6 +25,10 @@ pub fn select_and_rank<'a, S: 'a>(
     let n = solutions.len().min(n);
     debug_assert!(n <= solutions.len());
 
+    if multi_objective.is_totally_ordered() {
+        return sort_and_rank(solutions, n, multi_objective);
+    }
+
     let mut result = Vec::with_capacity(n);
     let mut missing_solutions = n;
 
@@ -55,3 +63,30 @@ pub fn select_and_rank<'a, S: 'a>(
 
     result
 }
+
+/// Selects `n` best solutions using total order of the single objective. Solutions with equal
+/// objective value share the same rank.
+fn sort_and_rank<'a, S: 'a>(
+    solutions: &'a [S],
+    n: usize,
+    objective: &impl MultiObjective<Solution = S>,
+) -> Vec<AssignedCrowdingDistance<'a, S>> {
+    let mut indices = (0..solutions.len()).collect::<Vec<_>>();
+    indices.sort_by(|&a, &b| objective.total_order(&solutions[a], &solutions[b]));
+
+    let mut rank = 0;
+    indices
+        .iter()
+        .enumerate()
+        .take(n)
+        .map(|(position, &index)| {
+            if position > 0
+                && objective.total_order(&solutions[indices[position - 1]], &solutions[index]) != Ordering::Equal
+            {
+                rank += 1;
+            }
+
+            AssignedCrowdingDistance { index, solution: &solutions[index], rank, crowding_distance: 0. }
+        })
+        .collect()
+}
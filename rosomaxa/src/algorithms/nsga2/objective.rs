@@ -36,6 +36,17 @@ pub trait Objective {
 
     /// An objective fitness value for given `solution`.
     fn fitness(&self, solution: &Self::Solution) -> f64;
+
+    /// Returns a spread of given solutions which are sorted according to the objective. It is used
+    /// to normalize distances between solutions when their crowding distance is estimated.
+    ///
+    /// By default, this is a distance between the best and the worst solution.
+    fn spread(&self, solutions: &[&Self::Solution]) -> f64 {
+        match (solutions.first(), solutions.last()) {
+            (Some(first), Some(last)) => self.distance(first, last).abs(),
+            _ => 0.,
+        }
+    }
 }
 
 /// A multi objective.
@@ -44,6 +55,12 @@ pub trait MultiObjective: Objective {
     fn objectives<'a>(
         &'a self,
     ) -> Box<dyn Iterator<Item = &'a (dyn Objective<Solution = Self::Solution> + Send + Sync)> + 'a>;
+
+    /// Returns true if solutions are totally ordered by a single scalar value, so they can be ranked
+    /// without non-dominated sorting. Default is false.
+    fn is_totally_ordered(&self) -> bool {
+        false
+    }
 }
 
 /// Calculates dominance order of two solutions using multiple objectives.
@@ -11,8 +11,10 @@ use std::cmp::Ordering;
 use std::fmt::Write;
 use std::marker::PhantomData;
 use std::ops::Deref;
+use std::sync::{Arc, RwLock};
 
 /// Encapsulates different measurements regarding algorithm evaluation.
+#[derive(Default)]
 pub struct TelemetryMetrics {
     /// Algorithm duration.
     pub duration: usize,
@@ -22,9 +24,12 @@ pub struct TelemetryMetrics {
     pub speed: f64,
     /// Evolution progress.
     pub evolution: Vec<TelemetryGeneration>,
+    /// New best known solutions found during evolution.
+    pub best: Vec<TelemetryBest>,
 }
 
 /// Represents information about generation.
+#[derive(Clone)]
 pub struct TelemetryGeneration {
     /// Generation sequence number.
     pub number: usize,
@@ -40,7 +45,19 @@ pub struct TelemetryGeneration {
     pub population: TelemetryPopulation,
 }
 
+/// Represents information about new best known solution.
+#[derive(Clone)]
+pub struct TelemetryBest {
+    /// Generation when solution was found.
+    pub generation: usize,
+    /// Time since evolution started.
+    pub timestamp: f64,
+    /// Objectives fitness values.
+    pub fitness: Vec<f64>,
+}
+
 /// Keeps essential information about particular individual in population.
+#[derive(Clone)]
 pub struct TelemetryIndividual {
     /// Rank in population.
     pub rank: usize,
@@ -51,6 +68,7 @@ pub struct TelemetryIndividual {
 }
 
 /// Holds population state.
+#[derive(Clone)]
 pub struct TelemetryPopulation {
     /// Population individuals.
     pub individuals: Vec<TelemetryIndividual>,
@@ -76,6 +94,8 @@ pub enum TelemetryMode {
     OnlyMetrics {
         /// Specifies how often population is tracked.
         track_population: usize,
+        /// Specifies a feed which can be used to poll metrics while algorithm is running.
+        feed: Option<TelemetryFeed>,
     },
     /// Both logging and metrics collection.
     All {
@@ -89,9 +109,80 @@ pub enum TelemetryMode {
         track_population: usize,
         /// Specifies whether population should be dumped.
         dump_population: bool,
+        /// Specifies a feed which can be used to poll metrics while algorithm is running.
+        feed: Option<TelemetryFeed>,
     },
 }
 
+/// Specifies a position in telemetry metrics since which changes are requested.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TelemetryCursor {
+    /// Changes starting from given generation (inclusive).
+    Generation(usize),
+    /// Changes tracked after given time since evolution started.
+    Time(f64),
+}
+
+/// Keeps telemetry changes since some cursor.
+pub struct TelemetryDelta {
+    /// A cursor to be used to request next changes.
+    pub cursor: TelemetryCursor,
+    /// Total amount of generations at the moment.
+    pub generations: usize,
+    /// Evolution progress since cursor.
+    pub evolution: Vec<TelemetryGeneration>,
+    /// New best known solutions since cursor.
+    pub best: Vec<TelemetryBest>,
+}
+
+impl TelemetryMetrics {
+    /// Returns changes since given cursor: only new generations and best known solutions are
+    /// returned, so the whole metrics history is not needed to be sent on each poll.
+    pub fn get_delta(&self, cursor: TelemetryCursor) -> TelemetryDelta {
+        let is_new = |generation: usize, timestamp: f64| match cursor {
+            TelemetryCursor::Generation(cursor) => generation >= cursor,
+            TelemetryCursor::Time(cursor) => timestamp > cursor,
+        };
+
+        let evolution = self
+            .evolution
+            .iter()
+            .filter(|generation| is_new(generation.number, generation.timestamp))
+            .cloned()
+            .collect::<Vec<_>>();
+        let best = self.best.iter().filter(|best| is_new(best.generation, best.timestamp)).cloned().collect::<Vec<_>>();
+
+        // NOTE next cursor is based on tracked items: population at the last generation can be
+        // tracked later on result
+        let cursor = evolution
+            .iter()
+            .map(|generation| generation.number)
+            .chain(best.iter().map(|best| best.generation))
+            .max()
+            .map_or(cursor, |generation| TelemetryCursor::Generation(generation + 1));
+
+        TelemetryDelta { cursor, generations: self.generations, evolution, best }
+    }
+}
+
+/// A thread safe handle to telemetry metrics which can be polled while algorithm is running,
+/// e.g. to update a dashboard.
+#[derive(Clone, Default)]
+pub struct TelemetryFeed {
+    metrics: Arc<RwLock<TelemetryMetrics>>,
+}
+
+impl TelemetryFeed {
+    /// Returns metrics changes since given cursor.
+    pub fn get_delta(&self, cursor: TelemetryCursor) -> TelemetryDelta {
+        self.metrics.read().unwrap().get_delta(cursor)
+    }
+
+    fn update<F: FnOnce(&mut TelemetryMetrics)>(&self, update_fn: F) {
+        update_fn(&mut self.metrics.write().unwrap())
+    }
+}
+
 /// Provides way to collect metrics and write information into log.
 pub struct Telemetry<O, S>
 where
@@ -117,7 +208,7 @@ where
     pub fn new(mode: TelemetryMode) -> Self {
         Self {
             time: Timer::start(),
-            metrics: TelemetryMetrics { duration: 0, generations: 0, speed: 0.0, evolution: vec![] },
+            metrics: TelemetryMetrics::default(),
             mode,
             statistics: Default::default(),
             improvement_tracker: ImprovementTracker::new(1000),
@@ -157,6 +248,7 @@ where
         let generation = self.next_generation.unwrap_or(0);
 
         self.metrics.generations = generation;
+        self.update_feed(|metrics| metrics.generations = generation);
         self.improvement_tracker.track(generation, is_improved);
         self.speed_tracker.track(generation, &self.time, termination_estimate);
         self.next_generation = Some(generation + 1);
@@ -186,6 +278,10 @@ where
             let should_log_population = generation % *log_population.unwrap_or(&usize::MAX) == 0;
             let should_track_population = generation % *track_population.unwrap_or(&usize::MAX) == 0;
 
+            if track_population.is_some() {
+                self.on_best(best_individual, generation);
+            }
+
             if should_log_best {
                 self.log_individual(
                     &self.get_individual_metrics(objective, population, best_individual, rank),
@@ -255,17 +351,33 @@ where
         }
 
         if should_track_population {
-            self.metrics.evolution.push(TelemetryGeneration {
+            let telemetry_generation = TelemetryGeneration {
                 number: generation,
                 timestamp: self.time.elapsed_secs_as_f64(),
                 i_all_ratio: self.improvement_tracker.i_all_ratio,
                 i_1000_ratio: self.improvement_tracker.i_1000_ratio,
                 is_improvement: self.improvement_tracker.is_last_improved,
                 population: TelemetryPopulation { individuals },
-            });
+            };
+
+            self.update_feed(|metrics| metrics.evolution.push(telemetry_generation.clone()));
+            self.metrics.evolution.push(telemetry_generation);
         }
     }
 
+    /// Tracks best known solution if its fitness is changed.
+    fn on_best(&mut self, solution: &S, generation: usize) {
+        let fitness = solution.get_fitness().collect::<Vec<_>>();
+        if self.metrics.best.last().is_some_and(|best| best.fitness == fitness) {
+            return;
+        }
+
+        let best = TelemetryBest { generation, timestamp: self.time.elapsed_secs_as_f64(), fitness };
+
+        self.update_feed(|metrics| metrics.best.push(best.clone()));
+        self.metrics.best.push(best);
+    }
+
     /// Reports final statistic.
     pub fn on_result(&mut self, objective: &O, population: &DynHeuristicPopulation<O, S>) {
         let generations = self.statistics.generation;
@@ -286,6 +398,10 @@ where
 
         self.metrics.duration = elapsed;
         self.metrics.speed = speed;
+        self.update_feed(|metrics| {
+            metrics.duration = elapsed;
+            metrics.speed = speed;
+        });
     }
 
     /// Gets metrics.
@@ -305,6 +421,16 @@ where
         }
     }
 
+    /// Applies metrics update to the feed, if it is specified.
+    fn update_feed<F: FnOnce(&mut TelemetryMetrics)>(&self, update_fn: F) {
+        match &self.mode {
+            TelemetryMode::OnlyMetrics { feed: Some(feed), .. } | TelemetryMode::All { feed: Some(feed), .. } => {
+                feed.update(update_fn)
+            }
+            _ => {}
+        }
+    }
+
     /// Returns current statistics.
     pub fn get_statistics(&self) -> &HeuristicStatistics {
         &self.statistics
@@ -454,4 +580,4 @@ where
 
 fn format_fitness(fitness: impl Iterator<Item = f64>) -> String {
     fitness.map(|v| format!("{:.3}", v)).collect::<Vec<_>>().join(", ")
-}
\ No newline at end of file
+}
@@ -26,6 +26,28 @@ pub type HeuristicDiversifyOperators<C, O, S> =
 /// Its values can be used to penalize instant heuristic reward.
 type RemedianUsize = Remedian<usize, fn(&usize, &usize) -> Ordering>;
 
+/// Specifies soft time budgets of heuristic search operators. When median running time of the
+/// operator exceeds its budget, the operator is skipped and its skip is penalized as stagnation,
+/// so other operators are preferred. A skipped operator is still run after some amount of
+/// consecutive skips to refresh its running time estimation.
+#[derive(Clone, Default)]
+pub struct OperatorTimeBudget {
+    /// A budget applied to operators which have no specific one.
+    pub default: Option<Duration>,
+    /// Budgets of specific operators by their names.
+    pub operators: HashMap<String, Duration>,
+}
+
+impl OperatorTimeBudget {
+    /// Returns a time budget of the operator with given name.
+    pub fn get(&self, name: &str) -> Option<Duration> {
+        self.operators.get(name).cloned().or(self.default)
+    }
+}
+
+/// Specifies max amount of consecutive skips of operator which exceeds its time budget.
+const MAX_BUDGET_SKIPS: usize = 10;
+
 /// An experimental dynamic selective hyper heuristic which selects inner heuristics
 /// based on how they work during the search. The selection process is modeled by
 /// Markov Decision Process.
@@ -149,11 +171,19 @@ where
             diversify_operators,
             tracker: HeuristicTracker {
                 total_median: RemedianUsize::new(11, |a, b| a.cmp(b)),
+                operators: Default::default(),
+                time_budget: Default::default(),
                 telemetry: Default::default(),
                 is_experimental: environment.is_experimental,
             },
         }
     }
+
+    /// Sets soft time budgets of search operators.
+    pub fn with_time_budget(mut self, time_budget: OperatorTimeBudget) -> Self {
+        self.tracker.time_budget = time_budget;
+        self
+    }
 }
 
 #[derive(Default, Clone)]
@@ -247,7 +277,7 @@ where
     state: SearchState,
     original: &'a S,
     solution: Option<S>,
-    runtime: Vec<(String, Duration, SearchState)>,
+    runtime: Vec<(String, Option<Duration>, SearchState)>,
 }
 
 impl<'a, C, O, S> Agent<SearchState> for SearchAgent<'a, C, O, S>
@@ -270,6 +300,13 @@ where
                 let solution = self.solution.as_ref().unwrap();
                 let (heuristic, name) = &self.registry.heuristics[*heuristic_idx];
 
+                if self.tracker.is_over_budget(name) {
+                    // NOTE keep solution as is and penalize the operator to make its selection less likely
+                    self.state = SearchState::Stagnated(MedianRatio { ratio: 2. });
+                    self.runtime.push((name.to_string(), None, self.state.clone()));
+                    return;
+                }
+
                 let (new_solution, duration) =
                     Timer::measure_duration(|| heuristic.search(self.heuristic_ctx, solution));
 
@@ -316,7 +353,7 @@ where
         };
 
         self.solution = Some(new_solution);
-        self.runtime.push((name.to_string(), duration, self.state.clone()))
+        self.runtime.push((name.to_string(), Some(duration), self.state.clone()))
     }
 }
 
@@ -385,19 +422,44 @@ where
 
 struct HeuristicTracker {
     total_median: RemedianUsize,
+    operators: HashMap<String, (RemedianUsize, usize)>,
+    time_budget: OperatorTimeBudget,
     telemetry: HashMap<String, Vec<(usize, Duration, SearchState)>>,
     is_experimental: bool,
 }
 
 impl HeuristicTracker {
-    pub fn observation(&mut self, generation: usize, name: String, duration: Duration, state: SearchState) {
+    pub fn observation(&mut self, generation: usize, name: String, duration: Option<Duration>, state: SearchState) {
+        let (operator_median, skips) =
+            self.operators.entry(name.clone()).or_insert_with(|| (RemedianUsize::new(11, |a, b| a.cmp(b)), 0));
+
+        let duration = if let Some(duration) = duration {
+            duration
+        } else {
+            *skips += 1;
+            return;
+        };
+
+        *skips = 0;
+        operator_median.add_observation(duration.as_millis() as usize);
         self.total_median.add_observation(duration.as_millis() as usize);
+
         // NOTE track heuristic telemetry only for experimental mode (performance)
         if self.is_experimental {
             self.telemetry.entry(name).or_default().push((generation, duration, state));
         }
     }
 
+    /// Checks whether operator with given name should be skipped as it exceeds its time budget.
+    pub fn is_over_budget(&self, name: &str) -> bool {
+        match (self.time_budget.get(name), self.operators.get(name)) {
+            (Some(budget), Some((operator_median, skips))) if *skips < MAX_BUDGET_SKIPS => {
+                matches!(operator_median.approx_median(), Some(median) if median as u128 > budget.as_millis())
+            }
+            _ => false,
+        }
+    }
+
     pub fn approx_median(&self) -> Option<usize> {
         self.total_median.approx_median()
     }
@@ -288,3 +288,27 @@ where
         Box::new(population)
     }
 }
+
+/// Gets default population algorithm with population sizes adapted to the problem size
+/// and to the search progress using given sizing policy.
+pub fn get_adaptive_population<O, S>(
+    objective: Arc<O>,
+    environment: Arc<Environment>,
+    selection_size: usize,
+    problem_size: usize,
+    sizing: PopulationSizing,
+) -> Box<dyn HeuristicPopulation<Objective = O, Individual = S> + Send + Sync>
+where
+    O: HeuristicObjective<Solution = S> + Shuffled + 'static,
+    S: HeuristicSolution + RosomaxaWeighted + DominanceOrdered + 'static,
+{
+    if selection_size == 1 {
+        Box::new(Greedy::new(objective, 1, None))
+    } else {
+        let config = RosomaxaConfig::new_with_sizing(selection_size, problem_size, sizing);
+        let population =
+            Rosomaxa::new(objective, environment, config).expect("cannot create rosomaxa with adaptive configuration");
+
+        Box::new(population)
+    }
+}
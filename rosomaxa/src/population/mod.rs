@@ -14,6 +14,9 @@ pub use self::rosomaxa::Rosomaxa;
 pub use self::rosomaxa::RosomaxaConfig;
 pub use self::rosomaxa::RosomaxaWeighted;
 
+mod sizing;
+pub use self::sizing::PopulationSizing;
+
 use crate::prelude::*;
 use std::cmp::Ordering;
 use std::fmt::Display;
@@ -33,6 +33,10 @@ pub struct RosomaxaConfig {
     pub rebalance_memory: usize,
     /// A ratio of exploration phase.
     pub exploration_ratio: f64,
+    /// An adaptive population sizing policy. When set, selection size shrinks with search progress.
+    pub sizing: Option<PopulationSizing>,
+    /// A merging of similar GSOM nodes. When set, it bounds network size in long runs.
+    pub node_merging: Option<NodeMerging>,
 }
 
 impl RosomaxaConfig {
@@ -49,6 +53,21 @@ impl RosomaxaConfig {
             learning_rate: 0.1,
             rebalance_memory: 100,
             exploration_ratio: 0.9,
+            sizing: None,
+            node_merging: None,
+        }
+    }
+
+    /// Creates an instance of `RosomaxaConfig` using default parameters with elite and node
+    /// population sizes scaled by problem size and selection size adapted to search progress.
+    pub fn new_with_sizing(selection_size: usize, problem_size: usize, sizing: PopulationSizing) -> Self {
+        let config = Self::new_with_defaults(selection_size);
+
+        Self {
+            elite_size: sizing.scale(config.elite_size, problem_size),
+            node_size: sizing.scale(config.node_size, problem_size),
+            sizing: Some(sizing),
+            ..config
         }
     }
 }
@@ -234,6 +253,11 @@ where
             HeuristicSpeed::Unknown | HeuristicSpeed::Moderate { .. } => self.config.selection_size,
             HeuristicSpeed::Slow { ratio, .. } => (self.config.selection_size as f64 * ratio).max(1.).round() as usize,
         };
+        let selection_size = self
+            .config
+            .sizing
+            .as_ref()
+            .map_or(selection_size, |sizing| sizing.shrink(selection_size, statistics.termination_estimate));
 
         match &mut self.phase {
             RosomaxaPhases::Initial { solutions: individuals, .. } => {
@@ -404,6 +428,7 @@ where
                 learning_rate: config.learning_rate,
                 rebalance_memory: config.rebalance_memory,
                 has_initial_error: true,
+                merging: config.node_merging.clone(),
             },
             environment.random.clone(),
             storage_factory,
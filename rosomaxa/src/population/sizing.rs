@@ -0,0 +1,52 @@
+#[cfg(test)]
+#[path = "../../tests/unit/population/sizing_test.rs"]
+mod sizing_test;
+
+/// Specifies a policy which adapts population sizes to the scale of the problem instance and
+/// to the search progress: larger instances get bigger populations to keep more diversity, while
+/// selection size shrinks when the search converges, so more compute is spent on deeper
+/// search around few best known individuals.
+#[derive(Clone, Debug)]
+pub struct PopulationSizing {
+    /// A problem size (e.g. amount of jobs) which corresponds to unscaled population sizes.
+    pub reference_size: usize,
+    /// A max scale factor applied to population sizes.
+    pub max_scale: f64,
+    /// A termination estimate after which selection size starts to shrink.
+    pub shrink_threshold: f64,
+    /// A min selection size reached at the end of the search.
+    pub min_selection_size: usize,
+}
+
+impl Default for PopulationSizing {
+    fn default() -> Self {
+        Self { reference_size: 100, max_scale: 4., shrink_threshold: 0.5, min_selection_size: 2 }
+    }
+}
+
+impl PopulationSizing {
+    /// Scales population size using problem size: it grows sublinearly (as a square root) with
+    /// problem size and never goes below original size or above its `max_scale` multiple.
+    pub fn scale(&self, size: usize, problem_size: usize) -> usize {
+        let ratio = problem_size as f64 / self.reference_size.max(1) as f64;
+        let scale = ratio.sqrt().clamp(1., self.max_scale.max(1.));
+
+        (size as f64 * scale).round() as usize
+    }
+
+    /// Shrinks selection size linearly from its original value at `shrink_threshold` down to
+    /// `min_selection_size` when termination estimate reaches one.
+    pub fn shrink(&self, selection_size: usize, termination_estimate: f64) -> usize {
+        let min_selection_size = self.min_selection_size.min(selection_size);
+        let threshold = self.shrink_threshold.clamp(0., 1.);
+
+        if termination_estimate <= threshold || threshold >= 1. {
+            return selection_size;
+        }
+
+        let progress = ((termination_estimate - threshold) / (1. - threshold)).min(1.);
+        let range = (selection_size - min_selection_size) as f64;
+
+        (selection_size as f64 - range * progress).round() as usize
+    }
+}
@@ -5,36 +5,15 @@ mod random_test;
 use rand::prelude::*;
 use rand::Error;
 use std::cell::UnsafeCell;
+use std::fmt::{Debug, Formatter};
 use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 
-/// Provides the way to use randomized values in generic way.
+/// Provides the way to use randomized values in generic way. All values are sampled from the
+/// generator returned by `get_rng`, so a custom random number generator stream can be used by
+/// implementing this method only.
 pub trait Random {
     /// Produces integral random value, uniformly distributed on the closed interval [min, max]
-    fn uniform_int(&self, min: i32, max: i32) -> i32;
-
-    /// Produces real random value, uniformly distributed on the closed interval [min, max)
-    fn uniform_real(&self, min: f64, max: f64) -> f64;
-
-    /// Flips a coin and returns true if it is "heads", false otherwise.
-    fn is_head_not_tails(&self) -> bool;
-
-    /// Tests probability value in (0., 1.) range.
-    fn is_hit(&self, probability: f64) -> bool;
-
-    /// Returns an index from collected with probability weight.
-    /// Uses exponential distribution where the weights are the rate of the distribution (lambda)
-    /// and selects the smallest sampled value.
-    fn weighted(&self, weights: &[usize]) -> usize;
-
-    /// Returns RNG.
-    fn get_rng(&self) -> RandomGen;
-}
-
-/// A default random implementation.
-#[derive(Default)]
-pub struct DefaultRandom {}
-
-impl Random for DefaultRandom {
     fn uniform_int(&self, min: i32, max: i32) -> i32 {
         if min == max {
             return min;
@@ -44,6 +23,7 @@ impl Random for DefaultRandom {
         self.get_rng().gen_range(min..max + 1)
     }
 
+    /// Produces real random value, uniformly distributed on the closed interval [min, max)
     fn uniform_real(&self, min: f64, max: f64) -> f64 {
         if (min - max).abs() < f64::EPSILON {
             return min;
@@ -53,14 +33,19 @@ impl Random for DefaultRandom {
         self.get_rng().gen_range(min..max)
     }
 
+    /// Flips a coin and returns true if it is "heads", false otherwise.
     fn is_head_not_tails(&self) -> bool {
         self.get_rng().gen_bool(0.5)
     }
 
+    /// Tests probability value in (0., 1.) range.
     fn is_hit(&self, probability: f64) -> bool {
         self.get_rng().gen_bool(probability.clamp(0., 1.))
     }
 
+    /// Returns an index from collected with probability weight.
+    /// Uses exponential distribution where the weights are the rate of the distribution (lambda)
+    /// and selects the smallest sampled value.
     fn weighted(&self, weights: &[usize]) -> usize {
         weights
             .iter()
@@ -71,9 +56,67 @@ impl Random for DefaultRandom {
             .1
     }
 
+    /// Returns RNG.
+    fn get_rng(&self) -> RandomGen;
+}
+
+/// A default random implementation.
+#[derive(Default)]
+pub struct DefaultRandom {}
+
+impl Random for DefaultRandom {
     fn get_rng(&self) -> RandomGen {
         let rng = DEFAULT_RNG.with(|t| t.clone());
-        RandomGen { rng }
+        RandomGen::with_rng(rng)
+    }
+}
+
+/// A random implementation which samples all values from a single user provided random number
+/// generator stream, e.g. a seeded or counter-based one used to run reproducible experiments.
+/// The stream is shared between threads, so the sequence of sampled values is deterministic
+/// only when the order of sampling is, e.g. when the search runs in a single thread.
+pub struct StreamRandom {
+    rng: Arc<Mutex<dyn RngCore + Send>>,
+}
+
+impl StreamRandom {
+    /// Creates a new instance of `StreamRandom` using given random number generator.
+    pub fn new<R: RngCore + Send + 'static>(rng: R) -> Self {
+        Self { rng: Arc::new(Mutex::new(rng)) }
+    }
+
+    /// Creates a new instance of `StreamRandom` using default generator initialized with given seed.
+    pub fn new_with_seed(seed: u64) -> Self {
+        Self::new(SmallRng::seed_from_u64(seed))
+    }
+}
+
+impl Random for StreamRandom {
+    fn get_rng(&self) -> RandomGen {
+        RandomGen::with_rng(Rc::new(UnsafeCell::new(SharedRng { rng: self.rng.clone() })))
+    }
+}
+
+/// Provides access to random number generator shared between threads.
+struct SharedRng {
+    rng: Arc<Mutex<dyn RngCore + Send>>,
+}
+
+impl RngCore for SharedRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.lock().unwrap().next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.lock().unwrap().next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.lock().unwrap().fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.rng.lock().unwrap().try_fill_bytes(dest)
     }
 }
 
@@ -82,18 +125,24 @@ thread_local! {
 }
 
 /// Specifies underlying random generator type.
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct RandomGen {
-    rng: Rc<UnsafeCell<SmallRng>>,
+    rng: Rc<UnsafeCell<dyn RngCore>>,
 }
 
 impl RandomGen {
-    /// Creates a new instance of `RandomGen` using given reference to small rng.
-    pub fn with_rng(rng: Rc<UnsafeCell<SmallRng>>) -> Self {
+    /// Creates a new instance of `RandomGen` using given reference to random number generator.
+    pub fn with_rng<R: RngCore + 'static>(rng: Rc<UnsafeCell<R>>) -> Self {
         Self { rng }
     }
 }
 
+impl Debug for RandomGen {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RandomGen").finish()
+    }
+}
+
 impl RngCore for RandomGen {
     #[inline(always)]
     fn next_u32(&mut self) -> u32 {
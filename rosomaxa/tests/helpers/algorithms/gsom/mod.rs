@@ -82,6 +82,7 @@ pub fn create_test_network(has_initial_error: bool) -> Network<Data, DataStorage
             learning_rate: 0.1,
             rebalance_memory: 100,
             has_initial_error,
+            merging: None,
         },
         Arc::new(DefaultRandom::default()),
         DataStorageFactory,
@@ -49,6 +49,44 @@ impl Objective for SliceSumObjective {
     }
 }
 
+pub struct SliceScalarObjective {
+    objective: SliceObjective,
+}
+
+impl SliceScalarObjective {
+    pub fn new(objective: SliceObjective) -> Self {
+        Self { objective }
+    }
+}
+
+impl Objective for SliceScalarObjective {
+    type Solution = Vec<f64>;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        self.objective.total_order(a, b)
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.objective.distance(a, b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        self.objective.fitness(solution)
+    }
+}
+
+impl MultiObjective for SliceScalarObjective {
+    fn objectives<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a (dyn Objective<Solution = Self::Solution> + Send + Sync)> + 'a> {
+        Box::new(std::iter::once(self.objective.as_ref()))
+    }
+
+    fn is_totally_ordered(&self) -> bool {
+        true
+    }
+}
+
 #[derive(Default)]
 pub struct SliceMultiObjective {
     objectives: Vec<SliceObjective>,
@@ -146,7 +146,7 @@ mod common {
 mod node_growing {
     use super::*;
     use crate::algorithms::gsom::{NetworkConfig, NodeLink};
-    use crate::prelude::RandomGen;
+    use crate::prelude::{DefaultRandom, RandomGen};
     use std::sync::{Arc, RwLock};
 
     fn create_trivial_network(has_initial_error: bool) -> NetworkType {
@@ -173,7 +173,7 @@ mod node_growing {
             }
 
             fn get_rng(&self) -> RandomGen {
-                unreachable!()
+                DefaultRandom::default().get_rng()
             }
         }
         Network::new(
@@ -189,6 +189,7 @@ mod node_growing {
                 learning_rate: 0.1,
                 rebalance_memory: 500,
                 has_initial_error,
+                merging: None,
             },
             Arc::new(DummyRandom {}),
             DataStorageFactory,
@@ -260,3 +261,82 @@ mod node_growing {
         assert!((mse - 0.0001138).abs() < 1E7);
     }
 }
+
+mod node_merging {
+    use super::*;
+    use crate::algorithms::gsom::{NetworkConfig, NodeMerging, Storage};
+    use crate::helpers::algorithms::gsom::create_test_network;
+    use crate::utils::DefaultRandom;
+    use std::sync::Arc;
+
+    fn create_network_with_merging(max_hits: usize) -> NetworkType {
+        Network::new(
+            [Data::new(0., 0., 0.), Data::new(10., 0., 0.), Data::new(0., 10., 0.), Data::new(0., 0., 10.)],
+            NetworkConfig {
+                spread_factor: 0.25,
+                distribution_factor: 0.25,
+                learning_rate: 0.1,
+                rebalance_memory: 100,
+                has_initial_error: false,
+                merging: Some(NodeMerging { max_distance: 1., max_hits }),
+            },
+            Arc::new(DefaultRandom::default()),
+            DataStorageFactory,
+        )
+    }
+
+    fn add_similar_node(network: &mut NetworkType, source: Coordinate, target: Coordinate, hits: usize) {
+        let weights = network.nodes.get(&source).unwrap().read().unwrap().weights.clone();
+        let weights = weights.iter().map(|w| w + 0.1).collect::<Vec<_>>();
+        network.insert(target, weights.as_slice());
+
+        [source, target].iter().for_each(|coordinate| {
+            let mut node = network.nodes.get(coordinate).unwrap().write().unwrap();
+            (0..hits).for_each(|time| node.new_hit(time));
+        });
+    }
+
+    parameterized_test! {can_merge_similar_nodes_on_smooth, (max_hits, hits, expected_size), {
+        can_merge_similar_nodes_on_smooth_impl(max_hits, hits, expected_size);
+    }}
+
+    can_merge_similar_nodes_on_smooth! {
+        case01_low_hits: (2, 1, 4),
+        case02_high_hits: (2, 3, 5),
+    }
+
+    fn can_merge_similar_nodes_on_smooth_impl(max_hits: usize, hits: usize, expected_size: usize) {
+        let mut network = create_network_with_merging(max_hits);
+        add_similar_node(&mut network, Coordinate(1, 0), Coordinate(2, 0), hits);
+        network.nodes.get(&Coordinate(1, 0)).unwrap().write().unwrap().storage.add(Data::new(1., 2., 3.));
+
+        network.smooth(0);
+
+        assert_eq!(network.size(), expected_size);
+        let data_size = network.get_nodes().map(|node| node.read().unwrap().storage.data.len()).sum::<usize>();
+        assert_eq!(data_size, 4);
+    }
+
+    #[test]
+    fn can_keep_min_network_size_when_merging() {
+        let mut network = create_network_with_merging(2);
+        let weights = network.nodes.get(&Coordinate(0, 0)).unwrap().read().unwrap().weights.clone();
+        network.nodes.values().for_each(|node| node.write().unwrap().weights = weights.clone());
+
+        network.smooth(0);
+
+        assert_eq!(network.size(), 4);
+    }
+
+    #[test]
+    fn can_skip_merging_when_not_configured() {
+        let mut network = create_test_network(false);
+        let weights = network.nodes.get(&Coordinate(0, 0)).unwrap().read().unwrap().weights.clone();
+        network.insert(Coordinate(2, 0), weights.as_slice());
+        network.insert(Coordinate(-1, 0), weights.as_slice());
+
+        network.smooth(0);
+
+        assert_eq!(network.size(), 6);
+    }
+}
@@ -0,0 +1,77 @@
+use super::*;
+use crate::algorithms::nsga2::crowding_distance::assign_crowding_distance;
+use crate::algorithms::nsga2::non_dominated_sort::non_dominated_sort;
+use crate::helpers::algorithms::nsga2::*;
+
+fn create_objective(normalization: ObjectiveNormalization) -> NormalizedObjective<Vec<f64>> {
+    NormalizedObjective::new(Arc::new(SliceDimensionObjective::new(0)), normalization)
+}
+
+fn get_spread(objective: &NormalizedObjective<Vec<f64>>, values: &[f64]) -> f64 {
+    let solutions = values.iter().map(|value| vec![*value]).collect::<Vec<_>>();
+    let solutions = solutions.iter().collect::<Vec<_>>();
+
+    objective.spread(solutions.as_slice())
+}
+
+#[test]
+fn can_use_default_spread() {
+    let objective = create_objective(ObjectiveNormalization::Spread);
+
+    assert_eq!(get_spread(&objective, &[1., 2., 4.]), 3.);
+    assert_eq!(get_spread(&objective, &[2., 3.]), 1.);
+}
+
+#[test]
+fn can_use_running_min_max_spread() {
+    let objective = create_objective(ObjectiveNormalization::RunningMinMax);
+
+    assert_eq!(get_spread(&objective, &[]), 0.);
+    assert_eq!(get_spread(&objective, &[1., 3.]), 2.);
+    assert_eq!(get_spread(&objective, &[2., 2.5]), 2.);
+    assert_eq!(get_spread(&objective, &[0., 2.]), 3.);
+}
+
+parameterized_test! {can_use_quantile_spread, (values, lower, upper, expected), {
+    can_use_quantile_spread_impl(values, lower, upper, expected);
+}}
+
+can_use_quantile_spread! {
+    case01_outlier: (vec![0., 1., 2., 3., 100.], 0.2, 0.8, 3.),
+    case02_full_range: (vec![0., 1., 2., 3., 100.], 0., 1., 100.),
+    case03_fallback: (vec![1., 1., 1., 1., 5.], 0.2, 0.8, 4.),
+    case04_same: (vec![1., 1.], 0.2, 0.8, 0.),
+}
+
+fn can_use_quantile_spread_impl(values: Vec<f64>, lower: f64, upper: f64, expected: f64) {
+    let objective = create_objective(ObjectiveNormalization::Quantile { lower, upper });
+
+    assert_eq!(get_spread(&objective, values.as_slice()), expected);
+}
+
+#[test]
+fn can_keep_total_order() {
+    let objective = create_objective(ObjectiveNormalization::RunningMinMax);
+
+    assert_eq!(get_spread(&objective, &[0., 100.]), 100.);
+    assert_eq!(objective.total_order(&vec![1.], &vec![2.]), Ordering::Less);
+    assert_eq!(objective.total_order(&vec![2.], &vec![1.]), Ordering::Greater);
+    assert_eq!(objective.distance(&vec![3.], &vec![1.]), 2.);
+}
+
+#[test]
+fn can_get_crowding_distance_with_running_min_max() {
+    let normalized = Arc::new(create_objective(ObjectiveNormalization::RunningMinMax));
+    let mo = SliceMultiObjective::new(vec![normalized.clone(), Arc::new(SliceDimensionObjective::new(1))]);
+    assert_eq!(get_spread(normalized.as_ref(), &[0., 10.]), 10.);
+    let solutions = vec![vec![1., 3.], vec![3., 1.], vec![2., 2.]];
+
+    let front = non_dominated_sort(&solutions, &mo);
+    let (crowding, stat) = assign_crowding_distance(&front, &mo);
+
+    assert_eq!(stat[0].spread, 10.);
+    assert_eq!(stat[1].spread, 2.);
+    let middle = crowding.iter().find(|item| item.solution.eq(&solutions[2])).unwrap();
+    // 2 / (10 * 2) + 2 / (2 * 2)
+    assert!((middle.crowding_distance - 0.6).abs() < 1E-9);
+}
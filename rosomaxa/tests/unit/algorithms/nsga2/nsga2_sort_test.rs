@@ -91,3 +91,14 @@ fn can_use_select_and_rank_with_non_transient_relationship_by_hierarchical_objec
 
     assert_eq!(ranked.len(), solutions.len())
 }
+
+#[test]
+fn can_use_select_and_rank_with_totally_ordered_objective() {
+    let solutions = vec![vec![3., 0.], vec![1., 2.], vec![2., 1.], vec![1., 3.], vec![5., 0.]];
+    let objective = SliceScalarObjective::new(Arc::new(SliceDimensionObjective::new(0)));
+
+    let ranked = select_and_rank(solutions.as_slice(), 4, &objective);
+
+    let results = ranked.iter().map(|s| (s.index, s.rank)).collect::<Vec<_>>();
+    assert_eq!(results, vec![(1, 0), (3, 0), (2, 1), (0, 2)]);
+}
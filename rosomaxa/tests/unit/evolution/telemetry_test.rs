@@ -1,6 +1,8 @@
 use super::*;
 use crate::example::*;
-use crate::helpers::example::{create_default_heuristic_context, create_example_objective};
+use crate::helpers::example::{
+    create_default_heuristic_context, create_example_objective, create_heuristic_context_with_solutions,
+};
 use crate::utils::compare_floats;
 use std::cmp::Ordering;
 
@@ -36,3 +38,78 @@ fn can_update_statistic() {
     telemetry.on_generation(objective, population, 0., Timer::start(), true);
     compare_statistic(telemetry.get_statistics(), (1000, 2. / 1001., 0.001));
 }
+
+fn create_generation(number: usize, timestamp: f64) -> TelemetryGeneration {
+    TelemetryGeneration {
+        number,
+        timestamp,
+        i_all_ratio: 0.,
+        i_1000_ratio: 0.,
+        is_improvement: false,
+        population: TelemetryPopulation { individuals: vec![] },
+    }
+}
+
+parameterized_test! {can_get_metrics_delta, (cursor, expected_evolution, expected_best, expected_cursor), {
+    can_get_metrics_delta_impl(cursor, expected_evolution, expected_best, expected_cursor);
+}}
+
+can_get_metrics_delta! {
+    case01_from_start: (TelemetryCursor::Generation(0), vec![0, 10, 20], vec![0, 15], TelemetryCursor::Generation(21)),
+    case02_generation: (TelemetryCursor::Generation(11), vec![20], vec![15], TelemetryCursor::Generation(21)),
+    case03_time: (TelemetryCursor::Time(1.5), vec![20], vec![15], TelemetryCursor::Generation(21)),
+    case04_no_changes: (TelemetryCursor::Generation(21), vec![], vec![], TelemetryCursor::Generation(21)),
+    case05_no_changes_time: (TelemetryCursor::Time(5.), vec![], vec![], TelemetryCursor::Time(5.)),
+}
+
+fn can_get_metrics_delta_impl(
+    cursor: TelemetryCursor,
+    expected_evolution: Vec<usize>,
+    expected_best: Vec<usize>,
+    expected_cursor: TelemetryCursor,
+) {
+    let metrics = TelemetryMetrics {
+        generations: 20,
+        evolution: vec![create_generation(0, 0.), create_generation(10, 1.), create_generation(20, 2.)],
+        best: vec![
+            TelemetryBest { generation: 0, timestamp: 0., fitness: vec![2.] },
+            TelemetryBest { generation: 15, timestamp: 1.6, fitness: vec![1.] },
+        ],
+        ..TelemetryMetrics::default()
+    };
+
+    let delta = metrics.get_delta(cursor);
+
+    assert_eq!(delta.generations, 20);
+    assert_eq!(delta.evolution.iter().map(|generation| generation.number).collect::<Vec<_>>(), expected_evolution);
+    assert_eq!(delta.best.iter().map(|best| best.generation).collect::<Vec<_>>(), expected_best);
+    assert_eq!(delta.cursor, expected_cursor);
+}
+
+#[test]
+fn can_poll_metrics_from_feed() {
+    let heuristic_ctx = create_heuristic_context_with_solutions(vec![vec![1., 1.]]);
+    let objective = heuristic_ctx.objective();
+    let population = heuristic_ctx.population();
+    let feed = TelemetryFeed::default();
+    let mut telemetry = Telemetry::new(TelemetryMode::OnlyMetrics { track_population: 2, feed: Some(feed.clone()) });
+
+    telemetry.on_generation(objective, population, 0., Timer::start(), true);
+    telemetry.on_generation(objective, population, 0., Timer::start(), false);
+    let delta = feed.get_delta(TelemetryCursor::Generation(0));
+    assert_eq!(delta.generations, 1);
+    assert_eq!(delta.evolution.len(), 1);
+    assert_eq!(delta.best.len(), 1);
+    assert_eq!(delta.cursor, TelemetryCursor::Generation(1));
+
+    telemetry.on_generation(objective, population, 0., Timer::start(), false);
+    let delta = feed.get_delta(delta.cursor);
+    assert_eq!(delta.generations, 2);
+    assert_eq!(delta.evolution.len(), 1);
+    assert!(delta.best.is_empty());
+    assert_eq!(delta.cursor, TelemetryCursor::Generation(3));
+
+    let delta = feed.get_delta(delta.cursor);
+    assert!(delta.evolution.is_empty());
+    assert_eq!(delta.cursor, TelemetryCursor::Generation(3));
+}
@@ -87,19 +87,19 @@ fn can_display_heuristic_info_impl(is_experimental: bool) {
     heuristic.tracker.observation(
         1,
         "name1".to_string(),
-        Duration::from_millis(100),
+        Some(Duration::from_millis(100)),
         SearchState::Stagnated(MedianRatio { ratio: 1. }),
     );
     heuristic.tracker.observation(
         2,
         "name1".to_string(),
-        Duration::from_millis(101),
+        Some(Duration::from_millis(101)),
         SearchState::BestMajorImprovement(MedianRatio { ratio: 1. }),
     );
     heuristic.tracker.observation(
         1,
         "name2".to_string(),
-        Duration::from_millis(102),
+        Some(Duration::from_millis(102)),
         SearchState::DiverseImprovement(MedianRatio { ratio: 1. }),
     );
 
@@ -107,3 +107,33 @@ fn can_display_heuristic_info_impl(is_experimental: bool) {
 
     assert_eq!(!formatted.is_empty(), is_experimental);
 }
+
+parameterized_test! {can_skip_operator_over_time_budget, (budget, skips, expected), {
+    can_skip_operator_over_time_budget_impl(budget, skips, expected);
+}}
+
+can_skip_operator_over_time_budget! {
+    case_01_no_budget: (None, 0, false),
+    case_02_within_budget: (Some(200), 0, false),
+    case_03_over_budget: (Some(50), 0, true),
+    case_04_over_budget_max_skips: (Some(50), MAX_BUDGET_SKIPS, false),
+}
+
+fn can_skip_operator_over_time_budget_impl(budget: Option<u64>, skips: usize, expected: bool) {
+    let environment = Environment::default();
+    let mut heuristic =
+        DynamicSelective::<VectorContext, VectorObjective, VectorSolution>::new(vec![], vec![], &environment)
+            .with_time_budget(OperatorTimeBudget {
+                default: None,
+                operators: budget
+                    .map(|budget| ("name1".to_string(), Duration::from_millis(budget)))
+                    .into_iter()
+                    .collect(),
+            });
+    let state = SearchState::Stagnated(MedianRatio { ratio: 1. });
+    heuristic.tracker.observation(1, "name1".to_string(), Some(Duration::from_millis(100)), state.clone());
+    (0..skips).for_each(|_| heuristic.tracker.observation(1, "name1".to_string(), None, state.clone()));
+
+    assert_eq!(heuristic.tracker.is_over_budget("name1"), expected);
+    assert!(!heuristic.tracker.is_over_budget("name2"));
+}
@@ -0,0 +1,37 @@
+use super::*;
+
+parameterized_test! {can_scale_population_size, (size, problem_size, expected), {
+    can_scale_population_size_impl(size, problem_size, expected);
+}}
+
+can_scale_population_size! {
+    case01_small_instance: (2, 10, 2),
+    case02_reference_instance: (2, 100, 2),
+    case03_larger_instance: (2, 400, 4),
+    case04_huge_instance: (2, 10000, 8),
+}
+
+fn can_scale_population_size_impl(size: usize, problem_size: usize, expected: usize) {
+    let sizing = PopulationSizing::default();
+
+    assert_eq!(sizing.scale(size, problem_size), expected);
+}
+
+parameterized_test! {can_shrink_selection_size, (selection_size, termination_estimate, expected), {
+    can_shrink_selection_size_impl(selection_size, termination_estimate, expected);
+}}
+
+can_shrink_selection_size! {
+    case01_start: (8, 0., 8),
+    case02_threshold: (8, 0.5, 8),
+    case03_middle: (8, 0.75, 5),
+    case04_end: (8, 1., 2),
+    case05_above_end: (8, 1.5, 2),
+    case06_small_selection: (1, 1., 1),
+}
+
+fn can_shrink_selection_size_impl(selection_size: usize, termination_estimate: f64, expected: usize) {
+    let sizing = PopulationSizing::default();
+
+    assert_eq!(sizing.shrink(selection_size, termination_estimate), expected);
+}
@@ -20,3 +20,20 @@ fn can_return_weights() {
         assert!((actual_ratio - expected_ratio).abs() < 0.05);
     });
 }
+
+#[test]
+fn can_reproduce_values_with_same_stream() {
+    let sample = |random: &dyn Random| {
+        let mut data = (0..10).collect::<Vec<_>>();
+        data.shuffle(&mut random.get_rng());
+
+        (random.uniform_int(0, 100), random.uniform_real(0., 1.), random.weighted(&[1, 2, 3]), data)
+    };
+
+    let first = sample(&StreamRandom::new_with_seed(42));
+    let second = sample(&StreamRandom::new(SmallRng::seed_from_u64(42)));
+    let third = sample(&StreamRandom::new_with_seed(7));
+
+    assert_eq!(first, second);
+    assert_ne!(first, third);
+}
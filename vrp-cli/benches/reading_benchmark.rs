@@ -0,0 +1,77 @@
+//! Contains throughput benchmarks of pragmatic problem and routing matrix reading: from a stream and from
+//! a memory slice, which is used with `mmap` feature. Use `cargo bench -p vrp-cli --features bench` to run them.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::io::BufReader;
+use vrp_cli::pragmatic::format::problem::*;
+
+/// Specifies bundled problem instances.
+const PROBLEMS: &[(&str, &str)] = &[
+    ("reload.100", "pragmatic/benches/simple.reload.100.json"),
+    ("multi-job.100", "pragmatic/benches/multi-job.100.json"),
+];
+
+/// Specifies amount of locations in generated routing matrices.
+const MATRIX_SIZES: &[usize] = &[100, 1000];
+
+fn read_instance(path: &str) -> Vec<u8> {
+    let path = format!("{}/../examples/data/{}", env!("CARGO_MANIFEST_DIR"), path);
+    std::fs::read(path.as_str()).unwrap_or_else(|err| panic!("cannot read {} file: '{}'", path, err))
+}
+
+fn generate_matrix(size: usize) -> Vec<u8> {
+    let values = (0..size * size).map(|idx| ((idx * 7919) % 100_000) as i64).collect::<Vec<_>>();
+    let matrix = Matrix {
+        profile: Some("car".to_string()),
+        timestamp: None,
+        travel_times: values.clone(),
+        distances: values,
+        error_codes: None,
+    };
+
+    serde_json::to_vec(&matrix).expect("cannot serialize matrix")
+}
+
+fn problem_reading_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("problem reading");
+
+    PROBLEMS.iter().for_each(|(name, path)| {
+        let content = read_instance(path);
+        group.throughput(Throughput::Bytes(content.len() as u64));
+
+        group.bench_with_input(BenchmarkId::new("stream", name), &content, |b, content| {
+            b.iter(|| black_box(deserialize_problem(BufReader::new(content.as_slice())).unwrap()))
+        });
+        group.bench_with_input(BenchmarkId::new("slice", name), &content, |b, content| {
+            b.iter(|| black_box(deserialize_problem_from_slice(content.as_slice()).unwrap()))
+        });
+    });
+
+    group.finish();
+}
+
+fn matrix_reading_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("matrix reading");
+
+    MATRIX_SIZES.iter().for_each(|size| {
+        let content = generate_matrix(*size);
+        group.throughput(Throughput::Bytes(content.len() as u64));
+
+        group.bench_with_input(BenchmarkId::new("stream", size), &content, |b, content| {
+            b.iter(|| black_box(deserialize_matrix(BufReader::new(content.as_slice())).unwrap()))
+        });
+        group.bench_with_input(BenchmarkId::new("slice", size), &content, |b, content| {
+            b.iter(|| black_box(deserialize_matrix_from_slice(content.as_slice()).unwrap()))
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(15);
+    targets = problem_reading_benchmark,
+              matrix_reading_benchmark
+}
+criterion_main!(benches);
@@ -0,0 +1,208 @@
+//! Contains performance benchmarks of the main solver parts run over a representative set of bundled instances.
+//! Use `cargo bench -p vrp-cli --features bench` to run them.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+use vrp_cli::core::construction::heuristics::*;
+use vrp_cli::core::models::Problem;
+use vrp_cli::core::prelude::{create_default_config_builder, HeuristicSolution, Solver};
+use vrp_cli::core::rosomaxa::evolution::TelemetryMode;
+use vrp_cli::core::solver::search::*;
+use vrp_cli::core::solver::{create_elitism_population, RefinementContext};
+use vrp_cli::core::utils::Environment;
+use vrp_cli::pragmatic::format::problem::PragmaticProblem;
+use vrp_cli::pragmatic::format::FormatError;
+use vrp_cli::scientific::lilim::LilimProblem;
+use vrp_cli::scientific::solomon::SolomonProblem;
+
+/// Specifies a format of bundled instance.
+enum InstanceFormat {
+    Solomon,
+    Lilim,
+    Pragmatic,
+}
+
+/// Specifies bundled instances: small ones for quick feedback and medium ones with rich features.
+const INSTANCES: &[(&str, &str, InstanceFormat)] = &[
+    ("solomon.c101.25", "scientific/solomon/C101.25.txt", InstanceFormat::Solomon),
+    ("solomon.c101.100", "scientific/solomon/C101.100.txt", InstanceFormat::Solomon),
+    ("lilim.lc101", "scientific/lilim/LC101.txt", InstanceFormat::Lilim),
+    ("pragmatic.reload.100", "pragmatic/benches/simple.reload.100.json", InstanceFormat::Pragmatic),
+    ("pragmatic.multi-job.100", "pragmatic/benches/multi-job.100.json", InstanceFormat::Pragmatic),
+];
+
+fn get_problem(path: &str, format: &InstanceFormat) -> Arc<Problem> {
+    let path = format!("{}/../examples/data/{}", env!("CARGO_MANIFEST_DIR"), path);
+    let file = File::open(path.as_str()).unwrap_or_else(|err| panic!("cannot open {} file: '{}'", path, err));
+    let reader = BufReader::new(file);
+
+    let problem = match format {
+        InstanceFormat::Solomon => reader.read_solomon(false),
+        InstanceFormat::Lilim => reader.read_lilim(false),
+        InstanceFormat::Pragmatic => {
+            reader.read_pragmatic().map_err(|errs| FormatError::format_many(errs.as_slice(), ","))
+        }
+    };
+
+    Arc::new(problem.unwrap_or_else(|err| panic!("cannot create problem from {}: {}", path, err)))
+}
+
+fn get_problems() -> impl Iterator<Item = (&'static str, Arc<Problem>)> {
+    INSTANCES.iter().map(|(name, path, format)| (*name, get_problem(path, format)))
+}
+
+fn create_refinement_ctx(problem: Arc<Problem>, environment: Arc<Environment>) -> RefinementContext {
+    RefinementContext::new(
+        problem.clone(),
+        create_elitism_population(problem.objective.clone(), environment.clone()),
+        TelemetryMode::None,
+        environment,
+    )
+}
+
+/// Builds initial solution using cheapest insertion heuristic.
+fn create_initial_solution(refinement_ctx: &RefinementContext) -> InsertionContext {
+    let environment = refinement_ctx.environment.clone();
+
+    RecreateWithCheapest::new(environment.random.clone())
+        .run(refinement_ctx, InsertionContext::new(refinement_ctx.problem.clone(), environment))
+}
+
+/// Builds initial solution and removes every second job from each route in deterministic way.
+fn create_partial_solution(refinement_ctx: &RefinementContext) -> InsertionContext {
+    let mut insertion_ctx = create_initial_solution(refinement_ctx);
+
+    let solution = &mut insertion_ctx.solution;
+    let locked = &solution.locked;
+    let removed = solution
+        .routes
+        .iter_mut()
+        .flat_map(|route_ctx| {
+            let jobs = route_ctx.route.tour.jobs().step_by(2).filter(|job| !locked.contains(job)).collect::<Vec<_>>();
+            jobs.iter().for_each(|job| {
+                route_ctx.route_mut().tour.remove(job);
+            });
+
+            jobs
+        })
+        .collect::<Vec<_>>();
+    solution.required.extend(removed);
+
+    insertion_ctx.restore();
+
+    insertion_ctx
+}
+
+fn construction_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("construction");
+
+    get_problems().for_each(|(name, problem)| {
+        let refinement_ctx = create_refinement_ctx(problem, Arc::new(Environment::default()));
+
+        group.bench_with_input(BenchmarkId::new("cheapest", name), &refinement_ctx, |b, refinement_ctx| {
+            b.iter(|| black_box(create_initial_solution(refinement_ctx)))
+        });
+    });
+
+    group.finish();
+}
+
+fn insertion_evaluation_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("insertion evaluation");
+    let leg_selector = AllLegSelector::default();
+    let result_selector = BestResultSelector::default();
+
+    get_problems().for_each(|(name, problem)| {
+        let refinement_ctx = create_refinement_ctx(problem, Arc::new(Environment::default()));
+        let insertion_ctx = create_partial_solution(&refinement_ctx);
+
+        group.bench_with_input(BenchmarkId::new("all legs", name), &insertion_ctx, |b, insertion_ctx| {
+            b.iter(|| {
+                insertion_ctx.solution.required.iter().for_each(|job| {
+                    let eval_ctx = EvaluationContext {
+                        constraint: &insertion_ctx.problem.constraint,
+                        job,
+                        leg_selector: &leg_selector,
+                        result_selector: &result_selector,
+                    };
+
+                    black_box(insertion_ctx.solution.routes.iter().fold(
+                        InsertionResult::make_failure(),
+                        |acc, route_ctx| {
+                            evaluate_job_insertion_in_route(
+                                insertion_ctx,
+                                &eval_ctx,
+                                route_ctx,
+                                InsertionPosition::Any,
+                                acc,
+                            )
+                        },
+                    ));
+                })
+            })
+        });
+    });
+
+    group.finish();
+}
+
+fn ruin_recreate_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ruin recreate");
+
+    let ruins: Vec<(&str, Arc<dyn Ruin + Send + Sync>)> = vec![
+        ("adjusted string removal", Arc::new(AdjustedStringRemoval::default())),
+        ("worst job removal", Arc::new(WorstJobRemoval::default())),
+    ];
+
+    get_problems().for_each(|(name, problem)| {
+        let environment = Arc::new(Environment::default());
+        let refinement_ctx = create_refinement_ctx(problem, environment.clone());
+        let insertion_ctx = create_initial_solution(&refinement_ctx);
+        let recreate = RecreateWithCheapest::new(environment.random.clone());
+
+        ruins.iter().for_each(|(ruin_name, ruin)| {
+            group.bench_with_input(BenchmarkId::new(*ruin_name, name), &insertion_ctx, |b, insertion_ctx| {
+                b.iter(|| {
+                    let insertion_ctx = ruin.run(&refinement_ctx, insertion_ctx.deep_copy());
+                    black_box(recreate.run(&refinement_ctx, insertion_ctx))
+                })
+            });
+        });
+    });
+
+    group.finish();
+}
+
+/// Runs solver with small amount of generations. It involves some non-determinism.
+fn short_solve_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("short solve");
+    group.sample_size(10);
+
+    get_problems().for_each(|(name, problem)| {
+        group.bench_with_input(BenchmarkId::new("10 generations", name), &problem, |b, problem| {
+            b.iter(|| {
+                create_default_config_builder(problem.clone(), Arc::new(Environment::default()), TelemetryMode::None)
+                    .with_max_generations(Some(10))
+                    .build()
+                    .map(|config| Solver::new(problem.clone(), config))
+                    .unwrap_or_else(|err| panic!("cannot build solver: {}", err))
+                    .solve()
+                    .unwrap_or_else(|err| panic!("cannot solve problem: {}", err))
+            })
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(15);
+    targets = construction_benchmark,
+              insertion_evaluation_benchmark,
+              ruin_recreate_benchmark,
+              short_solve_benchmark
+}
+criterion_main!(benches);
@@ -3,7 +3,7 @@
 mod analyze_test;
 
 use super::*;
-use vrp_cli::extensions::analyze::get_clusters;
+use vrp_cli::extensions::analyze::{get_clusters, get_fleet_size_recommendation};
 
 const FORMAT_ARG_NAME: &str = "FORMAT";
 const PROBLEM_ARG_NAME: &str = "PROBLEM";
@@ -11,54 +11,115 @@ const MATRIX_ARG_NAME: &str = "matrix";
 const MIN_POINTS_ARG_NAME: &str = "min-points";
 const EPSILON_ARG_NAME: &str = "epsilon";
 const OUT_RESULT_ARG_NAME: &str = "out-result";
+const MAX_VEHICLES_ARG_NAME: &str = "max-vehicles-per-type";
+const GENERATIONS_ARG_NAME: &str = "max-generations";
+const TIME_ARG_NAME: &str = "max-time";
 
 pub fn get_analyze_app() -> Command<'static> {
-    Command::new("analyze").about("Provides helper functionality to analyze problem or solution").subcommand(
-        Command::new("clusters")
-            .about("Analyzes job clusters")
-            .arg(
-                Arg::new(FORMAT_ARG_NAME)
-                    .help("Specifies input type")
-                    .required(true)
-                    .possible_values(&["pragmatic"])
-                    .index(1),
-            )
-            .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
-            .arg(
-                Arg::new(MIN_POINTS_ARG_NAME)
-                    .help("Minimum cluster size")
-                    .short('c')
-                    .default_value("3")
-                    .long(MIN_POINTS_ARG_NAME)
-                    .required(false)
-                    .takes_value(true),
-            )
-            .arg(
-                Arg::new(EPSILON_ARG_NAME)
-                    .help("Epsilon parameter in DBSCAN")
-                    .short('e')
-                    .long(EPSILON_ARG_NAME)
-                    .required(false)
-                    .takes_value(true),
-            )
-            .arg(
-                Arg::new(MATRIX_ARG_NAME)
-                    .help("Specifies path to file with routing matrix")
-                    .short('m')
-                    .long(MATRIX_ARG_NAME)
-                    .multiple_values(true)
-                    .required(false)
-                    .takes_value(true),
-            )
-            .arg(
-                Arg::new(OUT_RESULT_ARG_NAME)
-                    .help("Specifies path to the file for result output")
-                    .short('o')
-                    .long(OUT_RESULT_ARG_NAME)
-                    .required(true)
-                    .takes_value(true),
-            ),
-    )
+    Command::new("analyze")
+        .about("Provides helper functionality to analyze problem or solution")
+        .subcommand(get_clusters_app())
+        .subcommand(get_fleet_size_app())
+}
+
+fn get_clusters_app() -> Command<'static> {
+    Command::new("clusters")
+        .about("Analyzes job clusters")
+        .arg(
+            Arg::new(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(["pragmatic"])
+                .index(1),
+        )
+        .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+        .arg(
+            Arg::new(MIN_POINTS_ARG_NAME)
+                .help("Minimum cluster size")
+                .short('c')
+                .default_value("3")
+                .long(MIN_POINTS_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::new(EPSILON_ARG_NAME)
+                .help("Epsilon parameter in DBSCAN")
+                .short('e')
+                .long(EPSILON_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::new(MATRIX_ARG_NAME)
+                .help("Specifies path to file with routing matrix")
+                .short('m')
+                .long(MATRIX_ARG_NAME)
+                .multiple_values(true)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::new(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to the file for result output")
+                .short('o')
+                .long(OUT_RESULT_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+}
+
+fn get_fleet_size_app() -> Command<'static> {
+    Command::new("fleet-size")
+        .about("Recommends fleet mix for given demand treating each vehicle type as unlimited")
+        .arg(
+            Arg::new(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(["pragmatic"])
+                .index(1),
+        )
+        .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+        .arg(
+            Arg::new(MAX_VEHICLES_ARG_NAME)
+                .help("Maximum amount of vehicles per vehicle type, default is amount of jobs")
+                .long(MAX_VEHICLES_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::new(GENERATIONS_ARG_NAME)
+                .help("Specifies maximum number of generations")
+                .short('n')
+                .long(GENERATIONS_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::new(TIME_ARG_NAME)
+                .help("Specifies max time algorithm run in seconds")
+                .short('t')
+                .long(TIME_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::new(MATRIX_ARG_NAME)
+                .help("Specifies path to file with routing matrix")
+                .short('m')
+                .long(MATRIX_ARG_NAME)
+                .multiple_values(true)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::new(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to the file for result output")
+                .short('o')
+                .long(OUT_RESULT_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
 }
 
 pub fn run_analyze(
@@ -92,6 +153,41 @@ pub fn run_analyze(
 
             geo_writer.write_all(clusters.as_bytes()).map_err(|err| format!("cannot write result: '{}'", err))
         }
+        Some(("fleet-size", fleet_size_matches)) => {
+            let problem_path = fleet_size_matches.value_of(PROBLEM_ARG_NAME).unwrap();
+            let problem_format = fleet_size_matches.value_of(FORMAT_ARG_NAME).unwrap();
+
+            if problem_format != "pragmatic" {
+                return Err(format!("unknown problem format: '{}'", problem_format));
+            }
+
+            let problem_reader = BufReader::new(open_file(problem_path, "problem"));
+
+            let matrices_readers = fleet_size_matches
+                .values_of(MATRIX_ARG_NAME)
+                .map(|paths: Values| paths.map(|path| BufReader::new(open_file(path, "routing matrix"))).collect());
+
+            let max_vehicles =
+                parse_int_value::<usize>(fleet_size_matches, MAX_VEHICLES_ARG_NAME, "max vehicles per type")?;
+            let max_generations =
+                parse_int_value::<usize>(fleet_size_matches, GENERATIONS_ARG_NAME, "max generations")?;
+            let max_time = parse_int_value::<usize>(fleet_size_matches, TIME_ARG_NAME, "max time")?;
+
+            let recommendation = get_fleet_size_recommendation(
+                problem_reader,
+                matrices_readers,
+                max_vehicles,
+                max_generations,
+                max_time,
+            )
+            .map_err(|err| format!("cannot get fleet size recommendation: '{}'", err))?;
+
+            let out_result =
+                fleet_size_matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+            let mut writer = out_writer_func(out_result);
+
+            writer.write_all(recommendation.as_bytes()).map_err(|err| format!("cannot write result: '{}'", err))
+        }
         _ => Err("no argument with analyze subcommand was used. Use -h to print help information".to_string()),
     }
 }
@@ -0,0 +1,58 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/anonymize_test.rs"]
+mod anonymize_test;
+
+use super::*;
+use vrp_cli::extensions::anonymize::anonymize_pragmatic_problem;
+use vrp_core::utils::DefaultRandom;
+use vrp_pragmatic::format::problem::serialize_problem;
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const PROBLEM_ARG_NAME: &str = "problem-file";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_anonymize_app() -> Command<'static> {
+    Command::new("anonymize")
+        .about("Anonymizes problem definition: locations, ids and demands are obfuscated, but optimization structure is preserved")
+        .arg(
+            Arg::new(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::new(PROBLEM_ARG_NAME)
+                .help("Sets input file which contains a VRP definition")
+                .short('p')
+                .long(PROBLEM_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::new(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to the file for result output")
+                .short('o')
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_anonymize(
+    matches: &ArgMatches,
+    out_writer_func: fn(Option<File>) -> BufWriter<Box<dyn Write>>,
+) -> Result<(), String> {
+    let input_format = matches.value_of(FORMAT_ARG_NAME).unwrap();
+    if input_format != "pragmatic" {
+        return Err(format!("unknown format: '{}'", input_format));
+    }
+
+    let problem_reader = BufReader::new(open_file(matches.value_of(PROBLEM_ARG_NAME).unwrap(), "problem"));
+    let problem = anonymize_pragmatic_problem(problem_reader, &DefaultRandom::default())?;
+
+    let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+
+    serialize_problem(out_writer_func(out_result), &problem)
+        .map_err(|err| format!("cannot serialize as pragmatic problem: '{}'", err))
+}
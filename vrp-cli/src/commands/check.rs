@@ -8,6 +8,7 @@ const FORMAT_ARG_NAME: &str = "FORMAT";
 const PROBLEM_ARG_NAME: &str = "problem-file";
 const SOLUTION_ARG_NAME: &str = "solution-file";
 const MATRIX_ARG_NAME: &str = "matrix";
+const COVERAGE_ARG_NAME: &str = "coverage";
 
 pub fn get_check_app() -> Command<'static> {
     Command::new("check")
@@ -45,9 +46,22 @@ pub fn get_check_app() -> Command<'static> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::new(COVERAGE_ARG_NAME)
+                .help("Runs constraint and objective coverage self-test on feasible solution and prints its report")
+                .long(COVERAGE_ARG_NAME)
+                .required(false),
+        )
 }
 
 pub fn run_check(matches: &ArgMatches) -> Result<(), String> {
     let input_format = matches.value_of(FORMAT_ARG_NAME).unwrap();
-    check_solution(matches, input_format, PROBLEM_ARG_NAME, SOLUTION_ARG_NAME, MATRIX_ARG_NAME)
+    check_solution(matches, input_format, PROBLEM_ARG_NAME, SOLUTION_ARG_NAME, MATRIX_ARG_NAME)?;
+
+    if matches.is_present(COVERAGE_ARG_NAME) {
+        let report = check_coverage(matches, input_format, PROBLEM_ARG_NAME, SOLUTION_ARG_NAME, MATRIX_ARG_NAME)?;
+        print!("{}", report);
+    }
+
+    Ok(())
 }
@@ -0,0 +1,85 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/merge_test.rs"]
+mod merge_test;
+
+use super::*;
+use vrp_cli::extensions::merge::merge_pragmatic_solutions;
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const PROBLEM_ARG_NAME: &str = "problem-file";
+const SOLUTION_ARG_NAME: &str = "solution-file";
+const MATRIX_ARG_NAME: &str = "matrix";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_merge_app() -> Command<'static> {
+    Command::new("merge")
+        .about("Merges solutions of disjoint sub-problems into one solution of the original problem")
+        .arg(
+            Arg::new(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::new(PROBLEM_ARG_NAME)
+                .help("Sets input file which contains the original VRP definition")
+                .short('p')
+                .long(PROBLEM_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::new(SOLUTION_ARG_NAME)
+                .help("Sets solution files of sub-problems")
+                .short('s')
+                .long(SOLUTION_ARG_NAME)
+                .required(true)
+                .takes_value(true)
+                .multiple_values(true),
+        )
+        .arg(
+            Arg::new(MATRIX_ARG_NAME)
+                .help("Specifies path to file with routing matrix")
+                .short('m')
+                .long(MATRIX_ARG_NAME)
+                .multiple_values(true)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::new(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to the file for result output")
+                .short('o')
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_merge(
+    matches: &ArgMatches,
+    out_writer_func: fn(Option<File>) -> BufWriter<Box<dyn Write>>,
+) -> Result<(), String> {
+    let input_format = matches.value_of(FORMAT_ARG_NAME).unwrap();
+    if input_format != "pragmatic" {
+        return Err(format!("unknown format: '{}'", input_format));
+    }
+
+    let problem_reader = BufReader::new(open_file(matches.value_of(PROBLEM_ARG_NAME).unwrap(), "problem"));
+    let solution_readers = matches
+        .values_of(SOLUTION_ARG_NAME)
+        .map(|paths: Values| paths.map(|path| BufReader::new(open_file(path, "solution"))).collect())
+        .unwrap_or_default();
+    let matrices_readers = matches
+        .values_of(MATRIX_ARG_NAME)
+        .map(|paths: Values| paths.map(|path| BufReader::new(open_file(path, "routing matrix"))).collect());
+
+    let solution = merge_pragmatic_solutions(problem_reader, solution_readers, matrices_readers)
+        .map_err(|errs| format!("cannot merge solutions, found {} errors:\n{}", errs.len(), errs.join("\n")))?;
+
+    let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+    let mut writer = out_writer_func(out_result);
+
+    writer.write_all(solution.as_bytes()).map_err(|err| format!("cannot write result: '{}'", err))
+}
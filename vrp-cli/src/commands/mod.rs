@@ -1,16 +1,19 @@
 use clap::{Arg, ArgMatches, Command, Values};
 
 pub mod analyze;
+pub mod anonymize;
 pub mod check;
 pub mod generate;
 pub mod import;
+pub mod merge;
 pub mod solve;
 
 use std::fs::File;
 use std::io::{stdout, BufReader, BufWriter, Write};
 use std::process;
 use std::str::FromStr;
-use vrp_cli::extensions::check::check_pragmatic_solution;
+use vrp_cli::extensions::check::{check_pragmatic_coverage, check_pragmatic_solution};
+use vrp_core::construction::probing::CoverageReport;
 
 pub(crate) fn create_write_buffer(out_file: Option<File>) -> BufWriter<Box<dyn Write>> {
     if let Some(out_file) = out_file {
@@ -90,3 +93,30 @@ fn check_solution(
     }
     .map_err(|err| format!("checker found {} errors:\n{}", err.len(), err.join("\n")))
 }
+
+fn check_coverage(
+    matches: &ArgMatches,
+    input_format: &str,
+    problem_arg_name: &str,
+    solution_arg_name: &str,
+    matrix_arg_name: &str,
+) -> Result<CoverageReport, String> {
+    let problem_files = matches
+        .values_of(problem_arg_name)
+        .map(|paths: Values| paths.map(|path| BufReader::new(open_file(path, "problem"))).collect::<Vec<_>>());
+    let solution_file = matches.value_of(solution_arg_name).map(|path| BufReader::new(open_file(path, "solution")));
+    let matrix_files = matches
+        .values_of(matrix_arg_name)
+        .map(|paths: Values| paths.map(|path| BufReader::new(open_file(path, "routing matrix"))).collect());
+
+    match (input_format, problem_files, solution_file) {
+        ("pragmatic", Some(mut problem_files), Some(solution_file)) if problem_files.len() == 1 => {
+            check_pragmatic_coverage(problem_files.swap_remove(0), solution_file, matrix_files)
+        }
+        ("pragmatic", _, _) => {
+            Err(vec!["pragmatic format expects one problem, one solution file, and optionally matrices".to_string()])
+        }
+        _ => Err(vec![format!("unknown format: '{}'", input_format)]),
+    }
+    .map_err(|err| format!("coverage check failed with {} errors:\n{}", err.len(), err.join("\n")))
+}
@@ -8,16 +8,22 @@ use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Write};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Once};
 use vrp_cli::core::solver::TargetHeuristic;
-use vrp_cli::extensions::solve::config::create_builder_from_config_file;
+use vrp_cli::extensions::solve::config::{create_builder_from_config_with_quota, read_config, PersistenceConfig};
+use vrp_cli::pragmatic::format::solution::has_no_violations;
 use vrp_cli::scientific::tsplib::{TsplibProblem, TsplibSolution};
 use vrp_cli::{get_errors_serialized, get_locations_serialized};
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::problem::ProblemObjective;
-use vrp_core::prelude::*;
+use vrp_core::prelude::{
+    create_default_config_builder, get_default_telemetry_mode, DefaultRandom, Environment, InfoLogger, Problem, Random,
+    RefinementContext, Solution, Solver, TelemetryMode,
+};
 use vrp_core::rosomaxa::evolution::*;
-use vrp_core::rosomaxa::{get_default_population, get_default_selection_size};
+use vrp_core::rosomaxa::population::PopulationSizing;
+use vrp_core::rosomaxa::prelude::Objective;
+use vrp_core::rosomaxa::{get_adaptive_population, get_default_selection_size};
 use vrp_core::solver::*;
 use vrp_core::utils::*;
 
@@ -32,6 +38,8 @@ const GEO_JSON_ARG_NAME: &str = "geo-json";
 const INIT_SOLUTION_ARG_NAME: &str = "init-solution";
 const INIT_SIZE_ARG_NAME: &str = "init-size";
 const OUT_RESULT_ARG_NAME: &str = "out-result";
+const OUT_FEASIBLE_ARG_NAME: &str = "out-feasible";
+const PERSIST_SOLUTION_ARG_NAME: &str = "persist-solution";
 const GET_LOCATIONS_ARG_NAME: &str = "get-locations";
 const CONFIG_ARG_NAME: &str = "config";
 const LOG_ARG_NAME: &str = "log";
@@ -42,6 +50,10 @@ const HEURISTIC_ARG_NAME: &str = "heuristic";
 const EXPERIMENTAL_ARG_NAME: &str = "experimental";
 const ROUNDED_ARG_NAME: &str = "round";
 
+const INTERRUPTED_EXIT_CODE: i32 = 130;
+static SHOULD_INTERRUPT: AtomicBool = AtomicBool::new(false);
+static INTERRUPTION_HANDLER: Once = Once::new();
+
 #[allow(clippy::type_complexity)]
 struct ProblemReader(pub Box<dyn Fn(File, Option<Vec<File>>) -> Result<Problem, String>>);
 
@@ -49,15 +61,17 @@ struct InitSolutionReader(pub Box<dyn Fn(File, Arc<Problem>) -> Result<Solution,
 
 #[allow(clippy::type_complexity)]
 struct SolutionWriter(
-    pub  Box<
+    pub  Arc<
         dyn Fn(
-            &Problem,
-            Solution,
-            f64,
-            Option<TelemetryMetrics>,
-            BufWriter<Box<dyn Write>>,
-            Option<BufWriter<Box<dyn Write>>>,
-        ) -> Result<(), String>,
+                &Problem,
+                Solution,
+                f64,
+                Option<TelemetryMetrics>,
+                BufWriter<Box<dyn Write>>,
+                Option<BufWriter<Box<dyn Write>>>,
+            ) -> Result<(), String>
+            + Send
+            + Sync,
     >,
 );
 
@@ -70,10 +84,13 @@ type FormatMap<'a> = HashMap<&'a str, (ProblemReader, InitSolutionReader, Soluti
 fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn Random + Send + Sync>) {
     if cfg!(feature = "scientific-format") {
         use vrp_scientific::lilim::{LilimProblem, LilimSolution};
+        use vrp_scientific::lkh3::read_init_solution as read_init_lkh3;
+        use vrp_scientific::lkh3::{Lkh3Problem, Lkh3Solution};
         use vrp_scientific::solomon::read_init_solution as read_init_solomon;
         use vrp_scientific::solomon::{SolomonProblem, SolomonSolution};
 
         let is_rounded = matches.is_present(ROUNDED_ARG_NAME);
+        let lkh3_random = random.clone();
 
         formats.insert(
             "solomon",
@@ -85,7 +102,7 @@ fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn
                 InitSolutionReader(Box::new(move |file, problem| {
                     read_init_solomon(BufReader::new(file), problem, random.clone())
                 })),
-                SolutionWriter(Box::new(|_, solution, cost, _, writer, _| (&solution, cost).write_solomon(writer))),
+                SolutionWriter(Arc::new(|_, solution, cost, _, writer, _| (&solution, cost).write_solomon(writer))),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
             ),
         );
@@ -97,7 +114,7 @@ fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn
                     BufReader::new(problem).read_lilim(is_rounded)
                 })),
                 InitSolutionReader(Box::new(|_file, _problem| unimplemented!())),
-                SolutionWriter(Box::new(|_, solution, cost, _, writer, _| (&solution, cost).write_lilim(writer))),
+                SolutionWriter(Arc::new(|_, solution, cost, _, writer, _| (&solution, cost).write_lilim(writer))),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
             ),
         );
@@ -109,15 +126,64 @@ fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn
                     BufReader::new(problem).read_tsplib(is_rounded)
                 })),
                 InitSolutionReader(Box::new(|_file, _problem| unimplemented!())),
-                SolutionWriter(Box::new(|_, solution, cost, _, writer, _| (&solution, cost).write_tsplib(writer))),
+                SolutionWriter(Arc::new(|_, solution, cost, _, writer, _| (&solution, cost).write_tsplib(writer))),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
             ),
         );
+        formats.insert(
+            "lkh3",
+            (
+                ProblemReader(Box::new(move |problem: File, matrices: Option<Vec<File>>| {
+                    assert!(matrices.is_none());
+                    BufReader::new(problem).read_lkh3(is_rounded)
+                })),
+                InitSolutionReader(Box::new(move |file, problem| {
+                    read_init_lkh3(BufReader::new(file), problem, lkh3_random.clone())
+                })),
+                SolutionWriter(Arc::new(|_, solution, cost, _, writer, _| (&solution, cost).write_lkh3(writer))),
+                LocationWriter(Box::new(|_, _| unimplemented!())),
+            ),
+        );
+    }
+}
+
+/// Maps file into memory: with very large problems, it is much faster to parse json from memory than from reader.
+#[cfg(feature = "mmap")]
+fn map_file_content(file: File) -> Result<memmap2::Mmap, String> {
+    // NOTE the file is not expected to be modified while it is being read
+    unsafe { memmap2::Mmap::map(&file) }.map_err(|err| format!("cannot map file into memory: {}", err))
+}
+
+#[cfg(feature = "mmap")]
+fn read_pragmatic_problem(problem: File, matrices: Option<Vec<File>>) -> Result<Problem, String> {
+    use vrp_pragmatic::format::problem::PragmaticProblem;
+
+    let problem = map_file_content(problem)?;
+
+    if let Some(matrices) = matrices {
+        let matrices = matrices.into_iter().map(map_file_content).collect::<Result<Vec<_>, _>>()?;
+        (&problem[..], matrices.iter().map(|matrix| &matrix[..]).collect()).read_pragmatic()
+    } else {
+        (&problem[..]).read_pragmatic()
     }
+    .map_err(|errors| errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\t\n"))
+}
+
+#[cfg(not(feature = "mmap"))]
+fn read_pragmatic_problem(problem: File, matrices: Option<Vec<File>>) -> Result<Problem, String> {
+    use vrp_pragmatic::format::problem::PragmaticProblem;
+
+    if let Some(matrices) = matrices {
+        let matrices = matrices.into_iter().map(BufReader::new).collect();
+        (BufReader::new(problem), matrices).read_pragmatic()
+    } else {
+        BufReader::new(problem).read_pragmatic()
+    }
+    .map_err(|errors| errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\t\n"))
 }
 
 fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>) {
-    use vrp_pragmatic::format::problem::{deserialize_problem, PragmaticProblem};
+    use vrp_pragmatic::format::problem::deserialize_problem;
     use vrp_pragmatic::format::solution::read_init_solution as read_init_pragmatic;
     use vrp_pragmatic::format::solution::PragmaticSolution;
 
@@ -125,18 +191,12 @@ fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>)
         "pragmatic",
         (
             ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
-                if let Some(matrices) = matrices {
-                    let matrices = matrices.into_iter().map(BufReader::new).collect();
-                    (BufReader::new(problem), matrices).read_pragmatic()
-                } else {
-                    BufReader::new(problem).read_pragmatic()
-                }
-                .map_err(|errors| errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\t\n"))
+                read_pragmatic_problem(problem, matrices)
             })),
             InitSolutionReader(Box::new(move |file, problem| {
                 read_init_pragmatic(BufReader::new(file), problem, random.clone())
             })),
-            SolutionWriter(Box::new(|problem, solution, cost, metrics, default_writer, geojson_writer| {
+            SolutionWriter(Arc::new(|problem, solution, cost, metrics, default_writer, geojson_writer| {
                 geojson_writer
                     .map_or(Ok(()), |geojson_writer| (&solution, cost).write_geo_json(problem, geojson_writer))
                     .and_then(|_| {
@@ -174,7 +234,7 @@ pub fn get_solve_app() -> Command<'static> {
             Arg::new(FORMAT_ARG_NAME)
                 .help("Specifies the problem type")
                 .required(true)
-                .possible_values(&["solomon", "lilim", "tsplib", "pragmatic"])
+                .possible_values(["solomon", "lilim", "tsplib", "lkh3", "pragmatic"])
                 .index(1),
         )
         .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
@@ -236,6 +296,20 @@ pub fn get_solve_app() -> Command<'static> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::new(OUT_FEASIBLE_ARG_NAME)
+                .help("Specifies path to file for the best known solution without violations, e.g. skipped breaks")
+                .long(OUT_FEASIBLE_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::new(PERSIST_SOLUTION_ARG_NAME)
+                .help("Specifies path to file where the best known solution is written on each improvement during search")
+                .long(PERSIST_SOLUTION_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
         .arg(
             Arg::new(GET_LOCATIONS_ARG_NAME)
                 .help("Returns list of unique locations")
@@ -347,6 +421,12 @@ pub fn run_solve(
     let matrix_files = get_matrix_files(matches);
     let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out solution"));
     let out_geojson = matches.value_of(GEO_JSON_ARG_NAME).map(|path| create_file(path, "out geojson"));
+    let out_feasible = matches.value_of(OUT_FEASIBLE_ARG_NAME).map(|path| create_file(path, "out feasible solution"));
+    let persist_solution = matches.value_of(PERSIST_SOLUTION_ARG_NAME).map(|path| PersistenceConfig {
+        path: path.to_string(),
+        interval: None,
+        improvements: None,
+    });
     let is_get_locations_set = matches.is_present(GET_LOCATIONS_ARG_NAME);
     let mode = matches.value_of(SEARCH_MODE_ARG_NAME);
 
@@ -375,13 +455,21 @@ pub fn run_solve(
                             })
                             .unwrap_or_else(|| Ok(Vec::new()))?;
 
-                        let solver = if let Some(config) = config {
-                            create_builder_from_config_file(problem.clone(), solutions, BufReader::new(config))
-                                .and_then(|builder| builder.build())
-                                .map(|config| Solver::new(problem.clone(), config))
-                                .map_err(|err| format!("cannot read config: '{}'", err))?
+                        let (mut solver_config, persistence) = if let Some(config) = config {
+                            let config = read_config(BufReader::new(config))
+                                .map_err(|err| format!("cannot read config: '{}'", err))?;
+                            let persistence = config.telemetry.as_ref().and_then(|t| t.persistence.clone());
+
+                            let quota = create_interruption_quota(config.termination.as_ref().and_then(|t| t.max_time));
+
+                            let solver_config =
+                                create_builder_from_config_with_quota(problem.clone(), solutions, &config, quota)
+                                    .and_then(|builder| builder.build())
+                                    .map_err(|err| format!("cannot read config: '{}'", err))?;
+
+                            (solver_config, persistence)
                         } else {
-                            let config = create_default_config_builder(
+                            let solver_config = create_default_config_builder(
                                 problem.clone(),
                                 environment.clone(),
                                 telemetry_mode.clone(),
@@ -392,21 +480,46 @@ pub fn run_solve(
                             .with_min_cv(min_cv, "min_cv".to_string())
                             .with_context(RefinementContext::new(
                                 problem.clone(),
-                                get_population(mode, problem.objective.clone(), environment.clone()),
+                                get_population(
+                                    mode,
+                                    problem.objective.clone(),
+                                    problem.jobs.size(),
+                                    environment.clone(),
+                                ),
                                 telemetry_mode,
                                 environment.clone(),
                             ))
                             .with_heuristic(get_heuristic(matches, problem.clone(), environment)?)
                             .build()?;
 
-                            Solver::new(problem.clone(), config)
+                            (solver_config, None)
                         };
 
-                        let (solution, cost, metrics) =
-                            solver.solve().map_err(|err| format!("cannot find any solution: '{}'", err))?;
+                        // NOTE command line argument takes precedence over config file
+                        if let Some(persistence) = persist_solution.or(persistence) {
+                            let persistence =
+                                create_solution_persistence(problem.clone(), &persistence, solution_writer);
+                            solver_config.context = solver_config.context.with_persistence(persistence);
+                        }
+
+                        if out_feasible.is_some() {
+                            solver_config.context =
+                                solver_config.context.with_feasibility_check(Arc::new(has_no_violations));
+                        }
+
+                        let (solution, cost, feasible, metrics) = Solver::new(problem.clone(), solver_config)
+                            .solve_with_feasible()
+                            .map_err(|err| format!("cannot find any solution: '{}'", err))?;
 
                         solution_writer.0(&problem, solution, cost, metrics, out_buffer, geo_buffer).unwrap();
 
+                        if let Some(out_feasible) = out_feasible {
+                            let (solution, cost) =
+                                feasible.ok_or_else(|| "cannot find any solution without violations".to_string())?;
+                            let out_buffer = create_write_buffer(Some(out_feasible));
+                            solution_writer.0(&problem, solution, cost, None, out_buffer, None)?;
+                        }
+
                         if is_check_requested {
                             check_pragmatic_solution_with_args(matches)?;
                             println!("solution feasibility check is completed successfully");
@@ -497,13 +610,14 @@ fn get_matrix_files(matches: &ArgMatches) -> Option<Vec<File>> {
 fn get_population(
     mode: Option<&str>,
     objective: Arc<ProblemObjective>,
+    problem_size: usize,
     environment: Arc<Environment>,
 ) -> TargetPopulation {
     let selection_size = get_default_selection_size(environment.as_ref());
 
     match mode {
         Some("deep") => Box::new(ElitismPopulation::new(objective, environment.random.clone(), 4, selection_size)),
-        _ => get_default_population(objective, environment, selection_size),
+        _ => get_adaptive_population(objective, environment, selection_size, problem_size, PopulationSizing::default()),
     }
 }
 
@@ -520,34 +634,66 @@ fn get_heuristic(
     }
 }
 
+fn create_solution_persistence(
+    problem: Arc<Problem>,
+    config: &PersistenceConfig,
+    solution_writer: &SolutionWriter,
+) -> SolutionPersistence {
+    let path = config.path.clone();
+    let solution_writer = solution_writer.0.clone();
+    let policy = PersistencePolicy { interval: config.interval, improvements: config.improvements };
+
+    SolutionPersistence::new(
+        policy,
+        Arc::new(move |insertion_ctx: &InsertionContext| {
+            let solution = insertion_ctx.solution.to_solution(problem.extras.clone());
+            let cost = problem.objective.fitness(insertion_ctx);
+
+            // NOTE write to temporary file first and then rename it to keep the target file consistent
+            let tmp_path = format!("{}.tmp", path);
+            let file = File::create(tmp_path.as_str())
+                .map_err(|err| format!("cannot create file '{}': '{}'", tmp_path, err))?;
+            solution_writer(problem.as_ref(), solution, cost, None, create_write_buffer(Some(file)), None)?;
+
+            std::fs::rename(tmp_path.as_str(), path.as_str())
+                .map_err(|err| format!("cannot replace file '{}': '{}'", path, err))
+        }),
+    )
+}
+
 fn check_pragmatic_solution_with_args(matches: &ArgMatches) -> Result<(), String> {
     check_solution(matches, "pragmatic", PROBLEM_ARG_NAME, OUT_RESULT_ARG_NAME, MATRIX_ARG_NAME)
 }
 
-/// Creates interruption quota.
+/// Creates interruption quota which is reached when max time is elapsed or user interrupts the process,
+/// e.g. by pressing Ctrl+C. The first interruption stops the search gracefully, so the best known solution
+/// is written, the second one terminates the process immediately.
 pub fn create_interruption_quota(max_time: Option<usize>) -> Arc<dyn Quota + Send + Sync> {
     struct InterruptionQuota {
         inner: Option<Arc<dyn Quota + Send + Sync>>,
-        should_interrupt: Arc<AtomicBool>,
     }
 
     impl Quota for InterruptionQuota {
         fn is_reached(&self) -> bool {
-            self.inner.as_ref().map_or(false, |inner| inner.is_reached())
-                || self.should_interrupt.load(Ordering::Relaxed)
+            self.inner.as_ref().is_some_and(|inner| inner.is_reached()) || SHOULD_INTERRUPT.load(Ordering::Relaxed)
         }
     }
 
-    let inner = max_time.map::<Arc<dyn Quota + Send + Sync>, _>(|time| Arc::new(TimeQuota::new(time as f64)));
-    let should_interrupt = Arc::new(AtomicBool::new(false));
+    // NOTE handler can be set only once per process, so it is shared by all quotas
+    INTERRUPTION_HANDLER.call_once(|| {
+        // NOTE ignore error which happens in unit tests
+        let _ = ctrlc::set_handler(|| {
+            if SHOULD_INTERRUPT.swap(true, Ordering::Relaxed) {
+                std::process::exit(INTERRUPTED_EXIT_CODE);
+            }
 
-    // NOTE ignore error which happens in unit tests
-    let _ = ctrlc::set_handler({
-        let should_interrupt = should_interrupt.clone();
-        move || {
-            should_interrupt.store(true, Ordering::Relaxed);
-        }
+            eprintln!(
+                "interrupted: stopping search and writing the best known solution, interrupt again to exit immediately"
+            );
+        });
     });
 
-    Arc::new(InterruptionQuota { inner, should_interrupt })
+    let inner = max_time.map::<Arc<dyn Quota + Send + Sync>, _>(|time| Arc::new(TimeQuota::new(time as f64)));
+
+    Arc::new(InterruptionQuota { inner })
 }
@@ -0,0 +1,138 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/fleet_size_test.rs"]
+mod fleet_size_test;
+
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::io::{BufReader, BufWriter, Read};
+use std::sync::Arc;
+use vrp_core::prelude::{create_default_config_builder, Environment, Solution, Solver, TelemetryMode};
+use vrp_pragmatic::format::entities::VehicleTie;
+use vrp_pragmatic::format::problem::{
+    deserialize_matrix, deserialize_problem, PragmaticProblem, Problem as ApiProblem,
+};
+use vrp_pragmatic::format::FormatError;
+
+/// Specifies recommended amount of vehicles of specific type.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleTypeRecommendation {
+    /// A vehicle type id.
+    pub type_id: String,
+    /// A recommended amount of vehicles.
+    pub amount: usize,
+    /// An acquisition cost of one vehicle.
+    pub acquisition_cost: f64,
+}
+
+/// Specifies fleet sizing analysis result.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FleetSizeRecommendation {
+    /// Recommended fleet mix.
+    pub vehicle_types: Vec<VehicleTypeRecommendation>,
+    /// Total amount of recommended vehicles.
+    pub total_vehicles: usize,
+    /// Total acquisition cost of recommended vehicles.
+    pub total_acquisition_cost: f64,
+    /// Total cost of found solution.
+    pub cost: f64,
+    /// Amount of jobs which cannot be served even with elastic fleet.
+    pub unassigned_jobs: usize,
+}
+
+/// Recommends fleet mix for the given demand. The fleet is treated as elastic: each vehicle type gets
+/// enough copies to serve all jobs, so the solver decides how many vehicles of each type to acquire.
+/// Acquisition cost of a vehicle is specified by the fixed cost of its type.
+pub fn get_fleet_size_recommendation<F: Read>(
+    problem_reader: BufReader<F>,
+    matrices_readers: Option<Vec<BufReader<F>>>,
+    max_vehicles_per_type: Option<usize>,
+    max_generations: Option<usize>,
+    max_time: Option<usize>,
+) -> Result<String, String> {
+    let format_errors = |errs: Vec<FormatError>| FormatError::format_many(&errs, ",");
+
+    let api_problem =
+        create_elastic_problem(deserialize_problem(problem_reader).map_err(format_errors)?, max_vehicles_per_type);
+
+    let matrices = matrices_readers
+        .map(|matrices| {
+            matrices.into_iter().map(|file| deserialize_matrix(BufReader::new(file))).collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+        .map_err(format_errors)?;
+
+    let problem = Arc::new((api_problem.clone(), matrices).read_pragmatic().map_err(format_errors)?);
+
+    let environment = Arc::new(Environment::default());
+    let (solution, cost, _) = create_default_config_builder(problem.clone(), environment, TelemetryMode::None)
+        .with_max_generations(max_generations)
+        .with_max_time(max_time)
+        .build()
+        .map(|config| Solver::new(problem, config))
+        .and_then(|solver| solver.solve())
+        .map_err(|err| format!("cannot solve the problem: '{}'", err))?;
+
+    let recommendation = create_recommendation(&api_problem, &solution, cost);
+
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+    serde_json::to_writer_pretty(writer, &recommendation).map_err(|err| err.to_string())?;
+
+    Ok(buffer)
+}
+
+/// Adds vehicle copies to each vehicle type, keeping original vehicle ids as they can be used in relations.
+fn create_elastic_problem(problem: ApiProblem, max_vehicles_per_type: Option<usize>) -> ApiProblem {
+    let amount = max_vehicles_per_type.unwrap_or(problem.plan.jobs.len()).max(1);
+
+    let mut problem = problem;
+    problem.fleet.vehicles.iter_mut().for_each(|vehicle| {
+        let existing = vehicle.vehicle_ids.iter().cloned().collect::<HashSet<_>>();
+        let new_ids = (1..)
+            .map(|idx| format!("{}_{}", vehicle.type_id, idx))
+            .filter(|id| !existing.contains(id))
+            .take(amount.saturating_sub(vehicle.vehicle_ids.len()))
+            .collect::<Vec<_>>();
+
+        vehicle.vehicle_ids.extend(new_ids);
+    });
+
+    problem
+}
+
+fn create_recommendation(api_problem: &ApiProblem, solution: &Solution, cost: f64) -> FleetSizeRecommendation {
+    let used_vehicles = solution.routes.iter().filter(|route| route.tour.has_jobs()).fold(
+        HashMap::<String, HashSet<String>>::new(),
+        |mut acc, route| {
+            let dimens = &route.actor.vehicle.dimens;
+            if let (Some(type_id), Some(vehicle_id)) = (dimens.get_vehicle_type(), dimens.get_vehicle_id()) {
+                acc.entry(type_id.clone()).or_default().insert(vehicle_id.clone());
+            }
+            acc
+        },
+    );
+
+    let vehicle_types = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .map(|vehicle| VehicleTypeRecommendation {
+            type_id: vehicle.type_id.clone(),
+            amount: used_vehicles.get(&vehicle.type_id).map_or(0, |ids| ids.len()),
+            acquisition_cost: vehicle.costs.fixed.unwrap_or(0.),
+        })
+        .collect::<Vec<_>>();
+
+    FleetSizeRecommendation {
+        total_vehicles: vehicle_types.iter().map(|vehicle_type| vehicle_type.amount).sum(),
+        total_acquisition_cost: vehicle_types
+            .iter()
+            .map(|vehicle_type| vehicle_type.amount as f64 * vehicle_type.acquisition_cost)
+            .sum(),
+        vehicle_types,
+        cost,
+        unassigned_jobs: solution.unassigned.len(),
+    }
+}
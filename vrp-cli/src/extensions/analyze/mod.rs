@@ -2,3 +2,6 @@
 
 mod clusters;
 pub use self::clusters::get_clusters;
+
+mod fleet_size;
+pub use self::fleet_size::*;
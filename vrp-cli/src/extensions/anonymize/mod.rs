@@ -0,0 +1,299 @@
+//! A helper module which contains functionality to anonymize problem definition, so it can be shared
+//! without exposing sensitive data, e.g. as a reproducible bug report.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/anonymize/anonymize_test.rs"]
+mod anonymize_test;
+
+use std::collections::HashMap;
+use std::io::{BufReader, Read};
+use vrp_core::utils::Random;
+use vrp_pragmatic::format::problem::*;
+use vrp_pragmatic::format::{FormatError, Location};
+
+/// Reads pragmatic problem and returns its anonymized version.
+pub fn anonymize_pragmatic_problem<F: Read>(
+    problem_reader: BufReader<F>,
+    random: &(dyn Random + Send + Sync),
+) -> Result<Problem, String> {
+    let problem = deserialize_problem(problem_reader)
+        .map_err(|errs| format!("cannot read problem: '{}'", FormatError::format_many(&errs, ",")))?;
+
+    Ok(anonymize_problem(problem, random))
+}
+
+/// Anonymizes problem while preserving its optimization structure:
+/// * geocoordinates are re-projected to a random place on the globe with random rotation, so relative
+///   distances between locations are kept approximately the same
+/// * ids, skills, tags and other user defined names are replaced consistently with generated ones
+/// * demands and capacities are multiplied by the same random factor
+///
+/// Routing matrices, time windows and costs are kept as is, so the same matrices can be used with
+/// anonymized problem.
+pub fn anonymize_problem(mut problem: Problem, random: &(dyn Random + Send + Sync)) -> Problem {
+    let mut coordinates = Vec::new();
+    visit_locations(&mut problem, &mut |location| {
+        if let Location::Coordinate { lat, lng } = location {
+            coordinates.push((*lat, *lng));
+        }
+    });
+
+    let projection = Projection::new(coordinates.as_slice(), random);
+    visit_locations(&mut problem, &mut |location| {
+        if let Location::Coordinate { lat, lng } = location {
+            let (new_lat, new_lng) = projection.project(*lat, *lng);
+            *lat = new_lat;
+            *lng = new_lng;
+        }
+    });
+
+    scale_demand(&mut problem, random.uniform_int(2, 10));
+    rename_ids(&mut problem);
+
+    problem
+}
+
+/// Re-projects coordinates using local equirectangular approximation around their centroid.
+struct Projection {
+    origin: (f64, f64),
+    target: (f64, f64),
+    angle: f64,
+}
+
+impl Projection {
+    fn new(coordinates: &[(f64, f64)], random: &(dyn Random + Send + Sync)) -> Self {
+        let size = coordinates.len().max(1) as f64;
+        let (lat, lng) =
+            coordinates.iter().fold((0., 0.), |(lat, lng), coordinate| (lat + coordinate.0, lng + coordinate.1));
+
+        Self {
+            origin: (lat / size, lng / size),
+            target: (random.uniform_real(-45., 45.), random.uniform_real(-135., 135.)),
+            angle: random.uniform_real(0., 2. * std::f64::consts::PI),
+        }
+    }
+
+    fn project(&self, lat: f64, lng: f64) -> (f64, f64) {
+        let x = (lng - self.origin.1) * self.origin.0.to_radians().cos();
+        let y = lat - self.origin.0;
+
+        let (sin, cos) = self.angle.sin_cos();
+        let (x, y) = (x * cos - y * sin, x * sin + y * cos);
+
+        let lat = (self.target.0 + y).clamp(-89.9, 89.9);
+        let lng = self.target.1 + x / lat.to_radians().cos();
+
+        (lat, (lng + 180.).rem_euclid(360.) - 180.)
+    }
+}
+
+/// Generates new names keeping the same name for the same original value.
+struct NameMapper {
+    prefix: &'static str,
+    names: HashMap<String, String>,
+}
+
+impl NameMapper {
+    fn new(prefix: &'static str) -> Self {
+        Self { prefix, names: HashMap::default() }
+    }
+
+    fn map(&mut self, name: &mut String) {
+        let idx = self.names.len() + 1;
+        let new_name = self.names.entry(name.clone()).or_insert_with(|| format!("{}{}", self.prefix, idx)).clone();
+
+        *name = new_name;
+    }
+
+    fn map_all<'a>(&mut self, names: impl Iterator<Item = &'a mut String>) {
+        names.for_each(|name| self.map(name))
+    }
+}
+
+fn visit_locations(problem: &mut Problem, visitor: &mut dyn FnMut(&mut Location)) {
+    problem.plan.jobs.iter_mut().flat_map(get_job_places).for_each(|place| visitor(&mut place.location));
+
+    problem.fleet.vehicles.iter_mut().flat_map(|vehicle| vehicle.shifts.iter_mut()).for_each(|shift| {
+        visitor(&mut shift.start.location);
+
+        if let Some(end) = shift.end.as_mut() {
+            visitor(&mut end.location);
+        }
+
+        shift.depots.iter_mut().flatten().for_each(&mut *visitor);
+        shift.dispatch.iter_mut().flatten().for_each(|dispatch| visitor(&mut dispatch.location));
+        shift.reloads.iter_mut().flatten().for_each(|reload| {
+            visitor(&mut reload.location);
+            reload.alternatives.iter_mut().flatten().for_each(&mut *visitor);
+        });
+        shift.recharges.iter_mut().flatten().for_each(|recharge| visitor(&mut recharge.location));
+        shift.trailer_parkings.iter_mut().flatten().for_each(|parking| visitor(&mut parking.location));
+        shift.relays.iter_mut().flatten().for_each(|relay| visitor(&mut relay.location));
+        shift
+            .breaks
+            .iter_mut()
+            .flatten()
+            .filter_map(|vehicle_break| match vehicle_break {
+                VehicleBreak::Optional { places, .. } => Some(places),
+                VehicleBreak::Required { .. } => None,
+            })
+            .flat_map(|places| places.iter_mut())
+            .filter_map(|place| place.location.as_mut())
+            .for_each(&mut *visitor);
+    });
+}
+
+fn scale_demand(problem: &mut Problem, factor: i32) {
+    let scale = |values: &mut Vec<i32>| values.iter_mut().for_each(|value| *value *= factor);
+
+    problem.plan.jobs.iter_mut().flat_map(get_job_tasks).for_each(|task| {
+        task.demand.iter_mut().for_each(scale);
+        task.places.iter_mut().for_each(|place| {
+            place.duration_per_unit = place.duration_per_unit.map(|duration| duration / factor as f64);
+        });
+    });
+
+    problem.fleet.vehicles.iter_mut().for_each(|vehicle| {
+        scale(&mut vehicle.capacity);
+        vehicle.compartments.iter_mut().flatten().for_each(|compartment| scale(&mut compartment.capacity));
+        vehicle.trailer.iter_mut().for_each(|trailer| scale(&mut trailer.capacity));
+        vehicle.shifts.iter_mut().flat_map(|shift| shift.reloads.iter_mut().flatten()).for_each(|reload| {
+            reload.capacity.iter_mut().for_each(scale);
+        });
+        vehicle.energy.iter_mut().for_each(|energy| {
+            energy.load_consumption = energy.load_consumption.map(|consumption| consumption / factor as f64);
+        });
+    });
+
+    problem.fleet.resources.iter_mut().flatten().for_each(|resource| match resource {
+        VehicleResource::Reload { capacity, .. } => scale(capacity),
+        VehicleResource::Dock { .. } => {}
+    });
+}
+
+fn rename_ids(problem: &mut Problem) {
+    let mut job_ids = NameMapper::new("job");
+    let mut type_ids = NameMapper::new("vehicle_type");
+    let mut vehicle_ids = NameMapper::new("vehicle");
+    let mut skills = NameMapper::new("skill");
+    let mut groups = NameMapper::new("group");
+    let mut compatibilities = NameMapper::new("compatibility");
+    let mut classes = NameMapper::new("class");
+    let mut compartments = NameMapper::new("compartment");
+    let mut areas = NameMapper::new("area");
+    let mut resources = NameMapper::new("resource");
+    let mut tags = NameMapper::new("tag");
+    let mut sync_keys = NameMapper::new("sync");
+    let mut territories = NameMapper::new("territory");
+
+    problem.plan.jobs.iter_mut().for_each(|job| {
+        job_ids.map(&mut job.id);
+        job.group.iter_mut().for_each(|group| groups.map(group));
+        job.compatibility.iter_mut().for_each(|compatibility| compatibilities.map(compatibility));
+        job.product_class.iter_mut().for_each(|class| classes.map(class));
+        job.sync.iter_mut().for_each(|sync| sync_keys.map(&mut sync.key));
+        job_ids.map_all(job.predecessors.iter_mut().flatten());
+
+        if let Some(job_skills) = job.skills.as_mut() {
+            skills.map_all(job_skills.all_of.iter_mut().flatten());
+            skills.map_all(job_skills.one_of.iter_mut().flatten());
+            skills.map_all(job_skills.none_of.iter_mut().flatten());
+            skills.map_all(job_skills.preferred.iter_mut().flatten().map(|preferred| &mut preferred.skill));
+            skills.map_all(job_skills.counts.iter_mut().flatten().map(|count| &mut count.skill));
+        }
+
+        tags.map_all(get_job_places(job).filter_map(|place| place.tag.as_mut()));
+    });
+
+    problem.fleet.vehicles.iter_mut().for_each(|vehicle| {
+        type_ids.map(&mut vehicle.type_id);
+        vehicle_ids.map_all(vehicle.vehicle_ids.iter_mut());
+        skills.map_all(vehicle.skills.iter_mut().flatten());
+        skills.map_all(vehicle.skill_counts.iter_mut().flatten().map(|count| &mut count.skill));
+
+        vehicle.compartments.iter_mut().flatten().for_each(|compartment| {
+            compartments.map(&mut compartment.id);
+            classes.map_all(compartment.classes.iter_mut());
+        });
+
+        areas.map_all(
+            vehicle
+                .limits
+                .iter_mut()
+                .flat_map(|limits| limits.areas.iter_mut())
+                .flatten()
+                .flat_map(|area_limits| area_limits.iter_mut().map(|area_limit| &mut area_limit.area_id)),
+        );
+
+        vehicle.shifts.iter_mut().for_each(|shift| {
+            tags.map_all(shift.dispatch.iter_mut().flatten().filter_map(|dispatch| dispatch.tag.as_mut()));
+            tags.map_all(shift.recharges.iter_mut().flatten().filter_map(|recharge| recharge.tag.as_mut()));
+            tags.map_all(shift.trailer_parkings.iter_mut().flatten().filter_map(|parking| parking.tag.as_mut()));
+            tags.map_all(shift.relays.iter_mut().flatten().filter_map(|relay| relay.tag.as_mut()));
+            shift.reloads.iter_mut().flatten().for_each(|reload| {
+                reload.tag.iter_mut().for_each(|tag| tags.map(tag));
+                reload.resource_id.iter_mut().for_each(|resource_id| resources.map(resource_id));
+                reload.dock_id.iter_mut().for_each(|dock_id| resources.map(dock_id));
+            });
+            tags.map_all(
+                shift
+                    .breaks
+                    .iter_mut()
+                    .flatten()
+                    .filter_map(|vehicle_break| match vehicle_break {
+                        VehicleBreak::Optional { places, .. } => Some(places),
+                        VehicleBreak::Required { .. } => None,
+                    })
+                    .flat_map(|places| places.iter_mut())
+                    .filter_map(|place| place.tag.as_mut()),
+            );
+        });
+    });
+
+    problem.fleet.resources.iter_mut().flatten().for_each(|resource| match resource {
+        VehicleResource::Reload { id, .. } | VehicleResource::Dock { id, .. } => resources.map(id),
+    });
+
+    problem.plan.relations.iter_mut().flatten().for_each(|relation| {
+        vehicle_ids.map(&mut relation.vehicle_id);
+        job_ids.map_all(relation.jobs.iter_mut().filter(|job_id| !is_reserved_job_id(job_id)));
+    });
+
+    problem.plan.areas.iter_mut().flatten().for_each(|area| {
+        areas.map(&mut area.id);
+        job_ids.map_all(area.jobs.iter_mut());
+    });
+
+    problem.plan.blackouts.iter_mut().flatten().for_each(|blackout| areas.map_all(blackout.areas.iter_mut().flatten()));
+
+    problem.plan.territories.iter_mut().flatten().for_each(|territory| {
+        territories.map(&mut territory.id);
+        areas.map_all(territory.areas.iter_mut());
+        vehicle_ids.map_all(territory.vehicles.iter_mut());
+    });
+
+    if let Some(
+        Clustering::Vicinity { filtering: Some(filtering), .. }
+        | Clustering::TimeWindow { filtering: Some(filtering), .. },
+    ) = problem.plan.clustering.as_mut()
+    {
+        job_ids.map_all(filtering.exclude_job_ids.iter_mut());
+    }
+}
+
+fn get_job_tasks(job: &mut Job) -> impl Iterator<Item = &mut JobTask> {
+    job.pickups
+        .iter_mut()
+        .chain(job.deliveries.iter_mut())
+        .chain(job.replacements.iter_mut())
+        .chain(job.services.iter_mut())
+        .flatten()
+}
+
+fn get_job_places(job: &mut Job) -> impl Iterator<Item = &mut JobPlace> {
+    get_job_tasks(job).flat_map(|task| task.places.iter_mut())
+}
+
+fn is_reserved_job_id(job_id: &str) -> bool {
+    matches!(job_id, "departure" | "arrival" | "break" | "dispatch" | "reload" | "recharge" | "relay")
+}
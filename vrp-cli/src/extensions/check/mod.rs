@@ -5,11 +5,15 @@
 mod check_test;
 
 use vrp_pragmatic::checker::CheckerContext;
-use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, PragmaticProblem};
-use vrp_pragmatic::format::solution::deserialize_solution;
+use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, Matrix, PragmaticProblem, Problem};
+use vrp_pragmatic::format::solution::{deserialize_solution, read_init_solution};
 
 use std::io::{BufReader, Read};
 use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::construction::probing::{check_coverage, CoverageReport};
+use vrp_core::models::Problem as CoreProblem;
+use vrp_core::prelude::Environment;
 use vrp_pragmatic::format::FormatError;
 
 /// Checks pragmatic solution feasibility.
@@ -18,12 +22,41 @@ pub fn check_pragmatic_solution<F: Read>(
     solution_reader: BufReader<F>,
     matrices_readers: Option<Vec<BufReader<F>>>,
 ) -> Result<(), Vec<String>> {
-    let problem = deserialize_problem(problem_reader)
-        .map_err(|errs| vec![format!("cannot read problem: '{}'", FormatError::format_many(&errs, ","))])?;
+    let (problem, matrices, core_problem) = read_problem(problem_reader, matrices_readers)?;
 
     let solution =
         deserialize_solution(solution_reader).map_err(|err| vec![format!("cannot read solution: '{}'", err)])?;
 
+    CheckerContext::new(core_problem, problem, matrices, solution).and_then(|ctx| ctx.check())
+}
+
+/// Runs a coverage self-test on pragmatic solution: checks whether each hard constraint rejects
+/// violations and each objective responds to changes made to the solution.
+pub fn check_pragmatic_coverage<F: Read>(
+    problem_reader: BufReader<F>,
+    solution_reader: BufReader<F>,
+    matrices_readers: Option<Vec<BufReader<F>>>,
+) -> Result<CoverageReport, Vec<String>> {
+    let (_, _, core_problem) = read_problem(problem_reader, matrices_readers)?;
+
+    let environment = Arc::new(Environment::default());
+    let solution = read_init_solution(solution_reader, core_problem.clone(), environment.random.clone())
+        .map_err(|err| vec![format!("cannot read solution: '{}'", err)])?;
+
+    let insertion_ctx = InsertionContext::new_from_solution(core_problem, (solution, None), environment);
+
+    Ok(check_coverage(&insertion_ctx))
+}
+
+type ProblemData = (Problem, Option<Vec<Matrix>>, Arc<CoreProblem>);
+
+fn read_problem<F: Read>(
+    problem_reader: BufReader<F>,
+    matrices_readers: Option<Vec<BufReader<F>>>,
+) -> Result<ProblemData, Vec<String>> {
+    let problem = deserialize_problem(problem_reader)
+        .map_err(|errs| vec![format!("cannot read problem: '{}'", FormatError::format_many(&errs, ","))])?;
+
     let matrices = if let Some(matrices_readers) = matrices_readers {
         Some(
             matrices_readers
@@ -44,5 +77,5 @@ pub fn check_pragmatic_solution<F: Read>(
             .map_err(|err| vec![format!("cannot read pragmatic problem: {}", FormatError::format_many(&err, ","))])?,
     );
 
-    CheckerContext::new(core_problem, problem, matrices, solution).and_then(|ctx| ctx.check())
+    Ok((problem, matrices, core_problem))
 }
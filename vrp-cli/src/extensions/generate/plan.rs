@@ -37,6 +37,10 @@ pub(crate) fn generate_plan(
                             duration: get_random_item(durations.as_slice(), &rnd).cloned().unwrap(),
                             times: get_random_item(time_windows.as_slice(), &rnd).cloned(),
                             tag: place.tag.clone(),
+                            duration_per_unit: None,
+                            lateness_penalty: place.lateness_penalty,
+                            post_service_buffer: place.post_service_buffer,
+                            recurring_times: None,
                         })
                         .collect(),
                     demand: if keep_original_demand {
@@ -45,6 +49,7 @@ pub(crate) fn generate_plan(
                         get_random_item(demands.as_slice(), &rnd).cloned()
                     },
                     order: task.order,
+                    named_demand: None,
                 })
                 .collect::<Vec<_>>()
         })
@@ -68,11 +73,37 @@ pub(crate) fn generate_plan(
                 value: job_proto.value,
                 group: job_proto.group.clone(),
                 compatibility: job_proto.compatibility.clone(),
+                reservation: job_proto.reservation,
+                visit_gap: job_proto.visit_gap.clone(),
+                product_class: job_proto.product_class.clone(),
+                placement: job_proto.placement,
+                truck_only: job_proto.truck_only,
+                max_ride_duration: job_proto.max_ride_duration,
+                sync: None,
+                predecessors: None,
+                approach: job_proto.approach.clone(),
+                attempts: None,
+                item: None,
+                partition: None,
+                unassigned_penalty: None,
+                bundle: None,
             }
         })
         .collect();
 
-    Ok(Plan { jobs, relations: None, areas: None, clustering: None })
+    Ok(Plan {
+        jobs,
+        relations: None,
+        areas: None,
+        clustering: None,
+        blackouts: None,
+        territories: None,
+        escalation: None,
+        marginal_costs: None,
+        zone_sequences: None,
+        eta_confidence: None,
+        certificates: None,
+    })
 }
 
 type LocationFn = Box<dyn Fn(&DefaultRandom) -> Location>;
@@ -71,9 +71,14 @@ mod actual {
                 duration: job.duration as f64 * 60.,
                 times: parse_tw(job.tw_start.clone(), job.tw_end.clone()).map(|tw| vec![tw]),
                 tag: None,
+                duration_per_unit: None,
+                lateness_penalty: None,
+                post_service_buffer: None,
+                recurring_times: None,
             }],
             demand: if job.demand != 0 { Some(vec![job.demand.abs()]) } else { None },
             order: None,
+            named_demand: None,
         };
 
         let get_tasks = |jobs: &Vec<&CsvJob>, filter: Box<dyn Fn(&CsvJob) -> bool>| {
@@ -102,6 +107,20 @@ mod actual {
                 value: None,
                 group: None,
                 compatibility: None,
+                reservation: None,
+                visit_gap: None,
+                product_class: None,
+                placement: None,
+                truck_only: None,
+                max_ride_duration: None,
+                sync: None,
+                predecessors: None,
+                approach: None,
+                attempts: None,
+                item: None,
+                partition: None,
+                unassigned_penalty: None,
+                bundle: None,
             })
             .collect();
 
@@ -118,21 +137,44 @@ mod actual {
                     type_id: vehicle.id.clone(),
                     vehicle_ids: (1..=vehicle.amount).map(|seq| format!("{}_{}", vehicle.profile, seq)).collect(),
                     profile: VehicleProfile { matrix: vehicle.profile, scale: None },
-                    costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005 },
+                    costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005, waiting: None },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
                             earliest: vehicle.tw_start,
                             latest: None,
                             location: depot_location.clone(),
                         },
-                        end: Some(ShiftEnd { earliest: None, latest: vehicle.tw_end, location: depot_location }),
+                        end: Some(ShiftEnd {
+                            earliest: None,
+                            latest: vehicle.tw_end,
+                            location: depot_location,
+                            is_open: None,
+                        }),
                         dispatch: None,
                         breaks: None,
                         reloads: None,
+                        recharges: None,
+                        trailer_parkings: None,
+                        maintenance: None,
+                        depots: None,
+                        overtime: None,
+                        unloading: None,
+                        relays: None,
                     }],
                     capacity: vec![vehicle.capacity],
                     skills: None,
+                    skill_counts: None,
                     limits: None,
+                    tier: None,
+                    loading: None,
+                    compartments: None,
+                    energy: None,
+                    emissions: None,
+                    trailer: None,
+                    physical: None,
+                    optimize_departure: None,
+                    duration_per_unit: None,
+                    named_capacity: None,
                 }
             })
             .collect();
@@ -159,11 +201,27 @@ mod actual {
         let matrix_profile_names = vehicles.iter().map(|v| v.profile.matrix.clone()).collect::<HashSet<_>>();
 
         Ok(Problem {
-            plan: Plan { jobs, relations: None, areas: None, clustering: None },
+            plan: Plan {
+                jobs,
+                relations: None,
+                areas: None,
+                clustering: None,
+                blackouts: None,
+                territories: None,
+                escalation: None,
+                marginal_costs: None,
+                zone_sequences: None,
+                eta_confidence: None,
+                certificates: None,
+            },
             fleet: Fleet {
                 vehicles,
-                profiles: matrix_profile_names.into_iter().map(|name| MatrixProfile { name, speed: None }).collect(),
+                profiles: matrix_profile_names
+                    .into_iter()
+                    .map(|name| MatrixProfile { name, speed: None, infer: None, metric: None })
+                    .collect(),
                 resources: None,
+                dimensions: None,
             },
             objectives: None,
         })
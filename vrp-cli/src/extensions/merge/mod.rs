@@ -0,0 +1,139 @@
+//! A helper module which contains functionality to merge solutions of disjoint sub-problems into one solution.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/merge/merge_test.rs"]
+mod merge_test;
+
+use std::collections::{HashMap, HashSet};
+use std::io::{BufReader, BufWriter, Read};
+use std::sync::Arc;
+use vrp_pragmatic::checker::CheckerContext;
+use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, PragmaticProblem, Problem};
+use vrp_pragmatic::format::solution::{
+    deserialize_solution, serialize_solution, Extras, Solution, Statistic, UnassignedJob,
+};
+use vrp_pragmatic::format::FormatError;
+
+/// Merges pragmatic solutions of sub-problems (e.g. solved per region) into one solution of the original problem.
+/// Global resources are validated: the same vehicle shift cannot be used in more than one solution and
+/// the merged solution is checked for feasibility against the original problem, e.g. dispatch limits at depots.
+pub fn merge_pragmatic_solutions<F: Read>(
+    problem_reader: BufReader<F>,
+    solution_readers: Vec<BufReader<F>>,
+    matrices_readers: Option<Vec<BufReader<F>>>,
+) -> Result<String, Vec<String>> {
+    let problem = deserialize_problem(problem_reader)
+        .map_err(|errs| vec![format!("cannot read problem: '{}'", FormatError::format_many(&errs, ","))])?;
+
+    let solutions = solution_readers
+        .into_iter()
+        .map(|reader| deserialize_solution(reader).map_err(|err| vec![format!("cannot read solution: '{}'", err)]))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let matrices = matrices_readers
+        .map(|matrices_readers| {
+            matrices_readers
+                .into_iter()
+                .map(|reader| {
+                    deserialize_matrix(reader)
+                        .map_err(|errs| vec![format!("cannot read matrix: '{}'", FormatError::format_many(&errs, ","))])
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()?;
+
+    let solution = merge_solutions(&problem, solutions)?;
+
+    let core_problem = Arc::new(
+        (problem.clone(), matrices.clone())
+            .read_pragmatic()
+            .map_err(|err| vec![format!("cannot read pragmatic problem: {}", FormatError::format_many(&err, ","))])?,
+    );
+
+    CheckerContext::new(core_problem, problem, matrices, solution.clone()).and_then(|ctx| ctx.check())?;
+
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+    serialize_solution(writer, &solution).map_err(|err| vec![format!("cannot write solution: '{}'", err)])?;
+
+    Ok(buffer)
+}
+
+/// Merges solutions of sub-problems into one solution without feasibility check. A job which is unassigned
+/// in one solution, but assigned in another, is considered as assigned.
+pub fn merge_solutions(problem: &Problem, solutions: Vec<Solution>) -> Result<Solution, Vec<String>> {
+    let job_ids = problem.plan.jobs.iter().map(|job| job.id.clone()).collect::<HashSet<_>>();
+    let mut errors = Vec::new();
+
+    let mut used_shifts = HashMap::<(String, usize), usize>::new();
+    let mut assigned_jobs = HashMap::<String, usize>::new();
+
+    solutions.iter().enumerate().for_each(|(solution_idx, solution)| {
+        solution.tours.iter().for_each(|tour| {
+            let shift_key = (tour.vehicle_id.clone(), tour.shift_index);
+            match used_shifts.get(&shift_key) {
+                Some(&other_idx) if other_idx != solution_idx => errors.push(format!(
+                    "vehicle '{}' with shift index '{}' is used in solutions {} and {}",
+                    tour.vehicle_id, tour.shift_index, other_idx, solution_idx
+                )),
+                _ => {
+                    used_shifts.insert(shift_key, solution_idx);
+                }
+            }
+
+            let mut tour_jobs = HashSet::new();
+            tour.stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter())
+                .filter(|activity| job_ids.contains(&activity.job_id) && tour_jobs.insert(activity.job_id.clone()))
+                .map(|activity| activity.job_id.clone())
+                .for_each(|job_id| match assigned_jobs.get(&job_id) {
+                    Some(&other_idx) if other_idx != solution_idx => errors
+                        .push(format!("job '{}' is assigned in solutions {} and {}", job_id, other_idx, solution_idx)),
+                    _ => {
+                        assigned_jobs.insert(job_id, solution_idx);
+                    }
+                });
+        });
+    });
+
+    let mut seen_unassigned = HashSet::new();
+    let mut get_unassigned = |get_jobs: &dyn Fn(&Solution) -> Option<&Vec<UnassignedJob>>| {
+        solutions
+            .iter()
+            .flat_map(|solution| get_jobs(solution).into_iter().flatten())
+            .filter(|job| !assigned_jobs.contains_key(&job.job_id) && seen_unassigned.insert(job.job_id.clone()))
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+    let unassigned = get_unassigned(&|solution| solution.unassigned.as_ref());
+    let reservations =
+        get_unassigned(&|solution| solution.extras.as_ref().and_then(|extras| extras.reservations.as_ref()));
+
+    problem
+        .plan
+        .jobs
+        .iter()
+        .filter(|job| !assigned_jobs.contains_key(&job.id) && !seen_unassigned.contains(&job.id))
+        .for_each(|job| errors.push(format!("job '{}' is not present in any solution", job.id)));
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let tours = solutions.iter().flat_map(|solution| solution.tours.iter().cloned()).collect::<Vec<_>>();
+    let violations =
+        solutions.iter().flat_map(|solution| solution.violations.iter().flatten()).cloned().collect::<Vec<_>>();
+
+    Ok(Solution {
+        statistic: tours.iter().fold(Statistic::default(), |acc, tour| acc + tour.statistic.clone()),
+        tours,
+        unassigned: if unassigned.is_empty() { None } else { Some(unassigned) },
+        violations: if violations.is_empty() { None } else { Some(violations) },
+        extras: if reservations.is_empty() {
+            None
+        } else {
+            Some(Extras { reservations: Some(reservations), ..Extras::default() })
+        },
+    })
+}
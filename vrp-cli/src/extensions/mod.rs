@@ -3,9 +3,13 @@
 #[cfg(not(target_arch = "wasm32"))]
 pub mod analyze;
 #[cfg(not(target_arch = "wasm32"))]
+pub mod anonymize;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod check;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod generate;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod merge;
 
 pub mod import;
 pub mod solve;
@@ -8,20 +8,28 @@ mod config_test;
 
 extern crate serde_json;
 
-use serde::{Deserialize};
+use serde::Deserialize;
+use std::collections::HashMap;
 use std::io::{BufReader, Read};
 use std::sync::Arc;
+use std::time::Duration;
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::common::SingleDimLoad;
 use vrp_core::models::problem::ProblemObjective;
-use vrp_core::prelude::*;
+use vrp_core::prelude::{
+    create_default_config_builder, Environment, LocalOperator, Problem, ProblemConfigBuilder, Random, Recreate,
+    RefinementContext, Ruin,
+};
+use vrp_core::rosomaxa::algorithms::gsom::NodeMerging;
 use vrp_core::rosomaxa::evolution::{InitialOperator, TelemetryMode};
 use vrp_core::rosomaxa::get_default_selection_size;
+use vrp_core::rosomaxa::hyper::OperatorTimeBudget;
 use vrp_core::rosomaxa::prelude::*;
 use vrp_core::rosomaxa::utils::*;
 use vrp_core::solver::search::*;
 use vrp_core::solver::RecreateInitialOperator;
 use vrp_core::solver::*;
+use vrp_pragmatic::format::entities::VehicleTie;
 
 /// An algorithm configuration.
 #[derive(Clone, Default, Deserialize, Debug)]
@@ -36,6 +44,8 @@ pub struct Config {
     pub environment: Option<EnvironmentConfig>,
     /// Specifies telemetry configuration.
     pub telemetry: Option<TelemetryConfig>,
+    /// Specifies solution post processing configuration.
+    pub processing: Option<PostProcessingConfig>,
 }
 
 /// An evolution configuration.
@@ -91,9 +101,37 @@ pub enum PopulationType {
         rebalance_memory: Option<usize>,
         /// An exploration phase ratio. Default is 0.9.
         exploration_ratio: Option<f64>,
+        /// An adaptive population sizing policy. Default is no adaptation.
+        sizing: Option<PopulationSizingConfig>,
+        /// A merging of similar network nodes. Default is no merging.
+        node_merging: Option<NodeMergingConfig>,
     },
 }
 
+/// An adaptive population sizing configuration.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PopulationSizingConfig {
+    /// A problem size which corresponds to unscaled population sizes. Default is 100.
+    pub reference_size: Option<usize>,
+    /// A max scale factor of population sizes. Default is 4.
+    pub max_scale: Option<f64>,
+    /// A termination estimate after which selection size starts to shrink. Default is 0.5.
+    pub shrink_threshold: Option<f64>,
+    /// A min selection size reached at the end of the search. Default is 2.
+    pub min_selection_size: Option<usize>,
+}
+
+/// A network node merging configuration.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeMergingConfig {
+    /// A max relative distance between weights of adjacent nodes to be merged.
+    pub max_distance: f64,
+    /// A max amount of last hits of the node to be merged.
+    pub max_hits: usize,
+}
+
 /// An initial solution configuration.
 #[derive(Clone, Deserialize, Debug)]
 pub struct InitialConfig {
@@ -136,7 +174,20 @@ pub enum HyperType {
     /// A hyper heuristic which selects operator from the predefined list using reinforcement
     /// learning technics.
     #[serde(rename(deserialize = "dynamic-selective"))]
-    DynamicSelective,
+    #[serde(rename_all = "camelCase")]
+    DynamicSelective {
+        /// Soft time budgets of inner operators.
+        time_budget: Option<TimeBudgetConfig>,
+    },
+}
+
+/// Specifies soft time budgets of search operators in milliseconds.
+#[derive(Clone, Deserialize, Debug)]
+pub struct TimeBudgetConfig {
+    /// A budget applied to operators without specific one.
+    pub default: Option<u64>,
+    /// Budgets of specific operators by their names.
+    pub operators: Option<HashMap<String, u64>>,
 }
 
 /// A operator configuration.
@@ -168,6 +219,7 @@ pub enum SearchOperatorType {
 
     /// A ruin and recreate metaheuristic settings.
     #[serde(rename(deserialize = "ruin-recreate"))]
+    #[serde(rename_all = "camelCase")]
     RuinRecreate {
         /// Probability.
         probability: OperatorProbabilityType,
@@ -175,6 +227,8 @@ pub enum SearchOperatorType {
         ruins: Vec<RuinGroupConfig>,
         /// Recreate methods.
         recreates: Vec<RecreateMethod>,
+        /// Route bias which makes routes of specific vehicle types to be ruined less often.
+        route_bias: Option<Vec<RouteBiasConfig>>,
     },
 }
 
@@ -241,6 +295,16 @@ pub struct RuinGroupConfig {
     weight: usize,
 }
 
+/// Specifies probability of routes served by vehicles of given types to be affected by ruin methods.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RouteBiasConfig {
+    /// Vehicle type ids.
+    vehicle_types: Vec<String>,
+    /// Probability of the route to be exposed to ruin methods: `0` means that the route is never ruined.
+    probability: f64,
+}
+
 /// Specifies ruin methods with their probability weight and specific parameters.
 #[derive(Clone, Deserialize, Debug)]
 #[serde(tag = "type")]
@@ -284,15 +348,20 @@ pub enum RecreateMethod {
     /// SkipBest insertion method.
     #[serde(rename(deserialize = "skip-best"))]
     SkipBest { weight: usize, start: usize, end: usize },
-    /// Insertion with blinks method.
+    /// Insertion with blinks method. A ratio specifies probability to skip the best insertion
+    /// position, default is 0.01.
     #[serde(rename(deserialize = "blinks"))]
-    Blinks { weight: usize },
+    Blinks { weight: usize, ratio: Option<f64> },
     /// Insertion with gaps method.
     #[serde(rename(deserialize = "gaps"))]
     Gaps { weight: usize, min: usize, max: usize },
     /// Nearest neighbour method.
     #[serde(rename(deserialize = "nearest"))]
     Nearest { weight: usize },
+    /// Cheapest insertion method which evaluates jobs only within a shortlist of nearest routes.
+    #[serde(rename(deserialize = "nearest-routes"))]
+    #[serde(rename_all = "camelCase")]
+    NearestRoutes { weight: usize, max_routes: usize, max_samples: usize },
     /// Insertion with skip random method.
     #[serde(rename(deserialize = "skip-random"))]
     SkipRandom { weight: usize },
@@ -359,6 +428,8 @@ pub struct VariationConfig {
 pub struct TelemetryConfig {
     progress: Option<ProgressConfig>,
     metrics: Option<MetricsConfig>,
+    /// Specifies best known solution persistence. Applicable only when solver is run from command line.
+    pub persistence: Option<PersistenceConfig>,
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -383,6 +454,27 @@ pub struct MetricsConfig {
     track_population: Option<usize>,
 }
 
+/// A configuration which controls how often the best known solution is written to disk.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistenceConfig {
+    /// Specifies path to the file where solution is written. The file is replaced atomically.
+    pub path: String,
+    /// Specifies minimum interval between two writes (in seconds).
+    pub interval: Option<u64>,
+    /// Specifies how many changes of the best known solution trigger a write.
+    pub improvements: Option<usize>,
+}
+
+/// A solution post processing configuration.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PostProcessingConfig {
+    /// Specifies whether tails of routes exceeding shift time or duration limit are moved to spare
+    /// vehicles. Default is false.
+    pub split_routes: Option<bool>,
+}
+
 /// An environment specific configuration.
 #[derive(Clone, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -492,8 +584,22 @@ fn configure_from_evolution(
                     selection_size,
                     rebalance_memory,
                     exploration_ratio,
+                    sizing,
+                    node_merging,
                 } => {
-                    let mut config = RosomaxaConfig::new_with_defaults(default_selection_size);
+                    let mut config = match sizing {
+                        Some(sizing) => {
+                            let defaults = PopulationSizing::default();
+                            let sizing = PopulationSizing {
+                                reference_size: sizing.reference_size.unwrap_or(defaults.reference_size),
+                                max_scale: sizing.max_scale.unwrap_or(defaults.max_scale),
+                                shrink_threshold: sizing.shrink_threshold.unwrap_or(defaults.shrink_threshold),
+                                min_selection_size: sizing.min_selection_size.unwrap_or(defaults.min_selection_size),
+                            };
+                            RosomaxaConfig::new_with_sizing(default_selection_size, problem.jobs.size(), sizing)
+                        }
+                        None => RosomaxaConfig::new_with_defaults(default_selection_size),
+                    };
                     if let Some(selection_size) = selection_size {
                         config.selection_size = *selection_size;
                     }
@@ -521,6 +627,12 @@ fn configure_from_evolution(
                     if let Some(exploration_ratio) = exploration_ratio {
                         config.exploration_ratio = *exploration_ratio;
                     }
+                    if let Some(node_merging) = node_merging {
+                        config.node_merging = Some(NodeMerging {
+                            max_distance: node_merging.max_distance,
+                            max_hits: node_merging.max_hits,
+                        });
+                    }
 
                     Box::new(RosomaxaPopulation::new(problem.objective.clone(), environment.clone(), config)?)
                 }
@@ -554,8 +666,20 @@ fn configure_from_hyper(
 
                 builder = builder.with_heuristic(static_selective);
             }
-            HyperType::DynamicSelective => {
-                let dynamic_selective = get_dynamic_heuristic(problem, environment);
+            HyperType::DynamicSelective { time_budget } => {
+                let time_budget = time_budget
+                    .as_ref()
+                    .map(|config| OperatorTimeBudget {
+                        default: config.default.map(Duration::from_millis),
+                        operators: config
+                            .operators
+                            .iter()
+                            .flatten()
+                            .map(|(name, budget)| (name.clone(), Duration::from_millis(*budget)))
+                            .collect(),
+                    })
+                    .unwrap_or_default();
+                let dynamic_selective = get_dynamic_heuristic_with_time_budget(problem, environment, time_budget);
                 builder = builder.with_heuristic(dynamic_selective);
             }
         }
@@ -590,12 +714,18 @@ fn create_recreate_method(
             (Arc::new(RecreateWithSkipBest::new(*start, *end, random)), *weight)
         }
         RecreateMethod::Slice { weight } => (Arc::new(RecreateWithSlice::new(random)), *weight),
-        RecreateMethod::Blinks { weight } => {
+        RecreateMethod::Blinks { weight, ratio: Some(ratio) } => {
+            (Arc::new(RecreateWithBlinks::<SingleDimLoad>::new_with_ratio(*ratio, random.clone())), *weight)
+        }
+        RecreateMethod::Blinks { weight, ratio: None } => {
             (Arc::new(RecreateWithBlinks::<SingleDimLoad>::new_with_defaults(random.clone())), *weight)
         }
         RecreateMethod::SkipRandom { weight } => (Arc::new(RecreateWithSkipRandom::new(random)), *weight),
         RecreateMethod::Gaps { weight, min, max } => (Arc::new(RecreateWithGaps::new(*min, *max, random)), *weight),
         RecreateMethod::Nearest { weight } => (Arc::new(RecreateWithNearestNeighbor::new(random)), *weight),
+        RecreateMethod::NearestRoutes { weight, max_routes, max_samples } => {
+            (Arc::new(RecreateWithNearestRoutes::new(*max_routes, *max_samples, random)), *weight)
+        }
         RecreateMethod::Regret { weight, start, end } => {
             (Arc::new(RecreateWithRegret::new(*start, *end, random)), *weight)
         }
@@ -611,9 +741,10 @@ fn create_operator(
     operator: &SearchOperatorType,
 ) -> Result<(TargetSearchOperator, TargetHeuristicProbability), String> {
     Ok(match operator {
-        SearchOperatorType::RuinRecreate { probability, ruins, recreates } => {
+        SearchOperatorType::RuinRecreate { probability, ruins, recreates, route_bias } => {
+            let route_bias = route_bias.as_ref().map(|route_bias| create_route_bias(route_bias));
             let ruin = Arc::new(WeightedRuin::new(
-                ruins.iter().map(|g| create_ruin_group(&problem, environment.clone(), g)).collect(),
+                ruins.iter().map(|g| create_ruin_group(&problem, environment.clone(), g, route_bias.clone())).collect(),
             ));
             let recreate = Arc::new(WeightedRecreate::new(
                 recreates.iter().map(|r| create_recreate_method(r, environment.clone())).collect(),
@@ -666,8 +797,37 @@ fn create_operator_probability(
     }
 }
 
-fn create_ruin_group(problem: &Arc<Problem>, environment: Arc<Environment>, group: &RuinGroupConfig) -> RuinGroup {
-    (group.methods.iter().map(|r| create_ruin_method(problem, environment.clone(), r)).collect(), group.weight)
+fn create_ruin_group(
+    problem: &Arc<Problem>,
+    environment: Arc<Environment>,
+    group: &RuinGroupConfig,
+    route_bias: Option<RouteBiasFn>,
+) -> RuinGroup {
+    (
+        group
+            .methods
+            .iter()
+            .map(|r| create_ruin_method(problem, environment.clone(), r))
+            .map(|(ruin, probability)| match route_bias.clone() {
+                Some(route_bias) => {
+                    (Arc::new(BiasedRuin::new(ruin, route_bias)) as Arc<dyn Ruin + Send + Sync>, probability)
+                }
+                None => (ruin, probability),
+            })
+            .collect(),
+        group.weight,
+    )
+}
+
+fn create_route_bias(route_bias: &[RouteBiasConfig]) -> RouteBiasFn {
+    let biases = route_bias
+        .iter()
+        .flat_map(|bias| bias.vehicle_types.iter().map(move |type_id| (type_id.clone(), bias.probability)))
+        .collect::<HashMap<_, _>>();
+
+    Arc::new(move |actor| {
+        actor.vehicle.dimens.get_vehicle_type().and_then(|type_id| biases.get(type_id)).copied().unwrap_or(1.)
+    })
 }
 
 fn create_ruin_method(
@@ -738,6 +898,7 @@ fn get_telemetry_mode(environment: Arc<Environment>, telemetry_config: &Option<T
 
     let create_metrics = |track_population: &Option<usize>| TelemetryMode::OnlyMetrics {
         track_population: track_population.unwrap_or(TRACK_POPULATION),
+        feed: None,
     };
 
     let create_progress = |log_best: &Option<usize>, log_population: &Option<usize>, dump_population: &Option<bool>| {
@@ -764,6 +925,7 @@ fn get_telemetry_mode(environment: Arc<Environment>, telemetry_config: &Option<T
                 log_population: log_population.unwrap_or(LOG_POPULATION),
                 track_population: track_population.unwrap_or(TRACK_POPULATION),
                 dump_population: dump_population.unwrap_or(false),
+                feed: None,
             },
             (true, false) => create_progress(log_best, log_population, dump_population),
             (false, true) => create_metrics(track_population),
@@ -773,12 +935,27 @@ fn get_telemetry_mode(environment: Arc<Environment>, telemetry_config: &Option<T
     }
 }
 
+fn configure_from_processing(
+    builder: ProblemConfigBuilder,
+    processing_config: &Option<PostProcessingConfig>,
+) -> ProblemConfigBuilder {
+    match processing_config.as_ref().and_then(|config| config.split_routes) {
+        Some(true) => builder.with_processing(create_processing(true)),
+        _ => builder,
+    }
+}
+
 fn configure_from_environment(
     environment_config: &Option<EnvironmentConfig>,
     max_time: Option<usize>,
+    quota: Option<Arc<dyn Quota + Send + Sync>>,
 ) -> Arc<Environment> {
     let mut environment = Environment::new_with_time_quota(max_time);
 
+    if let Some(quota) = quota {
+        environment.quota = Some(quota);
+    }
+
     if let Some(parallelism) = environment_config.as_ref().and_then(|c| c.parallelism.as_ref()) {
         // TODO validate parameters
         environment.parallelism = Parallelism::new(parallelism.num_thread_pools, parallelism.threads_per_pool);
@@ -821,9 +998,29 @@ pub fn create_builder_from_config(
     problem: Arc<Problem>,
     solutions: Vec<InsertionContext>,
     config: &Config,
+) -> Result<ProblemConfigBuilder, String> {
+    create_builder_with_quota(problem, solutions, config, None)
+}
+
+/// Creates a solver `Builder` from config using given execution quota instead of the one defined
+/// by config's max time. The quota is expected to respect max time on its own.
+pub fn create_builder_from_config_with_quota(
+    problem: Arc<Problem>,
+    solutions: Vec<InsertionContext>,
+    config: &Config,
+    quota: Arc<dyn Quota + Send + Sync>,
+) -> Result<ProblemConfigBuilder, String> {
+    create_builder_with_quota(problem, solutions, config, Some(quota))
+}
+
+fn create_builder_with_quota(
+    problem: Arc<Problem>,
+    solutions: Vec<InsertionContext>,
+    config: &Config,
+    quota: Option<Arc<dyn Quota + Send + Sync>>,
 ) -> Result<ProblemConfigBuilder, String> {
     let environment =
-        configure_from_environment(&config.environment, config.termination.as_ref().and_then(|t| t.max_time));
+        configure_from_environment(&config.environment, config.termination.as_ref().and_then(|t| t.max_time), quota);
     let telemetry_mode = get_telemetry_mode(environment.clone(), &config.telemetry);
     let mut builder = create_default_config_builder(problem.clone(), environment.clone(), telemetry_mode.clone())
         .with_init_solutions(solutions, None);
@@ -832,6 +1029,7 @@ pub fn create_builder_from_config(
         configure_from_evolution(builder, problem.clone(), environment.clone(), telemetry_mode, &config.evolution)?;
     builder = configure_from_hyper(builder, problem, environment, &config.hyper)?;
     builder = configure_from_termination(builder, &config.termination);
+    builder = configure_from_processing(builder, &config.processing);
 
     Ok(builder)
 }
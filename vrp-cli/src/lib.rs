@@ -38,7 +38,7 @@ use std::io::{BufReader, BufWriter};
 use std::sync::Arc;
 use vrp_core::models::Problem as CoreProblem;
 use vrp_core::prelude::Solver;
-use vrp_pragmatic::format::problem::{serialize_problem, PragmaticProblem, Problem};
+use vrp_pragmatic::format::problem::{serialize_problem, PragmaticProblem, Problem, RouteCheck, RouteCheckFn};
 use vrp_pragmatic::format::solution::PragmaticSolution;
 use vrp_pragmatic::format::FormatError;
 use vrp_pragmatic::get_unique_locations;
@@ -57,6 +57,7 @@ mod interop {
     use vrp_pragmatic::format::CoordIndex;
 
     type Callback = extern "C" fn(*const c_char);
+    type RouteCheckCallback = extern "C" fn(*const c_char) -> i32;
 
     fn to_string(pointer: *const c_char) -> String {
         let slice = unsafe { CStr::from_ptr(pointer).to_bytes() };
@@ -203,6 +204,50 @@ mod interop {
         });
     }
 
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format with custom route constraint.
+    /// A `route_check` callback receives route and job serialized in json and should return non-zero
+    /// value if the job can be assigned to the route. Please note, that it is called from multiple threads.
+    #[no_mangle]
+    extern "C" fn solve_pragmatic_with_route_check(
+        problem: *const c_char,
+        matrices: *const *const c_char,
+        matrices_len: *const i32,
+        config: *const c_char,
+        route_check: RouteCheckCallback,
+        success: Callback,
+        failure: Callback,
+    ) {
+        catch_panic(failure, || {
+            let problem = to_string(problem);
+            let matrices = unsafe { slice::from_raw_parts(matrices, matrices_len as usize).to_vec() };
+            let matrices = matrices.iter().map(|m| to_string(*m)).collect::<Vec<_>>();
+
+            let route_check: RouteCheckFn = Arc::new(move |route: &RouteCheck| {
+                let route = CString::new(get_route_check_serialized(route)).unwrap();
+                route_check(route.as_ptr()) != 0
+            });
+
+            let result = deserialize_problem(BufReader::new(problem.as_bytes()))
+                .and_then(|problem| {
+                    matrices
+                        .iter()
+                        .map(|matrix| deserialize_matrix(BufReader::new(matrix.as_bytes())))
+                        .collect::<Result<Vec<_>, _>>()
+                        .map(|matrices| (problem, if matrices.is_empty() { None } else { Some(matrices) }))
+                })
+                .and_then(|(problem, matrices)| (problem, matrices, route_check).read_pragmatic())
+                .map_err(|errors| get_errors_serialized(&errors))
+                .and_then(|problem| {
+                    read_config(BufReader::new(to_string(config).as_bytes()))
+                        .map_err(|err| to_config_error(err.as_str()))
+                        .map(|config| (problem, config))
+                })
+                .and_then(|(problem, config)| get_solution_serialized(Arc::new(problem), config));
+
+            call_back(result, success, failure);
+        });
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -331,6 +376,37 @@ mod interop {
                 failure,
             );
         }
+
+        #[test]
+        fn can_solve_problem_with_route_check() {
+            extern "C" fn route_check(route: *const c_char) -> i32 {
+                let route = to_string(route);
+                assert!(route.contains("vehicleId"));
+                assert!(route.contains("jobIds"));
+                0
+            }
+            extern "C" fn success(solution: *const c_char) {
+                let solution = to_string(solution);
+                assert!(solution.contains("CUSTOM_ROUTE_CONSTRAINT"));
+            }
+            extern "C" fn failure(err: *const c_char) {
+                unreachable!("{}", to_string(err))
+            }
+
+            let problem = CString::new(SIMPLE_PROBLEM).unwrap();
+            let matrices = CString::new("[]").unwrap();
+            let config = CString::new("{\"termination\": {\"max-generations\": 1}}").unwrap();
+
+            solve_pragmatic_with_route_check(
+                problem.as_ptr() as *const c_char,
+                matrices.as_ptr() as *const *const c_char,
+                std::ptr::null::<i32>(),
+                config.as_ptr() as *const c_char,
+                route_check,
+                success,
+                failure,
+            );
+        }
     }
 }
 
@@ -391,16 +467,54 @@ mod wasm {
     /// Solves Vehicle Routing Problem passed in `pragmatic` format.
     #[wasm_bindgen]
     pub fn solve_pragmatic(problem: &JsValue, matrices: &JsValue, config: &JsValue) -> Result<JsValue, JsValue> {
+        solve_pragmatic_with_options(problem, matrices, config, None)
+    }
+
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format with custom route constraint.
+    /// A `route_check` function receives route and job serialized in json and should return true
+    /// if the job can be assigned to the route.
+    #[wasm_bindgen]
+    pub fn solve_pragmatic_with_route_check(
+        problem: &JsValue,
+        matrices: &JsValue,
+        config: &JsValue,
+        route_check: js_sys::Function,
+    ) -> Result<JsValue, JsValue> {
+        let route_check = JsRouteCheck(route_check);
+        let route_check: RouteCheckFn = Arc::new(move |route: &RouteCheck| {
+            let route = JsValue::from_str(get_route_check_serialized(route).as_str());
+            route_check.0.call1(&JsValue::NULL, &route).ok().and_then(|result| result.as_bool()).unwrap_or(false)
+        });
+
+        solve_pragmatic_with_options(problem, matrices, config, Some(route_check))
+    }
+
+    /// Wraps js function to make it usable by the solver.
+    struct JsRouteCheck(js_sys::Function);
+
+    // NOTE wasm32 target is single threaded, so it is safe to share js function
+    unsafe impl Send for JsRouteCheck {}
+    unsafe impl Sync for JsRouteCheck {}
+
+    fn solve_pragmatic_with_options(
+        problem: &JsValue,
+        matrices: &JsValue,
+        config: &JsValue,
+        route_check: Option<RouteCheckFn>,
+    ) -> Result<JsValue, JsValue> {
         let problem: Problem = problem.into_serde().map_err(|err| JsValue::from_str(err.to_string().as_str()))?;
 
         let matrices: Vec<Matrix> = matrices.into_serde().map_err(|err| JsValue::from_str(err.to_string().as_str()))?;
+        let matrices = if matrices.is_empty() { None } else { Some(matrices) };
 
         let problem = Arc::new(
-            if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }.map_err(
-                |errors| {
-                    JsValue::from_str(errors.iter().map(|err| err.to_json()).collect::<Vec<_>>().join("\n").as_str())
-                },
-            )?,
+            match route_check {
+                Some(route_check) => (problem, matrices, route_check).read_pragmatic(),
+                None => (problem, matrices).read_pragmatic(),
+            }
+            .map_err(|errors| {
+                JsValue::from_str(errors.iter().map(|err| err.to_json()).collect::<Vec<_>>().join("\n").as_str())
+            })?,
         );
 
         let config: Config = config
@@ -452,6 +566,11 @@ pub fn get_solution_serialized(problem: Arc<CoreProblem>, config: Config) -> Res
     Ok(buffer)
 }
 
+/// Gets route check data serialized in json.
+pub fn get_route_check_serialized(route_check: &RouteCheck) -> String {
+    serde_json::to_string(route_check).unwrap_or_default()
+}
+
 /// Gets errors serialized in free form.
 pub fn get_errors_serialized(errors: &[FormatError]) -> String {
     errors.iter().map(|err| format!("{}", err)).collect::<Vec<_>>().join("\n")
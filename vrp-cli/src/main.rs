@@ -17,9 +17,11 @@ mod cli {
     use super::commands::import::{get_import_app, run_import};
     use super::commands::solve::{get_solve_app, run_solve};
     use crate::commands::analyze::{get_analyze_app, run_analyze};
+    use crate::commands::anonymize::{get_anonymize_app, run_anonymize};
     use crate::commands::check::{get_check_app, run_check};
     use crate::commands::create_write_buffer;
     use crate::commands::generate::{get_generate_app, run_generate};
+    use crate::commands::merge::{get_merge_app, run_merge};
     use clap::{ArgMatches, Command};
     use std::process;
 
@@ -37,6 +39,8 @@ mod cli {
             .subcommand(get_import_app())
             .subcommand(get_check_app())
             .subcommand(get_generate_app())
+            .subcommand(get_merge_app())
+            .subcommand(get_anonymize_app())
     }
 
     pub fn run_subcommand(arg_matches: ArgMatches) {
@@ -46,6 +50,8 @@ mod cli {
             Some(("import", import_matches)) => run_import(import_matches),
             Some(("check", check_matches)) => run_check(check_matches),
             Some(("generate", generate_matches)) => run_generate(generate_matches),
+            Some(("merge", merge_matches)) => run_merge(merge_matches, create_write_buffer),
+            Some(("anonymize", anonymize_matches)) => run_anonymize(anonymize_matches, create_write_buffer),
             _ => {
                 eprintln!("no subcommand was used. Use -h to print help information.");
                 process::exit(1);
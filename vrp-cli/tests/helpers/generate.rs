@@ -12,19 +12,54 @@ pub fn create_empty_job() -> Job {
         value: None,
         group: None,
         compatibility: None,
+        reservation: None,
+        visit_gap: None,
+        product_class: None,
+        placement: None,
+        truck_only: None,
+        max_ride_duration: None,
+        sync: None,
+        predecessors: None,
+        approach: None,
+        attempts: None,
+        item: None,
+        partition: None,
+        unassigned_penalty: None,
+        bundle: None,
     }
 }
 
 pub fn create_empty_job_task() -> JobTask {
-    JobTask { places: vec![], demand: None, order: None }
+    JobTask { places: vec![], demand: None, order: None, named_demand: None }
 }
 
 pub fn create_empty_job_place() -> JobPlace {
-    JobPlace { location: Location::Coordinate { lat: 0.0, lng: 0.0 }, duration: 0.0, times: None, tag: None }
+    JobPlace {
+        location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+        duration: 0.0,
+        times: None,
+        tag: None,
+        duration_per_unit: None,
+        lateness_penalty: None,
+        post_service_buffer: None,
+        recurring_times: None,
+    }
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, areas: None, clustering: None }
+    Plan {
+        jobs: vec![],
+        relations: None,
+        areas: None,
+        clustering: None,
+        blackouts: None,
+        territories: None,
+        escalation: None,
+        marginal_costs: None,
+        zone_sequences: None,
+        eta_confidence: None,
+        certificates: None,
+    }
 }
 
 pub fn create_test_vehicle_type() -> VehicleType {
@@ -32,7 +67,7 @@ pub fn create_test_vehicle_type() -> VehicleType {
         type_id: "vehicle".to_string(),
         vehicle_ids: vec!["vehicle_1".to_string()],
         profile: VehicleProfile { matrix: "car".to_string(), scale: None },
-        costs: VehicleCosts { fixed: None, distance: 1., time: 0. },
+        costs: VehicleCosts { fixed: None, distance: 1., time: 0., waiting: None },
         shifts: vec![VehicleShift {
             start: ShiftStart {
                 earliest: "2020-05-01T09:00:00.00Z".to_string(),
@@ -43,15 +78,33 @@ pub fn create_test_vehicle_type() -> VehicleType {
             dispatch: None,
             breaks: None,
             reloads: None,
+            recharges: None,
+            trailer_parkings: None,
+            maintenance: None,
+            depots: None,
+            overtime: None,
+            unloading: None,
+            relays: None,
         }],
         capacity: vec![10],
         skills: None,
+        skill_counts: None,
         limits: None,
+        tier: None,
+        loading: None,
+        compartments: None,
+        energy: None,
+        emissions: None,
+        trailer: None,
+        physical: None,
+        optimize_departure: None,
+        duration_per_unit: None,
+        named_capacity: None,
     }
 }
 
 pub fn create_test_vehicle_profile() -> MatrixProfile {
-    MatrixProfile { name: "car".to_string(), speed: None }
+    MatrixProfile { name: "car".to_string(), speed: None, infer: None, metric: None }
 }
 
 pub fn create_test_time_window() -> Vec<String> {
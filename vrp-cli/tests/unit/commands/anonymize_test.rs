@@ -0,0 +1,38 @@
+use super::*;
+use vrp_cli::pragmatic::format::problem::{deserialize_matrix, deserialize_problem, PragmaticProblem};
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
+
+#[test]
+fn can_anonymize_problem_from_args() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "anonymize",
+        "pragmatic",
+        "--problem-file",
+        PRAGMATIC_PROBLEM_PATH,
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_anonymize_app().try_get_matches_from(args).unwrap();
+
+    run_anonymize(&matches, |out_file| BufWriter::new(Box::new(out_file.unwrap()))).unwrap();
+
+    let problem = BufReader::new(tmpfile.as_file()).read_pragmatic().unwrap();
+    assert_eq!(problem.jobs.size(), 3);
+    assert_eq!(problem.fleet.vehicles.len(), 1);
+}
+
+#[test]
+fn can_use_original_matrix_with_anonymized_problem() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec!["anonymize", "pragmatic", "-p", PRAGMATIC_PROBLEM_PATH, "-o", tmpfile.path().to_str().unwrap()];
+    let matches = get_anonymize_app().try_get_matches_from(args).unwrap();
+    run_anonymize(&matches, |out_file| BufWriter::new(Box::new(out_file.unwrap()))).unwrap();
+
+    let problem = deserialize_problem(BufReader::new(tmpfile.reopen().unwrap())).unwrap();
+    let matrix = deserialize_matrix(BufReader::new(open_file(PRAGMATIC_MATRIX_PATH, "matrix"))).unwrap();
+
+    assert!((problem, vec![matrix]).read_pragmatic().is_ok());
+}
@@ -20,3 +20,21 @@ fn can_run_check_solution() {
 
     run_check(&matches).unwrap();
 }
+
+#[test]
+fn can_run_check_solution_with_coverage() {
+    let args = vec![
+        "check",
+        "pragmatic",
+        "-p",
+        PRAGMATIC_PROBLEM_PATH,
+        "-m",
+        PRAGMATIC_MATRIX_PATH,
+        "-s",
+        PRAGMATIC_SOLUTION_PATH,
+        "--coverage",
+    ];
+    let matches = get_check_app().try_get_matches_from(args).unwrap();
+
+    run_check(&matches).unwrap();
+}
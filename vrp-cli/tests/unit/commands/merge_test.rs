@@ -0,0 +1,52 @@
+use super::*;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+struct DummyWrite {}
+
+impl Write for DummyWrite {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn can_run_merge_solutions() {
+    let args = vec![
+        "merge",
+        "pragmatic",
+        "--problem-file",
+        PRAGMATIC_PROBLEM_PATH,
+        "--matrix",
+        PRAGMATIC_MATRIX_PATH,
+        "--solution-file",
+        PRAGMATIC_SOLUTION_PATH,
+    ];
+    let matches = get_merge_app().try_get_matches_from(args).unwrap();
+
+    run_merge(&matches, |_| BufWriter::new(Box::new(DummyWrite {}))).unwrap();
+}
+
+#[test]
+fn can_detect_shared_vehicle_when_merging() {
+    let args = vec![
+        "merge",
+        "pragmatic",
+        "--problem-file",
+        PRAGMATIC_PROBLEM_PATH,
+        "--solution-file",
+        PRAGMATIC_SOLUTION_PATH,
+        PRAGMATIC_SOLUTION_PATH,
+    ];
+    let matches = get_merge_app().try_get_matches_from(args).unwrap();
+
+    let result = run_merge(&matches, |_| BufWriter::new(Box::new(DummyWrite {})));
+
+    assert!(result.unwrap_err().starts_with("cannot merge solutions, found 4 errors"));
+}
@@ -1,4 +1,5 @@
 use super::*;
+use vrp_cli::pragmatic::format::solution::deserialize_solution;
 
 const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
 const SOLOMON_PROBLEM_PATH: &str = "../examples/data/scientific/solomon/C101.25.txt";
@@ -49,7 +50,7 @@ fn can_solve_solomon_problem_with_generation_limit() {
 
 #[test]
 fn can_require_problem_path() {
-    for format in &["pragmatic", "solomon", "lilim", "tsplib"] {
+    for format in &["pragmatic", "solomon", "lilim", "tsplib", "lkh3"] {
         get_solve_app().try_get_matches_from(vec!["solve", format]).unwrap_err();
     }
 }
@@ -62,6 +63,48 @@ fn can_specify_search_mode_setting() {
     }
 }
 
+#[test]
+fn can_write_best_known_feasible_solution() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--max-generations",
+        "1",
+        "--out-feasible",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+
+    let solution = deserialize_solution(BufReader::new(tmpfile.as_file())).unwrap();
+    assert!(!solution.tours.is_empty());
+    assert!(solution.violations.is_none());
+}
+
+#[test]
+fn can_persist_best_known_solution_without_config() {
+    let tmpdir = tempfile::TempDir::new().unwrap();
+    let path = tmpdir.path().join("solution.wal.json");
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--max-generations",
+        "1",
+        "--persist-solution",
+        path.to_str().unwrap(),
+    ];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+
+    let solution = deserialize_solution(BufReader::new(File::open(path).unwrap())).unwrap();
+    assert!(!solution.tours.is_empty());
+}
+
 #[test]
 fn can_specify_experimental_setting() {
     let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--experimental"];
@@ -0,0 +1,39 @@
+use super::*;
+use std::fs::File;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+
+fn get_problem_reader() -> BufReader<File> {
+    BufReader::new(File::open(PRAGMATIC_PROBLEM_PATH).expect("cannot read problem file"))
+}
+
+parameterized_test! {can_create_elastic_problem, (max_vehicles_per_type, expected), {
+    can_create_elastic_problem_impl(max_vehicles_per_type, expected);
+}}
+
+can_create_elastic_problem! {
+    case01_default: (None, vec!["vehicle_1", "vehicle_2", "vehicle_3"]),
+    case02_more: (Some(4), vec!["vehicle_1", "vehicle_2", "vehicle_3", "vehicle_4"]),
+    case03_less: (Some(0), vec!["vehicle_1"]),
+}
+
+fn can_create_elastic_problem_impl(max_vehicles_per_type: Option<usize>, expected: Vec<&str>) {
+    let problem = deserialize_problem(get_problem_reader()).expect("cannot deserialize problem");
+
+    let problem = create_elastic_problem(problem, max_vehicles_per_type);
+
+    assert_eq!(problem.fleet.vehicles[0].vehicle_ids, expected);
+}
+
+#[test]
+fn can_get_fleet_size_recommendation() {
+    let result = get_fleet_size_recommendation(get_problem_reader(), None, None, Some(10), None)
+        .expect("cannot get recommendation");
+
+    let result: serde_json::Value = serde_json::from_str(result.as_str()).expect("cannot parse result");
+    assert_eq!(result["unassignedJobs"], 0);
+    assert_eq!(result["totalVehicles"], 1);
+    assert_eq!(result["totalAcquisitionCost"], 22.);
+    assert_eq!(result["vehicleTypes"][0]["typeId"], "vehicle");
+    assert_eq!(result["vehicleTypes"][0]["amount"], 1);
+}
@@ -0,0 +1,135 @@
+use super::*;
+use std::collections::HashSet;
+use vrp_core::utils::DefaultRandom;
+use vrp_pragmatic::format::CoordIndex;
+use vrp_pragmatic::validation::ValidationContext;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+
+const PROBLEM_WITH_NAMES: &str = r#"
+{
+  "plan": {
+    "jobs": [
+      {
+        "id": "customer_a",
+        "deliveries": [{ "places": [{ "location": { "lat": 52.52, "lng": 13.45 }, "duration": 60, "tag": "door" }], "demand": [2] }],
+        "skills": { "allOf": ["fridge"] }
+      },
+      {
+        "id": "customer_b",
+        "pickups": [{ "places": [{ "location": { "lat": 52.51, "lng": 13.38 }, "duration": 60 }], "demand": [3] }],
+        "group": "north"
+      }
+    ],
+    "relations": [{ "type": "sequence", "jobs": ["departure", "customer_b", "customer_a"], "vehicleId": "truck_1" }]
+  },
+  "fleet": {
+    "vehicles": [
+      {
+        "typeId": "truck",
+        "vehicleIds": ["truck_1", "truck_2"],
+        "profile": { "matrix": "car" },
+        "costs": { "fixed": 10, "distance": 1, "time": 1 },
+        "shifts": [
+          {
+            "start": { "earliest": "2020-01-01T08:00:00Z", "location": { "lat": 52.53, "lng": 13.39 } },
+            "end": { "latest": "2020-01-01T18:00:00Z", "location": { "lat": 52.53, "lng": 13.39 } }
+          }
+        ],
+        "capacity": [10],
+        "skills": ["fridge"]
+      }
+    ],
+    "profiles": [{ "name": "car" }]
+  }
+}
+"#;
+
+fn read_problem(data: &str) -> Problem {
+    deserialize_problem(BufReader::new(data.as_bytes())).expect("cannot read problem")
+}
+
+fn get_coordinates(problem: &mut Problem) -> Vec<(f64, f64)> {
+    let mut coordinates = Vec::new();
+    visit_locations(problem, &mut |location| {
+        if let Location::Coordinate { lat, lng } = location {
+            coordinates.push((*lat, *lng));
+        }
+    });
+
+    coordinates
+}
+
+fn get_approx_distance(a: &(f64, f64), b: &(f64, f64)) -> f64 {
+    let x = (a.1 - b.1) * ((a.0 + b.0) / 2.).to_radians().cos();
+    let y = a.0 - b.0;
+
+    (x * x + y * y).sqrt()
+}
+
+#[test]
+fn can_rename_ids_consistently() {
+    let problem = anonymize_problem(read_problem(PROBLEM_WITH_NAMES), &DefaultRandom::default());
+
+    let job_ids = problem.plan.jobs.iter().map(|job| job.id.as_str()).collect::<Vec<_>>();
+    assert_eq!(job_ids, vec!["job1", "job2"]);
+    assert_eq!(problem.fleet.vehicles[0].type_id, "vehicle_type1");
+    assert_eq!(problem.fleet.vehicles[0].vehicle_ids, vec!["vehicle1", "vehicle2"]);
+    assert_eq!(problem.fleet.vehicles[0].skills, Some(vec!["skill1".to_string()]));
+    assert_eq!(
+        problem.plan.jobs[0].skills.as_ref().and_then(|skills| skills.all_of.clone()),
+        Some(vec!["skill1".to_string()])
+    );
+    assert_eq!(problem.plan.jobs[0].deliveries.as_ref().unwrap()[0].places[0].tag, Some("tag1".to_string()));
+    assert_eq!(problem.plan.jobs[1].group, Some("group1".to_string()));
+
+    let relation = &problem.plan.relations.as_ref().unwrap()[0];
+    assert_eq!(relation.vehicle_id, "vehicle1");
+    assert_eq!(relation.jobs, vec!["departure", "job2", "job1"]);
+}
+
+#[test]
+fn can_scale_demand_and_capacity_with_the_same_factor() {
+    let problem = anonymize_problem(read_problem(PROBLEM_WITH_NAMES), &DefaultRandom::default());
+
+    let capacity = problem.fleet.vehicles[0].capacity[0];
+    let delivery = problem.plan.jobs[0].deliveries.as_ref().unwrap()[0].demand.as_ref().unwrap()[0];
+    let pickup = problem.plan.jobs[1].pickups.as_ref().unwrap()[0].demand.as_ref().unwrap()[0];
+
+    let factor = capacity / 10;
+    assert!((2..=10).contains(&factor));
+    assert_eq!(capacity, factor * 10);
+    assert_eq!(delivery, factor * 2);
+    assert_eq!(pickup, factor * 3);
+}
+
+#[test]
+fn can_keep_relative_distances_between_locations() {
+    let content = std::fs::read_to_string(PRAGMATIC_PROBLEM_PATH).expect("cannot read test file");
+    let mut original = read_problem(content.as_str());
+    let mut anonymized = anonymize_problem(original.clone(), &DefaultRandom::default());
+
+    let original = get_coordinates(&mut original);
+    let anonymized = get_coordinates(&mut anonymized);
+
+    assert_eq!(original.len(), anonymized.len());
+    assert_ne!(original, anonymized);
+    assert_eq!(
+        original.iter().map(|(lat, lng)| (lat.to_bits(), lng.to_bits())).collect::<HashSet<_>>().len(),
+        anonymized.iter().map(|(lat, lng)| (lat.to_bits(), lng.to_bits())).collect::<HashSet<_>>().len()
+    );
+    original.iter().zip(anonymized.iter()).for_each(|(a_orig, a_anon)| {
+        original.iter().zip(anonymized.iter()).for_each(|(b_orig, b_anon)| {
+            let (expected, actual) = (get_approx_distance(a_orig, b_orig), get_approx_distance(a_anon, b_anon));
+            assert!((expected - actual).abs() <= expected * 0.01 + 1E-9);
+        });
+    });
+}
+
+#[test]
+fn can_produce_valid_problem() {
+    let problem = anonymize_problem(read_problem(PROBLEM_WITH_NAMES), &DefaultRandom::default());
+    let coord_index = CoordIndex::new(&problem);
+
+    assert!(ValidationContext::new(&problem, None, &coord_index).validate().is_ok());
+}
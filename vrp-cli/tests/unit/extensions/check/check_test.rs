@@ -38,3 +38,17 @@ pub fn can_detect_invalid_matrix_file() {
         vec!["cannot read matrix: 'E0001, cause: 'cannot deserialize matrix', action: 'check input json: 'missing field `travelTimes` at line 159 column 1''.'"]
     );
 }
+
+#[test]
+pub fn can_check_coverage() {
+    let report = check_pragmatic_coverage(
+        reader(PRAGMATIC_PROBLEM_PATH),
+        reader(PRAGMATIC_SOLUTION_PATH),
+        Some(vec![reader(PRAGMATIC_MATRIX_PATH)]),
+    )
+    .expect("cannot check coverage");
+
+    assert!(!report.hard_activity.is_empty());
+    assert!(report.hard_activity.iter().all(|coverage| coverage.evaluated > 0));
+    assert!(report.objectives.iter().any(|coverage| coverage.is_covered()));
+}
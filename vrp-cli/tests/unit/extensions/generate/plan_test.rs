@@ -8,6 +8,13 @@ fn can_generate_bounding_box() {
         relations: None,
         areas: None,
         clustering: None,
+        blackouts: None,
+        territories: None,
+        escalation: None,
+        marginal_costs: None,
+        zone_sequences: None,
+        eta_confidence: None,
+        certificates: None,
     };
 
     let ((min_lat, min_lng), (max_lat, max_lng)) = get_bounding_box_from_plan(&plan);
@@ -25,6 +32,13 @@ fn can_get_bounding_box_from_size() {
         relations: None,
         areas: None,
         clustering: None,
+        blackouts: None,
+        territories: None,
+        escalation: None,
+        marginal_costs: None,
+        zone_sequences: None,
+        eta_confidence: None,
+        certificates: None,
     };
 
     let ((min_lat, min_lng), (max_lat, max_lng)) = get_bounding_box_from_size(&plan, 100.);
@@ -0,0 +1,119 @@
+use super::*;
+use vrp_pragmatic::format::solution::{UnassignedJob, UnassignedJobReason};
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+fn read_file(path: &str) -> String {
+    std::fs::read_to_string(path).expect("cannot read test file")
+}
+
+fn get_problem() -> Problem {
+    deserialize_problem(BufReader::new(read_file(PRAGMATIC_PROBLEM_PATH).as_bytes())).expect("cannot read problem")
+}
+
+fn get_solution() -> Solution {
+    deserialize_solution(BufReader::new(read_file(PRAGMATIC_SOLUTION_PATH).as_bytes())).expect("cannot read solution")
+}
+
+fn create_partial_solution(unassigned_job_ids: &[&str]) -> Solution {
+    Solution {
+        statistic: Statistic::default(),
+        tours: vec![],
+        unassigned: Some(
+            unassigned_job_ids
+                .iter()
+                .map(|job_id| UnassignedJob {
+                    job_id: job_id.to_string(),
+                    reasons: vec![UnassignedJobReason {
+                        code: "NO_REASON_FOUND".to_string(),
+                        description: "unknown".to_string(),
+                        details: None,
+                    }],
+                })
+                .collect(),
+        ),
+        violations: None,
+        extras: None,
+    }
+}
+
+fn serialize(solution: &Solution) -> String {
+    let mut buffer = String::new();
+    serialize_solution(unsafe { BufWriter::new(buffer.as_mut_vec()) }, solution).expect("cannot serialize solution");
+
+    buffer
+}
+
+#[test]
+fn can_merge_pragmatic_solutions() {
+    let problem = read_file(PRAGMATIC_PROBLEM_PATH);
+    let solutions = [serialize(&get_solution()), serialize(&create_partial_solution(&["job1"]))];
+
+    let result = merge_pragmatic_solutions(
+        BufReader::new(problem.as_bytes()),
+        solutions.iter().map(|solution| BufReader::new(solution.as_bytes())).collect(),
+        None,
+    )
+    .expect("cannot merge solutions");
+
+    let result = deserialize_solution(BufReader::new(result.as_bytes())).expect("cannot read merged solution");
+    assert_eq!(result.tours.len(), 1);
+    assert!(result.unassigned.is_none());
+    assert_eq!(result.statistic, get_solution().statistic);
+}
+
+#[test]
+fn can_keep_unassigned_jobs_from_all_solutions() {
+    let solutions = vec![create_partial_solution(&["job1", "job2"]), create_partial_solution(&["job2", "job3"])];
+
+    let result = merge_solutions(&get_problem(), solutions).expect("cannot merge solutions");
+
+    let unassigned = result.unassigned.expect("no unassigned jobs");
+    assert_eq!(unassigned.iter().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job1", "job2", "job3"]);
+    assert!(result.tours.is_empty());
+}
+
+#[test]
+fn can_keep_unassigned_reservations_separately() {
+    let mut with_reservation = create_partial_solution(&["job1"]);
+    let reservations = with_reservation.unassigned.take();
+    with_reservation.extras = Some(Extras { reservations, ..Extras::default() });
+    let solutions = vec![with_reservation, create_partial_solution(&["job2", "job3"])];
+
+    let result = merge_solutions(&get_problem(), solutions).expect("cannot merge solutions");
+
+    let unassigned = result.unassigned.expect("no unassigned jobs");
+    assert_eq!(unassigned.iter().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job2", "job3"]);
+    let reservations = result.extras.and_then(|extras| extras.reservations).expect("no reservations");
+    assert_eq!(reservations.iter().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job1"]);
+}
+
+#[test]
+fn can_detect_shared_vehicle_shift() {
+    let solutions = vec![get_solution(), get_solution()];
+
+    let result = merge_solutions(&get_problem(), solutions);
+
+    assert_eq!(
+        result.expect_err("no error returned"),
+        vec![
+            "vehicle 'vehicle_1' with shift index '0' is used in solutions 0 and 1",
+            "job 'job1' is assigned in solutions 0 and 1",
+            "job 'job2' is assigned in solutions 0 and 1",
+            "job 'job3' is assigned in solutions 0 and 1",
+        ]
+    );
+}
+
+#[test]
+fn can_detect_missing_jobs() {
+    let solutions = vec![create_partial_solution(&["job2"])];
+
+    let result = merge_solutions(&get_problem(), solutions);
+
+    assert_eq!(
+        result.expect_err("no error returned"),
+        vec!["job 'job1' is not present in any solution", "job 'job3' is not present in any solution"]
+    );
+}
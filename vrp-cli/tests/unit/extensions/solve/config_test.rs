@@ -18,6 +18,10 @@ fn can_read_full_config() {
     let metrics = telemetry.metrics.unwrap();
     assert!(!metrics.enabled);
     assert_eq!(metrics.track_population, Some(1000));
+    let persistence = telemetry.persistence.expect("no persistence config");
+    assert_eq!(persistence.path, "solution.wal.json");
+    assert_eq!(persistence.interval, Some(60));
+    assert_eq!(persistence.improvements, None);
 
     let evolution_config = config.evolution.expect("no evolution config");
 
@@ -42,6 +46,8 @@ fn can_read_full_config() {
             learning_rate,
             rebalance_memory,
             exploration_ratio,
+            sizing,
+            node_merging,
         } => {
             assert_eq!(selection_size, Some(8));
             assert_eq!(max_elite_size, Some(2));
@@ -52,6 +58,14 @@ fn can_read_full_config() {
             assert_eq!(learning_rate, Some(0.1));
             assert_eq!(rebalance_memory, Some(100));
             assert_eq!(exploration_ratio, Some(0.9));
+            let sizing = sizing.expect("no sizing config");
+            assert_eq!(sizing.reference_size, Some(100));
+            assert_eq!(sizing.max_scale, Some(4.));
+            assert_eq!(sizing.shrink_threshold, Some(0.5));
+            assert_eq!(sizing.min_selection_size, Some(2));
+            let node_merging = node_merging.expect("no node merging config");
+            assert_eq!(node_merging.max_distance, 0.01);
+            assert_eq!(node_merging.max_hits, 2);
         }
         _ => unreachable!(),
     }
@@ -88,10 +102,11 @@ fn can_read_full_config() {
             }
 
             match operators.get(2).unwrap() {
-                SearchOperatorType::RuinRecreate { probability, ruins, recreates } => {
+                SearchOperatorType::RuinRecreate { probability, ruins, recreates, route_bias } => {
                     assert_eq!(as_scalar_probability(probability), 1.);
                     assert_eq!(ruins.len(), 7);
-                    assert_eq!(recreates.len(), 12);
+                    assert_eq!(recreates.len(), 13);
+                    assert_eq!(route_bias.as_ref().map(|route_bias| route_bias.len()), Some(1));
                 }
                 _ => unreachable!(),
             }
@@ -105,7 +120,7 @@ fn can_read_full_config() {
                 _ => unreachable!(),
             }
         }
-        HyperType::DynamicSelective => unreachable!(),
+        HyperType::DynamicSelective { .. } => unreachable!(),
     }
 
     let termination = config.termination.expect("no termination config");
@@ -122,6 +137,9 @@ fn can_read_full_config() {
     let logging = environment.logging.expect("no logging config");
     assert!(logging.enabled);
     assert_eq!(logging.prefix, Some("[config.full]".to_string()));
+
+    let processing = config.processing.expect("no processing config");
+    assert_eq!(processing.split_routes, Some(false));
 }
 
 #[test]
@@ -132,6 +150,7 @@ fn can_create_default_config() {
     assert!(config.hyper.is_none());
     assert!(config.termination.is_none());
     assert!(config.telemetry.is_none());
+    assert!(config.processing.is_none());
 }
 
 #[test]
@@ -144,7 +163,9 @@ fn can_configure_telemetry_metrics() {
         telemetry: Some(TelemetryConfig {
             progress: None,
             metrics: Some(MetricsConfig { enabled: true, track_population: Some(10) }),
+            persistence: None,
         }),
+        processing: None,
     };
 
     let (_, _, metrics) = create_builder_from_config(create_example_problem(), Vec::default(), &config)
@@ -158,9 +179,76 @@ fn can_configure_telemetry_metrics() {
     assert_eq!(metrics.evolution.len(), 10 + 1);
 }
 
+#[test]
+fn can_read_dynamic_selective_with_time_budget() {
+    let config = r#"
+    {
+      "hyper": {
+        "type": "dynamic-selective",
+        "timeBudget": {
+          "default": 500,
+          "operators": { "local_exch_intra_route_random": 100 }
+        }
+      },
+      "termination": { "maxGenerations": 10 }
+    }
+    "#;
+
+    let config = read_config(BufReader::new(config.as_bytes())).unwrap();
+
+    match config.hyper.as_ref() {
+        Some(HyperType::DynamicSelective { time_budget: Some(time_budget) }) => {
+            assert_eq!(time_budget.default, Some(500));
+            assert_eq!(time_budget.operators.as_ref().and_then(|o| o.get("local_exch_intra_route_random")), Some(&100));
+        }
+        _ => unreachable!(),
+    }
+    assert!(create_builder_from_config(create_example_problem(), Vec::default(), &config)
+        .and_then(|builder| builder.build())
+        .is_ok());
+}
+
 fn as_scalar_probability(probability: &OperatorProbabilityType) -> f64 {
     match probability {
         OperatorProbabilityType::Scalar { scalar } => *scalar,
         _ => unreachable!(),
     }
 }
+
+#[test]
+fn can_stop_search_with_reached_quota() {
+    struct ReachedQuota;
+
+    impl Quota for ReachedQuota {
+        fn is_reached(&self) -> bool {
+            true
+        }
+    }
+
+    let config = Config {
+        evolution: None,
+        hyper: None,
+        termination: Some(TerminationConfig { max_time: None, max_generations: Some(usize::MAX), variation: None }),
+        environment: None,
+        telemetry: Some(TelemetryConfig {
+            progress: None,
+            metrics: Some(MetricsConfig { enabled: true, track_population: None }),
+            persistence: None,
+        }),
+        processing: None,
+    };
+
+    let (solution, _, metrics) = create_builder_from_config_with_quota(
+        create_example_problem(),
+        Vec::default(),
+        &config,
+        Arc::new(ReachedQuota),
+    )
+    .and_then(|config_builder| config_builder.build())
+    .map(|evolution_config| Solver::new(create_example_problem(), evolution_config))
+    .and_then(|solver| solver.solve())
+    .unwrap();
+
+    assert!(!solution.routes.is_empty() || !solution.unassigned.is_empty());
+    assert_eq!(metrics.expect("no metrics").generations, 0);
+}
@@ -126,6 +126,7 @@ fn get_dissimilarities(
     config: &ClusterConfig,
 ) -> Vec<DissimilarityInfo> {
     let min_shared_time = config.threshold.min_shared_time.unwrap_or(0.);
+    let min_overlap = config.threshold.min_time_window_overlap.unwrap_or(0.);
     outer
         .to_single()
         .places
@@ -135,17 +136,9 @@ fn get_dissimilarities(
         .flat_map(|(outer_place_idx, outer_loc, _, outer_times)| {
             inner.to_single().places.iter().enumerate().filter_map(map_place).filter_map(
                 move |(inner_place_idx, inner_loc, inner_duration, inner_times)| {
-                    let shared_time = outer_times
-                        .iter()
-                        .flat_map(|outer_time| {
-                            inner_times.iter().filter_map(move |inner_time| {
-                                outer_time.overlapping(inner_time).map(|tw| tw.duration())
-                            })
-                        })
-                        .max_by(|a, b| compare_floats(*a, *b))
-                        .unwrap_or(0.);
+                    let (shared_time, overlap) = get_shared_time(outer_times.as_slice(), inner_times.as_slice());
 
-                    if shared_time > min_shared_time {
+                    if shared_time > min_shared_time && overlap >= min_overlap {
                         let fwd_distance = transport.distance_approx(&config.profile, outer_loc, inner_loc);
                         let fwd_duration = transport.duration_approx(&config.profile, outer_loc, inner_loc);
 
@@ -191,6 +184,24 @@ fn get_dissimilarities(
         .collect()
 }
 
+/// Returns the longest shared time between two sets of time windows and the best ratio of shared
+/// time to the widest time window in the overlapping pair.
+fn get_shared_time(outer_times: &[TimeWindow], inner_times: &[TimeWindow]) -> (Duration, f64) {
+    outer_times
+        .iter()
+        .flat_map(|outer_time| {
+            inner_times.iter().filter_map(move |inner_time| {
+                outer_time.overlapping(inner_time).map(|tw| {
+                    let widest = outer_time.duration().max(inner_time.duration());
+                    let overlap = if widest > 0. { tw.duration() / widest } else { 1. };
+
+                    (tw.duration(), overlap)
+                })
+            })
+        })
+        .fold((0., 0.), |(shared_time, overlap), (time, ratio)| (shared_time.max(time), overlap.max(ratio)))
+}
+
 fn build_job_cluster(
     constraint: &ConstraintPipeline,
     center_job: &Job,
@@ -517,6 +528,8 @@ fn create_single_job(location: Option<Location>, duration: Duration, times: &[Ti
             location,
             duration,
             times: times.iter().map(|time| TimeSpan::Window(time.clone())).collect(),
+            setup: 0.,
+            lateness: None,
         }],
         dimens: dimens.clone(),
     }))
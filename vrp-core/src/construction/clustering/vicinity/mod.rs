@@ -71,6 +71,9 @@ pub struct ThresholdPolicy {
     pub moving_distance: Distance,
     /// Minimum shared time for jobs (non-inclusive).
     pub min_shared_time: Option<Duration>,
+    /// Minimum ratio (from 0 to 1) of shared time to the widest of two job time windows. When set,
+    /// jobs with narrow time windows are not clustered with jobs which have much wider ones.
+    pub min_time_window_overlap: Option<f64>,
     /// The smallest time window of the cluster after service time shrinking.
     pub smallest_time_window: Option<f64>,
     /// The maximum amount of jobs per cluster.
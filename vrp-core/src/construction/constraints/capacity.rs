@@ -8,6 +8,7 @@ use crate::construction::heuristics::*;
 use crate::models::common::*;
 use crate::models::problem::{Job, Single};
 use crate::models::solution::Activity;
+use hashbrown::HashMap;
 use std::iter::once;
 use std::slice::Iter;
 use std::sync::Arc;
@@ -31,7 +32,7 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
     pub fn new_with_multi_trip(code: i32, multi_trip: Arc<dyn MultiTrip<Constraint = T> + Send + Sync>) -> Self {
         Self {
             code,
-            state_keys: vec![CURRENT_CAPACITY_KEY, MAX_FUTURE_CAPACITY_KEY, MAX_PAST_CAPACITY_KEY],
+            state_keys: vec![CURRENT_CAPACITY_KEY, MAX_FUTURE_CAPACITY_KEY, MAX_PAST_CAPACITY_KEY, TRIP_CAPACITY_KEY],
             conditional: ConditionalJobModule::new(Box::new(ConcreteJobContextTransition {
                 remove_required: {
                     let multi_trip = multi_trip.clone();
@@ -54,6 +55,10 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
                     code,
                     multi_trip: multi_trip.clone(),
                 })),
+                ConstraintVariant::HardActivity(Arc::new(CompartmentHardActivityConstraint::<T> {
+                    code,
+                    multi_trip: multi_trip.clone(),
+                })),
             ],
             multi_trip,
         }
@@ -66,11 +71,21 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
             .get_marker_intervals(route_ctx)
             .cloned()
             .unwrap_or_else(|| vec![(0, route_ctx.route.tour.total() - 1)]);
+        let vehicle_capacity: Option<T> = route_ctx.route.actor.vehicle.dimens.get_capacity().cloned();
 
         let (_, max_load) =
             reload_intervals.into_iter().fold((T::default(), T::default()), |(acc, max), (start_idx, end_idx)| {
                 let (route, state) = route_ctx.as_mut();
 
+                // NOTE a trip which starts at multi trip marker can have its own capacity
+                let trip_capacity = route
+                    .tour
+                    .get(start_idx)
+                    .and_then(|activity| activity.job.as_ref())
+                    .and_then(|job| job.dimens.get_trip_capacity())
+                    .cloned()
+                    .or(vehicle_capacity);
+
                 // determine static deliveries loaded at the begin and static pickups brought to the end
                 let (start_delivery, end_pickup) = route.tour.activities_slice(start_idx, end_idx).iter().fold(
                     (acc, T::default()),
@@ -93,6 +108,9 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
 
                         state.put_activity_state(CURRENT_CAPACITY_KEY, activity, current);
                         state.put_activity_state(MAX_PAST_CAPACITY_KEY, activity, max);
+                        if let Some(trip_capacity) = trip_capacity {
+                            state.put_activity_state(TRIP_CAPACITY_KEY, activity, trip_capacity);
+                        }
 
                         (current, max)
                     },
@@ -173,7 +191,7 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
             CapacityConstraintModule::<T>::has_demand_violation(
                 &ctx.state,
                 activity,
-                ctx.route.actor.vehicle.dimens.get_capacity(),
+                CapacityConstraintModule::<T>::get_capacity(ctx, activity),
                 demand,
                 true,
             )
@@ -195,9 +213,38 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
             .unwrap_or_else(|| has_demand_violation(ctx.route.tour.get(insert_idx.unwrap_or(0)).unwrap()).is_none())
     }
 
+    /// Returns capacity of the trip which given activity belongs to, falls back to vehicle capacity.
+    fn get_capacity<'a>(ctx: &'a RouteContext, activity: &Activity) -> Option<&'a T> {
+        ctx.state
+            .get_activity_state(TRIP_CAPACITY_KEY, activity)
+            .or_else(|| ctx.route.actor.vehicle.dimens.get_capacity())
+    }
+
     fn get_demand(activity: &Activity) -> Option<&Demand<T>> {
         activity.job.as_ref().and_then(|job| job.dimens.get_demand())
     }
+
+    fn has_compartment_for(ctx: &RouteContext, job: &Job) -> bool {
+        let compartments: Option<&Vec<Compartment<T>>> = ctx.route.actor.vehicle.dimens.get_compartments();
+        let compartments = if let Some(compartments) = compartments {
+            compartments
+        } else {
+            return true;
+        };
+
+        let has_compartment = |single: &Arc<Single>| {
+            single
+                .dimens
+                .get_product_class()
+                .iter()
+                .all(|&class| compartments.iter().any(|compartment| compartment.classes.contains(class)))
+        };
+
+        match job {
+            Job::Single(single) => has_compartment(single),
+            Job::Multi(multi) => multi.jobs.iter().all(has_compartment),
+        }
+    }
 }
 
 impl<T: LoadOps> ConstraintModule for CapacityConstraintModule<T> {
@@ -225,6 +272,11 @@ impl<T: LoadOps> ConstraintModule for CapacityConstraintModule<T> {
         }
 
         match (&source, &candidate) {
+            (Job::Single(s_source), Job::Single(s_candidate))
+                if s_source.dimens.get_product_class() != s_candidate.dimens.get_product_class() =>
+            {
+                Err(self.code)
+            }
             (Job::Single(s_source), Job::Single(s_candidate)) => {
                 let source_demand: Option<&Demand<T>> = s_source.dimens.get_demand();
                 let candidate_demand: Option<&Demand<T>> = s_candidate.dimens.get_demand();
@@ -286,6 +338,10 @@ impl<T: LoadOps> HardRouteConstraint for CapacityHardRouteConstraint<T> {
             };
         };
 
+        if !CapacityConstraintModule::<T>::has_compartment_for(ctx, job) {
+            return Some(RouteConstraintViolation { code: self.code });
+        }
+
         let can_handle = match job {
             Job::Single(job) => CapacityConstraintModule::<T>::can_handle_demand_on_intervals(
                 ctx,
@@ -357,7 +413,7 @@ impl<T: LoadOps> HardActivityConstraint for CapacityHardActivityConstraint<T> {
             CapacityConstraintModule::<T>::has_demand_violation(
                 &route_ctx.state,
                 activity_ctx.prev,
-                route_ctx.route.actor.vehicle.dimens.get_capacity(),
+                CapacityConstraintModule::<T>::get_capacity(route_ctx, activity_ctx.prev),
                 demand,
                 !self.multi_trip.has_markers(route_ctx),
             )
@@ -366,3 +422,91 @@ impl<T: LoadOps> HardActivityConstraint for CapacityHardActivityConstraint<T> {
         violation.map(|stopped| ActivityConstraintViolation { code: self.code, stopped })
     }
 }
+
+/// Ensures that products of different classes never share the same vehicle compartment within one trip.
+struct CompartmentHardActivityConstraint<T: LoadOps> {
+    code: i32,
+    multi_trip: Arc<dyn MultiTrip<Constraint = T> + Send + Sync>,
+}
+
+impl<T: LoadOps> HardActivityConstraint for CompartmentHardActivityConstraint<T> {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let compartments: &Vec<Compartment<T>> = route_ctx.route.actor.vehicle.dimens.get_compartments()?;
+        activity_ctx.target.job.as_ref().and_then(|job| job.dimens.get_product_class())?;
+
+        let tour = &route_ctx.route.tour;
+        let is_marker = |activity: &Activity| {
+            activity.job.as_ref().is_some_and(|job| self.multi_trip.is_marker_job(&Job::Single(job.clone())))
+        };
+
+        // NOTE compartments can be reassigned to other product classes only at reload
+        let start_idx = (0..=activity_ctx.index).rev().find(|&idx| tour.get(idx).is_some_and(is_marker)).unwrap_or(0);
+        let end_idx = (activity_ctx.index + 1..tour.total()).find(|&idx| tour.get(idx).is_some_and(is_marker));
+        let trip = tour
+            .activities_slice(start_idx, activity_ctx.index)
+            .iter()
+            .chain(once(activity_ctx.target))
+            .chain(tour.all_activities().take(end_idx.unwrap_or(tour.total())).skip(activity_ctx.index + 1));
+
+        let loads = get_max_class_loads::<T>(trip);
+
+        if can_distribute_loads(compartments.as_slice(), loads.as_slice(), &mut vec![T::default(); loads.len()]) {
+            None
+        } else {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        }
+    }
+}
+
+/// Returns max load of each product class within the trip.
+fn get_max_class_loads<'a, T: LoadOps>(trip: impl Iterator<Item = &'a Activity> + Clone) -> Vec<(&'a String, T)> {
+    let demands = trip.filter_map(|activity| activity.job.as_ref()).filter_map(|job| {
+        let demand: Option<&Demand<T>> = job.dimens.get_demand();
+        job.dimens.get_product_class().zip(demand)
+    });
+
+    // NOTE static deliveries are loaded at the trip start
+    let mut loads = demands.clone().fold(HashMap::<&String, (T, T)>::default(), |mut acc, (class, demand)| {
+        let load = acc.entry(class).or_default();
+        load.0 = load.0 + demand.delivery.0;
+        load.1 = load.0;
+        acc
+    });
+
+    demands.for_each(|(class, demand)| {
+        let load = loads.get_mut(class).unwrap();
+        load.0 = load.0 + demand.change();
+        load.1 = load.1.max_load(load.0);
+    });
+
+    loads.into_iter().map(|(class, (_, max))| (class, max)).collect()
+}
+
+/// Checks whether compartments can be distributed between product classes to hold their loads.
+fn can_distribute_loads<T: LoadOps>(
+    compartments: &[Compartment<T>],
+    loads: &[(&String, T)],
+    assigned: &mut [T],
+) -> bool {
+    if loads.iter().zip(assigned.iter()).all(|((_, load), capacity)| capacity.can_fit(load)) {
+        return true;
+    }
+
+    match compartments.split_first() {
+        Some((compartment, rest)) => {
+            loads.iter().enumerate().filter(|(_, (class, _))| compartment.classes.contains(*class)).any(|(idx, _)| {
+                let original = assigned[idx];
+                assigned[idx] = original + compartment.capacity;
+                let result = can_distribute_loads(rest, loads, assigned);
+                assigned[idx] = original;
+
+                result
+            }) || can_distribute_loads(rest, loads, assigned)
+        }
+        None => false,
+    }
+}
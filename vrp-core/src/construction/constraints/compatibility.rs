@@ -0,0 +1,192 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/compatibility_test.rs"]
+mod compatibility_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::*;
+use crate::models::problem::Job;
+use std::ops::{Deref, Range};
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// Specifies a type for a function which returns job's compatibility class, if any.
+pub type CompatibilityFn = Arc<dyn Fn(&Job) -> Option<&String> + Send + Sync>;
+/// Specifies a type for a function which returns trip intervals of the route, if any.
+pub type CompatibilityIntervalFn = Arc<dyn Fn(&RouteContext) -> Option<&Vec<(usize, usize)>> + Send + Sync>;
+
+/// Specifies a scope in which jobs with different compatibility classes cannot be mixed.
+pub enum CompatibilityScope {
+    /// Jobs with different compatibility classes cannot be assigned to the same route.
+    Route,
+    /// Jobs with different compatibility classes cannot be assigned to the same trip, e.g. between
+    /// two reloads. Trips are defined by intervals of activity indices; when there are no intervals,
+    /// the whole route is considered as one trip.
+    Trip(CompatibilityIntervalFn),
+}
+
+/// A compatibility module provides the way to avoid assigning jobs with different compatibility
+/// classes in the same route or trip. Jobs without compatibility class can be mixed with any other.
+pub struct CompatibilityModule {
+    code: i32,
+    constraints: Vec<ConstraintVariant>,
+    state_key: i32,
+    keys: Vec<i32>,
+    compatibility_fn: CompatibilityFn,
+    interval_fn: Option<CompatibilityIntervalFn>,
+}
+
+impl CompatibilityModule {
+    /// Creates a new instance of `CompatibilityModule`.
+    pub fn new(code: i32, state_key: i32, compatibility_fn: CompatibilityFn, scope: CompatibilityScope) -> Self {
+        let (constraint, interval_fn) = match scope {
+            CompatibilityScope::Route => (
+                ConstraintVariant::HardRoute(Arc::new(CompatibilityHardRouteConstraint {
+                    code,
+                    state_key,
+                    compatibility_fn: compatibility_fn.clone(),
+                })),
+                None,
+            ),
+            CompatibilityScope::Trip(interval_fn) => (
+                ConstraintVariant::HardActivity(Arc::new(CompatibilityHardActivityConstraint {
+                    code,
+                    state_key,
+                    compatibility_fn: compatibility_fn.clone(),
+                    interval_fn: interval_fn.clone(),
+                })),
+                Some(interval_fn),
+            ),
+        };
+
+        Self { code, constraints: vec![constraint], state_key, keys: vec![state_key], compatibility_fn, interval_fn }
+    }
+
+    fn update_route_state(&self, route_ctx: &mut RouteContext) {
+        let new_compat = get_compatibility(route_ctx, 0..route_ctx.route.tour.total(), &self.compatibility_fn);
+        let current_compat = route_ctx.state.get_route_state::<Option<String>>(self.state_key);
+
+        match (new_compat, current_compat) {
+            (None, None) => {}
+            (None, Some(_)) => {
+                route_ctx.state_mut().put_route_state::<Option<String>>(self.state_key, None);
+            }
+            (value, None) | (value, Some(_)) => route_ctx.state_mut().put_route_state(self.state_key, value),
+        }
+    }
+
+    fn update_trip_state(&self, route_ctx: &mut RouteContext, interval_fn: &CompatibilityIntervalFn) {
+        get_intervals(route_ctx, interval_fn).into_iter().for_each(|(start_idx, end_idx)| {
+            let compat = get_compatibility(route_ctx, start_idx..end_idx + 1, &self.compatibility_fn);
+
+            let (route, state) = route_ctx.as_mut();
+            let activity = route.tour.get(start_idx).expect("cannot get activity by idx");
+            state.put_activity_state(self.state_key, activity, compat);
+        });
+    }
+}
+
+impl ConstraintModule for CompatibilityModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        if self.interval_fn.is_some() || self.compatibility_fn.deref()(job).is_some() {
+            self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap())
+        }
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        match self.interval_fn.as_ref() {
+            Some(interval_fn) => self.update_trip_state(ctx, interval_fn),
+            None => self.update_route_state(ctx),
+        }
+    }
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        match (self.compatibility_fn.deref()(&source), self.compatibility_fn.deref()(&candidate)) {
+            (None, None) => Ok(source),
+            (Some(s_compat), Some(c_compat)) if s_compat == c_compat => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct CompatibilityHardRouteConstraint {
+    code: i32,
+    state_key: i32,
+    compatibility_fn: CompatibilityFn,
+}
+
+impl HardRouteConstraint for CompatibilityHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        _: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        self.compatibility_fn.deref()(job).and_then(|job_compat| {
+            match route_ctx.state.get_route_state::<Option<String>>(self.state_key) {
+                None | Some(None) => None,
+                Some(Some(route_compat)) if job_compat == route_compat => None,
+                _ => Some(RouteConstraintViolation { code: self.code }),
+            }
+        })
+    }
+}
+
+struct CompatibilityHardActivityConstraint {
+    code: i32,
+    state_key: i32,
+    compatibility_fn: CompatibilityFn,
+    interval_fn: CompatibilityIntervalFn,
+}
+
+impl HardActivityConstraint for CompatibilityHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let job = activity_ctx.target.retrieve_job()?;
+        let job_compat = self.compatibility_fn.deref()(&job)?;
+
+        let start_idx = match self.interval_fn.deref()(route_ctx) {
+            Some(intervals) => intervals.iter().find(|(_, end_idx)| activity_ctx.index <= *end_idx)?.0,
+            None => 0,
+        };
+
+        let trip_compat = route_ctx
+            .route
+            .tour
+            .get(start_idx)
+            .and_then(|activity| route_ctx.state.get_activity_state::<Option<String>>(self.state_key, activity));
+
+        match trip_compat {
+            None | Some(None) => None,
+            Some(Some(trip_compat)) if job_compat == trip_compat => None,
+            _ => Some(ActivityConstraintViolation { code: self.code, stopped: false }),
+        }
+    }
+}
+
+fn get_intervals(route_ctx: &RouteContext, interval_fn: &CompatibilityIntervalFn) -> Vec<(usize, usize)> {
+    interval_fn.deref()(route_ctx).cloned().unwrap_or_else(|| vec![(0, route_ctx.route.tour.total() - 1)])
+}
+
+fn get_compatibility(
+    route_ctx: &RouteContext,
+    range: Range<usize>,
+    compatibility_fn: &CompatibilityFn,
+) -> Option<String> {
+    range
+        .filter_map(|idx| route_ctx.route.tour.get(idx))
+        .filter_map(|activity| activity.retrieve_job())
+        .find_map(|job| compatibility_fn.deref()(&job).cloned())
+}
@@ -0,0 +1,122 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/consecutive_driving_test.rs"]
+mod consecutive_driving_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::*;
+use crate::models::common::*;
+use crate::models::problem::*;
+use crate::models::solution::*;
+use std::ops::Deref;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which returns consecutive driving limits for given actor: max distance and max duration.
+pub type ConsecutiveDrivingResolver = Arc<dyn Fn(&Actor) -> (Option<Distance>, Option<Duration>) + Send + Sync>;
+
+/// Limits consecutive driving of the vehicle: distance and duration travelled between two stops.
+/// Any stop (job service, break, reload, etc.) resets the driving counter.
+pub struct ConsecutiveDrivingModule {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl ConsecutiveDrivingModule {
+    /// Creates a new instance of `ConsecutiveDrivingModule`.
+    pub fn new(
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        limit_func: ConsecutiveDrivingResolver,
+        code: i32,
+    ) -> Self {
+        Self {
+            state_keys: vec![],
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(ConsecutiveDrivingHardActivityConstraint {
+                code,
+                transport,
+                limit_func,
+            }))],
+        }
+    }
+}
+
+impl ConstraintModule for ConsecutiveDrivingModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct ConsecutiveDrivingHardActivityConstraint {
+    code: i32,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    limit_func: ConsecutiveDrivingResolver,
+}
+
+impl HardActivityConstraint for ConsecutiveDrivingHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let limits = self.limit_func.deref()(route_ctx.route.actor.as_ref());
+        if limits.0.is_none() && limits.1.is_none() {
+            return success();
+        }
+
+        let route = route_ctx.route.as_ref();
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        // NOTE insertion changes only driving counters of two legs: prev->target and target->next
+        let departure = prev.schedule.departure;
+        let (distance, duration) = self.get_leg_driving(route, prev, target, departure);
+        if !is_within_limits(limits, distance, duration) {
+            return stop(self.code);
+        }
+
+        if let Some(next) = activity_ctx.next {
+            let arrival = departure + duration;
+            let departure = arrival.max(target.place.time.start) + target.place.duration;
+
+            let (distance, duration) = self.get_leg_driving(route, target, next, departure);
+            if !is_within_limits(limits, distance, duration) {
+                return stop(self.code);
+            }
+        }
+
+        success()
+    }
+}
+
+impl ConsecutiveDrivingHardActivityConstraint {
+    fn get_leg_driving(
+        &self,
+        route: &Route,
+        from: &Activity,
+        to: &Activity,
+        departure: Timestamp,
+    ) -> (Distance, Duration) {
+        let (from, to, departure) = (from.place.location, to.place.location, TravelTime::Departure(departure));
+
+        (self.transport.distance(route, from, to, departure), self.transport.duration(route, from, to, departure))
+    }
+}
+
+fn is_within_limits(limits: (Option<Distance>, Option<Duration>), distance: Distance, duration: Duration) -> bool {
+    let (max_distance, max_duration) = limits;
+
+    max_distance.iter().all(|&max_distance| distance <= max_distance)
+        && max_duration.iter().all(|&max_duration| duration <= max_duration)
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/departure_shift_test.rs"]
+mod departure_shift_test;
+
+use crate::construction::constraints::*;
+use crate::construction::extensions::{advance_departure_time, recede_departure_time};
+use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::models::problem::{ActivityCost, Actor, Job, TransportCost};
+use std::ops::Deref;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// Specifies a function which checks whether departure time of actor's route should be shifted.
+pub type DepartureShiftFn = Arc<dyn Fn(&Actor) -> bool + Send + Sync>;
+
+/// A module which shifts route departure time forward to minimize waiting time while keeping all
+/// time windows feasible, instead of always starting at the earliest shift time. Departure time is
+/// reconsidered on each route update, so it has to follow the transport module in the pipeline.
+pub struct DepartureShiftModule {
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    shift_fn: DepartureShiftFn,
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl DepartureShiftModule {
+    /// Creates a new instance of `DepartureShiftModule`.
+    pub fn new(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        shift_fn: DepartureShiftFn,
+    ) -> Self {
+        Self { activity, transport, shift_fn, state_keys: vec![], constraints: vec![] }
+    }
+}
+
+impl ConstraintModule for DepartureShiftModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        if !self.shift_fn.deref()(ctx.route.actor.as_ref()) {
+            return;
+        }
+
+        let activity = self.activity.as_ref();
+        let transport = self.transport.as_ref();
+
+        // NOTE recede first: departure which was shifted before might be too late for the changed tour
+        recede_departure_time(ctx, activity, transport);
+        advance_departure_time(ctx, activity, transport, true);
+
+        TransportConstraintModule::update_statistics(ctx, transport);
+    }
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
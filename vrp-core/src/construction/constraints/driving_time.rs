@@ -0,0 +1,234 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/driving_time_test.rs"]
+mod driving_time_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::*;
+use crate::models::common::*;
+use crate::models::problem::*;
+use crate::models::solution::*;
+use std::slice::Iter;
+use std::sync::Arc;
+
+const DRIVING_RULES_DIMENSION_KEY: &str = "driving_rules";
+
+/// Specifies driving time rules of the vehicle, e.g. hours of service regulations: a break is
+/// required after given amount of cumulative driving and a rest is required after given amount
+/// of working time. Breaks and rests are taken automatically during travel once limits are reached.
+#[derive(Clone, Debug)]
+pub struct DrivingRules {
+    /// Max cumulative driving time since the last break or rest.
+    pub max_driving_time: Duration,
+    /// Duration of the break which resets driving time.
+    pub break_duration: Duration,
+    /// Max working time since route start or the last rest. Working time includes driving, serving,
+    /// waiting and breaks.
+    pub max_working_time: Option<Duration>,
+    /// Duration of the rest which resets both driving and working time.
+    pub rest_duration: Duration,
+}
+
+/// Keeps track of driving and working time since the last break or rest.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DrivingTime {
+    /// Driving time since the last break or rest.
+    pub driving: Duration,
+    /// Working time since the last rest.
+    pub working: Duration,
+}
+
+impl DrivingRules {
+    /// Returns driving time after travelling given duration started at given departure time and
+    /// a list of breaks and rests taken during the travel.
+    pub fn drive(&self, time: DrivingTime, departure: Timestamp, duration: Duration) -> (DrivingTime, Vec<TimeWindow>) {
+        let (mut time, mut current, mut remaining) = (time, departure, duration);
+        let mut breaks = vec![];
+
+        while remaining > 0. {
+            let until_rest = self.max_working_time.map_or(f64::MAX, |max_working_time| max_working_time - time.working);
+            let until_break = self.max_driving_time - time.driving;
+
+            if until_rest <= 0. {
+                breaks.push(TimeWindow::new(current, current + self.rest_duration));
+                current += self.rest_duration;
+                time = DrivingTime::default();
+            } else if until_break <= 0. {
+                breaks.push(TimeWindow::new(current, current + self.break_duration));
+                current += self.break_duration;
+                time = DrivingTime { driving: 0., working: time.working + self.break_duration };
+            } else {
+                let step = remaining.min(until_break).min(until_rest);
+                current += step;
+                remaining -= step;
+                time = DrivingTime { driving: time.driving + step, working: time.working + step };
+            }
+        }
+
+        (time, breaks)
+    }
+
+    /// Returns driving time after a stop of given duration: a long enough stop counts as a break or a rest.
+    pub fn stop(&self, time: DrivingTime, duration: Duration) -> DrivingTime {
+        if self.max_working_time.is_some() && duration >= self.rest_duration {
+            DrivingTime::default()
+        } else if duration >= self.break_duration {
+            DrivingTime { driving: 0., working: time.working + duration }
+        } else {
+            DrivingTime { driving: time.driving, working: time.working + duration }
+        }
+    }
+}
+
+/// A trait to get or set vehicle's driving rules.
+pub trait DrivingRulesDimension {
+    /// Sets driving rules.
+    fn set_driving_rules(&mut self, rules: DrivingRules) -> &mut Self;
+    /// Gets driving rules.
+    fn get_driving_rules(&self) -> Option<&DrivingRules>;
+}
+
+impl DrivingRulesDimension for Dimensions {
+    fn set_driving_rules(&mut self, rules: DrivingRules) -> &mut Self {
+        self.set_value(DRIVING_RULES_DIMENSION_KEY, rules);
+        self
+    }
+
+    fn get_driving_rules(&self) -> Option<&DrivingRules> {
+        self.get_value(DRIVING_RULES_DIMENSION_KEY)
+    }
+}
+
+/// Enforces driving rules specified on vehicle dimensions. Breaks and rests are inserted into the
+/// route schedule by `TransportConstraintModule`, this module checks that delay caused by them does
+/// not violate time windows of the affected activities.
+pub struct DrivingTimeModule {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl DrivingTimeModule {
+    /// Creates a new instance of `DrivingTimeModule`.
+    pub fn new(
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        code: i32,
+    ) -> Self {
+        Self {
+            state_keys: vec![],
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(DrivingTimeHardActivityConstraint {
+                code,
+                transport,
+                activity,
+            }))],
+        }
+    }
+}
+
+impl ConstraintModule for DrivingTimeModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct DrivingTimeHardActivityConstraint {
+    code: i32,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+}
+
+impl HardActivityConstraint for DrivingTimeHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let route = route_ctx.route.as_ref();
+        let rules = route.actor.vehicle.dimens.get_driving_rules()?;
+
+        let prev = activity_ctx.prev;
+        let time =
+            route_ctx.state.get_activity_state::<DrivingTime>(DRIVING_TIME_KEY, prev).cloned().unwrap_or_default();
+        let init = (prev.place.location, prev.schedule.departure, time);
+
+        let target = match self.visit(route, rules, init, activity_ctx.target) {
+            Some(target) => target,
+            None => return stop(self.code),
+        };
+
+        // NOTE delay caused by breaks is propagated till the end of the route unless the schedule
+        // of the next activities stays the same or becomes earlier
+        let result = activity_ctx
+            .next
+            .into_iter()
+            .chain(route.tour.all_activities().skip(activity_ctx.index + 2))
+            .try_fold(target, |acc, activity| {
+                let (location, departure, time) = self.visit(route, rules, acc, activity).ok_or(false)?;
+
+                let old_time = route_ctx.state.get_activity_state::<DrivingTime>(DRIVING_TIME_KEY, activity);
+                let is_dominated = old_time.is_some_and(|old_time| {
+                    departure <= activity.schedule.departure
+                        && time.driving <= old_time.driving
+                        && time.working <= old_time.working
+                });
+
+                if is_dominated {
+                    Err(true)
+                } else {
+                    Ok((location, departure, time))
+                }
+            });
+
+        let is_feasible = match result {
+            Ok(_) => true,
+            Err(is_dominated) => is_dominated,
+        };
+
+        if is_feasible {
+            success()
+        } else {
+            stop(self.code)
+        }
+    }
+}
+
+impl DrivingTimeHardActivityConstraint {
+    /// Returns location, departure and driving time after visiting given activity if its time
+    /// window is respected.
+    fn visit(
+        &self,
+        route: &Route,
+        rules: &DrivingRules,
+        acc: (Location, Timestamp, DrivingTime),
+        activity: &Activity,
+    ) -> Option<(Location, Timestamp, DrivingTime)> {
+        let (location, departure, time) = acc;
+
+        let duration =
+            self.transport.duration(route, location, activity.place.location, TravelTime::Departure(departure));
+        let (time, breaks) = rules.drive(time, departure, duration);
+        let arrival = departure + duration + breaks.iter().map(|time| time.duration()).sum::<Duration>();
+        let ready = arrival + activity.place.setup_from(location);
+
+        if ready > activity.place.latest_start() {
+            return None;
+        }
+
+        let activity_departure = self.activity.estimate_departure(route, activity, ready);
+
+        Some((activity.place.location, activity_departure, rules.stop(time, activity_departure - arrival)))
+    }
+}
@@ -1,11 +1,15 @@
 use crate::construction::constraints::{ConstraintModule, ConstraintVariant, SoftRouteConstraint};
 use crate::construction::heuristics::{RouteContext, SolutionContext};
 use crate::models::common::Cost;
-use crate::models::problem::Job;
+use crate::models::problem::{Actor, Job};
 use std::ops::Deref;
 use std::slice::Iter;
 use std::sync::Arc;
 
+/// An extra cost of using a new vehicle. It is large enough to dominate any travel or service cost of a
+/// single route, so fleet size is minimized (or maximized) before other costs are considered.
+const VEHICLE_USAGE_COST: Cost = 1E12;
+
 /// A module which controls fleet size usage.
 pub struct FleetUsageConstraintModule {
     state_keys: Vec<i32>,
@@ -35,12 +39,12 @@ impl ConstraintModule for FleetUsageConstraintModule {
 impl FleetUsageConstraintModule {
     /// Creates `FleetUsageConstraintModule` to minimize used fleet size.
     pub fn new_minimized() -> Self {
-        Self::new_with_cost(Box::new(|_| 1E12))
+        Self::new_with_cost(Box::new(|_| VEHICLE_USAGE_COST))
     }
 
     /// Creates `FleetUsageConstraintModule` to maximize used fleet size.
     pub fn new_maximized() -> Self {
-        Self::new_with_cost(Box::new(|_| -1E12))
+        Self::new_with_cost(Box::new(|_| -VEHICLE_USAGE_COST))
     }
 
     /// Creates `FleetUsageConstraintModule` to minimize total arrival time.
@@ -51,6 +55,14 @@ impl FleetUsageConstraintModule {
         }))
     }
 
+    /// Creates `FleetUsageConstraintModule` to minimize used fleet size preferring vehicles of lower tier:
+    /// usage cost of a vehicle grows linearly with its tier.
+    pub fn new_tiered(tier_fn: Arc<dyn Fn(&Actor) -> usize + Send + Sync>) -> Self {
+        Self::new_with_cost(Box::new(move |route_ctx| {
+            VEHICLE_USAGE_COST * (tier_fn.deref()(route_ctx.route.actor.as_ref()) + 1) as f64
+        }))
+    }
+
     fn new_with_cost(extra_cost_fn: Box<dyn Fn(&RouteContext) -> Cost + Send + Sync>) -> Self {
         Self {
             state_keys: vec![],
@@ -4,14 +4,20 @@ mod locking_test;
 
 use crate::construction::constraints::*;
 use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::common::Cost;
 use crate::models::problem::{Actor, Fleet, Job};
 use crate::models::{Lock, LockOrder, LockPosition};
 use hashbrown::{HashMap, HashSet};
+use std::ops::Deref;
 use std::slice::Iter;
 use std::sync::Arc;
 
 type ConditionMap = HashMap<Job, Arc<dyn Fn(&Actor) -> bool + Sync + Send>>;
 
+/// Specifies a function which returns a penalty of serving job by given actor when the job is pinned
+/// to other actors. Returns `None` when job is not pinned or can be served by the actor.
+pub type PinningPenaltyFn = Arc<dyn Fn(&Actor, &Job) -> Option<Cost> + Send + Sync>;
+
 /// A module which allows to lock specific actors within specific jobs using different rules.
 pub struct StrictLockingModule {
     code: i32,
@@ -92,6 +98,109 @@ impl StrictLockingModule {
     }
 }
 
+/// A module which pins jobs to specific actors in a hybrid hard/soft way: pinned jobs must be
+/// served by their actors unless no feasible assignment exists. A pinned job which failed to be
+/// assigned is relaxed and can be served by other actors with a penalty.
+pub struct PinnedLockingModule {
+    state_key: i32,
+    penalty_fn: PinningPenaltyFn,
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl PinnedLockingModule {
+    /// Creates an instance of `PinnedLockingModule`.
+    pub fn new(code: i32, state_key: i32, penalty_fn: PinningPenaltyFn) -> Self {
+        Self {
+            state_key,
+            penalty_fn: penalty_fn.clone(),
+            state_keys: vec![state_key],
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(PinnedLockingHardRouteConstraint {
+                    code,
+                    state_key,
+                    penalty_fn: penalty_fn.clone(),
+                })),
+                ConstraintVariant::SoftRoute(Arc::new(PinnedLockingSoftRouteConstraint { penalty_fn })),
+            ],
+        }
+    }
+}
+
+impl ConstraintModule for PinnedLockingModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        // NOTE jobs which failed to be assigned or already served by other actors are relaxed
+        let relaxed = ctx
+            .unassigned
+            .keys()
+            .cloned()
+            .chain(ctx.routes.iter().flat_map(|route_ctx| {
+                route_ctx
+                    .route
+                    .tour
+                    .jobs()
+                    .filter(|job| self.penalty_fn.deref()(&route_ctx.route.actor, job).is_some())
+                    .collect::<Vec<_>>()
+            }))
+            .collect::<HashSet<_>>();
+
+        ctx.state.insert(self.state_key, Arc::new(relaxed));
+    }
+
+    fn merge(&self, source: Job, _candidate: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct PinnedLockingHardRouteConstraint {
+    code: i32,
+    state_key: i32,
+    penalty_fn: PinningPenaltyFn,
+}
+
+impl HardRouteConstraint for PinnedLockingHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        let is_relaxed = solution_ctx
+            .state
+            .get(&self.state_key)
+            .and_then(|relaxed| relaxed.downcast_ref::<HashSet<Job>>())
+            .is_some_and(|relaxed| relaxed.contains(job));
+
+        if !is_relaxed && self.penalty_fn.deref()(&ctx.route.actor, job).is_some() {
+            Some(RouteConstraintViolation { code: self.code })
+        } else {
+            None
+        }
+    }
+}
+
+struct PinnedLockingSoftRouteConstraint {
+    penalty_fn: PinningPenaltyFn,
+}
+
+impl SoftRouteConstraint for PinnedLockingSoftRouteConstraint {
+    fn estimate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Cost {
+        self.penalty_fn.deref()(&ctx.route.actor, job).unwrap_or(0.)
+    }
+}
+
 struct StrictLockingHardRouteConstraint {
     code: i32,
     conditions: ConditionMap,
@@ -65,6 +65,16 @@ pub const TOTAL_DISTANCE_KEY: i32 = 3;
 pub const TOTAL_DURATION_KEY: i32 = 4;
 /// A key which tracks global duration limit.
 pub const LIMIT_DURATION_KEY: i32 = 5;
+/// A key which tracks total lateness penalty of activities with soft time windows.
+pub const TOTAL_LATENESS_COST_KEY: i32 = 6;
+/// A key which tracks global distance limit.
+pub const LIMIT_DISTANCE_KEY: i32 = 7;
+/// A key which tracks driving and working time since the last break or rest.
+pub const DRIVING_TIME_KEY: i32 = 8;
+/// A key which tracks total overtime cost of the route.
+pub const TOTAL_OVERTIME_COST_KEY: i32 = 9;
+/// A key which tracks total waiting time of the route.
+pub const TOTAL_WAITING_KEY: i32 = 10;
 
 /// A key which tracks current vehicle capacity.
 pub const CURRENT_CAPACITY_KEY: i32 = 11;
@@ -76,6 +86,8 @@ pub const MAX_PAST_CAPACITY_KEY: i32 = 13;
 pub const RELOAD_INTERVALS_KEY: i32 = 14;
 /// A key which tracks max load in tour.
 pub const MAX_LOAD_KEY: i32 = 15;
+/// A key which tracks effective vehicle capacity within the trip.
+pub const TRIP_CAPACITY_KEY: i32 = 16;
 
 #[allow(clippy::unnecessary_wraps)]
 fn fail(code: i32) -> Option<ActivityConstraintViolation> {
@@ -95,9 +107,18 @@ fn success() -> Option<ActivityConstraintViolation> {
 mod pipeline;
 pub use self::pipeline::*;
 
+mod overlay;
+pub use self::overlay::*;
+
 mod transport;
 pub use self::transport::*;
 
+mod departure_shift;
+pub use self::departure_shift::*;
+
+mod overtime;
+pub use self::overtime::*;
+
 mod capacity;
 pub use self::capacity::*;
 
@@ -107,6 +128,9 @@ pub use self::locking::*;
 mod shared_resource;
 pub use self::shared_resource::*;
 
+mod compatibility;
+pub use self::compatibility::*;
+
 mod tour_size;
 pub use self::tour_size::*;
 
@@ -118,3 +142,9 @@ pub use self::fleet_usage::*;
 
 mod travel_limit;
 pub use self::travel_limit::*;
+
+mod consecutive_driving;
+pub use self::consecutive_driving::*;
+
+mod driving_time;
+pub use self::driving_time::*;
@@ -0,0 +1,186 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/overlay_test.rs"]
+mod overlay_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::common::Cost;
+use crate::models::problem::{Job, TargetConstraint};
+use hashbrown::HashMap;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// Specifies how a violation of hard constraint is treated by constraint overlay.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConstraintOverride {
+    /// A violation is ignored.
+    Disable,
+    /// A violation is allowed, but insertion is penalized with given cost.
+    Soften(Cost),
+}
+
+/// A constraint overlay changes behavior of hard constraints of existing pipeline without rebuilding
+/// the problem, e.g. to ignore skills for a what-if solver run. Hard constraints are referenced by
+/// their violation codes. Constraint modules keep maintaining their states, so soft constraints and
+/// objectives are not affected.
+#[derive(Clone, Default)]
+pub struct ConstraintOverlay {
+    overrides: HashMap<i32, ConstraintOverride>,
+}
+
+impl ConstraintOverlay {
+    /// Disables hard constraint violations with given code.
+    pub fn with_disabled(mut self, code: i32) -> Self {
+        self.overrides.insert(code, ConstraintOverride::Disable);
+        self
+    }
+
+    /// Turns hard constraint violations with given code into a penalty.
+    pub fn with_softened(mut self, code: i32, penalty: Cost) -> Self {
+        self.overrides.insert(code, ConstraintOverride::Soften(penalty));
+        self
+    }
+
+    /// Returns true if overlay has no overrides.
+    pub fn is_empty(&self) -> bool {
+        self.overrides.is_empty()
+    }
+
+    /// Creates a new pipeline which shares modules and constraints with the original one, but
+    /// treats violations of hard constraints according to overrides.
+    pub fn apply(&self, pipeline: &ConstraintPipeline) -> ConstraintPipeline {
+        let overrides = Arc::new(self.overrides.clone());
+
+        pipeline.modules.iter().fold(ConstraintPipeline::default(), |mut result, module| {
+            result.add_module(Arc::new(OverlayModule {
+                inner: module.clone(),
+                overrides: overrides.clone(),
+                constraints: wrap_constraints(module.get_constraints(), overrides.clone()),
+            }));
+            result
+        })
+    }
+}
+
+struct OverlayModule {
+    inner: TargetConstraint,
+    overrides: Arc<HashMap<i32, ConstraintOverride>>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl ConstraintModule for OverlayModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        self.inner.accept_insertion(solution_ctx, route_index, job)
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        self.inner.accept_route_state(ctx)
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        self.inner.accept_solution_state(ctx)
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        match self.inner.merge(source.clone(), candidate) {
+            Err(code) if self.overrides.contains_key(&code) => Ok(source),
+            result => result,
+        }
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.inner.state_keys()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Wraps hard route constraint: acts as a hard constraint which ignores overridden violations and
+/// as a soft constraint which penalizes softened ones.
+struct OverlayRouteConstraint {
+    inner: Arc<dyn HardRouteConstraint + Send + Sync>,
+    overrides: Arc<HashMap<i32, ConstraintOverride>>,
+}
+
+impl HardRouteConstraint for OverlayRouteConstraint {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        self.inner
+            .evaluate_job(solution_ctx, ctx, job)
+            .filter(|violation| !self.overrides.contains_key(&violation.code))
+    }
+}
+
+impl SoftRouteConstraint for OverlayRouteConstraint {
+    fn estimate_job(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext, job: &Job) -> Cost {
+        self.inner
+            .evaluate_job(solution_ctx, route_ctx, job)
+            .map_or(0., |violation| get_penalty(self.overrides.as_ref(), violation.code))
+    }
+}
+
+/// Wraps hard activity constraint the same way as `OverlayRouteConstraint` does.
+struct OverlayActivityConstraint {
+    inner: Arc<dyn HardActivityConstraint + Send + Sync>,
+    overrides: Arc<HashMap<i32, ConstraintOverride>>,
+}
+
+impl HardActivityConstraint for OverlayActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        self.inner
+            .evaluate_activity(route_ctx, activity_ctx)
+            .filter(|violation| !self.overrides.contains_key(&violation.code))
+    }
+}
+
+impl SoftActivityConstraint for OverlayActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        self.inner
+            .evaluate_activity(route_ctx, activity_ctx)
+            .map_or(0., |violation| get_penalty(self.overrides.as_ref(), violation.code))
+    }
+}
+
+fn wrap_constraints(
+    constraints: Iter<ConstraintVariant>,
+    overrides: Arc<HashMap<i32, ConstraintOverride>>,
+) -> Vec<ConstraintVariant> {
+    let has_soften = overrides.values().any(|value| matches!(value, ConstraintOverride::Soften(_)));
+
+    constraints
+        .cloned()
+        .flat_map(|constraint| match constraint {
+            ConstraintVariant::HardRoute(inner) => {
+                let hard = Arc::new(OverlayRouteConstraint { inner, overrides: overrides.clone() });
+                let soft = Some(ConstraintVariant::SoftRoute(hard.clone())).filter(|_| has_soften);
+
+                vec![Some(ConstraintVariant::HardRoute(hard)), soft]
+            }
+            ConstraintVariant::HardActivity(inner) => {
+                let hard = Arc::new(OverlayActivityConstraint { inner, overrides: overrides.clone() });
+                let soft = Some(ConstraintVariant::SoftActivity(hard.clone())).filter(|_| has_soften);
+
+                vec![Some(ConstraintVariant::HardActivity(hard)), soft]
+            }
+            constraint => vec![Some(constraint)],
+        })
+        .flatten()
+        .collect()
+}
+
+fn get_penalty(overrides: &HashMap<i32, ConstraintOverride>, code: i32) -> Cost {
+    match overrides.get(&code) {
+        Some(ConstraintOverride::Soften(penalty)) => *penalty,
+        _ => 0.,
+    }
+}
@@ -0,0 +1,148 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/overtime_test.rs"]
+mod overtime_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::*;
+use crate::models::common::*;
+use crate::models::problem::*;
+use crate::models::solution::*;
+use std::slice::Iter;
+use std::sync::Arc;
+
+const SHIFT_OVERTIME_DIMENSION_KEY: &str = "shift_overtime";
+
+/// Specifies shift overtime: vehicle is allowed to finish its tour after regular shift end at extra
+/// cost. The hard limit is defined by actor's end time which is expected to include max overtime.
+#[derive(Clone, Debug)]
+pub struct ShiftOvertime {
+    /// A regular shift end time.
+    pub regular_end: Timestamp,
+    /// A cost per time unit of overtime.
+    pub cost_per_time: Cost,
+}
+
+impl ShiftOvertime {
+    /// Estimates overtime cost for given tour end time.
+    pub fn estimate(&self, end: Timestamp) -> Cost {
+        (end - self.regular_end).max(0.) * self.cost_per_time
+    }
+}
+
+/// A trait to get or set vehicle's shift overtime.
+pub trait ShiftOvertimeDimension {
+    /// Sets shift overtime.
+    fn set_shift_overtime(&mut self, overtime: ShiftOvertime) -> &mut Self;
+    /// Gets shift overtime.
+    fn get_shift_overtime(&self) -> Option<&ShiftOvertime>;
+}
+
+impl ShiftOvertimeDimension for Dimensions {
+    fn set_shift_overtime(&mut self, overtime: ShiftOvertime) -> &mut Self {
+        self.set_value(SHIFT_OVERTIME_DIMENSION_KEY, overtime);
+        self
+    }
+
+    fn get_shift_overtime(&self) -> Option<&ShiftOvertime> {
+        self.get_value(SHIFT_OVERTIME_DIMENSION_KEY)
+    }
+}
+
+/// A module which penalizes vehicle's work after regular shift end using shift overtime specified on
+/// vehicle dimensions. Overtime cost is kept in route state and included into the route cost.
+pub struct OvertimeModule {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl OvertimeModule {
+    /// Creates a new instance of `OvertimeModule`.
+    pub fn new(activity: Arc<dyn ActivityCost + Send + Sync>, transport: Arc<dyn TransportCost + Send + Sync>) -> Self {
+        Self {
+            state_keys: vec![TOTAL_OVERTIME_COST_KEY],
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(OvertimeSoftActivityConstraint {
+                activity,
+                transport,
+            }))],
+        }
+    }
+}
+
+impl ConstraintModule for OvertimeModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        if let Some(overtime) = ctx.route.actor.vehicle.dimens.get_shift_overtime() {
+            let cost = overtime.estimate(get_tour_end(&ctx.route));
+            ctx.state_mut().put_route_state(TOTAL_OVERTIME_COST_KEY, cost);
+        }
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        })
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Estimates overtime cost change caused by activity insertion.
+struct OvertimeSoftActivityConstraint {
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl OvertimeSoftActivityConstraint {
+    fn get_arrival(&self, route: &Route, from: &Activity, to: &Activity, departure: Timestamp) -> Timestamp {
+        departure
+            + self.transport.duration(route, from.place.location, to.place.location, TravelTime::Departure(departure))
+            + to.place.setup_from(from.place.location)
+    }
+}
+
+impl SoftActivityConstraint for OvertimeSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        let route = route_ctx.route.as_ref();
+        let overtime = if let Some(overtime) = route.actor.vehicle.dimens.get_shift_overtime() {
+            overtime
+        } else {
+            return 0.;
+        };
+
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let arrival_at_target = self.get_arrival(route, prev, target, prev.schedule.departure);
+        let departure_at_target = self.activity.estimate_departure(route, target, arrival_at_target);
+
+        let old_end = get_tour_end(route);
+        let new_end = if let Some(next) = activity_ctx.next {
+            let old_arrival = next.schedule.arrival + next.place.setup_from(prev.place.location);
+            let new_arrival = self.get_arrival(route, target, next, departure_at_target);
+            // NOTE delay can be absorbed by waiting time in the rest of the tour
+            let waiting = route_ctx.state.get_activity_state::<f64>(WAITING_KEY, next).cloned().unwrap_or(0.);
+
+            old_end + (new_arrival - old_arrival - waiting).max(0.)
+        } else {
+            departure_at_target
+        };
+
+        overtime.estimate(new_end) - overtime.estimate(old_end)
+    }
+}
+
+fn get_tour_end(route: &Route) -> Timestamp {
+    route.tour.end().map_or(0., |end| end.schedule.departure)
+}
@@ -2,7 +2,7 @@
 #[path = "../../../tests/unit/construction/constraints/pipeline_test.rs"]
 mod pipeline_test;
 
-use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::construction::heuristics::{state_flags, ActivityContext, RouteContext, SolutionContext};
 use crate::models::common::Cost;
 use crate::models::problem::{Job, TargetConstraint};
 use hashbrown::HashSet;
@@ -133,12 +133,15 @@ impl ConstraintPipeline {
         let activities = solution_ctx.routes.get_mut(route_index).unwrap().route.tour.job_activity_count();
         self.modules.iter().for_each(|c| c.accept_insertion(solution_ctx, route_index, job));
         assert_eq!(activities, solution_ctx.routes.get_mut(route_index).unwrap().route.tour.job_activity_count());
+
+        self.accept_rescheduled_routes(solution_ctx);
     }
 
     /// Accepts route state.
     pub fn accept_route_state(&self, route_ctx: &mut RouteContext) {
         if route_ctx.is_stale() {
             route_ctx.state_mut().clear();
+            route_ctx.state_mut().unset_flag(state_flags::RESCHEDULED);
 
             let activities = route_ctx.route.tour.job_activity_count();
             self.modules.iter().for_each(|c| c.accept_route_state(route_ctx));
@@ -184,11 +187,28 @@ impl ConstraintPipeline {
             }
         });
 
+        self.accept_rescheduled_routes(solution_ctx);
+
         solution_ctx.routes.iter_mut().for_each(|route_ctx| {
             route_ctx.mark_stale(false);
         })
     }
 
+    /// Recalculates route state of all modules for the routes which activities were moved by some
+    /// module, e.g. when their time windows are narrowed. Route state is not cleared as it can keep
+    /// values set on solution level.
+    fn accept_rescheduled_routes(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.state.has_flag(state_flags::RESCHEDULED)).for_each(
+            |route_ctx| {
+                route_ctx.state_mut().unset_flag(state_flags::RESCHEDULED);
+
+                let activities = route_ctx.route.tour.job_activity_count();
+                self.modules.iter().for_each(|c| c.accept_route_state(route_ctx));
+                assert_eq!(activities, route_ctx.route.tour.job_activity_count());
+            },
+        );
+    }
+
     /// Tries to merge two jobs taking into account common constraints.
     /// Returns a new job, if it is possible to merge them together having theoretically assignable
     /// job. Otherwise returns violation error code.
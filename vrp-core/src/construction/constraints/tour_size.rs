@@ -3,7 +3,7 @@
 mod tour_size_test;
 
 use crate::construction::constraints::*;
-use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::construction::heuristics::{RouteContext, SolutionContext, UnassignmentInfo};
 use crate::models::problem::{Actor, Job};
 use std::ops::Deref;
 use std::slice::Iter;
@@ -12,20 +12,61 @@ use std::sync::Arc;
 /// A function which returns tour size limit for given actor.
 pub type TourSizeResolver = Arc<dyn Fn(&Actor) -> Option<usize> + Sync + Send>;
 
-/// Limits amount of job activities per tour.
+/// Limits amount of job activities per tour. A max limit is checked on insertion, a min limit is
+/// enforced once all required jobs are processed: jobs of a tour below the limit are unassigned.
 pub struct TourSizeModule {
+    code: i32,
+    min_limit_func: TourSizeResolver,
     state_keys: Vec<i32>,
     constraints: Vec<ConstraintVariant>,
 }
 
 impl TourSizeModule {
     /// Creates a new instance of `TourSizeModule`.
-    pub fn new(limit_func: TourSizeResolver, code: i32) -> Self {
+    pub fn new(limit_func: TourSizeResolver, min_limit_func: TourSizeResolver, code: i32) -> Self {
         Self {
+            code,
+            min_limit_func,
             constraints: vec![ConstraintVariant::HardRoute(Arc::new(TourSizeHardRouteConstraint { code, limit_func }))],
             state_keys: vec![],
         }
     }
+
+    fn eject_small_tours(&self, solution_ctx: &mut SolutionContext) {
+        let locked = &solution_ctx.locked;
+        let min_limit_func = self.min_limit_func.deref();
+
+        let mut ejected = Vec::new();
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            let is_small = min_limit_func(route_ctx.route.actor.as_ref())
+                .is_some_and(|limit| route_ctx.route.tour.job_activity_count() < limit);
+
+            if is_small && route_ctx.route.tour.jobs().all(|job| !locked.contains(&job)) {
+                let jobs = route_ctx.route.tour.jobs().collect::<Vec<_>>();
+                let tour = &mut route_ctx.route_mut().tour;
+                jobs.iter().for_each(|job| {
+                    tour.remove(job);
+                });
+                ejected.extend(jobs);
+            }
+        });
+
+        if ejected.is_empty() {
+            return;
+        }
+
+        let registry = &mut solution_ctx.registry;
+        solution_ctx.routes.retain(|route_ctx| {
+            if route_ctx.route.tour.has_jobs() {
+                true
+            } else {
+                registry.free_route(route_ctx);
+                false
+            }
+        });
+
+        solution_ctx.unassigned.extend(ejected.into_iter().map(|job| (job, UnassignmentInfo::Simple(self.code))));
+    }
 }
 
 impl ConstraintModule for TourSizeModule {
@@ -33,7 +74,12 @@ impl ConstraintModule for TourSizeModule {
 
     fn accept_route_state(&self, _: &mut RouteContext) {}
 
-    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        // NOTE a small tour can grow while there are required jobs to be inserted
+        if ctx.required.is_empty() {
+            self.eject_small_tours(ctx);
+        }
+    }
 
     fn merge(&self, source: Job, _candidate: Job) -> Result<Job, i32> {
         Ok(source)
@@ -4,10 +4,12 @@ mod transport_test;
 
 use crate::construction::constraints::*;
 use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
-use crate::models::common::{Cost, Distance, Timestamp};
+use crate::models::common::{Cost, Distance, Duration, TimeSpan, TimeWindow, Timestamp};
 use crate::models::problem::{ActivityCost, Job, Single, TransportCost, TravelTime};
 use crate::models::solution::Activity;
 use crate::models::OP_START_MSG;
+use rosomaxa::prelude::compare_floats;
+use std::cmp::Ordering;
 use std::slice::Iter;
 use std::sync::Arc;
 
@@ -70,7 +72,15 @@ impl TransportConstraintModule {
         time_window_code: i32,
     ) -> Self {
         Self {
-            state_keys: vec![LATEST_ARRIVAL_KEY, WAITING_KEY, TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY],
+            state_keys: vec![
+                LATEST_ARRIVAL_KEY,
+                WAITING_KEY,
+                TOTAL_DISTANCE_KEY,
+                TOTAL_DURATION_KEY,
+                TOTAL_LATENESS_COST_KEY,
+                TOTAL_WAITING_KEY,
+                DRIVING_TIME_KEY,
+            ],
             constraints: vec![
                 ConstraintVariant::HardRoute(Arc::new(TimeHardRouteConstraint { code: time_window_code })),
                 ConstraintVariant::SoftRoute(Arc::new(RouteCostSoftRouteConstraint {})),
@@ -94,6 +104,11 @@ impl TransportConstraintModule {
         activity: &(dyn ActivityCost + Send + Sync),
         transport: &(dyn TransportCost + Send + Sync),
     ) {
+        if let Some(rules) = route_ctx.route.actor.vehicle.dimens.get_driving_rules().cloned() {
+            Self::update_route_schedules_with_breaks(route_ctx, activity, transport, &rules);
+            return;
+        }
+
         let init = {
             let start = route_ctx.route.tour.start().unwrap();
             (start.place.location, start.schedule.departure)
@@ -103,12 +118,41 @@ impl TransportConstraintModule {
 
         route_ctx.route_mut().tour.all_activities_mut().skip(1).fold(init, |(loc, dep), a| {
             a.schedule.arrival = dep + transport.duration(&route, loc, a.place.location, TravelTime::Departure(dep));
-            a.schedule.departure = activity.estimate_departure(&route, a, a.schedule.arrival);
+            a.schedule.departure = activity.estimate_departure(&route, a, a.schedule.arrival + a.place.setup_from(loc));
 
             (a.place.location, a.schedule.departure)
         });
     }
 
+    /// Updates route schedules taking into account breaks and rests required by driving rules.
+    fn update_route_schedules_with_breaks(
+        route_ctx: &mut RouteContext,
+        activity: &(dyn ActivityCost + Send + Sync),
+        transport: &(dyn TransportCost + Send + Sync),
+        rules: &DrivingRules,
+    ) {
+        let route = route_ctx.route.clone();
+        let (route_mut, state) = route_ctx.as_mut();
+
+        let mut activities = route_mut.tour.all_activities_mut();
+        let start = activities.next().unwrap();
+        state.put_activity_state(DRIVING_TIME_KEY, start, DrivingTime::default());
+
+        let init = (start.place.location, start.schedule.departure, DrivingTime::default());
+        activities.fold(init, |(loc, dep, time), a| {
+            let duration = transport.duration(&route, loc, a.place.location, TravelTime::Departure(dep));
+            let (time, breaks) = rules.drive(time, dep, duration);
+
+            a.schedule.arrival = dep + duration + breaks.iter().map(|time| time.duration()).sum::<f64>();
+            a.schedule.departure = activity.estimate_departure(&route, a, a.schedule.arrival + a.place.setup_from(loc));
+
+            let time = rules.stop(time, a.schedule.departure - a.schedule.arrival);
+            state.put_activity_state(DRIVING_TIME_KEY, a, time);
+
+            (a.place.location, a.schedule.departure, time)
+        });
+    }
+
     fn update_route_states(
         route_ctx: &mut RouteContext,
         activity: &(dyn ActivityCost + Send + Sync),
@@ -125,30 +169,35 @@ impl TransportConstraintModule {
                 .unwrap_or_else(|| actor.detail.start.as_ref().unwrap_or_else(|| panic!("{}", OP_START_MSG)))
                 .location,
             0_f64,
+            0_f64,
         );
 
         let route = route_ctx.route.clone();
         let (route_mut, state) = route_ctx.as_mut();
 
+        // NOTE latest arrival is the latest time when activity can be started after setup, so
+        // setup of the next activity is subtracted from the latest departure
         route_mut.tour.all_activities().rev().fold(init, |acc, act| {
             if act.job.is_none() {
                 return acc;
             }
 
-            let (end_time, prev_loc, waiting) = acc;
-            let latest_departure =
-                end_time - transport.duration(&route, act.place.location, prev_loc, TravelTime::Arrival(end_time));
+            let (end_time, prev_loc, prev_setup, waiting) = acc;
+            let setup = if act.place.location == prev_loc { 0. } else { prev_setup };
+            let latest_departure = end_time
+                - setup
+                - transport.duration(&route, act.place.location, prev_loc, TravelTime::Arrival(end_time));
             let latest_arrival_time = activity.estimate_arrival(&route, act, latest_departure);
             let future_waiting = waiting + (act.place.time.start - act.schedule.arrival).max(0.);
 
             state.put_activity_state(LATEST_ARRIVAL_KEY, act, latest_arrival_time);
             state.put_activity_state(WAITING_KEY, act, future_waiting);
 
-            (latest_arrival_time, act.place.location, future_waiting)
+            (latest_arrival_time, act.place.location, act.place.setup, future_waiting)
         });
     }
 
-    fn update_statistics(route_ctx: &mut RouteContext, transport: &(dyn TransportCost + Send + Sync)) {
+    pub(crate) fn update_statistics(route_ctx: &mut RouteContext, transport: &(dyn TransportCost + Send + Sync)) {
         let route = route_ctx.route.clone();
         let start = route.tour.start().unwrap();
         let end = route.tour.end().unwrap();
@@ -167,8 +216,15 @@ impl TransportConstraintModule {
             (a.place.location, a.schedule.departure, total_dist)
         });
 
+        let total_lateness_cost =
+            route.tour.all_activities().map(|a| a.place.lateness_cost(a.schedule.arrival)).sum::<Cost>();
+        let total_waiting =
+            route.tour.all_activities().map(|a| (a.place.time.start - a.schedule.arrival).max(0.)).sum::<Duration>();
+
         route_ctx.state_mut().put_route_state(TOTAL_DISTANCE_KEY, total_dist);
         route_ctx.state_mut().put_route_state(TOTAL_DURATION_KEY, total_dur);
+        route_ctx.state_mut().put_route_state(TOTAL_LATENESS_COST_KEY, total_lateness_cost);
+        route_ctx.state_mut().put_route_state(TOTAL_WAITING_KEY, total_waiting);
     }
 
     /// Updates route departure to the new one.
@@ -179,10 +235,38 @@ impl TransportConstraintModule {
         new_departure_time: Timestamp,
     ) {
         let mut start = ctx.route_mut().tour.get_mut(0).unwrap();
+        let old_departure_time = start.schedule.departure;
         start.schedule.departure = new_departure_time;
+        Self::update_offset_time_windows(ctx, old_departure_time, new_departure_time);
         Self::update_route_schedules(ctx, activity, transport);
         Self::update_route_states(ctx, activity, transport);
     }
+
+    /// Moves time windows of activities which are defined by time offsets, so they stay relative to
+    /// the actual route departure, e.g. a break after given amount of working time.
+    fn update_offset_time_windows(ctx: &mut RouteContext, old_departure: Timestamp, new_departure: Timestamp) {
+        let shift = new_departure - old_departure;
+        if compare_floats(shift, 0.) == Ordering::Equal {
+            return;
+        }
+
+        ctx.route_mut().tour.all_activities_mut().for_each(|activity| {
+            let time = &activity.place.time;
+            let is_offset = activity.job.as_ref().is_some_and(|single| {
+                single.places.iter().flat_map(|place| place.times.iter()).any(|span| match span {
+                    TimeSpan::Offset(_) => {
+                        let offset_time = span.to_time_window(old_departure);
+                        offset_time.contains(time.start) && offset_time.contains(time.end)
+                    }
+                    TimeSpan::Window(_) => false,
+                })
+            });
+
+            if is_offset {
+                activity.place.time = TimeWindow::new(time.start + shift, time.end + shift);
+            }
+        });
+    }
 }
 
 struct TimeHardRouteConstraint {
@@ -193,11 +277,17 @@ impl HardRouteConstraint for TimeHardRouteConstraint {
     fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
         let date = ctx.route.tour.start().unwrap().schedule.departure;
         let check_single = |single: &Arc<Single>| {
-            single
-                .places
-                .iter()
-                .flat_map(|place| place.times.iter())
-                .any(|time| time.intersects(date, &ctx.route.actor.detail.time))
+            single.places.iter().any(|place| {
+                place.times.iter().any(|time| {
+                    let shift_time = &ctx.route.actor.detail.time;
+                    // NOTE soft time window allows late arrival, so only its start is restrictive
+                    if place.lateness.is_some() {
+                        time.to_time_window(date).start <= shift_time.end
+                    } else {
+                        time.intersects(date, shift_time)
+                    }
+                })
+            })
         };
 
         let has_time_intersection = match job {
@@ -249,15 +339,18 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
             }
             (
                 next.place.location,
-                *route_ctx.state.get_activity_state(LATEST_ARRIVAL_KEY, next).unwrap_or(&next.place.time.end),
+                *route_ctx.state.get_activity_state(LATEST_ARRIVAL_KEY, next).unwrap_or(&next.place.latest_start()),
             )
         } else {
             // open vrp
-            (target.place.location, target.place.time.end.min(actor.detail.time.end))
+            (target.place.location, target.place.latest_start().min(actor.detail.time.end))
         };
 
+        let next_setup_from = |location| next.map_or(0., |next| next.place.setup_from(location));
+
         let arr_time_at_next = departure
-            + self.transport.duration(route, prev.place.location, next_act_location, TravelTime::Departure(departure));
+            + self.transport.duration(route, prev.place.location, next_act_location, TravelTime::Departure(departure))
+            + next_setup_from(prev.place.location);
 
         if arr_time_at_next > latest_arr_time_at_next {
             return fail(self.code);
@@ -272,9 +365,11 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
                 prev.place.location,
                 target.place.location,
                 TravelTime::Departure(departure),
-            );
+            )
+            + target.place.setup_from(prev.place.location);
 
         let latest_departure_at_target = latest_arr_time_at_next
+            - next_setup_from(target.place.location)
             - self.transport.duration(
                 route,
                 target.place.location,
@@ -283,7 +378,7 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
             );
 
         let latest_arr_time_at_target =
-            target.place.time.end.min(self.activity.estimate_arrival(route, target, latest_departure_at_target));
+            target.place.latest_start().min(self.activity.estimate_arrival(route, target, latest_departure_at_target));
 
         if arr_time_at_target > latest_arr_time_at_target {
             return stop(self.code);
@@ -301,7 +396,8 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
                 target.place.location,
                 next_act_location,
                 TravelTime::Departure(end_time_at_target),
-            );
+            )
+            + next_setup_from(target.place.location);
 
         if arr_time_at_next > latest_arr_time_at_next {
             stop(self.code)
@@ -339,14 +435,18 @@ impl CostSoftActivityConstraint {
         time: Timestamp,
     ) -> (Cost, Cost, Timestamp) {
         let route = route_ctx.route.as_ref();
+        let actor = route.actor.as_ref();
 
+        let setup = end.place.setup_from(start.place.location);
         let arrival = time
-            + self.transport.duration(route, start.place.location, end.place.location, TravelTime::Departure(time));
+            + self.transport.duration(route, start.place.location, end.place.location, TravelTime::Departure(time))
+            + setup;
         let departure = self.activity.estimate_departure(route, end, arrival);
 
         let transport_cost =
             self.transport.cost(route, start.place.location, end.place.location, TravelTime::Departure(time));
-        let activity_cost = self.activity.cost(route, end, arrival);
+        let activity_cost = self.activity.cost(route, end, arrival)
+            + setup * (actor.driver.costs.per_service_time + actor.vehicle.costs.per_service_time);
 
         (transport_cost, activity_cost, departure)
     }
@@ -9,10 +9,12 @@ use std::sync::Arc;
 
 type LimitFn<T> = Arc<dyn Fn(&Actor) -> Option<T> + Send + Sync>;
 
-/// A module which controls travel limits.
+/// A module which controls travel limits: tour distance, duration and cost. Tour cost includes
+/// fixed, distance and time costs of the actor.
 pub struct TravelLimitModule {
     state_keys: Vec<i32>,
     constraints: Vec<ConstraintVariant>,
+    tour_distance_limit: LimitFn<Distance>,
     tour_duration_limit: LimitFn<Duration>,
 }
 
@@ -20,6 +22,10 @@ impl ConstraintModule for TravelLimitModule {
     fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
 
     fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        if let Some(limit_distance) = self.tour_distance_limit.deref()(route_ctx.route.actor.as_ref()) {
+            route_ctx.state_mut().put_route_state(LIMIT_DISTANCE_KEY, limit_distance);
+        }
+
         if let Some(limit_duration) = self.tour_duration_limit.deref()(route_ctx.route.actor.as_ref()) {
             route_ctx.state_mut().put_route_state(LIMIT_DURATION_KEY, limit_duration);
         }
@@ -46,30 +52,106 @@ impl TravelLimitModule {
         transport: Arc<dyn TransportCost + Send + Sync>,
         tour_distance_limit: LimitFn<Distance>,
         tour_duration_limit: LimitFn<Duration>,
+        tour_cost_limit: LimitFn<Cost>,
         distance_code: i32,
         duration_code: i32,
+        cost_code: i32,
     ) -> Self {
         Self {
+            tour_distance_limit: tour_distance_limit.clone(),
             tour_duration_limit: tour_duration_limit.clone(),
-            state_keys: Vec::default(),
-            constraints: vec![ConstraintVariant::HardActivity(Arc::new(TravelHardActivityConstraint {
-                distance_code,
-                duration_code,
-                transport,
-                tour_distance_limit,
-                tour_duration_limit,
-            }))],
+            state_keys: vec![LIMIT_DISTANCE_KEY, LIMIT_DURATION_KEY],
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(TravelHardRouteConstraint {
+                    distance_code,
+                    transport: transport.clone(),
+                })),
+                ConstraintVariant::HardActivity(Arc::new(TravelHardActivityConstraint {
+                    distance_code,
+                    duration_code,
+                    cost_code,
+                    transport,
+                    tour_distance_limit,
+                    tour_duration_limit,
+                    tour_cost_limit,
+                })),
+            ],
+        }
+    }
+}
+
+/// A hard route constraint which rejects a job early when it cannot be served within actor's
+/// distance limit: the job's minimal detour is added to the route's accumulated distance.
+struct TravelHardRouteConstraint {
+    distance_code: i32,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardRouteConstraint for TravelHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        _: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        let distance_limit = *route_ctx.state.get_route_state::<Distance>(LIMIT_DISTANCE_KEY)?;
+        let total_distance = route_ctx.state.get_route_state::<Distance>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
+
+        // NOTE each sub job of multi job has to be inserted, so the largest of their detours is used
+        let min_detour = match job {
+            Job::Single(single) => self.estimate_detour(route_ctx.route.as_ref(), single),
+            Job::Multi(multi) => multi.jobs.iter().try_fold(0., |max_detour: Distance, single| {
+                self.estimate_detour(route_ctx.route.as_ref(), single).map(|detour| max_detour.max(detour))
+            }),
+        }?;
+
+        if total_distance + min_detour > distance_limit {
+            Some(RouteConstraintViolation { code: self.distance_code })
+        } else {
+            None
         }
     }
 }
 
-/// A hard activity constraint which allows to limit actor's traveling distance and time.
+impl TravelHardRouteConstraint {
+    /// Estimates the minimal distance increase caused by inserting the job at its best place and
+    /// position in the tour. Returns `None` when some place has no location, so estimation is not possible.
+    fn estimate_detour(&self, route: &Route, single: &Single) -> Option<Distance> {
+        let distance = |from: &Activity, to: Location| {
+            self.transport.distance(route, from.place.location, to, TravelTime::Departure(from.schedule.departure))
+        };
+
+        single.places.iter().try_fold(Distance::MAX, |min_detour, place| {
+            let location = place.location?;
+
+            Some(route.tour.legs().fold(min_detour, |min_detour, (activities, _)| {
+                let detour = match activities {
+                    [from, to] => {
+                        let departure = TravelTime::Departure(from.schedule.departure);
+                        distance(from, location)
+                            + self.transport.distance(route, location, to.place.location, departure)
+                            - distance(from, to.place.location)
+                    }
+                    // NOTE last activity of open tour: job is appended without return to depot
+                    [last] => distance(last, location),
+                    _ => Distance::MAX,
+                };
+
+                min_detour.min(detour)
+            }))
+        })
+    }
+}
+
+/// A hard activity constraint which allows to limit actor's traveling distance, time and cost.
 struct TravelHardActivityConstraint {
     distance_code: i32,
     duration_code: i32,
+    cost_code: i32,
     transport: Arc<dyn TransportCost + Send + Sync>,
     tour_distance_limit: LimitFn<Distance>,
     tour_duration_limit: LimitFn<Duration>,
+    tour_cost_limit: LimitFn<Cost>,
 }
 
 impl HardActivityConstraint for TravelHardActivityConstraint {
@@ -80,25 +162,35 @@ impl HardActivityConstraint for TravelHardActivityConstraint {
     ) -> Option<ActivityConstraintViolation> {
         let tour_distance_limit = self.tour_distance_limit.deref()(route_ctx.route.actor.as_ref());
         let tour_duration_limit = self.tour_duration_limit.deref()(route_ctx.route.actor.as_ref());
+        let tour_cost_limit = self.tour_cost_limit.deref()(route_ctx.route.actor.as_ref());
 
-        if tour_distance_limit.is_some() || tour_duration_limit.is_some() {
+        if tour_distance_limit.is_some() || tour_duration_limit.is_some() || tour_cost_limit.is_some() {
             let (change_distance, change_duration) = self.calculate_travel(route_ctx.route.as_ref(), activity_ctx);
 
+            let curr_dis = route_ctx.state.get_route_state(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
+            let total_distance = curr_dis + change_distance;
             if let Some(distance_limit) = tour_distance_limit {
-                let curr_dis = route_ctx.state.get_route_state(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
-                let total_distance = curr_dis + change_distance;
                 if distance_limit < total_distance {
                     return stop(self.distance_code);
                 }
             }
 
+            let curr_dur = route_ctx.state.get_route_state(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
+            let total_duration = curr_dur + change_duration;
             if let Some(duration_limit) = tour_duration_limit {
-                let curr_dur = route_ctx.state.get_route_state(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
-                let total_duration = curr_dur + change_duration;
                 if duration_limit < total_duration {
                     return stop(self.duration_code);
                 }
             }
+
+            if let Some(cost_limit) = tour_cost_limit {
+                // NOTE waiting time at target is charged as driving time
+                let service = activity_ctx.target.place.duration.min(change_duration);
+                let total_cost = get_tour_cost(route_ctx, change_distance, change_duration - service, service);
+                if cost_limit < total_cost {
+                    return stop(self.cost_code);
+                }
+            }
         }
 
         None
@@ -154,3 +246,10 @@ impl TravelHardActivityConstraint {
         (first_to_second_dis, second_dep - departure)
     }
 }
+
+/// Gets tour cost of the actor the same way as it is done for route cost, but without penalties.
+fn get_tour_cost(route_ctx: &RouteContext, distance: Distance, driving: Duration, service: Duration) -> Cost {
+    let actor = route_ctx.route.actor.as_ref();
+
+    actor.vehicle.costs.fixed + actor.driver.costs.fixed + route_ctx.get_variable_cost(distance, driving, service)
+}
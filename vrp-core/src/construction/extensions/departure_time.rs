@@ -17,7 +17,19 @@ pub fn advance_departure_time(
     consider_whole_tour: bool,
 ) {
     if let Some(new_departure_time) = try_advance_departure_time(route_ctx, transport, consider_whole_tour) {
+        let last_departure_time = route_ctx.route.tour.start().unwrap().schedule.departure;
         TransportConstraintModule::update_route_departure(route_ctx, activity, transport, new_departure_time);
+
+        // NOTE with time dependent travel times, a later departure might lead to time window violation:
+        // try to compensate it once by departing earlier, otherwise restore the original departure
+        if let Some(overshoot) = get_time_window_overshoot(route_ctx) {
+            let new_departure_time = (new_departure_time - overshoot).max(last_departure_time);
+            TransportConstraintModule::update_route_departure(route_ctx, activity, transport, new_departure_time);
+
+            if get_time_window_overshoot(route_ctx).is_some() {
+                TransportConstraintModule::update_route_departure(route_ctx, activity, transport, last_departure_time);
+            }
+        }
     }
 }
 
@@ -32,7 +44,7 @@ pub fn recede_departure_time(
     }
 }
 
-fn try_advance_departure_time(
+pub(crate) fn try_advance_departure_time(
     route_ctx: &RouteContext,
     transport: &(dyn TransportCost + Send + Sync),
     optimize_whole_tour: bool,
@@ -61,7 +73,7 @@ fn try_advance_departure_time(
             route,
             start.place.location,
             first.place.location,
-            TravelTime::Departure(last_departure_time),
+            TravelTime::Arrival(first.place.time.start),
         );
 
         last_departure_time.max(first.place.time.start - start_to_first).min(latest_allowed_departure)
@@ -74,6 +86,17 @@ fn try_advance_departure_time(
     }
 }
 
+/// Returns max time window violation of route activities, if there is any.
+fn get_time_window_overshoot(route_ctx: &RouteContext) -> Option<Timestamp> {
+    route_ctx
+        .route
+        .tour
+        .all_activities()
+        .map(|activity| activity.schedule.arrival - activity.place.time.end)
+        .filter(|&overshoot| overshoot > 0.)
+        .max_by(|a, b| compare_floats(*a, *b))
+}
+
 fn try_recede_departure_time(route_ctx: &RouteContext) -> Option<Timestamp> {
     let first = route_ctx.route.tour.get(1)?;
     let start = route_ctx.route.tour.start()?;
@@ -4,7 +4,7 @@ mod context_test;
 
 use crate::construction::constraints::*;
 use crate::construction::heuristics::factories::*;
-use crate::models::common::Cost;
+use crate::models::common::{Cost, Distance, Duration};
 use crate::models::problem::*;
 use crate::models::solution::*;
 use crate::models::{Extras, Problem, Solution};
@@ -214,6 +214,9 @@ pub mod state_flags {
     pub const NO_FLAGS: u8 = 0x00;
     /// Route is in unassignable state.
     pub const UNASSIGNABLE: u8 = 0x01;
+    /// Route activities were moved by a module outside of route state acceptance, so
+    /// route state of all modules has to be recalculated.
+    pub const RESCHEDULED: u8 = 0x02;
 }
 
 impl RouteContext {
@@ -242,22 +245,37 @@ impl RouteContext {
 
     /// Gets route cost.
     pub fn get_route_cost(&self) -> Cost {
-        let get_cost = |costs: &Costs, distance: f64, duration: f64| {
-            costs.fixed
-                + costs.per_distance * distance
-                // NOTE this is incorrect when timing costs are different: fitness value will be
-                // different from actual cost. However we accept this so far as it is simpler for
+        let actor = &self.route.actor;
+
+        let lateness = self.state.get_route_state::<f64>(TOTAL_LATENESS_COST_KEY).cloned().unwrap_or(0.);
+        let overtime = self.state.get_route_state::<f64>(TOTAL_OVERTIME_COST_KEY).cloned().unwrap_or(0.);
+
+        actor.vehicle.costs.fixed + actor.driver.costs.fixed + self.get_variable_cost(0., 0., 0.) + lateness + overtime
+    }
+
+    /// Gets route cost without fixed and penalty parts as if given distance, driving and service time were added
+    /// to the route. Route waiting time is taken from the route state and charged at waiting rate.
+    pub fn get_variable_cost(&self, distance: Distance, driving: Duration, service: Duration) -> Cost {
+        let route_distance = self.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
+        let route_duration = self.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
+        let waiting = self.state.get_route_state::<f64>(TOTAL_WAITING_KEY).cloned().unwrap_or(0.).min(route_duration);
+
+        let get_cost = |costs: &Costs| {
+            costs.per_distance * (route_distance + distance)
+                // NOTE this is incorrect when driving and service costs are different: fitness value will
+                // be different from actual cost. However we accept this so far as it is simpler for
                 // implementation and pragmatic format does not expose this feature
                 // .
                 // TODO calculate actual cost
-                + costs.per_driving_time.max(costs.per_service_time).max(costs.per_waiting_time) * duration
+                + costs.per_driving_time.max(costs.per_service_time) * (route_duration - waiting)
+                + costs.per_waiting_time * waiting
+                + costs.per_driving_time * driving
+                + costs.per_service_time * service
         };
 
         let actor = &self.route.actor;
-        let distance = self.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
-        let duration = self.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
 
-        get_cost(&actor.vehicle.costs, distance, duration) + get_cost(&actor.driver.costs, distance, duration)
+        get_cost(&actor.vehicle.costs) + get_cost(&actor.driver.costs)
     }
 
     /// Unwraps given `RouteContext` as pair of mutable references.
@@ -420,6 +438,11 @@ impl RouteState {
         (self.flags & flag) > 0
     }
 
+    /// Resets given flag.
+    pub fn unset_flag(&mut self, flag: u8) {
+        self.flags &= !flag;
+    }
+
     /// Resets all flags.
     pub fn reset_flags(&mut self) {
         self.flags = state_flags::NO_FLAGS
@@ -7,7 +7,7 @@ use std::sync::Arc;
 use crate::construction::constraints::{ActivityConstraintViolation, ConstraintPipeline};
 use crate::construction::heuristics::*;
 use crate::models::common::Cost;
-use crate::models::problem::{Job, Multi, Single};
+use crate::models::problem::{get_service_duration, Job, Multi, Single};
 use crate::models::solution::{Activity, Leg, Place};
 use crate::utils::Either;
 use rosomaxa::utils::unwrap_from_result;
@@ -255,13 +255,15 @@ fn analyze_insertion_in_route_leg(
     };
     let start_time = route_ctx.route.tour.start().unwrap().schedule.departure;
     // analyze service details
-    single.places.iter().try_fold(init, |acc, detail| {
+    single.places.iter().enumerate().try_fold(init, |acc, (place_idx, detail)| {
         // analyze detail time windows
         detail.times.iter().try_fold(acc, |acc, time| {
             target.place = Place {
                 location: detail.location.unwrap_or(prev.place.location),
-                duration: detail.duration,
+                duration: get_service_duration(route_ctx.route.actor.as_ref(), single, place_idx, detail.duration),
                 time: time.to_time_window(start_time),
+                setup: detail.setup,
+                lateness: detail.lateness.clone(),
             };
 
             let activity_ctx = ActivityContext { index, prev, target, next };
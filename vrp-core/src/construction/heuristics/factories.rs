@@ -28,7 +28,7 @@ pub fn create_insertion_context(problem: Arc<Problem>, environment: Arc<Environm
         match (actor, lock.is_lazy) {
             (Some(actor), false) => {
                 registry.use_actor(&actor);
-                let mut route_ctx = RouteContext::new(actor);
+                let mut route_ctx = RouteContext::new(actor.clone());
                 let start = route_ctx.route.tour.start().unwrap_or_else(|| panic!("{}", OP_START_MSG)).place.location;
 
                 let create_activity = |single: Arc<Single>, previous_location: usize| {
@@ -44,8 +44,10 @@ pub fn create_insertion_context(problem: Arc<Problem>, environment: Arc<Environm
                     Activity {
                         place: ActivityPlace {
                             location: place.location.unwrap_or(previous_location),
-                            duration: place.duration,
+                            duration: get_service_duration(&actor, single.as_ref(), 0, place.duration),
                             time,
+                            setup: place.setup,
+                            lateness: place.lateness.clone(),
                         },
                         schedule: Schedule { arrival: 0.0, departure: 0.0 },
                         job: Some(single),
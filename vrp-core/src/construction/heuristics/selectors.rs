@@ -3,7 +3,7 @@
 mod selectors_test;
 
 use crate::construction::heuristics::*;
-use crate::models::problem::Job;
+use crate::models::problem::{get_job_locations, Job};
 use crate::models::solution::Leg;
 use crate::utils::*;
 use rand::prelude::*;
@@ -188,6 +188,141 @@ impl InsertionEvaluator for PositionInsertionEvaluator {
     }
 }
 
+/// Evaluates job insertion only in a shortlist of routes which are spatially close to the job.
+/// Closeness is approximated using a small sample of route activity locations which roughly
+/// outlines the route's hull. When no feasible insertion is found within the shortlist, all routes
+/// are evaluated, so the evaluator never reports a job as unassignable when it fits somewhere.
+pub struct NearestRouteInsertionEvaluator {
+    inner: PositionInsertionEvaluator,
+    max_routes: usize,
+    max_samples: usize,
+}
+
+impl NearestRouteInsertionEvaluator {
+    /// Creates a new instance of `NearestRouteInsertionEvaluator`.
+    /// `max_routes` limits the amount of non-empty routes in the shortlist, `max_samples` limits
+    /// the amount of route locations used to estimate proximity.
+    pub fn new(max_routes: usize, max_samples: usize) -> Self {
+        assert!(max_routes > 0);
+        assert!(max_samples > 0);
+
+        Self { inner: PositionInsertionEvaluator::default(), max_routes, max_samples }
+    }
+
+    /// Returns routes which are considered for insertion of given job. Empty routes are always kept.
+    pub(crate) fn get_shortlist(
+        &self,
+        insertion_ctx: &InsertionContext,
+        job: &Job,
+        routes: &[RouteContext],
+    ) -> Option<Vec<RouteContext>> {
+        if routes.len() <= self.max_routes {
+            return None;
+        }
+
+        let job_locations = get_job_locations(job).flatten().collect::<Vec<_>>();
+        if job_locations.is_empty() {
+            return None;
+        }
+
+        let transport = insertion_ctx.problem.transport.as_ref();
+        let (empty, candidates): (Vec<_>, Vec<_>) =
+            routes.iter().partition(|route_ctx| route_ctx.route.tour.job_count() == 0);
+
+        if candidates.len() <= self.max_routes {
+            return None;
+        }
+
+        let mut candidates = candidates
+            .into_iter()
+            .map(|route_ctx| {
+                let tour = &route_ctx.route.tour;
+                let profile = &route_ctx.route.actor.vehicle.profile;
+                let step = (tour.total() / self.max_samples).max(1);
+
+                let distance = tour
+                    .all_activities()
+                    .step_by(step)
+                    .flat_map(|activity| {
+                        job_locations.iter().map(move |&location| {
+                            let distance = transport.distance_approx(profile, activity.place.location, location);
+                            if distance < 0. {
+                                f64::MAX
+                            } else {
+                                distance
+                            }
+                        })
+                    })
+                    .fold(f64::MAX, f64::min);
+
+                (route_ctx, distance)
+            })
+            .collect::<Vec<_>>();
+
+        candidates.sort_by(|(_, a), (_, b)| compare_floats(*a, *b));
+
+        Some(
+            candidates
+                .into_iter()
+                .take(self.max_routes)
+                .map(|(route_ctx, _)| route_ctx)
+                .chain(empty)
+                .cloned()
+                .collect(),
+        )
+    }
+}
+
+impl InsertionEvaluator for NearestRouteInsertionEvaluator {
+    fn evaluate_job(
+        &self,
+        insertion_ctx: &InsertionContext,
+        job: &Job,
+        routes: &[RouteContext],
+        leg_selector: &(dyn LegSelector + Send + Sync),
+        result_selector: &(dyn ResultSelector + Send + Sync),
+    ) -> InsertionResult {
+        match self.get_shortlist(insertion_ctx, job, routes) {
+            Some(shortlist) => {
+                match self.inner.evaluate_job(insertion_ctx, job, shortlist.as_slice(), leg_selector, result_selector) {
+                    InsertionResult::Failure(_) => {
+                        self.inner.evaluate_job(insertion_ctx, job, routes, leg_selector, result_selector)
+                    }
+                    success => success,
+                }
+            }
+            None => self.inner.evaluate_job(insertion_ctx, job, routes, leg_selector, result_selector),
+        }
+    }
+
+    fn evaluate_route(
+        &self,
+        insertion_ctx: &InsertionContext,
+        route_ctx: &RouteContext,
+        jobs: &[Job],
+        leg_selector: &(dyn LegSelector + Send + Sync),
+        result_selector: &(dyn ResultSelector + Send + Sync),
+    ) -> InsertionResult {
+        self.inner.evaluate_route(insertion_ctx, route_ctx, jobs, leg_selector, result_selector)
+    }
+
+    fn evaluate_all(
+        &self,
+        insertion_ctx: &InsertionContext,
+        jobs: &[Job],
+        routes: &[RouteContext],
+        leg_selector: &(dyn LegSelector + Send + Sync),
+        result_selector: &(dyn ResultSelector + Send + Sync),
+    ) -> InsertionResult {
+        map_reduce(
+            jobs,
+            |job| self.evaluate_job(insertion_ctx, job, routes, leg_selector, result_selector),
+            InsertionResult::make_failure,
+            |a, b| result_selector.select_insertion(insertion_ctx, a, b),
+        )
+    }
+}
+
 /// Insertion result selector.
 pub trait ResultSelector {
     /// Selects one insertion result from two to promote as best.
@@ -0,0 +1,237 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/probing/coverage_test.rs"]
+mod coverage_test;
+
+use crate::construction::heuristics::*;
+use crate::models::problem::Job;
+use crate::models::solution::{Activity, Place};
+use hashbrown::HashSet;
+use rosomaxa::prelude::*;
+use std::fmt::{Display, Formatter};
+
+/// Specifies how often a hard constraint has rejected perturbed insertions.
+#[derive(Clone, Debug, Default)]
+pub struct ConstraintCoverage {
+    /// Amount of evaluated insertions.
+    pub evaluated: usize,
+    /// Amount of insertions rejected by the constraint.
+    pub rejected: usize,
+    /// Violation codes returned by the constraint.
+    pub codes: HashSet<i32>,
+}
+
+impl ConstraintCoverage {
+    /// Returns true if constraint has rejected at least one insertion.
+    pub fn is_covered(&self) -> bool {
+        self.rejected > 0
+    }
+
+    fn add(&mut self, code: Option<i32>) {
+        self.evaluated += 1;
+
+        if let Some(code) = code {
+            self.rejected += 1;
+            self.codes.insert(code);
+        }
+    }
+}
+
+/// Specifies how often an objective has responded to solution perturbations.
+#[derive(Clone, Debug, Default)]
+pub struct ObjectiveCoverage {
+    /// Amount of evaluated perturbations.
+    pub evaluated: usize,
+    /// Amount of perturbations which have changed objective fitness.
+    pub changed: usize,
+    /// Maximum absolute fitness change.
+    pub max_delta: f64,
+}
+
+impl ObjectiveCoverage {
+    /// Returns true if objective has responded to at least one perturbation.
+    pub fn is_covered(&self) -> bool {
+        self.changed > 0
+    }
+
+    fn add(&mut self, original: f64, perturbed: f64) {
+        let delta = (original - perturbed).abs();
+
+        self.evaluated += 1;
+        if delta > f64::EPSILON {
+            self.changed += 1;
+            self.max_delta = self.max_delta.max(delta);
+        }
+    }
+}
+
+/// Keeps coverage statistics of hard constraints and objectives. Constraints and objectives are
+/// referenced by their index in the constraint pipeline and the problem objective respectively.
+#[derive(Clone, Debug, Default)]
+pub struct CoverageReport {
+    /// Coverage of hard route constraints.
+    pub hard_route: Vec<ConstraintCoverage>,
+    /// Coverage of hard activity constraints.
+    pub hard_activity: Vec<ConstraintCoverage>,
+    /// Coverage of objectives.
+    pub objectives: Vec<ObjectiveCoverage>,
+}
+
+impl CoverageReport {
+    /// Returns true if every constraint and objective is covered.
+    pub fn is_covered(&self) -> bool {
+        self.hard_route.iter().chain(self.hard_activity.iter()).all(|coverage| coverage.is_covered())
+            && self.objectives.iter().all(|coverage| coverage.is_covered())
+    }
+}
+
+impl Display for CoverageReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let write_constraints = |f: &mut Formatter<'_>, name: &str, coverage: &[ConstraintCoverage]| {
+            coverage.iter().enumerate().try_for_each(|(idx, coverage)| {
+                let mut codes = coverage.codes.iter().cloned().collect::<Vec<_>>();
+                codes.sort_unstable();
+
+                writeln!(
+                    f,
+                    "{} constraint #{}: {}, rejected {} of {} insertions, codes: {:?}",
+                    name,
+                    idx,
+                    get_status(coverage.is_covered()),
+                    coverage.rejected,
+                    coverage.evaluated,
+                    codes
+                )
+            })
+        };
+
+        write_constraints(f, "hard route", self.hard_route.as_slice())?;
+        write_constraints(f, "hard activity", self.hard_activity.as_slice())?;
+
+        self.objectives.iter().enumerate().try_for_each(|(idx, coverage)| {
+            writeln!(
+                f,
+                "objective #{}: {}, changed in {} of {} perturbations, max delta: {}",
+                idx,
+                get_status(coverage.is_covered()),
+                coverage.changed,
+                coverage.evaluated,
+                coverage.max_delta
+            )
+        })
+    }
+}
+
+/// Runs a coverage self-test on given solution: every assigned job, except locked ones, is removed
+/// from its route one by one, then:
+/// * the removal is used as a perturbation to check whether each objective responds to it
+/// * the removed job is evaluated at every leg of every used and available route to check whether
+///   each hard constraint rejects at least one of these insertions
+///
+/// A constraint or objective which is not covered is either not active for given problem or it is
+/// potentially implemented incorrectly.
+pub fn check_coverage(insertion_ctx: &InsertionContext) -> CoverageReport {
+    let constraint = insertion_ctx.problem.constraint.as_ref();
+    let original_fitness = insertion_ctx.get_fitness().collect::<Vec<_>>();
+
+    let mut report = CoverageReport {
+        hard_route: vec![ConstraintCoverage::default(); constraint.hard_route_constraints.len()],
+        hard_activity: vec![ConstraintCoverage::default(); constraint.hard_activity_constraints.len()],
+        objectives: vec![ObjectiveCoverage::default(); original_fitness.len()],
+    };
+
+    let jobs = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .enumerate()
+        .flat_map(|(route_idx, route_ctx)| route_ctx.route.tour.jobs().map(move |job| (route_idx, job)))
+        .filter(|(_, job)| !insertion_ctx.solution.locked.contains(job))
+        .collect::<Vec<_>>();
+
+    jobs.into_iter().for_each(|(route_idx, job)| {
+        let perturbed_ctx = remove_job(insertion_ctx, route_idx, &job);
+
+        perturbed_ctx
+            .get_fitness()
+            .zip(original_fitness.iter())
+            .zip(report.objectives.iter_mut())
+            .for_each(|((perturbed, original), coverage)| coverage.add(*original, perturbed));
+
+        let solution_ctx = &perturbed_ctx.solution;
+        solution_ctx.routes.iter().cloned().chain(solution_ctx.registry.next()).for_each(|route_ctx| {
+            evaluate_job(&perturbed_ctx, &route_ctx, &job, &mut report);
+        });
+    });
+
+    report
+}
+
+fn remove_job(insertion_ctx: &InsertionContext, route_idx: usize, job: &Job) -> InsertionContext {
+    let mut perturbed_ctx = insertion_ctx.deep_copy();
+
+    let route_ctx = perturbed_ctx.solution.routes.get_mut(route_idx).expect("invalid route index");
+    route_ctx.route_mut().tour.remove(job);
+
+    perturbed_ctx.solution.unassigned.insert(job.clone(), UnassignmentInfo::Unknown);
+    perturbed_ctx.restore();
+
+    perturbed_ctx
+}
+
+fn evaluate_job(insertion_ctx: &InsertionContext, route_ctx: &RouteContext, job: &Job, report: &mut CoverageReport) {
+    let constraint = insertion_ctx.problem.constraint.as_ref();
+
+    constraint.hard_route_constraints.iter().zip(report.hard_route.iter_mut()).for_each(|(constraint, coverage)| {
+        coverage.add(constraint.evaluate_job(&insertion_ctx.solution, route_ctx, job).map(|violation| violation.code))
+    });
+
+    let tour = &route_ctx.route.tour;
+    let start_time = tour.start().map_or(0., |start| start.schedule.departure);
+
+    let singles = match job {
+        Job::Single(single) => vec![single.clone()],
+        Job::Multi(multi) => multi.jobs.clone(),
+    };
+
+    singles.iter().for_each(|single| {
+        let mut target = Activity::new_with_job(single.clone());
+
+        tour.legs().for_each(|(items, index)| {
+            let (prev, next) = match items {
+                [prev] => (prev, None),
+                [prev, next] => (prev, Some(next)),
+                _ => return,
+            };
+
+            single.places.iter().for_each(|detail| {
+                detail.times.iter().for_each(|time| {
+                    target.place = Place {
+                        location: detail.location.unwrap_or(prev.place.location),
+                        duration: detail.duration,
+                        time: time.to_time_window(start_time),
+                        setup: detail.setup,
+                        lateness: detail.lateness.clone(),
+                    };
+
+                    let activity_ctx = ActivityContext { index, prev, target: &target, next };
+
+                    constraint.hard_activity_constraints.iter().zip(report.hard_activity.iter_mut()).for_each(
+                        |(constraint, coverage)| {
+                            coverage.add(
+                                constraint.evaluate_activity(route_ctx, &activity_ctx).map(|violation| violation.code),
+                            )
+                        },
+                    );
+                });
+            });
+        });
+    });
+}
+
+fn get_status(is_covered: bool) -> &'static str {
+    if is_covered {
+        "covered"
+    } else {
+        "NOT COVERED"
+    }
+}
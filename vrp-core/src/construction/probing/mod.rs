@@ -1,4 +1,8 @@
-//! This module responsible for functionality needed to restore feasible solution from infeasible one.
+//! This module responsible for functionality needed to restore feasible solution from infeasible one
+//! and to probe how constraints and objectives respond to solution changes.
 
 mod repair_solution;
 pub use self::repair_solution::*;
+
+mod coverage;
+pub use self::coverage::*;
@@ -5,7 +5,7 @@ mod repair_solution_test;
 use crate::construction::constraints::ConstraintPipeline;
 use crate::construction::heuristics::*;
 use crate::models::common::TimeSpan;
-use crate::models::problem::{Job, Multi, Single};
+use crate::models::problem::{get_service_duration, Actor, Job, Multi, Single};
 use crate::models::solution::Activity;
 use hashbrown::{HashMap, HashSet};
 use rosomaxa::prelude::*;
@@ -98,7 +98,7 @@ fn synchronize_jobs(
         .tour
         .all_activities()
         .filter_map(|activity| activity.job.as_ref().map(|job| (job, activity)))
-        .filter(|(single, activity)| is_activity_to_single_match(activity, single))
+        .filter(|(single, activity)| is_activity_to_single_match(route_ctx.route.actor.as_ref(), activity, single))
         .filter_map(|(single, activity)| activity.retrieve_job().map(|job| (job, single)))
         .filter(|(job, _)| !assigned_jobs.contains(job))
         .fold(
@@ -141,9 +141,11 @@ fn synchronize_jobs(
     synchronized_jobs
 }
 
-fn is_activity_to_single_match(activity: &Activity, single: &Single) -> bool {
-    unwrap_from_result(single.places.iter().try_fold(false, |_, place| {
-        let is_same_duration = compare_floats(activity.place.duration, place.duration) == Ordering::Equal;
+fn is_activity_to_single_match(actor: &Actor, activity: &Activity, single: &Single) -> bool {
+    unwrap_from_result(single.places.iter().enumerate().try_fold(false, |_, (place_idx, place)| {
+        let is_same_duration =
+            compare_floats(activity.place.duration, get_service_duration(actor, single, place_idx, place.duration))
+                == Ordering::Equal;
         let is_same_location = place.location.map_or(true, |location| location == activity.place.location);
         let is_same_time_window = place.times.iter().any(|time| {
             match time {
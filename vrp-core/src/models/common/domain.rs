@@ -66,6 +66,18 @@ pub enum TimeSpan {
     Offset(TimeOffset),
 }
 
+/// Represents a time window which recurs weekly on specific days of week.
+#[derive(Clone, Debug)]
+pub struct WeeklyTimeWindow {
+    /// Days of week when time window recurs: zero is the first day of week.
+    pub days: Vec<usize>,
+    /// Start of time window as an offset from the start of the day.
+    pub start: Duration,
+    /// End of time window as an offset from the start of the day. It can exceed one day, so time
+    /// window crosses midnight.
+    pub end: Duration,
+}
+
 /// Specifies a flexible time interval.
 #[derive(Clone, Debug, Default)]
 pub struct TimeInterval {
@@ -130,6 +142,35 @@ impl TimeWindow {
     }
 }
 
+impl WeeklyTimeWindow {
+    /// Expands weekly recurrence into absolute time windows which intersect with planning horizon.
+    /// Time windows are cut by the horizon and sorted by their start. Week start is a timestamp of
+    /// the first day of any week, all other weeks are calculated relative to it.
+    pub fn expand(&self, week_start: Timestamp, horizon: &TimeWindow) -> Vec<TimeWindow> {
+        const DAY: Duration = 86400.;
+        const WEEK: Duration = 7. * DAY;
+
+        let first_week = ((horizon.start - week_start - self.end) / WEEK).floor() as i64;
+        let last_week = ((horizon.end - week_start) / WEEK).ceil() as i64;
+
+        let mut time_windows = (first_week..=last_week)
+            .flat_map(|week| {
+                self.days.iter().map(move |&day| {
+                    let day_start = week_start + week as f64 * WEEK + day as f64 * DAY;
+                    TimeWindow::new(day_start + self.start, day_start + self.end)
+                })
+            })
+            .filter_map(|tw| tw.overlapping(horizon))
+            .filter(|tw| tw.duration() > 0.)
+            .collect::<Vec<_>>();
+
+        time_windows.sort_by(|a, b| compare_floats(a.start, b.start));
+        time_windows.dedup();
+
+        time_windows
+    }
+}
+
 impl PartialEq<TimeWindow> for TimeWindow {
     fn eq(&self, other: &TimeWindow) -> bool {
         compare_floats(self.start, other.start) == Ordering::Equal
@@ -186,6 +227,39 @@ impl TimeInterval {
     }
 }
 
+/// Specifies a penalty for arrival after time window end. When it is set, time window becomes soft:
+/// late arrival is allowed, but adds extra cost.
+#[derive(Clone)]
+pub enum LatenessPenalty {
+    /// A cost per time unit of lateness.
+    Linear(Cost),
+    /// A custom function which returns cost for given lateness.
+    Custom(Arc<dyn Fn(Duration) -> Cost + Send + Sync>),
+}
+
+impl LatenessPenalty {
+    /// Estimates penalty cost for given lateness.
+    pub fn estimate(&self, lateness: Duration) -> Cost {
+        if lateness > 0. {
+            match self {
+                LatenessPenalty::Linear(cost) => lateness * cost,
+                LatenessPenalty::Custom(func) => func(lateness),
+            }
+        } else {
+            0.
+        }
+    }
+}
+
+impl std::fmt::Debug for LatenessPenalty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LatenessPenalty::Linear(cost) => f.debug_tuple("Linear").field(cost).finish(),
+            LatenessPenalty::Custom(_) => f.write_str("Custom"),
+        }
+    }
+}
+
 /// Represents a schedule.
 #[derive(Clone, Debug)]
 pub struct Schedule {
@@ -4,6 +4,7 @@ mod load_test;
 
 use crate::models::common::{Dimensions, ValueDimension};
 use crate::models::Problem;
+use hashbrown::HashSet;
 use rosomaxa::utils::unwrap_from_result;
 use std::cmp::Ordering;
 use std::fmt::{Debug, Display, Formatter};
@@ -12,6 +13,9 @@ use std::ops::{Add, Mul, Sub};
 
 const CAPACITY_DIMENSION_KEY: &str = "cpc";
 const DEMAND_DIMENSION_KEY: &str = "dmd";
+const COMPARTMENTS_DIMENSION_KEY: &str = "cmp";
+const PRODUCT_CLASS_DIMENSION_KEY: &str = "pcl";
+const TRIP_CAPACITY_DIMENSION_KEY: &str = "tcp";
 const LOAD_DIMENSION_SIZE: usize = 8;
 
 /// Represents a load type used to represent customer's demand or vehicle's load.
@@ -52,6 +56,14 @@ pub trait CapacityDimension<T: LoadOps> {
     fn get_capacity(&self) -> Option<&T>;
 }
 
+/// A trait to get or set capacity of the trip which starts at multi trip marker job, e.g. reload.
+pub trait TripCapacityDimension<T: LoadOps> {
+    /// Sets trip capacity.
+    fn set_trip_capacity(&mut self, capacity: T) -> &mut Self;
+    /// Gets trip capacity.
+    fn get_trip_capacity(&self) -> Option<&T>;
+}
+
 /// A trait to get or set demand.
 pub trait DemandDimension<T: LoadOps> {
     /// Sets demand.
@@ -60,6 +72,34 @@ pub trait DemandDimension<T: LoadOps> {
     fn get_demand(&self) -> Option<&Demand<T>>;
 }
 
+/// Represents a vehicle compartment: a separate part of vehicle's capacity which can hold products
+/// of only one class within the same trip.
+#[derive(Clone, Debug)]
+pub struct Compartment<T: LoadOps> {
+    /// A compartment id.
+    pub id: String,
+    /// A compartment capacity.
+    pub capacity: T,
+    /// Product classes allowed to be loaded into compartment.
+    pub classes: HashSet<String>,
+}
+
+/// A trait to get or set vehicle's compartments.
+pub trait CompartmentDimension<T: LoadOps> {
+    /// Sets compartments.
+    fn set_compartments(&mut self, compartments: Vec<Compartment<T>>) -> &mut Self;
+    /// Gets compartments.
+    fn get_compartments(&self) -> Option<&Vec<Compartment<T>>>;
+}
+
+/// A trait to get or set job's product class which defines compartment requirement.
+pub trait ProductClassDimension {
+    /// Sets product class.
+    fn set_product_class(&mut self, class: String) -> &mut Self;
+    /// Gets product class.
+    fn get_product_class(&self) -> Option<&String>;
+}
+
 impl<T: LoadOps> Demand<T> {
     /// Returns capacity change as difference between pickup and delivery.
     pub fn change(&self) -> T {
@@ -101,6 +141,17 @@ impl<T: LoadOps> CapacityDimension<T> for Dimensions {
     }
 }
 
+impl<T: LoadOps> TripCapacityDimension<T> for Dimensions {
+    fn set_trip_capacity(&mut self, capacity: T) -> &mut Self {
+        self.set_value(TRIP_CAPACITY_DIMENSION_KEY, capacity);
+        self
+    }
+
+    fn get_trip_capacity(&self) -> Option<&T> {
+        self.get_value(TRIP_CAPACITY_DIMENSION_KEY)
+    }
+}
+
 impl<T: LoadOps> DemandDimension<T> for Dimensions {
     fn set_demand(&mut self, demand: Demand<T>) -> &mut Self {
         self.set_value(DEMAND_DIMENSION_KEY, demand);
@@ -112,6 +163,28 @@ impl<T: LoadOps> DemandDimension<T> for Dimensions {
     }
 }
 
+impl<T: LoadOps> CompartmentDimension<T> for Dimensions {
+    fn set_compartments(&mut self, compartments: Vec<Compartment<T>>) -> &mut Self {
+        self.set_value(COMPARTMENTS_DIMENSION_KEY, compartments);
+        self
+    }
+
+    fn get_compartments(&self) -> Option<&Vec<Compartment<T>>> {
+        self.get_value(COMPARTMENTS_DIMENSION_KEY)
+    }
+}
+
+impl ProductClassDimension for Dimensions {
+    fn set_product_class(&mut self, class: String) -> &mut Self {
+        self.set_value(PRODUCT_CLASS_DIMENSION_KEY, class);
+        self
+    }
+
+    fn get_product_class(&self) -> Option<&String> {
+        self.get_value(PRODUCT_CLASS_DIMENSION_KEY)
+    }
+}
+
 /// Specifies single dimensional load type.
 #[derive(Clone, Copy, Debug, Default)]
 pub struct SingleDimLoad {
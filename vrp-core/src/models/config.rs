@@ -0,0 +1,208 @@
+//! This module provides a way to compose constraints and objectives of the problem declaratively,
+//! without writing code for every experiment. Configuration can be deserialized from any format
+//! supported by serde, e.g. JSON or YAML.
+//!
+//! Constraint modules are added to the pipeline in the order of declaration and their violation
+//! codes are assigned sequentially starting from `1`. Each entry of `objectives` defines one level
+//! of hierarchical objective, several objectives can be specified on the same level:
+//!
+//! ```json
+//! {
+//!   "constraints": [
+//!     { "type": "transport" },
+//!     { "type": "capacity" },
+//!     { "type": "tour-size", "limit": 10 }
+//!   ],
+//!   "objectives": [
+//!     [{ "type": "minimize-unassigned" }],
+//!     [{ "type": "minimize-tours" }],
+//!     [{ "type": "minimize-cost" }, { "type": "balance-activities", "threshold": 0.1 }]
+//!   ]
+//! }
+//! ```
+//!
+//! Supported constraints:
+//! - `transport`: time windows and schedule
+//! - `capacity`: capacity, multi dimensional if `isMultiDim` is set
+//! - `tour-size`: max amount of job activities in the tour specified by `limit`
+//! - `driving-time`: driving rules specified on vehicle dimensions
+//!
+//! Supported objectives: `minimize-unassigned`, `minimize-tours`, `maximize-tours`, `minimize-cost`,
+//! `minimize-distance`, `minimize-duration`, `minimize-arrival-time`, `minimize-max-duration`,
+//! `minimize-waiting-time`, `minimize-affinity-penalty`, `minimize-max-waiting-time` with optional
+//! `percentile`, and `balance-activities`, `balance-distance`, `balance-duration` with optional `threshold`.
+
+#[cfg(test)]
+#[path = "../../tests/unit/models/config_test.rs"]
+mod config_test;
+
+use crate::construction::constraints::*;
+use crate::models::common::{MultiDimLoad, SingleDimLoad};
+use crate::models::problem::*;
+use crate::solver::objectives::*;
+use serde::Deserialize;
+use std::sync::Arc;
+
+/// Specifies a constraint module of the problem.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum ConstraintConfig {
+    /// Time windows and schedule constraint.
+    Transport,
+    /// Capacity constraint with single or multi dimensional load.
+    #[serde(rename_all = "camelCase")]
+    Capacity {
+        /// Whether load is multi dimensional.
+        #[serde(default)]
+        is_multi_dim: bool,
+    },
+    /// Limits amount of job activities in the tour.
+    TourSize {
+        /// Max amount of job activities.
+        limit: usize,
+    },
+    /// Driving time rules specified on vehicle dimensions.
+    DrivingTime,
+}
+
+/// Specifies an objective of the problem.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum ObjectiveConfig {
+    /// Minimizes amount of unassigned jobs.
+    MinimizeUnassigned,
+    /// Minimizes amount of tours.
+    MinimizeTours,
+    /// Maximizes amount of tours.
+    MaximizeTours,
+    /// Minimizes total cost.
+    MinimizeCost,
+    /// Minimizes total distance.
+    MinimizeDistance,
+    /// Minimizes total duration.
+    MinimizeDuration,
+    /// Minimizes average tour arrival time.
+    MinimizeArrivalTime,
+    /// Minimizes the longest tour duration.
+    MinimizeMaxDuration,
+    /// Minimizes total waiting time of all tours.
+    MinimizeWaitingTime,
+    /// Minimizes the maximum customer waiting time.
+    MinimizeMaxWaitingTime {
+        /// A percentile of waiting times to minimize instead of the maximum.
+        percentile: Option<f64>,
+    },
+    /// Minimizes total penalty of jobs served by vehicles which are not preferred by them.
+    MinimizeAffinityPenalty,
+    /// Balances amount of activities between tours.
+    BalanceActivities {
+        /// A relative threshold for balancing.
+        threshold: Option<f64>,
+    },
+    /// Balances distance between tours.
+    BalanceDistance {
+        /// A relative threshold for balancing.
+        threshold: Option<f64>,
+    },
+    /// Balances duration between tours.
+    BalanceDuration {
+        /// A relative threshold for balancing.
+        threshold: Option<f64>,
+    },
+}
+
+/// Specifies constraints and hierarchical objectives of the problem.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct ProblemConfig {
+    /// Constraint modules in the order of declaration.
+    pub constraints: Vec<ConstraintConfig>,
+    /// Objectives grouped by hierarchy level.
+    pub objectives: Vec<Vec<ObjectiveConfig>>,
+}
+
+impl ProblemConfig {
+    /// Creates constraint pipeline and objective from the configuration.
+    pub fn create(
+        &self,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+    ) -> (ConstraintPipeline, ProblemObjective) {
+        let mut pipeline = ConstraintPipeline::default();
+
+        (1..).zip(self.constraints.iter()).for_each(|(code, constraint)| {
+            let module: TargetConstraint = match constraint {
+                ConstraintConfig::Transport => {
+                    Arc::new(TransportConstraintModule::new(transport.clone(), activity.clone(), code))
+                }
+                ConstraintConfig::Capacity { is_multi_dim: false } => {
+                    Arc::new(CapacityConstraintModule::<SingleDimLoad>::new(code))
+                }
+                ConstraintConfig::Capacity { is_multi_dim: true } => {
+                    Arc::new(CapacityConstraintModule::<MultiDimLoad>::new(code))
+                }
+                ConstraintConfig::TourSize { limit } => {
+                    let limit = *limit;
+                    Arc::new(TourSizeModule::new(Arc::new(move |_| Some(limit)), Arc::new(|_| None), code))
+                }
+                ConstraintConfig::DrivingTime => {
+                    Arc::new(DrivingTimeModule::new(transport.clone(), activity.clone(), code))
+                }
+            };
+            pipeline.add_module(module);
+        });
+
+        let objectives = self
+            .objectives
+            .iter()
+            .map(|objectives| {
+                objectives
+                    .iter()
+                    .map(|objective| {
+                        let (module, objective) = create_objective(objective, &transport, &activity);
+                        if let Some(module) = module {
+                            pipeline.add_module(module);
+                        }
+                        objective
+                    })
+                    .collect()
+            })
+            .collect();
+
+        (pipeline, ProblemObjective::new(objectives))
+    }
+}
+
+fn create_objective(
+    objective: &ObjectiveConfig,
+    transport: &Arc<dyn TransportCost + Send + Sync>,
+    activity: &Arc<dyn ActivityCost + Send + Sync>,
+) -> (Option<TargetConstraint>, TargetObjective) {
+    let with_module = |(module, objective): (TargetConstraint, TargetObjective)| (Some(module), objective);
+
+    match objective {
+        ObjectiveConfig::MinimizeUnassigned => (None, Arc::new(TotalUnassignedJobs::default())),
+        ObjectiveConfig::MinimizeTours => {
+            (Some(Arc::new(FleetUsageConstraintModule::new_minimized())), Arc::new(TotalRoutes::new_minimized()))
+        }
+        ObjectiveConfig::MaximizeTours => {
+            (Some(Arc::new(FleetUsageConstraintModule::new_maximized())), Arc::new(TotalRoutes::new_maximized()))
+        }
+        ObjectiveConfig::MinimizeCost => (None, TotalCost::minimize()),
+        ObjectiveConfig::MinimizeDistance => (None, TotalDistance::minimize()),
+        ObjectiveConfig::MinimizeDuration => (None, TotalDuration::minimize()),
+        ObjectiveConfig::MinimizeArrivalTime => {
+            (Some(Arc::new(FleetUsageConstraintModule::new_earliest())), Arc::new(MinimizeArrivalTime::default()))
+        }
+        ObjectiveConfig::MinimizeMaxDuration => with_module(Minimax::new_duration_minimized()),
+        ObjectiveConfig::MinimizeWaitingTime => {
+            with_module(TotalWaitingTime::minimize(activity.clone(), transport.clone()))
+        }
+        ObjectiveConfig::MinimizeMaxWaitingTime { percentile } => {
+            with_module(Minimax::new_waiting_time_minimized(*percentile))
+        }
+        ObjectiveConfig::MinimizeAffinityPenalty => with_module(VehicleAffinity::minimize_penalty()),
+        ObjectiveConfig::BalanceActivities { threshold } => with_module(WorkBalance::new_activity_balanced(*threshold)),
+        ObjectiveConfig::BalanceDistance { threshold } => with_module(WorkBalance::new_distance_balanced(*threshold)),
+        ObjectiveConfig::BalanceDuration { threshold } => with_module(WorkBalance::new_duration_balanced(*threshold)),
+    }
+}
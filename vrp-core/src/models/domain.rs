@@ -12,6 +12,7 @@ use std::sync::Arc;
 pub type Extras = HashMap<String, Arc<dyn Any + Send + Sync>, BuildHasherDefault<FxHasher>>;
 
 /// Defines VRP problem.
+#[derive(Clone)]
 pub struct Problem {
     /// Specifies used fleet.
     pub fleet: Arc<Fleet>,
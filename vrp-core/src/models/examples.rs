@@ -34,6 +34,8 @@ fn create_example_jobs(fleet: &Fleet, transport: &Arc<dyn TransportCost + Sync +
                 location: Some(1),
                 duration: 0.0,
                 times: vec![TimeSpan::Window(TimeWindow::new(0., 100.))],
+                setup: 0.,
+                lateness: None,
             }],
             dimens: Default::default(),
         }))],
@@ -57,6 +59,7 @@ fn create_example_fleet() -> Arc<Fleet> {
         details: vec![VehicleDetail {
             start: Some(VehiclePlace { location: 0, time: TimeInterval::default() }),
             end: None,
+            is_open: false,
         }],
     })];
 
@@ -3,6 +3,7 @@
 pub(crate) const OP_START_MSG: &str = "Optional start is not yet implemented.";
 
 pub mod common;
+pub mod config;
 
 mod domain;
 pub use self::domain::*;
@@ -6,7 +6,7 @@ use crate::construction::heuristics::InsertionContext;
 use crate::models::common::*;
 use crate::models::problem::{Actor, TargetObjective};
 use crate::models::solution::{Activity, Route};
-use crate::solver::objectives::{TotalCost, TotalRoutes, TotalUnassignedJobs};
+use crate::solver::objectives::{TotalCost, TotalRoutes, TotalUnassignedJobs, WeightedComposite};
 use hashbrown::HashMap;
 use rand::prelude::SliceRandom;
 use rosomaxa::algorithms::nsga2::dominance_order;
@@ -20,12 +20,79 @@ use std::sync::Arc;
 /// A hierarchical multi objective for vehicle routing problem.
 pub struct ProblemObjective {
     objectives: Vec<Vec<TargetObjective>>,
+    is_totally_ordered: bool,
 }
 
 impl ProblemObjective {
     /// Creates an instance of `InsertionObjective`.
     pub fn new(objectives: Vec<Vec<TargetObjective>>) -> Self {
-        Self { objectives }
+        Self { objectives, is_totally_ordered: false }
+    }
+
+    /// Creates a builder which allows to compose hierarchical and weighted objectives.
+    pub fn builder() -> ProblemObjectiveBuilder {
+        ProblemObjectiveBuilder::default()
+    }
+}
+
+/// Provides the way to build `ProblemObjective` using fluent interface style. Each call adds a new
+/// level of hierarchy: the earlier level is added, the higher its priority.
+#[derive(Default)]
+pub struct ProblemObjectiveBuilder {
+    levels: Vec<ObjectiveLevel>,
+}
+
+enum ObjectiveLevel {
+    Dominance(Vec<TargetObjective>),
+    Weighted(Vec<(TargetObjective, f64)>),
+}
+
+impl ProblemObjectiveBuilder {
+    /// Adds a level with objectives which are compared using pareto dominance.
+    pub fn with_level(mut self, objectives: Vec<TargetObjective>) -> Self {
+        self.levels.push(ObjectiveLevel::Dominance(objectives));
+        self
+    }
+
+    /// Adds a level with a single objective which is a weighted sum of given objectives.
+    pub fn with_weighted_level(mut self, objectives: Vec<(TargetObjective, f64)>) -> Self {
+        self.levels.push(ObjectiveLevel::Weighted(objectives));
+        self
+    }
+
+    /// Builds a problem objective. If it consists of one weighted level only, the solver compares
+    /// solutions by scalarized value without non-dominated sorting.
+    pub fn build(self) -> Result<ProblemObjective, String> {
+        if self.levels.is_empty() {
+            return Err("no objectives specified".to_string());
+        }
+
+        let is_totally_ordered = matches!(self.levels.as_slice(), [ObjectiveLevel::Weighted(_)]);
+
+        let objectives = self
+            .levels
+            .into_iter()
+            .map(|level| match level {
+                ObjectiveLevel::Dominance(objectives) if objectives.is_empty() => {
+                    Err("objective level cannot be empty".to_string())
+                }
+                ObjectiveLevel::Dominance(objectives) => Ok(objectives),
+                ObjectiveLevel::Weighted(objectives) if objectives.is_empty() => {
+                    Err("weighted objective level cannot be empty".to_string())
+                }
+                ObjectiveLevel::Weighted(objectives)
+                    if objectives.iter().any(|(_, weight)| !weight.is_finite() || *weight < 0.) =>
+                {
+                    Err("objective weight should be finite and non-negative".to_string())
+                }
+                ObjectiveLevel::Weighted(objectives) => {
+                    let objective: TargetObjective = Arc::new(WeightedComposite::new(objectives));
+                    Ok(vec![objective])
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ProblemObjective { objectives, is_totally_ordered })
     }
 }
 
@@ -56,6 +123,10 @@ impl MultiObjective for ProblemObjective {
     ) -> Box<dyn Iterator<Item = &'a (dyn Objective<Solution = Self::Solution> + Send + Sync)> + 'a> {
         Box::new(self.objectives.iter().flatten().map(|o| o.as_ref()))
     }
+
+    fn is_totally_ordered(&self) -> bool {
+        self.is_totally_ordered
+    }
 }
 
 impl HeuristicObjective for ProblemObjective {}
@@ -67,7 +138,7 @@ impl Shuffled for ProblemObjective {
 
         objectives.shuffle(&mut random.get_rng());
 
-        Self { objectives }
+        Self { objectives, is_totally_ordered: self.is_totally_ordered }
     }
 }
 
@@ -101,6 +172,7 @@ pub trait ActivityCost {
 
         waiting * (actor.driver.costs.per_waiting_time + actor.vehicle.costs.per_waiting_time)
             + service * (actor.driver.costs.per_service_time + actor.vehicle.costs.per_service_time)
+            + activity.place.lateness_cost(arrival)
     }
 
     /// Estimates departure time for activity and actor at given arrival time.
@@ -120,7 +192,7 @@ impl ActivityCost for SimpleActivityCost {
     }
 
     fn estimate_arrival(&self, _: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
-        activity.place.time.end.min(departure - activity.place.duration)
+        activity.place.latest_start().min(departure - activity.place.duration)
     }
 }
 
@@ -164,7 +236,7 @@ impl ActivityCost for DynamicActivityCost {
             };
 
             // NOTE: do not allow to start or restart work after break finished
-            if activity_start + extra_duration > activity.place.time.end {
+            if activity_start + extra_duration > activity.place.latest_start() {
                 // TODO this branch is the reason why departure rescheduling is disabled.
                 //      theoretically, rescheduling should be aware somehow about dynamic costs
                 f64::MAX
@@ -175,7 +247,7 @@ impl ActivityCost for DynamicActivityCost {
     }
 
     fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
-        let arrival = activity.place.time.end.min(departure - activity.place.duration);
+        let arrival = activity.place.latest_start().min(departure - activity.place.duration);
         let schedule = TimeWindow::new(arrival, departure);
 
         self.reserved_times_func.deref()(route, &schedule).map_or(arrival, |reserved_time: TimeWindow| {
@@ -349,7 +421,8 @@ impl TransportCost for TimeAgnosticMatrixTransportCost {
     }
 }
 
-/// A time aware matrix costs.
+/// A time aware matrix costs: each routing profile has multiple matrices bound to departure time slices,
+/// travel duration and distance are linearly interpolated between two nearest slices.
 struct TimeAwareMatrixTransportCost {
     costs: HashMap<usize, (Vec<u64>, Vec<MatrixData>)>,
     size: usize,
@@ -388,59 +461,74 @@ impl TimeAwareMatrixTransportCost {
         to: Location,
         travel_time: TravelTime,
     ) -> Duration {
-        let timestamp = match travel_time {
-            TravelTime::Arrival(arrival) => arrival,
-            TravelTime::Departure(departure) => departure,
-        };
+        let departure = self.get_departure(profile, from, to, travel_time);
 
-        let (timestamps, matrices) = self.costs.get(&profile.index).unwrap();
-        let data_idx = from * self.size + to;
+        profile.scale * self.interpolate(profile, from, to, departure, |matrix| &matrix.durations)
+    }
 
-        profile.scale
-            * match timestamps.binary_search(&(timestamp as u64)) {
-                Ok(matrix_idx) => *matrices.get(matrix_idx).unwrap().durations.get(data_idx).unwrap(),
-                Err(matrix_idx) if matrix_idx == 0 => *matrices.first().unwrap().durations.get(data_idx).unwrap(),
-                Err(matrix_idx) if matrix_idx == matrices.len() => {
-                    *matrices.last().unwrap().durations.get(data_idx).unwrap()
-                }
-                Err(matrix_idx) => {
-                    let left_matrix = matrices.get(matrix_idx - 1).unwrap();
-                    let right_matrix = matrices.get(matrix_idx).unwrap();
+    fn interpolate_distance(
+        &self,
+        profile: &Profile,
+        from: Location,
+        to: Location,
+        travel_time: TravelTime,
+    ) -> Distance {
+        let departure = self.get_departure(profile, from, to, travel_time);
 
-                    let left_value = *matrices.get(matrix_idx - 1).unwrap().durations.get(data_idx).unwrap();
-                    let right_value = *matrices.get(matrix_idx).unwrap().durations.get(data_idx).unwrap();
+        self.interpolate(profile, from, to, departure, |matrix| &matrix.distances)
+    }
 
-                    // perform linear interpolation
-                    let ratio = (timestamp - left_matrix.timestamp.unwrap())
-                        / (right_matrix.timestamp.unwrap() - left_matrix.timestamp.unwrap());
+    /// Returns departure time used to select time slices as matrices are bound to departure time.
+    fn get_departure(&self, profile: &Profile, from: Location, to: Location, travel_time: TravelTime) -> Timestamp {
+        match travel_time {
+            TravelTime::Departure(departure) => departure,
+            // NOTE approximate departure using travel duration at arrival time
+            TravelTime::Arrival(arrival) => {
+                let duration = profile.scale * self.interpolate(profile, from, to, arrival, |matrix| &matrix.durations);
 
-                    left_value + ratio * (right_value - left_value)
+                if duration < 0. {
+                    arrival
+                } else {
+                    arrival - duration
                 }
             }
+        }
     }
 
-    fn interpolate_distance(
+    fn interpolate(
         &self,
         profile: &Profile,
         from: Location,
         to: Location,
-        travel_time: TravelTime,
-    ) -> Distance {
-        let timestamp = match travel_time {
-            TravelTime::Arrival(arrival) => arrival,
-            TravelTime::Departure(departure) => departure,
-        };
-
+        timestamp: Timestamp,
+        values_fn: fn(&MatrixData) -> &Vec<f64>,
+    ) -> f64 {
         let (timestamps, matrices) = self.costs.get(&profile.index).unwrap();
         let data_idx = from * self.size + to;
+        let get_value = |matrix: &MatrixData| *values_fn(matrix).get(data_idx).unwrap();
 
         match timestamps.binary_search(&(timestamp as u64)) {
-            Ok(matrix_idx) => *matrices.get(matrix_idx).unwrap().distances.get(data_idx).unwrap(),
-            Err(matrix_idx) if matrix_idx == 0 => *matrices.first().unwrap().distances.get(data_idx).unwrap(),
-            Err(matrix_idx) if matrix_idx == matrices.len() => {
-                *matrices.last().unwrap().distances.get(data_idx).unwrap()
+            Ok(matrix_idx) => get_value(matrices.get(matrix_idx).unwrap()),
+            Err(0) => get_value(matrices.first().unwrap()),
+            Err(matrix_idx) if matrix_idx == matrices.len() => get_value(matrices.last().unwrap()),
+            Err(matrix_idx) => {
+                let left_matrix = matrices.get(matrix_idx - 1).unwrap();
+                let right_matrix = matrices.get(matrix_idx).unwrap();
+
+                let left_value = get_value(left_matrix);
+                let right_value = get_value(right_matrix);
+
+                // NOTE negative value means unreachable location, so it is not interpolated
+                if left_value < 0. || right_value < 0. {
+                    return left_value.min(right_value);
+                }
+
+                // perform linear interpolation
+                let ratio = (timestamp - left_matrix.timestamp.unwrap())
+                    / (right_matrix.timestamp.unwrap() - left_matrix.timestamp.unwrap());
+
+                left_value + ratio * (right_value - left_value)
             }
-            Err(matrix_idx) => *matrices.get(matrix_idx - 1).unwrap().distances.get(data_idx).unwrap(),
         }
     }
 }
@@ -61,6 +61,10 @@ pub struct VehicleDetail {
 
     /// A place where vehicle ends.
     pub end: Option<VehiclePlace>,
+
+    /// Specifies whether the route is open: vehicle doesn't return to the end place and its tour ends
+    /// at the last served job, while the latest time of the end place still limits the shift.
+    pub is_open: bool,
 }
 
 /// Represents a vehicle.
@@ -74,7 +78,8 @@ pub struct Vehicle {
     /// Dimensions which contains extra work requirements.
     pub dimens: Dimensions,
 
-    /// Specifies vehicle details.
+    /// Specifies vehicle details. Multiple details are alternatives (e.g. different depots):
+    /// the vehicle is used with at most one of them and the solver chooses the best one.
     pub details: Vec<VehicleDetail>,
 }
 
@@ -144,7 +149,7 @@ impl Fleet {
                     driver: drivers.first().unwrap().clone(),
                     detail: ActorDetail {
                         start: detail.start.clone(),
-                        end: detail.end.clone(),
+                        end: if detail.is_open { None } else { detail.end.clone() },
                         time: TimeWindow {
                             start: detail.start.as_ref().and_then(|s| s.time.earliest).unwrap_or(0.),
                             end: detail.end.as_ref().and_then(|e| e.time.latest).unwrap_or(f64::MAX),
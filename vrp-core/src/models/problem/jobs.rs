@@ -3,7 +3,7 @@
 mod jobs_test;
 
 use crate::models::common::*;
-use crate::models::problem::{Costs, Fleet, TransportCost};
+use crate::models::problem::{Actor, Costs, Fleet, TransportCost};
 use hashbrown::HashMap;
 use rosomaxa::prelude::compare_floats;
 use std::cmp::Ordering::Less;
@@ -72,6 +72,11 @@ pub struct Place {
     pub duration: Duration,
     /// Time data which specifies when work can be started.
     pub times: Vec<TimeSpan>,
+    /// A setup time (e.g. parking) spent before work is started. It is shared by consecutive
+    /// activities at the same location, so only the first of them pays it.
+    pub setup: Duration,
+    /// A penalty for late arrival which makes time windows soft. If not set, time windows are hard.
+    pub lateness: Option<LatenessPenalty>,
 }
 
 /// Represents a job which should be performed once but actual place/time might vary.
@@ -82,6 +87,52 @@ pub struct Single {
     pub dimens: Dimensions,
 }
 
+/// A trait to get or set parameters of a service duration which depends on amount of served units.
+pub trait ServiceRateDimension {
+    /// Sets amount of units handled when job is served at each of its places. Units of a place which
+    /// has its own duration per unit are zero, so vehicle's service rate is not applied there.
+    fn set_service_units(&mut self, units: Vec<f64>) -> &mut Self;
+    /// Gets amount of units handled when job is served at each of its places.
+    fn get_service_units(&self) -> Option<&Vec<f64>>;
+    /// Sets vehicle's service duration per unit.
+    fn set_service_rate(&mut self, rate: Duration) -> &mut Self;
+    /// Gets vehicle's service duration per unit.
+    fn get_service_rate(&self) -> Option<Duration>;
+}
+
+impl ServiceRateDimension for Dimensions {
+    fn set_service_units(&mut self, units: Vec<f64>) -> &mut Self {
+        self.set_value("service_units", units);
+        self
+    }
+
+    fn get_service_units(&self) -> Option<&Vec<f64>> {
+        self.get_value("service_units")
+    }
+
+    fn set_service_rate(&mut self, rate: Duration) -> &mut Self {
+        self.set_value("service_rate", rate);
+        self
+    }
+
+    fn get_service_rate(&self) -> Option<Duration> {
+        self.get_value("service_rate").cloned()
+    }
+}
+
+/// Returns a service duration of the job at the place with given index when it is served by the actor:
+/// place's duration plus a time needed to handle job's units with actor's vehicle service rate.
+pub fn get_service_duration(actor: &Actor, single: &Single, place_idx: usize, duration: Duration) -> Duration {
+    let handling = single
+        .dimens
+        .get_service_units()
+        .and_then(|units| units.get(place_idx))
+        .zip(actor.vehicle.dimens.get_service_rate())
+        .map_or(0., |(units, rate)| units * rate);
+
+    duration + handling
+}
+
 /// Represents a job which consists of multiple sub jobs.
 /// All of these jobs must be performed or none of them. Order can be controlled
 /// via specific dimension value.
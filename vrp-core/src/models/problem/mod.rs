@@ -14,6 +14,9 @@ pub use self::jobs::*;
 mod fleet;
 pub use self::fleet::*;
 
+mod summary;
+pub use self::summary::*;
+
 /// An actual objective on solution type.
 pub type TargetObjective = Arc<dyn Objective<Solution = InsertionContext> + Send + Sync>;
 
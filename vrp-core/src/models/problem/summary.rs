@@ -0,0 +1,248 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/models/problem/summary_test.rs"]
+mod summary_test;
+
+use crate::models::common::*;
+use crate::models::problem::Job;
+use crate::models::Problem;
+use hashbrown::HashSet;
+
+/// Amount of buckets used to build time window histogram.
+const TIME_WINDOW_BUCKETS: usize = 10;
+
+/// Contains statistics about problem definition which can be used to sanity check inputs or
+/// to estimate solver settings.
+#[derive(Clone, Debug)]
+pub struct ProblemSummary {
+    /// Statistics about jobs.
+    pub jobs: JobsSummary,
+    /// Statistics about fleet.
+    pub fleet: FleetSummary,
+    /// Statistics about job time windows.
+    pub time_windows: TimeWindowHistogram,
+    /// Statistics about routing matrix per profile.
+    pub routing: Vec<RoutingSummary>,
+}
+
+/// Contains statistics about jobs.
+#[derive(Clone, Debug, Default)]
+pub struct JobsSummary {
+    /// Total amount of jobs.
+    pub total: usize,
+    /// Amount of single jobs.
+    pub singles: usize,
+    /// Amount of multi jobs.
+    pub multies: usize,
+    /// Total amount of job places.
+    pub places: usize,
+    /// Total static pickup demand per dimension.
+    pub pickup_demand: Vec<i32>,
+    /// Total static delivery demand per dimension.
+    pub delivery_demand: Vec<i32>,
+}
+
+/// Contains statistics about fleet.
+#[derive(Clone, Debug, Default)]
+pub struct FleetSummary {
+    /// Amount of vehicles.
+    pub vehicles: usize,
+    /// Amount of actors (vehicle shifts).
+    pub actors: usize,
+    /// Amount of routing profiles.
+    pub profiles: usize,
+    /// Total capacity of all actors per dimension.
+    pub capacity: Vec<i32>,
+}
+
+/// Contains a histogram of job time window starts over planning horizon.
+#[derive(Clone, Debug, Default)]
+pub struct TimeWindowHistogram {
+    /// Planning horizon covered by histogram: from earliest start till latest end of job time windows.
+    pub horizon: Option<TimeWindow>,
+    /// Amount of time windows which start within a bucket. Buckets split horizon equally.
+    pub buckets: Vec<usize>,
+    /// Amount of job places without time windows or with unbounded ones only.
+    pub unrestricted: usize,
+}
+
+/// Contains statistics about routing data for one profile calculated between used locations.
+#[derive(Clone, Debug)]
+pub struct RoutingSummary {
+    /// Profile index.
+    pub profile: usize,
+    /// Amount of unique locations used by jobs and fleet.
+    pub locations: usize,
+    /// Amount of checked location pairs, excluding same location pairs.
+    pub pairs: usize,
+    /// Amount of location pairs marked as unreachable (negative distance).
+    pub unreachable: usize,
+    /// Amount of location pairs with zero distance.
+    pub zero_distance: usize,
+    /// Maximum distance between two locations.
+    pub max_distance: Distance,
+    /// Maximum duration between two locations.
+    pub max_duration: Duration,
+}
+
+impl Problem {
+    /// Returns summary statistics about problem definition.
+    pub fn summary(&self) -> ProblemSummary {
+        ProblemSummary {
+            jobs: get_jobs_summary(self),
+            fleet: get_fleet_summary(self),
+            time_windows: get_time_window_histogram(self),
+            routing: get_routing_summaries(self),
+        }
+    }
+}
+
+fn get_jobs_summary(problem: &Problem) -> JobsSummary {
+    problem.jobs.all().fold(JobsSummary::default(), |mut acc, job| {
+        acc.total += 1;
+        match &job {
+            Job::Single(_) => acc.singles += 1,
+            Job::Multi(_) => acc.multies += 1,
+        }
+        acc.places += job.places().count();
+
+        let singles: Box<dyn Iterator<Item = &Dimensions>> = match &job {
+            Job::Single(single) => Box::new(std::iter::once(&single.dimens)),
+            Job::Multi(multi) => Box::new(multi.jobs.iter().map(|single| &single.dimens)),
+        };
+
+        singles.filter_map(get_demand_as_vec).for_each(|(pickup, delivery)| {
+            add_to_vec(&mut acc.pickup_demand, pickup.as_slice());
+            add_to_vec(&mut acc.delivery_demand, delivery.as_slice());
+        });
+
+        acc
+    })
+}
+
+fn get_fleet_summary(problem: &Problem) -> FleetSummary {
+    let capacity = problem.fleet.actors.iter().fold(Vec::default(), |mut acc, actor| {
+        let dimens = &actor.vehicle.dimens;
+        let capacity = CapacityDimension::<MultiDimLoad>::get_capacity(dimens)
+            .map(|capacity| capacity.as_vec())
+            .or_else(|| CapacityDimension::<SingleDimLoad>::get_capacity(dimens).map(|capacity| vec![capacity.value]));
+
+        if let Some(capacity) = capacity {
+            add_to_vec(&mut acc, capacity.as_slice());
+        }
+
+        acc
+    });
+
+    FleetSummary {
+        vehicles: problem.fleet.vehicles.len(),
+        actors: problem.fleet.actors.len(),
+        profiles: problem.fleet.profiles.len(),
+        capacity,
+    }
+}
+
+fn get_time_window_histogram(problem: &Problem) -> TimeWindowHistogram {
+    let (time_windows, unrestricted) = problem
+        .jobs
+        .all()
+        .flat_map(|job| job.places().map(|place| place.times.clone()).collect::<Vec<_>>())
+        .fold((Vec::default(), 0), |(mut time_windows, mut unrestricted), times| {
+            // NOTE a place is unrestricted when it has no time windows or all of them are unbounded
+            let windows = times
+                .iter()
+                .filter_map(|time| time.as_time_window())
+                .filter(|tw| tw.end < f64::MAX)
+                .collect::<Vec<_>>();
+            if windows.is_empty() {
+                unrestricted += 1;
+            } else {
+                time_windows.extend(windows);
+            }
+
+            (time_windows, unrestricted)
+        });
+
+    if time_windows.is_empty() {
+        return TimeWindowHistogram { horizon: None, buckets: vec![0; TIME_WINDOW_BUCKETS], unrestricted };
+    }
+
+    let start = time_windows.iter().map(|tw| tw.start).fold(f64::MAX, f64::min);
+    let end = time_windows.iter().map(|tw| tw.end).fold(f64::MIN, f64::max);
+    let bucket_size = (end - start) / TIME_WINDOW_BUCKETS as f64;
+
+    let buckets = time_windows.iter().fold(vec![0; TIME_WINDOW_BUCKETS], |mut buckets, tw| {
+        let idx = if bucket_size > 0. { ((tw.start - start) / bucket_size) as usize } else { 0 };
+        buckets[idx.min(TIME_WINDOW_BUCKETS - 1)] += 1;
+
+        buckets
+    });
+
+    TimeWindowHistogram { horizon: Some(TimeWindow::new(start, end)), buckets, unrestricted }
+}
+
+fn get_routing_summaries(problem: &Problem) -> Vec<RoutingSummary> {
+    let locations = problem
+        .jobs
+        .all()
+        .flat_map(|job| job.places().filter_map(|place| place.location).collect::<Vec<_>>())
+        .chain(problem.fleet.actors.iter().flat_map(|actor| {
+            actor.detail.start.iter().chain(actor.detail.end.iter()).map(|place| place.location).collect::<Vec<_>>()
+        }))
+        .collect::<HashSet<_>>();
+
+    problem
+        .fleet
+        .profiles
+        .iter()
+        .map(|profile| {
+            let summary = RoutingSummary {
+                profile: profile.index,
+                locations: locations.len(),
+                pairs: 0,
+                unreachable: 0,
+                zero_distance: 0,
+                max_distance: 0.,
+                max_duration: 0.,
+            };
+
+            locations
+                .iter()
+                .flat_map(|&from| locations.iter().filter(move |&&to| to != from).map(move |&to| (from, to)))
+                .fold(summary, |mut acc, (from, to)| {
+                    let distance = problem.transport.distance_approx(profile, from, to);
+                    let duration = problem.transport.duration_approx(profile, from, to);
+
+                    acc.pairs += 1;
+
+                    if distance < 0. {
+                        acc.unreachable += 1;
+                    } else {
+                        if distance == 0. {
+                            acc.zero_distance += 1;
+                        }
+                        acc.max_distance = acc.max_distance.max(distance);
+                        acc.max_duration = acc.max_duration.max(duration);
+                    }
+
+                    acc
+                })
+        })
+        .collect()
+}
+
+fn get_demand_as_vec(dimens: &Dimensions) -> Option<(Vec<i32>, Vec<i32>)> {
+    let multi: Option<&Demand<MultiDimLoad>> = dimens.get_demand();
+    let single: Option<&Demand<SingleDimLoad>> = dimens.get_demand();
+
+    multi
+        .map(|demand| (demand.pickup.0.as_vec(), demand.delivery.0.as_vec()))
+        .or_else(|| single.map(|demand| (vec![demand.pickup.0.value], vec![demand.delivery.0.value])))
+}
+
+fn add_to_vec(acc: &mut Vec<i32>, values: &[i32]) {
+    if acc.len() < values.len() {
+        acc.resize(values.len(), 0);
+    }
+
+    acc.iter_mut().zip(values.iter()).for_each(|(acc, value)| *acc += *value);
+}
@@ -8,9 +8,12 @@ use rosomaxa::prelude::Random;
 use std::sync::Arc;
 
 /// Specifies an entity responsible for providing actors and keeping track of their usage.
+/// Actors created from different details of the same vehicle are alternatives (e.g. different
+/// depots): when one of them is used, the others are not available.
 pub struct Registry {
     available: HashMap<usize, HashSet<Arc<Actor>>>,
     index: HashMap<Arc<Actor>, usize>,
+    siblings: HashMap<Arc<Actor>, Vec<Arc<Actor>>>,
     all: Vec<Arc<Actor>>,
     random: Arc<dyn Random + Send + Sync>,
 }
@@ -24,18 +27,41 @@ impl Registry {
             .flat_map(|(group_id, actors)| actors.iter().map(|a| (a.clone(), *group_id)).collect::<Vec<_>>())
             .collect();
 
-        Self { available: fleet.groups.clone(), index, all: fleet.actors.to_vec(), random }
+        let siblings = fleet
+            .actors
+            .iter()
+            .filter(|actor| actor.vehicle.details.len() > 1)
+            .map(|actor| {
+                let siblings = fleet
+                    .actors
+                    .iter()
+                    .filter(|other| !Arc::ptr_eq(other, actor) && Arc::ptr_eq(&other.vehicle, &actor.vehicle))
+                    .cloned()
+                    .collect();
+                (actor.clone(), siblings)
+            })
+            .collect();
+
+        Self { available: fleet.groups.clone(), index, siblings, all: fleet.actors.to_vec(), random }
     }
 
-    /// Removes an actor from the list of available actors.
+    /// Removes an actor and its alternatives from the list of available actors.
     /// Returns whether the actor was present in the registry.
     pub fn use_actor(&mut self, actor: &Arc<Actor>) -> bool {
+        self.get_siblings(actor).for_each(|sibling| {
+            self.available.get_mut(self.index.get(&sibling).unwrap()).unwrap().remove(&sibling);
+        });
+
         self.available.get_mut(self.index.get(actor).unwrap()).unwrap().remove(actor)
     }
 
-    /// Adds actor to the list of available actors.
+    /// Adds actor and its alternatives to the list of available actors.
     /// Returns whether the actor was not present in the registry.
     pub fn free_actor(&mut self, actor: &Arc<Actor>) -> bool {
+        self.get_siblings(actor).for_each(|sibling| {
+            self.available.get_mut(self.index.get(&sibling).unwrap()).unwrap().insert(sibling);
+        });
+
         self.available.get_mut(self.index.get(actor).unwrap()).unwrap().insert(actor.clone())
     }
 
@@ -63,6 +89,7 @@ impl Registry {
         Self {
             available: self.available.clone(),
             index: self.index.clone(),
+            siblings: self.siblings.clone(),
             all: self.all.clone(),
             random: self.random.clone(),
         }
@@ -85,8 +112,20 @@ impl Registry {
                 .filter(|(actor, _)| filter(actor.as_ref()))
                 .map(|(actor, idx)| (actor.clone(), *idx))
                 .collect(),
+            siblings: self
+                .siblings
+                .iter()
+                .filter(|(actor, _)| filter(actor.as_ref()))
+                .map(|(actor, siblings)| {
+                    (actor.clone(), siblings.iter().filter(|sibling| filter(sibling.as_ref())).cloned().collect())
+                })
+                .collect(),
             all: self.all.iter().filter(|actor| filter(actor.as_ref())).cloned().collect(),
             random: self.random.clone(),
         }
     }
+
+    fn get_siblings(&self, actor: &Arc<Actor>) -> impl Iterator<Item = Arc<Actor>> {
+        self.siblings.get(actor).cloned().unwrap_or_default().into_iter()
+    }
 }
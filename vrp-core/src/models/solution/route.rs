@@ -1,4 +1,4 @@
-use crate::models::common::{Distance, Duration, Location, Schedule, TimeWindow};
+use crate::models::common::*;
 use crate::models::problem::{Actor, Job, Multi, Single};
 use crate::models::solution::Tour;
 use crate::utils::compare_shared;
@@ -40,6 +40,41 @@ pub struct Place {
 
     /// Specifies activity's time window: an interval when job is allowed to be started.
     pub time: TimeWindow,
+
+    /// Specifies activity's setup duration which is paid before activity is started unless the
+    /// previous activity has the same location.
+    pub setup: Duration,
+
+    /// Specifies activity's lateness penalty. If set, activity is allowed to be started after
+    /// time window end at extra cost.
+    pub lateness: Option<LatenessPenalty>,
+}
+
+impl Place {
+    /// Returns the latest time when activity can be started: time window end for hard time window
+    /// and no limit for soft one.
+    pub fn latest_start(&self) -> Timestamp {
+        if self.lateness.is_some() {
+            f64::MAX
+        } else {
+            self.time.end
+        }
+    }
+
+    /// Returns setup duration when activity is visited right after given location: setup is
+    /// skipped for activities which follow another one at the same location.
+    pub fn setup_from(&self, location: Location) -> Duration {
+        if self.location == location {
+            0.
+        } else {
+            self.setup
+        }
+    }
+
+    /// Returns lateness penalty cost for given service start time.
+    pub fn lateness_cost(&self, start: Timestamp) -> Cost {
+        self.lateness.as_ref().map_or(0., |penalty| penalty.estimate(start - self.time.end))
+    }
 }
 
 /// Represents activity which is needed to be performed.
@@ -78,7 +113,13 @@ impl Activity {
     /// Creates an activity with a job.
     pub fn new_with_job(job: Arc<Single>) -> Self {
         Activity {
-            place: Place { location: 0, duration: 0.0, time: TimeWindow { start: 0.0, end: f64::MAX } },
+            place: Place {
+                location: 0,
+                duration: 0.0,
+                time: TimeWindow { start: 0.0, end: f64::MAX },
+                setup: 0.,
+                lateness: None,
+            },
             schedule: Schedule { arrival: 0.0, departure: 0.0 },
             job: Some(job),
             commute: None,
@@ -92,6 +133,8 @@ impl Activity {
                 location: self.place.location,
                 duration: self.place.duration,
                 time: self.place.time.clone(),
+                setup: self.place.setup,
+                lateness: self.place.lateness.clone(),
             },
             schedule: self.schedule.clone(),
             job: self.job.clone(),
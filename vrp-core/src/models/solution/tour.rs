@@ -205,7 +205,7 @@ fn create_start_activity(actor: &Actor) -> Activity {
 
     Activity {
         schedule: Schedule { arrival: time.start, departure: time.start },
-        place: Place { location: start.location, duration: 0.0, time },
+        place: Place { location: start.location, duration: 0.0, time, setup: 0., lateness: None },
         job: None,
         commute: None,
     }
@@ -217,7 +217,7 @@ fn create_end_activity(actor: &Actor) -> Option<Activity> {
         let time = place.time.to_time_window();
         Activity {
             schedule: Schedule { arrival: time.start, departure: time.start },
-            place: Place { location: place.location, duration: 0.0, time },
+            place: Place { location: place.location, duration: 0.0, time, setup: 0., lateness: None },
             job: None,
             commute: None,
         }
@@ -1,4 +1,41 @@
-//! This module reimports a common used types.
+//! This module reimports a common used types and defines a stable public API surface of the crate.
+//!
+//! Types and functions re-exported here are considered as supported API: breaking changes to them
+//! are done only with a version bump which follows semantic versioning and are mentioned in the
+//! change log. Everything else, which is accessible only through the crate's modules directly,
+//! is an implementation detail and can be changed in any release.
+//!
+//! The prelude covers the following use cases:
+//! - problem definition: jobs, fleet, costs and constraints
+//! - solving: solver configuration and running
+//! - solution reading: routes, tours, activities and unassigned jobs
+//! - extension: types needed to implement custom constraints, objectives and search operators
+//!
+//! Prelude grows together with the public API, so library code should import needed items explicitly:
+//! a glob import can clash with library's own types, e.g. `Schedule` of a solution format.
+//!
+//! # Examples
+//!
+//! The following example uses only prelude to solve a problem and read its solution:
+//!
+//! ```
+//! # use vrp_core::models::examples::create_example_problem;
+//! # use std::sync::Arc;
+//! use vrp_core::prelude::*;
+//!
+//! let problem: Arc<Problem> = create_example_problem();
+//! let environment = Arc::new(Environment::default());
+//! let config: ProblemConfigBuilder = create_default_config_builder(problem.clone(), environment, TelemetryMode::None);
+//! let config = config.with_max_generations(Some(10)).build()?;
+//!
+//! let (solution, _, _): (Solution, Cost, _) = Solver::new(problem, config).solve()?;
+//! let tours: Vec<&Tour> = solution.routes.iter().map(|route: &Route| &route.tour).collect();
+//! let unassigned: Vec<&UnassignmentInfo> = solution.unassigned.iter().map(|(_, info)| info).collect();
+//!
+//! assert_eq!(tours.len(), 1);
+//! assert!(unassigned.is_empty());
+//! # Ok::<(), String>(())
+//! ```
 
 // Reimport core types
 pub use crate::solver::create_default_config_builder;
@@ -9,6 +46,36 @@ pub use crate::models::Solution;
 
 pub use rosomaxa::evolution::EvolutionConfigBuilder;
 
+// Problem definition
+pub use crate::models::common::{
+    Cost, Demand, Dimensions, Distance, Duration, IdDimension, Location, MultiDimLoad, Profile, SingleDimLoad,
+    TimeSpan, TimeWindow, Timestamp, ValueDimension,
+};
+pub use crate::models::problem::{
+    create_matrix_transport_cost, ActivityCost, Actor, Costs, Fleet, Jobs, MatrixData, Multi, ProblemObjective,
+    SimpleActivityCost, Single, TargetConstraint, TargetObjective, TransportCost, TravelTime,
+};
+pub use crate::models::{Extras, Lock, LockDetail, LockOrder, LockPosition};
+
+// Solving
+pub use crate::solver::{get_default_telemetry_mode, ProblemConfigBuilder, RefinementContext};
+
+// Solution reading
+pub use crate::construction::heuristics::UnassignmentInfo;
+pub use crate::models::solution::{Route, Tour};
+
+// Extension points
+pub use crate::construction::constraints::{
+    ActivityConstraintViolation, ConstraintModule, ConstraintPipeline, ConstraintVariant, HardActivityConstraint,
+    HardRouteConstraint, RouteConstraintViolation, SoftActivityConstraint, SoftRouteConstraint,
+};
+pub use crate::construction::heuristics::{ActivityContext, InsertionContext, RouteContext, SolutionContext};
+pub use crate::solver::search::{LocalOperator, Recreate, Ruin};
+pub use rosomaxa::prelude::{
+    HeuristicContext, HeuristicObjective, HeuristicSearchOperator, HeuristicSolution, HyperHeuristic, MultiObjective,
+    Objective, Stateful, TelemetryMode, Termination,
+};
+
 // Reimport rosomaxa utils
 pub use rosomaxa::utils::compare_floats;
 pub use rosomaxa::utils::unwrap_from_result;
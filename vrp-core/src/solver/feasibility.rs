@@ -0,0 +1,57 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/feasibility_test.rs"]
+mod feasibility_test;
+
+use crate::construction::heuristics::InsertionContext;
+use crate::models::problem::ProblemObjective;
+use rosomaxa::prelude::*;
+use std::cmp::Ordering;
+use std::sync::{Arc, Mutex};
+
+/// A function which checks whether solution satisfies business rules which are not modeled as
+/// hard constraints and evaluated only post-hoc.
+pub type FeasibilityFn = Arc<dyn Fn(&InsertionContext) -> bool + Send + Sync>;
+
+/// Keeps track of the best known solution which passes feasibility check separately from the
+/// best known overall solution, as the latter might violate business rules checked post-hoc.
+/// Every solution produced during refinement is checked, not only those which survive in population.
+#[derive(Clone)]
+pub struct FeasibleSolutionTracker {
+    feasibility_fn: FeasibilityFn,
+    best_known: Arc<Mutex<Option<InsertionContext>>>,
+}
+
+impl FeasibleSolutionTracker {
+    /// Creates a new instance of `FeasibleSolutionTracker`.
+    pub fn new(feasibility_fn: FeasibilityFn) -> Self {
+        Self { feasibility_fn, best_known: Default::default() }
+    }
+
+    /// Checks given solutions and keeps a copy of the best feasible one. Feasibility is checked only
+    /// for solutions which are better than the best known one and only the best of them is copied.
+    pub(crate) fn on_solutions<'a>(
+        &self,
+        objective: &ProblemObjective,
+        solutions: impl Iterator<Item = &'a InsertionContext>,
+    ) {
+        let mut best_known = self.best_known.lock().unwrap();
+
+        let is_better = |solution: &InsertionContext, best_known: Option<&InsertionContext>| {
+            best_known.is_none_or(|best_known| objective.total_order(solution, best_known) == Ordering::Less)
+        };
+
+        let candidate = solutions
+            .filter(|solution| is_better(solution, best_known.as_ref()))
+            .filter(|solution| (self.feasibility_fn)(solution))
+            .min_by(|a, b| objective.total_order(a, b));
+
+        if let Some(candidate) = candidate {
+            *best_known = Some(candidate.deep_copy());
+        }
+    }
+
+    /// Takes the best known feasible solution, if any was found.
+    pub fn take_best_known(&self) -> Option<InsertionContext> {
+        self.best_known.lock().unwrap().take()
+    }
+}
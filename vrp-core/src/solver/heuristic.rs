@@ -57,7 +57,13 @@ pub fn create_default_config_builder(
     telemetry_mode: TelemetryMode,
 ) -> ProblemConfigBuilder {
     let selection_size = get_default_selection_size(environment.as_ref());
-    let population = get_default_population(problem.objective.clone(), environment.clone(), selection_size);
+    let population = get_adaptive_population(
+        problem.objective.clone(),
+        environment.clone(),
+        selection_size,
+        problem.jobs.size(),
+        PopulationSizing::default(),
+    );
 
     ProblemConfigBuilder::default()
         .with_heuristic(get_default_heuristic(problem.clone(), environment.clone()))
@@ -113,14 +119,26 @@ pub fn get_static_heuristic_from_heuristic_group(
 
 /// Gets dynamic heuristic using default settings.
 pub fn get_dynamic_heuristic(problem: Arc<Problem>, environment: Arc<Environment>) -> TargetHeuristic {
+    get_dynamic_heuristic_with_time_budget(problem, environment, OperatorTimeBudget::default())
+}
+
+/// Gets dynamic heuristic using default settings and given soft time budgets of search operators.
+pub fn get_dynamic_heuristic_with_time_budget(
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    time_budget: OperatorTimeBudget,
+) -> TargetHeuristic {
     let search_operators = dynamic::get_operators(problem.clone(), environment.clone());
     let diversify_operators = create_diversify_operators(problem, environment.clone());
 
-    Box::new(DynamicSelective::<RefinementContext, ProblemObjective, InsertionContext>::new(
-        search_operators,
-        diversify_operators,
-        environment.as_ref(),
-    ))
+    Box::new(
+        DynamicSelective::<RefinementContext, ProblemObjective, InsertionContext>::new(
+            search_operators,
+            diversify_operators,
+            environment.as_ref(),
+        )
+        .with_time_budget(time_budget),
+    )
 }
 
 /// Creates elitism population algorithm.
@@ -199,6 +217,7 @@ pub fn create_context_operator_probability(
 
 pub use self::builder::create_default_init_operators;
 pub use self::builder::create_default_processing;
+pub use self::builder::create_processing;
 pub use self::statik::create_default_heuristic_operator;
 pub use self::statik::create_default_random_ruin;
 
@@ -231,14 +250,26 @@ mod builder {
 
     /// Create default processing.
     pub fn create_default_processing() -> ProcessingConfig<RefinementContext, ProblemObjective, InsertionContext> {
-        ProcessingConfig {
-            context: vec![Box::new(VicinityClustering::default())],
-            solution: vec![
-                Box::new(AdvanceDeparture::default()),
-                Box::new(UnassignmentReason::default()),
-                Box::new(VicinityClustering::default()),
-            ],
+        create_processing(false)
+    }
+
+    /// Creates processing with optional `RouteSplitter` which moves tails of over-long routes to spare
+    /// vehicles. It changes routes of the found solution, so it is disabled by default.
+    pub fn create_processing(
+        split_routes: bool,
+    ) -> ProcessingConfig<RefinementContext, ProblemObjective, InsertionContext> {
+        let mut solution: Vec<Box<dyn HeuristicSolutionProcessing<Solution = InsertionContext> + Send + Sync>> =
+            vec![Box::new(AdvanceDeparture::default())];
+
+        if split_routes {
+            solution.push(Box::new(RouteSplitter::default()));
         }
+
+        solution.push(Box::new(UnassignmentReason::default()));
+        solution.push(Box::new(VicinityClustering::default()));
+        solution.push(Box::new(MarginalCostEstimation::default()));
+
+        ProcessingConfig { context: vec![Box::new(VicinityClustering::default())], solution }
     }
 }
 
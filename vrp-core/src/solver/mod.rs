@@ -96,16 +96,19 @@ use crate::construction::heuristics::InsertionContext;
 use crate::models::common::Cost;
 use crate::models::problem::ProblemObjective;
 use crate::models::{Problem, Solution};
+use crate::solver::processing::create_solution_extras;
 use crate::solver::search::Recreate;
 use hashbrown::HashMap;
 use rosomaxa::evolution::*;
 use rosomaxa::prelude::*;
-use rosomaxa::{get_default_population, DynHeuristicPopulation, TelemetryHeuristicContext};
+use rosomaxa::{get_adaptive_population, DynHeuristicPopulation, TelemetryHeuristicContext};
 use std::any::Any;
 use std::ops::Deref;
 use std::sync::Arc;
 
+pub use self::feasibility::*;
 pub use self::heuristic::*;
+pub use self::persistence::*;
 use rosomaxa::population::Rosomaxa;
 use rosomaxa::utils::Timer;
 
@@ -113,7 +116,9 @@ pub mod objectives;
 pub mod processing;
 pub mod search;
 
+mod feasibility;
 mod heuristic;
+mod persistence;
 
 /// A key to store solution order information.
 const SOLUTION_ORDER_KEY: i32 = 1;
@@ -125,6 +130,22 @@ const BALANCE_MAX_LOAD_KEY: i32 = 20;
 const BALANCE_ACTIVITY_KEY: i32 = 21;
 const BALANCE_DISTANCE_KEY: i32 = 22;
 const BALANCE_DURATION_KEY: i32 = 23;
+const BALANCE_VALUE_KEY: i32 = 27;
+
+/// A key for minimax duration objective.
+const MINIMAX_DURATION_KEY: i32 = 24;
+
+/// A key for vehicle affinity objective.
+const AFFINITY_PENALTY_KEY: i32 = 25;
+
+/// A key for minimax waiting time objective.
+const MINIMAX_WAITING_TIME_KEY: i32 = 26;
+
+/// A key to store marginal costs estimated by solution post processing.
+const MARGINAL_COSTS_KEY: i32 = 28;
+
+/// A best known feasible solution found by solver and its cost.
+pub type FeasibleSolution = (Solution, Cost);
 
 /// A type which encapsulates information needed to perform solution refinement process.
 pub struct RefinementContext {
@@ -136,6 +157,10 @@ pub struct RefinementContext {
     pub state: HashMap<String, Box<dyn Any + Sync + Send>>,
     /// Provides some basic implementation of context functionality.
     inner_context: TelemetryHeuristicContext<ProblemObjective, InsertionContext>,
+    /// Persists the best known solution during refinement.
+    persistence: Option<SolutionPersistence>,
+    /// Keeps track of the best known feasible solution during refinement.
+    feasible_tracker: Option<FeasibleSolutionTracker>,
 }
 
 /// Defines instant refinement speed type.
@@ -158,7 +183,27 @@ impl RefinementContext {
     ) -> Self {
         let inner_context =
             TelemetryHeuristicContext::new(problem.objective.clone(), population, telemetry_mode, environment.clone());
-        Self { problem, environment, inner_context, state: Default::default() }
+        Self {
+            problem,
+            environment,
+            inner_context,
+            state: Default::default(),
+            persistence: None,
+            feasible_tracker: None,
+        }
+    }
+
+    /// Sets solution persistence which is used to save the best known solution during refinement.
+    pub fn with_persistence(mut self, persistence: SolutionPersistence) -> Self {
+        self.persistence = Some(persistence);
+        self
+    }
+
+    /// Sets feasibility check which is used to track the best known feasible solution separately
+    /// from the best known overall.
+    pub fn with_feasibility_check(mut self, feasibility_fn: FeasibilityFn) -> Self {
+        self.feasible_tracker = Some(FeasibleSolutionTracker::new(feasibility_fn));
+        self
     }
 
     /// Adds solution to population.
@@ -188,11 +233,26 @@ impl HeuristicContext for RefinementContext {
     }
 
     fn on_initial(&mut self, solution: Self::Solution, item_time: Timer) {
+        if let Some(feasible_tracker) = self.feasible_tracker.as_ref() {
+            feasible_tracker.on_solutions(self.inner_context.objective(), std::iter::once(&solution));
+        }
+
         self.inner_context.on_initial(solution, item_time)
     }
 
     fn on_generation(&mut self, offspring: Vec<Self::Solution>, termination_estimate: f64, generation_time: Timer) {
-        self.inner_context.on_generation(offspring, termination_estimate, generation_time)
+        if let Some(feasible_tracker) = self.feasible_tracker.as_ref() {
+            feasible_tracker.on_solutions(self.inner_context.objective(), offspring.iter());
+        }
+
+        self.inner_context.on_generation(offspring, termination_estimate, generation_time);
+
+        if let Some(persistence) = self.persistence.as_mut() {
+            if let Err(err) = persistence.on_generation(self.inner_context.objective(), self.inner_context.population())
+            {
+                self.environment.logger.deref()(&format!("cannot persist best known solution: '{}'", err));
+            }
+        }
     }
 
     fn on_result(self) -> HeuristicResult<Self::Objective, Self::Solution> {
@@ -293,21 +353,47 @@ impl Solver {
     /// Solves a Vehicle Routing Problem and returns a _(solution, its cost)_ pair in case of success
     /// or error description, if solution cannot be found.
     pub fn solve(self) -> Result<(Solution, Cost, Option<TelemetryMetrics>), String> {
+        self.solve_with_feasible().map(|(solution, cost, _, metrics)| (solution, cost, metrics))
+    }
+
+    /// Solves a Vehicle Routing Problem as `solve` does, but returns also the best known feasible
+    /// _(solution, its cost)_ pair, if feasibility check is set on refinement context and such
+    /// solution is found.
+    pub fn solve_with_feasible(
+        self,
+    ) -> Result<(Solution, Cost, Option<FeasibleSolution>, Option<TelemetryMetrics>), String> {
         self.config.context.environment.logger.deref()(&format!(
             "total jobs: {}, actors: {}",
             self.problem.jobs.size(),
             self.problem.fleet.actors.len()
         ));
 
-        let (mut solutions, metrics) = EvolutionSimulator::new(self.config)?.run()?;
+        let mut config = self.config;
+        let feasible_tracker = config.context.feasible_tracker.clone();
+        // NOTE solution post processing is applied here as it is needed for the best known feasible solution too
+        let solution_hooks = std::mem::take(&mut config.processing.solution);
+        let post_process = |insertion_ctx: InsertionContext| {
+            solution_hooks.iter().fold(insertion_ctx, |insertion_ctx, hook| hook.post_process(insertion_ctx))
+        };
+
+        let (mut solutions, metrics) = EvolutionSimulator::new(config)?.run()?;
 
         // NOTE select the first best individual from population
         let insertion_ctx = if solutions.is_empty() { None } else { solutions.drain(0..1).next() }
+            .map(post_process)
             .ok_or_else(|| "cannot find any solution".to_string())?;
 
-        let solution = insertion_ctx.solution.to_solution(self.problem.extras.clone());
+        let solution = insertion_ctx.solution.to_solution(create_solution_extras(&self.problem, &insertion_ctx));
         let cost = self.problem.objective.fitness(&insertion_ctx);
 
-        Ok((solution, cost, metrics))
+        let feasible =
+            feasible_tracker.and_then(|tracker| tracker.take_best_known()).map(post_process).map(|insertion_ctx| {
+                let solution =
+                    insertion_ctx.solution.to_solution(create_solution_extras(&self.problem, &insertion_ctx));
+                let cost = self.problem.objective.fitness(&insertion_ctx);
+                (solution, cost)
+            });
+
+        Ok((solution, cost, feasible, metrics))
     }
 }
@@ -0,0 +1,95 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/minimax_test.rs"]
+mod minimax_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::models::problem::{TargetConstraint, TargetObjective};
+use crate::solver::objectives::GenericValue;
+use crate::solver::*;
+use rosomaxa::prelude::compare_floats;
+use std::sync::Arc;
+
+/// A type which provides functionality needed to minimize the worst (maximum) route characteristic.
+/// In contrast to work balance objectives, it does not care about dispersion of values, only the
+/// longest route is bounded.
+pub struct Minimax {}
+
+impl Minimax {
+    /// Creates _(constraint, objective)_  type pair which minimizes maximum tour duration.
+    pub fn new_duration_minimized() -> (TargetConstraint, TargetObjective) {
+        Self::new_transport_minimized(TOTAL_DURATION_KEY, MINIMAX_DURATION_KEY)
+    }
+
+    /// Creates _(constraint, objective)_  type pair which minimizes the maximum customer waiting time:
+    /// a delay of service start relative to the start of job's time window. When percentile is
+    /// specified, e.g. 0.95, the corresponding percentile of all waiting times is minimized instead.
+    pub fn new_waiting_time_minimized(percentile: Option<f64>) -> (TargetConstraint, TargetObjective) {
+        let get_route_value = Arc::new(|rc: &RouteContext| get_customer_waiting_times(rc).fold(0., f64::max));
+
+        GenericValue::new_constrained_objective(
+            None,
+            Arc::new(|source, _| Ok(source)),
+            get_route_value.clone(),
+            Arc::new(move |ctx: &SolutionContext| match percentile {
+                Some(percentile) => {
+                    let mut waiting_times = ctx.routes.iter().flat_map(get_customer_waiting_times).collect::<Vec<_>>();
+                    waiting_times.sort_by(|a, b| compare_floats(*a, *b));
+
+                    get_percentile(waiting_times.as_slice(), percentile)
+                }
+                None => ctx.routes.iter().map(|rc| get_route_value(rc)).fold(0., f64::max),
+            }),
+            Arc::new(|solution_ctx, _, _, value| get_max_value_penalty(solution_ctx, MINIMAX_WAITING_TIME_KEY, value)),
+            MINIMAX_WAITING_TIME_KEY,
+        )
+    }
+
+    fn new_transport_minimized(transport_state_key: i32, memory_state_key: i32) -> (TargetConstraint, TargetObjective) {
+        let get_route_value = Arc::new(move |rc: &RouteContext| {
+            rc.state.get_route_state::<f64>(transport_state_key).cloned().unwrap_or(0.)
+        });
+
+        GenericValue::new_constrained_objective(
+            None,
+            Arc::new(|source, _| Ok(source)),
+            get_route_value.clone(),
+            Arc::new(move |ctx: &SolutionContext| ctx.routes.iter().map(|rc| get_route_value(rc)).fold(0., f64::max)),
+            Arc::new(move |solution_ctx, _, _, value| get_max_value_penalty(solution_ctx, memory_state_key, value)),
+            memory_state_key,
+        )
+    }
+}
+
+/// Penalizes insertion into routes proportionally to closeness of their value to the max one.
+fn get_max_value_penalty(solution_ctx: &SolutionContext, memory_state_key: i32, value: f64) -> f64 {
+    let max_value =
+        solution_ctx.state.get(&memory_state_key).and_then(|s| s.downcast_ref::<f64>()).cloned().unwrap_or(0.);
+
+    if max_value > 0. {
+        (value / max_value).min(1.) * solution_ctx.get_max_cost()
+    } else {
+        0.
+    }
+}
+
+/// Returns waiting times of customers served in the route.
+fn get_customer_waiting_times(route_ctx: &RouteContext) -> impl Iterator<Item = f64> + '_ {
+    route_ctx
+        .route
+        .tour
+        .all_activities()
+        .filter(|activity| activity.job.is_some())
+        .map(|activity| (activity.schedule.arrival - activity.place.time.start).max(0.))
+}
+
+/// Returns a percentile of sorted values using nearest rank method.
+fn get_percentile(values: &[f64], percentile: f64) -> f64 {
+    if values.is_empty() {
+        return 0.;
+    }
+
+    let rank = (percentile * values.len() as f64).ceil() as usize;
+
+    values[rank.clamp(1, values.len()) - 1]
+}
@@ -6,17 +6,26 @@ use std::cmp::Ordering;
 mod generic_value;
 pub use self::generic_value::*;
 
+mod minimax;
+pub use self::minimax::Minimax;
+
 mod minimize_arrival_time;
 pub use self::minimize_arrival_time::*;
 
+mod total_cost_limit;
+pub use self::total_cost_limit::TotalCostLimit;
+
 mod total_routes;
 pub use self::total_routes::TotalRoutes;
 
 mod total_transport;
 pub use self::total_transport::*;
 
+mod total_waiting_time;
+pub use self::total_waiting_time::TotalWaitingTime;
+
 mod total_unassigned_jobs;
-pub use self::total_unassigned_jobs::TotalUnassignedJobs;
+pub use self::total_unassigned_jobs::{TotalUnassignedJobs, UnassignedPenaltyDimension};
 
 mod total_value;
 pub use self::total_value::*;
@@ -24,5 +33,11 @@ pub use self::total_value::*;
 mod tour_order;
 pub use self::tour_order::*;
 
+mod vehicle_affinity;
+pub use self::vehicle_affinity::*;
+
+mod weighted_composite;
+pub use self::weighted_composite::WeightedComposite;
+
 mod work_balance;
 pub use self::work_balance::WorkBalance;
@@ -0,0 +1,192 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/total_cost_limit_test.rs"]
+mod total_cost_limit_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::*;
+use crate::models::common::{Cost, Distance, Duration, Location, Profile};
+use crate::models::problem::{Job, Single, TargetConstraint, TargetObjective, TransportCost};
+use crate::models::solution::{Activity, Route};
+use crate::utils::Either;
+use rosomaxa::prelude::*;
+use std::iter::once;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A type which provides functionality needed to keep total solution cost within given budget.
+/// A job insertion is rejected when the solution cost together with a lower bound estimate of job's
+/// insertion cost exceeds the budget, so such job is reported as unassigned.
+/// The objective counts budget overrun, so it should be placed above objectives which minimize
+/// unassigned jobs or maximize their value: this way the solver prefers to skip jobs instead
+/// of exceeding the budget.
+pub struct TotalCostLimit {}
+
+impl TotalCostLimit {
+    /// Creates _(constraint, objective)_  type pair which limits total cost of the solution.
+    pub fn new_constrained(
+        max_cost: Cost,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        state_key: i32,
+        constraint_code: i32,
+    ) -> (TargetConstraint, TargetObjective) {
+        assert!(max_cost > 0.);
+
+        let constraint = TotalCostLimitConstraint {
+            state_key,
+            keys: vec![state_key],
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(TotalCostLimitHardRouteConstraint {
+                max_cost,
+                transport,
+                state_key,
+                constraint_code,
+            }))],
+        };
+
+        let objective = TotalCostLimitObjective { max_cost };
+
+        (Arc::new(constraint), Arc::new(objective))
+    }
+}
+
+struct TotalCostLimitConstraint {
+    state_key: i32,
+    keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl TotalCostLimitConstraint {
+    fn update_total_cost(&self, solution_ctx: &mut SolutionContext) {
+        let total_cost = solution_ctx.get_total_cost();
+        solution_ctx.state.insert(self.state_key, Arc::new(total_cost));
+    }
+}
+
+impl ConstraintModule for TotalCostLimitConstraint {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _: usize, _: &Job) {
+        // NOTE route states are expected to be updated by transport module at this point
+        self.update_total_cost(solution_ctx);
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        self.update_total_cost(ctx);
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct TotalCostLimitHardRouteConstraint {
+    max_cost: Cost,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    state_key: i32,
+    constraint_code: i32,
+}
+
+impl TotalCostLimitHardRouteConstraint {
+    /// Estimates a lower bound of job insertion cost into the route ignoring waiting time.
+    fn estimate_job_cost(&self, route_ctx: &RouteContext, job: &Job) -> Cost {
+        let route = &route_ctx.route;
+        let singles: Vec<&Arc<Single>> = match job {
+            Job::Single(single) => vec![single],
+            Job::Multi(multi) => multi.jobs.iter().collect(),
+        };
+
+        // NOTE inserting more activities cannot make tour shorter, so the longest detour is used
+        let (distance, duration) = singles
+            .iter()
+            .map(|single| self.get_min_detour(route, single))
+            .fold((0_f64, 0_f64), |(distance, duration), detour| (distance.max(detour.0), duration.max(detour.1)));
+
+        let service: Duration = singles
+            .iter()
+            .map(|single| single.places.iter().map(|place| place.duration).fold(Duration::MAX, f64::min))
+            .filter(|&service| service < Duration::MAX)
+            .sum();
+
+        let actor = route.actor.as_ref();
+        let fixed = if route.tour.has_jobs() { 0. } else { actor.vehicle.costs.fixed + actor.driver.costs.fixed };
+
+        fixed + route_ctx.get_variable_cost(distance, duration, service) - route_ctx.get_variable_cost(0., 0., 0.)
+    }
+
+    fn get_min_detour(&self, route: &Route, single: &Single) -> (Distance, Duration) {
+        let profile = &route.actor.vehicle.profile;
+
+        let (distance, duration) = single
+            .places
+            .iter()
+            .flat_map(|place| match place.location {
+                Some(location) => {
+                    Either::Left(route.tour.legs().map(move |(items, _)| self.get_leg_detour(profile, items, location)))
+                }
+                None => Either::Right(once((0., 0.))),
+            })
+            .fold((Distance::MAX, Duration::MAX), |(distance, duration), detour| {
+                (distance.min(detour.0), duration.min(detour.1))
+            });
+
+        if distance < Distance::MAX {
+            (distance, duration)
+        } else {
+            (0., 0.)
+        }
+    }
+
+    fn get_leg_detour(&self, profile: &Profile, items: &[Activity], location: Location) -> (Distance, Duration) {
+        let distance = |from: Location, to: Location| self.transport.distance_approx(profile, from, to);
+        let duration = |from: Location, to: Location| self.transport.duration_approx(profile, from, to);
+
+        match items {
+            [from, to] => {
+                let (from, to) = (from.place.location, to.place.location);
+                (
+                    (distance(from, location) + distance(location, to) - distance(from, to)).max(0.),
+                    (duration(from, location) + duration(location, to) - duration(from, to)).max(0.),
+                )
+            }
+            [from] => (distance(from.place.location, location), duration(from.place.location, location)),
+            _ => (0., 0.),
+        }
+    }
+}
+
+impl HardRouteConstraint for TotalCostLimitHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        let total_cost =
+            solution_ctx.state.get(&self.state_key).and_then(|s| s.downcast_ref::<Cost>()).cloned().unwrap_or(0.);
+
+        if total_cost + self.estimate_job_cost(route_ctx, job) > self.max_cost {
+            Some(RouteConstraintViolation { code: self.constraint_code })
+        } else {
+            None
+        }
+    }
+}
+
+struct TotalCostLimitObjective {
+    max_cost: Cost,
+}
+
+impl Objective for TotalCostLimitObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        (solution.solution.get_total_cost() - self.max_cost).max(0.)
+    }
+}
@@ -1,26 +1,42 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/total_routes_test.rs"]
+mod total_routes_test;
+
 use super::*;
+use crate::models::problem::Actor;
 use rosomaxa::prelude::*;
+use std::sync::Arc;
 
 /// An objective function which controls total amount of routes.
 pub struct TotalRoutes {
     is_minimization: bool,
+    tier_fn: Option<TierFn>,
 }
 
+type TierFn = Arc<dyn Fn(&Actor) -> usize + Send + Sync>;
+
 impl Default for TotalRoutes {
     fn default() -> Self {
-        Self { is_minimization: true }
+        Self { is_minimization: true, tier_fn: None }
     }
 }
 
 impl TotalRoutes {
     /// Creates an instance of `TotalRoutes` with fleet minimization as a target.
     pub fn new_minimized() -> Self {
-        Self { is_minimization: true }
+        Self { is_minimization: true, tier_fn: None }
     }
 
     /// Creates an instance of `TotalRoutes` with fleet maximization as a target.
     pub fn new_maximized() -> Self {
-        Self { is_minimization: false }
+        Self { is_minimization: false, tier_fn: None }
+    }
+
+    /// Creates an instance of `TotalRoutes` with tiered fleet minimization as a target: usage of vehicles
+    /// is minimized lexicographically starting from the highest tier, so vehicle of higher tier is used
+    /// only when it cannot be replaced by any amount of vehicles of lower tiers.
+    pub fn new_tiered(tier_fn: TierFn) -> Self {
+        Self { is_minimization: true, tier_fn: Some(tier_fn) }
     }
 }
 
@@ -28,6 +44,10 @@ impl Objective for TotalRoutes {
     type Solution = InsertionContext;
 
     fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        if let Some(tier_fn) = &self.tier_fn {
+            return compare_tier_usage(get_tier_usage(a, tier_fn), get_tier_usage(b, tier_fn));
+        }
+
         let fitness_a = self.fitness(a);
         let fitness_b = self.fitness(b);
 
@@ -41,3 +61,29 @@ impl Objective for TotalRoutes {
         solution.solution.routes.len() as f64
     }
 }
+
+/// Returns amount of routes per vehicle tier, indexed by tier.
+fn get_tier_usage(solution: &InsertionContext, tier_fn: &TierFn) -> Vec<usize> {
+    solution.solution.routes.iter().map(|route_ctx| tier_fn(route_ctx.route.actor.as_ref())).fold(
+        Vec::default(),
+        |mut usage, tier| {
+            if usage.len() <= tier {
+                usage.resize(tier + 1, 0);
+            }
+            usage[tier] += 1;
+            usage
+        },
+    )
+}
+
+/// Compares tier usage lexicographically starting from the highest tier: one route of higher tier
+/// weighs more than all routes of lower tiers.
+fn compare_tier_usage(a: Vec<usize>, b: Vec<usize>) -> Ordering {
+    let count = |usage: &[usize], tier: usize| usage.get(tier).cloned().unwrap_or(0);
+
+    (0..a.len().max(b.len()))
+        .rev()
+        .map(|tier| count(&a, tier).cmp(&count(&b, tier)))
+        .find(|order| *order != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
@@ -4,15 +4,39 @@ mod total_unassigned_jobs_test;
 
 use super::*;
 use crate::construction::heuristics::UnassignmentInfo;
+use crate::models::common::{Dimensions, ValueDimension};
 use crate::models::problem::Job;
 use rosomaxa::prelude::*;
 use std::ops::Deref;
 use std::sync::Arc;
 
+const UNASSIGNED_PENALTY_DIMENSION_KEY: &str = "unassigned_penalty";
+
+/// A trait to get or set job's unassignment penalty: a prize-collecting weight of the job in
+/// the objective, so jobs with higher penalty are the last ones to be left unassigned.
+pub trait UnassignedPenaltyDimension {
+    /// Sets job's unassignment penalty.
+    fn set_unassigned_penalty(&mut self, penalty: f64) -> &mut Self;
+    /// Gets job's unassignment penalty.
+    fn get_unassigned_penalty(&self) -> Option<f64>;
+}
+
+impl UnassignedPenaltyDimension for Dimensions {
+    fn set_unassigned_penalty(&mut self, penalty: f64) -> &mut Self {
+        self.set_value(UNASSIGNED_PENALTY_DIMENSION_KEY, penalty);
+        self
+    }
+
+    fn get_unassigned_penalty(&self) -> Option<f64> {
+        self.get_value(UNASSIGNED_PENALTY_DIMENSION_KEY).cloned()
+    }
+}
+
 /// A type which allows to control how job is estimated in objective fitness
 pub type UnassignedJobEstimator = Arc<dyn Fn(&InsertionContext, &Job, &UnassignmentInfo) -> f64 + Send + Sync>;
 
-/// An objective function which minimizes amount of unassigned jobs as a target.
+/// An objective function which minimizes amount of unassigned jobs as a target. By default, each
+/// unassigned job is estimated by its unassignment penalty or by one if the penalty is not set.
 pub struct TotalUnassignedJobs {
     unassigned_job_estimator: UnassignedJobEstimator,
 }
@@ -41,7 +65,7 @@ impl TotalUnassignedJobs {
 
 impl Default for TotalUnassignedJobs {
     fn default() -> Self {
-        Self::new(Arc::new(|_, _, _| 1.))
+        Self::new(Arc::new(|_, job, _| job.dimens().get_unassigned_penalty().unwrap_or(1.)))
     }
 }
 
@@ -0,0 +1,133 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/total_waiting_time_test.rs"]
+mod total_waiting_time_test;
+
+use crate::construction::constraints::*;
+use crate::construction::extensions::try_advance_departure_time;
+use crate::construction::heuristics::*;
+use crate::models::common::Timestamp;
+use crate::models::problem::*;
+use crate::models::solution::{Activity, Route};
+use rosomaxa::prelude::*;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A type which provides functionality needed to minimize total waiting time: a time spent by
+/// vehicles idling at job locations before job's time window starts. It allows to trade some
+/// distance for less driver idle time.
+pub struct TotalWaitingTime {}
+
+impl TotalWaitingTime {
+    /// Creates _(constraint, objective)_  type pair which minimizes total waiting time of all tours.
+    pub fn minimize(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+    ) -> (TargetConstraint, TargetObjective) {
+        let module = WaitingTimeModule {
+            state_keys: vec![],
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(WaitingTimeSoftActivityConstraint {
+                activity,
+                transport: transport.clone(),
+            }))],
+        };
+
+        (Arc::new(module), Arc::new(WaitingTimeObjective { transport }))
+    }
+}
+
+/// NOTE total waiting time is kept in route state by transport constraint module.
+struct WaitingTimeModule {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl ConstraintModule for WaitingTimeModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Estimates waiting time change caused by activity insertion.
+struct WaitingTimeSoftActivityConstraint {
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl WaitingTimeSoftActivityConstraint {
+    fn get_arrival(&self, route: &Route, from: &Activity, to: &Activity, departure: Timestamp) -> Timestamp {
+        departure
+            + self.transport.duration(route, from.place.location, to.place.location, TravelTime::Departure(departure))
+            + to.place.setup_from(from.place.location)
+    }
+}
+
+impl SoftActivityConstraint for WaitingTimeSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        let route = route_ctx.route.as_ref();
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let arrival_at_target = self.get_arrival(route, prev, target, prev.schedule.departure);
+        let waiting_at_target = if route.tour.has_jobs() {
+            (target.place.time.start - arrival_at_target).max(0.)
+        } else {
+            // NOTE departure of an empty tour can be postponed up to the latest shift start
+            let latest_departure = route.actor.detail.start.as_ref().and_then(|s| s.time.latest).unwrap_or(f64::MAX);
+            let departure_slack = (latest_departure - prev.schedule.departure).max(0.);
+            (target.place.time.start - arrival_at_target - departure_slack).max(0.)
+        };
+
+        let next_waiting_change = if let Some(next) = activity_ctx.next {
+            let departure_at_target = self.activity.estimate_departure(route, target, arrival_at_target);
+            let old_waiting = (next.place.time.start - next.schedule.arrival).max(0.);
+            let new_waiting =
+                (next.place.time.start - self.get_arrival(route, target, next, departure_at_target)).max(0.);
+
+            new_waiting - old_waiting
+        } else {
+            0.
+        };
+
+        waiting_at_target + next_waiting_change
+    }
+}
+
+struct WaitingTimeObjective {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl WaitingTimeObjective {
+    /// Returns route waiting time which cannot be avoided by postponing route departure.
+    fn get_route_waiting(&self, route_ctx: &RouteContext) -> f64 {
+        let waiting = route_ctx.state.get_route_state::<f64>(TOTAL_WAITING_KEY).cloned().unwrap_or(0.);
+        let departure_shift = route_ctx
+            .route
+            .tour
+            .start()
+            .zip(try_advance_departure_time(route_ctx, self.transport.as_ref(), true))
+            .map_or(0., |(start, departure)| departure - start.schedule.departure);
+
+        (waiting - departure_shift).max(0.)
+    }
+}
+
+impl Objective for WaitingTimeObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.routes.iter().map(|route_ctx| self.get_route_waiting(route_ctx)).sum()
+    }
+}
@@ -5,6 +5,7 @@ mod tour_order_test;
 use crate::construction::constraints::*;
 use crate::construction::heuristics::*;
 use crate::models::problem::*;
+use crate::models::solution::Route;
 use crate::utils::Either;
 use rosomaxa::prelude::*;
 use std::cmp::Ordering;
@@ -32,6 +33,22 @@ pub type SingleOrderFn = Arc<dyn Fn(&Single) -> OrderResult + Send + Sync>;
 /// Specifies an order func as a variant of two functions.
 pub type OrderFn = Either<SingleOrderFn, ActorOrderFn>;
 
+/// Specifies tour order violations.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct OrderViolations {
+    /// Amount of activity pairs where activity with larger order precedes activity with less order.
+    pub count: usize,
+    /// Total magnitude of violations: a sum of order differences of violating pairs. A violation
+    /// caused by a job without order adds one.
+    pub magnitude: f64,
+}
+
+impl OrderViolations {
+    fn merge(self, other: Self) -> Self {
+        Self { count: self.count + other.count, magnitude: self.magnitude + other.magnitude }
+    }
+}
+
 /// Allows to control desired activity order in tours.
 pub struct TourOrder {}
 
@@ -40,7 +57,14 @@ impl TourOrder {
     /// order can be assigned after a job with larger order in the tour. Violations are counted by the
     /// objective.
     pub fn new_unconstrained(order_fn: OrderFn, state_key: i32) -> (TargetConstraint, TargetObjective) {
-        Self::new_objective(order_fn, state_key, None)
+        Self::new_objective(order_fn, state_key, None, false)
+    }
+
+    /// Creates instances of unconstrained tour order logic where violations are graded: the objective
+    /// minimizes total magnitude of violations instead of their amount, so swapping jobs with close
+    /// order values is preferred over swapping jobs with distant ones.
+    pub fn new_graded(order_fn: OrderFn, state_key: i32) -> (TargetConstraint, TargetObjective) {
+        Self::new_objective(order_fn, state_key, None, true)
     }
 
     /// Creates instances of constrained tour order logic: a job with less order cannot be assigned after
@@ -50,13 +74,14 @@ impl TourOrder {
         state_key: i32,
         constraint_code: i32,
     ) -> (TargetConstraint, TargetObjective) {
-        Self::new_objective(order_fn, state_key, Some(constraint_code))
+        Self::new_objective(order_fn, state_key, Some(constraint_code), false)
     }
 
     fn new_objective(
         order_fn: OrderFn,
         state_key: i32,
         constraint_code: Option<i32>,
+        is_graded: bool,
     ) -> (TargetConstraint, TargetObjective) {
         let constraints = if let Some(constraint_code) = constraint_code {
             vec![
@@ -82,7 +107,7 @@ impl TourOrder {
         };
 
         // TODO do not use this objective for constrained variant as there should be no violations?
-        let objective = OrderActivityObjective { order_fn, state_key };
+        let objective = OrderActivityObjective { order_fn, state_key, is_graded };
 
         (Arc::new(constraint), Arc::new(objective))
     }
@@ -194,6 +219,7 @@ impl SoftActivityConstraint for TourOrderSoftActivityConstraint {
 struct OrderActivityObjective {
     order_fn: OrderFn,
     state_key: i32,
+    is_graded: bool,
 }
 
 impl Objective for OrderActivityObjective {
@@ -202,12 +228,18 @@ impl Objective for OrderActivityObjective {
     fn fitness(&self, solution: &Self::Solution) -> f64 {
         let solution = &solution.solution;
 
-        solution
+        let violations = solution
             .state
             .get(&self.state_key)
-            .and_then(|s| s.downcast_ref::<usize>())
+            .and_then(|s| s.downcast_ref::<OrderViolations>())
             .cloned()
-            .unwrap_or_else(|| get_violations(solution.routes.as_slice(), &self.order_fn)) as f64
+            .unwrap_or_else(|| get_violations(solution.routes.as_slice(), &self.order_fn));
+
+        if self.is_graded {
+            violations.magnitude
+        } else {
+            violations.count as f64
+        }
     }
 }
 
@@ -240,35 +272,37 @@ fn evaluate_result<T>(
     }
 }
 
-fn get_violations(routes: &[RouteContext], order_fn: &OrderFn) -> usize {
+/// Returns tour order violations of the route.
+pub fn get_route_order_violations(route: &Route, order_fn: &OrderFn) -> OrderViolations {
+    let orders = route
+        .tour
+        .all_activities()
+        .filter_map(|activity| activity.job.as_ref())
+        .map(|single| match order_fn {
+            Either::Left(left) => left.deref()(single.as_ref()),
+            Either::Right(right) => right.deref()(route.actor.as_ref(), single.as_ref()),
+        })
+        .filter(|order| !matches!(order, OrderResult::Ignored))
+        .collect::<Vec<OrderResult>>();
+
+    orders.windows(2).fold(OrderViolations::default(), |acc, pair| match *pair {
+        [prev, next] if compare_order_results(prev, next) == Ordering::Greater => {
+            let magnitude = match (prev, next) {
+                (OrderResult::Value(prev), OrderResult::Value(next)) => prev - next,
+                _ => 1.,
+            };
+
+            acc.merge(OrderViolations { count: 1, magnitude })
+        }
+        _ => acc,
+    })
+}
+
+fn get_violations(routes: &[RouteContext], order_fn: &OrderFn) -> OrderViolations {
     routes
         .iter()
-        .map(|route_ctx| {
-            let orders = route_ctx
-                .route
-                .tour
-                .all_activities()
-                .filter_map(|activity| activity.job.as_ref())
-                .map(|single| match order_fn {
-                    Either::Left(left) => left.deref()(single.as_ref()),
-                    Either::Right(right) => right.deref()(route_ctx.route.actor.as_ref(), single.as_ref()),
-                })
-                .filter(|order| !matches!(order, OrderResult::Ignored))
-                .collect::<Vec<OrderResult>>();
-
-            orders.windows(2).fold(0_usize, |acc, pair| {
-                let value = match *pair {
-                    [prev, next] => match compare_order_results(prev, next) {
-                        Ordering::Greater => 1,
-                        _ => 0,
-                    },
-                    _ => unreachable!(),
-                };
-
-                acc + value
-            })
-        })
-        .sum::<usize>()
+        .map(|route_ctx| get_route_order_violations(route_ctx.route.as_ref(), order_fn))
+        .fold(OrderViolations::default(), OrderViolations::merge)
 }
 
 fn compare_order_results(left: OrderResult, right: OrderResult) -> Ordering {
@@ -0,0 +1,82 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/vehicle_affinity_test.rs"]
+mod vehicle_affinity_test;
+
+use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::models::common::{Dimensions, IdDimension, ValueDimension};
+use crate::models::problem::{Actor, Job, TargetConstraint, TargetObjective};
+use crate::solver::objectives::GenericValue;
+use crate::solver::*;
+use hashbrown::HashSet;
+use std::sync::Arc;
+
+/// Specifies preferred vehicles or drivers of the job, e.g. to keep customer-driver familiarity.
+#[derive(Clone, Debug)]
+pub struct JobAffinity {
+    /// Ids of preferred vehicles or drivers, matched against their `id` dimension.
+    pub actors: HashSet<String>,
+    /// A penalty applied when the job is served by an actor which is not preferred.
+    pub penalty: f64,
+}
+
+impl JobAffinity {
+    /// Returns a penalty of serving the job by given actor.
+    pub fn get_penalty(&self, actor: &Actor) -> f64 {
+        let is_preferred = [actor.vehicle.dimens.get_id(), actor.driver.dimens.get_id()]
+            .into_iter()
+            .flatten()
+            .any(|id| self.actors.contains(id));
+
+        if is_preferred {
+            0.
+        } else {
+            self.penalty
+        }
+    }
+}
+
+/// A trait to get or set job's vehicle affinity.
+pub trait AffinityDimension {
+    /// Sets job's affinity.
+    fn set_job_affinity(&mut self, affinity: JobAffinity) -> &mut Self;
+    /// Gets job's affinity.
+    fn get_job_affinity(&self) -> Option<&JobAffinity>;
+}
+
+impl AffinityDimension for Dimensions {
+    fn set_job_affinity(&mut self, affinity: JobAffinity) -> &mut Self {
+        self.set_value("job_affinity", affinity);
+        self
+    }
+
+    fn get_job_affinity(&self) -> Option<&JobAffinity> {
+        self.get_value("job_affinity")
+    }
+}
+
+/// A type which provides functionality needed to minimize total penalty of jobs served by actors
+/// which are not their preferred ones. The penalty is also added to insertion cost.
+pub struct VehicleAffinity {}
+
+impl VehicleAffinity {
+    /// Creates _(constraint, objective)_  type pair which minimizes total affinity penalty.
+    pub fn minimize_penalty() -> (TargetConstraint, TargetObjective) {
+        let get_job_penalty = |actor: &Actor, job: &Job| {
+            job.dimens().get_job_affinity().map_or(0., |affinity| affinity.get_penalty(actor))
+        };
+        let get_route_penalty = Arc::new(move |route_ctx: &RouteContext| {
+            route_ctx.route.tour.jobs().map(|job| get_job_penalty(&route_ctx.route.actor, &job)).sum::<f64>()
+        });
+
+        GenericValue::new_constrained_objective(
+            None,
+            Arc::new(|source, _| Ok(source)),
+            get_route_penalty.clone(),
+            Arc::new(move |ctx: &SolutionContext| {
+                ctx.routes.iter().map(|route_ctx| get_route_penalty(route_ctx)).sum()
+            }),
+            Arc::new(move |_, route_ctx, job, _| get_job_penalty(&route_ctx.route.actor, job)),
+            AFFINITY_PENALTY_KEY,
+        )
+    }
+}
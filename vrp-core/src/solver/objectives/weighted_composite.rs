@@ -0,0 +1,36 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/weighted_composite_test.rs"]
+mod weighted_composite_test;
+
+use super::*;
+use crate::models::problem::TargetObjective;
+use rosomaxa::prelude::*;
+
+/// An objective function which scalarizes multiple objectives into one value using a weighted sum
+/// of their fitness values. It is an alternative to hierarchical objective: instead of strict
+/// priority between objectives, their trade off is controlled by weights.
+///
+/// Please note, that weights are applied only when solutions are compared: soft constraints which
+/// accompany the objectives still guide job insertion with their own, non weighted, costs.
+pub struct WeightedComposite {
+    objectives: Vec<(TargetObjective, f64)>,
+}
+
+impl WeightedComposite {
+    /// Creates a new instance of `WeightedComposite` from objectives and their weights.
+    pub fn new(objectives: Vec<(TargetObjective, f64)>) -> Self {
+        Self { objectives }
+    }
+}
+
+impl Objective for WeightedComposite {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        self.objectives.iter().map(|(objective, weight)| weight * objective.fitness(solution)).sum()
+    }
+}
@@ -2,7 +2,7 @@ use crate::construction::constraints::*;
 use crate::construction::heuristics::{RouteContext, SolutionContext};
 use crate::models::common::{CapacityDimension, LoadOps};
 use crate::models::problem::{TargetConstraint, TargetObjective};
-use crate::solver::objectives::GenericValue;
+use crate::solver::objectives::{GenericValue, SimpleValueFn};
 use crate::solver::*;
 use rosomaxa::algorithms::math::get_cv_safe;
 use std::cmp::Ordering;
@@ -88,6 +88,29 @@ impl WorkBalance {
         Self::new_transport_balanced(threshold, TOTAL_DURATION_KEY, BALANCE_DURATION_KEY)
     }
 
+    /// Creates _(constraint, objective)_  type pair which balances total value of served jobs across
+    /// all tours, e.g. revenue earned by each driver.
+    pub fn new_value_balanced(threshold: Option<f64>, value_fn: SimpleValueFn) -> (TargetConstraint, TargetObjective) {
+        let get_route_value = Arc::new(move |rc: &RouteContext| rc.route.tour.jobs().map(|job| value_fn(&job)).sum());
+
+        GenericValue::new_constrained_objective(
+            threshold,
+            Arc::new(|source, _| Ok(source)),
+            Arc::new({
+                let get_route_value = get_route_value.clone();
+                move |rc: &RouteContext| get_route_value(rc)
+            }),
+            Arc::new({
+                let get_route_value = get_route_value.clone();
+                move |ctx: &SolutionContext| {
+                    get_cv_safe(ctx.routes.iter().map(|rc| get_route_value(rc)).collect::<Vec<_>>().as_slice())
+                }
+            }),
+            Arc::new(|solution_ctx, _, _, value| value * solution_ctx.get_max_cost()),
+            BALANCE_VALUE_KEY,
+        )
+    }
+
     fn new_transport_balanced(
         threshold: Option<f64>,
         transport_state_key: i32,
@@ -0,0 +1,78 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/persistence_test.rs"]
+mod persistence_test;
+
+use crate::construction::heuristics::InsertionContext;
+use crate::models::problem::ProblemObjective;
+use rosomaxa::prelude::*;
+use rosomaxa::utils::Timer;
+use rosomaxa::DynHeuristicPopulation;
+use std::sync::Arc;
+
+/// Specifies how often the best known solution is persisted during refinement.
+/// When neither interval nor improvements limit is set, every change of the best known
+/// solution is persisted.
+#[derive(Clone, Debug, Default)]
+pub struct PersistencePolicy {
+    /// Minimum interval between two persisted solutions in seconds.
+    pub interval: Option<u64>,
+    /// Amount of changes of the best known solution which triggers its persistence.
+    pub improvements: Option<usize>,
+}
+
+/// A function which persists given solution, e.g. writes it to disk.
+pub type PersistenceFn = Arc<dyn Fn(&InsertionContext) -> Result<(), String> + Send + Sync>;
+
+/// Keeps track of the best known solution and persists it according to the policy, so that
+/// a usable solution is available even if refinement process is interrupted unexpectedly.
+pub struct SolutionPersistence {
+    policy: PersistencePolicy,
+    persist_fn: PersistenceFn,
+    last_fitness: Option<Vec<f64>>,
+    improvements: usize,
+    timer: Timer,
+}
+
+impl SolutionPersistence {
+    /// Creates a new instance of `SolutionPersistence`.
+    pub fn new(policy: PersistencePolicy, persist_fn: PersistenceFn) -> Self {
+        Self { policy, persist_fn, last_fitness: None, improvements: 0, timer: Timer::start() }
+    }
+
+    /// Checks the best known solution and persists it if policy conditions are met.
+    /// Returns an error from persistence function, if it fails.
+    pub(crate) fn on_generation(
+        &mut self,
+        objective: &ProblemObjective,
+        population: &DynHeuristicPopulation<ProblemObjective, InsertionContext>,
+    ) -> Result<(), String> {
+        let best = if let Some((best, _)) = population.ranked().next() { best } else { return Ok(()) };
+
+        let fitness = objective.objectives().map(|objective| objective.fitness(best)).collect::<Vec<_>>();
+        if self.last_fitness.as_ref() != Some(&fitness) {
+            self.last_fitness = Some(fitness);
+            self.improvements += 1;
+        }
+
+        // NOTE nothing to persist: the best known solution is already persisted
+        if self.improvements == 0 {
+            return Ok(());
+        }
+
+        let is_interval_reached =
+            matches!(self.policy.interval, Some(interval) if self.timer.elapsed_secs() >= interval);
+        let is_improvements_reached = self
+            .policy
+            .improvements
+            .map_or(self.policy.interval.is_none(), |improvements| self.improvements >= improvements);
+
+        if !is_interval_reached && !is_improvements_reached {
+            return Ok(());
+        }
+
+        self.improvements = 0;
+        self.timer = Timer::start();
+
+        (self.persist_fn)(best)
+    }
+}
@@ -0,0 +1,159 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/processing/marginal_cost_test.rs"]
+mod marginal_cost_test;
+
+use super::*;
+use crate::construction::heuristics::*;
+use crate::models::common::{Cost, ValueDimension};
+use crate::models::problem::Job;
+use crate::models::Extras;
+use crate::solver::MARGINAL_COSTS_KEY;
+use rosomaxa::utils::parallel_collect;
+use std::sync::Arc;
+
+/// A trait to get or set marginal cost estimation settings and results.
+pub trait MarginalCostDimension {
+    /// Sets amount of repair passes used by marginal cost estimation.
+    fn set_marginal_cost_passes(&mut self, repair_passes: usize) -> &mut Self;
+    /// Gets amount of repair passes used by marginal cost estimation.
+    fn get_marginal_cost_passes(&self) -> Option<usize>;
+    /// Sets estimated marginal costs of assigned jobs.
+    fn set_marginal_costs(&mut self, marginal_costs: Vec<(Job, Cost)>) -> &mut Self;
+    /// Gets estimated marginal costs of assigned jobs.
+    fn get_marginal_costs(&self) -> Option<&Vec<(Job, Cost)>>;
+}
+
+impl MarginalCostDimension for Extras {
+    fn set_marginal_cost_passes(&mut self, repair_passes: usize) -> &mut Self {
+        self.set_value("marginal_cost_passes", repair_passes);
+        self
+    }
+
+    fn get_marginal_cost_passes(&self) -> Option<usize> {
+        self.get_value("marginal_cost_passes").cloned()
+    }
+
+    fn set_marginal_costs(&mut self, marginal_costs: Vec<(Job, Cost)>) -> &mut Self {
+        self.set_value("marginal_costs", marginal_costs);
+        self
+    }
+
+    fn get_marginal_costs(&self) -> Option<&Vec<(Job, Cost)>> {
+        self.get_value("marginal_costs")
+    }
+}
+
+/// Estimates marginal costs of the final solution when it is requested via problem extras.
+/// Estimation results are kept in solution state and are exposed via solution extras.
+#[derive(Default)]
+pub struct MarginalCostEstimation {}
+
+impl HeuristicSolutionProcessing for MarginalCostEstimation {
+    type Solution = InsertionContext;
+
+    fn post_process(&self, solution: Self::Solution) -> Self::Solution {
+        let mut insertion_ctx = solution;
+
+        if let Some(repair_passes) = insertion_ctx.problem.extras.get_marginal_cost_passes() {
+            let marginal_costs = estimate_marginal_costs(&insertion_ctx, repair_passes);
+            insertion_ctx.solution.state.insert(MARGINAL_COSTS_KEY, Arc::new(marginal_costs));
+        }
+
+        insertion_ctx
+    }
+}
+
+/// Estimates marginal cost of each assigned job: a route cost delta if the job is removed from its
+/// route and the route is repaired. The route is always repaired by recalculating its schedule,
+/// then given amount of repair passes is applied: within each pass, every remaining job of the route
+/// is reinserted at its best position. More passes give more accurate estimation, but take more time.
+pub fn estimate_marginal_costs(insertion_ctx: &InsertionContext, repair_passes: usize) -> Vec<(Job, Cost)> {
+    let constraint = insertion_ctx.problem.constraint.as_ref();
+
+    parallel_collect(&insertion_ctx.solution.routes, |route_ctx| {
+        let route_cost = route_ctx.get_route_cost();
+
+        route_ctx
+            .route
+            .tour
+            .jobs()
+            .map(|job| {
+                let mut new_route_ctx = route_ctx.deep_copy();
+                new_route_ctx.route_mut().tour.remove(&job);
+                constraint.accept_route_state(&mut new_route_ctx);
+
+                let new_route_ctx = repair_route(insertion_ctx, new_route_ctx, repair_passes);
+                // NOTE route without jobs is not used, so its fixed cost is not paid
+                let new_route_cost =
+                    if new_route_ctx.route.tour.has_jobs() { new_route_ctx.get_route_cost() } else { 0. };
+
+                (job, route_cost - new_route_cost)
+            })
+            .collect::<Vec<_>>()
+    })
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Repairs the route by reinserting its jobs at their best positions while it leads to cost improvement.
+fn repair_route(insertion_ctx: &InsertionContext, route_ctx: RouteContext, repair_passes: usize) -> RouteContext {
+    let constraint = insertion_ctx.problem.constraint.as_ref();
+    let leg_selector = AllLegSelector::default();
+    let result_selector = BestResultSelector::default();
+
+    (0..repair_passes)
+        .try_fold(route_ctx, |route_ctx, _| {
+            let jobs = route_ctx
+                .route
+                .tour
+                .jobs()
+                .filter(|job| !insertion_ctx.solution.locked.contains(job))
+                .collect::<Vec<_>>();
+
+            let (route_ctx, is_improved) =
+                jobs.into_iter().fold((route_ctx, false), |(route_ctx, is_improved), job| {
+                    let mut new_route_ctx = route_ctx.deep_copy();
+                    new_route_ctx.route_mut().tour.remove(&job);
+                    constraint.accept_route_state(&mut new_route_ctx);
+
+                    let eval_ctx = EvaluationContext {
+                        constraint,
+                        job: &job,
+                        leg_selector: &leg_selector,
+                        result_selector: &result_selector,
+                    };
+
+                    let result = evaluate_job_insertion_in_route(
+                        insertion_ctx,
+                        &eval_ctx,
+                        &new_route_ctx,
+                        InsertionPosition::Any,
+                        InsertionResult::make_failure(),
+                    );
+
+                    match result {
+                        InsertionResult::Success(success) => {
+                            success.activities.into_iter().for_each(|(activity, index)| {
+                                new_route_ctx.route_mut().tour.insert_at(activity, index + 1);
+                            });
+                            constraint.accept_route_state(&mut new_route_ctx);
+
+                            if new_route_ctx.get_route_cost() < route_ctx.get_route_cost() {
+                                (new_route_ctx, true)
+                            } else {
+                                (route_ctx, is_improved)
+                            }
+                        }
+                        InsertionResult::Failure(_) => (route_ctx, is_improved),
+                    }
+                });
+
+            if is_improved {
+                Ok(route_ctx)
+            } else {
+                Err(route_ctx)
+            }
+        })
+        .unwrap_or_else(|route_ctx| route_ctx)
+}
@@ -1,13 +1,43 @@
 //! Contains pre and post processing logic.
 
 use crate::construction::heuristics::InsertionContext;
+use crate::models::common::Cost;
+use crate::models::problem::Job;
+use crate::models::{Extras, Problem};
+use crate::solver::MARGINAL_COSTS_KEY;
 use rosomaxa::prelude::*;
+use std::sync::Arc;
 
 mod advance_departure;
 pub use self::advance_departure::AdvanceDeparture;
 
+mod marginal_cost;
+pub use self::marginal_cost::{estimate_marginal_costs, MarginalCostDimension, MarginalCostEstimation};
+
+mod route_splitter;
+pub use self::route_splitter::RouteSplitter;
+
 mod unassignment_reason;
 pub use self::unassignment_reason::UnassignmentReason;
 
 mod vicinity_clustering;
 pub use self::vicinity_clustering::{VicinityClustering, VicinityDimension};
+
+/// Creates extras of the final solution: problem extras enriched with data estimated by solution
+/// post processing.
+pub(crate) fn create_solution_extras(problem: &Problem, insertion_ctx: &InsertionContext) -> Arc<Extras> {
+    let marginal_costs = insertion_ctx
+        .solution
+        .state
+        .get(&MARGINAL_COSTS_KEY)
+        .and_then(|marginal_costs| marginal_costs.downcast_ref::<Vec<(Job, Cost)>>());
+
+    if let Some(marginal_costs) = marginal_costs {
+        let mut extras: Extras = problem.extras.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+        extras.set_marginal_costs(marginal_costs.clone());
+
+        Arc::new(extras)
+    } else {
+        problem.extras.clone()
+    }
+}
@@ -0,0 +1,148 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/processing/route_splitter_test.rs"]
+mod route_splitter_test;
+
+use super::*;
+use crate::construction::constraints::{LIMIT_DURATION_KEY, TOTAL_DURATION_KEY};
+use crate::construction::heuristics::*;
+use crate::models::problem::Job;
+use rosomaxa::utils::compare_floats;
+use rosomaxa::HeuristicSolution;
+use std::cmp::Ordering;
+
+/// Repairs routes which exceed their shift time or duration limit: jobs from the route tail are
+/// removed one by one until the route is back in its limits and then greedily moved to a new route
+/// of a spare vehicle. Insertion is evaluated by the constraint pipeline, so capacity and time windows
+/// are respected. Jobs which cannot be moved are left unassigned.
+#[derive(Default)]
+pub struct RouteSplitter {}
+
+impl HeuristicSolutionProcessing for RouteSplitter {
+    type Solution = InsertionContext;
+
+    fn post_process(&self, solution: Self::Solution) -> Self::Solution {
+        if !solution.solution.routes.iter().any(is_over_long) {
+            return solution;
+        }
+
+        let mut insertion_ctx = solution.deep_copy();
+
+        let tail_jobs = cut_route_tails(&mut insertion_ctx);
+        insert_into_new_routes(&mut insertion_ctx, tail_jobs);
+
+        insertion_ctx.problem.constraint.accept_solution_state(&mut insertion_ctx.solution);
+
+        insertion_ctx
+    }
+}
+
+/// Removes jobs from the end of over-long routes and returns them in their original order.
+fn cut_route_tails(insertion_ctx: &mut InsertionContext) -> Vec<Job> {
+    let constraint = insertion_ctx.problem.constraint.clone();
+    let locked = &insertion_ctx.solution.locked;
+
+    let tail_jobs = insertion_ctx
+        .solution
+        .routes
+        .iter_mut()
+        .filter(|route_ctx| is_over_long(route_ctx))
+        .flat_map(|route_ctx| {
+            let mut tail = Vec::new();
+
+            while is_over_long(route_ctx) {
+                let job = route_ctx
+                    .route
+                    .tour
+                    .all_activities()
+                    .rev()
+                    .filter_map(|activity| activity.retrieve_job())
+                    .find(|job| !locked.contains(job));
+
+                if let Some(job) = job {
+                    route_ctx.route_mut().tour.remove(&job);
+                    constraint.accept_route_state(route_ctx);
+                    tail.push(job);
+                } else {
+                    break;
+                }
+            }
+
+            tail.into_iter().rev()
+        })
+        .collect::<Vec<_>>();
+
+    let registry = &mut insertion_ctx.solution.registry;
+    insertion_ctx.solution.routes.retain(|route_ctx| {
+        if route_ctx.route.tour.has_jobs() {
+            true
+        } else {
+            registry.free_route(route_ctx);
+            false
+        }
+    });
+
+    tail_jobs
+}
+
+/// Inserts jobs into routes created by splitting, opening a new route only when none of them fits.
+fn insert_into_new_routes(insertion_ctx: &mut InsertionContext, jobs: Vec<Job>) {
+    let leg_selector = VariableLegSelector::new(insertion_ctx.environment.random.clone());
+    let result_selector = BestResultSelector::default();
+    let constraint = insertion_ctx.problem.constraint.clone();
+    let mut split_routes: Vec<usize> = Vec::new();
+
+    jobs.into_iter().for_each(|job| {
+        let eval_ctx = EvaluationContext {
+            constraint: &constraint,
+            job: &job,
+            leg_selector: &leg_selector,
+            result_selector: &result_selector,
+        };
+
+        let evaluate = |routes: Vec<RouteContext>| {
+            routes.iter().fold(InsertionResult::make_failure(), |acc, route_ctx| {
+                evaluate_job_insertion_in_route(insertion_ctx, &eval_ctx, route_ctx, InsertionPosition::Any, acc)
+            })
+        };
+
+        let result =
+            match evaluate(split_routes.iter().map(|&idx| insertion_ctx.solution.routes[idx].clone()).collect()) {
+                InsertionResult::Failure(_) => evaluate(insertion_ctx.solution.registry.next().collect()),
+                success => success,
+            };
+
+        match result {
+            InsertionResult::Success(success) => {
+                let is_new_route = !insertion_ctx.solution.routes.contains(&success.context);
+                apply_insertion_success(insertion_ctx, success);
+
+                if is_new_route {
+                    split_routes.push(insertion_ctx.solution.routes.len() - 1);
+                }
+            }
+            InsertionResult::Failure(failure) => {
+                let code = match failure.constraint {
+                    -1 => UnassignmentInfo::Unknown,
+                    code => UnassignmentInfo::Simple(code),
+                };
+                insertion_ctx.solution.unassigned.insert(job, code);
+            }
+        }
+    });
+}
+
+/// Checks whether route finishes after its shift end or exceeds its duration limit.
+fn is_over_long(route_ctx: &RouteContext) -> bool {
+    let tour = &route_ctx.route.tour;
+
+    let is_late =
+        tour.end().is_some_and(|end| compare_floats(end.schedule.arrival, end.place.time.end) == Ordering::Greater);
+
+    let is_too_long = route_ctx
+        .state
+        .get_route_state::<f64>(TOTAL_DURATION_KEY)
+        .zip(route_ctx.state.get_route_state::<f64>(LIMIT_DURATION_KEY))
+        .is_some_and(|(&total, &limit)| compare_floats(total, limit) == Ordering::Greater);
+
+    tour.has_jobs() && (is_late || is_too_long)
+}
@@ -144,6 +144,8 @@ impl HeuristicSolutionProcessing for VicinityClustering {
                                 location: place.location.unwrap(),
                                 duration: info.service_time,
                                 time: cluster_time.clone(),
+                                setup: 0.,
+                                lateness: None,
                             },
                             schedule: Schedule::new(arrival, departure),
                             job: Some(job),
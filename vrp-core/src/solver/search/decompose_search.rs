@@ -3,14 +3,37 @@
 mod decompose_search_test;
 
 use crate::construction::heuristics::*;
+use crate::models::common::{Dimensions, ValueDimension};
 use crate::solver::*;
-use hashbrown::HashSet;
+use hashbrown::{HashMap, HashSet};
 use rand::prelude::SliceRandom;
 use rosomaxa::utils::parallel_into_collect;
 use std::cmp::Ordering;
 use std::iter::{empty, once};
 use std::sync::RwLock;
 
+const PARTITION_DIMENSION_KEY: &str = "partition";
+
+/// A trait to get or set job's partition hint: a user defined id of the problem part, e.g. a region.
+/// Routes which serve jobs from different partitions are never refined together in decomposed search.
+pub trait PartitionDimension {
+    /// Sets job's partition id.
+    fn set_partition(&mut self, partition: String) -> &mut Self;
+    /// Gets job's partition id.
+    fn get_partition(&self) -> Option<&String>;
+}
+
+impl PartitionDimension for Dimensions {
+    fn set_partition(&mut self, partition: String) -> &mut Self {
+        self.set_value(PARTITION_DIMENSION_KEY, partition);
+        self
+    }
+
+    fn get_partition(&self) -> Option<&String> {
+        self.get_value(PARTITION_DIMENSION_KEY)
+    }
+}
+
 /// A search operator which decomposes original solution into multiple partial solutions,
 /// preforms search independently, and then merges partial solution back into one solution.
 pub struct DecomposeSearch {
@@ -94,6 +117,13 @@ fn create_multiple_insertion_contexts(
     max_routes_range: (i32, i32),
 ) -> Option<Vec<(InsertionContext, HashSet<usize>)>> {
     let mut route_groups_distances = group_routes_by_proximity(insertion_ctx)?;
+
+    // NOTE keep only routes from the same partition as neighbours, so partition hints are respected
+    let route_partitions = get_route_partitions(insertion_ctx);
+    route_groups_distances.iter_mut().enumerate().for_each(|(outer_idx, route_group_distance)| {
+        route_group_distance.retain(|(inner_idx, _)| route_partitions[outer_idx] == route_partitions[*inner_idx]);
+    });
+
     route_groups_distances.iter_mut().for_each(|route_group_distance| {
         let random = &insertion_ctx.environment.random;
         let shuffle_count = random.uniform_int(2, (route_group_distance.len() as i32 / 5).max(2)) as usize;
@@ -134,6 +164,29 @@ fn create_multiple_insertion_contexts(
     Some(insertion_ctxs)
 }
 
+/// Returns the most frequent partition of jobs for each route.
+fn get_route_partitions(insertion_ctx: &InsertionContext) -> Vec<Option<String>> {
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| {
+            route_ctx
+                .route
+                .tour
+                .jobs()
+                .filter_map(|job| job.dimens().get_partition().cloned())
+                .fold(HashMap::<String, usize>::new(), |mut acc, partition| {
+                    *acc.entry(partition).or_insert(0) += 1;
+                    acc
+                })
+                .into_iter()
+                .max_by(|(a_id, a_count), (b_id, b_count)| a_count.cmp(b_count).then_with(|| b_id.cmp(a_id)))
+                .map(|(partition, _)| partition)
+        })
+        .collect()
+}
+
 fn create_partial_insertion_ctx(
     insertion_ctx: &InsertionContext,
     route_indices: HashSet<usize>,
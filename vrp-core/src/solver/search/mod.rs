@@ -25,7 +25,7 @@ mod utils;
 pub(crate) use self::utils::*;
 
 mod decompose_search;
-pub use self::decompose_search::DecomposeSearch;
+pub use self::decompose_search::{DecomposeSearch, PartitionDimension};
 
 mod infeasible_search;
 pub use self::infeasible_search::InfeasibleSearch;
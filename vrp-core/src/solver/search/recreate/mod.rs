@@ -28,6 +28,9 @@ pub use self::recreate_with_gaps::RecreateWithGaps;
 mod recreate_with_nearest_neighbor;
 pub use self::recreate_with_nearest_neighbor::RecreateWithNearestNeighbor;
 
+mod recreate_with_nearest_routes;
+pub use self::recreate_with_nearest_routes::RecreateWithNearestRoutes;
+
 mod recreate_with_perturbation;
 pub use self::recreate_with_perturbation::RecreateWithPerturbation;
 
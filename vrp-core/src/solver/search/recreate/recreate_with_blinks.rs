@@ -122,11 +122,6 @@ impl BlinkResultSelector {
     fn new(ratio: f64, random: Arc<dyn Random + Send + Sync>) -> Self {
         Self { random, ratio }
     }
-
-    /// Creates an instance of `BlinkResultSelector` with default values.
-    fn new_with_defaults(random: Arc<dyn Random + Send + Sync>) -> Self {
-        Self::new(0.01, random)
-    }
 }
 
 impl ResultSelector for BlinkResultSelector {
@@ -171,9 +166,11 @@ pub struct RecreateWithBlinks<T: LoadOps> {
 }
 
 impl<T: LoadOps> RecreateWithBlinks<T> {
-    /// Creates a new instance of `RecreateWithBlinks`.
+    /// Creates a new instance of `RecreateWithBlinks`. A blink ratio specifies probability to skip
+    /// the best insertion position.
     pub fn new(
         selectors: Vec<(Box<dyn JobSelector + Send + Sync>, usize)>,
+        blink_ratio: f64,
         random: Arc<dyn Random + Send + Sync>,
     ) -> Self {
         let weights = selectors.iter().map(|(_, weight)| *weight).collect();
@@ -181,7 +178,7 @@ impl<T: LoadOps> RecreateWithBlinks<T> {
             job_selectors: selectors.into_iter().map(|(selector, _)| selector).collect(),
             route_selector: Box::new(AllRouteSelector::default()),
             leg_selector: Box::new(VariableLegSelector::new(random.clone())),
-            result_selector: Box::new(BlinkResultSelector::new_with_defaults(random)),
+            result_selector: Box::new(BlinkResultSelector::new(blink_ratio, random)),
             insertion_heuristic: Default::default(),
             weights,
             phantom: PhantomData,
@@ -190,6 +187,11 @@ impl<T: LoadOps> RecreateWithBlinks<T> {
 
     /// Creates a new instance of `RecreateWithBlinks` with default prameters.
     pub fn new_with_defaults(random: Arc<dyn Random + Send + Sync>) -> Self {
+        Self::new_with_ratio(0.01, random)
+    }
+
+    /// Creates a new instance of `RecreateWithBlinks` with default job selectors and given blink ratio.
+    pub fn new_with_ratio(blink_ratio: f64, random: Arc<dyn Random + Send + Sync>) -> Self {
         Self::new(
             vec![
                 (Box::new(AllJobSelector::default()), 10),
@@ -199,6 +201,7 @@ impl<T: LoadOps> RecreateWithBlinks<T> {
                 (Box::new(RankedJobSelector::new(true)), 5),
                 (Box::new(RankedJobSelector::new(false)), 1),
             ],
+            blink_ratio,
             random,
         )
     }
@@ -0,0 +1,40 @@
+use crate::construction::heuristics::InsertionContext;
+use crate::construction::heuristics::*;
+use crate::solver::search::recreate::Recreate;
+use crate::solver::search::ConfigurableRecreate;
+use crate::solver::RefinementContext;
+use rosomaxa::prelude::Random;
+use std::sync::Arc;
+
+/// A recreate method which is similar to cheapest insertion heuristic, but evaluates each job
+/// only within a shortlist of spatially close routes. It falls back to all routes when job cannot
+/// be inserted into any of shortlisted ones. Intended to speed up recreate on problems with many routes.
+pub struct RecreateWithNearestRoutes {
+    recreate: ConfigurableRecreate,
+}
+
+impl RecreateWithNearestRoutes {
+    /// Creates a new instance of `RecreateWithNearestRoutes`.
+    pub fn new(max_routes: usize, max_samples: usize, random: Arc<dyn Random + Send + Sync>) -> Self {
+        Self {
+            recreate: ConfigurableRecreate::new(
+                Box::new(AllJobSelector::default()),
+                Box::new(AllRouteSelector::default()),
+                Box::new(VariableLegSelector::new(random)),
+                Box::new(BestResultSelector::default()),
+                InsertionHeuristic::new(Box::new(NearestRouteInsertionEvaluator::new(max_routes, max_samples))),
+            ),
+        }
+    }
+
+    /// Creates a new instance of `RecreateWithNearestRoutes` with default parameters.
+    pub fn new_with_defaults(random: Arc<dyn Random + Send + Sync>) -> Self {
+        Self::new(16, 8, random)
+    }
+}
+
+impl Recreate for RecreateWithNearestRoutes {
+    fn run(&self, refinement_ctx: &RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        self.recreate.run(refinement_ctx, insertion_ctx)
+    }
+}
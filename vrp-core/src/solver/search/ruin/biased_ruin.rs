@@ -0,0 +1,50 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/ruin/biased_ruin_test.rs"]
+mod biased_ruin_test;
+
+use super::*;
+use crate::construction::heuristics::InsertionContext;
+use crate::models::problem::Actor;
+use crate::solver::RefinementContext;
+
+/// A function which returns probability of the route served by given actor to be ruined.
+pub type RouteBiasFn = Arc<dyn Fn(&Actor) -> f64 + Send + Sync>;
+
+/// A ruin decorator which keeps some routes stable by hiding them from the inner ruin method.
+/// Each route is exposed to the inner ruin with probability returned by route bias function:
+/// `1` (or greater) means that the route is always exposed, `0` means that it is never ruined.
+/// This allows to keep stable backbone routes (e.g. long-haul ones) while optimizing flexible ones.
+pub struct BiasedRuin {
+    inner: Arc<dyn Ruin + Send + Sync>,
+    route_bias: RouteBiasFn,
+}
+
+impl BiasedRuin {
+    /// Creates a new instance of `BiasedRuin`.
+    pub fn new(inner: Arc<dyn Ruin + Send + Sync>, route_bias: RouteBiasFn) -> Self {
+        Self { inner, route_bias }
+    }
+}
+
+impl Ruin for BiasedRuin {
+    fn run(&self, refinement_ctx: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+        let random = insertion_ctx.environment.random.clone();
+
+        let (exposed, protected): (Vec<_>, Vec<_>) = insertion_ctx.solution.routes.drain(0..).partition(|route_ctx| {
+            let bias = (self.route_bias)(route_ctx.route.actor.as_ref());
+            bias >= 1. || (bias > 0. && random.is_hit(bias))
+        });
+
+        insertion_ctx.solution.routes = exposed;
+
+        let mut insertion_ctx = if insertion_ctx.solution.routes.is_empty() {
+            insertion_ctx
+        } else {
+            self.inner.run(refinement_ctx, insertion_ctx)
+        };
+
+        insertion_ctx.solution.routes.extend(protected);
+
+        insertion_ctx
+    }
+}
@@ -14,6 +14,9 @@ pub trait Ruin {
 mod adjusted_string_removal;
 pub use self::adjusted_string_removal::AdjustedStringRemoval;
 
+mod biased_ruin;
+pub use self::biased_ruin::{BiasedRuin, RouteBiasFn};
+
 mod cluster_removal;
 pub use self::cluster_removal::ClusterRemoval;
 
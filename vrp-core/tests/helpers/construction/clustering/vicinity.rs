@@ -116,6 +116,7 @@ pub fn create_cluster_config() -> ClusterConfig {
             moving_duration: 10.,
             moving_distance: 10.,
             min_shared_time: None,
+            min_time_window_overlap: None,
             smallest_time_window: None,
             max_jobs_per_cluster: None,
         },
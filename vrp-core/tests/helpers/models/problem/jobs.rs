@@ -10,7 +10,7 @@ pub const DEFAULT_ACTIVITY_TIME_WINDOW: TimeWindow = TimeWindow { start: 0., end
 pub type TestPlace = (Option<Location>, Duration, Vec<(f64, f64)>);
 
 pub fn test_place_with_location(location: Option<Location>) -> Place {
-    Place { location, duration: DEFAULT_JOB_DURATION, times: vec![DEFAULT_JOB_TIME_SPAN] }
+    Place { location, duration: DEFAULT_JOB_DURATION, times: vec![DEFAULT_JOB_TIME_SPAN], setup: 0., lateness: None }
 }
 
 pub fn test_single() -> Single {
@@ -119,6 +119,8 @@ impl SingleBuilder {
                 location: p.0,
                 duration: p.1,
                 times: p.2.into_iter().map(|(start, end)| TimeSpan::Window(TimeWindow::new(start, end))).collect(),
+                setup: 0.,
+                lateness: None,
             })
             .collect();
 
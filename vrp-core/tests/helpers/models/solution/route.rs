@@ -14,7 +14,13 @@ pub fn test_activity() -> Activity {
 
 pub fn test_activity_with_location(location: Location) -> Activity {
     Activity {
-        place: Place { location, duration: DEFAULT_JOB_DURATION, time: DEFAULT_ACTIVITY_TIME_WINDOW },
+        place: Place {
+            location,
+            duration: DEFAULT_JOB_DURATION,
+            time: DEFAULT_ACTIVITY_TIME_WINDOW,
+            setup: 0.,
+            lateness: None,
+        },
         schedule: Schedule::new(location as f64, location as f64 + DEFAULT_JOB_DURATION),
         job: Some(test_single_with_location(Some(location))),
         commute: None,
@@ -23,7 +29,7 @@ pub fn test_activity_with_location(location: Location) -> Activity {
 
 pub fn test_activity_with_location_and_duration(location: Location, duration: Duration) -> Activity {
     Activity {
-        place: Place { location, duration, time: DEFAULT_ACTIVITY_TIME_WINDOW },
+        place: Place { location, duration, time: DEFAULT_ACTIVITY_TIME_WINDOW, setup: 0., lateness: None },
         schedule: Schedule::new(location as f64, location as f64 + DEFAULT_JOB_DURATION),
         job: Some(test_single_with_location(Some(location))),
         commute: None,
@@ -32,7 +38,7 @@ pub fn test_activity_with_location_and_duration(location: Location, duration: Du
 
 pub fn test_activity_with_location_and_tw(location: Location, tw: TimeWindow) -> Activity {
     Activity {
-        place: Place { location, duration: DEFAULT_JOB_DURATION, time: tw },
+        place: Place { location, duration: DEFAULT_JOB_DURATION, time: tw, setup: 0., lateness: None },
         schedule: Schedule::new(location as f64, location as f64 + DEFAULT_JOB_DURATION),
         job: Some(test_single_with_location(Some(location))),
         commute: None,
@@ -41,7 +47,7 @@ pub fn test_activity_with_location_and_tw(location: Location, tw: TimeWindow) ->
 
 pub fn test_activity_with_location_tw_and_duration(location: Location, tw: TimeWindow, duration: Duration) -> Activity {
     Activity {
-        place: Place { location, duration, time: tw },
+        place: Place { location, duration, time: tw, setup: 0., lateness: None },
         schedule: Schedule::new(location as f64, location as f64 + duration),
         job: Some(test_single_with_location(Some(location))),
         commute: None,
@@ -54,6 +60,8 @@ pub fn test_activity_with_schedule(schedule: Schedule) -> Activity {
             location: DEFAULT_JOB_LOCATION,
             duration: DEFAULT_JOB_DURATION,
             time: DEFAULT_ACTIVITY_TIME_WINDOW,
+            setup: 0.,
+            lateness: None,
         },
         schedule,
         job: None,
@@ -67,6 +75,8 @@ pub fn test_activity_with_job(job: Arc<Single>) -> Activity {
             location: DEFAULT_JOB_LOCATION,
             duration: DEFAULT_JOB_DURATION,
             time: DEFAULT_ACTIVITY_TIME_WINDOW,
+            setup: 0.,
+            lateness: None,
         },
         schedule: DEFAULT_ACTIVITY_SCHEDULE,
         job: Some(job),
@@ -80,6 +90,8 @@ pub fn test_activity_without_job() -> Activity {
             location: DEFAULT_JOB_LOCATION,
             duration: DEFAULT_JOB_DURATION,
             time: DEFAULT_ACTIVITY_TIME_WINDOW,
+            setup: 0.,
+            lateness: None,
         },
         schedule: DEFAULT_ACTIVITY_SCHEDULE,
         job: None,
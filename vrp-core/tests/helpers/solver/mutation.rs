@@ -90,6 +90,8 @@ pub fn rearrange_jobs_in_routes(insertion_ctx: &mut InsertionContext, job_order:
                     location: place.location.unwrap(),
                     duration: place.duration,
                     time: place.times.first().unwrap().to_time_window(0.),
+                    setup: 0.,
+                    lateness: None,
                 },
                 schedule: Schedule::new(0., 0.),
                 job: Some(job),
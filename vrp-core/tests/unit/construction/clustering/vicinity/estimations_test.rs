@@ -69,6 +69,7 @@ parameterized_test! {can_get_dissimilarities, (places_outer, places_inner, thres
         moving_duration: threshold.0,
         moving_distance: threshold.1,
         min_shared_time: threshold.2,
+        min_time_window_overlap: None,
         smallest_time_window: None,
         max_jobs_per_cluster: None,
     };
@@ -174,6 +175,32 @@ can_get_dissimilarities! {
     ),
 }
 
+parameterized_test! {can_filter_dissimilarities_by_time_window_overlap, (outer_time, inner_time, min_overlap, is_expected), {
+    let threshold = ThresholdPolicy { min_time_window_overlap: Some(min_overlap), ..create_cluster_config().threshold };
+    let expected = if is_expected {
+        vec![(0, create_cluster_info(SingleBuilder::default().build_as_job_ref(), 3., 0, (1, 1., 1.), (1, 1., 1.)))]
+    } else {
+        Vec::default()
+    };
+
+    can_get_dissimilarities_impl(
+        vec![(Some(1), 2., vec![outer_time])],
+        vec![(Some(2), 3., vec![inner_time])],
+        threshold,
+        ServingPolicy::Original { parking: 0. },
+        expected,
+    );
+}}
+
+can_filter_dissimilarities_by_time_window_overlap! {
+    case_01_same_windows: ((0., 10.), (0., 10.), 1., true),
+    case_02_half_overlap: ((0., 10.), (5., 15.), 0.5, true),
+    case_03_half_overlap: ((0., 10.), (5., 15.), 0.6, false),
+    case_04_narrow_and_wide: ((0., 10.), (0., 100.), 0.5, false),
+    case_05_narrow_and_wide: ((0., 10.), (0., 100.), 0.1, true),
+    case_06_no_overlap: ((0., 10.), (20., 30.), 0., false),
+}
+
 fn can_get_dissimilarities_impl(
     places_outer: Vec<TestPlace>,
     places_inner: Vec<TestPlace>,
@@ -435,6 +462,7 @@ pub fn can_get_clusters_impl(
         moving_duration,
         moving_distance: 10.0,
         min_shared_time: None,
+        min_time_window_overlap: None,
         smallest_time_window: None,
         max_jobs_per_cluster,
     };
@@ -4,8 +4,8 @@ use crate::helpers::construction::constraints::*;
 use crate::helpers::models::domain::create_empty_solution_context;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
-use crate::models::common::{Demand, DemandDimension, SingleDimLoad};
-use crate::models::problem::{Job, Vehicle};
+use crate::models::common::*;
+use crate::models::problem::{Job, Single, Vehicle};
 use crate::models::solution::Activity;
 use std::sync::Arc;
 
@@ -13,6 +13,32 @@ fn create_test_vehicle(capacity: i32) -> Vehicle {
     VehicleBuilder::default().id("v1").capacity(capacity).build()
 }
 
+fn create_test_vehicle_with_compartments(compartments: Vec<(i32, Vec<&str>)>) -> Vehicle {
+    let mut vehicle = create_test_vehicle(20);
+    vehicle.dimens.set_compartments(
+        compartments
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (capacity, classes))| Compartment {
+                id: format!("c{}", idx + 1),
+                capacity: SingleDimLoad::new(capacity),
+                classes: classes.into_iter().map(|class| class.to_string()).collect(),
+            })
+            .collect(),
+    );
+
+    vehicle
+}
+
+fn create_single_with_class(size: i32, class: Option<&str>) -> Arc<Single> {
+    let mut single = SingleBuilder::default().demand(create_simple_demand(size)).build();
+    if let Some(class) = class {
+        single.dimens.set_product_class(class.to_string());
+    }
+
+    Arc::new(single)
+}
+
 fn create_activity_violation(stopped: bool) -> Option<ActivityConstraintViolation> {
     Some(ActivityConstraintViolation { code: 2, stopped })
 }
@@ -133,6 +159,89 @@ fn can_evaluate_demand_on_activity_impl(
     assert_eq!(result, expected);
 }
 
+parameterized_test! {can_evaluate_compartments_on_activity, (existing, target, expected), {
+    can_evaluate_compartments_on_activity_impl(existing, target, expected);
+}}
+
+can_evaluate_compartments_on_activity! {
+    case01_separate_compartments: (vec![(-3, "frozen")], (-3, Some("ambient")), None),
+    case02_not_enough_capacity: (vec![(-3, "frozen")], (-2, Some("frozen")), create_activity_violation(false)),
+    case03_use_shared_compartment: (vec![(-3, "ambient")], (-3, Some("frozen")), None),
+    case04_compartments_taken: (vec![(-3, "ambient"), (-3, "ambient")], (-1, Some("frozen")), create_activity_violation(false)),
+    case05_no_class: (vec![(-3, "ambient"), (-3, "ambient")], (-1, None), None),
+}
+
+fn can_evaluate_compartments_on_activity_impl(
+    existing: Vec<(i32, &str)>,
+    target: (i32, Option<&str>),
+    expected: Option<ActivityConstraintViolation>,
+) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(create_test_vehicle_with_compartments(vec![(4, vec!["frozen", "ambient"]), (4, vec!["ambient"])]))
+        .build();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        existing
+            .into_iter()
+            .map(|(size, class)| test_activity_with_job(create_single_with_class(size, Some(class))))
+            .collect(),
+    );
+    let pipeline = create_constraint_pipeline_with_simple_capacity();
+    pipeline.accept_route_state(&mut route_ctx);
+    let target = test_activity_with_job(create_single_with_class(target.0, target.1));
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route.tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(2),
+    };
+
+    let result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_compartments_on_route, (compartments, class, expected), {
+    can_evaluate_compartments_on_route_impl(compartments, class, expected);
+}}
+
+can_evaluate_compartments_on_route! {
+    case01_allowed_class: (Some(vec![(4, vec!["frozen"])]), Some("frozen"), None),
+    case02_not_allowed_class: (Some(vec![(4, vec!["frozen"])]), Some("ambient"), Some(RouteConstraintViolation { code: 2 })),
+    case03_no_compartments: (None, Some("ambient"), None),
+    case04_no_class: (Some(vec![(4, vec!["frozen"])]), None, None),
+}
+
+fn can_evaluate_compartments_on_route_impl(
+    compartments: Option<Vec<(i32, Vec<&str>)>>,
+    class: Option<&str>,
+    expected: Option<RouteConstraintViolation>,
+) {
+    let vehicle = compartments.map_or_else(|| create_test_vehicle(20), create_test_vehicle_with_compartments);
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+    let solution_ctx = create_empty_solution_context();
+    let route_ctx = create_route_context_with_activities(&fleet, "v1", vec![]);
+    let job = Job::Single(create_single_with_class(-1, class));
+
+    let result = create_constraint_pipeline_with_simple_capacity().evaluate_hard_route(&solution_ctx, &route_ctx, &job);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_reject_merge_of_jobs_with_different_product_classes() {
+    let constraint = CapacityConstraintModule::<SingleDimLoad>::new(2);
+
+    let result = constraint.merge(
+        Job::Single(create_single_with_class(1, Some("frozen"))),
+        Job::Single(create_single_with_class(1, Some("ambient"))),
+    );
+
+    assert_eq!(result.err(), Some(2));
+}
+
 parameterized_test! {can_merge_jobs_with_demand, (cluster, candidate, expected), {
     can_merge_jobs_with_demand_impl(cluster, candidate, expected);
 }}
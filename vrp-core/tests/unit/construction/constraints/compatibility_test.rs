@@ -0,0 +1,161 @@
+use super::*;
+use crate::construction::extensions::route_intervals;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::ValueDimension;
+use crate::models::problem::Single;
+use crate::models::solution::Activity;
+
+const VIOLATION_CODE: i32 = 1;
+const STATE_KEY: i32 = 2;
+const INTERVALS_KEY: i32 = 3;
+
+fn create_test_single(compatibility: Option<&str>) -> Arc<Single> {
+    let mut single = test_single();
+    if let Some(compatibility) = compatibility {
+        single.dimens.set_value("compat", compatibility.to_string());
+    }
+
+    Arc::new(single)
+}
+
+fn create_reload_activity() -> Activity {
+    let mut single = test_single();
+    single.dimens.set_value("reload", true);
+
+    test_activity_with_job(Arc::new(single))
+}
+
+fn create_compatibility_fn() -> CompatibilityFn {
+    Arc::new(|job| job.dimens().get_value::<String>("compat"))
+}
+
+fn create_trip_scope() -> CompatibilityScope {
+    CompatibilityScope::Trip(Arc::new(|route_ctx| {
+        route_ctx.state.get_route_state::<Vec<(usize, usize)>>(INTERVALS_KEY)
+    }))
+}
+
+fn create_test_route_ctx(activities: Vec<Activity>) -> RouteContext {
+    let mut route_ctx = create_route_context_with_activities(&test_fleet(), "v1", activities);
+
+    let intervals = route_intervals(&route_ctx.route, |activity| {
+        activity.job.as_ref().is_some_and(|job| job.dimens.get_value::<bool>("reload").is_some())
+    });
+    route_ctx.state_mut().put_route_state(INTERVALS_KEY, intervals);
+
+    route_ctx
+}
+
+parameterized_test! {can_use_compatibility, (job_compat, route_compat, expected), {
+    can_use_compatibility_impl(job_compat, route_compat, expected);
+}}
+
+can_use_compatibility! {
+    case_01: (Some("junk"), Some("food"), Some(())),
+    case_02: (Some("junk"), None, None),
+    case_03: (None, Some("junk"), None),
+    case_04: (Some("food"), Some("food"), None),
+}
+
+fn can_use_compatibility_impl(job_compat: Option<&str>, route_compat: Option<&str>, expected: Option<()>) {
+    let module =
+        CompatibilityModule::new(VIOLATION_CODE, STATE_KEY, create_compatibility_fn(), CompatibilityScope::Route);
+    let mut route_ctx = create_test_route_ctx(vec![test_activity_with_job(create_test_single(route_compat))]);
+    module.accept_route_state(&mut route_ctx);
+    let job = Job::Single(create_test_single(job_compat));
+
+    let result = ConstraintPipeline::default()
+        .add_module(Arc::new(module))
+        .evaluate_hard_route(&create_empty_solution_context(), &route_ctx, &job)
+        .map(|_| ());
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_accept_route_state, (route_compat, expected), {
+    can_accept_route_state_impl(route_compat, expected);
+}}
+
+can_accept_route_state! {
+    case_01: (Some("junk"), Some(Some("junk"))),
+    case_02: (None, None),
+}
+
+fn can_accept_route_state_impl(route_compat: Option<&str>, expected: Option<Option<&str>>) {
+    let expected = expected.map(|v| v.map(|v| v.to_string()));
+    let mut route_ctx = create_test_route_ctx(vec![test_activity_with_job(create_test_single(route_compat))]);
+    let module =
+        CompatibilityModule::new(VIOLATION_CODE, STATE_KEY, create_compatibility_fn(), CompatibilityScope::Route);
+
+    module.accept_route_state(&mut route_ctx);
+
+    let result = route_ctx.state.get_route_state::<Option<String>>(STATE_KEY).cloned();
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_use_trip_compatibility, (index, job_compat, expected), {
+    can_use_trip_compatibility_impl(index, job_compat, expected);
+}}
+
+can_use_trip_compatibility! {
+    case_01_first_trip_conflict: (0, Some("junk"), Some(VIOLATION_CODE)),
+    case_02_first_trip_same: (1, Some("food"), None),
+    case_03_second_trip_conflict: (2, Some("food"), Some(VIOLATION_CODE)),
+    case_04_second_trip_same: (3, Some("junk"), None),
+    case_05_no_compat: (0, None, None),
+    case_06_last_trip_no_compat: (4, Some("food"), None),
+}
+
+fn can_use_trip_compatibility_impl(index: usize, job_compat: Option<&str>, expected: Option<i32>) {
+    let module = CompatibilityModule::new(VIOLATION_CODE, STATE_KEY, create_compatibility_fn(), create_trip_scope());
+    let mut route_ctx = create_test_route_ctx(vec![
+        test_activity_with_job(create_test_single(Some("food"))),
+        create_reload_activity(),
+        test_activity_with_job(create_test_single(Some("junk"))),
+        create_reload_activity(),
+    ]);
+    module.accept_route_state(&mut route_ctx);
+    let target = test_activity_with_job(create_test_single(job_compat));
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let result = ConstraintPipeline::default()
+        .add_module(Arc::new(module))
+        .evaluate_hard_activity(&route_ctx, &activity_ctx)
+        .map(|violation| violation.code);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_merge_jobs, (source_compat, candidate_compat, expected), {
+    can_merge_jobs_impl(source_compat, candidate_compat, expected);
+}}
+
+can_merge_jobs! {
+    case_01: (Some("junk"), Some("junk"), Ok(Some("junk".to_string()))),
+    case_02: (Some("junk"), Some("food"), Err(VIOLATION_CODE)),
+    case_03: (Some("food"), Some("junk"), Err(VIOLATION_CODE)),
+    case_04: (None, None, Ok(None)),
+    case_05: (None, Some("junk"), Err(VIOLATION_CODE)),
+}
+
+fn can_merge_jobs_impl(
+    source_compat: Option<&str>,
+    candidate_compat: Option<&str>,
+    expected: Result<Option<String>, i32>,
+) {
+    let source = Job::Single(create_test_single(source_compat));
+    let candidate = Job::Single(create_test_single(candidate_compat));
+    let module =
+        CompatibilityModule::new(VIOLATION_CODE, STATE_KEY, create_compatibility_fn(), CompatibilityScope::Route);
+
+    let result = module.merge(source, candidate).map(|job| job.dimens().get_value::<String>("compat").cloned());
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,44 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+use crate::helpers::models::problem::{test_fleet, TestTransportCost};
+use crate::helpers::models::solution::{create_route_context_with_activities, test_activity_with_location};
+
+parameterized_test! {can_limit_consecutive_driving, (locations, limits, expected), {
+    can_limit_consecutive_driving_impl(locations, limits, expected);
+}}
+
+can_limit_consecutive_driving! {
+    case01_within_distance: ((0, 10, Some(20)), (Some(10.), None), None),
+    case02_prev_leg_distance: ((0, 11, Some(20)), (Some(10.), None), stop(1)),
+    case03_next_leg_distance: ((0, 10, Some(25)), (Some(10.), None), stop(1)),
+    case04_no_next: ((0, 10, None), (Some(10.), None), None),
+    case05_duration: ((0, 10, Some(20)), (None, Some(9.)), stop(1)),
+    case06_no_limits: ((0, 100, Some(200)), (None, None), None),
+}
+
+fn can_limit_consecutive_driving_impl(
+    locations: (Location, Location, Option<Location>),
+    limits: (Option<Distance>, Option<Duration>),
+    expected: Option<ActivityConstraintViolation>,
+) {
+    let (prev, target, next) = locations;
+    let route_ctx = create_route_context_with_activities(&test_fleet(), "v1", vec![]);
+    let pipeline = create_constraint_pipeline_with_module(Arc::new(ConsecutiveDrivingModule::new(
+        TestTransportCost::new_shared(),
+        Arc::new(move |_| limits),
+        1,
+    )));
+    let next = next.map(test_activity_with_location);
+
+    let result = pipeline.evaluate_hard_activity(
+        &route_ctx,
+        &ActivityContext {
+            index: 0,
+            prev: &test_activity_with_location(prev),
+            target: &test_activity_with_location(target),
+            next: next.as_ref(),
+        },
+    );
+
+    assert_eq!(result, expected);
+}
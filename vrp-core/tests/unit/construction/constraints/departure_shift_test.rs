@@ -0,0 +1,67 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_modules;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::*;
+use crate::models::problem::*;
+
+fn create_route_ctx(start_departure: f64) -> RouteContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(Vehicle {
+            details: vec![VehicleDetail {
+                start: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: Some(0.), latest: None } }),
+                ..test_vehicle_detail()
+            }],
+            ..test_vehicle_with_id("v1")
+        })
+        .build();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![
+            test_activity_with_location_and_tw(10, TimeWindow::new(0., 100.)),
+            test_activity_with_location_and_tw(20, TimeWindow::new(25., 100.)),
+            test_activity_with_location_and_tw(30, TimeWindow::new(0., 100.)),
+        ],
+    );
+    route_ctx.route_mut().tour.get_mut(0).unwrap().schedule.departure = start_departure;
+
+    route_ctx
+}
+
+parameterized_test! {can_shift_departure_time, (is_enabled, start_departure, expected), {
+    can_shift_departure_time_impl(is_enabled, start_departure, expected);
+}}
+
+can_shift_departure_time! {
+    case01_enabled: (true, 0., 5.),
+    case02_disabled: (false, 0., 0.),
+    case03_enabled_late_departure: (true, 20., 5.),
+}
+
+fn can_shift_departure_time_impl(is_enabled: bool, start_departure: f64, expected: f64) {
+    let mut route_ctx = create_route_ctx(start_departure);
+    let pipeline = create_constraint_pipeline_with_modules(vec![
+        Arc::new(TransportConstraintModule::new(TestTransportCost::new_shared(), TestActivityCost::new_shared(), 1)),
+        Arc::new(DepartureShiftModule::new(
+            TestActivityCost::new_shared(),
+            TestTransportCost::new_shared(),
+            Arc::new(move |_| is_enabled),
+        )),
+    ]);
+
+    pipeline.accept_route_state(&mut route_ctx);
+
+    let start = route_ctx.route.tour.start().unwrap();
+    assert_eq!(start.schedule.departure, expected);
+    let waiting = route_ctx
+        .route
+        .tour
+        .all_activities()
+        .map(|activity| (activity.place.time.start - activity.schedule.arrival).max(0.))
+        .sum::<f64>();
+    assert_eq!(waiting, if is_enabled { 0. } else { 5. });
+    let total_duration = route_ctx.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned();
+    assert_eq!(total_duration, Some(route_ctx.route.tour.end().unwrap().schedule.arrival - expected));
+}
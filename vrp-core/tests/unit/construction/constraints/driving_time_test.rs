@@ -0,0 +1,134 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_modules;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+const VIOLATION_CODE: i32 = 1;
+
+fn create_rules(max_working: Option<(Duration, Duration)>) -> DrivingRules {
+    DrivingRules {
+        max_driving_time: 10.,
+        break_duration: 5.,
+        max_working_time: max_working.map(|(time, _)| time),
+        rest_duration: max_working.map_or(0., |(_, duration)| duration),
+    }
+}
+
+fn create_route_ctx(rules: DrivingRules, activities: Vec<Activity>) -> (ConstraintPipeline, RouteContext) {
+    let mut vehicle = test_vehicle_with_id("v1");
+    vehicle.dimens.set_driving_rules(rules);
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+
+    let pipeline = create_constraint_pipeline_with_modules(vec![
+        Arc::new(TransportConstraintModule::new(
+            TestTransportCost::new_shared(),
+            TestActivityCost::new_shared(),
+            VIOLATION_CODE,
+        )),
+        Arc::new(DrivingTimeModule::new(
+            TestTransportCost::new_shared(),
+            TestActivityCost::new_shared(),
+            VIOLATION_CODE,
+        )),
+    ]);
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v1", activities);
+    pipeline.accept_route_state(&mut route_ctx);
+
+    (pipeline, route_ctx)
+}
+
+parameterized_test! {can_drive_with_breaks, (max_working, time, duration, expected), {
+    can_drive_with_breaks_impl(max_working, time, duration, expected);
+}}
+
+can_drive_with_breaks! {
+    case01_no_break: (None, (0., 0.), 5., ((5., 5.), vec![])),
+    case02_single_break: (None, (0., 0.), 15., ((5., 20.), vec![(10., 15.)])),
+    case03_two_breaks: (None, (0., 0.), 25., ((5., 35.), vec![(10., 15.), (25., 30.)])),
+    case04_break_before_travel: (None, (10., 10.), 5., ((5., 20.), vec![(0., 5.)])),
+    case05_rest: (Some((20., 8.)), (8., 18.), 5., ((3., 3.), vec![(2., 10.)])),
+    case06_break_and_rest: (Some((20., 8.)), (0., 0.), 20., ((5., 5.), vec![(10., 15.), (20., 28.)])),
+}
+
+fn can_drive_with_breaks_impl(
+    max_working: Option<(Duration, Duration)>,
+    time: (Duration, Duration),
+    duration: Duration,
+    expected: ((Duration, Duration), Vec<(Timestamp, Timestamp)>),
+) {
+    let rules = create_rules(max_working);
+
+    let (time, breaks) = rules.drive(DrivingTime { driving: time.0, working: time.1 }, 0., duration);
+
+    assert_eq!((time.driving, time.working), expected.0);
+    assert_eq!(breaks.into_iter().map(|time| (time.start, time.end)).collect::<Vec<_>>(), expected.1);
+}
+
+parameterized_test! {can_reset_driving_time_on_stop, (max_working, duration, expected), {
+    can_reset_driving_time_on_stop_impl(max_working, duration, expected);
+}}
+
+can_reset_driving_time_on_stop! {
+    case01_short_stop: (Some((20., 8.)), 1., (6., 11.)),
+    case02_break_stop: (Some((20., 8.)), 5., (0., 15.)),
+    case03_rest_stop: (Some((20., 8.)), 8., (0., 0.)),
+    case04_long_stop_without_rest: (None, 8., (0., 18.)),
+}
+
+fn can_reset_driving_time_on_stop_impl(
+    max_working: Option<(Duration, Duration)>,
+    duration: Duration,
+    expected: (Duration, Duration),
+) {
+    let rules = create_rules(max_working);
+
+    let time = rules.stop(DrivingTime { driving: 6., working: 10. }, duration);
+
+    assert_eq!((time.driving, time.working), expected);
+}
+
+parameterized_test! {can_update_route_schedule_with_breaks, (max_working, expected), {
+    can_update_route_schedule_with_breaks_impl(max_working, expected);
+}}
+
+can_update_route_schedule_with_breaks! {
+    case01_breaks: (None, (25., 55.)),
+    case02_breaks_and_rest: (Some((30., 8.)), (25., 63.)),
+}
+
+fn can_update_route_schedule_with_breaks_impl(
+    max_working: Option<(Duration, Duration)>,
+    expected: (Timestamp, Timestamp),
+) {
+    let (_, route_ctx) = create_route_ctx(create_rules(max_working), vec![test_activity_with_location(20)]);
+
+    let tour = &route_ctx.route.tour;
+    assert_eq!(tour.get(1).unwrap().schedule.arrival, expected.0);
+    assert_eq!(tour.end().unwrap().schedule.arrival, expected.1);
+}
+
+parameterized_test! {can_evaluate_activity_insertion, (target_tw_end, next_tw_end, expected), {
+    can_evaluate_activity_insertion_impl(target_tw_end, next_tw_end, expected);
+}}
+
+can_evaluate_activity_insertion! {
+    case01_feasible: (100., 60., None),
+    case02_next_is_late_due_to_breaks: (100., 50., Some(VIOLATION_CODE)),
+    case03_target_is_late_due_to_breaks: (35., 60., Some(VIOLATION_CODE)),
+}
+
+fn can_evaluate_activity_insertion_impl(target_tw_end: Timestamp, next_tw_end: Timestamp, expected: Option<i32>) {
+    let (pipeline, route_ctx) = create_route_ctx(
+        create_rules(None),
+        vec![test_activity_with_location_and_tw(20, TimeWindow::new(0., next_tw_end))],
+    );
+    let target = test_activity_with_location_and_tw(30, TimeWindow::new(0., target_tw_end));
+    let tour = &route_ctx.route.tour;
+
+    let result = pipeline.evaluate_hard_activity(
+        &route_ctx,
+        &ActivityContext { index: 0, prev: tour.get(0).unwrap(), target: &target, next: tour.get(1) },
+    );
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
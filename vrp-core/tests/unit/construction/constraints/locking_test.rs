@@ -1,10 +1,11 @@
-use crate::construction::constraints::locking::StrictLockingModule;
+use crate::construction::constraints::locking::{PinnedLockingModule, PinningPenaltyFn, StrictLockingModule};
 use crate::construction::constraints::{ActivityConstraintViolation, ConstraintModule, RouteConstraintViolation};
-use crate::construction::heuristics::ActivityContext;
+use crate::construction::heuristics::{ActivityContext, UnassignmentInfo};
 use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
 use crate::helpers::models::domain::create_empty_solution_context;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
+use crate::models::common::IdDimension;
 use crate::models::problem::{Job, Single};
 use crate::models::solution::Activity;
 use crate::models::{Lock, LockDetail, LockOrder, LockPosition};
@@ -195,3 +196,80 @@ fn can_handle_merge_locked_jobs() {
     assert!(constraint.merge(source.clone(), candidate1).is_err());
     assert!(constraint.merge(source, candidate2).is_ok());
 }
+
+fn create_pinning_penalty_fn() -> PinningPenaltyFn {
+    Arc::new(|actor, job| {
+        let is_pinned = job.dimens().get_id().is_some_and(|id| id == "pinned");
+        let is_pinned_actor = get_vehicle_id(actor.vehicle.as_ref()) == "v1";
+
+        if is_pinned && !is_pinned_actor {
+            Some(100.)
+        } else {
+            None
+        }
+    })
+}
+
+parameterized_test! {can_pin_jobs_to_actor, (job_id, used, is_unassigned, expected_violation, expected_penalty), {
+    can_pin_jobs_to_actor_impl(job_id, used, is_unassigned, expected_violation, expected_penalty);
+}}
+
+can_pin_jobs_to_actor! {
+    case01_pinned_actor: ("pinned", "v1", false, None, 0.),
+    case02_other_actor: ("pinned", "v2", false, Some(1), 100.),
+    case03_other_actor_relaxed: ("pinned", "v2", true, None, 100.),
+    case04_not_pinned: ("job", "v2", false, None, 0.),
+}
+
+fn can_pin_jobs_to_actor_impl(
+    job_id: &str,
+    used: &str,
+    is_unassigned: bool,
+    expected_violation: Option<i32>,
+    expected_penalty: f64,
+) {
+    let job = Job::Single(test_single_with_id(job_id));
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(test_vehicle_with_id("v1"))
+        .add_vehicle(test_vehicle_with_id("v2"))
+        .build();
+    let mut solution_ctx = create_empty_solution_context();
+    if is_unassigned {
+        solution_ctx.unassigned.insert(job.clone(), UnassignmentInfo::Unknown);
+    }
+    let route_ctx = create_route_context_with_activities(&fleet, used, vec![]);
+    let pipeline =
+        create_constraint_pipeline_with_module(Arc::new(PinnedLockingModule::new(1, 2, create_pinning_penalty_fn())));
+    pipeline.accept_solution_state(&mut solution_ctx);
+
+    let violation = pipeline.evaluate_hard_route(&solution_ctx, &route_ctx, &job).map(|violation| violation.code);
+    let penalty = pipeline.evaluate_soft_route(&solution_ctx, &route_ctx, &job);
+
+    assert_eq!(violation, expected_violation);
+    assert_eq!(penalty, expected_penalty);
+}
+
+#[test]
+fn can_keep_pinned_job_relaxed_when_served_by_other_actor() {
+    let job = Job::Single(test_single_with_id("pinned"));
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(test_vehicle_with_id("v1"))
+        .add_vehicle(test_vehicle_with_id("v2"))
+        .build();
+    let module = PinnedLockingModule::new(1, 2, create_pinning_penalty_fn());
+    let mut solution_ctx = create_empty_solution_context();
+    solution_ctx.routes.push(create_route_context_with_activities(
+        &fleet,
+        "v2",
+        vec![test_activity_with_job(job.to_single().clone())],
+    ));
+
+    module.accept_solution_state(&mut solution_ctx);
+
+    let route_ctx = create_route_context_with_activities(&fleet, "v2", vec![]);
+    let result =
+        create_constraint_pipeline_with_module(Arc::new(module)).evaluate_hard_route(&solution_ctx, &route_ctx, &job);
+    assert_eq!(result, None);
+}
@@ -0,0 +1,108 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::test_single_with_id;
+use crate::helpers::models::solution::{test_activity_without_job, test_actor};
+
+struct TestConstraintModule {
+    keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+    merge_code: Option<i32>,
+}
+
+impl ConstraintModule for TestConstraintModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        self.merge_code.map_or(Ok(source), Err)
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct TestHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for TestHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, _: &RouteContext, _: &Job) -> Option<RouteConstraintViolation> {
+        Some(RouteConstraintViolation { code: self.code })
+    }
+}
+
+struct TestHardActivityConstraint {
+    code: i32,
+}
+
+impl HardActivityConstraint for TestHardActivityConstraint {
+    fn evaluate_activity(&self, _: &RouteContext, _: &ActivityContext) -> Option<ActivityConstraintViolation> {
+        Some(ActivityConstraintViolation { code: self.code, stopped: true })
+    }
+}
+
+fn create_test_pipeline(route_code: i32, activity_code: i32) -> ConstraintPipeline {
+    let mut pipeline = ConstraintPipeline::default();
+    pipeline.add_module(Arc::new(TestConstraintModule {
+        keys: vec![1],
+        constraints: vec![
+            ConstraintVariant::HardRoute(Arc::new(TestHardRouteConstraint { code: route_code })),
+            ConstraintVariant::HardActivity(Arc::new(TestHardActivityConstraint { code: activity_code })),
+        ],
+        merge_code: Some(route_code),
+    }));
+
+    pipeline
+}
+
+fn evaluate(pipeline: &ConstraintPipeline) -> (Option<i32>, Option<i32>, Cost, Cost, bool) {
+    let solution_ctx = create_empty_solution_context();
+    let route_ctx = RouteContext::new(test_actor());
+    let job = Job::Single(test_single_with_id("job1"));
+    let (prev, target) = (test_activity_without_job(), test_activity_without_job());
+    let activity_ctx = ActivityContext { index: 0, prev: &prev, target: &target, next: None };
+
+    (
+        pipeline.evaluate_hard_route(&solution_ctx, &route_ctx, &job).map(|violation| violation.code),
+        pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx).map(|violation| violation.code),
+        pipeline.evaluate_soft_route(&solution_ctx, &route_ctx, &job),
+        pipeline.evaluate_soft_activity(&route_ctx, &activity_ctx),
+        pipeline.merge_constrained(job.clone(), job).is_ok(),
+    )
+}
+
+parameterized_test! {can_apply_constraint_overlay, (overlay, expected), {
+    can_apply_constraint_overlay_impl(overlay, expected);
+}}
+
+can_apply_constraint_overlay! {
+    case01_empty: (ConstraintOverlay::default(), (Some(1), Some(2), 0., 0., false)),
+    case02_disable_route: (ConstraintOverlay::default().with_disabled(1), (None, Some(2), 0., 0., true)),
+    case03_disable_activity: (ConstraintOverlay::default().with_disabled(2), (Some(1), None, 0., 0., false)),
+    case04_disable_both: (ConstraintOverlay::default().with_disabled(1).with_disabled(2), (None, None, 0., 0., true)),
+    case05_soften_route: (ConstraintOverlay::default().with_softened(1, 10.), (None, Some(2), 10., 0., true)),
+    case06_soften_activity: (ConstraintOverlay::default().with_softened(2, 5.), (Some(1), None, 0., 5., false)),
+    case07_unknown_code: (ConstraintOverlay::default().with_softened(3, 5.), (Some(1), Some(2), 0., 0., false)),
+}
+
+fn can_apply_constraint_overlay_impl(
+    overlay: ConstraintOverlay,
+    expected: (Option<i32>, Option<i32>, Cost, Cost, bool),
+) {
+    let pipeline = create_test_pipeline(1, 2);
+
+    let overlaid = overlay.apply(&pipeline);
+
+    assert_eq!(evaluate(&overlaid), expected);
+    assert_eq!(evaluate(&pipeline), (Some(1), Some(2), 0., 0., false));
+    assert_eq!(overlaid.modules.len(), pipeline.modules.len());
+    assert_eq!(overlaid.state_keys, pipeline.state_keys);
+}
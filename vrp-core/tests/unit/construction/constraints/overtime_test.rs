@@ -0,0 +1,82 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_modules;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+const REGULAR_END: f64 = 50.;
+const COST_PER_TIME: f64 = 2.;
+
+fn create_route_ctx(locations: Vec<Location>, has_overtime: bool) -> (ConstraintPipeline, RouteContext) {
+    let mut vehicle = test_vehicle_with_id("v1");
+    if has_overtime {
+        vehicle.dimens.set_shift_overtime(ShiftOvertime { regular_end: REGULAR_END, cost_per_time: COST_PER_TIME });
+    }
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        locations.into_iter().map(test_activity_with_location).collect(),
+    );
+    let pipeline = create_constraint_pipeline_with_modules(vec![
+        Arc::new(TransportConstraintModule::new(TestTransportCost::new_shared(), TestActivityCost::new_shared(), 1)),
+        Arc::new(OvertimeModule::new(TestActivityCost::new_shared(), TestTransportCost::new_shared())),
+    ]);
+
+    pipeline.accept_route_state(&mut route_ctx);
+
+    (pipeline, route_ctx)
+}
+
+parameterized_test! {can_update_route_state, (locations, has_overtime, expected), {
+    can_update_route_state_impl(locations, has_overtime, expected);
+}}
+
+can_update_route_state! {
+    case01_no_overtime: (vec![10, 20], true, Some(0.)),
+    case02_overtime: (vec![30], true, Some(20.)),
+    case03_no_dimension: (vec![30], false, None),
+}
+
+fn can_update_route_state_impl(locations: Vec<Location>, has_overtime: bool, expected: Option<f64>) {
+    let (_, route_ctx) = create_route_ctx(locations, has_overtime);
+
+    let result = route_ctx.state.get_route_state::<f64>(TOTAL_OVERTIME_COST_KEY).cloned();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_include_overtime_into_route_cost() {
+    let (_, with_overtime) = create_route_ctx(vec![30], true);
+    let (_, without_overtime) = create_route_ctx(vec![30], false);
+
+    assert_eq!(with_overtime.get_route_cost() - without_overtime.get_route_cost(), 20.);
+}
+
+parameterized_test! {can_estimate_insertion_cost, (index, location, has_overtime, expected), {
+    can_estimate_insertion_cost_impl(index, location, has_overtime, expected);
+}}
+
+can_estimate_insertion_cost! {
+    case01_no_delay: (0, 5, true, 0.),
+    case02_delay_within_shift: (1, 25, true, 0.),
+    case03_delay_beyond_shift: (2, 30, true, 20.),
+    case04_no_dimension: (2, 30, false, 0.),
+}
+
+fn can_estimate_insertion_cost_impl(index: usize, location: Location, has_overtime: bool, expected: f64) {
+    let (_, route_ctx) = create_route_ctx(vec![10, 20], has_overtime);
+    let module = OvertimeModule::new(TestActivityCost::new_shared(), TestTransportCost::new_shared());
+    let pipeline = create_constraint_pipeline_with_modules(vec![Arc::new(module)]);
+    let target = test_activity_with_location(location);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let result = pipeline.evaluate_soft_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result, expected);
+}
@@ -1,6 +1,7 @@
-use crate::construction::constraints::{RouteConstraintViolation, TourSizeModule};
+use crate::construction::constraints::{ConstraintModule, RouteConstraintViolation, TourSizeModule};
+use crate::construction::heuristics::{SolutionContext, UnassignmentInfo};
 use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
-use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::domain::{create_empty_solution_context, create_registry_context};
 use crate::helpers::models::problem::{test_fleet, test_multi_job_with_locations, test_single_with_id};
 use crate::helpers::models::solution::{create_route_context_with_activities, test_activity_with_location};
 use crate::models::common::Location;
@@ -42,8 +43,54 @@ fn can_limit_by_job_activities_impl(
         (0..activities).map(|idx| test_activity_with_location(idx as Location)).collect(),
     );
 
-    let result = create_constraint_pipeline_with_module(Arc::new(TourSizeModule::new(Arc::new(move |_| limit), 1)))
-        .evaluate_hard_route(&create_empty_solution_context(), &route_ctx, &job);
+    let result = create_constraint_pipeline_with_module(Arc::new(TourSizeModule::new(
+        Arc::new(move |_| limit),
+        Arc::new(|_| None),
+        1,
+    )))
+    .evaluate_hard_route(&create_empty_solution_context(), &route_ctx, &job);
 
     assert_eq!(result, expected);
 }
+
+parameterized_test! {can_eject_jobs_of_small_tour, (activities, min_limit, has_required, is_locked, expected), {
+    can_eject_jobs_of_small_tour_impl(activities, min_limit, has_required, is_locked, expected);
+}}
+
+can_eject_jobs_of_small_tour! {
+    case01_below_limit: (2, Some(3), false, false, 2),
+    case02_at_limit: (3, Some(3), false, false, 0),
+    case03_no_limit: (1, None, false, false, 0),
+    case04_has_required: (2, Some(3), true, false, 0),
+    case05_has_locked: (2, Some(3), false, true, 0),
+}
+
+fn can_eject_jobs_of_small_tour_impl(
+    activities: usize,
+    min_limit: Option<usize>,
+    has_required: bool,
+    is_locked: bool,
+    expected: usize,
+) {
+    let fleet = test_fleet();
+    let route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        (0..activities).map(|idx| test_activity_with_location(idx as Location + 1)).collect(),
+    );
+    let jobs = route_ctx.route.tour.jobs().collect::<Vec<_>>();
+    let mut solution_ctx = SolutionContext {
+        required: if has_required { vec![Job::Single(test_single_with_id("job1"))] } else { vec![] },
+        locked: if is_locked { jobs.iter().take(1).cloned().collect() } else { Default::default() },
+        routes: vec![route_ctx],
+        registry: create_registry_context(&fleet),
+        ..create_empty_solution_context()
+    };
+    let module = TourSizeModule::new(Arc::new(|_| None), Arc::new(move |_| min_limit), 1);
+
+    module.accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.unassigned.len(), expected);
+    assert!(solution_ctx.unassigned.values().all(|info| matches!(info, UnassignmentInfo::Simple(1))));
+    assert_eq!(solution_ctx.routes.len(), if expected > 0 { 0 } else { 1 });
+}
@@ -3,7 +3,7 @@ use crate::construction::heuristics::*;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
 use crate::models::common::*;
-use crate::models::problem::{VehicleDetail, VehiclePlace};
+use crate::models::problem::{Costs, VehicleDetail, VehiclePlace};
 use std::sync::Arc;
 
 type VehicleData = (Location, Location, Timestamp, Timestamp);
@@ -23,6 +23,7 @@ fn create_detail(
             location,
             time: time.map_or(Default::default(), |(_, end)| TimeInterval { earliest: None, latest: Some(end) }),
         }),
+        is_open: false,
     }
 }
 
@@ -119,6 +120,34 @@ mod timing {
         assert_eq!(result, expected);
     }
 
+    parameterized_test! {can_allow_late_arrival_with_soft_time_window, (lateness, expected), {
+        can_allow_late_arrival_with_soft_time_window_impl(lateness, expected);
+    }}
+
+    can_allow_late_arrival_with_soft_time_window! {
+        case01_hard: (None, Some(ActivityConstraintViolation{ code: 1, stopped: false })),
+        case02_soft: (Some(LatenessPenalty::Linear(1.)), None),
+    }
+
+    fn can_allow_late_arrival_with_soft_time_window_impl(
+        lateness: Option<LatenessPenalty>,
+        expected: Option<ActivityConstraintViolation>,
+    ) {
+        let (pipeline, mut route_ctx) = create_constraint_pipeline_and_route((0, 0, 0., 100.));
+        pipeline.accept_route_state(&mut route_ctx);
+
+        let prev = route_ctx.route.tour.get(3).unwrap();
+        let target = ActivityBuilder::default()
+            .place(Place { location: 40, duration: 0., time: TimeWindow::new(0., 35.), setup: 0., lateness })
+            .build();
+        let next = route_ctx.route.tour.get(4);
+        let activity_ctx = ActivityContext { index: 0, prev, target: &target, next };
+
+        let result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn can_update_activity_schedule() {
         let fleet = FleetBuilder::default()
@@ -131,11 +160,23 @@ mod timing {
                 "v1",
                 vec![
                     ActivityBuilder::default()
-                        .place(Place { location: 10, duration: 5., time: TimeWindow { start: 20., end: 30. } })
+                        .place(Place {
+                            location: 10,
+                            duration: 5.,
+                            time: TimeWindow { start: 20., end: 30. },
+                            setup: 0.,
+                            lateness: None,
+                        })
                         .schedule(Schedule::new(10., 25.))
                         .build(),
                     ActivityBuilder::default()
-                        .place(Place { location: 20, duration: 10., time: TimeWindow { start: 50., end: 100. } })
+                        .place(Place {
+                            location: 20,
+                            duration: 10.,
+                            time: TimeWindow { start: 50., end: 100. },
+                            setup: 0.,
+                            lateness: None,
+                        })
                         .schedule(Schedule::new(35., 60.))
                         .build(),
                 ],
@@ -151,6 +192,82 @@ mod timing {
         assert_eq!(route_ctx.route.tour.get(2).unwrap().schedule, Schedule { arrival: 35., departure: 60. });
     }
 
+    fn create_activity_with_setup(location: Location, time: TimeWindow) -> Activity {
+        ActivityBuilder::default().place(Place { location, duration: 1., time, setup: 5., lateness: None }).build()
+    }
+
+    parameterized_test! {can_share_setup_between_activities_at_same_location, (location, expected), {
+        can_share_setup_between_activities_at_same_location_impl(location, expected);
+    }}
+
+    can_share_setup_between_activities_at_same_location! {
+        case01_same_location: (10, vec![(10., 16.), (16., 17.), (27., 33.)]),
+        case02_different_location: (15, vec![(10., 16.), (21., 27.), (32., 38.)]),
+    }
+
+    fn can_share_setup_between_activities_at_same_location_impl(location: Location, expected: Vec<(f64, f64)>) {
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![VehicleBuilder::default().id("v1").build()])
+            .build();
+        let mut route_ctx = create_route_context_with_activities(
+            &fleet,
+            "v1",
+            vec![
+                create_activity_with_setup(10, DEFAULT_ACTIVITY_TIME_WINDOW),
+                create_activity_with_setup(location, DEFAULT_ACTIVITY_TIME_WINDOW),
+                create_activity_with_setup(20, DEFAULT_ACTIVITY_TIME_WINDOW),
+            ],
+        );
+
+        create_constraint_pipeline_with_transport().accept_route_state(&mut route_ctx);
+
+        let schedules = route_ctx
+            .route
+            .tour
+            .all_activities()
+            .skip(1)
+            .take(3)
+            .map(|activity| (activity.schedule.arrival, activity.schedule.departure))
+            .collect::<Vec<_>>();
+        assert_eq!(schedules, expected);
+    }
+
+    parameterized_test! {can_skip_setup_when_inserting_at_same_location, (location, expected), {
+        can_skip_setup_when_inserting_at_same_location_impl(location, expected);
+    }}
+
+    can_skip_setup_when_inserting_at_same_location! {
+        case01_same_location: (10, None),
+        case02_different_location: (11, Some(1)),
+    }
+
+    fn can_skip_setup_when_inserting_at_same_location_impl(location: Location, expected: Option<i32>) {
+        let pipeline = create_constraint_pipeline_with_transport();
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![VehicleBuilder::default().id("v1").build()])
+            .build();
+        let mut route_ctx = create_route_context_with_activities(
+            &fleet,
+            "v1",
+            vec![create_activity_with_setup(10, DEFAULT_ACTIVITY_TIME_WINDOW)],
+        );
+        pipeline.accept_route_state(&mut route_ctx);
+
+        let target = create_activity_with_setup(location, TimeWindow::new(0., 18.));
+        let activity_ctx = ActivityContext {
+            index: 1,
+            prev: route_ctx.route.tour.get(1).unwrap(),
+            target: &target,
+            next: route_ctx.route.tour.get(2),
+        };
+
+        let result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+        assert_eq!(result.map(|violation| violation.code), expected);
+    }
+
     #[test]
     fn can_calculate_soft_activity_cost_for_empty_tour() {
         let fleet = FleetBuilder::default()
@@ -159,7 +276,7 @@ mod timing {
             .build();
         let route_ctx = create_route_context_with_activities(&fleet, "v1", vec![]);
         let target = Box::new(Activity {
-            place: Place { location: 5, duration: 1.0, time: DEFAULT_ACTIVITY_TIME_WINDOW },
+            place: Place { location: 5, duration: 1.0, time: DEFAULT_ACTIVITY_TIME_WINDOW, setup: 0., lateness: None },
             schedule: DEFAULT_ACTIVITY_SCHEDULE,
             job: None,
             commute: None,
@@ -187,16 +304,34 @@ mod timing {
             "v1",
             vec![
                 ActivityBuilder::default()
-                    .place(Place { location: 10, duration: 0.0, time: DEFAULT_ACTIVITY_TIME_WINDOW.clone() })
+                    .place(Place {
+                        location: 10,
+                        duration: 0.0,
+                        time: DEFAULT_ACTIVITY_TIME_WINDOW.clone(),
+                        setup: 0.,
+                        lateness: None,
+                    })
                     .schedule(Schedule { arrival: 0.0, departure: 10.0 })
                     .build(),
                 ActivityBuilder::default()
-                    .place(Place { location: 20, duration: 0.0, time: TimeWindow { start: 40.0, end: 70.0 } })
+                    .place(Place {
+                        location: 20,
+                        duration: 0.0,
+                        time: TimeWindow { start: 40.0, end: 70.0 },
+                        setup: 0.,
+                        lateness: None,
+                    })
                     .build(),
             ],
         );
         let target = Box::new(Activity {
-            place: Place { location: 30, duration: 10.0, time: DEFAULT_ACTIVITY_TIME_WINDOW },
+            place: Place {
+                location: 30,
+                duration: 10.0,
+                time: DEFAULT_ACTIVITY_TIME_WINDOW,
+                setup: 0.,
+                lateness: None,
+            },
             schedule: DEFAULT_ACTIVITY_SCHEDULE,
             job: None,
             commute: None,
@@ -213,6 +348,94 @@ mod timing {
         assert_eq!(compare_floats(result, 30.0), Ordering::Equal);
     }
 
+    #[test]
+    fn can_calculate_soft_activity_cost_with_lateness_penalty() {
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver_with_costs(empty_costs()))
+            .add_vehicles(vec![VehicleBuilder::default().id("v1").build()])
+            .build();
+        let route_ctx = create_route_context_with_activities(&fleet, "v1", vec![]);
+        let target = Box::new(Activity {
+            place: Place {
+                location: 5,
+                duration: 1.0,
+                time: TimeWindow::new(0., 1.),
+                setup: 0.,
+                lateness: Some(LatenessPenalty::Linear(2.)),
+            },
+            schedule: DEFAULT_ACTIVITY_SCHEDULE,
+            job: None,
+            commute: None,
+        });
+        let activity_ctx = ActivityContext {
+            index: 0,
+            prev: route_ctx.route.tour.get(0).unwrap(),
+            target: &target,
+            next: route_ctx.route.tour.get(1),
+        };
+
+        let result = create_constraint_pipeline_with_transport().evaluate_soft_activity(&route_ctx, &activity_ctx);
+
+        assert_eq!(compare_floats(result, 29.0), Ordering::Equal);
+    }
+
+    #[test]
+    fn can_include_lateness_penalty_into_route_cost() {
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver_with_costs(empty_costs()))
+            .add_vehicles(vec![VehicleBuilder::default().id("v1").build()])
+            .build();
+        let create_route_ctx = |lateness: Option<LatenessPenalty>| {
+            let mut route_ctx = create_route_context_with_activities(
+                &fleet,
+                "v1",
+                vec![ActivityBuilder::default()
+                    .place(Place { location: 10, duration: 0., time: TimeWindow::new(0., 5.), setup: 0., lateness })
+                    .build()],
+            );
+            create_constraint_pipeline_with_transport().accept_route_state(&mut route_ctx);
+            route_ctx
+        };
+
+        let hard_cost = create_route_ctx(None).get_route_cost();
+        let soft_route_ctx = create_route_ctx(Some(LatenessPenalty::Linear(3.)));
+
+        assert_eq!(soft_route_ctx.state.get_route_state::<f64>(TOTAL_LATENESS_COST_KEY), Some(&15.));
+        assert_eq!(compare_floats(soft_route_ctx.get_route_cost() - hard_cost, 15.), Ordering::Equal);
+    }
+
+    parameterized_test! {can_include_waiting_cost_into_route_cost, (per_waiting_time, expected), {
+        can_include_waiting_cost_into_route_cost_impl(per_waiting_time, expected);
+    }}
+
+    can_include_waiting_cost_into_route_cost! {
+        case01_same_as_time_cost: (1., 30.),
+        case02_expensive_waiting: (3., 50.),
+        case03_free_waiting: (0., 20.),
+    }
+
+    fn can_include_waiting_cost_into_route_cost_impl(per_waiting_time: f64, expected: f64) {
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver_with_costs(empty_costs()))
+            .add_vehicles(vec![VehicleBuilder::default()
+                .id("v1")
+                .costs(Costs { per_distance: 0., per_waiting_time, ..test_costs() })
+                .build()])
+            .build();
+        let mut route_ctx = create_route_context_with_activities(
+            &fleet,
+            "v1",
+            vec![ActivityBuilder::default()
+                .place(Place { location: 10, duration: 0., time: TimeWindow::new(20., 30.), setup: 0., lateness: None })
+                .build()],
+        );
+
+        create_constraint_pipeline_with_transport().accept_route_state(&mut route_ctx);
+
+        assert_eq!(route_ctx.state.get_route_state::<f64>(TOTAL_WAITING_KEY), Some(&10.));
+        assert_eq!(route_ctx.get_route_cost(), expected);
+    }
+
     #[test]
     fn can_stop_with_time_route_constraint() {
         let fleet = FleetBuilder::default()
@@ -233,12 +456,13 @@ mod traveling {
     use super::super::stop;
     use super::*;
     use crate::helpers::construction::constraints::create_constraint_pipeline_with_modules;
-    use crate::models::problem::Actor;
+    use crate::helpers::models::domain::create_empty_solution_context;
+    use crate::models::problem::{Actor, Job};
 
     fn create_test_data(
         vehicle: &str,
         target: &str,
-        limit: (Option<Distance>, Option<Duration>),
+        limit: (Option<Distance>, Option<Duration>, Option<Cost>),
     ) -> (ConstraintPipeline, RouteContext) {
         let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
         let mut state = RouteState::default();
@@ -260,11 +484,21 @@ mod traveling {
                 }
             }
         });
-        let tour_duration_limit =
+        let tour_duration_limit = Arc::new({
+            let target = target.clone();
+            move |actor: &Actor| {
+                if get_vehicle_id(actor.vehicle.as_ref()) == target.as_str() {
+                    limit.1
+                } else {
+                    None
+                }
+            }
+        });
+        let tour_cost_limit =
             Arc::new(
                 move |actor: &Actor| {
                     if get_vehicle_id(actor.vehicle.as_ref()) == target.as_str() {
-                        limit.1
+                        limit.2
                     } else {
                         None
                     }
@@ -272,7 +506,15 @@ mod traveling {
             );
         let pipeline = create_constraint_pipeline_with_modules(vec![
             Arc::new(TransportConstraintModule::new(transport.clone(), Arc::new(TestActivityCost::default()), 1)),
-            Arc::new(TravelLimitModule::new(transport, tour_distance_limit, tour_duration_limit, 2, 3)),
+            Arc::new(TravelLimitModule::new(
+                transport,
+                tour_distance_limit,
+                tour_duration_limit,
+                tour_cost_limit,
+                2,
+                3,
+                4,
+            )),
         ]);
 
         (pipeline, route_ctx)
@@ -283,20 +525,24 @@ mod traveling {
     }}
 
     can_check_traveling_limits! {
-        case01: ("v1", "v1", 76, (Some(100.), None), stop(2)),
-        case02: ("v1", "v1", 74, (Some(100.), None), None),
-        case03: ("v1", "v2", 76, (Some(100.), None), None),
+        case01: ("v1", "v1", 76, (Some(100.), None, None), stop(2)),
+        case02: ("v1", "v1", 74, (Some(100.), None, None), None),
+        case03: ("v1", "v2", 76, (Some(100.), None, None), None),
+
+        case04: ("v1", "v1", 76, (None, Some(100.), None), stop(3)),
+        case05: ("v1", "v1", 74, (None, Some(100.), None), None),
+        case06: ("v1", "v2", 76, (None, Some(100.), None), None),
 
-        case04: ("v1", "v1", 76, (None, Some(100.)), stop(3)),
-        case05: ("v1", "v1", 74, (None, Some(100.)), None),
-        case06: ("v1", "v2", 76, (None, Some(100.)), None),
+        case07: ("v1", "v1", 76, (None, None, Some(400.)), stop(4)),
+        case08: ("v1", "v1", 74, (None, None, Some(400.)), None),
+        case09: ("v1", "v2", 76, (None, None, Some(400.)), None),
     }
 
     fn can_check_traveling_limits_impl(
         vehicle: &str,
         target: &str,
         location: Location,
-        limit: (Option<Distance>, Option<Duration>),
+        limit: (Option<Distance>, Option<Duration>, Option<Cost>),
         expected: Option<ActivityConstraintViolation>,
     ) {
         let (pipeline, route_ctx) = create_test_data(vehicle, target, limit);
@@ -314,9 +560,63 @@ mod traveling {
         assert_eq!(result, expected);
     }
 
+    parameterized_test! {can_check_distance_limit_on_route_level, (location, has_jobs, expected), {
+        can_check_distance_limit_on_route_level_impl(location, has_jobs, expected);
+    }}
+
+    can_check_distance_limit_on_route_level! {
+        case01_empty_tour_exceeds_limit: (51, false, Some(RouteConstraintViolation { code: 2 })),
+        case02_empty_tour_within_limit: (50, false, None),
+        case03_non_empty_tour_exceeds_limit: (51, true, Some(RouteConstraintViolation { code: 2 })),
+        case04_non_empty_tour_within_limit: (50, true, None),
+    }
+
+    fn can_check_distance_limit_on_route_level_impl(
+        location: Location,
+        has_jobs: bool,
+        expected: Option<RouteConstraintViolation>,
+    ) {
+        let (pipeline, _) = create_test_data("v1", "v1", (Some(100.), None, None));
+        let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+        let activities = if has_jobs { vec![test_activity_with_location(10)] } else { vec![] };
+        let mut route_ctx = create_route_context_with_activities(&fleet, "v1", activities);
+        pipeline.accept_route_state(&mut route_ctx);
+        let job = SingleBuilder::default().location(Some(location)).build_as_job_ref();
+
+        let result = pipeline.evaluate_hard_route(&create_empty_solution_context(), &route_ctx, &job);
+
+        assert_eq!(result, expected);
+    }
+
+    parameterized_test! {can_check_distance_limit_for_multi_job, (locations, expected), {
+        can_check_distance_limit_for_multi_job_impl(locations, expected);
+    }}
+
+    can_check_distance_limit_for_multi_job! {
+        case01_one_sub_job_exceeds_limit: (vec![10, 51], Some(RouteConstraintViolation { code: 2 })),
+        case02_all_sub_jobs_within_limit: (vec![10, 50], None),
+    }
+
+    fn can_check_distance_limit_for_multi_job_impl(
+        locations: Vec<Location>,
+        expected: Option<RouteConstraintViolation>,
+    ) {
+        let (pipeline, _) = create_test_data("v1", "v1", (Some(100.), None, None));
+        let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+        let mut route_ctx = create_route_context_with_activities(&fleet, "v1", vec![test_activity_with_location(10)]);
+        pipeline.accept_route_state(&mut route_ctx);
+        let job = Job::Multi(test_multi_job_with_locations(
+            locations.into_iter().map(|location| vec![Some(location)]).collect(),
+        ));
+
+        let result = pipeline.evaluate_hard_route(&create_empty_solution_context(), &route_ctx, &job);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn can_consider_waiting_time() {
-        let (pipeline, route_ctx) = create_test_data("v1", "v1", (None, Some(100.)));
+        let (pipeline, route_ctx) = create_test_data("v1", "v1", (None, Some(100.), None));
 
         let result = pipeline.evaluate_hard_activity(
             &route_ctx,
@@ -330,6 +630,49 @@ mod traveling {
 
         assert_eq!(result, stop(3));
     }
+
+    parameterized_test! {can_charge_waiting_time_at_vehicle_rate_in_cost_limit, (per_waiting_time, limit, expected), {
+        can_charge_waiting_time_at_vehicle_rate_in_cost_limit_impl(per_waiting_time, limit, expected);
+    }}
+
+    can_charge_waiting_time_at_vehicle_rate_in_cost_limit! {
+        case01_free_waiting: (0., 120., None),
+        case02_cheap_waiting: (0.5, 130., None),
+        case03_same_as_time_cost: (1., 120., stop(4)),
+    }
+
+    fn can_charge_waiting_time_at_vehicle_rate_in_cost_limit_impl(
+        per_waiting_time: f64,
+        limit: Cost,
+        expected: Option<ActivityConstraintViolation>,
+    ) {
+        let (pipeline, _) = create_test_data("v1", "v1", (None, None, Some(limit)));
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver_with_costs(empty_costs()))
+            .add_vehicles(vec![VehicleBuilder::default()
+                .id("v1")
+                .costs(Costs { per_waiting_time, ..test_costs() })
+                .build()])
+            .build();
+        let mut state = RouteState::default();
+        state.put_route_state(TOTAL_DISTANCE_KEY, 50.);
+        state.put_route_state(TOTAL_DURATION_KEY, 50.);
+        state.put_route_state(TOTAL_WAITING_KEY, 40.);
+        let route_ctx =
+            RouteContext::new_with_state(Arc::new(create_route_with_activities(&fleet, "v1", vec![])), Arc::new(state));
+
+        let result = pipeline.evaluate_hard_activity(
+            &route_ctx,
+            &ActivityContext {
+                index: 0,
+                prev: &test_activity_with_location(50),
+                target: &test_activity_with_location(60),
+                next: Some(&test_activity_with_location(50)),
+            },
+        );
+
+        assert_eq!(result, expected);
+    }
 }
 
 mod time_dependent {
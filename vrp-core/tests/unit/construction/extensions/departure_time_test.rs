@@ -3,6 +3,8 @@ use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
 use crate::models::common::*;
 use crate::models::problem::*;
+use crate::models::solution::Activity;
+use std::sync::Arc;
 
 parameterized_test! {can_advance_departure_time, (latest, optimize_whole_tour, tws, expected), {
     let tws = tws.into_iter().map(|(start, end)| TimeWindow::new(start, end)).collect::<Vec<_>>();
@@ -106,3 +108,42 @@ fn can_recede_departure_time_impl(
 
     assert_eq!(departure_time, expected);
 }
+
+parameterized_test! {can_keep_offset_time_window_relative_to_departure, (new_departure, expected), {
+    can_keep_offset_time_window_relative_to_departure_impl(new_departure, expected);
+}}
+
+can_keep_offset_time_window_relative_to_departure! {
+    case01_advance: (5., (25., 35.)),
+    case02_same: (0., (20., 30.)),
+}
+
+fn can_keep_offset_time_window_relative_to_departure_impl(new_departure: Timestamp, expected: (f64, f64)) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let offset_job = Arc::new(Single {
+        places: vec![Place {
+            times: vec![TimeSpan::Offset(TimeOffset::new(20., 30.))],
+            ..test_place_with_location(Some(10))
+        }],
+        dimens: Default::default(),
+    });
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![
+            Activity { job: Some(offset_job), ..test_activity_with_location_and_tw(10, TimeWindow::new(20., 30.)) },
+            test_activity_with_location_and_tw(20, TimeWindow::new(0., 100.)),
+        ],
+    );
+
+    TransportConstraintModule::update_route_departure(
+        &mut route_ctx,
+        &TestActivityCost::default(),
+        &TestTransportCost::default(),
+        new_departure,
+    );
+
+    let tour = &route_ctx.route.tour;
+    assert_eq!(tour.get(1).unwrap().place.time, TimeWindow::new(expected.0, expected.1));
+    assert_eq!(tour.get(2).unwrap().place.time, TimeWindow::new(0., 100.));
+}
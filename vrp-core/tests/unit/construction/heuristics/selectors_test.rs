@@ -1,7 +1,7 @@
 use super::*;
 use crate::helpers::models::domain::create_empty_insertion_context;
-use crate::helpers::models::problem::test_single_with_id;
-use crate::helpers::models::solution::create_empty_route_ctx;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
 use crate::helpers::utils::random::FakeRandom;
 use crate::models::common::Cost;
 use std::sync::Arc;
@@ -44,3 +44,44 @@ fn can_compare_insertion_result_with_noise_impl(
         _ => unreachable!(),
     }
 }
+
+parameterized_test! {can_get_nearest_routes_shortlist, (job_location, max_routes, expected), {
+    can_get_nearest_routes_shortlist_impl(job_location, max_routes, expected);
+}}
+
+can_get_nearest_routes_shortlist! {
+    case01: (29, 1, Some(vec!["v3", "v4"])),
+    case02: (11, 2, Some(vec!["v1", "v2", "v4"])),
+    case03: (11, 3, None),
+}
+
+fn can_get_nearest_routes_shortlist_impl(job_location: usize, max_routes: usize, expected: Option<Vec<&str>>) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![
+            test_vehicle_with_id("v1"),
+            test_vehicle_with_id("v2"),
+            test_vehicle_with_id("v3"),
+            test_vehicle_with_id("v4"),
+        ])
+        .build();
+    let routes = vec![("v1", Some(10)), ("v2", Some(20)), ("v3", Some(30)), ("v4", None)]
+        .into_iter()
+        .map(|(vehicle, location)| {
+            create_route_context_with_activities(
+                &fleet,
+                vehicle,
+                location.map(test_activity_with_location).into_iter().collect(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let job = Job::Single(test_single_with_location(Some(job_location)));
+
+    let result = NearestRouteInsertionEvaluator::new(max_routes, 4)
+        .get_shortlist(&create_empty_insertion_context(), &job, routes.as_slice())
+        .map(|routes| {
+            routes.iter().map(|route_ctx| get_vehicle_id(&route_ctx.route.actor.vehicle).clone()).collect::<Vec<_>>()
+        });
+
+    assert_eq!(result, expected.map(|ids| ids.into_iter().map(|id| id.to_string()).collect()));
+}
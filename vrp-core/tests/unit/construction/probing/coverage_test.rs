@@ -0,0 +1,47 @@
+use super::*;
+use crate::helpers::solver::{add_leg_constraint, generate_matrix_routes_with_defaults};
+use std::sync::Arc;
+
+fn create_insertion_ctx(rows: usize, cols: usize, disallowed_pairs: Vec<(&str, &str)>) -> InsertionContext {
+    let (mut problem, solution) = generate_matrix_routes_with_defaults(rows, cols, false);
+    if !disallowed_pairs.is_empty() {
+        add_leg_constraint(&mut problem, disallowed_pairs);
+    }
+
+    InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default()))
+}
+
+#[test]
+fn can_detect_covered_and_not_covered_constraints() {
+    let insertion_ctx = create_insertion_ctx(2, 2, vec![("c0", "cX")]);
+    let constraint = insertion_ctx.problem.constraint.clone();
+
+    let report = check_coverage(&insertion_ctx);
+
+    assert_eq!(report.hard_route.len(), constraint.hard_route_constraints.len());
+    assert_eq!(report.hard_activity.len(), constraint.hard_activity_constraints.len());
+    let leg_coverage = report.hard_activity.last().unwrap();
+    assert!(leg_coverage.is_covered());
+    assert!(leg_coverage.rejected < leg_coverage.evaluated);
+    assert_eq!(leg_coverage.codes.iter().cloned().collect::<Vec<_>>(), vec![7]);
+    assert!(!report.is_covered());
+}
+
+#[test]
+fn can_detect_objective_coverage() {
+    let report = check_coverage(&create_insertion_ctx(2, 2, vec![]));
+
+    assert_eq!(report.objectives.len(), 3);
+    assert!(report.objectives.iter().all(|coverage| coverage.evaluated == 4));
+    assert_eq!(report.objectives[0].changed, 4);
+    assert!(!report.objectives[1].is_covered());
+    assert!(report.objectives[2].is_covered());
+}
+
+#[test]
+fn can_detect_objective_coverage_when_route_is_removed() {
+    let report = check_coverage(&create_insertion_ctx(1, 2, vec![]));
+
+    assert!(report.objectives.iter().all(|coverage| coverage.is_covered()));
+    assert!(report.to_string().contains("objective #1: covered, changed in 2 of 2 perturbations"));
+}
@@ -53,6 +53,7 @@ fn create_test_problem(
                         location: end_location,
                         time: TimeInterval { earliest: end_earliest, latest: end_latest },
                     }),
+                    is_open: false,
                 }])
                 .capacity(capacity)
                 .build()
@@ -108,7 +109,7 @@ fn add_new_route(insertion_ctx: &mut InsertionContext, vehicle_id: &str, activit
 
     activities.into_iter().for_each(|(location, duration, (tw_start, tw_end), single)| {
         tour.insert_last(Activity {
-            place: Place { location, duration, time: TimeWindow::new(tw_start, tw_end) },
+            place: Place { location, duration, time: TimeWindow::new(tw_start, tw_end), setup: 0., lateness: None },
             schedule: Schedule::new(0., 0.),
             job: Some(single),
             commute: None,
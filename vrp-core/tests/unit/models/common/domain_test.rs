@@ -49,3 +49,57 @@ mod time_window {
         assert_eq!(time.duration(), expected);
     }
 }
+
+mod lateness_penalty {
+    use super::*;
+
+    parameterized_test! {can_estimate_penalty, (penalty, lateness, expected), {
+        can_estimate_penalty_impl(penalty, lateness, expected);
+    }}
+
+    can_estimate_penalty! {
+        case_01_linear: (LatenessPenalty::Linear(2.), 10., 20.),
+        case_02_linear_not_late: (LatenessPenalty::Linear(2.), 0., 0.),
+        case_03_linear_early: (LatenessPenalty::Linear(2.), -10., 0.),
+        case_04_custom: (LatenessPenalty::Custom(Arc::new(|lateness| lateness * lateness)), 3., 9.),
+        case_05_custom_early: (LatenessPenalty::Custom(Arc::new(|lateness| lateness * lateness)), -3., 0.),
+    }
+
+    fn can_estimate_penalty_impl(penalty: LatenessPenalty, lateness: Duration, expected: Cost) {
+        assert_eq!(penalty.estimate(lateness), expected);
+    }
+}
+
+mod weekly_time_window {
+    use super::*;
+
+    const DAY: f64 = 86400.;
+    const WEEK: f64 = 7. * DAY;
+
+    parameterized_test! {can_expand_weekly_time_window, (days, times, horizon, expected), {
+        can_expand_weekly_time_window_impl(days, times, horizon, expected);
+    }}
+
+    can_expand_weekly_time_window! {
+        case01_single_day: (vec![0], (10., 20.), (0., WEEK), vec![(10., 20.)]),
+        case02_two_days: (vec![2, 0], (10., 20.), (0., WEEK), vec![(10., 20.), (2. * DAY + 10., 2. * DAY + 20.)]),
+        case03_two_weeks: (vec![1], (10., 20.), (0., 2. * WEEK), vec![(DAY + 10., DAY + 20.), (WEEK + DAY + 10., WEEK + DAY + 20.)]),
+        case04_cut_by_horizon: (vec![0], (10., 20.), (15., WEEK + 15.), vec![(15., 20.), (WEEK + 10., WEEK + 15.)]),
+        case05_before_week_start: (vec![6], (10., 20.), (-WEEK, 0.), vec![(-DAY + 10., -DAY + 20.)]),
+        case06_crosses_midnight: (vec![6], (DAY - 10., DAY + 10.), (0., WEEK), vec![(0., 10.), (WEEK - 10., WEEK)]),
+        case07_outside_horizon: (vec![3], (10., 20.), (0., 2. * DAY), vec![]),
+    }
+
+    fn can_expand_weekly_time_window_impl(
+        days: Vec<usize>,
+        times: (f64, f64),
+        horizon: (f64, f64),
+        expected: Vec<(f64, f64)>,
+    ) {
+        let weekly = WeeklyTimeWindow { days, start: times.0, end: times.1 };
+
+        let result = weekly.expand(0., &TimeWindow::new(horizon.0, horizon.1));
+
+        assert_eq!(result, expected.into_iter().map(|(start, end)| TimeWindow::new(start, end)).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,97 @@
+use super::*;
+use crate::models::examples::create_example_problem;
+use crate::models::Problem;
+use crate::prelude::{create_default_config_builder, Environment, Solver, TelemetryMode};
+
+const CONFIG: &str = r#"
+{
+  "constraints": [
+    { "type": "transport" },
+    { "type": "capacity" },
+    { "type": "tour-size", "limit": 10 }
+  ],
+  "objectives": [
+    [{ "type": "minimize-unassigned" }],
+    [{ "type": "minimize-tours" }],
+    [{ "type": "minimize-cost" }, { "type": "balance-activities", "threshold": 0.1 }]
+  ]
+}
+"#;
+
+fn read_config(config: &str) -> Result<ProblemConfig, String> {
+    serde_json::from_str(config).map_err(|err| err.to_string())
+}
+
+#[test]
+fn can_deserialize_config() {
+    let config = read_config(CONFIG).unwrap();
+
+    assert_eq!(
+        config,
+        ProblemConfig {
+            constraints: vec![
+                ConstraintConfig::Transport,
+                ConstraintConfig::Capacity { is_multi_dim: false },
+                ConstraintConfig::TourSize { limit: 10 },
+            ],
+            objectives: vec![
+                vec![ObjectiveConfig::MinimizeUnassigned],
+                vec![ObjectiveConfig::MinimizeTours],
+                vec![ObjectiveConfig::MinimizeCost, ObjectiveConfig::BalanceActivities { threshold: Some(0.1) }],
+            ],
+        }
+    );
+}
+
+#[test]
+fn can_deserialize_multi_dim_capacity() {
+    let config = read_config(r#"{ "constraints": [{ "type": "capacity", "isMultiDim": true }], "objectives": [] }"#);
+
+    assert_eq!(config.unwrap().constraints, vec![ConstraintConfig::Capacity { is_multi_dim: true }]);
+}
+
+parameterized_test! {can_detect_invalid_config, (constraint, objective, expected), {
+    can_detect_invalid_config_impl(constraint, objective, expected);
+}}
+
+can_detect_invalid_config! {
+    case01_unknown_constraint: (r#"{ "type": "magic" }"#, r#"{ "type": "minimize-cost" }"#, "unknown variant `magic`"),
+    case02_missing_argument: (r#"{ "type": "tour-size" }"#, r#"{ "type": "minimize-cost" }"#, "missing field `limit`"),
+    case03_unknown_objective: (r#"{ "type": "transport" }"#, r#"{ "type": "minimize-magic" }"#, "unknown variant `minimize-magic`"),
+    case04_invalid_threshold: (r#"{ "type": "transport" }"#, r#"{ "type": "balance-distance", "threshold": "abc" }"#, "invalid type"),
+}
+
+fn can_detect_invalid_config_impl(constraint: &str, objective: &str, expected: &str) {
+    let config = format!(r#"{{ "constraints": [{}], "objectives": [[{}]] }}"#, constraint, objective);
+
+    let result = read_config(config.as_str());
+
+    assert!(result.clone().err().is_some_and(|err| err.contains(expected)), "unexpected result: {:?}", result);
+}
+
+#[test]
+fn can_solve_problem_with_composed_pipeline_and_objective() {
+    let example = create_example_problem();
+    let config = read_config(CONFIG).unwrap();
+    let (constraint, objective) = config.create(example.transport.clone(), example.activity.clone());
+    let problem = Arc::new(Problem {
+        fleet: example.fleet.clone(),
+        jobs: example.jobs.clone(),
+        locks: example.locks.clone(),
+        constraint: Arc::new(constraint),
+        activity: example.activity.clone(),
+        transport: example.transport.clone(),
+        objective: Arc::new(objective),
+        extras: example.extras.clone(),
+    });
+    let environment = Arc::new(Environment::default());
+
+    let config = create_default_config_builder(problem.clone(), environment, TelemetryMode::None)
+        .with_max_generations(Some(10))
+        .build()
+        .unwrap();
+    let (solution, _, _) = Solver::new(problem, config).solve().unwrap();
+
+    assert_eq!(solution.routes.len(), 1);
+    assert!(solution.unassigned.is_empty());
+}
@@ -102,6 +102,65 @@ fn can_interpolate_durations() {
     assert_eq!(costs.distance_approx(&p1, 0, 1), 5.);
 }
 
+#[test]
+fn can_interpolate_distances() {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let profile = route.actor.vehicle.profile.clone();
+
+    let costs = TimeAwareMatrixTransportCost::new(
+        vec![
+            create_matrix_data(profile.clone(), Some(0.), (100., 2), (10., 2)),
+            create_matrix_data(profile, Some(10.), (200., 2), (20., 2)),
+        ],
+        2,
+    )
+    .unwrap();
+
+    for &(timestamp, distance) in &[(0., 10.), (10., 20.), (15., 20.), (3., 13.), (5., 15.)] {
+        assert_eq!(costs.distance(&route, 0, 1, TravelTime::Departure(timestamp)), distance);
+    }
+}
+
+#[test]
+fn can_use_departure_time_slice_for_arrival() {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let profile = route.actor.vehicle.profile.clone();
+
+    let costs = TimeAwareMatrixTransportCost::new(
+        vec![
+            create_matrix_data(profile.clone(), Some(0.), (10., 2), (1., 2)),
+            create_matrix_data(profile.clone(), Some(100.), (50., 2), (1., 2)),
+            create_matrix_data(profile, Some(200.), (50., 2), (1., 2)),
+        ],
+        2,
+    )
+    .unwrap();
+
+    // NOTE arrival at 250 means departure around 200 where travel takes 50
+    assert_eq!(costs.duration(&route, 0, 1, TravelTime::Arrival(250.)), 50.);
+    // NOTE arrival at 100 means departure around 50 where travel takes 30
+    assert_eq!(costs.duration(&route, 0, 1, TravelTime::Arrival(100.)), 30.);
+}
+
+#[test]
+fn can_keep_unreachable_value_when_interpolating() {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let profile = route.actor.vehicle.profile.clone();
+
+    let costs = TimeAwareMatrixTransportCost::new(
+        vec![
+            create_matrix_data(profile.clone(), Some(0.), (-1., 2), (-1., 2)),
+            create_matrix_data(profile, Some(10.), (200., 2), (20., 2)),
+        ],
+        2,
+    )
+    .unwrap();
+
+    assert_eq!(costs.duration(&route, 0, 1, TravelTime::Departure(5.)), -1.);
+    assert_eq!(costs.distance(&route, 0, 1, TravelTime::Departure(5.)), -1.);
+    assert_eq!(costs.duration(&route, 0, 1, TravelTime::Departure(10.)), 200.);
+}
+
 parameterized_test! {can_search_for_reserved_time, (times, tests), {
     can_search_for_reserved_time_impl(times, tests);
 }}
@@ -248,4 +307,86 @@ mod objective {
 
         assert_eq!(result, expected);
     }
+
+    fn create_test_objective(index: usize) -> TargetObjective {
+        Arc::new(TestObjective { index })
+    }
+
+    parameterized_test! {can_use_total_order_with_weighted_level, (data_a, data_b, expected), {
+        can_use_total_order_with_weighted_level_impl(data_a, data_b, expected);
+    }}
+
+    can_use_total_order_with_weighted_level! {
+        case01_equal: (vec![1., 10., 0.], vec![2., 0., 0.], Ordering::Equal),
+        case02_less: (vec![1., 1., 0.], vec![0., 20., 0.], Ordering::Less),
+        case03_greater: (vec![0., 20., 0.], vec![1., 1., 0.], Ordering::Greater),
+        case04_hierarchy: (vec![0., 0., 1.], vec![10., 10., 0.], Ordering::Greater),
+    }
+
+    fn can_use_total_order_with_weighted_level_impl(data_a: Vec<f64>, data_b: Vec<f64>, expected: Ordering) {
+        let objective = ProblemObjective::builder()
+            .with_level(vec![create_test_objective(2)])
+            .with_weighted_level(vec![(create_test_objective(0), 10.), (create_test_objective(1), 1.)])
+            .build()
+            .expect("cannot build objective");
+
+        let a = create_individual(data_a);
+        let b = create_individual(data_b);
+
+        let result = objective.total_order(&a, &b);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn can_use_single_weighted_level_as_one_objective() {
+        let objective = ProblemObjective::builder()
+            .with_weighted_level(vec![(create_test_objective(0), 2.), (create_test_objective(1), 0.5)])
+            .build()
+            .expect("cannot build objective");
+
+        let fitness = objective.objectives().map(|o| o.fitness(&create_individual(vec![3., 4.]))).collect::<Vec<_>>();
+
+        assert_eq!(fitness, vec![8.]);
+        assert!(objective.is_totally_ordered());
+    }
+
+    #[test]
+    fn can_keep_dominance_ranking_for_non_weighted_objective() {
+        let hierarchical = ProblemObjective::builder()
+            .with_level(vec![create_test_objective(2)])
+            .with_weighted_level(vec![(create_test_objective(0), 1.)])
+            .build()
+            .expect("cannot build objective");
+        let single = ProblemObjective::new(vec![vec![create_test_objective(0)]]);
+
+        assert!(!hierarchical.is_totally_ordered());
+        assert!(!single.is_totally_ordered());
+    }
+
+    parameterized_test! {can_detect_invalid_builder_usage, (levels, weight, expected), {
+        can_detect_invalid_builder_usage_impl(levels, weight, expected);
+    }}
+
+    can_detect_invalid_builder_usage! {
+        case01_no_levels: (0, 1., Some("no objectives specified")),
+        case02_empty_level: (1, 1., Some("objective level cannot be empty")),
+        case03_empty_weighted_level: (2, 1., Some("weighted objective level cannot be empty")),
+        case04_negative_weight: (3, -1., Some("objective weight should be finite and non-negative")),
+        case05_nan_weight: (3, f64::NAN, Some("objective weight should be finite and non-negative")),
+        case06_valid: (3, 1., None),
+    }
+
+    fn can_detect_invalid_builder_usage_impl(levels: usize, weight: f64, expected: Option<&str>) {
+        let builder = match levels {
+            0 => ProblemObjective::builder(),
+            1 => ProblemObjective::builder().with_level(vec![]),
+            2 => ProblemObjective::builder().with_weighted_level(vec![]),
+            _ => ProblemObjective::builder().with_weighted_level(vec![(create_test_objective(0), weight)]),
+        };
+
+        let result = builder.build();
+
+        assert_eq!(result.err(), expected.map(|err| err.to_string()));
+    }
 }
@@ -192,6 +192,7 @@ fn returns_proper_job_ranks_impl(index: usize, profile_index: usize, expected: D
     let create_vehicle_detail = |start_location: usize| VehicleDetail {
         start: Some(VehiclePlace { location: start_location, time: TimeInterval::default() }),
         end: Some(VehiclePlace { location: 0, time: TimeInterval::default() }),
+        is_open: false,
     };
     let fleet = FleetBuilder::default()
         .add_driver(test_driver())
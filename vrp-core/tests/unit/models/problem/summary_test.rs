@@ -0,0 +1,60 @@
+use super::*;
+use crate::construction::constraints::ConstraintPipeline;
+use crate::helpers::models::domain::create_problem_with_constraint_jobs_and_fleet;
+use crate::helpers::models::problem::*;
+use crate::models::common::{Demand, SingleDimLoad, TimeWindow};
+
+fn create_delivery(id: &str, location: usize, demand: i32, time: (f64, f64)) -> Job {
+    SingleBuilder::default()
+        .id(id)
+        .location(Some(location))
+        .times(vec![TimeWindow::new(time.0, time.1)])
+        .demand(Demand {
+            pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
+            delivery: (SingleDimLoad::new(demand), SingleDimLoad::default()),
+        })
+        .build_as_job_ref()
+}
+
+#[test]
+fn can_create_problem_summary() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(VehicleBuilder::default().id("v1").capacity(10).build())
+        .add_vehicle(VehicleBuilder::default().id("v2").capacity(5).build())
+        .build();
+    let jobs = vec![
+        create_delivery("job1", 1, 2, (0., 10.)),
+        create_delivery("job2", 2, 3, (250., 300.)),
+        create_delivery("job3", 3, 1, (950., 1000.)),
+        create_delivery("job4", 6, 0, (0., f64::MAX)),
+        Job::Multi(test_multi_job_with_locations(vec![vec![Some(4)], vec![Some(5)]])),
+    ];
+    let problem = create_problem_with_constraint_jobs_and_fleet(ConstraintPipeline::default(), jobs, fleet);
+
+    let summary = problem.summary();
+
+    assert_eq!(summary.jobs.total, 5);
+    assert_eq!(summary.jobs.singles, 4);
+    assert_eq!(summary.jobs.multies, 1);
+    assert_eq!(summary.jobs.places, 6);
+    assert_eq!(summary.jobs.delivery_demand, vec![6]);
+    assert_eq!(summary.jobs.pickup_demand, vec![0]);
+
+    assert_eq!(summary.fleet.vehicles, 2);
+    assert_eq!(summary.fleet.actors, 2);
+    assert_eq!(summary.fleet.profiles, 1);
+    assert_eq!(summary.fleet.capacity, vec![15]);
+
+    assert_eq!(summary.time_windows.horizon, Some(TimeWindow::new(0., 1000.)));
+    // NOTE multi job places have default time window which starts at zero
+    assert_eq!(summary.time_windows.buckets, vec![3, 0, 1, 0, 0, 0, 0, 0, 0, 1]);
+    assert_eq!(summary.time_windows.unrestricted, 1);
+
+    assert_eq!(summary.routing.len(), 1);
+    let routing = summary.routing.first().unwrap();
+    assert_eq!(routing.locations, 7);
+    assert_eq!(routing.pairs, 42);
+    assert_eq!(routing.unreachable, 0);
+    assert_eq!(routing.max_distance, 6.);
+}
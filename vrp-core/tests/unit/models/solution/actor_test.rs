@@ -22,7 +22,8 @@ fn can_provide_available_actors_from_registry_impl(count: usize, expected: usize
         .add_driver(test_driver())
         .add_vehicles(vec![
             VehicleBuilder::default().id("v1").details(vec![test_vehicle_detail()]).build(),
-            VehicleBuilder::default().id("v2").details(create_two_test_vehicle_details()).build(),
+            VehicleBuilder::default().id("v2").details(vec![test_vehicle_detail()]).build(),
+            VehicleBuilder::default().id("v3").details(vec![test_vehicle_detail()]).build(),
         ])
         .build();
     let mut registry = Registry::new(&fleet, test_random());
@@ -57,12 +58,35 @@ fn can_provide_next_actors_from_registry() {
     assert_eq!(actors.last().unwrap().detail.start.as_ref().map(|s| s.location), Some(1));
 }
 
+#[test]
+fn can_use_only_one_of_alternative_vehicle_details() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![
+            VehicleBuilder::default().id("v1").details(vec![test_vehicle_detail()]).build(),
+            VehicleBuilder::default().id("v2").details(create_two_test_vehicle_details()).build(),
+        ])
+        .build();
+    let mut registry = Registry::new(&fleet, test_random());
+    let alternative =
+        registry.available().find(|actor| actor.detail.start.as_ref().map(|s| s.location) == Some(1)).unwrap();
+    assert_eq!(registry.available().count(), 3);
+
+    assert!(registry.use_actor(&alternative));
+    assert_eq!(registry.available().count(), 1);
+    assert!(registry.available().all(|actor| !Arc::ptr_eq(&actor.vehicle, &alternative.vehicle)));
+
+    assert!(registry.free_actor(&alternative));
+    assert_eq!(registry.available().count(), 3);
+}
+
 fn create_two_test_vehicle_details() -> Vec<VehicleDetail> {
     vec![
         test_vehicle_detail(),
         VehicleDetail {
             start: Some(VehiclePlace { location: 1, time: TimeInterval { earliest: Some(0.), latest: None } }),
             end: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: None, latest: Some(50.) } }),
+            is_open: false,
         },
     ]
 }
@@ -0,0 +1,87 @@
+use super::*;
+use crate::helpers::models::domain::test_random;
+use crate::models::examples::create_example_problem;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
+use crate::solver::{create_default_config_builder, RefinementContext, Solver};
+use rosomaxa::evolution::{HeuristicSolutionProcessing, ProcessingConfig, TelemetryMode};
+use rosomaxa::population::Greedy;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+fn create_solutions() -> (InsertionContext, InsertionContext) {
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::default());
+    let population = Box::new(Greedy::new(problem.objective.clone(), 1, None));
+    let refinement_ctx = RefinementContext::new(problem.clone(), population, TelemetryMode::None, environment.clone());
+
+    let empty = InsertionContext::new(problem.clone(), environment.clone());
+    let solved = RecreateWithCheapest::new(test_random()).run(&refinement_ctx, empty.deep_copy());
+
+    (empty, solved)
+}
+
+fn get_unassigned(tracker: &FeasibleSolutionTracker) -> Option<usize> {
+    tracker.best_known.lock().unwrap().as_ref().map(|insertion_ctx| insertion_ctx.solution.required.len())
+}
+
+#[test]
+fn can_keep_best_known_feasible_solution() {
+    let problem = create_example_problem();
+    let (empty, solved) = create_solutions();
+    let tracker = FeasibleSolutionTracker::new(Arc::new(|_| true));
+
+    tracker.on_solutions(problem.objective.as_ref(), std::iter::once(&empty));
+    assert_eq!(get_unassigned(&tracker), Some(empty.solution.required.len()));
+
+    tracker.on_solutions(problem.objective.as_ref(), vec![&solved, &empty].into_iter());
+    assert_eq!(get_unassigned(&tracker), Some(0));
+
+    tracker.on_solutions(problem.objective.as_ref(), std::iter::once(&empty));
+    assert_eq!(get_unassigned(&tracker), Some(0));
+}
+
+#[test]
+fn can_skip_infeasible_solutions() {
+    let problem = create_example_problem();
+    let (empty, solved) = create_solutions();
+    let tracker = FeasibleSolutionTracker::new(Arc::new(|insertion_ctx| !insertion_ctx.solution.routes.is_empty()));
+
+    tracker.on_solutions(problem.objective.as_ref(), std::iter::once(&empty));
+    assert_eq!(get_unassigned(&tracker), None);
+
+    tracker.on_solutions(problem.objective.as_ref(), std::iter::once(&solved));
+    assert_eq!(get_unassigned(&tracker), Some(0));
+}
+
+#[test]
+fn can_post_process_best_known_feasible_solution() {
+    struct CountingProcessing(Arc<AtomicUsize>);
+
+    impl HeuristicSolutionProcessing for CountingProcessing {
+        type Solution = InsertionContext;
+
+        fn post_process(&self, solution: Self::Solution) -> Self::Solution {
+            self.0.fetch_add(1, AtomicOrdering::Relaxed);
+            solution
+        }
+    }
+
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::default());
+    let counter = Arc::new(AtomicUsize::new(0));
+    let mut config = create_default_config_builder(problem.clone(), environment, TelemetryMode::None)
+        .with_max_generations(Some(10))
+        .with_processing(ProcessingConfig {
+            context: vec![],
+            solution: vec![Box::new(CountingProcessing(counter.clone()))],
+        })
+        .build()
+        .expect("cannot build config");
+    config.context = config.context.with_feasibility_check(Arc::new(|_| true));
+
+    let (_, cost, feasible, _) = Solver::new(problem, config).solve_with_feasible().expect("cannot solve problem");
+
+    let (feasible_solution, feasible_cost) = feasible.expect("no feasible solution");
+    assert_eq!(feasible_cost, cost);
+    assert!(feasible_solution.unassigned.is_empty());
+    assert_eq!(counter.load(AtomicOrdering::Relaxed), 2);
+}
@@ -0,0 +1,115 @@
+use super::*;
+use crate::construction::heuristics::InsertionContext;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::solution::*;
+use std::cmp::Ordering;
+
+fn create_test_insertion_ctx(durations: &[f64]) -> InsertionContext {
+    let mut insertion_ctx = create_empty_insertion_context();
+    let problem = insertion_ctx.problem.clone();
+
+    durations.iter().for_each(|duration| {
+        let mut route_ctx = create_route_context_with_activities(problem.fleet.as_ref(), "v1", vec![]);
+        route_ctx.state_mut().put_route_state(TOTAL_DURATION_KEY, *duration);
+
+        insertion_ctx.solution.routes.push(route_ctx);
+    });
+
+    insertion_ctx
+}
+
+parameterized_test! {can_compare_solutions_by_max_duration, (left, right, expected), {
+    can_compare_solutions_by_max_duration_impl(left, right, expected);
+}}
+
+can_compare_solutions_by_max_duration! {
+    case_01: (&[10.], &[10.], Ordering::Equal),
+    case_02: (&[10.], &[11.], Ordering::Less),
+    case_03: (&[10., 2.], &[6., 6.], Ordering::Greater),
+    case_04: (&[10., 2.], &[10., 9.], Ordering::Equal),
+    case_05: (&[], &[1.], Ordering::Less),
+}
+
+fn can_compare_solutions_by_max_duration_impl(left: &[f64], right: &[f64], expected: Ordering) {
+    let (_, objective) = Minimax::new_duration_minimized();
+    let left = create_test_insertion_ctx(left);
+    let right = create_test_insertion_ctx(right);
+
+    let result = objective.total_order(&left, &right);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_penalize_insertion_into_longest_route() {
+    let (module, _) = Minimax::new_duration_minimized();
+    let mut insertion_ctx = create_test_insertion_ctx(&[10., 5.]);
+    module.accept_solution_state(&mut insertion_ctx.solution);
+    let constraint = module.get_constraints().next().cloned();
+    let job = crate::helpers::models::problem::SingleBuilder::default().build_as_job_ref();
+
+    let estimates = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| match &constraint {
+            Some(ConstraintVariant::SoftRoute(constraint)) => {
+                constraint.estimate_job(&insertion_ctx.solution, route_ctx, &job)
+            }
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(estimates.len(), 2);
+    assert!(estimates[0] > estimates[1]);
+}
+
+fn create_waiting_time_insertion_ctx(routes: &[Vec<usize>]) -> InsertionContext {
+    let mut insertion_ctx = create_empty_insertion_context();
+    let problem = insertion_ctx.problem.clone();
+
+    // NOTE activity's arrival is equal to its location and its time window starts at zero
+    insertion_ctx.solution.routes = routes
+        .iter()
+        .map(|locations| {
+            create_route_context_with_activities(
+                problem.fleet.as_ref(),
+                "v1",
+                locations.iter().map(|location| test_activity_with_location(*location)).collect(),
+            )
+        })
+        .collect();
+
+    insertion_ctx
+}
+
+parameterized_test! {can_estimate_waiting_time_fitness, (routes, percentile, expected), {
+    can_estimate_waiting_time_fitness_impl(routes, percentile, expected);
+}}
+
+can_estimate_waiting_time_fitness! {
+    case01_empty: (vec![], None, 0.),
+    case02_max: (vec![vec![10, 30], vec![20]], None, 30.),
+    case03_median: (vec![vec![10, 30], vec![20, 40]], Some(0.5), 20.),
+    case04_high_percentile: (vec![vec![10, 30], vec![20, 40]], Some(0.95), 40.),
+    case05_low_percentile: (vec![vec![10, 30], vec![20, 40]], Some(0.), 10.),
+}
+
+fn can_estimate_waiting_time_fitness_impl(routes: Vec<Vec<usize>>, percentile: Option<f64>, expected: f64) {
+    let (_, objective) = Minimax::new_waiting_time_minimized(percentile);
+    let insertion_ctx = create_waiting_time_insertion_ctx(routes.as_slice());
+
+    let result = objective.fitness(&insertion_ctx);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_compare_solutions_by_max_waiting_time() {
+    let (_, objective) = Minimax::new_waiting_time_minimized(None);
+    let left = create_waiting_time_insertion_ctx(&[vec![10, 20], vec![15]]);
+    let right = create_waiting_time_insertion_ctx(&[vec![10], vec![15, 25]]);
+
+    assert_eq!(objective.total_order(&left, &right), Ordering::Less);
+    assert_eq!(objective.total_order(&right, &left), Ordering::Greater);
+}
@@ -0,0 +1,136 @@
+use super::*;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::{Costs, Vehicle};
+
+const STATE_KEY: i32 = 1;
+const VIOLATION_CODE: i32 = 2;
+
+fn create_test_insertion_ctx(distances: &[f64]) -> InsertionContext {
+    let mut insertion_ctx = create_empty_insertion_context();
+    let problem = insertion_ctx.problem.clone();
+
+    distances.iter().for_each(|distance| {
+        let mut route_ctx = create_route_context_with_activities(problem.fleet.as_ref(), "v1", vec![]);
+        route_ctx.state_mut().put_route_state(TOTAL_DISTANCE_KEY, *distance);
+        route_ctx.state_mut().put_route_state(TOTAL_DURATION_KEY, 0.);
+
+        insertion_ctx.solution.routes.push(route_ctx);
+    });
+
+    insertion_ctx
+}
+
+parameterized_test! {can_limit_total_cost, (distances, budget_delta, expected_violation, expected_fitness), {
+    can_limit_total_cost_impl(distances, budget_delta, expected_violation, expected_fitness);
+}}
+
+can_limit_total_cost! {
+    case_01_within_budget: (&[10., 20.], 1., None, 0.),
+    case_02_at_budget: (&[10., 20.], 0., None, 0.),
+    case_03_over_budget: (&[10., 20.], -5., Some(VIOLATION_CODE), 5.),
+    case_04_no_routes: (&[], 10., None, 0.),
+}
+
+fn can_limit_total_cost_impl(
+    distances: &[f64],
+    budget_delta: f64,
+    expected_violation: Option<i32>,
+    expected_fitness: f64,
+) {
+    let mut insertion_ctx = create_test_insertion_ctx(distances);
+    let max_cost = insertion_ctx.solution.get_total_cost() + budget_delta;
+    let (module, objective) =
+        TotalCostLimit::new_constrained(max_cost, TestTransportCost::new_shared(), STATE_KEY, VIOLATION_CODE);
+    let job = SingleBuilder::default().location(None).build_as_job_ref();
+    let route_ctx = create_route_context_with_activities(insertion_ctx.problem.fleet.as_ref(), "v1", vec![]);
+
+    module.accept_solution_state(&mut insertion_ctx.solution);
+    let violation = match module.get_constraints().next() {
+        Some(ConstraintVariant::HardRoute(constraint)) => {
+            constraint.evaluate_job(&insertion_ctx.solution, &route_ctx, &job)
+        }
+        _ => unreachable!(),
+    };
+
+    assert_eq!(violation.map(|violation| violation.code), expected_violation);
+    assert_eq!(objective.fitness(&insertion_ctx), expected_fitness);
+}
+
+parameterized_test! {can_estimate_job_insertion_cost, (activities, job_location, expected), {
+    can_estimate_job_insertion_cost_impl(activities, job_location, expected);
+}}
+
+can_estimate_job_insertion_cost! {
+    case_01_empty_route: (vec![], Some(5), 40.),
+    case_02_on_the_way: (vec![10], Some(5), 0.),
+    case_03_detour: (vec![10], Some(15), 40.),
+    case_04_no_location: (vec![10], None, 0.),
+}
+
+fn can_estimate_job_insertion_cost_impl(activities: Vec<Location>, job_location: Option<Location>, expected: Cost) {
+    let insertion_ctx = create_empty_insertion_context();
+    let route_ctx = create_route_context_with_activities(
+        insertion_ctx.problem.fleet.as_ref(),
+        "v1",
+        activities.into_iter().map(test_activity_with_location).collect(),
+    );
+    let job = SingleBuilder::default().location(job_location).build_as_job_ref();
+    let constraint = TotalCostLimitHardRouteConstraint {
+        max_cost: 100.,
+        transport: TestTransportCost::new_shared(),
+        state_key: STATE_KEY,
+        constraint_code: VIOLATION_CODE,
+    };
+
+    let result = constraint.estimate_job_cost(&route_ctx, &job);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_estimate_job_insertion_cost_with_different_time_rates() {
+    let costs =
+        Costs { fixed: 0., per_distance: 0., per_driving_time: 1., per_waiting_time: 100., per_service_time: 10. };
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver_with_costs(empty_costs()))
+        .add_vehicle(Vehicle { costs, ..test_vehicle_with_id("v1") })
+        .build();
+    let route_ctx = create_route_context_with_activities(&fleet, "v1", vec![test_activity_with_location(10)]);
+    let job = SingleBuilder::default().location(Some(15)).duration(2.).build_as_job_ref();
+    let constraint = TotalCostLimitHardRouteConstraint {
+        max_cost: 100.,
+        transport: TestTransportCost::new_shared(),
+        state_key: STATE_KEY,
+        constraint_code: VIOLATION_CODE,
+    };
+
+    let result = constraint.estimate_job_cost(&route_ctx, &job);
+
+    assert_eq!(result, 10. * 1. + 2. * 10.);
+}
+
+#[test]
+fn can_ignore_route_waiting_time_in_job_insertion_cost() {
+    let costs =
+        Costs { fixed: 0., per_distance: 1., per_driving_time: 1., per_waiting_time: 100., per_service_time: 1. };
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver_with_costs(empty_costs()))
+        .add_vehicle(Vehicle { costs, ..test_vehicle_with_id("v1") })
+        .build();
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v1", vec![test_activity_with_location(10)]);
+    route_ctx.state_mut().put_route_state(TOTAL_DURATION_KEY, 20.);
+    route_ctx.state_mut().put_route_state(TOTAL_WAITING_KEY, 5.);
+    let job = SingleBuilder::default().location(Some(15)).build_as_job_ref();
+    let constraint = TotalCostLimitHardRouteConstraint {
+        max_cost: 100.,
+        transport: TestTransportCost::new_shared(),
+        state_key: STATE_KEY,
+        constraint_code: VIOLATION_CODE,
+    };
+
+    let result = constraint.estimate_job_cost(&route_ctx, &job);
+
+    assert_eq!(result, 10. + 10.);
+}
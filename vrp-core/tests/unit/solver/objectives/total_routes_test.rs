@@ -0,0 +1,64 @@
+use super::*;
+use crate::construction::heuristics::InsertionContext;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::Fleet;
+use std::cmp::Ordering;
+
+fn create_tier_fn() -> Arc<dyn Fn(&Actor) -> usize + Send + Sync> {
+    Arc::new(|actor| if get_vehicle_id(&actor.vehicle).starts_with("premium") { 1 } else { 0 })
+}
+
+fn create_test_insertion_ctx(fleet: &Fleet, vehicle_ids: &[&str]) -> InsertionContext {
+    let mut insertion_ctx = create_empty_insertion_context();
+
+    vehicle_ids.iter().for_each(|vehicle_id| {
+        insertion_ctx.solution.routes.push(create_route_context_with_activities(fleet, vehicle_id, vec![]));
+    });
+
+    insertion_ctx
+}
+
+parameterized_test! {can_compare_solutions_by_vehicle_tiers, (left, right, expected), {
+    can_compare_solutions_by_vehicle_tiers_impl(left, right, expected);
+}}
+
+can_compare_solutions_by_vehicle_tiers! {
+    case_01: (&["v1"], &["v2"], Ordering::Equal),
+    case_02: (&["v1"], &["premium1"], Ordering::Less),
+    case_03: (&["v1", "v2", "v3"], &["premium1"], Ordering::Less),
+    case_04: (&["premium1", "v1"], &["premium1", "v1", "v2"], Ordering::Less),
+    case_05: (&["premium1", "premium2"], &["premium1", "v1", "v2", "v3"], Ordering::Greater),
+}
+
+fn can_compare_solutions_by_vehicle_tiers_impl(left: &[&str], right: &[&str], expected: Ordering) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(["v1", "v2", "v3", "premium1", "premium2"].iter().map(|id| test_vehicle_with_id(id)).collect())
+        .build();
+    let objective = TotalRoutes::new_tiered(create_tier_fn());
+
+    let result =
+        objective.total_order(&create_test_insertion_ctx(&fleet, left), &create_test_insertion_ctx(&fleet, right));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_compare_solutions_with_high_vehicle_tiers() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(["v1", "v2", "premium1"].iter().map(|id| test_vehicle_with_id(id)).collect())
+        .build();
+    let tier_fn: Arc<dyn Fn(&Actor) -> usize + Send + Sync> =
+        Arc::new(|actor| if get_vehicle_id(&actor.vehicle).starts_with("premium") { 5000 } else { 2000 });
+    let objective = TotalRoutes::new_tiered(tier_fn);
+
+    let result = objective.total_order(
+        &create_test_insertion_ctx(&fleet, &["v1", "v2"]),
+        &create_test_insertion_ctx(&fleet, &["premium1"]),
+    );
+
+    assert_eq!(result, Ordering::Less);
+}
@@ -1,5 +1,6 @@
 use super::*;
 use crate::helpers::models::domain::{create_empty_insertion_context, create_simple_insertion_ctx};
+use crate::helpers::models::problem::SingleBuilder;
 
 #[test]
 fn can_properly_estimate_empty_solution() {
@@ -11,3 +12,29 @@ fn can_properly_estimate_empty_solution() {
 
     assert_eq!(result, Ordering::Greater);
 }
+
+parameterized_test! {can_use_unassigned_penalty, (penalties, expected), {
+    can_use_unassigned_penalty_impl(penalties, expected);
+}}
+
+can_use_unassigned_penalty! {
+    case01_no_penalties: (vec![None, None], 2.),
+    case02_mixed_penalties: (vec![Some(5.), None], 6.),
+    case03_all_penalties: (vec![Some(5.), Some(0.5)], 5.5),
+}
+
+fn can_use_unassigned_penalty_impl(penalties: Vec<Option<f64>>, expected: f64) {
+    let mut insertion_ctx = create_empty_insertion_context();
+    penalties.into_iter().enumerate().for_each(|(idx, penalty)| {
+        let mut dimens = Dimensions::default();
+        if let Some(penalty) = penalty {
+            dimens.set_unassigned_penalty(penalty);
+        }
+        let job = SingleBuilder::default().dimens(dimens).id(format!("job{idx}").as_str()).build_as_job_ref();
+        insertion_ctx.solution.unassigned.insert(job, UnassignmentInfo::Unknown);
+    });
+
+    let result = TotalUnassignedJobs::default().fitness(&insertion_ctx);
+
+    assert_eq!(result, expected);
+}
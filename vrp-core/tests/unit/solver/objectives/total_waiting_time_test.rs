@@ -0,0 +1,73 @@
+use super::*;
+use crate::helpers::construction::constraints::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Location, TimeWindow};
+
+fn create_route_ctx(activities: Vec<(Location, (f64, f64))>) -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        activities
+            .into_iter()
+            .map(|(location, (start, end))| test_activity_with_location_and_tw(location, TimeWindow::new(start, end)))
+            .collect(),
+    );
+
+    create_constraint_pipeline_with_transport().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+parameterized_test! {can_estimate_waiting_time_change, (activities, index, target, expected), {
+    can_estimate_waiting_time_change_impl(activities, index, target, expected);
+}}
+
+can_estimate_waiting_time_change! {
+    case01_fill_waiting: (vec![(10, (0., 1000.)), (20, (50., 100.))], 1, (30, (0., 1000.)), -20.),
+    case02_move_waiting: (vec![(10, (0., 1000.)), (20, (50., 100.))], 1, (15, (40., 1000.)), 0.),
+    case03_add_waiting: (vec![(10, (0., 1000.)), (20, (50., 100.))], 2, (30, (100., 1000.)), 40.),
+    case04_postpone_departure: (vec![], 0, (30, (100., 1000.)), 0.),
+}
+
+fn can_estimate_waiting_time_change_impl(
+    activities: Vec<(Location, (f64, f64))>,
+    index: usize,
+    target: (Location, (f64, f64)),
+    expected: f64,
+) {
+    let route_ctx = create_route_ctx(activities);
+    let (module, _) = TotalWaitingTime::minimize(TestActivityCost::new_shared(), TestTransportCost::new_shared());
+    let pipeline = create_constraint_pipeline_with_modules(vec![module]);
+    let target = test_activity_with_location_and_tw(target.0, TimeWindow::new((target.1).0, (target.1).1));
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let result = pipeline.evaluate_soft_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_ignore_waiting_time_avoided_by_later_departure, (first_tw_end, expected), {
+    can_ignore_waiting_time_avoided_by_later_departure_impl(first_tw_end, expected);
+}}
+
+can_ignore_waiting_time_avoided_by_later_departure! {
+    case01_can_postpone: (1000., 0.),
+    case02_cannot_postpone: (10., 30.),
+    case03_can_postpone_partially: (25., 15.),
+}
+
+fn can_ignore_waiting_time_avoided_by_later_departure_impl(first_tw_end: f64, expected: f64) {
+    let route_ctx = create_route_ctx(vec![(10, (0., first_tw_end)), (20, (50., 100.))]);
+    let objective = WaitingTimeObjective { transport: TestTransportCost::new_shared() };
+
+    let result = objective.get_route_waiting(&route_ctx);
+
+    assert_eq!(result, expected);
+}
@@ -1,5 +1,6 @@
 use super::*;
 use crate::construction::heuristics::RouteContext;
+use crate::helpers::models::domain::create_empty_insertion_context;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
 use crate::models::common::{IdDimension, ValueDimension};
@@ -39,7 +40,42 @@ fn can_get_violations() {
 
     let violations = get_violations(&[route], &get_order_fn());
 
-    assert_eq!(violations, 1);
+    assert_eq!(violations, OrderViolations { count: 1, magnitude: 1. });
+}
+
+parameterized_test! {can_calculate_fitness_with_violations, (orders, is_graded, expected), {
+    can_calculate_fitness_with_violations_impl(orders, is_graded, expected);
+}}
+
+can_calculate_fitness_with_violations! {
+    case01_counted: (vec![Some(5.), Some(1.), Some(3.), Some(2.)], false, 2.),
+    case02_graded: (vec![Some(5.), Some(1.), Some(3.), Some(2.)], true, 5.),
+    case03_graded_with_default: (vec![None, Some(2.), Some(3.)], true, 1.),
+    case04_no_violations: (vec![Some(1.), Some(2.), None], true, 0.),
+}
+
+fn can_calculate_fitness_with_violations_impl(orders: Vec<Option<f64>>, is_graded: bool, expected: f64) {
+    let fleet = test_fleet();
+    let route = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        orders
+            .into_iter()
+            .enumerate()
+            .map(|(idx, order)| create_activity_for_job_with_order(&format!("job{}", idx), order))
+            .collect(),
+    );
+    let (_, objective) = if is_graded {
+        TourOrder::new_graded(get_order_fn(), 1)
+    } else {
+        TourOrder::new_unconstrained(get_order_fn(), 1)
+    };
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes.push(route);
+
+    let result = objective.fitness(&insertion_ctx);
+
+    assert_eq!(result, expected);
 }
 
 parameterized_test! {can_merge_order, (source, candidate, expected), {
@@ -70,4 +106,4 @@ fn get_order_fn() -> OrderFn {
         Some(value) => OrderResult::Value(*value),
         _ => OrderResult::Default,
     }))
-}
\ No newline at end of file
+}
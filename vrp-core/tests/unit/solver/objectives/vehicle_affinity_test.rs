@@ -0,0 +1,81 @@
+use super::*;
+use crate::construction::constraints::ConstraintVariant;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::{Fleet, Single};
+
+fn create_fleet() -> Fleet {
+    let mut driver = test_driver();
+    driver.dimens.set_id("d1");
+
+    FleetBuilder::default()
+        .add_driver(driver)
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build()
+}
+
+fn create_job_with_affinity(id: &str, actors: Vec<&str>, penalty: f64) -> Arc<Single> {
+    let mut single = SingleBuilder::default().id(id).build();
+    single
+        .dimens
+        .set_job_affinity(JobAffinity { actors: actors.into_iter().map(|id| id.to_string()).collect(), penalty });
+
+    Arc::new(single)
+}
+
+parameterized_test! {can_estimate_job_insertion_penalty, (vehicle, actors, expected), {
+    can_estimate_job_insertion_penalty_impl(vehicle, actors, expected);
+}}
+
+can_estimate_job_insertion_penalty! {
+    case01_preferred_vehicle: ("v1", vec!["v1"], 0.),
+    case02_other_vehicle: ("v2", vec!["v1"], 10.),
+    case03_one_of_preferred_vehicles: ("v2", vec!["v1", "v2"], 0.),
+    case04_preferred_driver: ("v2", vec!["d1"], 0.),
+}
+
+fn can_estimate_job_insertion_penalty_impl(vehicle: &str, actors: Vec<&str>, expected: f64) {
+    let fleet = create_fleet();
+    let (module, _) = VehicleAffinity::minimize_penalty();
+    let route_ctx = create_route_context_with_activities(&fleet, vehicle, vec![]);
+    let job = Job::Single(create_job_with_affinity("job1", actors, 10.));
+
+    let result = match module.get_constraints().next() {
+        Some(ConstraintVariant::SoftRoute(constraint)) => {
+            constraint.estimate_job(&create_empty_solution_context(), &route_ctx, &job)
+        }
+        _ => unreachable!(),
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_calculate_total_penalty() {
+    let fleet = create_fleet();
+    let (module, objective) = VehicleAffinity::minimize_penalty();
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes = vec![
+        create_route_context_with_activities(
+            &fleet,
+            "v1",
+            vec![
+                test_activity_with_job(create_job_with_affinity("job1", vec!["v1"], 10.)),
+                test_activity_with_job(create_job_with_affinity("job2", vec!["v2"], 5.)),
+            ],
+        ),
+        create_route_context_with_activities(
+            &fleet,
+            "v2",
+            vec![
+                test_activity_with_job(create_job_with_affinity("job3", vec!["v1"], 3.)),
+                test_activity_with_job(SingleBuilder::default().id("job4").build_shared()),
+            ],
+        ),
+    ];
+    insertion_ctx.solution.routes.iter_mut().for_each(|route_ctx| module.accept_route_state(route_ctx));
+    module.accept_solution_state(&mut insertion_ctx.solution);
+
+    assert_eq!(objective.fitness(&insertion_ctx), 8.);
+}
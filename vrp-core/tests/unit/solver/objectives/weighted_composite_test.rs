@@ -0,0 +1,68 @@
+use super::*;
+use crate::helpers::models::domain::create_simple_insertion_ctx;
+use crate::models::examples::create_example_problem;
+use crate::models::problem::ProblemObjective;
+use crate::models::Problem;
+use crate::prelude::{create_default_config_builder, Environment, Solver, TelemetryMode};
+use crate::solver::objectives::{TotalDistance, TotalRoutes, TotalUnassignedJobs};
+use std::sync::Arc;
+
+fn create_objective(unassigned_weight: f64, distance_weight: f64) -> WeightedComposite {
+    WeightedComposite::new(vec![
+        (Arc::new(TotalUnassignedJobs::default()), unassigned_weight),
+        (TotalDistance::minimize(), distance_weight),
+    ])
+}
+
+#[test]
+fn can_calculate_weighted_fitness() {
+    let objective = create_objective(100., 0.5);
+
+    let result = objective.fitness(&create_simple_insertion_ctx(50., 1));
+
+    assert_eq!(result, 125.);
+}
+
+parameterized_test! {can_use_weights_to_compare_solutions, (unassigned_weight, expected), {
+    can_use_weights_to_compare_solutions_impl(unassigned_weight, expected);
+}}
+
+can_use_weights_to_compare_solutions! {
+    case01_prefer_assigned: (100., Ordering::Greater),
+    case02_prefer_short: (10., Ordering::Less),
+    case03_tie: (70., Ordering::Equal),
+}
+
+fn can_use_weights_to_compare_solutions_impl(unassigned_weight: f64, expected: Ordering) {
+    let objective = create_objective(unassigned_weight, 1.);
+    let with_unassigned = create_simple_insertion_ctx(50., 1);
+    let without_unassigned = create_simple_insertion_ctx(120., 0);
+
+    let result = objective.total_order(&with_unassigned, &without_unassigned);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_solve_problem_with_single_weighted_objective() {
+    let example = create_example_problem();
+    let objective = ProblemObjective::builder()
+        .with_weighted_level(vec![
+            (Arc::new(TotalUnassignedJobs::default()), 1000.),
+            (Arc::new(TotalRoutes::default()), 100.),
+            (TotalCost::minimize(), 1.),
+        ])
+        .build()
+        .unwrap();
+    let problem = Arc::new(Problem { objective: Arc::new(objective), ..example.as_ref().clone() });
+    let environment = Arc::new(Environment::default());
+
+    let config = create_default_config_builder(problem.clone(), environment, TelemetryMode::None)
+        .with_max_generations(Some(10))
+        .build()
+        .unwrap();
+    let (solution, _, _) = Solver::new(problem, config).solve().unwrap();
+
+    assert_eq!(solution.routes.len(), 1);
+    assert!(solution.unassigned.is_empty());
+}
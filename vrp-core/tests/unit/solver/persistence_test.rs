@@ -0,0 +1,70 @@
+use super::*;
+use crate::helpers::models::domain::test_random;
+use crate::models::examples::create_example_problem;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
+use crate::solver::RefinementContext;
+use rosomaxa::evolution::TelemetryMode;
+use rosomaxa::population::Greedy;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+fn create_persistence(policy: PersistencePolicy) -> (SolutionPersistence, Arc<AtomicUsize>) {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let persist_fn: PersistenceFn = {
+        let counter = counter.clone();
+        Arc::new(move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            Ok(())
+        })
+    };
+
+    (SolutionPersistence::new(policy, persist_fn), counter)
+}
+
+fn create_solutions() -> (InsertionContext, InsertionContext) {
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::default());
+    let population = Box::new(Greedy::new(problem.objective.clone(), 1, None));
+    let refinement_ctx = RefinementContext::new(problem.clone(), population, TelemetryMode::None, environment.clone());
+
+    let empty = InsertionContext::new(problem.clone(), environment.clone());
+    let solved = RecreateWithCheapest::new(test_random()).run(&refinement_ctx, empty.deep_copy());
+
+    (empty, solved)
+}
+
+#[test]
+fn can_persist_only_changed_best_known_solution() {
+    let problem = create_example_problem();
+    let (empty, solved) = create_solutions();
+    let mut population = Greedy::new(problem.objective.clone(), 1, None);
+    let (mut persistence, counter) = create_persistence(PersistencePolicy::default());
+
+    persistence.on_generation(problem.objective.as_ref(), &population).unwrap();
+    assert_eq!(counter.load(Ordering::Relaxed), 0);
+
+    population.add(empty);
+    persistence.on_generation(problem.objective.as_ref(), &population).unwrap();
+    persistence.on_generation(problem.objective.as_ref(), &population).unwrap();
+    assert_eq!(counter.load(Ordering::Relaxed), 1);
+
+    population.add(solved);
+    persistence.on_generation(problem.objective.as_ref(), &population).unwrap();
+    assert_eq!(counter.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn can_persist_after_given_amount_of_improvements() {
+    let problem = create_example_problem();
+    let (empty, solved) = create_solutions();
+    let mut population = Greedy::new(problem.objective.clone(), 1, None);
+    let (mut persistence, counter) =
+        create_persistence(PersistencePolicy { interval: Some(3600), improvements: Some(2) });
+
+    population.add(empty);
+    persistence.on_generation(problem.objective.as_ref(), &population).unwrap();
+    assert_eq!(counter.load(Ordering::Relaxed), 0);
+
+    population.add(solved);
+    persistence.on_generation(problem.objective.as_ref(), &population).unwrap();
+    assert_eq!(counter.load(Ordering::Relaxed), 1);
+}
@@ -0,0 +1,102 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{IdDimension, Location};
+use crate::models::problem::Job;
+use crate::models::solution::Place;
+
+fn create_test_insertion_ctx(locations: &[Location]) -> InsertionContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let singles = locations
+        .iter()
+        .map(|location| {
+            SingleBuilder::default().id(format!("job{}", location).as_str()).location(Some(*location)).build_shared()
+        })
+        .collect::<Vec<_>>();
+    let activities = singles
+        .iter()
+        .zip(locations.iter())
+        .map(|(single, location)| {
+            ActivityBuilder::default()
+                .place(Place { location: *location, ..test_activity().place })
+                .job(Some(single.clone()))
+                .build()
+        })
+        .collect();
+    let routes = vec![create_route_context_with_activities(&fleet, "v1", activities)];
+
+    let mut insertion_ctx = InsertionContext {
+        problem: create_problem_with_constraint_jobs_and_fleet(
+            create_constraint_pipeline_with_transport(),
+            singles.into_iter().map(Job::Single).collect(),
+            fleet,
+        ),
+        solution: SolutionContext { routes, ..create_empty_solution_context() },
+        ..create_empty_insertion_context()
+    };
+    insertion_ctx.problem.constraint.accept_solution_state(&mut insertion_ctx.solution);
+
+    insertion_ctx
+}
+
+parameterized_test! {can_estimate_marginal_costs, (repair_passes, job_id, expected), {
+    can_estimate_marginal_costs_impl(repair_passes, job_id, expected);
+}}
+
+can_estimate_marginal_costs! {
+    case01_removal_keeps_cost: (0, "job4", 0.),
+    case02_removal_reduces_cost: (0, "job10", 64.),
+    case03_repair_reorders_route: (1, "job4", 64.),
+    case04_repair_keeps_optimal_route: (1, "job10", 64.),
+    case05_repair_many_passes: (10, "job4", 64.),
+}
+
+fn can_estimate_marginal_costs_impl(repair_passes: usize, job_id: &str, expected: f64) {
+    // NOTE the route is not optimal: 0 -> 10 -> 2 -> 12 -> 4 -> 0, so removal of job4 alone does not
+    // change the route cost, but repair reorders the rest of jobs
+    let insertion_ctx = create_test_insertion_ctx(&[10, 2, 12, 4]);
+
+    let marginal_costs = estimate_marginal_costs(&insertion_ctx, repair_passes);
+
+    assert_eq!(marginal_costs.len(), 4);
+    let (_, cost) = marginal_costs.iter().find(|(job, _)| job.dimens().get_id().unwrap() == job_id).unwrap();
+    assert_eq!(*cost, expected);
+}
+
+#[test]
+fn can_estimate_marginal_cost_of_the_last_job_in_route() {
+    let insertion_ctx = create_test_insertion_ctx(&[10]);
+
+    let marginal_costs = estimate_marginal_costs(&insertion_ctx, 1);
+
+    assert_eq!(marginal_costs.len(), 1);
+    assert_eq!(marginal_costs[0].1, 80.);
+}
+
+parameterized_test! {can_estimate_marginal_costs_in_post_processing, (repair_passes, expected), {
+    can_estimate_marginal_costs_in_post_processing_impl(repair_passes, expected);
+}}
+
+can_estimate_marginal_costs_in_post_processing! {
+    case01_requested: (Some(1), Some(vec![64., 64., 80., 64.])),
+    case02_not_requested: (None, None),
+}
+
+fn can_estimate_marginal_costs_in_post_processing_impl(repair_passes: Option<usize>, expected: Option<Vec<f64>>) {
+    let insertion_ctx = create_test_insertion_ctx(&[10, 2, 12, 4]);
+    let mut extras = Extras::default();
+    if let Some(repair_passes) = repair_passes {
+        extras.set_marginal_cost_passes(repair_passes);
+    }
+    let problem = Arc::new(Problem { extras: Arc::new(extras), ..insertion_ctx.problem.as_ref().clone() });
+    let insertion_ctx = InsertionContext { problem: problem.clone(), ..insertion_ctx };
+
+    let insertion_ctx = MarginalCostEstimation::default().post_process(insertion_ctx);
+
+    let solution_extras = create_solution_extras(problem.as_ref(), &insertion_ctx);
+    let marginal_costs =
+        solution_extras.get_marginal_costs().map(|costs| costs.iter().map(|(_, cost)| *cost).collect::<Vec<_>>());
+    assert_eq!(marginal_costs, expected);
+}
@@ -0,0 +1,132 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{IdDimension, TimeInterval, TimeWindow};
+use crate::models::problem::{Vehicle, VehicleDetail, VehiclePlace};
+use crate::models::solution::Place;
+use crate::solver::{create_default_processing, create_processing};
+
+fn create_test_vehicle(id: &str, shift_end: f64) -> Vehicle {
+    Vehicle {
+        details: vec![VehicleDetail {
+            end: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: None, latest: Some(shift_end) } }),
+            ..test_vehicle_detail()
+        }],
+        ..test_vehicle_with_id(id)
+    }
+}
+
+fn create_test_insertion_ctx(shift_end: f64, vehicle_ids: Vec<&str>) -> InsertionContext {
+    let fleet = vehicle_ids
+        .iter()
+        .fold(FleetBuilder::default().add_driver(test_driver()), |builder, id| {
+            builder.add_vehicle(create_test_vehicle(id, shift_end))
+        })
+        .build();
+    let jobs = (1..=3)
+        .map(|idx| {
+            SingleBuilder::default().id(format!("job{}", idx).as_str()).location(Some(10)).duration(10.).build_shared()
+        })
+        .collect::<Vec<_>>();
+    let activities = jobs
+        .iter()
+        .map(|single| {
+            ActivityBuilder::default()
+                .place(Place {
+                    location: 10,
+                    duration: 10.,
+                    time: TimeWindow::new(0., 1000.),
+                    setup: 0.,
+                    lateness: None,
+                })
+                .job(Some(single.clone()))
+                .build()
+        })
+        .collect();
+
+    let route_ctx = create_route_context_with_activities(&fleet, "v1", activities);
+    let mut registry = create_registry_context(&fleet);
+    registry.use_route(&route_ctx);
+
+    let mut insertion_ctx = InsertionContext {
+        problem: create_problem_with_constraint_jobs_and_fleet(
+            create_constraint_pipeline_with_transport(),
+            jobs.into_iter().map(Job::Single).collect(),
+            fleet,
+        ),
+        solution: SolutionContext { routes: vec![route_ctx], registry, ..create_empty_solution_context() },
+        ..create_empty_insertion_context()
+    };
+    insertion_ctx.problem.constraint.accept_solution_state(&mut insertion_ctx.solution);
+
+    insertion_ctx
+}
+
+fn get_job_ids<'a>(jobs: impl Iterator<Item = &'a Job>) -> Vec<String> {
+    let mut ids = jobs.map(|job| job.dimens().get_id().unwrap().clone()).collect::<Vec<_>>();
+    ids.sort();
+
+    ids
+}
+
+parameterized_test! {can_split_over_long_route, (shift_end, vehicle_ids, expected_routes, expected_unassigned), {
+    can_split_over_long_route_impl(shift_end, vehicle_ids, expected_routes, expected_unassigned);
+}}
+
+can_split_over_long_route! {
+    case01_feasible_route: (100., vec!["v1", "v2"], vec![vec!["job1", "job2", "job3"]], vec![]),
+    case02_spare_vehicle: (45., vec!["v1", "v2"], vec![vec!["job1", "job2"], vec!["job3"]], vec![]),
+    case03_no_spare_vehicle: (45., vec!["v1"], vec![vec!["job1", "job2"]], vec!["job3"]),
+    case04_several_tail_jobs: (35., vec!["v1", "v2", "v3"], vec![vec!["job1"], vec!["job2"], vec!["job3"]], vec![]),
+}
+
+fn can_split_over_long_route_impl(
+    shift_end: f64,
+    vehicle_ids: Vec<&str>,
+    expected_routes: Vec<Vec<&str>>,
+    expected_unassigned: Vec<&str>,
+) {
+    let insertion_ctx = create_test_insertion_ctx(shift_end, vehicle_ids);
+
+    let insertion_ctx = RouteSplitter::default().post_process(insertion_ctx);
+
+    let mut actual_routes = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| get_job_ids(route_ctx.route.tour.jobs().collect::<Vec<_>>().iter()))
+        .collect::<Vec<_>>();
+    actual_routes.sort();
+    assert_eq!(actual_routes, expected_routes);
+    assert!(insertion_ctx.solution.routes.iter().all(|route_ctx| !is_over_long(route_ctx)));
+    assert_eq!(get_job_ids(insertion_ctx.solution.unassigned.keys()), expected_unassigned);
+}
+
+parameterized_test! {can_split_routes_only_when_enabled, (split_routes, expected_routes), {
+    can_split_routes_only_when_enabled_impl(split_routes, expected_routes);
+}}
+
+can_split_routes_only_when_enabled! {
+    case01_default: (false, vec![vec!["job1", "job2", "job3"]]),
+    case02_enabled: (true, vec![vec!["job1", "job2"], vec!["job3"]]),
+}
+
+fn can_split_routes_only_when_enabled_impl(split_routes: bool, expected_routes: Vec<Vec<&str>>) {
+    let insertion_ctx = create_test_insertion_ctx(45., vec!["v1", "v2"]);
+    let processing = if split_routes { create_processing(true) } else { create_default_processing() };
+
+    let insertion_ctx =
+        processing.solution.iter().fold(insertion_ctx, |insertion_ctx, hook| hook.post_process(insertion_ctx));
+
+    let mut actual_routes = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| get_job_ids(route_ctx.route.tour.jobs().collect::<Vec<_>>().iter()))
+        .collect::<Vec<_>>();
+    actual_routes.sort();
+    assert_eq!(actual_routes, expected_routes);
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+}
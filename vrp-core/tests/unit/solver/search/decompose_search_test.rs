@@ -1,5 +1,8 @@
 use super::*;
-use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::helpers::models::problem::test_place_with_location;
+use crate::helpers::solver::{generate_matrix_routes, generate_matrix_routes_with_defaults};
+use crate::models::common::IdDimension;
+use crate::models::problem::Single;
 use crate::solver::create_default_heuristic_operator;
 use rosomaxa::prelude::*;
 
@@ -44,6 +47,42 @@ fn can_create_multiple_insertion_ctxs_with_unassigned() {
     assert_eq!(individuals[3].0.solution.unassigned.len(), 5);
 }
 
+#[test]
+fn can_respect_partition_hints() {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes(
+        5,
+        7,
+        false,
+        |id, location| {
+            let mut single = Single { places: vec![test_place_with_location(location)], dimens: Default::default() };
+            // NOTE first two routes serve locations from 0 to 9
+            let partition = if location.unwrap() < 10 { "a" } else { "b" };
+            single.dimens.set_id(id).set_partition(partition.to_string());
+            Arc::new(single)
+        },
+        |v| v,
+        |data| (data.clone(), data),
+    );
+    let individual = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment);
+
+    (0..10).for_each(|_| {
+        let individuals = create_multiple_insertion_contexts(&individual, (2, 4)).unwrap();
+
+        individuals.iter().for_each(|(insertion_ctx, _)| {
+            let partitions = insertion_ctx
+                .solution
+                .routes
+                .iter()
+                .flat_map(|route_ctx| route_ctx.route.tour.jobs())
+                .filter_map(|job| job.dimens().get_partition().cloned())
+                .collect::<HashSet<_>>();
+            assert!(partitions.len() <= 1);
+        });
+        assert_eq!(individuals.iter().map(|(insertion_ctx, _)| insertion_ctx.solution.routes.len()).sum::<usize>(), 7);
+    });
+}
+
 #[test]
 fn can_mutate() {
     let environment = Arc::new(Environment::default());
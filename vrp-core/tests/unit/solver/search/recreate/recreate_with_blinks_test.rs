@@ -1,11 +1,13 @@
-use super::DemandJobSelector;
-use crate::construction::heuristics::JobSelector;
+use super::{BlinkResultSelector, DemandJobSelector};
+use crate::construction::heuristics::{JobSelector, ResultSelector};
 use crate::helpers::construction::constraints::create_simple_demand;
 use crate::helpers::construction::heuristics::create_test_insertion_context;
 use crate::helpers::models::problem::test_single_with_simple_demand;
-use crate::helpers::models::solution::create_test_registry;
+use crate::helpers::models::solution::{create_empty_route_ctx, create_test_registry};
 use crate::models::common::SingleDimLoad;
 use crate::models::problem::Job;
+use crate::utils::{DefaultRandom, Either};
+use std::sync::Arc;
 
 parameterized_test! {can_sort_jobs_by_demand, (demands, is_asc_order, expected), {
         can_sort_jobs_by_demand_impl(demands, is_asc_order, expected);
@@ -31,3 +33,23 @@ fn can_sort_jobs_by_demand_impl(demands: Vec<i32>, is_asc_order: bool, expected:
 
     assert_eq!(result, expected);
 }
+
+parameterized_test! {can_skip_best_cost_with_blink_ratio, (ratio, expected), {
+        can_skip_best_cost_with_blink_ratio_impl(ratio, expected);
+}}
+
+can_skip_best_cost_with_blink_ratio! {
+        case01_never_blink: (0., 1.),
+        case02_always_blink: (1., 2.),
+}
+
+fn can_skip_best_cost_with_blink_ratio_impl(ratio: f64, expected: f64) {
+    let selector = BlinkResultSelector::new(ratio, Arc::new(DefaultRandom::default()));
+
+    let result = match selector.select_cost(&create_empty_route_ctx(), 2., 1.) {
+        Either::Left(left) => left,
+        Either::Right(right) => right,
+    };
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,54 @@
+use super::*;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
+use crate::models::common::IdDimension;
+
+struct RemoveAllRoutes {}
+
+impl Ruin for RemoveAllRoutes {
+    fn run(&self, _: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+        let solution = &mut insertion_ctx.solution;
+        solution
+            .required
+            .extend(solution.routes.drain(0..).flat_map(|route_ctx| route_ctx.route.tour.jobs().collect::<Vec<_>>()));
+
+        insertion_ctx
+    }
+}
+
+parameterized_test! {can_protect_routes_from_ruin, (biases, reals, expected_routes), {
+    can_protect_routes_from_ruin_impl(biases, reals, expected_routes);
+}}
+
+can_protect_routes_from_ruin! {
+    case01_no_bias: (vec![1., 1., 1., 1.], vec![], vec![]),
+    case02_never_ruin_some: (vec![0., 1., 0., 1.], vec![], vec!["0", "2"]),
+    case03_never_ruin_all: (vec![0., 0., 0., 0.], vec![], vec!["0", "1", "2", "3"]),
+    case04_probability: (vec![0.5, 0.5, 1., 1.], vec![0.2, 0.7], vec!["1"]),
+}
+
+fn can_protect_routes_from_ruin_impl(biases: Vec<f64>, reals: Vec<f64>, expected_routes: Vec<&str>) {
+    let (problem, solution) = generate_matrix_routes_with_defaults(4, 4, false);
+    let insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(FakeRandom::new(vec![], reals))),
+    );
+    let ruin = BiasedRuin::new(
+        Arc::new(RemoveAllRoutes {}),
+        Arc::new(move |actor: &Actor| biases[actor.vehicle.dimens.get_id().unwrap().parse::<usize>().unwrap()]),
+    );
+
+    let insertion_ctx = ruin.run(&create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    let mut routes = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| route_ctx.route.actor.vehicle.dimens.get_id().unwrap().clone())
+        .collect::<Vec<_>>();
+    routes.sort();
+    assert_eq!(routes, expected_routes);
+    assert_eq!(insertion_ctx.solution.required.len(), 16 - 4 * expected_routes.len());
+}
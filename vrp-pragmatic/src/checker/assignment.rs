@@ -9,8 +9,12 @@ use crate::utils::combine_error_results;
 use hashbrown::HashSet;
 use std::cmp::Ordering;
 use vrp_core::construction::clustering::vicinity::ServingPolicy;
+use vrp_core::models::problem::ServiceRateDimension;
 use vrp_core::prelude::compare_floats;
 
+/// A tolerance used when activity times are compared: they are rounded to seconds.
+const TIME_TOLERANCE: f64 = 1.;
+
 /// Checks assignment of jobs and vehicles.
 pub fn check_assignment(ctx: &CheckerContext) -> Result<(), Vec<String>> {
     combine_error_results(&[
@@ -19,6 +23,17 @@ pub fn check_assignment(ctx: &CheckerContext) -> Result<(), Vec<String>> {
         check_jobs_match(ctx),
         check_dispatch(ctx),
         check_groups(ctx),
+        check_bundles(ctx),
+        check_visit_gaps(ctx),
+        check_ride_durations(ctx),
+        check_placements(ctx),
+        check_loading_order(ctx),
+        check_backhaul(ctx),
+        check_sync(ctx),
+        check_precedences(ctx),
+        check_strict_territories(ctx),
+        check_allowed_areas(ctx),
+        check_physical_limits(ctx),
     ])
 }
 
@@ -113,10 +128,12 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
         Ok(())
     })?;
 
+    let reservations = ctx.solution.extras.as_ref().and_then(|extras| extras.reservations.as_ref());
     let all_unassigned_jobs = ctx
         .solution
         .unassigned
         .iter()
+        .chain(reservations)
         .flat_map(|jobs| jobs.iter().filter(|job| !job.job_id.ends_with("_break")))
         .map(|job| job.job_id.clone())
         .collect::<Vec<_>>();
@@ -173,14 +190,19 @@ fn check_jobs_match(ctx: &CheckerContext) -> Result<(), String> {
                                     let result = try_match_point_job(tour, stop, activity, job_index, coord_index);
                                     match result {
                                         Err(_) => {
-                                            // NOTE required break is not a job
-                                            if activity.activity_type == "break" {
-                                                try_match_break_activity(&ctx.problem, tour, &stop.time, activity).is_err()
-                                            } else {
-                                                true
+                                            // NOTE required break and maintenance are not jobs
+                                            match activity.activity_type.as_str() {
+                                                "break" => try_match_break_activity(&ctx.problem, tour, &stop.time, activity).is_err(),
+                                                "maintenance" => try_match_maintenance_activity(&ctx.problem, tour, &stop.time, activity).is_err(),
+                                                _ => true,
                                             }
                                         },
-                                        Ok(Some(JobInfo(_, _, place, time))) => {
+                                        Ok(Some(JobInfo(_, single, place_idx, mut place, time))) => {
+                                            // NOTE service duration depends on vehicle's service rate unless the place has own one
+                                            let rate = ctx.get_vehicle(&tour.vehicle_id).ok().and_then(|vehicle| vehicle.duration_per_unit);
+                                            let units = single.dimens.get_service_units().and_then(|units| units.get(place_idx));
+                                            place.duration += units.zip(rate).map_or(0., |(units, rate)| units * rate);
+
                                             let not_equal = |left: f64, right: f64| compare_floats(left, right) != Ordering::Equal;
                                             let parking = ctx
                                                 .clustering
@@ -198,7 +220,12 @@ fn check_jobs_match(ctx: &CheckerContext) -> Result<(), String> {
                                                 | (&None, &Some(_), Ok(Some(_))) => true,
                                                 (_, None, Ok(None)) => {
                                                     let expected_departure = time.start.max(place.time.start) + place.duration + extra_time;
-                                                    not_equal(time.end, expected_departure)
+                                                    // NOTE recharge duration depends on energy level at arrival
+                                                    if activity.activity_type == "recharge" {
+                                                        time.end < expected_departure
+                                                    } else {
+                                                        not_equal(time.end, expected_departure)
+                                                    }
                                                 }
                                                 (Some(config), Some(commute), Ok(Some(d_commute))) => {
                                                     let (service_time, parking) = match config.serving {
@@ -215,7 +242,15 @@ fn check_jobs_match(ctx: &CheckerContext) -> Result<(), String> {
                                                     let service_time = service_time
                                                         + if a_commute.is_zero_distance() && *idx > 0 { parking } else { 0. };
 
-                                                    let expected_departure = time.start.max(place.time.start)
+                                                    // NOTE time window is applied before parking and commute which delay service start
+                                                    let parked = stop
+                                                        .parking
+                                                        .as_ref()
+                                                        .filter(|_| *idx == 0)
+                                                        .map_or(0., |parking| parse_time(&parking.end) - parse_time(&parking.start));
+                                                    let service_start = place.time.start + parked + d_commute.forward.duration;
+
+                                                    let expected_departure = time.start.max(service_start)
                                                         + service_time
                                                         + d_commute.backward.duration
                                                         + extra_time;
@@ -368,3 +403,508 @@ fn check_groups(ctx: &CheckerContext) -> Result<(), String> {
         Err(format!("job groups are not respected: '{}'", err_info))
     }
 }
+
+/// Checks that jobs of the same bundle are either all assigned or all unassigned.
+fn check_bundles(ctx: &CheckerContext) -> Result<(), String> {
+    let assigned = ctx
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| activity.job_id.as_str())
+        .collect::<HashSet<_>>();
+
+    let mut violations = ctx
+        .problem
+        .plan
+        .jobs
+        .iter()
+        .filter_map(|job| job.bundle.as_ref().map(|bundle| (bundle, assigned.contains(job.id.as_str()))))
+        .fold(HashMap::<&String, HashSet<bool>>::default(), |mut acc, (bundle, is_assigned)| {
+            acc.entry(bundle).or_default().insert(is_assigned);
+            acc
+        })
+        .into_iter()
+        .filter(|(_, usage)| usage.len() > 1)
+        .map(|(bundle, _)| bundle.clone())
+        .collect::<Vec<_>>();
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        violations.sort();
+        Err(format!("job bundles are partially assigned: '{}'", violations.join(",")))
+    }
+}
+
+/// Checks that time between consecutive visits of the job respects its visit gap.
+fn check_visit_gaps(ctx: &CheckerContext) -> Result<(), String> {
+    let violations = ctx
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            tour.stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+                .filter_map(|(stop, activity)| {
+                    ctx.get_job_by_id(&activity.job_id)
+                        .and_then(|job| job.visit_gap.as_ref())
+                        .map(|gap| (activity.job_id.clone(), (gap, ctx.get_activity_time(stop, activity))))
+                })
+                .fold(HashMap::<String, Vec<_>>::default(), |mut acc, (job_id, visit)| {
+                    acc.entry(job_id).or_default().push(visit);
+                    acc
+                })
+                .into_iter()
+        })
+        .filter(|(_, visits)| {
+            visits.windows(2).any(|pair| match pair {
+                [(gap, prev), (_, next)] => {
+                    let actual = next.start - prev.end;
+                    gap.min.iter().any(|&min| actual < min - TIME_TOLERANCE)
+                        || gap.max.iter().any(|&max| actual > max + TIME_TOLERANCE)
+                }
+                _ => false,
+            })
+        })
+        .map(|(job_id, _)| job_id)
+        .collect::<Vec<_>>();
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("job visit gaps are not respected: '{}'", violations.join(",")))
+    }
+}
+
+/// Checks that time between completion of the first pickup and start of each delivery of the job
+/// respects its max ride duration.
+fn check_ride_durations(ctx: &CheckerContext) -> Result<(), String> {
+    let violations = ctx
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            tour.stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+                .filter_map(|(stop, activity)| {
+                    ctx.get_job_by_id(&activity.job_id)
+                        .and_then(|job| job.max_ride_duration.map(|max_duration| (job, max_duration)))
+                        .map(|(job, max_duration)| (job, max_duration, activity, ctx.get_activity_time(stop, activity)))
+                })
+                .fold(
+                    HashMap::<String, (Duration, Option<Timestamp>, Vec<Timestamp>)>::default(),
+                    |mut acc, (job, max_duration, activity, time)| {
+                        let (_, pickup_end, delivery_starts) =
+                            acc.entry(job.id.clone()).or_insert((max_duration, None, vec![]));
+
+                        match activity.activity_type.as_str() {
+                            "pickup" => *pickup_end = Some(pickup_end.map_or(time.end, |end| end.min(time.end))),
+                            "delivery" => delivery_starts.push(get_service_start(
+                                job.deliveries.iter().flatten(),
+                                activity,
+                                time.start,
+                            )),
+                            _ => {}
+                        }
+
+                        acc
+                    },
+                )
+                .into_iter()
+        })
+        .filter(|(_, (max_duration, pickup_end, delivery_starts))| {
+            pickup_end.is_some_and(|pickup_end| {
+                delivery_starts.iter().any(|start| start - pickup_end > max_duration + TIME_TOLERANCE)
+            })
+        })
+        .map(|(job_id, _)| job_id)
+        .collect::<Vec<_>>();
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("job max ride durations are not respected: '{}'", violations.join(",")))
+    }
+}
+
+/// Returns service start of activity taking into account waiting for time window start.
+fn get_service_start<'a>(
+    tasks: impl Iterator<Item = &'a JobTask>,
+    activity: &Activity,
+    arrival: Timestamp,
+) -> Timestamp {
+    tasks
+        .flat_map(|task| task.places.iter())
+        .filter(|place| activity.job_tag.is_none() || place.tag == activity.job_tag)
+        .flat_map(|place| place.times.iter().flatten())
+        .map(|time| parse_time_window(time))
+        .filter(|time| time.end >= arrival)
+        .map(|time| time.start)
+        .reduce(f64::min)
+        .map_or(arrival, |start| arrival.max(start))
+}
+
+/// Checks that deliveries within each trip respect item placement order.
+fn check_placements(ctx: &CheckerContext) -> Result<(), String> {
+    let violations = ctx
+        .solution
+        .tours
+        .iter()
+        .filter(|tour| {
+            let mut placements = tour.stops.iter().flat_map(|stop| stop.activities().iter()).filter_map(|activity| {
+                match activity.activity_type.as_str() {
+                    "reload" => Some(None),
+                    "delivery" => ctx
+                        .get_job_by_id(&activity.job_id)
+                        .filter(|job| job.pickups.as_ref().map_or(0, |pickups| pickups.len()) == 0)
+                        .and_then(|job| job.placement)
+                        .map(Some),
+                    _ => None,
+                }
+            });
+
+            let mut last_placement = None;
+            placements.any(|placement| match placement {
+                Some(placement) if last_placement.is_some_and(|last| last > placement) => true,
+                Some(placement) => {
+                    last_placement = Some(placement);
+                    false
+                }
+                None => {
+                    last_placement = None;
+                    false
+                }
+            })
+        })
+        .map(|tour| tour.vehicle_id.clone())
+        .collect::<Vec<_>>();
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("job placements are not respected in tours: '{}'", violations.join(",")))
+    }
+}
+
+/// Checks that pickup and delivery jobs are served in vehicle's loading order.
+fn check_loading_order(ctx: &CheckerContext) -> Result<(), String> {
+    let is_pair_job = |job: &Job| {
+        let get_size = |tasks: &Option<Vec<JobTask>>| tasks.as_ref().map_or(0, |tasks| tasks.len());
+        get_size(&job.pickups) == 1 && get_size(&job.deliveries) == 1
+    };
+
+    let violations = ctx
+        .solution
+        .tours
+        .iter()
+        .filter_map(|tour| {
+            ctx.get_vehicle(&tour.vehicle_id)
+                .ok()
+                .and_then(|vehicle| vehicle.loading.as_ref())
+                .and_then(|loading| loading.order)
+                .map(|order| (tour, order))
+        })
+        .filter(|(tour, order)| {
+            let mut loaded: Vec<&String> = Vec::new();
+
+            tour.stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter())
+                .filter(|activity| ctx.get_job_by_id(&activity.job_id).is_some_and(is_pair_job))
+                .any(|activity| match activity.activity_type.as_str() {
+                    "pickup" => {
+                        loaded.push(&activity.job_id);
+                        false
+                    }
+                    "delivery" => {
+                        let expected = match order {
+                            VehicleLoadingOrder::Lifo => loaded.pop(),
+                            VehicleLoadingOrder::Fifo if loaded.is_empty() => None,
+                            VehicleLoadingOrder::Fifo => Some(loaded.remove(0)),
+                        };
+
+                        expected != Some(&activity.job_id)
+                    }
+                    _ => false,
+                })
+        })
+        .map(|(tour, _)| tour.vehicle_id.clone())
+        .collect::<Vec<_>>();
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("vehicle loading order is not respected in tours: '{}'", violations.join(",")))
+    }
+}
+
+fn check_backhaul(ctx: &CheckerContext) -> Result<(), String> {
+    let has_tasks = |tasks: &Option<Vec<JobTask>>| tasks.as_ref().is_some_and(|tasks| !tasks.is_empty());
+    let get_haul_type = |job: &Job| match (has_tasks(&job.pickups), has_tasks(&job.deliveries)) {
+        _ if has_tasks(&job.replacements) || has_tasks(&job.services) => None,
+        (true, false) => Some("pickup"),
+        (false, true) => Some("delivery"),
+        _ => None,
+    };
+
+    let violations = ctx
+        .solution
+        .tours
+        .iter()
+        .filter(|tour| {
+            ctx.get_vehicle(&tour.vehicle_id)
+                .ok()
+                .and_then(|vehicle| vehicle.loading.as_ref())
+                .and_then(|loading| loading.backhaul.as_ref())
+                .is_some_and(|backhaul| backhaul.penalty.is_none())
+        })
+        .filter(|tour| {
+            let mut has_pickups = false;
+
+            tour.stops.iter().flat_map(|stop| stop.activities().iter()).any(|activity| {
+                let haul_type = ctx.get_job_by_id(&activity.job_id).and_then(get_haul_type);
+                match haul_type {
+                    Some(haul_type) if haul_type == activity.activity_type => {
+                        has_pickups |= haul_type == "pickup";
+                        has_pickups && haul_type == "delivery"
+                    }
+                    _ => false,
+                }
+            })
+        })
+        .map(|tour| tour.vehicle_id.clone())
+        .collect::<Vec<_>>();
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("vehicle backhaul requirement is not respected in tours: '{}'", violations.join(",")))
+    }
+}
+
+/// Checks that synchronized jobs are either all assigned to different tours with service start
+/// times within the offset or all unassigned.
+fn check_sync(ctx: &CheckerContext) -> Result<(), String> {
+    let sizes = ctx.problem.plan.jobs.iter().filter_map(|job| job.sync.as_ref()).fold(
+        HashMap::<String, usize>::default(),
+        |mut acc, sync| {
+            *acc.entry(sync.key.clone()).or_insert(0) += 1;
+            acc
+        },
+    );
+
+    let mut violations = ctx
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            tour.stops.iter().flat_map(move |stop| stop.activities().iter().map(move |activity| (tour, stop, activity)))
+        })
+        .filter_map(|(tour, stop, activity)| {
+            ctx.get_job_by_id(&activity.job_id).and_then(|job| job.sync.as_ref().map(|sync| (job, sync))).map(
+                |(job, sync)| {
+                    let arrival = ctx.get_activity_time(stop, activity).start;
+                    (tour, sync, get_service_start(get_job_tasks(job), activity, arrival))
+                },
+            )
+        })
+        .fold(
+            HashMap::<String, (Duration, HashSet<(String, usize)>, Vec<Timestamp>)>::default(),
+            |mut acc, (tour, sync, start)| {
+                let (_, tours, starts) =
+                    acc.entry(sync.key.clone()).or_insert((sync.offset.unwrap_or(0.), HashSet::default(), vec![]));
+                tours.insert((tour.vehicle_id.clone(), tour.shift_index));
+                starts.push(start);
+
+                acc
+            },
+        )
+        .into_iter()
+        .filter(|(key, (offset, tours, starts))| {
+            let earliest = starts.iter().cloned().fold(f64::MAX, f64::min);
+            let latest = starts.iter().cloned().fold(f64::MIN, f64::max);
+
+            sizes.get(key).cloned().unwrap_or(0) != starts.len()
+                || tours.len() != starts.len()
+                || latest - earliest > offset + TIME_TOLERANCE
+        })
+        .map(|(key, _)| key)
+        .collect::<Vec<_>>();
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        violations.sort();
+        Err(format!("job sync requirement is not respected for sync keys: '{}'", violations.join(",")))
+    }
+}
+
+/// Checks that assigned jobs have all their predecessors assigned and started after they are completed.
+fn check_precedences(ctx: &CheckerContext) -> Result<(), String> {
+    let times = ctx
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            tour.stops.iter().flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        })
+        .filter_map(|(stop, activity)| {
+            ctx.get_job_by_id(&activity.job_id).map(|job| {
+                let time = ctx.get_activity_time(stop, activity);
+                (job.id.clone(), (get_service_start(get_job_tasks(job), activity, time.start), time.end))
+            })
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut violations = ctx
+        .problem
+        .plan
+        .jobs
+        .iter()
+        .filter(|job| {
+            times.get(&job.id).is_some_and(|(start, _)| {
+                job.predecessors.iter().flatten().any(|predecessor| match times.get(predecessor) {
+                    Some((_, completion)) => *start + TIME_TOLERANCE < *completion,
+                    None => true,
+                })
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        violations.sort();
+        Err(format!("job precedence is not respected for jobs: '{}'", violations.join(",")))
+    }
+}
+
+/// Checks that jobs of strict territories are served only by territory vehicles.
+fn check_strict_territories(ctx: &CheckerContext) -> Result<(), String> {
+    let plan = &ctx.problem.plan;
+    let area_jobs = plan.areas.iter().flatten().map(|area| (&area.id, &area.jobs)).collect::<HashMap<_, _>>();
+    let job_vehicles = plan
+        .territories
+        .iter()
+        .flatten()
+        .filter(|territory| territory.is_strict.unwrap_or(false))
+        .flat_map(|territory| {
+            territory
+                .areas
+                .iter()
+                .filter_map(|area_id| area_jobs.get(area_id))
+                .flat_map(|jobs| jobs.iter())
+                .map(move |job_id| (job_id, &territory.vehicles))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut violations = ctx
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            tour.stops.iter().flat_map(|stop| stop.activities().iter()).map(move |activity| (tour, activity))
+        })
+        .filter(|(tour, activity)| {
+            job_vehicles.get(&activity.job_id).is_some_and(|vehicles| !vehicles.contains(&tour.vehicle_id))
+        })
+        .map(|(_, activity)| activity.job_id.clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        violations.sort();
+        Err(format!("strict territory is not respected for jobs: '{}'", violations.join(",")))
+    }
+}
+
+/// Checks that vehicles with hard area restriction serve only jobs from their allowed areas.
+fn check_allowed_areas(ctx: &CheckerContext) -> Result<(), String> {
+    let area_jobs =
+        ctx.problem.plan.areas.iter().flatten().map(|area| (&area.id, &area.jobs)).collect::<HashMap<_, _>>();
+    let type_jobs = ctx
+        .problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| {
+            vehicle
+                .limits
+                .as_ref()
+                .and_then(|limits| limits.allowed_areas.as_ref())
+                .filter(|allowed_areas| allowed_areas.penalty.is_none())
+                .map(|allowed_areas| {
+                    let jobs = allowed_areas
+                        .areas
+                        .iter()
+                        .filter_map(|area_id| area_jobs.get(area_id))
+                        .flat_map(|jobs| jobs.iter())
+                        .collect::<HashSet<_>>();
+
+                    (&vehicle.type_id, jobs)
+                })
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut violations = ctx
+        .solution
+        .tours
+        .iter()
+        .filter_map(|tour| type_jobs.get(&tour.type_id).map(|jobs| (tour, jobs)))
+        .flat_map(|(tour, jobs)| {
+            tour.stops.iter().flat_map(|stop| stop.activities().iter()).map(move |activity| (jobs, activity))
+        })
+        .filter(|(jobs, activity)| ctx.job_map.contains_key(&activity.job_id) && !jobs.contains(&activity.job_id))
+        .map(|(_, activity)| activity.job_id.clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        violations.sort();
+        Err(format!("allowed vehicle areas are not respected for jobs: '{}'", violations.join(",")))
+    }
+}
+
+/// Checks that jobs are served only by vehicles which physical limits allow to carry their items.
+fn check_physical_limits(ctx: &CheckerContext) -> Result<(), String> {
+    let exceeds = |value: Option<f64>, limit: Option<f64>| value.zip(limit).is_some_and(|(value, limit)| value > limit);
+
+    let mut violations = ctx
+        .solution
+        .tours
+        .iter()
+        .filter_map(|tour| {
+            let physical = ctx.get_vehicle(tour.vehicle_id.as_str()).ok()?.physical.as_ref()?;
+            Some(tour.stops.iter().flat_map(|stop| stop.activities().iter()).map(move |activity| (physical, activity)))
+        })
+        .flatten()
+        .filter(|(physical, activity)| {
+            ctx.job_map.get(&activity.job_id).and_then(|job| job.item.as_ref()).is_some_and(|item| {
+                exceeds(item.weight, physical.max_axle_weight)
+                    || exceeds(item.length, physical.max_item_length)
+                    || exceeds(item.height, physical.max_item_height)
+            })
+        })
+        .map(|(_, activity)| activity.job_id.clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        violations.sort();
+        Err(format!("vehicle physical limits are not respected for jobs: '{}'", violations.join(",")))
+    }
+}
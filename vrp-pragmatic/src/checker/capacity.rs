@@ -3,89 +3,196 @@
 mod capacity_test;
 
 use super::*;
+use crate::constraints::mask_load;
+use crate::format::problem::get_non_reloadable_dimensions;
 use crate::utils::combine_error_results;
 use std::iter::once;
 use vrp_core::models::common::{Load, MultiDimLoad};
 
 /// Checks that vehicle load is assigned correctly. The following rules are checked:
-/// * max vehicle's capacity is not violated
+/// * max vehicle's capacity is not violated, trip capacity of reload is respected after it
 /// * load change is correct
+/// * truck only jobs are served while trailer is detached and truck capacity is not violated
+/// * amount of vehicles reloaded at the same dock simultaneously does not exceed amount of docks
 pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), Vec<String>> {
-    combine_error_results(&[check_vehicle_load_assignment(context), check_resource_consumption(context)])
+    combine_error_results(&[
+        check_vehicle_load_assignment(context),
+        check_resource_consumption(context),
+        check_trailer_usage(context),
+        check_dock_usage(context),
+    ])
 }
 
 fn check_vehicle_load_assignment(context: &CheckerContext) -> Result<(), String> {
     context.solution.tours.iter().try_for_each(|tour| {
         let capacity = MultiDimLoad::new(context.get_vehicle(&tour.vehicle_id)?.capacity.clone());
-        let intervals = get_intervals(context, tour);
 
-        intervals
-            .iter()
-            .try_fold::<_, _, Result<_, String>>(MultiDimLoad::default(), |acc, interval| {
-                let (start_delivery, end_pickup) = get_activities_from_interval(context, tour, interval.as_slice())
-                    .try_fold::<_, _, Result<_, String>>(
-                    (acc, MultiDimLoad::default()),
-                    |acc, (activity, activity_type)| {
-                        let activity_type = activity_type?;
-                        let demand = get_demand(context, &activity, &activity_type)?;
-                        Ok(match demand {
-                            (DemandType::StaticDelivery, demand) => (acc.0 + demand, acc.1),
-                            (DemandType::StaticPickup, demand) => (acc.0, acc.1 + demand),
-                            (DemandType::StaticPickupDelivery, demand) => (acc.0 + demand, acc.1 + demand),
-                            _ => acc,
-                        })
-                    },
-                )?;
+        fold_tour_intervals(context, tour, |interval, trip| {
+            let capacity = get_trip_capacity(context, tour, interval)?.unwrap_or(capacity);
 
-                let end_capacity = interval.iter().try_fold(start_delivery, |acc, (idx, (from, to))| {
-                    let from_load = MultiDimLoad::new(from.load().clone());
-                    let to_load = MultiDimLoad::new(to.load().clone());
+            interval.iter().try_fold(trip.start_delivery, |acc, (idx, (from, to))| {
+                let from_load = MultiDimLoad::new(from.load().clone());
+                let to_load = MultiDimLoad::new(to.load().clone());
 
-                    if !capacity.can_fit(&from_load) || !capacity.can_fit(&to_load) {
-                        return Err(format!("load exceeds capacity in tour '{}'", tour.vehicle_id));
-                    }
+                if !capacity.can_fit(&from_load) || !capacity.can_fit(&to_load) {
+                    return Err(format!("load exceeds capacity in tour '{}'", tour.vehicle_id));
+                }
 
-                    let change = to.activities().iter().try_fold::<_, _, Result<_, String>>(
-                        MultiDimLoad::default(),
-                        |acc, activity| {
-                            let activity_type = context.get_activity_type(tour, to, activity)?;
-                            let (demand_type, demand) =
-                                if activity.activity_type == "arrival" || activity.activity_type == "reload" {
-                                    (DemandType::StaticDelivery, end_pickup)
-                                } else {
-                                    get_demand(context, activity, &activity_type)?
-                                };
-
-                            Ok(match demand_type {
-                                DemandType::StaticDelivery | DemandType::DynamicDelivery => acc - demand,
-                                DemandType::StaticPickup | DemandType::DynamicPickup => acc + demand,
-                                DemandType::None | DemandType::StaticPickupDelivery => acc,
-                            })
-                        },
-                    )?;
-
-                    let is_from_valid = from_load == acc;
-                    let is_to_valid = to_load == from_load + change;
-
-                    if (is_from_valid && is_to_valid) || (*idx == 0 && has_dispatch(tour)) {
-                        Ok(to_load)
-                    } else {
-                        let message = match (is_from_valid, is_to_valid) {
-                            (true, false) => format!("at stop {}", idx + 1),
-                            (false, true) => format!("at stop {}", idx),
-                            _ => format!("at stops {}, {}", idx, idx + 1),
-                        };
-
-                        Err(format!("load mismatch {} in tour '{}'", message, tour.vehicle_id))
-                    }
-                })?;
+                let change = get_load_change(context, tour, to, &trip)?;
+
+                let is_from_valid = from_load == acc;
+                let is_to_valid = to_load == from_load + change;
 
-                Ok(end_capacity - end_pickup)
+                if (is_from_valid && is_to_valid) || (*idx == 0 && has_dispatch(tour)) {
+                    Ok(to_load)
+                } else {
+                    let message = match (is_from_valid, is_to_valid) {
+                        (true, false) => format!("at stop {}", idx + 1),
+                        (false, true) => format!("at stop {}", idx),
+                        _ => format!("at stops {}, {}", idx, idx + 1),
+                    };
+
+                    Err(format!("load mismatch {} in tour '{}'", message, tour.vehicle_id))
+                }
             })
-            .map(|_| ())
+        })
     })
 }
 
+/// Recomputes vehicle load at each stop of the tour from demand of its activities ignoring reported loads.
+pub(super) fn get_stop_loads(context: &CheckerContext, tour: &Tour) -> Result<Vec<Option<MultiDimLoad>>, String> {
+    let mut loads = vec![None; tour.stops.len()];
+
+    fold_tour_intervals(context, tour, |interval, trip| {
+        interval.iter().try_fold(trip.start_delivery, |from_load, (idx, (from, to))| {
+            // NOTE vehicle is loaded at dispatch, so it leaves depot empty if dispatch is a separate stop
+            let is_empty_departure = *idx == 0 && has_dispatch(tour) && from.activities().len() == 1;
+            let departure_load = if is_empty_departure { MultiDimLoad::default() } else { from_load };
+            loads[*idx].get_or_insert(departure_load);
+
+            let to_load = from_load + get_load_change(context, tour, to, &trip)?;
+            loads[*idx + 1].get_or_insert(to_load);
+
+            Ok(to_load)
+        })
+    })?;
+
+    Ok(loads)
+}
+
+/// Keeps static demand of the trip between two reloads.
+struct TripDemand {
+    start_delivery: MultiDimLoad,
+    end_pickup: MultiDimLoad,
+    carried_pickup: MultiDimLoad,
+    reloaded_pickup: MultiDimLoad,
+}
+
+/// Folds tour trips separated by reloads passing their static demand to the function which
+/// returns vehicle load at the end of the trip.
+fn fold_tour_intervals<F>(context: &CheckerContext, tour: &Tour, mut trip_fn: F) -> Result<(), String>
+where
+    F: FnMut(&[(usize, (&Stop, &Stop))], TripDemand) -> Result<MultiDimLoad, String>,
+{
+    let intervals = get_intervals(context, tour);
+    let static_demands = intervals
+        .iter()
+        .map(|interval| get_static_demand(context, tour, interval.as_slice()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // NOTE goods in non-reloadable dimensions are loaded at tour start and unloaded only at tour end
+    let non_reloadable = get_non_reloadable_dimensions(context.problem.fleet.dimensions.as_ref());
+    let mask = |load: MultiDimLoad| mask_load(load, non_reloadable.as_slice());
+
+    intervals
+        .iter()
+        .zip(static_demands.iter())
+        .enumerate()
+        .try_fold::<_, _, Result<_, String>>(MultiDimLoad::default(), |acc, (interval_idx, (interval, demand))| {
+            let &(delivery, end_pickup) = demand;
+            let start_delivery = if interval_idx == 0 {
+                static_demands.iter().skip(1).fold(acc + delivery, |acc, (delivery, _)| acc + mask(*delivery))
+            } else {
+                acc + delivery - mask(delivery)
+            };
+            let carried_pickup = static_demands
+                .iter()
+                .take(interval_idx)
+                .fold(MultiDimLoad::default(), |acc, (_, pickup)| acc + mask(*pickup));
+            let reloaded_pickup = end_pickup - mask(end_pickup);
+
+            let end_capacity = trip_fn(
+                interval.as_slice(),
+                TripDemand { start_delivery, end_pickup, carried_pickup, reloaded_pickup },
+            )?;
+
+            Ok(end_capacity - reloaded_pickup)
+        })
+        .map(|_| ())
+}
+
+/// Returns load change caused by activities of the given stop.
+fn get_load_change(
+    context: &CheckerContext,
+    tour: &Tour,
+    stop: &Stop,
+    trip: &TripDemand,
+) -> Result<MultiDimLoad, String> {
+    stop.activities().iter().try_fold(MultiDimLoad::default(), |acc, activity| {
+        let activity_type = context.get_activity_type(tour, stop, activity)?;
+        let (demand_type, demand) = match activity.activity_type.as_str() {
+            "arrival" => (DemandType::StaticDelivery, trip.end_pickup + trip.carried_pickup),
+            "reload" => (DemandType::StaticDelivery, trip.reloaded_pickup),
+            _ => get_demand(context, activity, &activity_type)?,
+        };
+
+        Ok(match demand_type {
+            DemandType::StaticDelivery | DemandType::DynamicDelivery => acc - demand,
+            DemandType::StaticPickup | DemandType::DynamicPickup => acc + demand,
+            DemandType::None | DemandType::StaticPickupDelivery => acc,
+        })
+    })
+}
+
+/// Returns capacity of the trip which starts at reload stop, if it is specified.
+fn get_trip_capacity(
+    context: &CheckerContext,
+    tour: &Tour,
+    interval: &[(usize, (&Stop, &Stop))],
+) -> Result<Option<MultiDimLoad>, String> {
+    let reload = interval.first().and_then(|(_, (from, _))| {
+        from.activities().iter().find(|activity| activity.activity_type == "reload").map(|activity| (*from, activity))
+    });
+
+    match reload {
+        Some((stop, activity)) => match context.get_activity_type(tour, stop, activity)? {
+            ActivityType::Reload(reload) => Ok(reload.capacity.map(MultiDimLoad::new)),
+            _ => Ok(None),
+        },
+        None => Ok(None),
+    }
+}
+
+fn get_static_demand(
+    context: &CheckerContext,
+    tour: &Tour,
+    interval: &[(usize, (&Stop, &Stop))],
+) -> Result<(MultiDimLoad, MultiDimLoad), String> {
+    get_activities_from_interval(context, tour, interval).try_fold(
+        (MultiDimLoad::default(), MultiDimLoad::default()),
+        |acc, (activity, activity_type)| {
+            let activity_type = activity_type?;
+            let demand = get_demand(context, &activity, &activity_type)?;
+            Ok(match demand {
+                (DemandType::StaticDelivery, demand) => (acc.0 + demand, acc.1),
+                (DemandType::StaticPickup, demand) => (acc.0, acc.1 + demand),
+                (DemandType::StaticPickupDelivery, demand) => (acc.0 + demand, acc.1 + demand),
+                _ => acc,
+            })
+        },
+    )
+}
+
 fn check_resource_consumption(context: &CheckerContext) -> Result<(), String> {
     let resources = context
         .problem
@@ -93,8 +200,9 @@ fn check_resource_consumption(context: &CheckerContext) -> Result<(), String> {
         .resources
         .iter()
         .flat_map(|resources| resources.iter().cloned())
-        .map(|resource| match resource {
-            VehicleResource::Reload { id, capacity } => (id, MultiDimLoad::new(capacity)),
+        .filter_map(|resource| match resource {
+            VehicleResource::Reload { id, capacity } => Some((id, MultiDimLoad::new(capacity))),
+            VehicleResource::Dock { .. } => None,
         })
         .collect::<HashMap<_, _>>();
 
@@ -152,6 +260,141 @@ fn check_resource_consumption(context: &CheckerContext) -> Result<(), String> {
     })
 }
 
+fn check_trailer_usage(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let trailer = match vehicle.trailer.as_ref() {
+            Some(trailer) => trailer,
+            None => return Ok(()),
+        };
+        let truck_capacity = MultiDimLoad::new(vehicle.capacity.clone()) - MultiDimLoad::new(trailer.capacity.clone());
+
+        let mut detached: Option<(VehicleTrailerParking, Vec<(DemandType, MultiDimLoad)>)> = None;
+
+        tour.stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .try_for_each(|(stop, activity)| {
+                let activity_type = context.get_activity_type(tour, stop, activity)?;
+
+                match (&activity_type, activity.activity_type.as_str(), detached.take()) {
+                    (ActivityType::Trailer(parking), "detach", None) => {
+                        detached = Some((parking.clone(), vec![]));
+                    }
+                    (ActivityType::Trailer(parking), "attach", Some((detach_parking, demands)))
+                        if parking.location == detach_parking.location =>
+                    {
+                        if !can_fit_truck(&truck_capacity, demands.as_slice()) {
+                            return Err(format!("truck capacity is exceeded in tour '{}'", tour.vehicle_id));
+                        }
+                    }
+                    (ActivityType::Trailer(_), _, _) => {
+                        return Err(format!("invalid trailer detach and attach order in tour '{}'", tour.vehicle_id));
+                    }
+                    (ActivityType::Job(job), _, None) if job.truck_only.unwrap_or(false) => {
+                        return Err(format!(
+                            "truck only job '{}' is served with attached trailer in tour '{}'",
+                            job.id, tour.vehicle_id
+                        ));
+                    }
+                    (ActivityType::Job(_), _, Some((parking, mut demands))) => {
+                        demands.push(get_demand(context, activity, &activity_type)?);
+                        detached = Some((parking, demands));
+                    }
+                    (_, _, state) => detached = state,
+                }
+
+                Ok(())
+            })?;
+
+        if detached.is_some() {
+            Err(format!("trailer is not attached back in tour '{}'", tour.vehicle_id))
+        } else {
+            Ok(())
+        }
+    })
+}
+
+fn check_dock_usage(context: &CheckerContext) -> Result<(), String> {
+    let docks = context
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flatten()
+        .filter_map(|resource| match resource {
+            VehicleResource::Dock { id, capacity } => Some((id.clone(), *capacity)),
+            VehicleResource::Reload { .. } => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    if docks.is_empty() {
+        return Ok(());
+    }
+
+    let usage =
+        context.solution.tours.iter().try_fold(HashMap::<String, Vec<TimeWindow>>::default(), |acc, tour| {
+            tour.stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+                .filter(|(_, activity)| activity.activity_type == "reload")
+                .try_fold(acc, |mut acc, (stop, activity)| {
+                    if let ActivityType::Reload(reload) = context.get_activity_type(tour, stop, activity)? {
+                        if let Some(dock_id) = reload.dock_id {
+                            let time = context.get_activity_time(stop, activity);
+                            acc.entry(dock_id).or_default().push(TimeWindow::new(time.end - reload.duration, time.end));
+                        }
+                    }
+
+                    Ok::<_, String>(acc)
+                })
+        })?;
+
+    usage.into_iter().try_for_each(|(dock_id, usage)| {
+        let capacity = docks
+            .get(&dock_id)
+            .ok_or_else(|| format!("cannot find dock '{}' in list of available resources", dock_id))?;
+
+        let used = usage
+            .iter()
+            .map(|time| usage.iter().filter(|other| other.start <= time.start && time.start < other.end).count())
+            .max()
+            .unwrap_or_default();
+
+        if used > *capacity {
+            Err(format!("dock '{}' is used by more vehicles than available: {} vs {}", dock_id, used, capacity))
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// Checks truck capacity assuming that deliveries are moved from trailer to truck at trailer parking.
+fn can_fit_truck(capacity: &MultiDimLoad, demands: &[(DemandType, MultiDimLoad)]) -> bool {
+    let start_load = demands
+        .iter()
+        .filter(|(demand_type, _)| !matches!(demand_type, DemandType::StaticPickup | DemandType::DynamicPickup))
+        .fold(MultiDimLoad::default(), |acc, (_, demand)| acc + *demand);
+
+    demands
+        .iter()
+        .try_fold(start_load, |load, (demand_type, demand)| {
+            let load = match demand_type {
+                DemandType::StaticDelivery | DemandType::DynamicDelivery => load - *demand,
+                DemandType::StaticPickup | DemandType::DynamicPickup => load + *demand,
+                DemandType::None | DemandType::StaticPickupDelivery => load,
+            };
+
+            if capacity.can_fit(&load) {
+                Some(load)
+            } else {
+                None
+            }
+        })
+        .is_some()
+        && capacity.can_fit(&start_load)
+}
+
 enum DemandType {
     None,
     StaticPickup,
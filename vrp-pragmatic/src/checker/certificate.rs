@@ -0,0 +1,254 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/certificate_test.rs"]
+mod certificate_test;
+
+use crate::format::problem::*;
+use crate::format::solution::{RouteCertificate, StateTransition};
+use crate::format::{CoordIndex, Location};
+use crate::parse_time;
+
+// NOTE certificate times are rounded to seconds
+const TOLERANCE: f64 = 1.;
+
+/// Verifies route feasibility certificate against the problem and routing matrices. Verification
+/// does not depend on the solver: it checks that travel between consecutive transitions takes not
+/// less than routing matrix time, jobs are served at their locations within time windows, shift
+/// times are respected and vehicle load is changed by job demands and stays within capacity.
+pub fn verify_certificate(
+    problem: &Problem,
+    matrices: &[Matrix],
+    certificate: &RouteCertificate,
+) -> Result<(), Vec<String>> {
+    let problem = normalize_dimensions(normalize_times(problem.clone()));
+    let matrices = apply_metric_policies(&problem, infer_matrices(&problem, matrices.to_vec()));
+    let coord_index = CoordIndex::new(&problem);
+
+    let (vehicle, shift) = problem
+        .fleet
+        .vehicles
+        .iter()
+        .find(|vehicle| vehicle.vehicle_ids.contains(&certificate.vehicle_id))
+        .and_then(|vehicle| vehicle.shifts.get(certificate.shift_index).map(|shift| (vehicle, shift)))
+        .ok_or_else(|| vec![format!("unknown vehicle '{}' or its shift", certificate.vehicle_id)])?;
+
+    let verifier = Verifier { problem: &problem, matrices: matrices.as_slice(), coord_index: &coord_index, vehicle };
+
+    let errors = certificate
+        .transitions
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, transition)| {
+            let prev = idx.checked_sub(1).and_then(|idx| certificate.transitions.get(idx));
+            let is_last = idx + 1 == certificate.transitions.len();
+
+            verifier
+                .check_shift(shift, transition, idx == 0, is_last)
+                .err()
+                .into_iter()
+                .chain(prev.and_then(|prev| verifier.check_travel(prev, transition).err()))
+                .chain(verifier.check_job(transition).err())
+                .chain(verifier.check_load(prev, transition).err())
+                .map(move |err| format!("transition {} of vehicle '{}': {}", idx, certificate.vehicle_id, err))
+        })
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+struct Verifier<'a> {
+    problem: &'a Problem,
+    matrices: &'a [Matrix],
+    coord_index: &'a CoordIndex,
+    vehicle: &'a VehicleType,
+}
+
+impl<'a> Verifier<'a> {
+    fn check_shift(
+        &self,
+        shift: &VehicleShift,
+        transition: &StateTransition,
+        is_first: bool,
+        is_last: bool,
+    ) -> Result<(), String> {
+        let departure = parse_time(&transition.departure);
+        let arrival = parse_time(&transition.arrival);
+
+        if arrival > departure + TOLERANCE {
+            return Err("departure is earlier than arrival".to_string());
+        }
+
+        if is_first {
+            if transition.activity_type != "departure" || !self.is_same_location(&shift.start.location, transition) {
+                return Err("route does not start at shift start".to_string());
+            }
+
+            let earliest = parse_time(&shift.start.earliest);
+            let latest = shift.start.latest.as_ref().map_or(f64::MAX, |latest| parse_time(latest));
+            if departure < earliest - TOLERANCE || departure > latest + TOLERANCE {
+                return Err("departure is outside of shift start time".to_string());
+            }
+        }
+
+        if transition.activity_type == "arrival" {
+            let end = shift.end.as_ref().ok_or_else(|| "arrival at shift end which is not defined".to_string())?;
+
+            if !is_last || !self.is_same_location(&end.location, transition) {
+                return Err("route does not end at shift end".to_string());
+            }
+
+            let overtime = shift.overtime.as_ref().map_or(0., |overtime| overtime.max_duration);
+            if arrival > parse_time(&end.latest) + overtime + TOLERANCE {
+                return Err("arrival is later than shift end time".to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_travel(&self, from: &StateTransition, to: &StateTransition) -> Result<(), String> {
+        let from_idx = self.get_location_index(&from.location)?;
+        let to_idx = self.get_location_index(&to.location)?;
+        let matrix_size = (self.matrices.first().map_or(0, |matrix| matrix.travel_times.len()) as f64).sqrt() as usize;
+        let matrix_idx = from_idx * matrix_size + to_idx;
+
+        // NOTE with time dependent routing the fastest travel time is used as a lower bound
+        let duration = self
+            .get_profile_matrices()
+            .filter_map(|matrix| matrix.travel_times.get(matrix_idx).cloned())
+            .filter(|&duration| duration >= 0)
+            .min()
+            .ok_or_else(|| format!("no routing data between '{}' and '{}'", from.job_id, to.job_id))?;
+        let duration = duration as f64 * self.vehicle.profile.scale.unwrap_or(1.);
+
+        if parse_time(&to.arrival) + TOLERANCE < parse_time(&from.departure) + duration {
+            Err(format!("arrival is earlier than travel from '{}' allows", from.job_id))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn check_job(&self, transition: &StateTransition) -> Result<(), String> {
+        let task = match self.get_job_task(transition)? {
+            Some(task) => task,
+            None => return Ok(()),
+        };
+
+        let arrival = parse_time(&transition.arrival);
+        let departure = parse_time(&transition.departure);
+
+        let is_served = task
+            .places
+            .iter()
+            .filter(|place| self.is_same_location(&place.location, transition))
+            .filter(|place| transition.job_tag.is_none() || place.tag == transition.job_tag)
+            .any(|place| {
+                let time_windows = place
+                    .times
+                    .as_ref()
+                    .map(|times| times.iter().map(|tw| (parse_time(&tw[0]), parse_time(&tw[1]))).collect::<Vec<_>>())
+                    .unwrap_or_else(|| vec![(f64::MIN, f64::MAX)]);
+
+                time_windows.into_iter().any(|(start, end)| {
+                    let service_start = arrival.max(start);
+                    let is_in_time = place.lateness_penalty.is_some() || service_start <= end + TOLERANCE;
+
+                    is_in_time && departure + TOLERANCE >= service_start + place.duration
+                })
+            });
+
+        if is_served {
+            Ok(())
+        } else {
+            Err(format!("job '{}' is not served at its place and time", transition.job_id))
+        }
+    }
+
+    fn check_load(&self, prev: Option<&StateTransition>, transition: &StateTransition) -> Result<(), String> {
+        let capacity = &self.vehicle.capacity;
+        let load = |load: &[i32], idx: usize| load.get(idx).cloned().unwrap_or(0);
+        let size = capacity.len().max(transition.load.len());
+
+        if (0..size).any(|idx| load(&transition.load, idx) < 0 || load(&transition.load, idx) > load(capacity, idx)) {
+            return Err("load is out of vehicle capacity".to_string());
+        }
+
+        // NOTE only job activities have known demand, other activities, e.g. reload, can change load freely
+        let (task, prev) = match (self.get_job_task(transition)?, prev) {
+            (Some(task), Some(prev)) => (task, prev),
+            _ => return Ok(()),
+        };
+
+        let demand = task.demand.clone().unwrap_or_default();
+        let sign = match transition.activity_type.as_str() {
+            "pickup" => 1,
+            "delivery" => -1,
+            _ => 0,
+        };
+
+        let size = size.max(prev.load.len());
+        if (0..size).any(|idx| load(&transition.load, idx) != load(&prev.load, idx) + sign * load(&demand, idx)) {
+            Err(format!("load change does not match demand of job '{}'", transition.job_id))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn get_job_task(&self, transition: &StateTransition) -> Result<Option<&'a JobTask>, String> {
+        let tasks = |job: &'a Job| match transition.activity_type.as_str() {
+            "pickup" => job.pickups.as_ref(),
+            "delivery" => job.deliveries.as_ref(),
+            "replacement" => job.replacements.as_ref(),
+            "service" => job.services.as_ref(),
+            _ => None,
+        };
+
+        if !matches!(transition.activity_type.as_str(), "pickup" | "delivery" | "replacement" | "service") {
+            return Ok(None);
+        }
+
+        let job = self
+            .problem
+            .plan
+            .jobs
+            .iter()
+            .find(|job| job.id == transition.job_id)
+            .ok_or_else(|| format!("unknown job '{}'", transition.job_id))?;
+
+        tasks(job)
+            .and_then(|tasks| {
+                tasks.iter().find(|task| {
+                    task.places.iter().any(|place| {
+                        self.is_same_location(&place.location, transition)
+                            && (transition.job_tag.is_none() || place.tag == transition.job_tag)
+                    })
+                })
+            })
+            .map(Some)
+            .ok_or_else(|| format!("job '{}' has no {} task at given location", job.id, transition.activity_type))
+    }
+
+    fn get_profile_matrices(&self) -> impl Iterator<Item = &'a Matrix> + 'a {
+        let profile_name = &self.vehicle.profile.matrix;
+        let profile_idx = self.problem.fleet.profiles.iter().position(|profile| profile.name == *profile_name);
+
+        self.matrices.iter().enumerate().filter_map(move |(idx, matrix)| match matrix.profile.as_ref() {
+            Some(name) if name == profile_name => Some(matrix),
+            None if Some(idx) == profile_idx => Some(matrix),
+            _ => None,
+        })
+    }
+
+    fn get_location_index(&self, location: &Location) -> Result<usize, String> {
+        self.coord_index.get_by_loc(location).ok_or_else(|| format!("unknown location: {:?}", location))
+    }
+
+    fn is_same_location(&self, location: &Location, transition: &StateTransition) -> bool {
+        self.coord_index
+            .get_by_loc(location)
+            .is_some_and(|idx| Some(idx) == self.coord_index.get_by_loc(&transition.location))
+    }
+}
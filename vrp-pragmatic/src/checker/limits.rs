@@ -4,15 +4,24 @@ mod limits_test;
 
 use super::*;
 use crate::utils::combine_error_results;
+use std::iter::once;
 
 /// NOTE to ensure distance/duration correctness, routing check should be performed first.
 pub fn check_limits(context: &CheckerContext) -> Result<(), Vec<String>> {
-    combine_error_results(&[check_shift_limits(context), check_shift_time(context)])
+    combine_error_results(&[
+        check_shift_limits(context),
+        check_shift_time(context),
+        check_driver_shift_time(context),
+        check_consecutive_driving(context),
+        check_driving_time(context),
+        check_energy(context),
+    ])
 }
 
 /// Check that shift limits are not violated:
 /// * max shift time
 /// * max distance
+/// * max cost
 fn check_shift_limits(context: &CheckerContext) -> Result<(), String> {
     context.solution.tours.iter().try_for_each::<_, Result<_, String>>(|tour| {
         let vehicle = context.get_vehicle(&tour.vehicle_id)?;
@@ -36,18 +45,42 @@ fn check_shift_limits(context: &CheckerContext) -> Result<(), String> {
                 }
             }
 
-            if let Some(tour_size_limit) = limits.tour_size {
+            if let Some(max_cost) = limits.max_cost {
+                let costs = &vehicle.costs;
+                let waiting = tour.statistic.times.waiting as f64;
+                let tour_cost = costs.fixed.unwrap_or(0.)
+                    + costs.distance * tour.statistic.distance as f64
+                    + costs.time * (tour.statistic.duration as f64 - waiting)
+                    + costs.waiting.unwrap_or(costs.time) * waiting;
+
+                if tour_cost > max_cost {
+                    return Err(format!(
+                        "max cost limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                        max_cost, tour_cost, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+            }
+
+            if limits.tour_size.is_some() || limits.min_tour_size.is_some() {
                 let shift = context.get_vehicle_shift(tour)?;
 
-                let extra_activities = if shift.end.is_some() { 2 } else { 1 };
+                let has_arrival = matches!(&shift.end, Some(end) if !end.is_open.unwrap_or(false));
+                let extra_activities = if has_arrival { 2 } else { 1 };
                 let tour_activities = tour.stops.iter().flat_map(|stop| stop.activities()).count();
                 let tour_activities = if tour_activities > extra_activities { tour_activities - extra_activities } else { 0 };
 
-                if tour_activities > tour_size_limit {
+                if let Some(tour_size_limit) = limits.tour_size.filter(|&limit| tour_activities > limit) {
                     return Err(format!(
                         "tour size limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
                         tour_size_limit, tour_activities, tour.vehicle_id, tour.shift_index
-                    ))
+                    ));
+                }
+
+                if let Some(min_tour_size) = limits.min_tour_size.filter(|&limit| tour_activities < limit) {
+                    return Err(format!(
+                        "min tour size limit violation, expected: not less than {}, got: {}, vehicle id '{}', shift index: {}",
+                        min_tour_size, tour_activities, tour.vehicle_id, tour.shift_index
+                    ));
                 }
             }
         }
@@ -70,7 +103,7 @@ fn check_shift_time(context: &CheckerContext) -> Result<(), String> {
             .iter()
             .map(|shift| {
                 let start = parse_time(&shift.start.earliest);
-                let end = shift.end.as_ref().map(|end| parse_time(&end.latest)).unwrap_or(f64::MAX);
+                let end = get_shift_end_time(shift).unwrap_or(f64::MAX);
 
                 (start, end)
             })
@@ -86,3 +119,223 @@ fn check_shift_time(context: &CheckerContext) -> Result<(), String> {
         }
     })
 }
+
+/// Checks that working time of each driver between relays does not exceed driver shift time.
+fn check_driver_shift_time(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each::<_, Result<_, String>>(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let shift_time = match vehicle.limits.as_ref().and_then(|limits| limits.driver_shift_time) {
+            Some(shift_time) => shift_time,
+            _ => return Ok(()),
+        };
+
+        let (first, last) = tour.stops.first().zip(tour.stops.last()).ok_or("empty tour")?;
+        let start = parse_time(&first.schedule().departure);
+        let end = parse_time(&last.schedule().departure);
+
+        let relays = tour
+            .stops
+            .iter()
+            .flat_map(|stop| {
+                stop.activities()
+                    .iter()
+                    .filter(|activity| activity.activity_type == "relay")
+                    .map(move |activity| context.get_activity_time(stop, activity))
+            })
+            .collect::<Vec<_>>();
+
+        // NOTE driver shift starts at tour start or relay departure and ends at next relay arrival or tour end
+        once(start)
+            .chain(relays.iter().map(|time| time.end))
+            .zip(relays.iter().map(|time| time.start).chain(once(end)))
+            .try_for_each(|(start, end)| {
+                // NOTE times in solution are rounded, so one second tolerance is used
+                if end - start > shift_time + 1. {
+                    Err(format!(
+                        "driver shift time limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                        shift_time,
+                        end - start,
+                        tour.vehicle_id,
+                        tour.shift_index
+                    ))
+                } else {
+                    Ok(())
+                }
+            })
+    })
+}
+
+/// Checks that consecutive driving between two stops does not exceed vehicle limits.
+fn check_consecutive_driving(context: &CheckerContext) -> Result<(), String> {
+    // NOTE stop location is approximated in case of clustering
+    if context.clustering.is_some() {
+        return Ok(());
+    }
+
+    context.solution.tours.iter().try_for_each::<_, Result<_, String>>(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let driving = match vehicle.limits.as_ref().and_then(|limits| limits.consecutive_driving.as_ref()) {
+            Some(driving) => driving,
+            _ => return Ok(()),
+        };
+
+        tour.stops.iter().filter_map(|stop| stop.as_point()).collect::<Vec<_>>().windows(2).try_for_each(|stops| {
+            let (from, to) = match stops {
+                [from, to] => (from, to),
+                _ => unreachable!(),
+            };
+
+            let departure = parse_time(&from.time.departure);
+            let arrival = parse_time(&to.time.arrival);
+
+            // NOTE breaks without location are taken during travel, so they are excluded from driving
+            let transit_time = tour
+                .stops
+                .iter()
+                .filter(|stop| matches!(stop, Stop::Transit(_)))
+                .map(|stop| (parse_time(&stop.schedule().arrival), parse_time(&stop.schedule().departure)))
+                .filter(|&(start, end)| start >= departure && end <= arrival)
+                .map(|(start, end)| end - start)
+                .sum::<f64>();
+
+            let distance = (to.distance - from.distance) as f64;
+            let duration = arrival - departure - transit_time;
+
+            if driving.max_distance.iter().any(|&max_distance| distance > max_distance)
+                || driving.max_duration.iter().any(|&max_duration| duration > max_duration + 1.)
+            {
+                Err(format!(
+                    "consecutive driving limit violation, distance: {}, duration: {}, vehicle id '{}', shift index: {}",
+                    distance, duration, tour.vehicle_id, tour.shift_index
+                ))
+            } else {
+                Ok(())
+            }
+        })
+    })
+}
+
+/// Checks that driving and working time between breaks and rests do not exceed vehicle limits.
+fn check_driving_time(context: &CheckerContext) -> Result<(), String> {
+    // NOTE stop location is approximated in case of clustering
+    if context.clustering.is_some() {
+        return Ok(());
+    }
+
+    context.solution.tours.iter().try_for_each::<_, Result<_, String>>(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let limit = match vehicle.limits.as_ref().and_then(|limits| limits.driving_time.as_ref()) {
+            Some(limit) => limit,
+            _ => return Ok(()),
+        };
+        let max_working_time = limit.max_working_time.unwrap_or(f64::MAX);
+        let rest_duration = limit.rest_duration.unwrap_or(f64::MAX);
+
+        let start = tour.stops.first().map(|stop| parse_time(&stop.schedule().departure)).unwrap_or_default();
+
+        // NOTE times in solution are rounded, so tolerance is accumulated on each leg
+        tour.stops
+            .iter()
+            .skip(1)
+            .try_fold((start, 0., 0., 0.), |(departure, driving, working, tolerance), stop| {
+                let arrival = parse_time(&stop.schedule().arrival);
+                let (driving, working, tolerance) =
+                    (driving + arrival - departure, working + arrival - departure, tolerance + 1.);
+
+                if arrival > departure
+                    && (driving > limit.max_driving_time + tolerance || working > max_working_time + tolerance)
+                {
+                    return Err(format!(
+                        "driving time limit violation, driving: {}, working: {}, vehicle id '{}', shift index: {}",
+                        driving, working, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+
+                // NOTE any long enough stop counts as a break or a rest
+                let departure = parse_time(&stop.schedule().departure);
+                let duration = departure - arrival;
+
+                Ok(if duration >= rest_duration {
+                    (departure, 0., 0., 0.)
+                } else if duration >= limit.break_duration {
+                    (departure, 0., working + duration, tolerance)
+                } else {
+                    (departure, driving, working + duration, tolerance)
+                })
+            })
+            .map(|_| ())
+    })
+}
+
+/// Checks that electric vehicle's energy level does not drop below its reserve.
+fn check_energy(context: &CheckerContext) -> Result<(), String> {
+    // NOTE stop location is approximated in case of clustering
+    if context.clustering.is_some() {
+        return Ok(());
+    }
+
+    context.solution.tours.iter().try_for_each::<_, Result<_, String>>(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let energy = match vehicle.energy.as_ref() {
+            Some(energy) => energy,
+            _ => return Ok(()),
+        };
+
+        let load_consumption = energy.load_consumption.unwrap_or(0.);
+        let reserve = energy.reserve.unwrap_or(0.);
+
+        // NOTE distances in solution are rounded, so tolerance is accumulated on each leg
+        tour.stops
+            .iter()
+            .filter_map(|stop| stop.as_point().map(|point| (stop, point)))
+            .collect::<Vec<_>>()
+            .windows(2)
+            .try_fold((energy.capacity, 0.), |(level, tolerance), stops| {
+                let ((_, from), (to_stop, to)) = match stops {
+                    [from, to] => (from, to),
+                    _ => unreachable!(),
+                };
+
+                let load = from.load.first().cloned().unwrap_or_default().max(0) as f64;
+                let consumption = energy.consumption + load_consumption * load;
+                let (level, tolerance) =
+                    (level - (to.distance - from.distance) as f64 * consumption, tolerance + consumption);
+
+                if level + tolerance < reserve {
+                    return Err(format!(
+                        "energy limit violation, expected: not less than {}, got: {}, vehicle id '{}', shift index: {}",
+                        reserve, level, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+
+                let recharge = to
+                    .activities
+                    .iter()
+                    .filter(|activity| activity.activity_type == "recharge")
+                    .map(|activity| context.get_activity_type(tour, to_stop, activity).map(|a_type| (activity, a_type)))
+                    .next()
+                    .transpose()?;
+
+                match recharge {
+                    Some((activity, ActivityType::Recharge(recharge))) => {
+                        let time = context.get_activity_time(to_stop, activity);
+                        let expected = ((energy.capacity - level - tolerance) / recharge.rate).ceil();
+
+                        if time.duration() < expected {
+                            return Err(format!(
+                                "recharge duration violation, expected: not less than {}, got: {}, vehicle id '{}', shift index: {}",
+                                expected,
+                                time.duration(),
+                                tour.vehicle_id,
+                                tour.shift_index
+                            ));
+                        }
+
+                        Ok((energy.capacity, 0.))
+                    }
+                    _ => Ok((level, tolerance)),
+                }
+            })
+            .map(|_| ())
+    })
+}
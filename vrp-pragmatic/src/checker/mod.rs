@@ -13,7 +13,7 @@ use hashbrown::{HashMap, HashSet};
 use std::sync::Arc;
 use vrp_core::construction::clustering::vicinity::ClusterConfig;
 use vrp_core::construction::clustering::vicinity::VisitPolicy;
-use vrp_core::models::common::{Duration, Profile, TimeWindow};
+use vrp_core::models::common::{Duration, Profile, TimeWindow, Timestamp};
 use vrp_core::models::solution::{Commute as DomainCommute, CommuteInfo as DomainCommuteInfo};
 use vrp_core::models::Problem as CoreProblem;
 use vrp_core::solver::processing::VicinityDimension;
@@ -37,10 +37,16 @@ pub struct CheckerContext {
 /// Represents all possible activity types.
 enum ActivityType {
     Terminal,
-    Job(Job),
+    Job(Box<Job>),
     Depot(VehicleDispatch),
     Break(VehicleBreak),
     Reload(VehicleReload),
+    Recharge(VehicleRecharge),
+    Trailer(VehicleTrailerParking),
+    Unloading,
+    Relay,
+    Rest,
+    Maintenance,
 }
 
 impl CheckerContext {
@@ -51,6 +57,8 @@ impl CheckerContext {
         matrices: Option<Vec<Matrix>>,
         solution: Solution,
     ) -> Result<Self, Vec<String>> {
+        let problem = normalize_dimensions(normalize_areas(normalize_times(problem)));
+        let matrices = matrices.map(|matrices| apply_metric_policies(&problem, infer_matrices(&problem, matrices)));
         let job_map = problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect();
         let clustering = core_problem.extras.get_cluster_config().cloned();
         let coord_index = CoordIndex::new(&problem);
@@ -76,6 +84,7 @@ impl CheckerContext {
             .chain(check_assignment(self).err().into_iter())
             .chain(check_routing(self).err().into_iter())
             .chain(check_limits(self).err().into_iter())
+            .chain(check_statistic(self).err())
             .flatten()
             .fold((HashSet::new(), Vec::default()), |(mut used, mut errors), error| {
                 if !used.contains(&error) {
@@ -149,10 +158,8 @@ impl CheckerContext {
             .shifts
             .iter()
             .find(|shift| {
-                let shift_time = TimeWindow::new(
-                    parse_time(&shift.start.earliest),
-                    shift.end.as_ref().map_or_else(|| f64::MAX, |place| parse_time(&place.latest)),
-                );
+                let shift_time =
+                    TimeWindow::new(parse_time(&shift.start.earliest), get_shift_end_time(shift).unwrap_or(f64::MAX));
                 shift_time.intersects(&tour_time)
             })
             .cloned()
@@ -175,7 +182,7 @@ impl CheckerContext {
             "pickup" | "delivery" | "service" | "replacement" => {
                 self.job_map.get(activity.job_id.as_str()).map_or_else(
                     || Err(format!("cannot find job with id '{}'", activity.job_id)),
-                    |job| Ok(ActivityType::Job(job.clone())),
+                    |job| Ok(ActivityType::Job(Box::new(job.clone()))),
                 )
             }
             "break" => shift
@@ -221,11 +228,54 @@ impl CheckerContext {
                 // TODO match reload's time windows
                 .and_then(|reload| {
                     reload.iter().find(|r| {
-                        location.as_ref().map_or(false, |location| r.location == *location) && r.tag == activity.job_tag
+                        let is_reload_location = |location: &Location| {
+                            r.location == *location || r.alternatives.iter().flatten().any(|alt| alt == location)
+                        };
+                        location.as_ref().is_some_and(is_reload_location) && r.tag == activity.job_tag
                     })
                 })
                 .map(|r| ActivityType::Reload(r.clone()))
                 .ok_or_else(|| format!("cannot find reload for tour '{}'", tour.vehicle_id)),
+            "recharge" => shift
+                .recharges
+                .as_ref()
+                .and_then(|recharges| {
+                    recharges.iter().find(|r| {
+                        location.as_ref().is_some_and(|location| r.location == *location) && r.tag == activity.job_tag
+                    })
+                })
+                .map(|r| ActivityType::Recharge(r.clone()))
+                .ok_or_else(|| format!("cannot find recharge for tour '{}'", tour.vehicle_id)),
+            "detach" | "attach" => shift
+                .trailer_parkings
+                .as_ref()
+                .and_then(|parkings| {
+                    parkings.iter().find(|p| {
+                        location.as_ref().is_some_and(|location| p.location == *location) && p.tag == activity.job_tag
+                    })
+                })
+                .map(|p| ActivityType::Trailer(p.clone()))
+                .ok_or_else(|| format!("cannot find trailer parking for tour '{}'", tour.vehicle_id)),
+            "unloading" => shift
+                .unloading
+                .as_ref()
+                .filter(|unloading| {
+                    let end_location = shift.end.as_ref().map(|end| &end.location);
+                    location.as_ref().is_some_and(|location| end_location == Some(location))
+                        && unloading.tag == activity.job_tag
+                })
+                .map(|_| ActivityType::Unloading)
+                .ok_or_else(|| format!("cannot find unloading for tour '{}'", tour.vehicle_id)),
+            "relay" => shift
+                .relays
+                .as_ref()
+                .filter(|relays| {
+                    relays.iter().any(|r| {
+                        location.as_ref().is_some_and(|location| r.location == *location) && r.tag == activity.job_tag
+                    })
+                })
+                .map(|_| ActivityType::Relay)
+                .ok_or_else(|| format!("cannot find relay for tour '{}'", tour.vehicle_id)),
             "dispatch" => shift
                 .dispatch
                 .as_ref()
@@ -234,6 +284,19 @@ impl CheckerContext {
                 })
                 .map(|d| ActivityType::Depot(d.clone()))
                 .ok_or_else(|| format!("cannot find dispatch for tour '{}'", tour.vehicle_id)),
+            "rest" => self
+                .get_vehicle(&tour.vehicle_id)?
+                .limits
+                .as_ref()
+                .and_then(|limits| limits.driving_time.as_ref())
+                .map(|_| ActivityType::Rest)
+                .ok_or_else(|| format!("cannot find driving time limit for tour '{}'", tour.vehicle_id)),
+            "maintenance" => shift
+                .maintenance
+                .as_ref()
+                .filter(|maintenance| maintenance.iter().any(|tw| parse_time_window(tw).intersects(&time)))
+                .map(|_| ActivityType::Maintenance)
+                .ok_or_else(|| format!("cannot find maintenance for tour '{}'", tour.vehicle_id)),
             _ => Err(format!("unknown activity type: '{}'", activity.activity_type)),
         }
     }
@@ -266,6 +329,7 @@ impl CheckerContext {
         match (&self.clustering, &profile, get_activity_commute_by_idx(activity_idx)) {
             (Some(config), Some(profile), Some(commute)) => {
                 let stop_location = self.get_location_index(&stop.location).ok();
+                let departure = parse_time(&stop.time.arrival);
                 // NOTE we don't check whether zero time commute is correct here
                 match (commute.is_zero_distance(), activity_idx) {
                     (true, _) => Ok(Some(commute)),
@@ -282,7 +346,7 @@ impl CheckerContext {
                         match (curr_location, prev_location) {
                             (Some(curr_location), Some(prev_location)) => {
                                 let (f_distance, f_duration) =
-                                    self.get_matrix_data(profile, prev_location, curr_location)?;
+                                    self.get_matrix_data(profile, departure, prev_location, curr_location)?;
 
                                 let has_next_commute = get_activity_location_by_idx(idx + 1)
                                     .zip(get_activity_commute_by_idx(idx + 1))
@@ -291,7 +355,7 @@ impl CheckerContext {
                                     (VisitPolicy::Return, _) | (VisitPolicy::ClosedContinuation, false) => {
                                         let stop_location = stop_location.ok_or("no location for clustered stop")?;
                                         let (b_distance, b_duration) =
-                                            self.get_matrix_data(profile, curr_location, stop_location)?;
+                                            self.get_matrix_data(profile, departure, curr_location, stop_location)?;
 
                                         (stop_location, b_distance, b_duration)
                                     }
@@ -366,16 +430,45 @@ impl CheckerContext {
             .ok_or_else(|| format!("cannot find coordinate in coord index: {:?}", location))
     }
 
-    fn get_matrix_data(&self, profile: &Profile, from_idx: usize, to_idx: usize) -> Result<(i64, i64), String> {
+    fn get_matrix_data(
+        &self,
+        profile: &Profile,
+        departure: Timestamp,
+        from_idx: usize,
+        to_idx: usize,
+    ) -> Result<(i64, i64), String> {
         let matrices = get_matrices(&self.matrices)?;
-        let matrix =
-            matrices.get(profile.index).ok_or_else(|| format!("cannot find matrix with index {}", profile.index))?;
-
         let matrix_size = get_matrix_size(matrices.as_slice());
         let matrix_idx = from_idx * matrix_size + to_idx;
 
-        let distance = get_matrix_value(matrix_idx, &matrix.distances)?;
-        let duration = get_matrix_value(matrix_idx, &matrix.travel_times)?;
+        let (distance, duration) = if matrices.iter().any(|matrix| matrix.timestamp.is_some()) {
+            let profile_name = self
+                .problem
+                .fleet
+                .profiles
+                .get(profile.index)
+                .map(|profile| &profile.name)
+                .ok_or_else(|| format!("cannot find profile with index {}", profile.index))?;
+
+            let mut profile_matrices = matrices
+                .iter()
+                .filter(|matrix| matrix.profile.as_ref() == Some(profile_name))
+                .map(|matrix| (matrix.timestamp.as_ref().map_or(0., |timestamp| parse_time(timestamp)), matrix))
+                .collect::<Vec<_>>();
+            profile_matrices.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+            let distance = get_time_aware_matrix_value(matrix_idx, departure, &profile_matrices, |m| &m.distances)?;
+            let duration = get_time_aware_matrix_value(matrix_idx, departure, &profile_matrices, |m| &m.travel_times)?;
+
+            (distance, duration)
+        } else {
+            let matrix = matrices
+                .get(profile.index)
+                .ok_or_else(|| format!("cannot find matrix with index {}", profile.index))?;
+
+            (get_matrix_value(matrix_idx, &matrix.distances)?, get_matrix_value(matrix_idx, &matrix.travel_times)?)
+        };
+
         let duration = (duration as f64 * profile.scale) as i64;
 
         Ok((distance, duration))
@@ -406,6 +499,11 @@ fn parse_time_window(tw: &[String]) -> TimeWindow {
     TimeWindow::new(parse_time(tw.first().unwrap()), parse_time(tw.last().unwrap()))
 }
 
+/// Returns the latest time when vehicle can finish the shift including allowed overtime.
+fn get_shift_end_time(shift: &VehicleShift) -> Option<f64> {
+    shift.end.as_ref().map(|end| parse_time(&end.latest) + shift.overtime.as_ref().map_or(0., |ot| ot.max_duration))
+}
+
 fn get_time_window(stop: &Stop, activity: &Activity) -> TimeWindow {
     let schedule = stop.schedule();
     let (start, end) = activity
@@ -427,23 +525,50 @@ fn get_matrix_value(idx: usize, matrix_values: &[i64]) -> Result<i64, String> {
         .ok_or_else(|| format!("attempt to get value out of bounds: {} vs {}", idx, matrix_values.len()))
 }
 
-fn get_matrices(matrices: &Option<Vec<Matrix>>) -> Result<&Vec<Matrix>, String> {
-    let matrices = matrices.as_ref().unwrap();
+/// Gets matrix value for given departure time: values of two adjacent time slices are interpolated linearly.
+fn get_time_aware_matrix_value(
+    idx: usize,
+    departure: Timestamp,
+    matrices: &[(Timestamp, &Matrix)],
+    values_fn: fn(&Matrix) -> &Vec<i64>,
+) -> Result<i64, String> {
+    let slice_idx = matrices.iter().filter(|(timestamp, _)| *timestamp <= departure).count();
+
+    match (slice_idx.checked_sub(1).and_then(|idx| matrices.get(idx)), matrices.get(slice_idx)) {
+        (Some((_, matrix)), None) | (None, Some((_, matrix))) => get_matrix_value(idx, values_fn(matrix)),
+        (Some((left_time, left)), Some((right_time, right))) => {
+            let left_value = get_matrix_value(idx, values_fn(left))?;
+            let right_value = get_matrix_value(idx, values_fn(right))?;
+
+            if left_value < 0 || right_value < 0 {
+                return Ok(left_value.min(right_value));
+            }
 
-    if matrices.iter().any(|matrix| matrix.timestamp.is_some()) {
-        return Err("not implemented: time aware routing check".to_string());
+            let ratio = (departure - left_time) / (right_time - left_time);
+
+            Ok((left_value as f64 + (right_value - left_value) as f64 * ratio).round() as i64)
+        }
+        (None, None) => Err("no matrices for profile".to_string()),
     }
+}
 
-    Ok(matrices)
+fn get_matrices(matrices: &Option<Vec<Matrix>>) -> Result<&Vec<Matrix>, String> {
+    Ok(matrices.as_ref().unwrap())
 }
 
 fn get_profile_index(problem: &Problem, matrices: &[Matrix]) -> Result<HashMap<String, usize>, String> {
     let profiles = problem.fleet.profiles.len();
-    if profiles != matrices.len() {
+    // NOTE time aware routing has multiple matrices per profile
+    let matrix_profiles = if matrices.iter().any(|matrix| matrix.timestamp.is_some()) {
+        matrices.iter().filter_map(|matrix| matrix.profile.as_ref()).collect::<HashSet<_>>().len()
+    } else {
+        matrices.len()
+    };
+
+    if profiles != matrix_profiles {
         return Err(format!(
             "precondition failed: amount of matrices supplied ({}) does not match profile specified ({})",
-            matrices.len(),
-            profiles,
+            matrix_profiles, profiles,
         ));
     }
 
@@ -462,6 +587,9 @@ use crate::checker::assignment::check_assignment;
 mod capacity;
 use crate::checker::capacity::check_vehicle_load;
 
+mod certificate;
+pub use crate::checker::certificate::verify_certificate;
+
 mod limits;
 use crate::checker::limits::check_limits;
 
@@ -473,3 +601,6 @@ use crate::checker::relations::check_relations;
 
 mod routing;
 use crate::checker::routing::check_routing;
+
+mod statistic;
+use crate::checker::statistic::check_statistic;
@@ -12,7 +12,9 @@ pub fn check_relations(context: &CheckerContext) -> Result<(), Vec<String>> {
 }
 
 fn check_relations_assignment(context: &CheckerContext) -> Result<(), String> {
-    let reserved_ids = vec!["departure", "arrival", "break", "dispatch", "reload"].into_iter().collect::<HashSet<_>>();
+    let reserved_ids = vec!["departure", "arrival", "break", "dispatch", "reload", "recharge", "relay"]
+        .into_iter()
+        .collect::<HashSet<_>>();
 
     (0_usize..)
         .zip(context.problem.plan.relations.as_ref().map_or(vec![].iter(), |relations| relations.iter()))
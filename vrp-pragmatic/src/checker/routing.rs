@@ -25,7 +25,7 @@ fn check_routing_rules(context: &CheckerContext) -> Result<(), String> {
         let get_matrix_data = |from: &PointStop, to: &PointStop| -> Result<(i64, i64), String> {
             let from_idx = context.get_location_index(&from.location)?;
             let to_idx = context.get_location_index(&to.location)?;
-            context.get_matrix_data(&profile, from_idx, to_idx)
+            context.get_matrix_data(&profile, parse_time(&from.time.departure), from_idx, to_idx)
         };
 
         //let stops = tour.stops.iter().filter_map(|stop| stop.as_point()).collect::<Vec<_>>();
@@ -43,17 +43,17 @@ fn check_routing_rules(context: &CheckerContext) -> Result<(), String> {
                         (distance, duration, to.distance)
                     }
                     (_, Stop::Transit(transit)) => {
+                        // NOTE transit stop is taken somewhere during travel, so only its duration is accumulated
                         let duration = parse_time(&transit.time.departure) - parse_time(&transit.time.arrival);
-                        (0_i64, duration as i64, total_distance)
+                        return Ok((arrival_time + duration as i64, total_distance));
                     }
                     (Stop::Transit(_), Stop::Point(to)) => {
                         assert!(leg_idx > 0);
-                        let from = tour
-                            .stops
-                            .get(leg_idx - 1)
-                            .unwrap()
-                            .as_point()
-                            .expect("two consistent transit stops are not supported");
+                        let from = tour.stops[..leg_idx]
+                            .iter()
+                            .rev()
+                            .find_map(|stop| stop.as_point())
+                            .ok_or_else(|| "transit stop without preceding point stop".to_string())?;
                         let (distance, duration) = get_matrix_data(from, to)?;
                         (distance, duration, to.distance)
                     }
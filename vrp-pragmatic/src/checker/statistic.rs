@@ -0,0 +1,249 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/statistic_test.rs"]
+mod statistic_test;
+
+use super::capacity::get_stop_loads;
+use super::*;
+use crate::format::solution::activity_matcher::*;
+use crate::format::{get_coord_index, get_job_index, JobIndex};
+use crate::utils::combine_error_results;
+use vrp_core::models::common::MultiDimLoad;
+
+/// Checks that reported statistic matches statistic recomputed from the solution and routing matrices.
+/// The following rules are checked:
+/// * tour duration equals to sum of its timing components
+/// * travel time and distance of each leg equal to values taken from routing matrices
+/// * tour driving time and distance equal to sum of travel times and distances of its legs
+/// * tour waiting time equals to waiting recomputed from activity arrival, time window and departure
+/// * load at each stop equals to load recomputed from demand of served jobs
+/// * solution timing equals to sum of tours timing
+///
+/// Mismatches are reported for each tour separately which helps to detect integration issues, e.g. when
+/// solution was built using stale routing matrices.
+pub fn check_statistic(context: &CheckerContext) -> Result<(), Vec<String>> {
+    let job_index = get_job_index(&context.core_problem);
+    let coord_index = get_coord_index(&context.core_problem);
+
+    let tour_results = context
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            vec![
+                check_tour_timing(tour),
+                check_tour_travel(context, tour),
+                check_tour_waiting(context, tour, job_index, coord_index),
+                check_tour_load(context, tour),
+            ]
+        })
+        .chain(std::iter::once(check_solution_timing(&context.solution)))
+        .collect::<Vec<_>>();
+
+    combine_error_results(tour_results.as_slice())
+}
+
+fn check_tour_timing(tour: &Tour) -> Result<(), String> {
+    let times = &tour.statistic.times;
+    let total = times.driving + times.serving + times.waiting + times.break_time + times.commuting + times.parking;
+
+    if (total - tour.statistic.duration).abs() > get_tolerance(tour) {
+        Err(format!(
+            "timing mismatch for tour statistic: {}, expected duration: '{}', got sum of times: '{}'",
+            tour.vehicle_id, tour.statistic.duration, total
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn check_tour_travel(context: &CheckerContext, tour: &Tour) -> Result<(), String> {
+    if context.matrices.iter().all(|matrices| matrices.is_empty()) {
+        return Ok(());
+    }
+
+    let profile = context.get_vehicle_profile(&tour.vehicle_id).map_err(|err| get_tour_error(tour, err))?;
+    // NOTE some formats do not report distance, so it is checked only when defined
+    let has_distance = tour.stops.iter().filter_map(|stop| stop.as_point()).any(|stop| stop.distance != 0);
+
+    let (expected_distance, expected_driving) = get_tour_legs(tour)
+        .into_iter()
+        .try_fold::<_, _, Result<_, String>>((0_i64, 0_i64), |(total_distance, total_driving), leg| {
+            let TourLeg { stop_idx, from, to, transit } = leg;
+            let from_idx = context.get_location_index(&from.location)?;
+            let to_idx = context.get_location_index(&to.location)?;
+            let (distance, duration) =
+                context.get_matrix_data(&profile, parse_time(&from.time.departure), from_idx, to_idx)?;
+
+            let reported_duration =
+                (parse_time(&to.time.arrival) - parse_time(&from.time.departure) - transit).round() as i64;
+            if (duration - reported_duration).abs() > 1 {
+                return Err(format!(
+                    "travel time mismatch for tour statistic: {}, stop: {}, expected: '{}', got: '{}'",
+                    tour.vehicle_id, stop_idx, duration, reported_duration
+                ));
+            }
+
+            let total_distance = total_distance + distance;
+            if has_distance && (total_distance - to.distance).abs() > 1 {
+                return Err(format!(
+                    "distance mismatch for tour statistic: {}, stop: {}, expected: '{}', got: '{}'",
+                    tour.vehicle_id, stop_idx, total_distance, to.distance
+                ));
+            }
+
+            Ok((total_distance, total_driving + duration))
+        })
+        .map_err(|err| get_tour_error(tour, err))?;
+
+    if has_distance && (expected_distance - tour.statistic.distance).abs() > 1 {
+        return Err(format!(
+            "distance mismatch for tour statistic: {}, expected: '{}', got: '{}'",
+            tour.vehicle_id, expected_distance, tour.statistic.distance
+        ));
+    }
+
+    if (expected_driving - tour.statistic.times.driving).abs() > get_tolerance(tour) {
+        Err(format!(
+            "driving time mismatch for tour statistic: {}, expected: '{}', got: '{}'",
+            tour.vehicle_id, expected_driving, tour.statistic.times.driving
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn check_tour_waiting(
+    context: &CheckerContext,
+    tour: &Tour,
+    job_index: &JobIndex,
+    coord_index: &CoordIndex,
+) -> Result<(), String> {
+    let route_start = tour.stops.first().map_or(0., |stop| parse_time(&stop.schedule().departure));
+    let shift_end = context
+        .get_vehicle_shift(tour)
+        .map_err(|err| get_tour_error(tour, err))?
+        .end
+        .and_then(|end| end.earliest)
+        .map(|earliest| parse_time(&earliest));
+
+    let expected_waiting = tour
+        .stops
+        .iter()
+        .filter_map(|stop| stop.as_point())
+        .flat_map(|stop| stop.activities.iter().enumerate().map(move |(idx, activity)| (stop, idx, activity)))
+        .try_fold(0., |acc, (stop, activity_idx, activity)| {
+            let time = get_time_window(&Stop::Point(stop.clone()), activity);
+            let (time_start, buffer) = match try_match_point_job(tour, stop, activity, job_index, coord_index) {
+                Ok(Some(JobInfo(_, single, _, place, _))) => {
+                    let buffer = get_place_buffer(
+                        single.as_ref(),
+                        (place.location, place.duration, (place.time.clone(), route_start)),
+                    );
+                    (Some(place.time.start), buffer.unwrap_or(0.))
+                }
+                Ok(None) if activity.activity_type == "arrival" => (shift_end, 0.),
+                Ok(None) => (None, 0.),
+                // NOTE required break and maintenance are not jobs and have no waiting time
+                Err(_) if matches!(activity.activity_type.as_str(), "break" | "maintenance") => (None, 0.),
+                Err(err) => return Err(get_tour_error(tour, err)),
+            };
+
+            // NOTE clustered activity starts after parking and commute which happen after waiting
+            let parking = stop.parking.as_ref().filter(|_| activity_idx == 0).map_or(0., get_interval_duration);
+            let commute = activity
+                .commute
+                .as_ref()
+                .and_then(|commute| commute.forward.as_ref())
+                .map_or(0., |info| get_interval_duration(&info.time));
+
+            // NOTE vehicle waits for time window start, but departs not earlier than reported
+            let waiting =
+                time_start.map_or(0., |start| (start + parking + commute - time.start).max(0.).min(time.duration()));
+
+            Ok(acc + waiting + buffer)
+        })?;
+
+    let expected_waiting = expected_waiting.round() as i64;
+    if (expected_waiting - tour.statistic.times.waiting).abs() > get_tolerance(tour) {
+        Err(format!(
+            "waiting time mismatch for tour statistic: {}, expected: '{}', got: '{}'",
+            tour.vehicle_id, expected_waiting, tour.statistic.times.waiting
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn check_tour_load(context: &CheckerContext, tour: &Tour) -> Result<(), String> {
+    let loads = get_stop_loads(context, tour).map_err(|err| get_tour_error(tour, err))?;
+
+    tour.stops.iter().zip(loads).enumerate().try_for_each(|(idx, (stop, expected))| match expected {
+        Some(expected) if expected != MultiDimLoad::new(stop.load().clone()) => Err(format!(
+            "load mismatch for tour statistic: {}, stop: {}, expected: '{:?}', got: '{:?}'",
+            tour.vehicle_id,
+            idx,
+            expected.as_vec(),
+            stop.load()
+        )),
+        _ => Ok(()),
+    })
+}
+
+fn check_solution_timing(solution: &Solution) -> Result<(), String> {
+    let statistic = solution.tours.iter().fold(Statistic::default(), |acc, tour| acc + tour.statistic.clone());
+
+    if statistic.times != solution.statistic.times {
+        Err(format!(
+            "solution timing mismatch, expected: '{:?}', got: '{:?}'",
+            statistic.times, solution.statistic.times
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn get_tour_error(tour: &Tour, err: String) -> String {
+    // NOTE keep error as is when it refers to the tour already, so it can be deduplicated with other checks
+    if err.contains(tour.vehicle_id.as_str()) {
+        err
+    } else {
+        format!("cannot check tour statistic: {}, error: '{}'", tour.vehicle_id, err)
+    }
+}
+
+/// A travel between two consecutive point stops of the tour.
+struct TourLeg<'a> {
+    stop_idx: usize,
+    from: &'a PointStop,
+    to: &'a PointStop,
+    /// Duration of transit stops, e.g. breaks, taken during the travel.
+    transit: f64,
+}
+
+fn get_tour_legs(tour: &Tour) -> Vec<TourLeg<'_>> {
+    let (_, _, legs) = tour.stops.iter().enumerate().fold(
+        (None, 0., Vec::new()),
+        |(from, transit, mut legs): (Option<&PointStop>, f64, Vec<TourLeg>), (stop_idx, stop)| match stop {
+            Stop::Point(to) => {
+                if let Some(from) = from {
+                    legs.push(TourLeg { stop_idx, from, to, transit });
+                }
+                (Some(to), 0., legs)
+            }
+            Stop::Transit(stop) => {
+                (from, transit + parse_time(&stop.time.departure) - parse_time(&stop.time.arrival), legs)
+            }
+        },
+    );
+
+    legs
+}
+
+fn get_interval_duration(interval: &Interval) -> f64 {
+    parse_time(&interval.end) - parse_time(&interval.start)
+}
+
+/// Returns tolerance which is caused by rounding of activity times: one second per activity.
+fn get_tolerance(tour: &Tour) -> i64 {
+    tour.stops.iter().map(|stop| stop.activities().len()).sum::<usize>().max(1) as i64
+}
@@ -1,18 +1,53 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/areas_test.rs"]
+mod areas_test;
+
 use crate::constraints::{AREA_ORDER_KEY, AREA_VALUE_KEY};
+use crate::extensions::{JobTie, VehicleTie};
+use hashbrown::HashSet;
 use std::slice::Iter;
 use std::sync::Arc;
 use vrp_core::construction::constraints::*;
 use vrp_core::construction::heuristics::*;
+use vrp_core::models::common::Dimensions;
 use vrp_core::models::problem::Job;
 use vrp_core::models::problem::*;
 use vrp_core::solver::objectives::*;
 use vrp_core::utils::Either;
 
+/// Specifies areas where vehicle is allowed to serve jobs.
+pub struct VehicleAreas {
+    /// Ids of allowed areas.
+    pub areas: HashSet<String>,
+    /// A penalty for serving a job outside of allowed areas. When it is not set, such jobs
+    /// cannot be served by the vehicle.
+    pub penalty: Option<f64>,
+}
+
+impl VehicleAreas {
+    /// Checks whether a job with given dimensions is outside of allowed areas.
+    pub fn is_violated(&self, job_dimens: &Dimensions) -> bool {
+        match job_dimens.get_job_areas() {
+            Some(areas) => self.areas.is_disjoint(areas),
+            None => true,
+        }
+    }
+
+    /// Returns a penalty of serving a job with given dimensions.
+    pub fn get_penalty(&self, job_dimens: &Dimensions) -> f64 {
+        match self.penalty {
+            Some(penalty) if self.is_violated(job_dimens) => penalty,
+            _ => 0.,
+        }
+    }
+}
+
 /// An area module provides way to restrict given actor to work in specific areas.
 pub struct AreaModule {
     keys: Vec<i32>,
     constraints: Vec<ConstraintVariant>,
     modules: Vec<TargetConstraint>,
+    code: Option<i32>,
 }
 
 impl AreaModule {
@@ -41,6 +76,17 @@ impl AreaModule {
         Self::new_objective(order_fn, value_fn, solution_fn, max_value, Either::Left(constraint_code))
     }
 
+    /// Creates instance of area restriction logic: jobs outside of vehicle's allowed areas cannot be
+    /// served by the vehicle unless its allowed areas have a penalty.
+    pub fn new_restricted(code: i32) -> Self {
+        Self {
+            keys: vec![],
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(AreaHardRouteConstraint { code }))],
+            modules: vec![],
+            code: Some(code),
+        }
+    }
+
     fn new_objective(
         order_fn: ActorOrderFn,
         value_fn: ActorValueFn,
@@ -73,6 +119,7 @@ impl AreaModule {
                 .cloned()
                 .collect(),
             modules: vec![order_constraint, value_constraint],
+            code: None,
         };
 
         let objectives = match optimization_type {
@@ -97,8 +144,11 @@ impl ConstraintModule for AreaModule {
         self.modules.iter().for_each(|module| module.accept_solution_state(ctx));
     }
 
-    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
-        Ok(source)
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        match self.code {
+            Some(code) if source.dimens().get_job_areas() != candidate.dimens().get_job_areas() => Err(code),
+            _ => Ok(source),
+        }
     }
 
     fn state_keys(&self) -> Iter<i32> {
@@ -109,3 +159,19 @@ impl ConstraintModule for AreaModule {
         self.constraints.iter()
     }
 }
+
+struct AreaHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for AreaHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        ctx.route
+            .actor
+            .vehicle
+            .dimens
+            .get_vehicle_areas()
+            .filter(|areas| areas.penalty.is_none() && areas.is_violated(job.dimens()))
+            .map(|_| RouteConstraintViolation { code: self.code })
+    }
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/backhaul_test.rs"]
+mod backhaul_test;
+
+use crate::extensions::{JobTie, VehicleTie};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::problem::{Job, Multi, Single};
+use vrp_core::models::solution::Activity;
+
+/// Specifies how backhaul requirement is enforced for a vehicle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BackhaulPolicy {
+    /// Pickups cannot be served before any delivery of the route.
+    Strict,
+    /// Pickups can be served before deliveries, but each such violation is penalized.
+    Soft(f64),
+}
+
+/// A backhaul module enforces serving all deliveries (linehauls) before any pickup (backhauls) in
+/// the route of a vehicle with backhaul policy, e.g. when goods cannot be unloaded through picked
+/// up ones. Only jobs which are either pickups or deliveries are considered, pickup and delivery
+/// jobs and other activities can be served in any order.
+pub struct BackhaulModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl BackhaulModule {
+    /// Creates a new instance of `BackhaulModule`.
+    pub fn new(code: i32) -> Self {
+        Self {
+            constraints: vec![
+                ConstraintVariant::HardActivity(Arc::new(BackhaulHardActivityConstraint { code })),
+                ConstraintVariant::SoftActivity(Arc::new(BackhaulSoftActivityConstraint {})),
+            ],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for BackhaulModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct BackhaulHardActivityConstraint {
+    code: i32,
+}
+
+impl HardActivityConstraint for BackhaulHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        match route_ctx.route.actor.vehicle.dimens.get_backhaul_policy() {
+            Some(BackhaulPolicy::Strict) if count_violations(route_ctx, activity_ctx) > 0 => {
+                Some(ActivityConstraintViolation { code: self.code, stopped: false })
+            }
+            _ => None,
+        }
+    }
+}
+
+struct BackhaulSoftActivityConstraint {}
+
+impl SoftActivityConstraint for BackhaulSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        match route_ctx.route.actor.vehicle.dimens.get_backhaul_policy() {
+            Some(BackhaulPolicy::Soft(penalty)) => penalty * count_violations(route_ctx, activity_ctx) as f64,
+            _ => 0.,
+        }
+    }
+}
+
+/// Counts activities which are served in wrong order relatively to the target activity: deliveries
+/// after inserted pickup or pickups before inserted delivery.
+fn count_violations(route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> usize {
+    let tour = &route_ctx.route.tour;
+    let index = activity_ctx.index;
+
+    match get_haul_type(activity_ctx.target) {
+        Some("pickup") => {
+            tour.all_activities().skip(index + 1).filter(|activity| get_haul_type(activity) == Some("delivery")).count()
+        }
+        Some("delivery") => {
+            tour.all_activities().take(index + 1).filter(|activity| get_haul_type(activity) == Some("pickup")).count()
+        }
+        _ => 0,
+    }
+}
+
+/// Returns activity type if activity belongs to a job which has only pickups or only deliveries.
+fn get_haul_type(activity: &Activity) -> Option<&str> {
+    let single = activity.job.as_ref()?;
+    let job_type = get_job_type(single).filter(|job_type| *job_type == "pickup" || *job_type == "delivery")?;
+
+    match Multi::roots(single) {
+        Some(multi) if !multi.jobs.iter().all(|single| get_job_type(single) == Some(job_type)) => None,
+        _ => Some(job_type),
+    }
+}
+
+fn get_job_type(single: &Single) -> Option<&str> {
+    single.dimens.get_job_type().map(|job_type| job_type.as_str())
+}
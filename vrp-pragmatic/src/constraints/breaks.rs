@@ -15,7 +15,6 @@ use vrp_core::models::problem::{Job, Single};
 use vrp_core::models::solution::Activity;
 
 /// Implements break functionality with variable location and time.
-/// NOTE known issue: rescheduling departure might affect break with time offset.
 pub struct BreakModule {
     code: i32,
     conditional: ConditionalJobModule,
@@ -188,12 +187,9 @@ fn remove_invalid_breaks(ctx: &mut SolutionContext) {
                         let is_locked = ctx.locked.contains(&break_job);
 
                         if !is_locked {
-                            // NOTE break should have location defined for all places or for none of them
-                            let location_count = break_single.places.iter().filter(|p| p.location.is_some()).count();
-                            assert!(location_count == 0 || location_count == break_single.places.len());
-
-                            let is_orphan =
-                                prev != current && break_single.places.first().and_then(|p| p.location).is_none();
+                            // NOTE break place without location sticks to location of the job served before
+                            let is_at_own_location = break_single.places.iter().any(|p| p.location == Some(current));
+                            let is_orphan = prev != current && !is_at_own_location;
                             let is_not_on_time = !is_on_proper_time(rc, break_single, &activity.schedule)
                                 || !can_be_scheduled(rc, break_single);
                             let is_ovrp_last = rc.route.tour.end().map_or(false, |end| std::ptr::eq(activity, end));
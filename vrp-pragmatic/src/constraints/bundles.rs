@@ -0,0 +1,132 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/bundle_test.rs"]
+mod bundle_test;
+
+use crate::extensions::JobTie;
+use hashbrown::HashSet;
+use std::slice::Iter;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext, UnassignmentInfo};
+use vrp_core::models::problem::Job;
+
+/// A bundle module provides the way to assign certain jobs all together or none of them. Unlike
+/// group, jobs of the same bundle can be served by different tours. When some job of the bundle
+/// is removed from the solution, e.g. by ruin method, the rest of the bundle is ejected with it,
+/// and when some job cannot be assigned, the rest of the bundle is unassigned too.
+pub struct BundleModule {
+    code: i32,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl BundleModule {
+    /// Creates a new instance of `BundleModule`.
+    pub fn new(code: i32) -> Self {
+        Self { code, constraints: vec![], keys: vec![] }
+    }
+
+    /// Removes assigned jobs of incomplete bundles from tours: they are moved back to required jobs
+    /// when the bundle has jobs to be inserted, otherwise they are unassigned.
+    fn eject_incomplete_bundles(&self, solution_ctx: &mut SolutionContext) {
+        let (pending, failed) = {
+            let required = solution_ctx.required.iter().collect::<HashSet<_>>();
+            let pending = solution_ctx
+                .required
+                .iter()
+                .filter_map(|job| job.dimens().get_job_bundle().cloned())
+                .collect::<HashSet<_>>();
+            let failed = solution_ctx
+                .unassigned
+                .keys()
+                .filter(|job| !required.contains(job))
+                .filter_map(|job| job.dimens().get_job_bundle().cloned())
+                .filter(|bundle| !pending.contains(bundle))
+                .collect::<HashSet<_>>();
+
+            (pending, failed)
+        };
+
+        if pending.is_empty() && failed.is_empty() {
+            return;
+        }
+
+        let locked = &solution_ctx.locked;
+        let (to_required, to_unassigned) = solution_ctx
+            .routes
+            .iter_mut()
+            .flat_map(|route_ctx| {
+                let ejected = route_ctx
+                    .route
+                    .tour
+                    .jobs()
+                    .filter(|job| !locked.contains(job))
+                    .filter_map(|job| {
+                        let bundle = job.dimens().get_job_bundle()?;
+                        if pending.contains(bundle) {
+                            Some((job, true))
+                        } else if failed.contains(bundle) {
+                            Some((job, false))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                if !ejected.is_empty() {
+                    let tour = &mut route_ctx.route_mut().tour;
+                    ejected.iter().for_each(|(job, _)| {
+                        tour.remove(job);
+                    });
+                }
+
+                ejected
+            })
+            .fold((Vec::new(), Vec::new()), |(mut to_required, mut to_unassigned), (job, is_pending)| {
+                if is_pending {
+                    to_required.push(job);
+                } else {
+                    to_unassigned.push(job);
+                }
+
+                (to_required, to_unassigned)
+            });
+
+        solution_ctx.required.extend(to_required);
+        solution_ctx.unassigned.extend(to_unassigned.into_iter().map(|job| (job, UnassignmentInfo::Simple(self.code))));
+
+        let registry = &mut solution_ctx.registry;
+        solution_ctx.routes.retain(|route_ctx| {
+            if route_ctx.route.tour.has_jobs() {
+                true
+            } else {
+                registry.free_route(route_ctx);
+                false
+            }
+        });
+    }
+}
+
+impl ConstraintModule for BundleModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.eject_incomplete_bundles(solution_ctx);
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        match (source.dimens().get_job_bundle(), candidate.dimens().get_job_bundle()) {
+            (None, None) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
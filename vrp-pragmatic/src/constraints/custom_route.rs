@@ -0,0 +1,102 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/custom_route_test.rs"]
+mod custom_route_test;
+
+use crate::extensions::{JobTie, VehicleTie};
+use serde::Serialize;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::problem::Job;
+
+/// Contains information about a route and a job which is evaluated for insertion into it.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RouteCheck {
+    /// A vehicle id.
+    pub vehicle_id: String,
+    /// A vehicle shift index.
+    pub shift_index: usize,
+    /// Ids of jobs already assigned to the route in their tour order.
+    pub job_ids: Vec<String>,
+    /// An id of the job which is evaluated for insertion.
+    pub job_id: String,
+}
+
+/// A user defined route check which returns true if the job can be assigned to the route.
+/// Please note, that the check is called from multiple threads.
+pub type RouteCheckFn = Arc<dyn Fn(&RouteCheck) -> bool + Send + Sync>;
+
+/// A custom route module delegates route level hard check to user defined function.
+/// It allows to inject business rules without recompiling the solver, e.g. via FFI or WASM.
+pub struct CustomRouteModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl CustomRouteModule {
+    /// Creates a new instance of `CustomRouteModule`.
+    pub fn new(route_check: RouteCheckFn, code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(CustomHardRouteConstraint { route_check, code }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for CustomRouteModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct CustomHardRouteConstraint {
+    route_check: RouteCheckFn,
+    code: i32,
+}
+
+impl HardRouteConstraint for CustomHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        let job_id = get_user_job_id(job)?;
+        let dimens = &ctx.route.actor.vehicle.dimens;
+
+        let route_check = RouteCheck {
+            vehicle_id: dimens.get_vehicle_id()?.clone(),
+            shift_index: dimens.get_shift_index()?,
+            job_ids: ctx.route.tour.jobs().filter_map(|job| get_user_job_id(&job)).collect(),
+            job_id,
+        };
+
+        if (self.route_check)(&route_check) {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
+
+/// Returns job id if the job is defined by user, not a conditional one such as break, reload, etc.
+fn get_user_job_id(job: &Job) -> Option<String> {
+    let dimens = job.dimens();
+
+    if dimens.get_vehicle_id().is_some() {
+        None
+    } else {
+        dimens.get_job_id().cloned()
+    }
+}
@@ -0,0 +1,431 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/docks_test.rs"]
+mod docks_test;
+
+use super::{eject_jobs, get_original_window};
+use crate::extensions::{JobTie, VehicleTie};
+use hashbrown::HashMap;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::*;
+use vrp_core::models::common::{Duration, TimeWindow, Timestamp};
+use vrp_core::models::problem::{ActivityCost, Actor, Job, TransportCost, TravelTime};
+use vrp_core::models::solution::Activity;
+use vrp_core::utils::compare_floats;
+
+/// Keeps time intervals when docks are occupied by assigned reloads per dock id.
+type DockUsage = HashMap<String, Vec<(Arc<Actor>, TimeWindow)>>;
+
+/// Keeps assigned reloads per dock id.
+type DockVisits = HashMap<String, Vec<DockVisit>>;
+
+/// Specifies an assigned reload activity which uses a dock.
+struct DockVisit {
+    actor: Arc<Actor>,
+    route_idx: usize,
+    activity_idx: usize,
+    /// Job's time window which contains activity's (potentially narrowed) time window.
+    original: TimeWindow,
+    /// Activity's current time window.
+    current: TimeWindow,
+    /// Service start without waiting for a dock.
+    earliest: Timestamp,
+    /// Latest service start which does not violate the rest of the route.
+    latest: Timestamp,
+    /// Actual service start.
+    start: Timestamp,
+    /// Actual service completion.
+    end: Timestamp,
+}
+
+/// Limits amount of alignment passes as rescheduling one route can affect other docks.
+const MAX_ALIGN_ITERATIONS: usize = 4;
+
+/// A tolerance used to compare dock usage intervals.
+const ALIGN_TOLERANCE: f64 = 1E-3;
+
+/// A dock module limits how many vehicles can be reloaded at the same place simultaneously, e.g.
+/// a depot has only a few loading docks shared by the whole fleet.
+///
+/// Dock usage of assigned reloads is kept in route state, so insertion of a reload is evaluated
+/// against reloads of other routes: a vehicle waits till a dock is released. Similar to job
+/// synchronization, routes are coupled via activity time windows: time window of each assigned
+/// reload is narrowed to start when a dock becomes available, then the route is rescheduled.
+/// Docks are assigned greedily in the order of reload arrivals.
+///
+/// Insertion of other jobs can delay reloads in the same route, so docks are aligned again after
+/// each change. If some reload cannot wait for a dock anymore, e.g. after a local search, it is
+/// removed together with jobs of its trip to keep solution feasible.
+pub struct DockModule {
+    code: i32,
+    state_key: i32,
+    docks: Arc<HashMap<String, usize>>,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    schedule: TransportConstraintModule,
+}
+
+impl DockModule {
+    /// Creates a new instance of `DockModule`. Docks specify amount of available docks per dock id.
+    pub fn new(
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        docks: HashMap<String, usize>,
+        code: i32,
+        state_key: i32,
+    ) -> Self {
+        let docks = Arc::new(docks);
+
+        Self {
+            code,
+            state_key,
+            docks: docks.clone(),
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(DockHardActivityConstraint {
+                code,
+                state_key,
+                docks,
+                activity: activity.clone(),
+                transport: transport.clone(),
+            }))],
+            keys: vec![state_key],
+            transport: transport.clone(),
+            schedule: TransportConstraintModule::new(transport, activity, code),
+        }
+    }
+
+    /// Delays reloads till docks are available and updates dock usage in all routes.
+    fn align(&self, solution_ctx: &mut SolutionContext) {
+        for _ in 0..MAX_ALIGN_ITERATIONS {
+            let visits = get_visits(solution_ctx, self.transport.as_ref());
+            let windows = visits
+                .iter()
+                .flat_map(|(dock_id, visits)| {
+                    let capacity = self.docks.get(dock_id).cloned().unwrap_or(usize::MAX);
+                    let mut visits = visits.iter().collect::<Vec<_>>();
+                    visits.sort_by(|a, b| {
+                        compare_floats(a.earliest, b.earliest)
+                            .then(a.route_idx.cmp(&b.route_idx))
+                            .then(a.activity_idx.cmp(&b.activity_idx))
+                    });
+
+                    let mut usage = Vec::<TimeWindow>::new();
+                    visits
+                        .into_iter()
+                        .filter_map(|visit| {
+                            let duration = visit.end - visit.start;
+                            let slot = get_slot(usage.as_slice(), capacity, visit.earliest, duration);
+
+                            // NOTE reload which cannot wait is not delayed and is ejected later
+                            let window = if slot <= visit.latest + ALIGN_TOLERANCE {
+                                usage.push(TimeWindow::new(slot, slot + duration));
+                                TimeWindow::new(slot.max(visit.original.start), visit.original.end)
+                            } else {
+                                visit.original.clone()
+                            };
+
+                            if window == visit.current {
+                                None
+                            } else {
+                                Some((visit.route_idx, visit.activity_idx, window))
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .fold(HashMap::<usize, Vec<_>>::default(), |mut acc, (route_idx, activity_idx, window)| {
+                    acc.entry(route_idx).or_default().push((activity_idx, window));
+                    acc
+                });
+
+            if windows.is_empty() {
+                break;
+            }
+
+            windows.into_iter().for_each(|(route_idx, windows)| {
+                let route_ctx = solution_ctx.routes.get_mut(route_idx).expect("invalid route index");
+
+                let tour = &mut route_ctx.route_mut().tour;
+                windows.into_iter().for_each(|(activity_idx, window)| {
+                    tour.get_mut(activity_idx).expect("invalid activity index").place.time = window;
+                });
+
+                self.schedule.accept_route_state(route_ctx);
+            });
+        }
+
+        let usage = get_visits(solution_ctx, self.transport.as_ref())
+            .into_iter()
+            .map(|(dock_id, visits)| {
+                let usage = visits
+                    .into_iter()
+                    .map(|visit| (visit.actor, TimeWindow::new(visit.start, visit.end)))
+                    .collect::<Vec<_>>();
+
+                (dock_id, usage)
+            })
+            .collect::<DockUsage>();
+        let usage: StateValue = Arc::new(usage);
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            route_ctx.state_mut().put_route_state_raw(self.state_key, usage.clone());
+        });
+        solution_ctx.state.insert(self.state_key, usage);
+    }
+
+    /// Ejects reloads which overlap with other reloads on a saturated dock together with jobs of their trips.
+    fn eject_overlapping_reloads(&self, solution_ctx: &mut SolutionContext) {
+        let broken = get_visits(solution_ctx, self.transport.as_ref())
+            .into_iter()
+            .flat_map(|(dock_id, visits)| {
+                let capacity = self.docks.get(&dock_id).cloned().unwrap_or(usize::MAX);
+                let mut visits = visits;
+                visits.sort_by(|a, b| compare_floats(a.start, b.start));
+
+                let mut usage = Vec::<TimeWindow>::new();
+                visits
+                    .into_iter()
+                    .filter(|visit| {
+                        let time = TimeWindow::new(visit.start, visit.end);
+                        let is_broken = visit.start > visit.original.end + ALIGN_TOLERANCE
+                            || count_overlaps(usage.as_slice(), &time) >= capacity;
+
+                        if !is_broken {
+                            usage.push(time);
+                        }
+
+                        is_broken
+                    })
+                    .map(|visit| (visit.route_idx, visit.activity_idx))
+                    .collect::<Vec<_>>()
+            })
+            .fold(HashMap::<usize, usize>::default(), |mut acc, (route_idx, activity_idx)| {
+                // NOTE only the first broken reload per route is handled as the rest of the route is shifted
+                let entry = acc.entry(route_idx).or_insert(activity_idx);
+                *entry = (*entry).min(activity_idx);
+                acc
+            });
+
+        if broken.is_empty() {
+            return;
+        }
+
+        let (mut ejected, mut ignored) = (Vec::new(), Vec::new());
+        eject_jobs(solution_ctx, |route_idx, route_ctx| {
+            let activity_idx = match broken.get(&route_idx) {
+                Some(activity_idx) => *activity_idx,
+                None => return vec![],
+            };
+            let tour = &route_ctx.route.tour;
+
+            let reload = tour.get(activity_idx).and_then(|activity| activity.retrieve_job());
+            let trip = tour
+                .all_activities()
+                .skip(activity_idx + 1)
+                .take_while(|activity| !is_reload(activity))
+                .filter(|activity| activity.job.as_ref().is_some_and(|single| single.dimens.get_vehicle_id().is_none()))
+                .filter_map(|activity| activity.retrieve_job())
+                .collect::<Vec<_>>();
+
+            ignored.extend(reload.clone());
+            ejected.extend(trip.iter().cloned());
+
+            reload.into_iter().chain(trip).collect()
+        });
+
+        solution_ctx.ignored.extend(ignored);
+        solution_ctx.unassigned.extend(ejected.into_iter().map(|job| (job, UnassignmentInfo::Simple(self.code))));
+    }
+}
+
+impl ConstraintModule for DockModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        let route_ctx = solution_ctx.routes.get(route_index).unwrap();
+        if route_ctx.route.tour.all_activities().any(|activity| get_dock(activity).is_some()) {
+            self.align(solution_ctx);
+        } else if let Some(usage) = solution_ctx.state.get(&self.state_key).cloned() {
+            // NOTE a new route has no state yet, so share the current one
+            let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+            route_ctx.state_mut().put_route_state_raw(self.state_key, usage);
+        }
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        self.align(ctx);
+        self.eject_overlapping_reloads(ctx);
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct DockHardActivityConstraint {
+    code: i32,
+    state_key: i32,
+    docks: Arc<HashMap<String, usize>>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardActivityConstraint for DockHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let dock_id = get_dock(activity_ctx.target)?;
+        let capacity = self.docks.get(dock_id).cloned()?;
+
+        let route = route_ctx.route.as_ref();
+        let usage = route_ctx
+            .state
+            .get_route_state::<DockUsage>(self.state_key)
+            .and_then(|usage| usage.get(dock_id))
+            .map(|usage| {
+                usage
+                    .iter()
+                    .filter(|(actor, _)| !Arc::ptr_eq(actor, &route.actor))
+                    .map(|(_, time)| time.clone())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let departure = prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+
+        // NOTE vehicle has to wait for a dock if all of them are occupied by other vehicles
+        let service_start = arrival.max(target.place.time.start);
+        let duration = self.activity.estimate_departure(route, target, service_start) - service_start;
+        let forced_start = get_slot(usage.as_slice(), capacity, service_start, duration);
+
+        let is_next_violated = forced_start > service_start
+            && activity_ctx.next.is_some_and(|next| {
+                let departure = self.activity.estimate_departure(route, target, forced_start);
+                let arrival = departure
+                    + self.transport.duration(
+                        route,
+                        target.place.location,
+                        next.place.location,
+                        TravelTime::Departure(departure),
+                    );
+                let latest_arrival =
+                    *route_ctx.state.get_activity_state(LATEST_ARRIVAL_KEY, next).unwrap_or(&next.place.latest_start());
+
+                arrival > latest_arrival
+            });
+
+        if forced_start > target.place.time.end || is_next_violated {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+fn get_dock(activity: &Activity) -> Option<&String> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_dock())
+}
+
+fn is_reload(activity: &Activity) -> bool {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_type()).is_some_and(|job_type| job_type == "reload")
+}
+
+fn get_visits(solution_ctx: &SolutionContext, transport: &(dyn TransportCost + Send + Sync)) -> DockVisits {
+    solution_ctx.routes.iter().enumerate().fold(DockVisits::default(), |acc, (route_idx, route_ctx)| {
+        let route = route_ctx.route.as_ref();
+        let date = route.tour.start().map_or(0., |start| start.schedule.departure);
+
+        route.tour.all_activities().enumerate().fold(acc, |mut acc, (activity_idx, activity)| {
+            let visit = get_dock(activity).and_then(|dock_id| {
+                let original = get_original_window(activity, date)?;
+
+                // NOTE latest start is estimated from the next activity to ignore own narrowed time window
+                let latest = match route.tour.get(activity_idx + 1) {
+                    Some(next) => {
+                        let latest_arrival = *route_ctx
+                            .state
+                            .get_activity_state(LATEST_ARRIVAL_KEY, next)
+                            .unwrap_or(&next.place.latest_start());
+                        let duration = transport.duration(
+                            route,
+                            activity.place.location,
+                            next.place.location,
+                            TravelTime::Arrival(latest_arrival),
+                        );
+
+                        (latest_arrival - duration - activity.place.duration).min(original.end)
+                    }
+                    None => original.end,
+                };
+
+                let start = activity.schedule.arrival.max(activity.place.time.start);
+
+                Some((
+                    dock_id,
+                    DockVisit {
+                        actor: route.actor.clone(),
+                        route_idx,
+                        activity_idx,
+                        earliest: activity.schedule.arrival.max(original.start),
+                        latest,
+                        start,
+                        end: activity.schedule.departure.max(start),
+                        current: activity.place.time.clone(),
+                        original,
+                    },
+                ))
+            });
+
+            if let Some((dock_id, visit)) = visit {
+                acc.entry(dock_id.clone()).or_default().push(visit);
+            }
+
+            acc
+        })
+    })
+}
+
+/// Returns amount of intervals which overlap with given time.
+fn count_overlaps(usage: &[TimeWindow], time: &TimeWindow) -> usize {
+    usage
+        .iter()
+        .filter(|other| other.start < time.end - ALIGN_TOLERANCE && time.start < other.end - ALIGN_TOLERANCE)
+        .count()
+}
+
+/// Returns the earliest service start, not earlier than given one, when one of the docks is
+/// available for given duration.
+fn get_slot(usage: &[TimeWindow], capacity: usize, earliest: Timestamp, duration: Duration) -> Timestamp {
+    if duration <= 0. {
+        return earliest;
+    }
+
+    let mut candidates = std::iter::once(earliest)
+        .chain(usage.iter().map(|time| time.end).filter(|end| *end > earliest))
+        .collect::<Vec<_>>();
+    candidates.sort_by(|a, b| compare_floats(*a, *b));
+
+    candidates
+        .into_iter()
+        .find(|start| count_overlaps(usage, &TimeWindow::new(*start, *start + duration)) < capacity)
+        .unwrap_or(earliest)
+}
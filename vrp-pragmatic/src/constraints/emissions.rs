@@ -0,0 +1,111 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/emissions_test.rs"]
+mod emissions_test;
+
+use crate::constraints::*;
+use crate::extensions::VehicleTie;
+use vrp_core::construction::constraints::TOTAL_DISTANCE_KEY;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::problem::{Job, TargetConstraint, TargetObjective, TransportCost, TravelTime};
+use vrp_core::solver::objectives::GenericValue;
+
+/// Specifies vehicle fuel consumption model used to estimate its CO2 emissions.
+#[derive(Clone, Debug)]
+pub struct EmissionProfile {
+    /// A fuel consumption per distance unit of empty vehicle.
+    pub consumption: f64,
+    /// An extra fuel consumption per distance unit and per unit of carried load.
+    pub load_consumption: f64,
+    /// An amount of CO2 emitted per fuel unit.
+    pub co2_factor: f64,
+}
+
+/// Specifies fuel burn and CO2 emissions of the route.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RouteEmissions {
+    /// A fuel burn.
+    pub fuel: f64,
+    /// CO2 emissions.
+    pub co2: f64,
+}
+
+/// Calculates fuel burn of the route from travelled distance and load carried on each leg.
+pub struct EmissionCalculator {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    demand_fn: EnergyDemandFn,
+}
+
+impl EmissionCalculator {
+    /// Creates a new instance of `EmissionCalculator`.
+    pub fn new(transport: Arc<dyn TransportCost + Send + Sync>, demand_fn: EnergyDemandFn) -> Self {
+        Self { transport, demand_fn }
+    }
+
+    /// Returns fuel burn and CO2 emissions of the route or `None` if its vehicle has no emission profile.
+    pub fn calculate(&self, route: &Route) -> Option<RouteEmissions> {
+        let profile = route.actor.vehicle.dimens.get_vehicle_emissions()?;
+        let activities = route.tour.all_activities().collect::<Vec<_>>();
+        let loads = get_departure_loads(activities.as_slice(), &self.demand_fn);
+
+        let fuel = activities
+            .windows(2)
+            .zip(loads.iter())
+            .map(|(leg, load)| match leg {
+                [prev, next] => {
+                    let distance = self.transport.distance(
+                        route,
+                        prev.place.location,
+                        next.place.location,
+                        TravelTime::Departure(prev.schedule.departure),
+                    );
+
+                    distance * (profile.consumption + profile.load_consumption * load.max(0.))
+                }
+                _ => 0.,
+            })
+            .sum::<f64>();
+
+        Some(RouteEmissions { fuel, co2: fuel * profile.co2_factor })
+    }
+
+    /// Returns a rough estimate of extra CO2 emitted due to job's load: it is assumed to be carried
+    /// over half of the route distance.
+    fn estimate_job(&self, route_ctx: &RouteContext, job: &Job) -> f64 {
+        let profile = match route_ctx.route.actor.vehicle.dimens.get_vehicle_emissions() {
+            Some(profile) => profile,
+            None => return 0.,
+        };
+
+        let get_load = |single: &Arc<Single>| {
+            let (delivery, change) = (self.demand_fn)(single);
+            delivery.max(change.abs())
+        };
+        let load = match job {
+            Job::Single(single) => get_load(single),
+            Job::Multi(multi) => multi.jobs.iter().map(get_load).fold(0., f64::max),
+        };
+        let distance = route_ctx.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
+
+        distance / 2. * profile.load_consumption * load * profile.co2_factor
+    }
+}
+
+/// Creates _(constraint, objective)_ type pair which minimizes total CO2 emissions.
+pub fn create_emission_objective(
+    calculator: Arc<EmissionCalculator>,
+    state_key: i32,
+) -> (TargetConstraint, TargetObjective) {
+    let get_route_emissions = {
+        let calculator = calculator.clone();
+        Arc::new(move |route_ctx: &RouteContext| calculator.calculate(&route_ctx.route).map_or(0., |e| e.co2))
+    };
+
+    GenericValue::new_constrained_objective(
+        None,
+        Arc::new(|source, _| Ok(source)),
+        get_route_emissions.clone(),
+        Arc::new(move |ctx: &SolutionContext| ctx.routes.iter().map(|route_ctx| get_route_emissions(route_ctx)).sum()),
+        Arc::new(move |_, route_ctx, job, _| calculator.estimate_job(route_ctx, job)),
+        state_key,
+    )
+}
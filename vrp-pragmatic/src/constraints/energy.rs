@@ -0,0 +1,363 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/energy_test.rs"]
+mod energy_test;
+
+use crate::constraints::*;
+use crate::extensions::{JobTie, RechargeTie, VehicleTie};
+use std::cmp::Ordering;
+use std::iter::once;
+use std::slice::Iter;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::Timestamp;
+use vrp_core::models::problem::{Job, Single, TransportCost, TravelTime};
+use vrp_core::utils::compare_floats;
+
+/// A fraction of usable energy: when vehicle's energy level drops below it, recharge jobs
+/// are promoted to required ones.
+const RECHARGE_THRESHOLD: f64 = 0.5;
+
+/// Specifies electric vehicle energy parameters.
+#[derive(Clone, Debug)]
+pub struct EnergyProfile {
+    /// A battery capacity.
+    pub capacity: f64,
+    /// An energy consumption per distance unit.
+    pub consumption: f64,
+    /// An extra energy consumption per distance unit and per unit of carried load.
+    pub load_consumption: f64,
+    /// A reserve energy level which vehicle should never go below.
+    pub reserve: f64,
+}
+
+impl EnergyProfile {
+    /// Returns energy level below which vehicle should be recharged.
+    fn get_threshold(&self) -> f64 {
+        self.reserve + (self.capacity - self.reserve) * RECHARGE_THRESHOLD
+    }
+}
+
+/// Returns static delivery and total load change of the job activity.
+pub type EnergyDemandFn = Arc<dyn Fn(&Single) -> (f64, f64) + Send + Sync>;
+
+/// An energy module models battery consumption of electric vehicles and their recharging at
+/// charging stations. Recharge duration depends on energy level at arrival.
+pub struct EnergyModule {
+    code: i32,
+    state_key: i32,
+    calculator: Arc<EnergyCalculator>,
+    conditional: ConditionalJobModule,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl EnergyModule {
+    /// Creates a new instance of `EnergyModule`.
+    pub fn new(
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        demand_fn: EnergyDemandFn,
+        code: i32,
+        state_key: i32,
+    ) -> Self {
+        let calculator = Arc::new(EnergyCalculator { transport, demand_fn });
+
+        Self {
+            code,
+            state_key,
+            calculator: calculator.clone(),
+            conditional: ConditionalJobModule::new(create_job_transition(state_key)),
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(EnergyHardRouteConstraint { code })),
+                ConstraintVariant::HardActivity(Arc::new(EnergyHardActivityConstraint { code, calculator })),
+                ConstraintVariant::SoftRoute(Arc::new(EnergySoftRouteConstraint {})),
+            ],
+            keys: vec![state_key],
+        }
+    }
+
+    fn update_route(&self, route_ctx: &mut RouteContext) {
+        let profile = match route_ctx.route.actor.vehicle.dimens.get_vehicle_energy() {
+            Some(profile) => profile.clone(),
+            None => return,
+        };
+
+        let activities = get_activities(route_ctx.route.tour.all_activities());
+        let levels = self.calculator.get_levels(&route_ctx.route, &profile, activities.as_slice());
+
+        let durations = activities
+            .iter()
+            .zip(levels.iter())
+            .enumerate()
+            .filter_map(|(idx, ((activity, _), level))| {
+                let rate =
+                    activity.job.as_ref().filter(|single| is_recharge_single(single))?.dimens.get_recharge_rate()?;
+                let duration = ((profile.capacity - level).max(0.) / rate).ceil();
+
+                if compare_floats(duration, activity.place.duration) != Ordering::Equal {
+                    Some((idx, duration))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if !durations.is_empty() {
+            let tour = &mut route_ctx.route_mut().tour;
+            durations.into_iter().for_each(|(idx, duration)| {
+                tour.get_mut(idx).expect("invalid recharge index").place.duration = duration;
+            });
+        }
+
+        let min_level = levels.into_iter().fold(profile.capacity, f64::min);
+        route_ctx.state_mut().put_route_state(self.state_key, min_level);
+    }
+
+    fn remove_obsolete_recharges(&self, solution_ctx: &mut SolutionContext) {
+        let mut extra_ignored = Vec::new();
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            let profile = match route_ctx.route.actor.vehicle.dimens.get_vehicle_energy() {
+                Some(profile) => profile,
+                None => return,
+            };
+
+            let tour = &route_ctx.route.tour;
+            let obsolete_idx = tour.all_activities().enumerate().filter(|(_, a)| is_recharge_activity(a)).find_map(
+                |(recharge_idx, _)| {
+                    let activities = get_activities(
+                        tour.all_activities().enumerate().filter(|(idx, _)| *idx != recharge_idx).map(|(_, a)| a),
+                    );
+                    let levels = self.calculator.get_levels(&route_ctx.route, profile, activities.as_slice());
+                    let threshold = profile.get_threshold();
+
+                    if levels.iter().all(|level| compare_floats(*level, threshold) != Ordering::Less) {
+                        Some(recharge_idx)
+                    } else {
+                        None
+                    }
+                },
+            );
+
+            if let Some(obsolete_idx) = obsolete_idx {
+                // NOTE: we remove only one recharge per tour, state update should be handled externally
+                extra_ignored.push(route_ctx.route_mut().tour.remove_activity_at(obsolete_idx));
+            }
+        });
+
+        solution_ctx.ignored.extend(extra_ignored);
+    }
+}
+
+impl ConstraintModule for EnergyModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        self.update_route(solution_ctx.routes.get_mut(route_index).unwrap());
+        self.conditional.accept_insertion(solution_ctx, route_index, job);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        self.update_route(ctx);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.update_route(route_ctx);
+        });
+
+        self.conditional.accept_solution_state(ctx);
+        self.remove_obsolete_recharges(ctx);
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        if once(&source).chain(once(&candidate)).any(is_recharge_job) {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct EnergyCalculator {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    demand_fn: EnergyDemandFn,
+}
+
+impl EnergyCalculator {
+    /// Returns energy level at arrival for each activity.
+    fn get_levels(&self, route: &Route, profile: &EnergyProfile, activities: &[(&Activity, Timestamp)]) -> Vec<f64> {
+        let loads = get_departure_loads(
+            activities.iter().map(|(activity, _)| *activity).collect::<Vec<_>>().as_slice(),
+            &self.demand_fn,
+        );
+
+        let mut levels = Vec::with_capacity(activities.len());
+        let mut level = profile.capacity;
+
+        activities.iter().enumerate().for_each(|(idx, (activity, _))| {
+            if idx > 0 {
+                let (prev, departure) = activities[idx - 1];
+                let distance = self.transport.distance(
+                    route,
+                    prev.place.location,
+                    activity.place.location,
+                    TravelTime::Departure(departure),
+                );
+                level -= distance * (profile.consumption + profile.load_consumption * loads[idx - 1].max(0.));
+            }
+
+            levels.push(level);
+
+            if is_recharge_activity(activity) {
+                level = profile.capacity;
+            }
+        });
+
+        levels
+    }
+}
+
+/// Returns vehicle's load on departure from each activity. Vehicle's load is assumed to be the
+/// sum of static deliveries of the trip at the tour start or at reload.
+pub(crate) fn get_departure_loads(activities: &[&Activity], demand_fn: &EnergyDemandFn) -> Vec<f64> {
+    let demands = activities
+        .iter()
+        .map(|activity| activity.job.as_ref().map_or((0., 0.), |single| (demand_fn)(single)))
+        .collect::<Vec<_>>();
+
+    let mut load = 0_f64;
+
+    activities
+        .iter()
+        .enumerate()
+        .map(|(idx, activity)| {
+            load = if idx == 0 || is_reload_activity(activity) {
+                activities
+                    .iter()
+                    .zip(demands.iter())
+                    .skip(idx + 1)
+                    .take_while(|(activity, _)| !is_reload_activity(activity))
+                    .map(|(_, (delivery, _))| *delivery)
+                    .sum()
+            } else {
+                load + demands[idx].1
+            };
+
+            load
+        })
+        .collect()
+}
+
+/// Locks recharge jobs to specific vehicles.
+struct EnergyHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for EnergyHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        match job.as_single() {
+            Some(single) if is_recharge_single(single) && !is_single_belongs_to_route(ctx, single) => {
+                Some(RouteConstraintViolation { code: self.code })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Checks that vehicle's energy level does not drop below its reserve.
+struct EnergyHardActivityConstraint {
+    code: i32,
+    calculator: Arc<EnergyCalculator>,
+}
+
+impl HardActivityConstraint for EnergyHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let profile = route_ctx.route.actor.vehicle.dimens.get_vehicle_energy()?;
+        let tour = &route_ctx.route.tour;
+
+        // NOTE longer recharge caused by insertion might shift schedule of the next activities,
+        // this is not taken into account by time constraint here
+        let activities = get_activities(tour.all_activities().take(activity_ctx.index + 1))
+            .into_iter()
+            .chain(once((activity_ctx.target, activity_ctx.prev.schedule.departure)))
+            .chain(get_activities(tour.all_activities().skip(activity_ctx.index + 1)))
+            .collect::<Vec<_>>();
+
+        let levels = self.calculator.get_levels(&route_ctx.route, profile, activities.as_slice());
+
+        if levels.iter().all(|level| compare_floats(*level, profile.reserve) != Ordering::Less) {
+            None
+        } else {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        }
+    }
+}
+
+/// Encourages insertion of promoted recharge jobs.
+struct EnergySoftRouteConstraint {}
+
+impl SoftRouteConstraint for EnergySoftRouteConstraint {
+    fn estimate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> f64 {
+        if is_recharge_job(job) {
+            0. - ctx.route.actor.vehicle.costs.fixed.max(1000.)
+        } else {
+            0.
+        }
+    }
+}
+
+fn create_job_transition(state_key: i32) -> Box<dyn JobContextTransition + Send + Sync> {
+    Box::new(ConcreteJobContextTransition {
+        remove_required: move |solution_ctx, _, job| {
+            is_recharge_job(job) && !is_recharge_needed(solution_ctx, job, state_key)
+        },
+        promote_required: move |solution_ctx, _, job| {
+            is_recharge_job(job) && is_recharge_needed(solution_ctx, job, state_key)
+        },
+        remove_locked: |_, _, _| false,
+        promote_locked: |_, _, job| is_recharge_job(job),
+    })
+}
+
+/// Checks whether route of recharge job's vehicle has energy level below recharge threshold.
+fn is_recharge_needed(solution_ctx: &SolutionContext, job: &Job, state_key: i32) -> bool {
+    job.as_single()
+        .and_then(|single| solution_ctx.routes.iter().find(|route_ctx| is_single_belongs_to_route(route_ctx, single)))
+        .and_then(|route_ctx| {
+            let profile = route_ctx.route.actor.vehicle.dimens.get_vehicle_energy()?;
+            let min_level = route_ctx.state.get_route_state::<f64>(state_key)?;
+
+            Some(compare_floats(*min_level, profile.get_threshold()) == Ordering::Less)
+        })
+        .unwrap_or(false)
+}
+
+fn get_activities<'a>(activities: impl Iterator<Item = &'a Activity>) -> Vec<(&'a Activity, Timestamp)> {
+    activities.map(|activity| (activity, activity.schedule.departure)).collect()
+}
+
+fn is_recharge_job(job: &Job) -> bool {
+    job.as_single().is_some_and(|single| is_recharge_single(single))
+}
+
+fn is_recharge_single(single: &Single) -> bool {
+    single.dimens.get_job_type().is_some_and(|t| t == "recharge")
+}
+
+fn is_recharge_activity(activity: &Activity) -> bool {
+    activity.job.as_ref().is_some_and(|single| is_recharge_single(single))
+}
+
+fn is_reload_activity(activity: &Activity) -> bool {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_type()).is_some_and(|t| t == "reload")
+}
@@ -0,0 +1,137 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/loading_order_test.rs"]
+mod loading_order_test;
+
+use crate::extensions::{JobTie, VehicleTie};
+use hashbrown::{HashMap, HashSet};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::problem::Job;
+use vrp_core::models::solution::Activity;
+
+/// Specifies loading order of pickup and delivery jobs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LoadingOrder {
+    /// Last in, first out: the job picked up last has to be delivered first.
+    Lifo,
+    /// First in, first out: the job picked up first has to be delivered first.
+    Fifo,
+}
+
+/// A loading order module enforces LIFO or FIFO order between pickup and delivery activities of
+/// jobs served by a vehicle with loading order, e.g. rear-loaded truck. Only jobs with exactly one
+/// pickup and one delivery are considered, other jobs can be served in any order.
+pub struct LoadingOrderModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl LoadingOrderModule {
+    /// Creates a new instance of `LoadingOrderModule`.
+    pub fn new(code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(LoadingOrderHardActivityConstraint { code }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for LoadingOrderModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct LoadingOrderHardActivityConstraint {
+    code: i32,
+}
+
+impl HardActivityConstraint for LoadingOrderHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let order = route_ctx.route.actor.vehicle.dimens.get_loading_order()?;
+
+        // NOTE pickup is always inserted before delivery, so order is checked only on delivery insertion
+        let job = match get_pair_activity(activity_ctx.target)? {
+            (job, false) => job,
+            (_, true) => return None,
+        };
+
+        let tour = &route_ctx.route.tour;
+        let index = activity_ctx.index;
+        let pickup_idx = tour.all_activities().take(index + 1).position(|activity| {
+            get_pair_activity(activity).is_some_and(|(other, is_pickup)| is_pickup && other == job)
+        })?;
+
+        let inside =
+            tour.all_activities().skip(pickup_idx + 1).take(index - pickup_idx).filter_map(get_pair_activity).fold(
+                HashMap::<Job, usize>::default(),
+                |mut acc, (job, _)| {
+                    *acc.entry(job).or_insert(0) += 1;
+                    acc
+                },
+            );
+
+        let is_valid = match order {
+            // NOTE jobs loaded after the target one have to be unloaded before it
+            LoadingOrder::Lifo => inside.values().all(|count| *count == 2),
+            // NOTE jobs loaded before the target one have to be unloaded before it and jobs loaded
+            // after it have to be unloaded after it
+            LoadingOrder::Fifo => {
+                let loaded_before = tour
+                    .all_activities()
+                    .take(pickup_idx)
+                    .filter_map(get_pair_activity)
+                    .filter(|(_, is_pickup)| *is_pickup)
+                    .map(|(job, _)| job)
+                    .collect::<HashSet<_>>();
+
+                inside.values().all(|count| *count == 1)
+                    && !tour
+                        .all_activities()
+                        .skip(index + 1)
+                        .filter_map(get_pair_activity)
+                        .any(|(job, is_pickup)| !is_pickup && loaded_before.contains(&job))
+            }
+        };
+
+        if is_valid {
+            None
+        } else {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        }
+    }
+}
+
+/// Returns pickup and delivery job of the activity and a flag whether activity is pickup.
+fn get_pair_activity(activity: &Activity) -> Option<(Job, bool)> {
+    let is_pickup = match activity.job.as_ref().and_then(|single| single.dimens.get_job_type())?.as_str() {
+        "pickup" => true,
+        "delivery" => false,
+        _ => return None,
+    };
+
+    activity
+        .retrieve_job()
+        .filter(|job| job.as_multi().is_some_and(|multi| multi.jobs.len() == 2))
+        .map(|job| (job, is_pickup))
+}
@@ -2,9 +2,9 @@
 
 use crate::extensions::VehicleTie;
 use std::sync::Arc;
-use vrp_core::construction::heuristics::RouteContext;
-use vrp_core::models::common::Dimensions;
-use vrp_core::models::problem::Single;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::common::{Dimensions, TimeWindow, Timestamp};
+use vrp_core::models::problem::{Job, Single};
 use vrp_core::models::solution::{Activity, Route};
 
 /// A key which tracks job group state.
@@ -25,6 +25,54 @@ pub const AREA_ORDER_KEY: i32 = 1005;
 /// A key which tracks reload resource consumption state.
 pub const RELOAD_RESOURCE_KEY: i32 = 1006;
 
+/// A key which tracks total solution cost.
+pub const TOTAL_COST_LIMIT_KEY: i32 = 1007;
+
+/// A key which tracks whether route has jobs with visit gap.
+pub const VISIT_GAP_KEY: i32 = 1008;
+
+/// A key which tracks total penalty of missing preferred skills.
+pub const SKILL_PENALTY_KEY: i32 = 1009;
+
+/// A key which tracks minimum energy level of electric vehicle.
+pub const ENERGY_LEVEL_KEY: i32 = 1010;
+
+/// A key which tracks latest arrival time which respects max ride duration of jobs.
+pub const RIDE_DURATION_KEY: i32 = 1011;
+
+/// A key which tracks service start bounds of synchronized jobs.
+pub const SYNC_KEY: i32 = 1012;
+
+/// A key which tracks assigned jobs with precedence relations.
+pub const PRECEDENCE_KEY: i32 = 1013;
+
+/// A key which tracks amount of territory jobs served by not preferred vehicles.
+pub const TERRITORY_VIOLATION_KEY: i32 = 1014;
+
+/// A key which tracks dock usage of reloads across all routes.
+pub const DOCK_KEY: i32 = 1015;
+
+/// A key which tracks total penalty of jobs served outside of allowed vehicle areas.
+pub const AREA_PENALTY_KEY: i32 = 1016;
+
+/// A key which tracks pinned territory jobs relaxed to be served by not preferred vehicles.
+pub const TERRITORY_PINNING_KEY: i32 = 1017;
+
+/// A key which tracks max load of non-reloadable dimensions in the past.
+pub const NON_RELOADABLE_MAX_PAST_KEY: i32 = 1018;
+
+/// A key which tracks max load of non-reloadable dimensions in the future.
+pub const NON_RELOADABLE_MAX_FUTURE_KEY: i32 = 1019;
+
+/// A key which tracks max working time of a single driver within the tour.
+pub const DRIVER_TIME_KEY: i32 = 1020;
+
+/// A key which tracks CO2 emissions of the route.
+pub const EMISSIONS_KEY: i32 = 1021;
+
+/// A key which tracks convex hull of tour jobs and overlap between tours.
+pub const OVERLAP_KEY: i32 = 1022;
+
 fn as_single_job<F>(activity: &Activity, condition: F) -> Option<&Arc<Single>>
 where
     F: Fn(&Arc<Single>) -> bool,
@@ -52,27 +100,141 @@ fn is_single_belongs_to_route(ctx: &RouteContext, single: &Arc<Single>) -> bool
     is_correct_vehicle(&ctx.route, vehicle_id, shift_index)
 }
 
+/// Returns job's time window which contains (potentially narrowed) activity's time window.
+fn get_original_window(activity: &Activity, date: Timestamp) -> Option<TimeWindow> {
+    let current = &activity.place.time;
+
+    activity
+        .job
+        .as_ref()?
+        .places
+        .iter()
+        .filter(|place| place.location.is_none() || place.location == Some(activity.place.location))
+        .flat_map(|place| place.times.iter())
+        .map(|time| time.to_time_window(date))
+        .find(|time| time.start <= current.start && current.end <= time.end)
+}
+
+/// Ejects jobs selected by given function from their routes, frees routes left without jobs and
+/// returns ejected jobs. It is used by modules which align activities across routes and eject jobs
+/// which cannot be aligned anymore.
+///
+/// NOTE ejection changes solution state, so alignment is repeated on the next pipeline run.
+fn eject_jobs(
+    solution_ctx: &mut SolutionContext,
+    mut select_fn: impl FnMut(usize, &RouteContext) -> Vec<Job>,
+) -> Vec<Job> {
+    let mut ejected = Vec::new();
+    solution_ctx.routes.iter_mut().enumerate().for_each(|(route_idx, route_ctx)| {
+        let jobs = select_fn(route_idx, route_ctx);
+
+        if !jobs.is_empty() {
+            let tour = &mut route_ctx.route_mut().tour;
+            jobs.iter().for_each(|job| {
+                tour.remove(job);
+            });
+            ejected.extend(jobs);
+        }
+    });
+
+    let registry = &mut solution_ctx.registry;
+    solution_ctx.routes.retain(|route_ctx| {
+        if route_ctx.route.tour.has_jobs() {
+            true
+        } else {
+            registry.free_route(route_ctx);
+            false
+        }
+    });
+
+    ejected
+}
+
 mod areas;
-pub use self::areas::AreaModule;
+pub use self::areas::{AreaModule, VehicleAreas};
+
+mod backhaul;
+pub use self::backhaul::{BackhaulModule, BackhaulPolicy};
 
 mod breaks;
 pub use self::breaks::{BreakModule, BreakPolicy};
 
-mod compatibility;
-pub use self::compatibility::CompatibilityModule;
+mod bundles;
+pub use self::bundles::BundleModule;
+
+pub use vrp_core::construction::constraints::CompatibilityModule;
+
+mod custom_route;
+pub use self::custom_route::{CustomRouteModule, RouteCheck, RouteCheckFn};
 
 mod dispatch;
 pub use self::dispatch::DispatchModule;
 
+mod docks;
+pub use self::docks::DockModule;
+
+mod emissions;
+pub use self::emissions::{create_emission_objective, EmissionCalculator, EmissionProfile};
+
+mod energy;
+pub(crate) use self::energy::get_departure_loads;
+pub use self::energy::{EnergyDemandFn, EnergyModule, EnergyProfile};
+
 mod groups;
 pub use self::groups::GroupModule;
 
+mod loading_order;
+pub use self::loading_order::{LoadingOrder, LoadingOrderModule};
+
+mod non_reloadable;
+pub(crate) use self::non_reloadable::mask_load;
+pub use self::non_reloadable::NonReloadableLoadModule;
+
+mod overlap;
+pub use self::overlap::{create_overlap_objective, LocationPointFn};
+
+mod penalties;
+pub use self::penalties::UnassignedPenaltyModule;
+
+mod physical;
+pub use self::physical::{ItemAttributes, PhysicalModule, VehiclePhysicalLimits};
+
+mod placement;
+pub use self::placement::PlacementModule;
+
+mod relays;
+pub use self::relays::RelayModule;
+
 mod reloads;
 pub use self::reloads::*;
 
 mod reachable;
 pub use self::reachable::ReachableModule;
 
+mod ride_duration;
+pub use self::ride_duration::RideDurationModule;
+
+mod precedence;
+pub use self::precedence::PrecedenceModule;
+
 mod skills;
 pub use self::skills::JobSkills;
 pub use self::skills::SkillsModule;
+
+mod sync;
+pub use self::sync::{JobSync, SyncModule};
+
+mod territories;
+pub use self::territories::{JobTerritory, TerritoryModule};
+
+mod trailer;
+pub use self::trailer::TrailerModule;
+
+mod unloading;
+pub use self::unloading::UnloadingModule;
+
+mod visit_gap;
+pub use self::visit_gap::VisitGapModule;
+
+mod zones;
+pub use self::zones::ZoneSequenceModule;
@@ -0,0 +1,144 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/non_reloadable_test.rs"]
+mod non_reloadable_test;
+
+use crate::constraints::*;
+use crate::extensions::JobTie;
+use std::slice::Iter;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::*;
+use vrp_core::models::problem::Job;
+use vrp_core::models::solution::Tour;
+
+/// A module which keeps load of non-reloadable dimensions within vehicle capacity for the whole tour:
+/// goods in such dimensions cannot be loaded or unloaded at reload, so all static deliveries are
+/// loaded at tour start and all static pickups stay in the vehicle till tour end. Tours without
+/// reloads are ignored as capacity module already checks them in the same way.
+pub struct NonReloadableLoadModule {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+    dimensions: Vec<usize>,
+}
+
+impl NonReloadableLoadModule {
+    /// Creates a new instance of `NonReloadableLoadModule` for given dimension indices.
+    pub fn new(dimensions: Vec<usize>, code: i32) -> Self {
+        Self {
+            state_keys: vec![NON_RELOADABLE_MAX_PAST_KEY, NON_RELOADABLE_MAX_FUTURE_KEY],
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(NonReloadableHardActivityConstraint {
+                code,
+                dimensions: dimensions.clone(),
+            }))],
+            dimensions,
+        }
+    }
+}
+
+impl ConstraintModule for NonReloadableLoadModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        if !has_reloads(&ctx.route.tour) {
+            return;
+        }
+
+        let (route, state) = ctx.as_mut();
+        let dimensions = self.dimensions.as_slice();
+
+        let start = route
+            .tour
+            .all_activities()
+            .filter_map(|activity| get_demand(activity.job.as_ref()?))
+            .fold(MultiDimLoad::default(), |acc, demand| acc + mask_load(demand.delivery.0, dimensions));
+
+        let current = route.tour.all_activities().fold((start, start), |(current, max), activity| {
+            let change = activity
+                .job
+                .as_ref()
+                .and_then(get_demand)
+                .map_or_else(MultiDimLoad::default, |demand| mask_load(demand.change(), dimensions));
+
+            let current = current + change;
+            let max = max.max_load(current);
+
+            state.put_activity_state(NON_RELOADABLE_MAX_PAST_KEY, activity, max);
+
+            (current, max)
+        });
+
+        route.tour.all_activities().rev().fold(current.0, |max, activity| {
+            let max =
+                max.max_load(*state.get_activity_state::<MultiDimLoad>(NON_RELOADABLE_MAX_PAST_KEY, activity).unwrap());
+            state.put_activity_state(NON_RELOADABLE_MAX_FUTURE_KEY, activity, max);
+            max
+        });
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        })
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Checks that insertion does not exceed capacity in non-reloadable dimensions.
+struct NonReloadableHardActivityConstraint {
+    code: i32,
+    dimensions: Vec<usize>,
+}
+
+impl HardActivityConstraint for NonReloadableHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let demand = activity_ctx.target.job.as_ref().and_then(get_demand)?;
+        let capacity: &MultiDimLoad = route_ctx.route.actor.vehicle.dimens.get_capacity()?;
+
+        let state = &route_ctx.state;
+        let max_past = state.get_activity_state::<MultiDimLoad>(NON_RELOADABLE_MAX_PAST_KEY, activity_ctx.prev)?;
+        let max_future = state.get_activity_state::<MultiDimLoad>(NON_RELOADABLE_MAX_FUTURE_KEY, activity_ctx.prev)?;
+
+        // NOTE static delivery increases load before the activity, pickup increases it after
+        let delivery = mask_load(demand.delivery.0, self.dimensions.as_slice());
+        let pickup = mask_load(demand.pickup.0 + demand.pickup.1, self.dimensions.as_slice());
+
+        if capacity.can_fit(&(*max_past + delivery)) && capacity.can_fit(&(*max_future + pickup)) {
+            None
+        } else {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        }
+    }
+}
+
+fn get_demand(single: &Arc<Single>) -> Option<&Demand<MultiDimLoad>> {
+    single.dimens.get_demand()
+}
+
+fn has_reloads(tour: &Tour) -> bool {
+    tour.jobs().any(|job| job.dimens().get_job_type().is_some_and(|job_type| job_type == "reload"))
+}
+
+/// Keeps load only in given dimensions.
+pub(crate) fn mask_load(load: MultiDimLoad, dimensions: &[usize]) -> MultiDimLoad {
+    let mut masked = MultiDimLoad { size: load.size, ..MultiDimLoad::default() };
+    dimensions.iter().filter(|&&idx| idx < load.load.len()).for_each(|&idx| masked.load[idx] = load.load[idx]);
+
+    masked
+}
@@ -0,0 +1,292 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/overlap_test.rs"]
+mod overlap_test;
+
+use crate::constraints::*;
+use std::cmp::Ordering;
+use std::slice::Iter;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{InsertionContext, RouteContext, SolutionContext};
+use vrp_core::models::common::Location;
+use vrp_core::models::problem::{Job, TargetConstraint, TargetObjective};
+use vrp_core::rosomaxa::prelude::Objective;
+use vrp_core::utils::compare_floats;
+
+/// A point on a plane.
+type Point = (f64, f64);
+
+/// A function which returns a point of location or `None` if location has no coordinates.
+pub type LocationPointFn = Arc<dyn Fn(Location) -> Option<(f64, f64)> + Send + Sync>;
+
+/// Creates _(constraint, objective)_ type pair which minimizes geometric overlap between tours.
+/// Overlap is measured as total intersection area of convex hulls of tour jobs divided by total
+/// area of these hulls.
+pub fn create_overlap_objective(point_fn: LocationPointFn, state_key: i32) -> (TargetConstraint, TargetObjective) {
+    let objective = Arc::new(OverlapObjective { point_fn, state_key });
+
+    let module = OverlapModule {
+        constraints: vec![ConstraintVariant::SoftRoute(objective.clone())],
+        objective: objective.clone(),
+        keys: vec![state_key],
+    };
+
+    (Arc::new(module), objective)
+}
+
+struct OverlapModule {
+    constraints: Vec<ConstraintVariant>,
+    objective: Arc<OverlapObjective>,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for OverlapModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let hull = self.objective.get_route_hull(ctx);
+
+        ctx.state_mut().put_route_state(self.objective.state_key, hull);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        let overlap = self.objective.get_solution_overlap(ctx);
+
+        ctx.state.insert(self.objective.state_key, Arc::new(overlap));
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct OverlapObjective {
+    point_fn: LocationPointFn,
+    state_key: i32,
+}
+
+impl OverlapObjective {
+    fn get_route_hull(&self, route_ctx: &RouteContext) -> Vec<Point> {
+        let points = route_ctx
+            .route
+            .tour
+            .all_activities()
+            .filter(|activity| activity.job.is_some())
+            .filter_map(|activity| (self.point_fn)(activity.place.location))
+            .collect();
+
+        get_convex_hull(points)
+    }
+
+    fn get_solution_overlap(&self, solution_ctx: &SolutionContext) -> f64 {
+        let hulls = solution_ctx
+            .routes
+            .iter()
+            .map(|route_ctx| match route_ctx.state.get_route_state::<Vec<Point>>(self.state_key) {
+                Some(hull) => hull.clone(),
+                None => self.get_route_hull(route_ctx),
+            })
+            .collect::<Vec<_>>();
+
+        get_hulls_overlap(hulls.as_slice())
+    }
+
+    /// Counts how many hulls of other routes contain job's locations.
+    fn count_intrusions(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext, job: &Job) -> usize {
+        let hulls = solution_ctx
+            .routes
+            .iter()
+            .filter(|other| other.route.actor != route_ctx.route.actor)
+            .filter_map(|other| other.state.get_route_state::<Vec<Point>>(self.state_key))
+            .filter(|hull| hull.len() > 2)
+            .collect::<Vec<_>>();
+
+        if hulls.is_empty() {
+            return 0;
+        }
+
+        let singles = match job {
+            Job::Single(single) => std::slice::from_ref(single),
+            Job::Multi(multi) => multi.jobs.as_slice(),
+        };
+
+        singles
+            .iter()
+            .filter_map(|single| {
+                single
+                    .places
+                    .iter()
+                    .filter_map(|place| place.location.and_then(|location| (self.point_fn)(location)))
+                    .map(|point| hulls.iter().filter(|hull| is_inside_convex_hull(point, hull)).count())
+                    .min()
+            })
+            .sum()
+    }
+}
+
+impl SoftRouteConstraint for OverlapObjective {
+    fn estimate_job(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext, job: &Job) -> f64 {
+        match self.count_intrusions(solution_ctx, route_ctx, job) {
+            0 => 0.,
+            intrusions => {
+                let other_routes = solution_ctx.routes.len().max(2) - 1;
+
+                intrusions as f64 / other_routes as f64 * solution_ctx.get_max_cost()
+            }
+        }
+    }
+}
+
+impl Objective for OverlapObjective {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .state
+            .get(&self.state_key)
+            .and_then(|s| s.downcast_ref::<f64>())
+            .cloned()
+            .unwrap_or_else(|| self.get_solution_overlap(&solution.solution))
+    }
+}
+
+/// Returns total pairwise intersection area of hulls divided by their total area.
+fn get_hulls_overlap(hulls: &[Vec<Point>]) -> f64 {
+    let total_area = hulls.iter().map(|hull| get_polygon_area(hull)).sum::<f64>();
+
+    if compare_floats(total_area, 0.) == Ordering::Equal {
+        return 0.;
+    }
+
+    let intersection_area = hulls
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, hull)| hulls.iter().skip(idx + 1).map(move |other| get_intersection_area(hull, other)))
+        .sum::<f64>();
+
+    intersection_area / total_area
+}
+
+/// Builds convex hull in counter clockwise order using monotone chain algorithm.
+fn get_convex_hull(mut points: Vec<Point>) -> Vec<Point> {
+    points.sort_by(|a, b| compare_floats(a.0, b.0).then_with(|| compare_floats(a.1, b.1)));
+    points.dedup();
+
+    if points.len() < 3 {
+        return points;
+    }
+
+    let mut lower: Vec<Point> = Vec::with_capacity(points.len());
+    points.iter().for_each(|&point| {
+        while lower.len() > 1 && cross(lower[lower.len() - 2], lower[lower.len() - 1], point) <= 0. {
+            lower.pop();
+        }
+        lower.push(point);
+    });
+
+    let mut upper: Vec<Point> = Vec::with_capacity(points.len());
+    points.iter().rev().for_each(|&point| {
+        while upper.len() > 1 && cross(upper[upper.len() - 2], upper[upper.len() - 1], point) <= 0. {
+            upper.pop();
+        }
+        upper.push(point);
+    });
+
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+
+    lower
+}
+
+/// Checks whether point is strictly inside of convex polygon given in counter clockwise order.
+fn is_inside_convex_hull(point: Point, hull: &[Point]) -> bool {
+    hull.len() > 2 && hull.iter().zip(hull.iter().cycle().skip(1)).all(|(&a, &b)| cross(a, b, point) > 0.)
+}
+
+/// Calculates intersection area of two convex polygons using Sutherland-Hodgman clipping.
+fn get_intersection_area(subject: &[Point], clip: &[Point]) -> f64 {
+    if subject.len() < 3 || clip.len() < 3 || !is_bbox_intersects(subject, clip) {
+        return 0.;
+    }
+
+    let intersection = clip.iter().zip(clip.iter().cycle().skip(1)).try_fold(subject.to_vec(), |polygon, (&a, &b)| {
+        let clipped = polygon
+            .iter()
+            .zip(polygon.iter().cycle().skip(1))
+            .flat_map(|(&current, &next)| {
+                let is_current_inside = cross(a, b, current) >= 0.;
+                let is_next_inside = cross(a, b, next) >= 0.;
+
+                match (is_current_inside, is_next_inside) {
+                    (true, true) => vec![next],
+                    (true, false) => vec![get_line_intersection(current, next, a, b)],
+                    (false, true) => vec![get_line_intersection(current, next, a, b), next],
+                    (false, false) => vec![],
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if clipped.len() < 3 {
+            Err(())
+        } else {
+            Ok(clipped)
+        }
+    });
+
+    intersection.map_or(0., |polygon| get_polygon_area(polygon.as_slice()))
+}
+
+fn is_bbox_intersects(a: &[Point], b: &[Point]) -> bool {
+    let get_bbox = |points: &[Point]| {
+        points.iter().fold((f64::MAX, f64::MAX, f64::MIN, f64::MIN), |(min_x, min_y, max_x, max_y), &(x, y)| {
+            (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+        })
+    };
+
+    let (a_min_x, a_min_y, a_max_x, a_max_y) = get_bbox(a);
+    let (b_min_x, b_min_y, b_max_x, b_max_y) = get_bbox(b);
+
+    a_min_x < b_max_x && b_min_x < a_max_x && a_min_y < b_max_y && b_min_y < a_max_y
+}
+
+/// Calculates polygon area using shoelace formula.
+fn get_polygon_area(polygon: &[Point]) -> f64 {
+    if polygon.len() < 3 {
+        return 0.;
+    }
+
+    let double_area =
+        polygon.iter().zip(polygon.iter().cycle().skip(1)).map(|(&(x1, y1), &(x2, y2))| x1 * y2 - x2 * y1).sum::<f64>();
+
+    double_area.abs() / 2.
+}
+
+/// Returns intersection point of segment `(p1, p2)` with a line going through `a` and `b`.
+fn get_line_intersection(p1: Point, p2: Point, a: Point, b: Point) -> Point {
+    let c1 = cross(a, b, p1);
+    let c2 = cross(a, b, p2);
+    let ratio = c1 / (c1 - c2);
+
+    (p1.0 + (p2.0 - p1.0) * ratio, p1.1 + (p2.1 - p1.1) * ratio)
+}
+
+/// Returns cross product of vectors `(o, a)` and `(o, b)`: it is positive when `b` is on the left
+/// side of `(o, a)`.
+fn cross(o: Point, a: Point, b: Point) -> f64 {
+    (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/penalties_test.rs"]
+mod penalties_test;
+
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::problem::Job;
+use vrp_core::solver::objectives::UnassignedPenaltyDimension;
+
+/// An unassigned penalty module biases insertion towards jobs with higher unassignment penalty.
+/// Penalty is considered only by the objective, so without the bias insertion heuristics prefer
+/// cheaper jobs and the search rarely swaps them with more important ones when capacity is scarce.
+pub struct UnassignedPenaltyModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl Default for UnassignedPenaltyModule {
+    fn default() -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::SoftRoute(Arc::new(UnassignedPenaltySoftRouteConstraint {}))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for UnassignedPenaltyModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Rewards insertion of jobs with penalty above default one and penalizes the rest.
+struct UnassignedPenaltySoftRouteConstraint {}
+
+impl SoftRouteConstraint for UnassignedPenaltySoftRouteConstraint {
+    fn estimate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> f64 {
+        job.dimens()
+            .get_unassigned_penalty()
+            .map_or(0., |penalty| (1. - penalty) * ctx.route.actor.vehicle.costs.fixed.max(1000.))
+    }
+}
@@ -0,0 +1,123 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/physical_test.rs"]
+mod physical_test;
+
+use crate::extensions::{JobTie, VehicleTie};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::problem::Job;
+
+/// Physical attributes of job's items: the heaviest item weight and the biggest item dimensions.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ItemAttributes {
+    /// A weight of the heaviest item.
+    pub weight: Option<f64>,
+    /// A length of the longest item.
+    pub length: Option<f64>,
+    /// A height of the highest item.
+    pub height: Option<f64>,
+}
+
+/// Physical limits of vehicle: items which exceed them cannot be transported by the vehicle.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VehiclePhysicalLimits {
+    /// A maximum weight which can be put on a single axle.
+    pub max_axle_weight: Option<f64>,
+    /// A maximum item length.
+    pub max_item_length: Option<f64>,
+    /// A maximum item height.
+    pub max_item_height: Option<f64>,
+}
+
+impl VehiclePhysicalLimits {
+    /// Checks whether items with given attributes can be transported by the vehicle.
+    pub fn can_carry(&self, item: &ItemAttributes) -> bool {
+        let fits = |value: Option<f64>, limit: Option<f64>| match (value, limit) {
+            (Some(value), Some(limit)) => value <= limit,
+            _ => true,
+        };
+
+        fits(item.weight, self.max_axle_weight)
+            && fits(item.length, self.max_item_length)
+            && fits(item.height, self.max_item_height)
+    }
+}
+
+/// A physical module restricts job assignment to vehicles which can carry job's items considering
+/// their weight and dimensions.
+pub struct PhysicalModule {
+    code: i32,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl PhysicalModule {
+    /// Creates a new instance of `PhysicalModule`.
+    pub fn new(code: i32) -> Self {
+        Self {
+            code,
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(PhysicalHardRouteConstraint { code }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for PhysicalModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        // NOTE merged job keeps source's attributes, so candidate's items should not exceed them
+        let is_covered = |value: Option<f64>, source: Option<f64>| match (value, source) {
+            (Some(value), Some(source)) => value <= source,
+            (Some(_), None) => false,
+            (None, _) => true,
+        };
+
+        let is_mergeable = match (source.dimens().get_job_item(), candidate.dimens().get_job_item()) {
+            (_, None) => true,
+            (None, Some(_)) => false,
+            (Some(source), Some(candidate)) => {
+                is_covered(candidate.weight, source.weight)
+                    && is_covered(candidate.length, source.length)
+                    && is_covered(candidate.height, source.height)
+            }
+        };
+
+        if is_mergeable {
+            Ok(source)
+        } else {
+            Err(self.code)
+        }
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct PhysicalHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for PhysicalHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        let item = job.dimens().get_job_item()?;
+        let limits = ctx.route.actor.vehicle.dimens.get_vehicle_physical_limits()?;
+
+        if limits.can_carry(item) {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
@@ -0,0 +1,97 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/placement_test.rs"]
+mod placement_test;
+
+use crate::extensions::JobTie;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::problem::Job;
+use vrp_core::models::solution::Activity;
+
+/// A placement module restricts relative delivery order of items inside a trip: items placed closer
+/// to the rear door (lower placement rank) have to be delivered before items placed at the front.
+pub struct PlacementModule {
+    code: i32,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl PlacementModule {
+    /// Creates a new instance of `PlacementModule`.
+    pub fn new(code: i32) -> Self {
+        Self {
+            code,
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(PlacementHardActivityConstraint { code }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for PlacementModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        if get_job_placement(&source) == get_job_placement(&candidate) {
+            Ok(source)
+        } else {
+            Err(self.code)
+        }
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct PlacementHardActivityConstraint {
+    code: i32,
+}
+
+impl HardActivityConstraint for PlacementHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let placement = get_placement(activity_ctx.target)?;
+        let tour = &route_ctx.route.tour;
+
+        // NOTE vehicle is loaded again at reload, so order is checked only within the same trip
+        let prev_trip = tour.all_activities().take(activity_ctx.index + 1).rev().take_while(|a| !is_reload(a));
+        let next_trip = tour.all_activities().skip(activity_ctx.index + 1).take_while(|a| !is_reload(a));
+
+        let is_ordered = prev_trip.filter_map(get_placement).all(|prev| prev <= placement)
+            && next_trip.filter_map(get_placement).all(|next| next >= placement);
+
+        if is_ordered {
+            None
+        } else {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        }
+    }
+}
+
+fn get_placement(activity: &Activity) -> Option<usize> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_placement())
+}
+
+fn get_job_placement(job: &Job) -> Option<usize> {
+    match job {
+        Job::Single(single) => single.dimens.get_job_placement(),
+        Job::Multi(multi) => multi.jobs.iter().find_map(|single| single.dimens.get_job_placement()),
+    }
+}
+
+fn is_reload(activity: &Activity) -> bool {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_type()).is_some_and(|job_type| job_type == "reload")
+}
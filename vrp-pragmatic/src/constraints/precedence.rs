@@ -0,0 +1,457 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/precedence_test.rs"]
+mod precedence_test;
+
+use super::{eject_jobs, get_original_window};
+use crate::extensions::JobTie;
+use hashbrown::{HashMap, HashSet};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::*;
+use vrp_core::models::common::{TimeWindow, Timestamp};
+use vrp_core::models::problem::{ActivityCost, Actor, Job, Single, TransportCost, TravelTime};
+use vrp_core::models::solution::Activity;
+
+/// Keeps assigned jobs which have precedence relations by their ids.
+type PrecedenceVisits = HashMap<String, PrecedenceVisit>;
+
+/// Specifies an assigned job which has precedence relations.
+struct PrecedenceVisit {
+    actor: Arc<Actor>,
+    activity_idx: usize,
+    /// Actual service start.
+    start: Timestamp,
+    /// Actual service completion.
+    completion: Timestamp,
+}
+
+/// Keeps precedence relations between jobs in both directions.
+#[derive(Default)]
+struct PrecedenceRelations {
+    predecessors: HashMap<String, Vec<String>>,
+    successors: HashMap<String, Vec<String>>,
+}
+
+impl PrecedenceRelations {
+    fn contains(&self, job_id: &str) -> bool {
+        self.predecessors.contains_key(job_id) || self.successors.contains_key(job_id)
+    }
+
+    fn predecessors(&self, job_id: &str) -> impl Iterator<Item = &String> + '_ {
+        self.predecessors.get(job_id).into_iter().flatten()
+    }
+
+    fn successors(&self, job_id: &str) -> impl Iterator<Item = &String> + '_ {
+        self.successors.get(job_id).into_iter().flatten()
+    }
+
+    /// Returns the earliest service start and the latest completion of the job imposed by its
+    /// partners assigned to other routes.
+    fn get_bounds(&self, job_id: &str, actor: &Arc<Actor>, visits: &PrecedenceVisits) -> (Timestamp, Timestamp) {
+        let other_visits = |ids: &mut dyn Iterator<Item = &String>| {
+            ids.filter_map(|id| visits.get(id)).filter(|visit| !Arc::ptr_eq(&visit.actor, actor)).collect::<Vec<_>>()
+        };
+
+        let earliest = other_visits(&mut self.predecessors(job_id))
+            .into_iter()
+            .map(|visit| visit.completion)
+            .fold(f64::MIN, f64::max);
+        // NOTE successors which are already served too early are ignored as they are going to be ejected
+        let completion = visits.get(job_id).map_or(f64::MIN, |visit| visit.completion);
+        let latest = other_visits(&mut self.successors(job_id))
+            .into_iter()
+            .map(|visit| visit.start)
+            .filter(|start| *start + ALIGN_TOLERANCE >= completion)
+            .fold(f64::MAX, f64::min);
+
+        (earliest, latest)
+    }
+}
+
+/// Limits amount of alignment passes as rescheduling one route can affect other relations.
+const MAX_ALIGN_ITERATIONS: usize = 4;
+
+/// A tolerance used to compare service times of related activities.
+const ALIGN_TOLERANCE: f64 = 1E-3;
+
+/// A precedence module ensures that a job is completed before its successor jobs start, even when
+/// they are served by different vehicles, e.g. a pickup of materials before installation at site.
+///
+/// Assigned related jobs are kept in the solution state, so route level constraint can check
+/// feasibility against the whole solution. Similar to job synchronization, routes are coupled via
+/// activity time windows: successor's time window cannot start before its predecessors in other
+/// routes are completed and predecessor's time window cannot end after its successors in other
+/// routes are started, so the rest of insertions cannot break the relation. Within the same route,
+/// the relation is kept by activity order.
+///
+/// A successor job cannot be served without its predecessors, so predecessor insertion is never
+/// blocked by successors. If relation cannot be kept anymore, e.g. after a local search or when
+/// predecessor is unassigned, the successor job is ejected to keep solution feasible.
+pub struct PrecedenceModule {
+    code: i32,
+    state_key: i32,
+    relations: Arc<PrecedenceRelations>,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    schedule: TransportConstraintModule,
+}
+
+impl PrecedenceModule {
+    /// Creates a new instance of `PrecedenceModule`. Relations are specified as pairs of job ids
+    /// where the first job has to be completed before the second one starts.
+    pub fn new(
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        relations: Vec<(String, String)>,
+        code: i32,
+        state_key: i32,
+    ) -> Self {
+        let relations =
+            Arc::new(relations.into_iter().fold(PrecedenceRelations::default(), |mut acc, (before, after)| {
+                acc.predecessors.entry(after.clone()).or_default().push(before.clone());
+                acc.successors.entry(before).or_default().push(after);
+                acc
+            }));
+
+        Self {
+            code,
+            state_key,
+            relations: relations.clone(),
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(PrecedenceHardRouteConstraint {
+                    code,
+                    state_key,
+                    relations: relations.clone(),
+                    transport: transport.clone(),
+                })),
+                ConstraintVariant::HardActivity(Arc::new(PrecedenceHardActivityConstraint {
+                    code,
+                    state_key,
+                    relations,
+                    activity: activity.clone(),
+                    transport: transport.clone(),
+                })),
+            ],
+            keys: vec![state_key],
+            schedule: TransportConstraintModule::new(transport, activity, code),
+        }
+    }
+
+    /// Aligns related activities in different routes and updates solution and route states.
+    fn align(&self, solution_ctx: &mut SolutionContext) {
+        for _ in 0..MAX_ALIGN_ITERATIONS {
+            let visits = get_visits(solution_ctx, self.relations.as_ref());
+            let windows = solution_ctx
+                .routes
+                .iter()
+                .enumerate()
+                .flat_map(|(route_idx, route_ctx)| {
+                    let actor = &route_ctx.route.actor;
+                    let date = route_ctx.route.tour.start().map_or(0., |start| start.schedule.departure);
+                    let visits = &visits;
+
+                    route_ctx.route.tour.all_activities().enumerate().filter_map(move |(activity_idx, activity)| {
+                        let job_id = get_job_id(activity).filter(|job_id| self.relations.contains(job_id))?;
+                        let original = get_original_window(activity, date)?;
+                        let (earliest, latest) = self.relations.get_bounds(job_id, actor, visits);
+                        let service_time = visits.get(job_id).map_or(0., |visit| visit.completion - visit.start);
+
+                        let window = TimeWindow::new(earliest, latest - service_time);
+                        let window = if window.start <= window.end { original.overlapping(&window) } else { None }
+                            .unwrap_or(original);
+
+                        if window == activity.place.time {
+                            None
+                        } else {
+                            Some((route_idx, activity_idx, window))
+                        }
+                    })
+                })
+                .fold(HashMap::<usize, Vec<_>>::default(), |mut acc, (route_idx, activity_idx, window)| {
+                    acc.entry(route_idx).or_default().push((activity_idx, window));
+                    acc
+                });
+
+            if windows.is_empty() {
+                break;
+            }
+
+            windows.into_iter().for_each(|(route_idx, windows)| {
+                let route_ctx = solution_ctx.routes.get_mut(route_idx).expect("invalid route index");
+
+                let tour = &mut route_ctx.route_mut().tour;
+                windows.into_iter().for_each(|(activity_idx, window)| {
+                    tour.get_mut(activity_idx).expect("invalid activity index").place.time = window;
+                });
+
+                self.schedule.accept_route_state(route_ctx);
+            });
+        }
+
+        let visits: StateValue = Arc::new(get_visits(solution_ctx, self.relations.as_ref()));
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            route_ctx.state_mut().put_route_state_raw(self.state_key, visits.clone());
+        });
+        solution_ctx.state.insert(self.state_key, visits);
+    }
+
+    /// Ejects successor jobs which are served before their predecessors are completed or whose
+    /// predecessors cannot be assigned.
+    fn eject_broken_relations(&self, solution_ctx: &mut SolutionContext) {
+        let visits = get_visits(solution_ctx, self.relations.as_ref());
+        let pending = solution_ctx
+            .required
+            .iter()
+            .filter_map(|job| job.dimens().get_job_id())
+            .filter(|job_id| self.relations.contains(job_id))
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        let broken = visits
+            .iter()
+            .filter(|(job_id, visit)| {
+                self.relations.predecessors(job_id).any(|id| match visits.get(id) {
+                    Some(predecessor) if Arc::ptr_eq(&predecessor.actor, &visit.actor) => {
+                        predecessor.activity_idx > visit.activity_idx
+                    }
+                    Some(predecessor) => visit.start + ALIGN_TOLERANCE < predecessor.completion,
+                    None => !pending.contains(id),
+                })
+            })
+            .map(|(job_id, _)| job_id.clone())
+            .collect::<HashSet<_>>();
+
+        if broken.is_empty() {
+            return;
+        }
+
+        let ejected = eject_jobs(solution_ctx, |_, route_ctx| {
+            route_ctx
+                .route
+                .tour
+                .jobs()
+                .filter(|job| job.dimens().get_job_id().is_some_and(|job_id| broken.contains(job_id)))
+                .collect()
+        });
+
+        solution_ctx.unassigned.extend(ejected.into_iter().map(|job| (job, UnassignmentInfo::Simple(self.code))));
+    }
+}
+
+impl ConstraintModule for PrecedenceModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        let route_ctx = solution_ctx.routes.get(route_index).unwrap();
+        let has_relations = route_ctx
+            .route
+            .tour
+            .all_activities()
+            .any(|activity| get_job_id(activity).is_some_and(|job_id| self.relations.contains(job_id)));
+
+        if has_relations {
+            self.align(solution_ctx);
+        } else if let Some(visits) = solution_ctx.state.get(&self.state_key).cloned() {
+            // NOTE a new route has no state yet, so share the current one
+            let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+            route_ctx.state_mut().put_route_state_raw(self.state_key, visits);
+        }
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        self.align(ctx);
+        self.eject_broken_relations(ctx);
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        let has_relations = |job: &Job| job.dimens().get_job_id().is_some_and(|job_id| self.relations.contains(job_id));
+
+        if has_relations(&source) || has_relations(&candidate) {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct PrecedenceHardRouteConstraint {
+    code: i32,
+    state_key: i32,
+    relations: Arc<PrecedenceRelations>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl PrecedenceHardRouteConstraint {
+    /// Checks whether the job can be served by an empty route not earlier than given time.
+    fn can_serve_in_new_route(&self, route_ctx: &RouteContext, single: &Single, earliest: Timestamp) -> bool {
+        let start = if let Some(start) = route_ctx.route.tour.start() { start } else { return false };
+
+        let route = route_ctx.route.as_ref();
+        let end = route_ctx.route.tour.end();
+        let departure = start.schedule.departure;
+
+        // NOTE all time windows reachable by the vehicle should respect bounds as any of them can be used
+        single.places.iter().all(|place| {
+            let location = place.location.unwrap_or(start.place.location);
+            let arrival = departure
+                + self.transport.duration(route, start.place.location, location, TravelTime::Departure(departure));
+
+            place.times.iter().map(|time| time.to_time_window(departure)).filter(|time| arrival <= time.end).all(
+                |time| {
+                    let service_start = arrival.max(time.start).max(earliest);
+                    let completion = service_start + place.duration;
+                    let is_end_reachable = match end {
+                        Some(end) => {
+                            let duration = self.transport.duration(
+                                route,
+                                location,
+                                end.place.location,
+                                TravelTime::Departure(completion),
+                            );
+
+                            completion + duration <= end.place.latest_start()
+                        }
+                        None => true,
+                    };
+
+                    service_start <= time.end && is_end_reachable
+                },
+            )
+        })
+    }
+}
+
+impl HardRouteConstraint for PrecedenceHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        let single = job.as_single()?;
+        let job_id = single.dimens.get_job_id().filter(|job_id| self.relations.contains(job_id))?;
+        let visits = solution_ctx.state.get(&self.state_key).and_then(|s| s.downcast_ref::<PrecedenceVisits>())?;
+
+        // NOTE a job which is not assigned yet is limited only by its predecessors
+        let (earliest, _) = self.relations.get_bounds(job_id, &route_ctx.route.actor, visits);
+
+        if route_ctx.route.tour.has_jobs() || self.can_serve_in_new_route(route_ctx, single, earliest) {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
+
+struct PrecedenceHardActivityConstraint {
+    code: i32,
+    state_key: i32,
+    relations: Arc<PrecedenceRelations>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl PrecedenceHardActivityConstraint {
+    /// Checks whether related jobs in the same route are kept in the right order.
+    fn is_order_violated(&self, job_id: &str, actor: &Arc<Actor>, index: usize, visits: &PrecedenceVisits) -> bool {
+        let same_route_visits = |ids: &mut dyn Iterator<Item = &String>| -> Vec<usize> {
+            ids.filter_map(|id| visits.get(id))
+                .filter(|visit| Arc::ptr_eq(&visit.actor, actor))
+                .map(|visit| visit.activity_idx)
+                .collect()
+        };
+
+        same_route_visits(&mut self.relations.predecessors(job_id)).into_iter().any(|idx| idx > index)
+            || same_route_visits(&mut self.relations.successors(job_id)).into_iter().any(|idx| idx <= index)
+    }
+}
+
+impl HardActivityConstraint for PrecedenceHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let job_id = get_job_id(activity_ctx.target).filter(|job_id| self.relations.contains(job_id))?;
+        let visits = route_ctx.state.get_route_state::<PrecedenceVisits>(self.state_key)?;
+
+        let route = route_ctx.route.as_ref();
+        if self.is_order_violated(job_id, &route.actor, activity_ctx.index, visits) {
+            return Some(ActivityConstraintViolation { code: self.code, stopped: false });
+        }
+
+        let (earliest, _) = self.relations.get_bounds(job_id, &route.actor, visits);
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let departure = prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+
+        // NOTE vehicle has to wait for predecessors in other routes if it arrives earlier
+        let service_start = arrival.max(target.place.time.start);
+        let forced_start = service_start.max(earliest);
+        let completion = self.activity.estimate_departure(route, target, forced_start);
+
+        let is_next_violated = forced_start > service_start
+            && activity_ctx.next.is_some_and(|next| {
+                let arrival = completion
+                    + self.transport.duration(
+                        route,
+                        target.place.location,
+                        next.place.location,
+                        TravelTime::Departure(completion),
+                    );
+                let latest_arrival =
+                    *route_ctx.state.get_activity_state(LATEST_ARRIVAL_KEY, next).unwrap_or(&next.place.latest_start());
+
+                arrival > latest_arrival
+            });
+
+        if forced_start > target.place.time.end || is_next_violated {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+fn get_job_id(activity: &Activity) -> Option<&String> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_id())
+}
+
+fn get_visits(solution_ctx: &SolutionContext, relations: &PrecedenceRelations) -> PrecedenceVisits {
+    solution_ctx.routes.iter().fold(PrecedenceVisits::default(), |acc, route_ctx| {
+        let route = route_ctx.route.as_ref();
+
+        route.tour.all_activities().enumerate().fold(acc, |mut acc, (activity_idx, activity)| {
+            if let Some(job_id) = get_job_id(activity).filter(|job_id| relations.contains(job_id)) {
+                acc.insert(
+                    job_id.clone(),
+                    PrecedenceVisit {
+                        actor: route.actor.clone(),
+                        activity_idx,
+                        start: activity.schedule.arrival.max(activity.place.time.start),
+                        completion: activity.schedule.departure,
+                    },
+                );
+            }
+
+            acc
+        })
+    })
+}
@@ -0,0 +1,289 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/relays_test.rs"]
+mod relays_test;
+
+use crate::constraints::*;
+use crate::extensions::{JobTie, VehicleTie};
+use std::iter::once;
+use std::slice::Iter;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Duration, Timestamp};
+use vrp_core::models::problem::{Job, Single, TransportCost, TravelTime};
+use vrp_core::models::solution::Tour;
+
+/// A fraction of driver shift time: when a driver works longer, relay jobs are promoted to
+/// required ones.
+const RELAY_THRESHOLD: f64 = 0.5;
+
+/// A relay module splits vehicle tour into driver shifts at relay places, where driver is changed
+/// while vehicle continues its tour. Working time of each driver is limited separately from vehicle
+/// usage: it starts at tour start or relay departure and ends at arrival to the next relay or at
+/// tour end. Driver change duration is not counted as working time of any driver.
+pub struct RelayModule {
+    code: i32,
+    state_key: i32,
+    conditional: ConditionalJobModule,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl RelayModule {
+    /// Creates a new instance of `RelayModule`.
+    pub fn new(transport: Arc<dyn TransportCost + Send + Sync>, code: i32, state_key: i32) -> Self {
+        Self {
+            code,
+            state_key,
+            conditional: ConditionalJobModule::new(create_job_transition(state_key)),
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(RelayHardRouteConstraint { code })),
+                ConstraintVariant::HardActivity(Arc::new(RelayHardActivityConstraint { code, transport })),
+                ConstraintVariant::SoftRoute(Arc::new(RelaySoftRouteConstraint {})),
+            ],
+            keys: vec![state_key],
+        }
+    }
+
+    fn update_route(&self, route_ctx: &mut RouteContext) {
+        if route_ctx.route.actor.vehicle.dimens.get_driver_shift_time().is_none() {
+            return;
+        }
+
+        let max_duration = get_driver_shifts(&route_ctx.route.tour)
+            .into_iter()
+            .map(|(start, end)| get_driver_shift_duration(&route_ctx.route.tour, start, end))
+            .fold(0., f64::max);
+
+        route_ctx.state_mut().put_route_state(self.state_key, max_duration);
+    }
+
+    fn remove_obsolete_relays(&self, solution_ctx: &mut SolutionContext) {
+        let mut extra_ignored = Vec::new();
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            let shift_time = match route_ctx.route.actor.vehicle.dimens.get_driver_shift_time() {
+                Some(shift_time) => shift_time,
+                None => return,
+            };
+
+            let tour = &route_ctx.route.tour;
+            let shifts = get_driver_shifts(tour);
+
+            // NOTE merged duration excludes driver change only, travel detour to relay place is kept
+            let obsolete_idx = shifts.windows(2).find_map(|shifts| match shifts {
+                [(start, relay_idx), (_, end)] => {
+                    let relay = tour.get(*relay_idx)?;
+                    let duration = get_driver_shift_duration(tour, *start, *end)
+                        - (relay.schedule.departure - relay.schedule.arrival);
+
+                    if duration <= shift_time * RELAY_THRESHOLD {
+                        Some(*relay_idx)
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            });
+
+            if let Some(obsolete_idx) = obsolete_idx {
+                // NOTE: we remove only one relay per tour, state update should be handled externally
+                extra_ignored.push(route_ctx.route_mut().tour.remove_activity_at(obsolete_idx));
+            }
+        });
+
+        solution_ctx.ignored.extend(extra_ignored);
+    }
+}
+
+impl ConstraintModule for RelayModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        self.update_route(solution_ctx.routes.get_mut(route_index).unwrap());
+        self.conditional.accept_insertion(solution_ctx, route_index, job);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        self.update_route(ctx);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.update_route(route_ctx);
+        });
+
+        self.conditional.accept_solution_state(ctx);
+        self.remove_obsolete_relays(ctx);
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        if once(&source).chain(once(&candidate)).any(is_relay_job) {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Locks relay jobs to specific vehicles.
+struct RelayHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for RelayHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        match job.as_single() {
+            Some(single) if is_relay_single(single) && !is_single_belongs_to_route(ctx, single) => {
+                Some(RouteConstraintViolation { code: self.code })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Checks that working time of each driver does not exceed driver shift time.
+struct RelayHardActivityConstraint {
+    code: i32,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardActivityConstraint for RelayHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let shift_time = route_ctx.route.actor.vehicle.dimens.get_driver_shift_time()?;
+        let tour = &route_ctx.route.tour;
+
+        let (start_idx, end_idx) =
+            get_driver_shifts(tour).into_iter().rev().find(|(start, _)| *start <= activity_ctx.index)?;
+        let start_time = tour.get(start_idx)?.schedule.departure;
+
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let route = route_ctx.route.as_ref();
+
+        let prev_departure = prev.schedule.departure;
+        let target_arrival = prev_departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(prev_departure),
+            );
+        let target_departure = target_arrival.max(target.place.time.start) + target.place.duration;
+
+        // NOTE schedule shift of the next activities is not absorbed by waiting time here
+        let end_time = match activity_ctx.next {
+            Some(next) => {
+                let next_arrival = target_departure
+                    + self.transport.duration(
+                        route,
+                        target.place.location,
+                        next.place.location,
+                        TravelTime::Departure(target_departure),
+                    );
+                let shift = (next_arrival - next.schedule.arrival).max(0.);
+
+                get_driver_shift_end(tour, end_idx) + shift
+            }
+            None => target_departure,
+        };
+
+        let durations = if is_relay_activity(target) {
+            vec![target_arrival - start_time, end_time - target_departure]
+        } else {
+            vec![end_time - start_time]
+        };
+
+        if durations.into_iter().all(|duration| duration <= shift_time) {
+            None
+        } else {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        }
+    }
+}
+
+/// Encourages insertion of promoted relay jobs.
+struct RelaySoftRouteConstraint {}
+
+impl SoftRouteConstraint for RelaySoftRouteConstraint {
+    fn estimate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> f64 {
+        if is_relay_job(job) {
+            0. - ctx.route.actor.vehicle.costs.fixed.max(1000.)
+        } else {
+            0.
+        }
+    }
+}
+
+fn create_job_transition(state_key: i32) -> Box<dyn JobContextTransition + Send + Sync> {
+    Box::new(ConcreteJobContextTransition {
+        remove_required: move |solution_ctx, _, job| {
+            is_relay_job(job) && !is_relay_needed(solution_ctx, job, state_key)
+        },
+        promote_required: move |solution_ctx, _, job| {
+            is_relay_job(job) && is_relay_needed(solution_ctx, job, state_key)
+        },
+        remove_locked: |_, _, _| false,
+        promote_locked: |_, _, job| is_relay_job(job),
+    })
+}
+
+/// Checks whether route of relay job's vehicle has a driver shift longer than relay threshold.
+fn is_relay_needed(solution_ctx: &SolutionContext, job: &Job, state_key: i32) -> bool {
+    job.as_single()
+        .and_then(|single| solution_ctx.routes.iter().find(|route_ctx| is_single_belongs_to_route(route_ctx, single)))
+        .and_then(|route_ctx| {
+            let shift_time = route_ctx.route.actor.vehicle.dimens.get_driver_shift_time()?;
+            let max_duration = route_ctx.state.get_route_state::<Duration>(state_key)?;
+
+            Some(*max_duration > shift_time * RELAY_THRESHOLD)
+        })
+        .unwrap_or(false)
+}
+
+/// Returns index ranges of driver shifts: from tour start or relay to the next relay or tour end.
+fn get_driver_shifts(tour: &Tour) -> Vec<(usize, usize)> {
+    let last_idx = tour.total().max(1) - 1;
+
+    tour.all_activities().enumerate().skip(1).filter(|(_, activity)| is_relay_activity(activity)).fold(
+        vec![(0, last_idx)],
+        |mut acc, (idx, _)| {
+            if let Some(last) = acc.last_mut() {
+                last.1 = idx;
+            }
+            acc.push((idx, last_idx));
+
+            acc
+        },
+    )
+}
+
+fn get_driver_shift_duration(tour: &Tour, start_idx: usize, end_idx: usize) -> Duration {
+    tour.get(start_idx).map_or(0., |start| get_driver_shift_end(tour, end_idx) - start.schedule.departure)
+}
+
+/// Returns end time of driver shift: arrival at relay or departure from the last activity.
+fn get_driver_shift_end(tour: &Tour, end_idx: usize) -> Timestamp {
+    tour.get(end_idx)
+        .map_or(0., |end| if is_relay_activity(end) { end.schedule.arrival } else { end.schedule.departure })
+}
+
+fn is_relay_job(job: &Job) -> bool {
+    job.as_single().is_some_and(|single| is_relay_single(single))
+}
+
+fn is_relay_single(single: &Single) -> bool {
+    single.dimens.get_job_type().is_some_and(|t| t == "relay")
+}
+
+fn is_relay_activity(activity: &Activity) -> bool {
+    activity.job.as_ref().is_some_and(|single| is_relay_single(single))
+}
@@ -68,15 +68,16 @@ fn create_reload_multi_trip<T: LoadOps>(
                     let current: T =
                         route_ctx.state.get_activity_state(MAX_PAST_CAPACITY_KEY, end).cloned().unwrap_or_default();
 
-                    let max_capacity = route_ctx.route.actor.vehicle.dimens.get_capacity().unwrap();
-                    let threshold_capacity = load_schedule_threshold_fn.deref()(max_capacity);
+                    let max_capacity = get_trip_capacity(route_ctx, end);
+                    let threshold_capacity = load_schedule_threshold_fn.deref()(&max_capacity);
 
                     current.partial_cmp(&threshold_capacity) != Some(Ordering::Less)
                 })
                 .unwrap_or(false)
         }),
         is_obsolete_interval: Box::new(move |route_ctx, left, right| {
-            let capacity: T = route_ctx.route.actor.vehicle.dimens.get_capacity().cloned().unwrap_or_default();
+            // NOTE merged trip keeps capacity of the left one
+            let capacity: T = get_trip_capacity(route_ctx, route_ctx.route.tour.get(left.start).unwrap());
 
             let get_load = |activity_index: usize, state_key: i32| {
                 let activity = route_ctx.route.tour.get(activity_index).unwrap();
@@ -261,6 +262,16 @@ impl<T: Send + Sync> FixedMultiTrip<T> {
     }
 }
 
+/// Returns capacity of the trip which given activity belongs to.
+fn get_trip_capacity<T: LoadOps>(route_ctx: &RouteContext, activity: &Activity) -> T {
+    route_ctx
+        .state
+        .get_activity_state::<T>(TRIP_CAPACITY_KEY, activity)
+        .or_else(|| route_ctx.route.actor.vehicle.dimens.get_capacity())
+        .cloned()
+        .unwrap_or_default()
+}
+
 fn is_reload_single(single: &Single) -> bool {
     single.dimens.get_job_type().map_or(false, |t| t == "reload")
 }
@@ -0,0 +1,215 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/ride_duration_test.rs"]
+mod ride_duration_test;
+
+use crate::extensions::JobTie;
+use hashbrown::HashMap;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Duration, Timestamp};
+use vrp_core::models::problem::{ActivityCost, Job, TransportCost, TravelTime};
+use vrp_core::models::solution::Activity;
+
+/// A ride duration module limits time spent in vehicle by goods or passengers of pickup and delivery
+/// job: it is measured from completion of the first served pickup to start of each delivery of the job.
+///
+/// Similar to latest arrival time, the module keeps in activity state the latest arrival time which
+/// doesn't violate max ride duration of jobs picked up before the activity and delivered after it.
+/// This allows to evaluate an insertion between pickup and delivery without rescheduling the tour.
+pub struct RideDurationModule {
+    code: i32,
+    state_key: i32,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl RideDurationModule {
+    /// Creates a new instance of `RideDurationModule`.
+    pub fn new(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        code: i32,
+        state_key: i32,
+    ) -> Self {
+        Self {
+            code,
+            state_key,
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(RideDurationHardActivityConstraint {
+                code,
+                state_key,
+                activity: activity.clone(),
+                transport: transport.clone(),
+            }))],
+            keys: vec![state_key],
+            activity,
+            transport,
+        }
+    }
+
+    fn update_route_state(&self, route_ctx: &mut RouteContext) {
+        let route = route_ctx.route.clone();
+        let tour = &route.tour;
+
+        // NOTE ride starts at departure from the first served pickup of the job
+        let mut rides = HashMap::<Job, (usize, Timestamp)>::default();
+        tour.all_activities().enumerate().filter(|(_, activity)| is_activity_type(activity, "pickup")).for_each(
+            |(idx, activity)| {
+                if let Some((job, _)) = get_ride_duration(activity) {
+                    rides.entry(job).or_insert((idx, activity.schedule.departure));
+                }
+            },
+        );
+
+        // NOTE propagate ride deadlines backwards: each entry keeps pickup index and latest arrival
+        // time at currently processed activity which respects deadline of the job's delivery
+        let state = route_ctx.state_mut();
+        let mut open_rides: Vec<(usize, Timestamp)> = Vec::new();
+        let mut next: Option<&Activity> = None;
+
+        tour.all_activities().enumerate().rev().for_each(|(idx, activity)| {
+            if let Some(next) = next {
+                open_rides.iter_mut().for_each(|(_, latest_arrival)| {
+                    let travel_time = TravelTime::Arrival(*latest_arrival);
+                    let latest_departure = *latest_arrival
+                        - self.transport.duration(&route, activity.place.location, next.place.location, travel_time);
+                    *latest_arrival = self.activity.estimate_arrival(&route, activity, latest_departure);
+                });
+            }
+            next = Some(activity);
+
+            if is_activity_type(activity, "delivery") {
+                if let Some((pickup_idx, deadline)) = get_ride_duration(activity).and_then(|(job, max_duration)| {
+                    rides.get(&job).map(|(idx, departure)| (*idx, departure + max_duration))
+                }) {
+                    open_rides.push((pickup_idx, deadline));
+                }
+            }
+
+            // NOTE insertion before pickup shifts the pickup too, so its ride is not affected
+            open_rides.retain(|(pickup_idx, _)| *pickup_idx < idx);
+
+            if activity.job.is_some() {
+                let latest_arrival =
+                    open_rides.iter().map(|(_, latest_arrival)| *latest_arrival).fold(f64::MAX, f64::min);
+                state.put_activity_state(self.state_key, activity, latest_arrival);
+            }
+        });
+    }
+}
+
+impl ConstraintModule for RideDurationModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        self.update_route_state(ctx);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.update_route_state(route_ctx);
+        });
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        match (source.dimens().get_job_max_ride_duration(), candidate.dimens().get_job_max_ride_duration()) {
+            (None, None) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct RideDurationHardActivityConstraint {
+    code: i32,
+    state_key: i32,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardActivityConstraint for RideDurationHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let next = activity_ctx.next.and_then(|next| {
+            route_ctx
+                .state
+                .get_activity_state::<Timestamp>(self.state_key, next)
+                .filter(|latest_arrival| **latest_arrival < f64::MAX)
+                .map(|latest_arrival| (next, *latest_arrival))
+        });
+        let target_ride =
+            get_ride_duration(activity_ctx.target).filter(|_| is_activity_type(activity_ctx.target, "delivery"));
+
+        if next.is_none() && target_ride.is_none() {
+            return None;
+        }
+
+        let route = route_ctx.route.as_ref();
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let departure = prev.schedule.departure;
+        let arrival_at_target = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+
+        let is_target_violated = target_ride.is_some_and(|(job, max_duration)| {
+            route
+                .tour
+                .all_activities()
+                .take(activity_ctx.index + 1)
+                .filter(|activity| is_activity_type(activity, "pickup"))
+                .filter(|activity| activity.retrieve_job().is_some_and(|other| other == job))
+                .map(|activity| activity.schedule.departure)
+                .reduce(f64::min)
+                .is_some_and(|pickup_departure| {
+                    arrival_at_target.max(target.place.time.start) - pickup_departure > max_duration
+                })
+        });
+
+        let is_next_violated = next.is_some_and(|(next, latest_arrival)| {
+            let departure = self.activity.estimate_departure(route, target, arrival_at_target);
+            let arrival_at_next = departure
+                + self.transport.duration(
+                    route,
+                    target.place.location,
+                    next.place.location,
+                    TravelTime::Departure(departure),
+                );
+
+            arrival_at_next > latest_arrival
+        });
+
+        if is_target_violated || is_next_violated {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+fn get_ride_duration(activity: &Activity) -> Option<(Job, Duration)> {
+    activity.retrieve_job().and_then(|job| job.dimens().get_job_max_ride_duration().map(|duration| (job, duration)))
+}
+
+fn is_activity_type(activity: &Activity, activity_type: &str) -> bool {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_type()).is_some_and(|t| t == activity_type)
+}
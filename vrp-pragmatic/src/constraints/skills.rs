@@ -3,7 +3,7 @@
 mod skills_test;
 
 use crate::extensions::{JobTie, VehicleTie};
-use hashbrown::HashSet;
+use hashbrown::{HashMap, HashSet};
 use std::slice::Iter;
 use std::sync::Arc;
 use vrp_core::construction::constraints::*;
@@ -18,6 +18,23 @@ pub struct JobSkills {
     pub one_of: Option<HashSet<String>>,
     /// Vehicle should have none of these skills defined.
     pub none_of: Option<HashSet<String>>,
+    /// Vehicle is preferred to have these skills: a penalty is applied for each missing one.
+    pub preferred: Option<HashMap<String, f64>>,
+    /// Vehicle should provide at least given amount of units of these skills.
+    pub counts: Option<HashMap<String, usize>>,
+}
+
+impl JobSkills {
+    /// Returns a total penalty of preferred skills which are missing in given vehicle skills.
+    pub fn get_penalty(&self, vehicle_skills: Option<&HashSet<String>>) -> f64 {
+        self.preferred.as_ref().map_or(0., |preferred| {
+            preferred
+                .iter()
+                .filter(|(skill, _)| !vehicle_skills.is_some_and(|vehicle_skills| vehicle_skills.contains(*skill)))
+                .map(|(_, penalty)| *penalty)
+                .sum()
+        })
+    }
 }
 
 /// A skills module provides way to control jobs/vehicle assignment.
@@ -64,6 +81,8 @@ impl ConstraintModule for SkillsModule {
                 check_skill_sets(source_skills.all_of.as_ref(), candidate_skills.all_of.as_ref())
                     && check_skill_sets(source_skills.one_of.as_ref(), candidate_skills.one_of.as_ref())
                     && check_skill_sets(source_skills.none_of.as_ref(), candidate_skills.none_of.as_ref())
+                    && check_preferred_skills(source_skills, candidate_skills)
+                    && check_skill_counts(source_skills, candidate_skills)
             }
         };
 
@@ -90,10 +109,12 @@ struct SkillsHardRouteConstraint {
 impl HardRouteConstraint for SkillsHardRouteConstraint {
     fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
         if let Some(job_skills) = job.dimens().get_job_skills() {
-            let vehicle_skills = ctx.route.actor.vehicle.dimens.get_vehicle_skills();
+            let dimens = &ctx.route.actor.vehicle.dimens;
+            let vehicle_skills = dimens.get_vehicle_skills();
             let is_ok = check_all_of(job_skills, &vehicle_skills)
                 && check_one_of(job_skills, &vehicle_skills)
-                && check_none_of(job_skills, &vehicle_skills);
+                && check_none_of(job_skills, &vehicle_skills)
+                && check_counts(job_skills, &vehicle_skills, dimens.get_vehicle_skill_counts());
             if !is_ok {
                 return Some(RouteConstraintViolation { code: self.code });
             }
@@ -127,3 +148,40 @@ fn check_none_of(job_skills: &JobSkills, vehicle_skills: &Option<&HashSet<String
         _ => true,
     }
 }
+
+fn check_counts(
+    job_skills: &JobSkills,
+    vehicle_skills: &Option<&HashSet<String>>,
+    vehicle_skill_counts: Option<&HashMap<String, usize>>,
+) -> bool {
+    match job_skills.counts.as_ref() {
+        Some(counts) => counts.iter().all(|(skill, required)| {
+            let available = match vehicle_skill_counts.and_then(|counts| counts.get(skill)) {
+                Some(count) => *count,
+                None if vehicle_skills.is_some_and(|vehicle_skills| vehicle_skills.contains(skill)) => 1,
+                None => 0,
+            };
+
+            available >= *required
+        }),
+        None => true,
+    }
+}
+
+fn check_skill_counts(source_skills: &JobSkills, candidate_skills: &JobSkills) -> bool {
+    match (source_skills.counts.as_ref(), candidate_skills.counts.as_ref()) {
+        (Some(_), None) | (None, None) => true,
+        (None, Some(_)) => false,
+        (Some(source), Some(candidate)) => candidate
+            .iter()
+            .all(|(skill, required)| source.get(skill).is_some_and(|source_required| source_required >= required)),
+    }
+}
+
+fn check_preferred_skills(source_skills: &JobSkills, candidate_skills: &JobSkills) -> bool {
+    match (source_skills.preferred.as_ref(), candidate_skills.preferred.as_ref()) {
+        (Some(_), None) | (None, None) => true,
+        (None, Some(_)) => false,
+        (Some(source), Some(candidate)) => candidate.keys().all(|skill| source.contains_key(skill)),
+    }
+}
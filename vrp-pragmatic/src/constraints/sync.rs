@@ -0,0 +1,453 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/sync_test.rs"]
+mod sync_test;
+
+use super::{eject_jobs, get_original_window};
+use crate::extensions::JobTie;
+use hashbrown::{HashMap, HashSet};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::*;
+use vrp_core::models::common::{Duration, TimeWindow, Timestamp};
+use vrp_core::models::problem::{ActivityCost, Job, Single, TransportCost, TravelTime};
+use vrp_core::models::solution::Activity;
+
+/// Specifies synchronization of the job with other jobs which share the same key.
+#[derive(Clone, Debug)]
+pub struct JobSync {
+    /// A sync key.
+    pub key: String,
+    /// A maximum difference between service start times of synchronized jobs.
+    pub offset: Duration,
+}
+
+/// Keeps allowed service start interval of a new synchronized job per sync key.
+type SyncBounds = HashMap<String, TimeWindow>;
+
+/// Keeps offset and assigned visits per sync key.
+type SyncVisits = HashMap<String, (Duration, Vec<SyncVisit>)>;
+
+/// Specifies an assigned synchronized activity.
+struct SyncVisit {
+    route_idx: usize,
+    activity_idx: usize,
+    /// Job's time window which contains activity's (potentially narrowed) time window.
+    original: TimeWindow,
+    /// Activity's current time window.
+    current: TimeWindow,
+    /// Service start without waiting for partners.
+    earliest: Timestamp,
+    /// Latest service start which does not violate the rest of the route.
+    latest: Timestamp,
+    /// Actual service start.
+    start: Timestamp,
+}
+
+/// Limits amount of alignment passes as rescheduling one route can affect other sync keys.
+const MAX_ALIGN_ITERATIONS: usize = 4;
+
+/// A tolerance used to compare service start times of synchronized activities.
+const ALIGN_TOLERANCE: f64 = 1E-3;
+
+/// A sync module couples jobs which have to be served by different vehicles at the same time or
+/// within given time offset, e.g. installation which requires two technicians.
+///
+/// Routes are coupled via activity time windows: time window of each assigned synchronized activity
+/// is narrowed to the interval where its partners in other routes can be served, then the route is
+/// rescheduled, so the vehicle waits when it arrives earlier than its partners. Rescheduled routes
+/// are flagged, so the pipeline recalculates states of all modules for them. As narrowed time
+/// windows are regular ones, other insertions cannot shift synchronized activity beyond its partners.
+/// Service start interval per sync key is kept in route and solution state to evaluate insertion of
+/// the rest of the group without rescheduling other routes.
+///
+/// If some job of the group cannot be assigned or the group cannot be aligned anymore, e.g. after
+/// a local search, all its assigned jobs are ejected to keep solution feasible.
+pub struct SyncModule {
+    code: i32,
+    state_key: i32,
+    sizes: HashMap<String, usize>,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    schedule: TransportConstraintModule,
+}
+
+impl SyncModule {
+    /// Creates a new instance of `SyncModule`. Sizes specify total amount of jobs per sync key.
+    pub fn new(
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        sizes: HashMap<String, usize>,
+        code: i32,
+        state_key: i32,
+    ) -> Self {
+        Self {
+            code,
+            state_key,
+            sizes,
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(SyncHardRouteConstraint {
+                    code,
+                    state_key,
+                    transport: transport.clone(),
+                })),
+                ConstraintVariant::HardActivity(Arc::new(SyncHardActivityConstraint {
+                    code,
+                    state_key,
+                    activity: activity.clone(),
+                    transport: transport.clone(),
+                })),
+            ],
+            keys: vec![state_key],
+            transport: transport.clone(),
+            schedule: TransportConstraintModule::new(transport, activity, code),
+        }
+    }
+
+    /// Aligns synchronized activities with their partners and updates service start bounds in all routes
+    /// and in solution state. Returns visits of aligned solution.
+    fn align(&self, solution_ctx: &mut SolutionContext) -> SyncVisits {
+        for _ in 0..MAX_ALIGN_ITERATIONS {
+            let visits = get_visits(solution_ctx, self.transport.as_ref());
+            let windows = visits
+                .values()
+                .flat_map(|(offset, visits)| {
+                    visits.iter().filter_map(move |visit| {
+                        let partners = visits.iter().filter(|other| other.route_idx != visit.route_idx);
+                        let window = get_bounds(partners, *offset)
+                            .filter(|bounds| bounds.start <= bounds.end)
+                            .and_then(|bounds| visit.original.overlapping(&bounds))
+                            .unwrap_or_else(|| visit.original.clone());
+
+                        if window == visit.current {
+                            None
+                        } else {
+                            Some((visit.route_idx, visit.activity_idx, window))
+                        }
+                    })
+                })
+                .fold(HashMap::<usize, Vec<_>>::default(), |mut acc, (route_idx, activity_idx, window)| {
+                    acc.entry(route_idx).or_default().push((activity_idx, window));
+                    acc
+                });
+
+            if windows.is_empty() {
+                break;
+            }
+
+            windows.into_iter().for_each(|(route_idx, windows)| {
+                let route_ctx = solution_ctx.routes.get_mut(route_idx).expect("invalid route index");
+
+                let tour = &mut route_ctx.route_mut().tour;
+                windows.into_iter().for_each(|(activity_idx, window)| {
+                    tour.get_mut(activity_idx).expect("invalid activity index").place.time = window;
+                });
+
+                // NOTE reschedule here to get partners' service start on the next pass, other route
+                // states are recalculated by the pipeline once alignment is done
+                self.schedule.accept_route_state(route_ctx);
+                route_ctx.state_mut().set_flag(state_flags::RESCHEDULED);
+            });
+        }
+
+        let visits = get_visits(solution_ctx, self.transport.as_ref());
+        let bounds: StateValue = Arc::new(
+            visits
+                .iter()
+                .filter_map(|(key, (offset, visits))| {
+                    get_bounds(visits.iter(), *offset).map(|bounds| (key.clone(), bounds))
+                })
+                .collect::<SyncBounds>(),
+        );
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            route_ctx.state_mut().put_route_state_raw(self.state_key, bounds.clone());
+        });
+        solution_ctx.state.insert(self.state_key, bounds);
+
+        visits
+    }
+
+    /// Ejects synchronized jobs of misaligned or partially assigned groups.
+    fn eject_broken_groups(&self, solution_ctx: &mut SolutionContext, visits: SyncVisits) {
+        let pending = solution_ctx
+            .required
+            .iter()
+            .filter_map(|job| job.dimens().get_job_sync().map(|sync| sync.key.clone()))
+            .collect::<HashSet<_>>();
+
+        let broken = visits
+            .into_iter()
+            .filter(|(key, (offset, visits))| {
+                let is_partial = visits.len() < self.sizes.get(key).cloned().unwrap_or(0) && !pending.contains(key);
+
+                let earliest = visits.iter().map(|visit| visit.start).fold(f64::MAX, f64::min);
+                let latest = visits.iter().map(|visit| visit.start).fold(f64::MIN, f64::max);
+                let is_misaligned = latest - earliest > *offset + ALIGN_TOLERANCE
+                    || visits.iter().any(|visit| visit.start > visit.original.end)
+                    || visits.iter().map(|visit| visit.route_idx).collect::<HashSet<_>>().len() != visits.len();
+
+                is_partial || is_misaligned
+            })
+            .map(|(key, _)| key)
+            .collect::<HashSet<_>>();
+
+        if broken.is_empty() {
+            return;
+        }
+
+        let ejected = eject_jobs(solution_ctx, |_, route_ctx| {
+            route_ctx
+                .route
+                .tour
+                .jobs()
+                .filter(|job| job.dimens().get_job_sync().is_some_and(|sync| broken.contains(&sync.key)))
+                .collect()
+        });
+
+        solution_ctx.unassigned.extend(ejected.into_iter().map(|job| (job, UnassignmentInfo::Simple(self.code))));
+    }
+}
+
+impl ConstraintModule for SyncModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        let route_ctx = solution_ctx.routes.get(route_index).unwrap();
+        if route_ctx.route.tour.all_activities().any(|activity| get_sync(activity).is_some()) {
+            self.align(solution_ctx);
+        } else if let Some(bounds) = solution_ctx.state.get(&self.state_key).cloned() {
+            // NOTE a new route has no state yet, so share the current one
+            let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+            route_ctx.state_mut().put_route_state_raw(self.state_key, bounds);
+        }
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        let visits = self.align(ctx);
+        self.eject_broken_groups(ctx, visits);
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        match (source.dimens().get_job_sync(), candidate.dimens().get_job_sync()) {
+            (None, None) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct SyncHardRouteConstraint {
+    code: i32,
+    state_key: i32,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl SyncHardRouteConstraint {
+    /// Checks whether the job can be served by an empty route when its partners can be served.
+    fn can_serve_in_new_route(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        single: &Single,
+        sync: &JobSync,
+    ) -> bool {
+        let bounds = solution_ctx
+            .state
+            .get(&self.state_key)
+            .and_then(|bounds| bounds.downcast_ref::<SyncBounds>())
+            .and_then(|bounds| bounds.get(&sync.key));
+
+        let (bounds, start) = match (bounds, route_ctx.route.tour.start()) {
+            (Some(bounds), Some(start)) => (bounds, start),
+            (None, _) => return true,
+            _ => return false,
+        };
+
+        let route = route_ctx.route.as_ref();
+        let end = route_ctx.route.tour.end();
+        let departure = start.schedule.departure;
+
+        // NOTE all time windows reachable by the vehicle should respect bounds as any of them can be used
+        single.places.iter().all(|place| {
+            let location = place.location.unwrap_or(start.place.location);
+            let arrival = departure
+                + self.transport.duration(route, start.place.location, location, TravelTime::Departure(departure));
+
+            place.times.iter().map(|time| time.to_time_window(departure)).filter(|time| arrival <= time.end).all(
+                |time| {
+                    let service_start = arrival.max(time.start).max(bounds.start);
+                    let is_end_reachable = match end {
+                        Some(end) => {
+                            let departure = service_start + place.duration;
+                            let duration = self.transport.duration(
+                                route,
+                                location,
+                                end.place.location,
+                                TravelTime::Departure(departure),
+                            );
+
+                            departure + duration <= end.place.latest_start()
+                        }
+                        None => true,
+                    };
+
+                    service_start <= bounds.end && service_start <= time.end && is_end_reachable
+                },
+            )
+        })
+    }
+}
+
+impl HardRouteConstraint for SyncHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        let single = job.as_single()?;
+        let sync = single.dimens.get_job_sync()?;
+        let tour = &route_ctx.route.tour;
+
+        let has_partner = tour.jobs().any(|job| job.dimens().get_job_sync().is_some_and(|other| other.key == sync.key));
+        let is_feasible =
+            !has_partner && (tour.has_jobs() || self.can_serve_in_new_route(solution_ctx, route_ctx, single, sync));
+
+        if is_feasible {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
+
+struct SyncHardActivityConstraint {
+    code: i32,
+    state_key: i32,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardActivityConstraint for SyncHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let sync = get_sync(activity_ctx.target)?;
+        let bounds = route_ctx.state.get_route_state::<SyncBounds>(self.state_key).and_then(|b| b.get(&sync.key))?;
+
+        let route = route_ctx.route.as_ref();
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let departure = prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+
+        // NOTE vehicle has to wait for its partners if it arrives earlier
+        let service_start = arrival.max(target.place.time.start);
+        let forced_start = service_start.max(bounds.start);
+
+        let is_next_violated = forced_start > service_start
+            && activity_ctx.next.is_some_and(|next| {
+                let departure = self.activity.estimate_departure(route, target, forced_start);
+                let arrival = departure
+                    + self.transport.duration(
+                        route,
+                        target.place.location,
+                        next.place.location,
+                        TravelTime::Departure(departure),
+                    );
+                let latest_arrival =
+                    *route_ctx.state.get_activity_state(LATEST_ARRIVAL_KEY, next).unwrap_or(&next.place.latest_start());
+
+                arrival > latest_arrival
+            });
+
+        if forced_start > bounds.end || forced_start > target.place.time.end || is_next_violated {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+fn get_sync(activity: &Activity) -> Option<&JobSync> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_sync())
+}
+
+fn get_visits(solution_ctx: &SolutionContext, transport: &(dyn TransportCost + Send + Sync)) -> SyncVisits {
+    solution_ctx.routes.iter().enumerate().fold(SyncVisits::default(), |acc, (route_idx, route_ctx)| {
+        let route = route_ctx.route.as_ref();
+        let date = route.tour.start().map_or(0., |start| start.schedule.departure);
+
+        route.tour.all_activities().enumerate().fold(acc, |mut acc, (activity_idx, activity)| {
+            let visit = get_sync(activity).and_then(|sync| {
+                let original = get_original_window(activity, date)?;
+
+                // NOTE latest start is estimated from the next activity to ignore own narrowed time window
+                let latest = match route.tour.get(activity_idx + 1) {
+                    Some(next) => {
+                        let latest_arrival = *route_ctx
+                            .state
+                            .get_activity_state(LATEST_ARRIVAL_KEY, next)
+                            .unwrap_or(&next.place.latest_start());
+                        let duration = transport.duration(
+                            route,
+                            activity.place.location,
+                            next.place.location,
+                            TravelTime::Arrival(latest_arrival),
+                        );
+
+                        (latest_arrival - duration - activity.place.duration).min(original.end)
+                    }
+                    None => original.end,
+                };
+
+                Some((
+                    sync,
+                    SyncVisit {
+                        route_idx,
+                        activity_idx,
+                        earliest: activity.schedule.arrival.max(original.start),
+                        latest,
+                        start: activity.schedule.arrival.max(activity.place.time.start),
+                        current: activity.place.time.clone(),
+                        original,
+                    },
+                ))
+            });
+
+            if let Some((sync, visit)) = visit {
+                acc.entry(sync.key.clone()).or_insert_with(|| (sync.offset, vec![])).1.push(visit);
+            }
+
+            acc
+        })
+    })
+}
+
+/// Returns service start interval which respects given visits and offset. The interval is empty
+/// (start is after end) when visits cannot be aligned.
+fn get_bounds<'a>(visits: impl Iterator<Item = &'a SyncVisit>, offset: Duration) -> Option<TimeWindow> {
+    visits
+        .fold(None, |acc: Option<(Timestamp, Timestamp)>, visit| match acc {
+            Some((earliest, latest)) => Some((earliest.max(visit.earliest), latest.min(visit.latest))),
+            None => Some((visit.earliest, visit.latest)),
+        })
+        .map(|(earliest, latest)| TimeWindow::new(earliest - offset, latest + offset))
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/territories_test.rs"]
+mod territories_test;
+
+use crate::extensions::{JobTie, VehicleTie};
+use hashbrown::HashSet;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::common::Dimensions;
+use vrp_core::models::problem::Job;
+
+/// A service territory which job belongs to.
+pub struct JobTerritory {
+    /// An unique territory id.
+    pub id: String,
+    /// Ids of vehicles preferred to serve territory jobs.
+    pub vehicles: HashSet<String>,
+    /// Whether territory jobs cannot be served by other vehicles.
+    pub is_strict: bool,
+    /// A penalty of serving territory jobs by other vehicles when jobs are pinned to the territory.
+    pub cross_zone_penalty: Option<f64>,
+}
+
+impl JobTerritory {
+    /// Checks whether serving a job from the territory by vehicle with given dimensions is a violation.
+    pub fn is_violated(&self, vehicle_dimens: &Dimensions) -> bool {
+        !vehicle_dimens.get_vehicle_id().is_some_and(|vehicle_id| self.vehicles.contains(vehicle_id))
+    }
+}
+
+/// A territory module forbids serving jobs of strict territories by vehicles which are not
+/// preferred by the territory.
+pub struct TerritoryModule {
+    code: i32,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl TerritoryModule {
+    /// Creates a new instance of `TerritoryModule`.
+    pub fn new(code: i32) -> Self {
+        Self {
+            code,
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(TerritoryHardRouteConstraint { code }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for TerritoryModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        let source_territory = source.dimens().get_job_territory().map(|territory| territory.id.as_str());
+        let candidate_territory = candidate.dimens().get_job_territory().map(|territory| territory.id.as_str());
+
+        if source_territory == candidate_territory {
+            Ok(source)
+        } else {
+            Err(self.code)
+        }
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct TerritoryHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for TerritoryHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        job.dimens()
+            .get_job_territory()
+            .filter(|territory| territory.is_strict && territory.is_violated(&ctx.route.actor.vehicle.dimens))
+            .map(|_| RouteConstraintViolation { code: self.code })
+    }
+}
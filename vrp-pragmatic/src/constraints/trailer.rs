@@ -0,0 +1,319 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/trailer_test.rs"]
+mod trailer_test;
+
+use crate::constraints::*;
+use crate::extensions::{JobTie, TrailerTie};
+use std::iter::once;
+use std::marker::PhantomData;
+use std::slice::Iter;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::*;
+use vrp_core::models::problem::{Job, Multi, Single};
+use vrp_core::models::solution::Tour;
+
+/// A trailer module models vehicles which can leave their trailer at trailer parking, serve truck
+/// only jobs without it having reduced capacity, and pick the trailer up again at the same parking.
+pub struct TrailerModule<T: LoadOps> {
+    code: i32,
+    conditional: ConditionalJobModule,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> TrailerModule<T> {
+    /// Creates a new instance of `TrailerModule`.
+    pub fn new(code: i32) -> Self {
+        Self {
+            code,
+            conditional: ConditionalJobModule::new(create_job_transition()),
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(TrailerHardRouteConstraint { code })),
+                ConstraintVariant::HardActivity(Arc::new(TrailerHardActivityConstraint::<T> {
+                    code,
+                    phantom: PhantomData,
+                })),
+                ConstraintVariant::SoftRoute(Arc::new(TrailerSoftRouteConstraint {})),
+            ],
+            keys: vec![],
+            phantom: PhantomData,
+        }
+    }
+
+    /// Moves truck only jobs which are served outside of trailer parking visit back to required.
+    /// This happens when trailer job is removed from the tour, e.g. by ruin method.
+    fn remove_stray_jobs(&self, solution_ctx: &mut SolutionContext) -> bool {
+        let mut extra_required = Vec::new();
+
+        solution_ctx.routes.iter_mut().filter(|route_ctx| has_trailer::<T>(route_ctx)).for_each(|route_ctx| {
+            let tour = &route_ctx.route.tour;
+            let intervals = get_detached_intervals(tour);
+
+            let stray_jobs = tour
+                .all_activities()
+                .enumerate()
+                .filter(|(idx, activity)| {
+                    activity.job.as_ref().is_some_and(is_truck_only_single)
+                        && !intervals.iter().any(|(start, end)| start < idx && idx < end)
+                })
+                .filter_map(|(_, activity)| activity.retrieve_job())
+                .collect::<Vec<_>>();
+
+            if !stray_jobs.is_empty() {
+                let tour = &mut route_ctx.route_mut().tour;
+                stray_jobs.into_iter().for_each(|job| {
+                    if tour.remove(&job) {
+                        extra_required.push(job);
+                    }
+                });
+            }
+        });
+
+        let has_changes = !extra_required.is_empty();
+        solution_ctx.required.extend(extra_required);
+
+        has_changes
+    }
+
+    /// Removes trailer jobs which have no truck only jobs served while trailer is detached.
+    fn remove_obsolete_trailer_jobs(&self, solution_ctx: &mut SolutionContext) -> bool {
+        let mut extra_ignored = Vec::new();
+
+        solution_ctx.routes.iter_mut().filter(|route_ctx| has_trailer::<T>(route_ctx)).for_each(|route_ctx| {
+            let tour = &route_ctx.route.tour;
+
+            let obsolete_jobs = get_detached_intervals(tour)
+                .into_iter()
+                .filter(|(start, end)| {
+                    !tour
+                        .activities_slice(*start, (*end).min(tour.total() - 1))
+                        .iter()
+                        .filter_map(|activity| activity.job.as_ref())
+                        .any(is_truck_only_single)
+                })
+                .filter_map(|(start, _)| tour.get(start).and_then(|activity| activity.retrieve_job()))
+                .collect::<Vec<_>>();
+
+            if !obsolete_jobs.is_empty() {
+                let tour = &mut route_ctx.route_mut().tour;
+                obsolete_jobs.into_iter().for_each(|job| {
+                    if tour.remove(&job) {
+                        extra_ignored.push(job);
+                    }
+                });
+            }
+        });
+
+        let has_changes = !extra_ignored.is_empty();
+        solution_ctx.ignored.extend(extra_ignored);
+
+        has_changes
+    }
+}
+
+impl<T: LoadOps> ConstraintModule for TrailerModule<T> {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        self.conditional.accept_insertion(solution_ctx, route_index, job);
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        // NOTE stray jobs should be moved to required before conditional transitions are applied
+        let has_stray_jobs = self.remove_stray_jobs(ctx);
+        let has_obsolete_jobs = self.remove_obsolete_trailer_jobs(ctx);
+
+        self.conditional.accept_solution_state(ctx);
+
+        if has_stray_jobs || has_obsolete_jobs {
+            let registry = &mut ctx.registry;
+            ctx.routes.retain(|route_ctx| {
+                if route_ctx.route.tour.has_jobs() {
+                    true
+                } else {
+                    registry.free_route(route_ctx);
+                    false
+                }
+            });
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        if once(&source).chain(once(&candidate)).any(is_trailer_job) {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Locks trailer jobs to specific vehicles.
+struct TrailerHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for TrailerHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        match job {
+            Job::Multi(multi) if is_trailer_job(job) => {
+                if multi.jobs.first().is_some_and(|single| is_single_belongs_to_route(ctx, single)) {
+                    None
+                } else {
+                    Some(RouteConstraintViolation { code: self.code })
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Checks that trailer is detached and attached back in proper order, truck only jobs are served
+/// while trailer is detached and truck capacity is not exceeded.
+struct TrailerHardActivityConstraint<T: LoadOps> {
+    code: i32,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> HardActivityConstraint for TrailerHardActivityConstraint<T> {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let capacity: &T = route_ctx.route.actor.vehicle.dimens.get_truck_capacity()?;
+        let target = activity_ctx.target.job.as_ref()?;
+
+        let tour = &route_ctx.route.tour;
+        let index = activity_ctx.index;
+        let interval = get_detached_intervals(tour).into_iter().find(|(start, end)| *start <= index && index < *end);
+
+        let is_valid = match (get_trailer_activity_type(target), interval) {
+            (Some("detach"), Some(_)) => false,
+            (Some("detach"), None) => true,
+            (Some("attach"), Some((start, _))) => {
+                let is_same_trailer = tour
+                    .get(start)
+                    .and_then(|activity| activity.job.as_ref())
+                    .and_then(|detach| Multi::roots(detach))
+                    .zip(Multi::roots(target))
+                    .is_some_and(|(lhs, rhs)| Arc::ptr_eq(&lhs, &rhs));
+
+                is_same_trailer && can_fit_truck(capacity, tour.activities_slice(start + 1, index).iter())
+            }
+            (Some("attach"), None) => false,
+            (_, Some((start, end))) => can_fit_truck(
+                capacity,
+                tour.activities_slice(start + 1, index)
+                    .iter()
+                    .chain(once(activity_ctx.target))
+                    .chain(tour.activities_slice(index + 1, end - 1).iter()),
+            ),
+            (_, None) => !is_truck_only_single(target),
+        };
+
+        if is_valid {
+            None
+        } else {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        }
+    }
+}
+
+/// Encourages insertion of promoted trailer jobs.
+struct TrailerSoftRouteConstraint {}
+
+impl SoftRouteConstraint for TrailerSoftRouteConstraint {
+    fn estimate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> f64 {
+        if is_trailer_job(job) {
+            0. - ctx.route.actor.vehicle.costs.fixed.max(1000.)
+        } else {
+            0.
+        }
+    }
+}
+
+fn create_job_transition() -> Box<dyn JobContextTransition + Send + Sync> {
+    Box::new(ConcreteJobContextTransition {
+        remove_required: |solution_ctx, _, job| is_trailer_job(job) && !has_truck_only_required(solution_ctx),
+        promote_required: |solution_ctx, _, job| is_trailer_job(job) && has_truck_only_required(solution_ctx),
+        remove_locked: |_, _, _| false,
+        promote_locked: |_, _, job| is_trailer_job(job),
+    })
+}
+
+/// Checks whether truck capacity is not exceeded while serving given activities. Static deliveries
+/// are assumed to be moved from trailer to truck at trailer parking.
+/// NOTE: a pickup and delivery pair served both while trailer is detached is counted twice.
+fn can_fit_truck<'a, T: LoadOps>(capacity: &T, activities: impl Iterator<Item = &'a Activity>) -> bool {
+    let demands = activities
+        .filter_map(|activity| activity.job.as_ref())
+        .filter_map(|single| single.dimens.get_demand())
+        .collect::<Vec<&Demand<T>>>();
+
+    let start_load = demands.iter().fold(T::default(), |acc, demand| acc + demand.delivery.0 + demand.delivery.1);
+
+    capacity.can_fit(&start_load)
+        && demands
+            .iter()
+            .try_fold(start_load, |load, demand| {
+                let load = load - demand.delivery.0 - demand.delivery.1 + demand.pickup.0 + demand.pickup.1;
+                if capacity.can_fit(&load) {
+                    Some(load)
+                } else {
+                    None
+                }
+            })
+            .is_some()
+}
+
+/// Returns index ranges of tour parts where trailer is detached: from detach activity to attach
+/// activity. If attach activity is not yet inserted, the range ends at the last activity.
+fn get_detached_intervals(tour: &Tour) -> Vec<(usize, usize)> {
+    tour.all_activities().enumerate().fold(Vec::new(), |mut acc, (idx, activity)| {
+        match activity.job.as_ref().and_then(|single| get_trailer_activity_type(single)) {
+            Some("detach") => acc.push((idx, tour.total())),
+            Some("attach") => {
+                if let Some(last) = acc.last_mut() {
+                    last.1 = idx;
+                }
+            }
+            _ => {}
+        }
+
+        acc
+    })
+}
+
+/// Checks whether there are truck only jobs among required ones.
+fn has_truck_only_required(solution_ctx: &SolutionContext) -> bool {
+    solution_ctx.required.iter().any(|job| match job {
+        Job::Single(single) => is_truck_only_single(single),
+        Job::Multi(multi) => multi.jobs.iter().any(is_truck_only_single),
+    })
+}
+
+fn has_trailer<T: LoadOps>(route_ctx: &RouteContext) -> bool {
+    TrailerTie::<T>::get_truck_capacity(&route_ctx.route.actor.vehicle.dimens).is_some()
+}
+
+fn get_trailer_activity_type(single: &Single) -> Option<&str> {
+    single.dimens.get_job_type().map(|job_type| job_type.as_str()).filter(|t| *t == "detach" || *t == "attach")
+}
+
+fn is_trailer_job(job: &Job) -> bool {
+    job.as_multi().and_then(|multi| multi.dimens.get_job_type()).is_some_and(|t| t == "trailer")
+}
+
+fn is_truck_only_single(single: &Arc<Single>) -> bool {
+    single.dimens.get_job_truck_only().unwrap_or(false)
+}
@@ -0,0 +1,198 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/unloading_test.rs"]
+mod unloading_test;
+
+use crate::constraints::*;
+use crate::extensions::JobTie;
+use std::iter::once;
+use std::marker::PhantomData;
+use std::slice::Iter;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::*;
+use vrp_core::models::problem::{Job, Single};
+use vrp_core::models::solution::Tour;
+
+/// An unloading module models returns to sender flows: goods picked up at customers are brought back
+/// to the depot where they have to be unloaded. Unloading is modeled as a conditional job at shift end
+/// location which is required only when the tour has static pickups and is served as the last activity
+/// of the tour. Depot capacity at return can be limited by a dock resource shared with reloads.
+pub struct UnloadingModule<T: LoadOps> {
+    code: i32,
+    conditional: ConditionalJobModule,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> UnloadingModule<T> {
+    /// Creates a new instance of `UnloadingModule`.
+    pub fn new(code: i32) -> Self {
+        Self {
+            code,
+            conditional: ConditionalJobModule::new(create_job_transition::<T>()),
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(UnloadingHardRouteConstraint { code })),
+                ConstraintVariant::HardActivity(Arc::new(UnloadingHardActivityConstraint { code })),
+                ConstraintVariant::SoftRoute(Arc::new(UnloadingSoftRouteConstraint {})),
+            ],
+            keys: vec![],
+            phantom: PhantomData,
+        }
+    }
+
+    /// Removes unloading jobs from tours which have no returns anymore, e.g. after ruin method.
+    fn remove_obsolete_unloading_jobs(&self, solution_ctx: &mut SolutionContext) -> bool {
+        let mut extra_ignored = Vec::new();
+
+        solution_ctx.routes.iter_mut().filter(|route_ctx| !has_returns::<T>(&route_ctx.route.tour)).for_each(
+            |route_ctx| {
+                let obsolete_jobs = route_ctx.route.tour.jobs().filter(is_unloading_job).collect::<Vec<_>>();
+
+                if !obsolete_jobs.is_empty() {
+                    let tour = &mut route_ctx.route_mut().tour;
+                    obsolete_jobs.into_iter().for_each(|job| {
+                        if tour.remove(&job) {
+                            extra_ignored.push(job);
+                        }
+                    });
+                }
+            },
+        );
+
+        let has_changes = !extra_ignored.is_empty();
+        solution_ctx.ignored.extend(extra_ignored);
+
+        has_changes
+    }
+}
+
+impl<T: LoadOps> ConstraintModule for UnloadingModule<T> {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        self.conditional.accept_insertion(solution_ctx, route_index, job);
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        let has_obsolete_jobs = self.remove_obsolete_unloading_jobs(ctx);
+
+        self.conditional.accept_solution_state(ctx);
+
+        if has_obsolete_jobs {
+            let registry = &mut ctx.registry;
+            ctx.routes.retain(|route_ctx| {
+                if route_ctx.route.tour.has_jobs() {
+                    true
+                } else {
+                    registry.free_route(route_ctx);
+                    false
+                }
+            });
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        if once(&source).chain(once(&candidate)).any(is_unloading_job) {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Locks unloading jobs to specific vehicles.
+struct UnloadingHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for UnloadingHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        match job.as_single() {
+            Some(single) if is_unloading_single(single) && !is_single_belongs_to_route(ctx, single) => {
+                Some(RouteConstraintViolation { code: self.code })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Keeps unloading as the last activity of the tour.
+struct UnloadingHardActivityConstraint {
+    code: i32,
+}
+
+impl HardActivityConstraint for UnloadingHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        _: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let is_target_unloading = activity_ctx.target.job.as_ref().is_some_and(is_unloading_single);
+        let is_prev_unloading = activity_ctx.prev.job.as_ref().is_some_and(is_unloading_single);
+        let has_next_job = activity_ctx.next.is_some_and(|next| next.job.is_some());
+
+        if is_prev_unloading || (is_target_unloading && has_next_job) {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+/// Encourages insertion of promoted unloading jobs.
+struct UnloadingSoftRouteConstraint {}
+
+impl SoftRouteConstraint for UnloadingSoftRouteConstraint {
+    fn estimate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> f64 {
+        if is_unloading_job(job) {
+            0. - ctx.route.actor.vehicle.costs.fixed.max(1000.)
+        } else {
+            0.
+        }
+    }
+}
+
+fn create_job_transition<T: LoadOps>() -> Box<dyn JobContextTransition + Send + Sync> {
+    Box::new(ConcreteJobContextTransition {
+        remove_required: |solution_ctx, _, job| is_unloading_job(job) && !has_route_returns::<T>(solution_ctx, job),
+        promote_required: |solution_ctx, _, job| is_unloading_job(job) && has_route_returns::<T>(solution_ctx, job),
+        remove_locked: |_, _, _| false,
+        promote_locked: |_, _, job| is_unloading_job(job),
+    })
+}
+
+/// Checks whether the route of unloading job's vehicle has returns.
+fn has_route_returns<T: LoadOps>(solution_ctx: &SolutionContext, job: &Job) -> bool {
+    job.as_single().is_some_and(|single| {
+        solution_ctx
+            .routes
+            .iter()
+            .find(|route_ctx| is_single_belongs_to_route(route_ctx, single))
+            .is_some_and(|route_ctx| has_returns::<T>(&route_ctx.route.tour))
+    })
+}
+
+/// Checks whether tour has static pickups which are brought back to the depot.
+fn has_returns<T: LoadOps>(tour: &Tour) -> bool {
+    tour.all_activities()
+        .filter_map(|activity| activity.job.as_ref())
+        .filter_map(|single| single.dimens.get_demand())
+        .any(|demand: &Demand<T>| demand.pickup.0.is_not_empty())
+}
+
+fn is_unloading_job(job: &Job) -> bool {
+    job.as_single().is_some_and(is_unloading_single)
+}
+
+fn is_unloading_single(single: &Arc<Single>) -> bool {
+    single.dimens.get_job_type().is_some_and(|job_type| job_type == "unloading")
+}
@@ -0,0 +1,148 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/visit_gap_test.rs"]
+mod visit_gap_test;
+
+use crate::extensions::JobTie;
+use hashbrown::HashMap;
+use std::iter::once;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Duration, Timestamp};
+use vrp_core::models::problem::{ActivityCost, Job, TransportCost, TravelTime};
+use vrp_core::models::solution::Activity;
+
+type VisitGap = (Option<Duration>, Option<Duration>);
+
+/// A visit gap module restricts time between consecutive visits of the same job, e.g. to drop some
+/// equipment and return to collect it a few hours later. The gap is measured from departure of the
+/// previous visit to arrival to the next one. As visits belong to the same job, they are served by the same vehicle.
+pub struct VisitGapModule {
+    code: i32,
+    state_key: i32,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl VisitGapModule {
+    /// Creates a new instance of `VisitGapModule`.
+    pub fn new(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        code: i32,
+        state_key: i32,
+    ) -> Self {
+        Self {
+            code,
+            state_key,
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(VisitGapHardActivityConstraint {
+                code,
+                state_key,
+                activity,
+                transport,
+            }))],
+            keys: vec![state_key],
+        }
+    }
+}
+
+impl ConstraintModule for VisitGapModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        if job.dimens().get_job_visit_gap().is_some() {
+            self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap())
+        }
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let has_visit_gaps = ctx.route.tour.jobs().any(|job| job.dimens().get_job_visit_gap().is_some());
+        ctx.state_mut().put_route_state(self.state_key, has_visit_gaps);
+    }
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        match (source.dimens().get_job_visit_gap(), candidate.dimens().get_job_visit_gap()) {
+            (None, None) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct VisitGapHardActivityConstraint {
+    code: i32,
+    state_key: i32,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardActivityConstraint for VisitGapHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let has_visit_gaps = route_ctx.state.get_route_state::<bool>(self.state_key).cloned().unwrap_or(false);
+        if !has_visit_gaps && get_visit_gap(activity_ctx.target).is_none() {
+            return None;
+        }
+
+        let route = route_ctx.route.as_ref();
+        let tour = &route.tour;
+
+        // NOTE insertion shifts schedule of all activities after it, so gaps of already assigned jobs
+        // can be affected too: estimate the new schedule of the rest of the tour
+        let mut visits = HashMap::<Job, (VisitGap, Vec<(Timestamp, Timestamp)>)>::default();
+        let mut add_visit = |activity: &Activity, arrival: Timestamp, departure: Timestamp| {
+            if let Some((job, gap)) = get_visit_gap(activity) {
+                visits.entry(job).or_insert_with(|| (gap, vec![])).1.push((arrival, departure));
+            }
+        };
+
+        tour.all_activities()
+            .take(activity_ctx.index + 1)
+            .for_each(|activity| add_visit(activity, activity.schedule.arrival, activity.schedule.departure));
+
+        once(activity_ctx.target).chain(tour.all_activities().skip(activity_ctx.index + 1)).fold(
+            (activity_ctx.prev.place.location, activity_ctx.prev.schedule.departure),
+            |(location, departure), activity| {
+                let travel_time = TravelTime::Departure(departure);
+                let arrival =
+                    departure + self.transport.duration(route, location, activity.place.location, travel_time);
+                let departure = self.activity.estimate_departure(route, activity, arrival);
+
+                add_visit(activity, arrival, departure);
+
+                (activity.place.location, departure)
+            },
+        );
+
+        let is_violated = visits.values().any(|((min, max), visits)| {
+            visits.windows(2).any(|pair| match pair {
+                [(_, prev_departure), (next_arrival, _)] => {
+                    let gap = next_arrival - prev_departure;
+                    min.iter().any(|&min| gap < min) || max.iter().any(|&max| gap > max)
+                }
+                _ => false,
+            })
+        });
+
+        if is_violated {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+fn get_visit_gap(activity: &Activity) -> Option<(Job, VisitGap)> {
+    activity.retrieve_job().and_then(|job| job.dimens().get_job_visit_gap().map(|gap| (job, gap)))
+}
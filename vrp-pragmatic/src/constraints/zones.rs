@@ -0,0 +1,88 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/zones_test.rs"]
+mod zones_test;
+
+use crate::extensions::JobTie;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::problem::Job;
+use vrp_core::models::solution::Activity;
+
+/// A zone sequence module penalizes serving zones (groups of jobs) in an order different from the
+/// preferred one within a tour, e.g. frozen goods should be delivered after ambient ones. Zones of
+/// jobs are specified on job dimensions as pairs of sequence index and zone position within the
+/// sequence. Jobs without zones can be served in any order.
+pub struct ZoneSequenceModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ZoneSequenceModule {
+    /// Creates a new instance of `ZoneSequenceModule` with penalties specified per zone sequence.
+    pub fn new(penalties: Vec<f64>) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(ZoneSequenceSoftActivityConstraint {
+                penalties,
+            }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for ZoneSequenceModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct ZoneSequenceSoftActivityConstraint {
+    penalties: Vec<f64>,
+}
+
+impl SoftActivityConstraint for ZoneSequenceSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        let target_zones = match get_zones(activity_ctx.target) {
+            Some(zones) => zones,
+            None => return 0.,
+        };
+
+        // NOTE activities before the target should have the same or preceding zones, after - the same or following
+        route_ctx
+            .route
+            .tour
+            .all_activities()
+            .enumerate()
+            .filter_map(|(idx, activity)| get_zones(activity).map(|zones| (idx <= activity_ctx.index, zones)))
+            .flat_map(|(is_before, zones)| {
+                target_zones.iter().filter_map(move |(sequence_idx, position)| {
+                    zones
+                        .iter()
+                        .find(|(idx, _)| idx == sequence_idx)
+                        .filter(|(_, other)| if is_before { other > position } else { other < position })
+                        .map(|_| *sequence_idx)
+                })
+            })
+            .map(|sequence_idx| self.penalties.get(sequence_idx).cloned().unwrap_or(0.))
+            .sum()
+    }
+}
+
+fn get_zones(activity: &Activity) -> Option<&Vec<(usize, usize)>> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_zones())
+}
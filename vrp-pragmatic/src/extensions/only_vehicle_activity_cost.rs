@@ -1,4 +1,5 @@
 use crate::core::models::solution::Route;
+use crate::format::entities::JobTie;
 use vrp_core::models::common::{Cost, Timestamp};
 use vrp_core::models::problem::{ActivityCost, SimpleActivityCost};
 use vrp_core::models::solution::Activity;
@@ -15,8 +16,12 @@ impl ActivityCost for OnlyVehicleActivityCost {
 
         let waiting = if activity.place.time.start > arrival { activity.place.time.start - arrival } else { 0.0 };
         let service = activity.place.duration;
+        let approach = activity.job.as_ref().and_then(|job| job.dimens.get_job_approach_distance()).unwrap_or(0.);
 
-        waiting * actor.vehicle.costs.per_waiting_time + service * actor.vehicle.costs.per_service_time
+        waiting * actor.vehicle.costs.per_waiting_time
+            + service * actor.vehicle.costs.per_service_time
+            + approach * actor.vehicle.costs.per_distance
+            + activity.place.lateness_cost(arrival)
     }
 
     fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
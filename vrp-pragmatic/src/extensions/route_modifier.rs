@@ -3,7 +3,7 @@ use crate::format::JobIndex;
 use std::sync::Arc;
 use vrp_core::construction::constraints::ConstraintPipeline;
 use vrp_core::construction::heuristics::*;
-use vrp_core::prelude::*;
+use vrp_core::prelude::{compare_floats, RouteContext};
 
 /// Returns route modifier.
 pub fn get_route_modifier(constraint: Arc<ConstraintPipeline>, job_index: JobIndex) -> RouteModifier {
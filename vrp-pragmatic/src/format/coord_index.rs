@@ -39,6 +39,10 @@ impl CoordIndex {
                     index.add(&end.location);
                 }
 
+                if let Some(depots) = &shift.depots {
+                    depots.iter().for_each(|depot| index.add(depot));
+                }
+
                 if let Some(dispatch) = &shift.dispatch {
                     dispatch.iter().for_each(|dispatch| index.add(&dispatch.location));
                 }
@@ -56,7 +60,22 @@ impl CoordIndex {
                 }
 
                 if let Some(reloads) = &shift.reloads {
-                    reloads.iter().for_each(|reload| index.add(&reload.location));
+                    reloads.iter().for_each(|reload| {
+                        index.add(&reload.location);
+                        reload.alternatives.iter().flatten().for_each(|location| index.add(location));
+                    });
+                }
+
+                if let Some(recharges) = &shift.recharges {
+                    recharges.iter().for_each(|recharge| index.add(&recharge.location));
+                }
+
+                if let Some(parkings) = &shift.trailer_parkings {
+                    parkings.iter().for_each(|parking| index.add(&parking.location));
+                }
+
+                if let Some(relays) = &shift.relays {
+                    relays.iter().for_each(|relay| index.add(&relay.location));
                 }
             });
         });
@@ -1,8 +1,12 @@
 //! Specifies different entities as extension points on Dimensions type.
 
-use crate::constraints::{BreakPolicy, JobSkills};
+use crate::constraints::{
+    BackhaulPolicy, BreakPolicy, EmissionProfile, EnergyProfile, ItemAttributes, JobSkills, JobSync, JobTerritory,
+    LoadingOrder, VehicleAreas, VehiclePhysicalLimits,
+};
 use hashbrown::{HashMap, HashSet};
-use vrp_core::models::common::{Dimensions, ValueDimension};
+use std::sync::Arc;
+use vrp_core::models::common::{Dimensions, Distance, Duration, LoadOps, TimeWindow, ValueDimension};
 
 /// Specifies vehicle entity.
 pub trait VehicleTie {
@@ -26,15 +30,79 @@ pub trait VehicleTie {
     /// Sets vehicle's skills set.
     fn set_vehicle_skills(&mut self, skills: HashSet<String>) -> &mut Self;
 
+    /// Gets vehicle's skill counts.
+    fn get_vehicle_skill_counts(&self) -> Option<&HashMap<String, usize>>;
+    /// Sets vehicle's skill counts.
+    fn set_vehicle_skill_counts(&mut self, counts: HashMap<String, usize>) -> &mut Self;
+
     /// Gets vehicle's area.
     fn get_areas(&self) -> Option<&HashMap<String, (usize, f64)>>;
     /// Sets vehicle's area.
     fn set_areas(&mut self, areas: HashMap<String, (usize, f64)>) -> &mut Self;
 
+    /// Gets vehicle's allowed areas.
+    fn get_vehicle_areas(&self) -> Option<&VehicleAreas>;
+    /// Sets vehicle's allowed areas.
+    fn set_vehicle_areas(&mut self, areas: VehicleAreas) -> &mut Self;
+
     /// Gets vehicle's tour size.
     fn get_tour_size(&self) -> Option<usize>;
     /// Sets vehicle's tour size.
     fn set_tour_size(&mut self, tour_size: usize) -> &mut Self;
+
+    /// Gets vehicle's min tour size.
+    fn get_min_tour_size(&self) -> Option<usize>;
+    /// Sets vehicle's min tour size.
+    fn set_min_tour_size(&mut self, min_tour_size: usize) -> &mut Self;
+
+    /// Gets vehicle's tier.
+    fn get_vehicle_tier(&self) -> Option<usize>;
+    /// Sets vehicle's tier.
+    fn set_vehicle_tier(&mut self, tier: usize) -> &mut Self;
+    /// Returns true if loading manifest is requested for vehicle's tour.
+    fn get_loading_manifest(&self) -> bool;
+    /// Sets loading manifest flag.
+    fn set_loading_manifest(&mut self, manifest: bool) -> &mut Self;
+
+    /// Gets vehicle's loading order.
+    fn get_loading_order(&self) -> Option<LoadingOrder>;
+    /// Sets vehicle's loading order.
+    fn set_loading_order(&mut self, order: LoadingOrder) -> &mut Self;
+
+    /// Gets vehicle's backhaul policy.
+    fn get_backhaul_policy(&self) -> Option<BackhaulPolicy>;
+    /// Sets vehicle's backhaul policy.
+    fn set_backhaul_policy(&mut self, policy: BackhaulPolicy) -> &mut Self;
+
+    /// Gets vehicle's energy profile.
+    fn get_vehicle_energy(&self) -> Option<&EnergyProfile>;
+    /// Sets vehicle's energy profile.
+    fn set_vehicle_energy(&mut self, energy: EnergyProfile) -> &mut Self;
+
+    /// Gets vehicle's emission profile.
+    fn get_vehicle_emissions(&self) -> Option<&EmissionProfile>;
+    /// Sets vehicle's emission profile.
+    fn set_vehicle_emissions(&mut self, emissions: EmissionProfile) -> &mut Self;
+
+    /// Gets vehicle's maintenance windows.
+    fn get_vehicle_maintenance(&self) -> Option<&Vec<TimeWindow>>;
+    /// Sets vehicle's maintenance windows.
+    fn set_vehicle_maintenance(&mut self, maintenance: Vec<TimeWindow>) -> &mut Self;
+
+    /// Gets vehicle's physical limits.
+    fn get_vehicle_physical_limits(&self) -> Option<&VehiclePhysicalLimits>;
+    /// Sets vehicle's physical limits.
+    fn set_vehicle_physical_limits(&mut self, limits: VehiclePhysicalLimits) -> &mut Self;
+
+    /// Returns true if departure time of vehicle's tour should be optimized.
+    fn get_departure_optimization(&self) -> bool;
+    /// Sets departure optimization flag.
+    fn set_departure_optimization(&mut self, optimize: bool) -> &mut Self;
+
+    /// Gets max working time of a single driver.
+    fn get_driver_shift_time(&self) -> Option<Duration>;
+    /// Sets max working time of a single driver.
+    fn set_driver_shift_time(&mut self, shift_time: Duration) -> &mut Self;
 }
 
 impl VehicleTie for Dimensions {
@@ -74,6 +142,15 @@ impl VehicleTie for Dimensions {
         self
     }
 
+    fn get_vehicle_skill_counts(&self) -> Option<&HashMap<String, usize>> {
+        self.get_value("vehicle_skill_counts")
+    }
+
+    fn set_vehicle_skill_counts(&mut self, counts: HashMap<String, usize>) -> &mut Self {
+        self.set_value("vehicle_skill_counts", counts);
+        self
+    }
+
     fn get_areas(&self) -> Option<&HashMap<String, (usize, f64)>> {
         self.get_value("areas")
     }
@@ -83,6 +160,15 @@ impl VehicleTie for Dimensions {
         self
     }
 
+    fn get_vehicle_areas(&self) -> Option<&VehicleAreas> {
+        self.get_value("vehicle_areas")
+    }
+
+    fn set_vehicle_areas(&mut self, areas: VehicleAreas) -> &mut Self {
+        self.set_value("vehicle_areas", areas);
+        self
+    }
+
     fn get_tour_size(&self) -> Option<usize> {
         self.get_value("tour_size").cloned()
     }
@@ -91,6 +177,105 @@ impl VehicleTie for Dimensions {
         self.set_value("tour_size", tour_size);
         self
     }
+
+    fn get_min_tour_size(&self) -> Option<usize> {
+        self.get_value("min_tour_size").cloned()
+    }
+
+    fn set_min_tour_size(&mut self, min_tour_size: usize) -> &mut Self {
+        self.set_value("min_tour_size", min_tour_size);
+        self
+    }
+
+    fn get_vehicle_tier(&self) -> Option<usize> {
+        self.get_value("vehicle_tier").cloned()
+    }
+
+    fn set_vehicle_tier(&mut self, tier: usize) -> &mut Self {
+        self.set_value("vehicle_tier", tier);
+        self
+    }
+
+    fn get_loading_manifest(&self) -> bool {
+        self.get_value("loading_manifest").cloned().unwrap_or(false)
+    }
+
+    fn set_loading_manifest(&mut self, manifest: bool) -> &mut Self {
+        self.set_value("loading_manifest", manifest);
+        self
+    }
+
+    fn get_loading_order(&self) -> Option<LoadingOrder> {
+        self.get_value("loading_order").cloned()
+    }
+
+    fn set_loading_order(&mut self, order: LoadingOrder) -> &mut Self {
+        self.set_value("loading_order", order);
+        self
+    }
+
+    fn get_backhaul_policy(&self) -> Option<BackhaulPolicy> {
+        self.get_value("backhaul_policy").cloned()
+    }
+
+    fn set_backhaul_policy(&mut self, policy: BackhaulPolicy) -> &mut Self {
+        self.set_value("backhaul_policy", policy);
+        self
+    }
+
+    fn get_vehicle_energy(&self) -> Option<&EnergyProfile> {
+        self.get_value("vehicle_energy")
+    }
+
+    fn set_vehicle_energy(&mut self, energy: EnergyProfile) -> &mut Self {
+        self.set_value("vehicle_energy", energy);
+        self
+    }
+
+    fn get_vehicle_emissions(&self) -> Option<&EmissionProfile> {
+        self.get_value("vehicle_emissions")
+    }
+
+    fn set_vehicle_emissions(&mut self, emissions: EmissionProfile) -> &mut Self {
+        self.set_value("vehicle_emissions", emissions);
+        self
+    }
+
+    fn get_vehicle_maintenance(&self) -> Option<&Vec<TimeWindow>> {
+        self.get_value("vehicle_maintenance")
+    }
+
+    fn set_vehicle_maintenance(&mut self, maintenance: Vec<TimeWindow>) -> &mut Self {
+        self.set_value("vehicle_maintenance", maintenance);
+        self
+    }
+
+    fn get_vehicle_physical_limits(&self) -> Option<&VehiclePhysicalLimits> {
+        self.get_value("vehicle_physical_limits")
+    }
+
+    fn set_vehicle_physical_limits(&mut self, limits: VehiclePhysicalLimits) -> &mut Self {
+        self.set_value("vehicle_physical_limits", limits);
+        self
+    }
+
+    fn get_departure_optimization(&self) -> bool {
+        self.get_value("departure_optimization").cloned().unwrap_or(false)
+    }
+
+    fn set_departure_optimization(&mut self, optimize: bool) -> &mut Self {
+        self.set_value("departure_optimization", optimize);
+        self
+    }
+
+    fn get_driver_shift_time(&self) -> Option<Duration> {
+        self.get_value("driver_shift_time").cloned()
+    }
+
+    fn set_driver_shift_time(&mut self, shift_time: Duration) -> &mut Self {
+        self.set_value("driver_shift_time", shift_time);
+        self
+    }
 }
 
 /// Specifies job entity.
@@ -110,6 +295,11 @@ pub trait JobTie {
     /// Sets job place tags.
     fn set_place_tags(&mut self, tags: Option<Vec<(usize, String)>>) -> &mut Self;
 
+    /// Gets job place post service buffers.
+    fn get_place_buffers(&self) -> Option<&Vec<(usize, Duration)>>;
+    /// Sets job place post service buffers.
+    fn set_place_buffers(&mut self, buffers: Option<Vec<(usize, Duration)>>) -> &mut Self;
+
     /// Gets job order.
     fn get_job_order(&self) -> Option<i32>;
     /// Sets job order.
@@ -125,15 +315,85 @@ pub trait JobTie {
     /// Sets job group.
     fn set_job_group(&mut self, group: Option<String>) -> &mut Self;
 
+    /// Gets job bundle.
+    fn get_job_bundle(&self) -> Option<&String>;
+    /// Sets job bundle.
+    fn set_job_bundle(&mut self, bundle: Option<String>) -> &mut Self;
+
     /// Gets job compatibility.
     fn get_job_compatibility(&self) -> Option<&String>;
     /// Sets job compatibility.
     fn set_job_compatibility(&mut self, compatibility: Option<String>) -> &mut Self;
 
+    /// Gets job reservation flag.
+    fn get_job_reservation(&self) -> Option<bool>;
+    /// Sets job reservation flag.
+    fn set_job_reservation(&mut self, reservation: Option<bool>) -> &mut Self;
+
+    /// Gets amount of failed planning attempts of the job.
+    fn get_job_attempts(&self) -> Option<usize>;
+    /// Sets amount of failed planning attempts of the job.
+    fn set_job_attempts(&mut self, attempts: Option<usize>) -> &mut Self;
+
+    /// Gets job visit gap: min and max time between consecutive visits.
+    fn get_job_visit_gap(&self) -> Option<(Option<Duration>, Option<Duration>)>;
+    /// Sets job visit gap.
+    fn set_job_visit_gap(&mut self, visit_gap: Option<(Option<Duration>, Option<Duration>)>) -> &mut Self;
+
+    /// Gets job placement rank: lower values are delivered first within a trip.
+    fn get_job_placement(&self) -> Option<usize>;
+    /// Sets job placement rank.
+    fn set_job_placement(&mut self, placement: Option<usize>) -> &mut Self;
+
+    /// Gets job truck only flag: such job cannot be served by vehicle with attached trailer.
+    fn get_job_truck_only(&self) -> Option<bool>;
+    /// Sets job truck only flag.
+    fn set_job_truck_only(&mut self, truck_only: Option<bool>) -> &mut Self;
+
+    /// Gets job max ride duration: max time between pickup completion and delivery start.
+    fn get_job_max_ride_duration(&self) -> Option<Duration>;
+    /// Sets job max ride duration.
+    fn set_job_max_ride_duration(&mut self, max_ride_duration: Option<Duration>) -> &mut Self;
+
+    /// Gets job sync: jobs with the same sync key are served simultaneously by different vehicles.
+    fn get_job_sync(&self) -> Option<&JobSync>;
+    /// Sets job sync.
+    fn set_job_sync(&mut self, sync: Option<JobSync>) -> &mut Self;
+
+    /// Gets job approach distance: a distance of the final approach to the job, e.g. on foot.
+    fn get_job_approach_distance(&self) -> Option<Distance>;
+    /// Sets job approach distance.
+    fn set_job_approach_distance(&mut self, distance: Option<Distance>) -> &mut Self;
+
+    /// Gets job territory.
+    fn get_job_territory(&self) -> Option<&Arc<JobTerritory>>;
+    /// Sets job territory.
+    fn set_job_territory(&mut self, territory: Option<Arc<JobTerritory>>) -> &mut Self;
+
+    /// Gets ids of plan areas which job belongs to.
+    fn get_job_areas(&self) -> Option<&HashSet<String>>;
+    /// Sets ids of plan areas which job belongs to.
+    fn set_job_areas(&mut self, areas: Option<HashSet<String>>) -> &mut Self;
+
+    /// Gets job zones: pairs of zone sequence index and position of job's zone within the sequence.
+    fn get_job_zones(&self) -> Option<&Vec<(usize, usize)>>;
+    /// Sets job zones.
+    fn set_job_zones(&mut self, zones: Option<Vec<(usize, usize)>>) -> &mut Self;
+
+    /// Gets job dock: a shared dock resource id used by reload job.
+    fn get_job_dock(&self) -> Option<&String>;
+    /// Sets job dock.
+    fn set_job_dock(&mut self, dock: Option<String>) -> &mut Self;
+
     /// Gets job (activity) type.
     fn get_job_type(&self) -> Option<&String>;
     /// Sets job (activity) type
     fn set_job_type(&mut self, job_type: String) -> &mut Self;
+
+    /// Gets physical attributes of job's items.
+    fn get_job_item(&self) -> Option<&ItemAttributes>;
+    /// Sets physical attributes of job's items.
+    fn set_job_item(&mut self, item: Option<ItemAttributes>) -> &mut Self;
 }
 
 impl JobTie for Dimensions {
@@ -174,6 +434,20 @@ impl JobTie for Dimensions {
         self
     }
 
+    fn get_place_buffers(&self) -> Option<&Vec<(usize, Duration)>> {
+        self.get_value("job_buffers")
+    }
+
+    fn set_place_buffers(&mut self, buffers: Option<Vec<(usize, Duration)>>) -> &mut Self {
+        if let Some(buffers) = buffers {
+            self.set_value("job_buffers", buffers);
+        } else {
+            self.remove("job_buffers");
+        }
+
+        self
+    }
+
     fn get_job_order(&self) -> Option<i32> {
         self.get_value("job_order").cloned()
     }
@@ -216,6 +490,20 @@ impl JobTie for Dimensions {
         self
     }
 
+    fn get_job_bundle(&self) -> Option<&String> {
+        self.get_value("job_bundle")
+    }
+
+    fn set_job_bundle(&mut self, bundle: Option<String>) -> &mut Self {
+        if let Some(bundle) = bundle {
+            self.set_value("job_bundle", bundle);
+        } else {
+            self.remove("job_bundle");
+        }
+
+        self
+    }
+
     fn get_job_compatibility(&self) -> Option<&String> {
         self.get_value("job_compat")
     }
@@ -230,6 +518,174 @@ impl JobTie for Dimensions {
         self
     }
 
+    fn get_job_reservation(&self) -> Option<bool> {
+        self.get_value("job_reservation").cloned()
+    }
+
+    fn set_job_reservation(&mut self, reservation: Option<bool>) -> &mut Self {
+        if let Some(reservation) = reservation {
+            self.set_value("job_reservation", reservation);
+        } else {
+            self.remove("job_reservation");
+        }
+
+        self
+    }
+
+    fn get_job_attempts(&self) -> Option<usize> {
+        self.get_value("job_attempts").cloned()
+    }
+
+    fn set_job_attempts(&mut self, attempts: Option<usize>) -> &mut Self {
+        if let Some(attempts) = attempts {
+            self.set_value("job_attempts", attempts);
+        } else {
+            self.remove("job_attempts");
+        }
+
+        self
+    }
+
+    fn get_job_visit_gap(&self) -> Option<(Option<Duration>, Option<Duration>)> {
+        self.get_value("job_visit_gap").cloned()
+    }
+
+    fn set_job_visit_gap(&mut self, visit_gap: Option<(Option<Duration>, Option<Duration>)>) -> &mut Self {
+        if let Some(visit_gap) = visit_gap {
+            self.set_value("job_visit_gap", visit_gap);
+        } else {
+            self.remove("job_visit_gap");
+        }
+
+        self
+    }
+
+    fn get_job_placement(&self) -> Option<usize> {
+        self.get_value("job_placement").cloned()
+    }
+
+    fn set_job_placement(&mut self, placement: Option<usize>) -> &mut Self {
+        if let Some(placement) = placement {
+            self.set_value("job_placement", placement);
+        } else {
+            self.remove("job_placement");
+        }
+
+        self
+    }
+
+    fn get_job_truck_only(&self) -> Option<bool> {
+        self.get_value("job_truck_only").cloned()
+    }
+
+    fn set_job_truck_only(&mut self, truck_only: Option<bool>) -> &mut Self {
+        if let Some(truck_only) = truck_only {
+            self.set_value("job_truck_only", truck_only);
+        } else {
+            self.remove("job_truck_only");
+        }
+
+        self
+    }
+
+    fn get_job_max_ride_duration(&self) -> Option<Duration> {
+        self.get_value("job_max_ride_duration").cloned()
+    }
+
+    fn set_job_max_ride_duration(&mut self, max_ride_duration: Option<Duration>) -> &mut Self {
+        if let Some(max_ride_duration) = max_ride_duration {
+            self.set_value("job_max_ride_duration", max_ride_duration);
+        } else {
+            self.remove("job_max_ride_duration");
+        }
+
+        self
+    }
+
+    fn get_job_sync(&self) -> Option<&JobSync> {
+        self.get_value("job_sync")
+    }
+
+    fn set_job_sync(&mut self, sync: Option<JobSync>) -> &mut Self {
+        if let Some(sync) = sync {
+            self.set_value("job_sync", sync);
+        } else {
+            self.remove("job_sync");
+        }
+
+        self
+    }
+
+    fn get_job_approach_distance(&self) -> Option<Distance> {
+        self.get_value("job_approach_distance").cloned()
+    }
+
+    fn set_job_approach_distance(&mut self, distance: Option<Distance>) -> &mut Self {
+        if let Some(distance) = distance {
+            self.set_value("job_approach_distance", distance);
+        } else {
+            self.remove("job_approach_distance");
+        }
+
+        self
+    }
+
+    fn get_job_territory(&self) -> Option<&Arc<JobTerritory>> {
+        self.get_value("job_territory")
+    }
+
+    fn set_job_territory(&mut self, territory: Option<Arc<JobTerritory>>) -> &mut Self {
+        if let Some(territory) = territory {
+            self.set_value("job_territory", territory);
+        } else {
+            self.remove("job_territory");
+        }
+
+        self
+    }
+
+    fn get_job_areas(&self) -> Option<&HashSet<String>> {
+        self.get_value("job_areas")
+    }
+
+    fn set_job_areas(&mut self, areas: Option<HashSet<String>>) -> &mut Self {
+        if let Some(areas) = areas {
+            self.set_value("job_areas", areas);
+        } else {
+            self.remove("job_areas");
+        }
+
+        self
+    }
+
+    fn get_job_zones(&self) -> Option<&Vec<(usize, usize)>> {
+        self.get_value("job_zones")
+    }
+
+    fn set_job_zones(&mut self, zones: Option<Vec<(usize, usize)>>) -> &mut Self {
+        if let Some(zones) = zones {
+            self.set_value("job_zones", zones);
+        } else {
+            self.remove("job_zones");
+        }
+
+        self
+    }
+
+    fn get_job_dock(&self) -> Option<&String> {
+        self.get_value("job_dock")
+    }
+
+    fn set_job_dock(&mut self, dock: Option<String>) -> &mut Self {
+        if let Some(dock) = dock {
+            self.set_value("job_dock", dock);
+        } else {
+            self.remove("job_dock");
+        }
+
+        self
+    }
+
     fn get_job_type(&self) -> Option<&String> {
         self.get_value("job_type")
     }
@@ -238,6 +694,20 @@ impl JobTie for Dimensions {
         self.set_value("job_type", job_type);
         self
     }
+
+    fn get_job_item(&self) -> Option<&ItemAttributes> {
+        self.get_value("job_item")
+    }
+
+    fn set_job_item(&mut self, item: Option<ItemAttributes>) -> &mut Self {
+        if let Some(item) = item {
+            self.set_value("job_item", item);
+        } else {
+            self.remove("job_item");
+        }
+
+        self
+    }
 }
 
 /// Specifies break entity.
@@ -258,3 +728,41 @@ impl BreakTie for Dimensions {
         self
     }
 }
+
+/// Specifies recharge entity.
+pub trait RechargeTie {
+    /// Gets recharge rate: amount of energy restored per time unit.
+    fn get_recharge_rate(&self) -> Option<f64>;
+    /// Sets recharge rate.
+    fn set_recharge_rate(&mut self, rate: f64) -> &mut Self;
+}
+
+impl RechargeTie for Dimensions {
+    fn get_recharge_rate(&self) -> Option<f64> {
+        self.get_value("recharge_rate").cloned()
+    }
+
+    fn set_recharge_rate(&mut self, rate: f64) -> &mut Self {
+        self.set_value("recharge_rate", rate);
+        self
+    }
+}
+
+/// Specifies trailer entity.
+pub trait TrailerTie<T: LoadOps> {
+    /// Gets truck capacity: vehicle's capacity available while its trailer is detached.
+    fn get_truck_capacity(&self) -> Option<&T>;
+    /// Sets truck capacity.
+    fn set_truck_capacity(&mut self, capacity: T) -> &mut Self;
+}
+
+impl<T: LoadOps> TrailerTie<T> for Dimensions {
+    fn get_truck_capacity(&self) -> Option<&T> {
+        self.get_value("truck_capacity")
+    }
+
+    fn set_truck_capacity(&mut self, capacity: T) -> &mut Self {
+        self.set_value("truck_capacity", capacity);
+        self
+    }
+}
@@ -135,6 +135,27 @@ const TOUR_ORDER_CONSTRAINT_CODE: i32 = 12;
 const GROUP_CONSTRAINT_CODE: i32 = 13;
 const COMPATIBILITY_CONSTRAINT_CODE: i32 = 14;
 const RELOAD_RESOURCE_CONSTRAINT_CODE: i32 = 15;
+const TOTAL_COST_LIMIT_CONSTRAINT_CODE: i32 = 16;
+const CUSTOM_ROUTE_CONSTRAINT_CODE: i32 = 17;
+const CONSECUTIVE_DRIVING_CONSTRAINT_CODE: i32 = 18;
+const VISIT_GAP_CONSTRAINT_CODE: i32 = 19;
+const PLACEMENT_CONSTRAINT_CODE: i32 = 20;
+const ENERGY_CONSTRAINT_CODE: i32 = 21;
+const TRAILER_CONSTRAINT_CODE: i32 = 22;
+const RIDE_DURATION_CONSTRAINT_CODE: i32 = 23;
+const LOADING_ORDER_CONSTRAINT_CODE: i32 = 24;
+const BACKHAUL_CONSTRAINT_CODE: i32 = 25;
+const SYNC_CONSTRAINT_CODE: i32 = 26;
+const PRECEDENCE_CONSTRAINT_CODE: i32 = 27;
+const DRIVING_TIME_CONSTRAINT_CODE: i32 = 28;
+const TERRITORY_CONSTRAINT_CODE: i32 = 29;
+const DOCK_CONSTRAINT_CODE: i32 = 30;
+const AREA_RESTRICTION_CONSTRAINT_CODE: i32 = 31;
+const COST_LIMIT_CONSTRAINT_CODE: i32 = 32;
+const PHYSICAL_CONSTRAINT_CODE: i32 = 33;
+const UNLOADING_CONSTRAINT_CODE: i32 = 34;
+const BUNDLE_CONSTRAINT_CODE: i32 = 35;
+const RELAY_CONSTRAINT_CODE: i32 = 36;
 
 /// An job id to job index.
 pub type JobIndex = HashMap<String, CoreJob>;
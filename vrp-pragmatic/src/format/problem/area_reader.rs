@@ -0,0 +1,77 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/area_reader_test.rs"]
+mod area_reader_test;
+
+use super::ApiProblem;
+use crate::format::problem::get_job_tasks;
+use crate::format::Location;
+
+/// Normalizes plan areas defined by polygons: ids of jobs which have all their places inside of
+/// area's polygon are added to the area's job list, so the rest of the logic works only with job ids.
+/// Places specified by location index cannot be evaluated and are considered as outside of any polygon.
+pub(crate) fn normalize_areas(mut api_problem: ApiProblem) -> ApiProblem {
+    let has_polygons = api_problem.plan.areas.iter().flatten().any(|area| area.polygon.is_some());
+    if !has_polygons {
+        return api_problem;
+    }
+
+    // NOTE job coordinates are extracted once and reused for all polygons
+    let job_points = api_problem
+        .plan
+        .jobs
+        .iter()
+        .filter_map(|job| {
+            get_job_tasks(job)
+                .flat_map(|task| task.places.iter())
+                .map(|place| get_point(&place.location))
+                .collect::<Option<Vec<_>>>()
+                .filter(|points| !points.is_empty())
+                .map(|points| (job.id.clone(), points))
+        })
+        .collect::<Vec<_>>();
+
+    api_problem.plan.areas.iter_mut().flatten().for_each(|area| {
+        let polygon = match area.polygon.as_ref() {
+            Some(polygon) => polygon.iter().filter_map(get_point).collect::<Vec<_>>(),
+            None => return,
+        };
+
+        let new_jobs = job_points
+            .iter()
+            .filter(|(job_id, points)| {
+                !area.jobs.contains(job_id) && points.iter().all(|point| is_inside_polygon(*point, polygon.as_slice()))
+            })
+            .map(|(job_id, _)| job_id.clone())
+            .collect::<Vec<_>>();
+
+        area.jobs.extend(new_jobs);
+    });
+
+    api_problem
+}
+
+/// Checks whether a point is inside of polygon using ray casting algorithm.
+fn is_inside_polygon(point: (f64, f64), polygon: &[(f64, f64)]) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+
+    let (x, y) = point;
+
+    (0..polygon.len())
+        .zip((0..polygon.len()).cycle().skip(polygon.len() - 1))
+        .filter(|&(i, j)| {
+            let ((xi, yi), (xj, yj)) = (polygon[i], polygon[j]);
+            (yi > y) != (yj > y) && x < (xj - xi) * (y - yi) / (yj - yi) + xi
+        })
+        .count()
+        % 2
+        == 1
+}
+
+fn get_point(location: &Location) -> Option<(f64, f64)> {
+    match location {
+        Location::Coordinate { lat, lng } => Some((*lat, *lng)),
+        Location::Reference { .. } => None,
+    }
+}
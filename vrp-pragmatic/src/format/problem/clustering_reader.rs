@@ -9,7 +9,10 @@ use vrp_core::construction::clustering::vicinity::ClusterConfig;
 use vrp_core::construction::clustering::vicinity::*;
 use vrp_core::models::common::Profile;
 use vrp_core::models::problem::Job;
-use vrp_core::prelude::*;
+use vrp_core::prelude::compare_floats;
+
+/// A default minimum time window overlap used by time window clustering.
+const DEFAULT_MIN_TIME_WINDOW_OVERLAP: f64 = 0.5;
 
 /// Creates cluster config if it is defined on the api problem.
 pub(crate) fn create_cluster_config(api_problem: &ApiProblem) -> Result<Option<ClusterConfig>, String> {
@@ -21,6 +24,7 @@ pub(crate) fn create_cluster_config(api_problem: &ApiProblem) -> Result<Option<C
                     moving_duration: threshold.distance,
                     moving_distance: threshold.duration,
                     min_shared_time: threshold.min_shared_time,
+                    min_time_window_overlap: None,
                     smallest_time_window: threshold.smallest_time_window,
                     max_jobs_per_cluster: threshold.max_jobs_per_cluster,
                 },
@@ -28,16 +32,33 @@ pub(crate) fn create_cluster_config(api_problem: &ApiProblem) -> Result<Option<C
                     VicinityVisitPolicy::Continue => VisitPolicy::ClosedContinuation,
                     VicinityVisitPolicy::Return => VisitPolicy::Return,
                 },
-                serving: match *serving {
-                    VicinityServingPolicy::Original { parking } => ServingPolicy::Original { parking },
-                    VicinityServingPolicy::Multiplier { value, parking } => {
-                        ServingPolicy::Multiplier { multiplier: value, parking }
-                    }
-                    VicinityServingPolicy::Fixed { value, parking } => ServingPolicy::Fixed { value, parking },
-                },
+                serving: get_serving_policy(serving),
                 filtering: get_filter_policy(filtering.as_ref()),
                 building: get_builder_policy(),
             })),
+            Clustering::TimeWindow { profile, threshold, serving, filtering } => {
+                let min_overlap = threshold.min_overlap.unwrap_or(DEFAULT_MIN_TIME_WINDOW_OVERLAP);
+                if !(0. ..=1.).contains(&min_overlap) {
+                    return Err(format!("min overlap should be in range [0, 1], got: {}", min_overlap));
+                }
+
+                Ok(Some(ClusterConfig {
+                    profile: get_profile(api_problem, profile)?,
+                    threshold: ThresholdPolicy {
+                        moving_duration: threshold.duration,
+                        moving_distance: threshold.distance,
+                        min_shared_time: None,
+                        min_time_window_overlap: Some(min_overlap),
+                        smallest_time_window: None,
+                        max_jobs_per_cluster: threshold.max_jobs_per_cluster,
+                    },
+                    // NOTE jobs are served one by one as a sequence which starts and ends at parking place
+                    visiting: VisitPolicy::ClosedContinuation,
+                    serving: get_serving_policy(serving),
+                    filtering: get_filter_policy(filtering.as_ref()),
+                    building: get_builder_policy(),
+                }))
+            }
         }
     } else {
         Ok(None)
@@ -54,6 +75,16 @@ fn get_profile(api_problem: &ApiProblem, profile: &VehicleProfile) -> Result<Pro
     Ok(Profile { index: profile_index, scale: profile.scale.unwrap_or(1.) })
 }
 
+fn get_serving_policy(serving: &VicinityServingPolicy) -> ServingPolicy {
+    match *serving {
+        VicinityServingPolicy::Original { parking } => ServingPolicy::Original { parking },
+        VicinityServingPolicy::Multiplier { value, parking } => {
+            ServingPolicy::Multiplier { multiplier: value, parking }
+        }
+        VicinityServingPolicy::Fixed { value, parking } => ServingPolicy::Fixed { value, parking },
+    }
+}
+
 fn get_builder_policy() -> BuilderPolicy {
     // NOTE use ordering rule which is based on job id to make clusters stable
     let ordering_rule = |result: Ordering, left_job: &Job, right_job: &Job| match result {
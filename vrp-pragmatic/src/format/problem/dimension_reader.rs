@@ -0,0 +1,61 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/dimension_reader_test.rs"]
+mod dimension_reader_test;
+
+use super::ApiProblem;
+use crate::format::problem::{LoadDimension, NamedLoad};
+
+/// Normalizes vehicle capacity and job demand specified by named load dimensions to anonymous
+/// vectors which follow the order of dimensions declared on fleet, so the rest of the logic works
+/// only with vectors. Named values take precedence over anonymous ones, undeclared dimensions are ignored.
+pub(crate) fn normalize_dimensions(mut api_problem: ApiProblem) -> ApiProblem {
+    let names = match api_problem.fleet.dimensions.as_ref() {
+        Some(dimensions) if !dimensions.is_empty() => {
+            dimensions.iter().map(|dimension| dimension.name.clone()).collect::<Vec<_>>()
+        }
+        _ => return api_problem,
+    };
+
+    let to_vec = |named: &[NamedLoad]| {
+        names
+            .iter()
+            .map(|name| named.iter().filter(|load| load.dimension == *name).map(|load| load.value).sum())
+            .collect::<Vec<i32>>()
+    };
+
+    api_problem.fleet.vehicles.iter_mut().for_each(|vehicle| {
+        if let Some(named_capacity) = vehicle.named_capacity.as_ref() {
+            vehicle.capacity = to_vec(named_capacity.as_slice());
+        }
+    });
+
+    api_problem
+        .plan
+        .jobs
+        .iter_mut()
+        .flat_map(|job| {
+            job.pickups
+                .iter_mut()
+                .chain(job.deliveries.iter_mut())
+                .chain(job.services.iter_mut())
+                .chain(job.replacements.iter_mut())
+                .flatten()
+        })
+        .for_each(|task| {
+            if let Some(named_demand) = task.named_demand.as_ref() {
+                task.demand = Some(to_vec(named_demand.as_slice()));
+            }
+        });
+
+    api_problem
+}
+
+/// Returns indices of load dimensions which cannot be loaded or unloaded at reload.
+pub(crate) fn get_non_reloadable_dimensions(dimensions: Option<&Vec<LoadDimension>>) -> Vec<usize> {
+    dimensions
+        .iter()
+        .flat_map(|dimensions| dimensions.iter().enumerate())
+        .filter(|(_, dimension)| !dimension.is_reloadable.unwrap_or(true))
+        .map(|(idx, _)| idx)
+        .collect()
+}
@@ -2,13 +2,18 @@
 #[path = "../../../tests/unit/format/problem/fleet_reader_test.rs"]
 mod fleet_reader_test;
 
-use crate::extensions::{create_typed_actor_groups, VehicleTie};
+use crate::constraints::{
+    BackhaulPolicy, EmissionProfile, EnergyProfile, LoadingOrder, VehicleAreas, VehiclePhysicalLimits,
+};
+use crate::extensions::{create_typed_actor_groups, TrailerTie, VehicleTie};
 use crate::format::coord_index::CoordIndex;
-use crate::format::problem::reader::{ApiProblem, ProblemProperties};
-use crate::format::problem::Matrix;
+use crate::format::problem::reader::{parse_time_window, ApiProblem, ProblemProperties};
+use crate::format::problem::{EmissionClass, Matrix, VehicleCompartment, VehicleLoadingOrder};
 use crate::parse_time;
 use hashbrown::{HashMap, HashSet};
+use std::iter::once;
 use std::sync::Arc;
+use vrp_core::construction::constraints::{DrivingRules, DrivingRulesDimension, ShiftOvertime, ShiftOvertimeDimension};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::*;
 
@@ -100,7 +105,7 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
             fixed: vehicle.costs.fixed.unwrap_or(0.),
             per_distance: vehicle.costs.distance,
             per_driving_time: vehicle.costs.time,
-            per_waiting_time: vehicle.costs.time,
+            per_waiting_time: vehicle.costs.waiting.unwrap_or(vehicle.costs.time),
             per_service_time: vehicle.costs.time,
         };
 
@@ -108,6 +113,7 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
         let profile = Profile::new(index, vehicle.profile.scale);
 
         let tour_size = vehicle.limits.as_ref().and_then(|l| l.tour_size);
+        let min_tour_size = vehicle.limits.as_ref().and_then(|l| l.min_tour_size);
         let mut area_jobs = vehicle.limits.as_ref().and_then(|l| l.areas.as_ref()).map({
             let area_index = &area_index;
             move |areas| {
@@ -138,22 +144,38 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                 (location, earliest, latest)
             };
 
+            // NOTE overtime extends hard shift end limit, its cost is handled separately
+            let overtime = shift.end.as_ref().zip(shift.overtime.as_ref()).map(|(end, overtime)| {
+                (
+                    overtime.max_duration,
+                    ShiftOvertime {
+                        regular_end: parse_time(&end.latest),
+                        cost_per_time: overtime.cost_per_hour / 3600.,
+                    },
+                )
+            });
             let end = shift.end.as_ref().map(|end| {
                 let location = coord_index.get_by_loc(&end.location).unwrap();
-                let time = parse_time(&end.latest);
+                let time = parse_time(&end.latest) + overtime.as_ref().map_or(0., |(max_duration, _)| *max_duration);
                 (location, time)
             });
+            let is_open = shift.end.as_ref().and_then(|end| end.is_open).unwrap_or(false);
 
-            let details = vec![VehicleDetail {
-                start: Some(VehiclePlace {
-                    location: start.0,
-                    time: TimeInterval { earliest: Some(start.1), latest: start.2 },
-                }),
-                end: end.map(|(location, time)| VehiclePlace {
-                    location,
-                    time: TimeInterval { earliest: None, latest: Some(time) },
-                }),
-            }];
+            let depots = shift.depots.iter().flatten().map(|depot| coord_index.get_by_loc(depot).unwrap());
+            let details = once((start.0, end.map(|(location, _)| location)))
+                .chain(depots.map(|depot| (depot, end.map(|_| depot))))
+                .map(|(start_location, end_location)| VehicleDetail {
+                    start: Some(VehiclePlace {
+                        location: start_location,
+                        time: TimeInterval { earliest: Some(start.1), latest: start.2 },
+                    }),
+                    end: end.zip(end_location).map(|((_, time), location)| VehiclePlace {
+                        location,
+                        time: TimeInterval { earliest: None, latest: Some(time) },
+                    }),
+                    is_open,
+                })
+                .collect::<Vec<_>>();
 
             vehicle.vehicle_ids.iter().for_each(|vehicle_id| {
                 let mut dimens: Dimensions = Default::default();
@@ -171,16 +193,146 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     dimens.set_tour_size(tour_size);
                 }
 
+                if let Some(min_tour_size) = min_tour_size {
+                    dimens.set_min_tour_size(min_tour_size);
+                }
+
+                if let Some(allowed_areas) = vehicle.limits.as_ref().and_then(|limits| limits.allowed_areas.as_ref()) {
+                    dimens.set_vehicle_areas(VehicleAreas {
+                        areas: allowed_areas.areas.iter().cloned().collect(),
+                        penalty: allowed_areas.penalty,
+                    });
+                }
+
+                if let Some(tier) = vehicle.tier {
+                    dimens.set_vehicle_tier(tier);
+                }
+
+                if let Some(rate) = vehicle.duration_per_unit {
+                    dimens.set_service_rate(rate);
+                }
+
+                if let Some(manifest) = vehicle.loading.as_ref().and_then(|loading| loading.manifest) {
+                    dimens.set_loading_manifest(manifest);
+                }
+
+                if let Some(order) = vehicle.loading.as_ref().and_then(|loading| loading.order) {
+                    dimens.set_loading_order(match order {
+                        VehicleLoadingOrder::Lifo => LoadingOrder::Lifo,
+                        VehicleLoadingOrder::Fifo => LoadingOrder::Fifo,
+                    });
+                }
+
+                if let Some(backhaul) = vehicle.loading.as_ref().and_then(|loading| loading.backhaul.as_ref()) {
+                    dimens.set_backhaul_policy(match backhaul.penalty {
+                        Some(penalty) => BackhaulPolicy::Soft(penalty),
+                        None => BackhaulPolicy::Strict,
+                    });
+                }
+
+                if let Some(driving_time) = vehicle.limits.as_ref().and_then(|limits| limits.driving_time.as_ref()) {
+                    dimens.set_driving_rules(DrivingRules {
+                        max_driving_time: driving_time.max_driving_time,
+                        break_duration: driving_time.break_duration,
+                        max_working_time: driving_time.max_working_time,
+                        rest_duration: driving_time.rest_duration.unwrap_or(0.),
+                    });
+                }
+
+                if let Some((_, overtime)) = overtime.as_ref() {
+                    dimens.set_shift_overtime(overtime.clone());
+                }
+
+                if let Some(maintenance) = shift.maintenance.as_ref() {
+                    dimens.set_vehicle_maintenance(maintenance.iter().map(|tw| parse_time_window(tw)).collect());
+                }
+
+                if let Some(energy) = vehicle.energy.as_ref() {
+                    dimens.set_vehicle_energy(EnergyProfile {
+                        capacity: energy.capacity,
+                        consumption: energy.consumption,
+                        load_consumption: energy.load_consumption.unwrap_or(0.),
+                        reserve: energy.reserve.unwrap_or(0.),
+                    });
+                }
+
+                if let Some(emissions) = vehicle.emissions.as_ref() {
+                    dimens.set_vehicle_emissions(EmissionProfile {
+                        consumption: emissions.consumption,
+                        load_consumption: emissions.load_consumption.unwrap_or(0.),
+                        co2_factor: get_co2_factor(emissions.class),
+                    });
+                }
+
+                if let Some(shift_time) = vehicle.limits.as_ref().and_then(|limits| limits.driver_shift_time) {
+                    dimens.set_driver_shift_time(shift_time);
+                }
+
+                if let Some(optimize) = vehicle.optimize_departure {
+                    dimens.set_departure_optimization(optimize);
+                }
+
+                if let Some(physical) = vehicle.physical.as_ref() {
+                    dimens.set_vehicle_physical_limits(VehiclePhysicalLimits {
+                        max_axle_weight: physical.max_axle_weight,
+                        max_item_length: physical.max_item_length,
+                        max_item_height: physical.max_item_height,
+                    });
+                }
+
                 if props.has_multi_dimen_capacity {
                     dimens.set_capacity(MultiDimLoad::new(vehicle.capacity.clone()));
                 } else {
                     dimens.set_capacity(SingleDimLoad::new(*vehicle.capacity.first().unwrap()));
                 }
 
+                if let Some(trailer) = vehicle.trailer.as_ref() {
+                    let truck_capacity = vehicle
+                        .capacity
+                        .iter()
+                        .zip(trailer.capacity.iter())
+                        .map(|(capacity, trailer)| capacity - trailer)
+                        .collect::<Vec<_>>();
+
+                    if props.has_multi_dimen_capacity {
+                        dimens.set_truck_capacity(MultiDimLoad::new(truck_capacity));
+                    } else {
+                        dimens.set_truck_capacity(SingleDimLoad::new(*truck_capacity.first().unwrap()));
+                    }
+                }
+
+                if let Some(compartments) = vehicle.compartments.as_ref() {
+                    if props.has_multi_dimen_capacity {
+                        dimens.set_compartments(get_compartments(compartments, |capacity| {
+                            MultiDimLoad::new(capacity.to_vec())
+                        }));
+                    } else {
+                        dimens.set_compartments(get_compartments(compartments, |capacity| {
+                            SingleDimLoad::new(*capacity.first().unwrap())
+                        }));
+                    }
+                }
+
                 if let Some(skills) = vehicle.skills.as_ref() {
                     dimens.set_vehicle_skills(skills.iter().cloned().collect::<HashSet<_>>());
                 }
 
+                if let Some(skill_counts) = vehicle.skill_counts.as_ref() {
+                    let counts = skill_counts
+                        .iter()
+                        .map(|skill_count| (skill_count.skill.clone(), skill_count.count))
+                        .collect::<HashMap<_, _>>();
+                    let skills = dimens
+                        .get_vehicle_skills()
+                        .cloned()
+                        .unwrap_or_default()
+                        .into_iter()
+                        .chain(counts.iter().filter(|(_, count)| **count > 0).map(|(skill, _)| skill.clone()))
+                        .collect::<HashSet<_>>();
+
+                    dimens.set_vehicle_skills(skills).set_vehicle_skill_counts(counts);
+                }
+
                 vehicles.push(Arc::new(Vehicle {
                     profile: profile.clone(),
                     costs: costs.clone(),
@@ -205,3 +357,28 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
 
     Fleet::new(drivers, vehicles, Box::new(|actors| create_typed_actor_groups(actors)))
 }
+
+fn get_compartments<T: LoadOps>(
+    compartments: &[VehicleCompartment],
+    create_capacity: impl Fn(&[i32]) -> T,
+) -> Vec<Compartment<T>> {
+    compartments
+        .iter()
+        .map(|compartment| Compartment {
+            id: compartment.id.clone(),
+            capacity: create_capacity(compartment.capacity.as_slice()),
+            classes: compartment.classes.iter().cloned().collect(),
+        })
+        .collect()
+}
+
+/// Returns amount of CO2 emitted per fuel unit.
+fn get_co2_factor(class: EmissionClass) -> f64 {
+    match class {
+        EmissionClass::Diesel => 2.68,
+        EmissionClass::Petrol => 2.31,
+        EmissionClass::Lpg => 1.51,
+        EmissionClass::Cng => 2.54,
+        EmissionClass::Electric => 0.,
+    }
+}
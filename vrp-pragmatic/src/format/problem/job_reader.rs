@@ -1,19 +1,26 @@
-use crate::constraints::{BreakPolicy, JobSkills as ConstraintJobSkills};
-use crate::extensions::{BreakTie, JobTie, VehicleTie};
+use crate::constraints::{
+    BreakPolicy, ItemAttributes, JobSkills as ConstraintJobSkills, JobSync as ConstraintJobSync, JobTerritory,
+};
+use crate::extensions::{BreakTie, JobTie, RechargeTie, VehicleTie};
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::reader::{parse_time_window, ApiProblem, ProblemProperties};
 use crate::format::problem::JobSkills as FormatJobSkills;
+use crate::format::problem::JobSync as FormatJobSync;
 use crate::format::problem::*;
 use crate::format::{JobIndex, Location};
 use crate::parse_time;
 use crate::utils::VariableJobPermutation;
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use std::cmp::Ordering;
 use std::sync::Arc;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Actor, Fleet, Job, Jobs, Multi, Place, Single, TransportCost};
+use vrp_core::models::problem::{Actor, Fleet, Job, Jobs, Multi, Place, ServiceRateDimension, Single, TransportCost};
 use vrp_core::models::{Lock, LockDetail, LockOrder, LockPosition};
-use vrp_core::prelude::*;
+use vrp_core::prelude::{
+    compare_floats, Demand, Dimensions, Duration, MultiDimLoad, Random, SingleDimLoad, TimeSpan, TimeWindow,
+};
+use vrp_core::solver::objectives::UnassignedPenaltyDimension;
+use vrp_core::solver::search::PartitionDimension;
 
 // TODO configure sample size
 const MULTI_JOB_SAMPLE_SIZE: usize = 3;
@@ -31,7 +38,7 @@ pub(crate) fn read_jobs_with_extra_locks(
     random: &Arc<dyn Random + Send + Sync>,
 ) -> (Jobs, Vec<Arc<Lock>>) {
     let (mut jobs, mut locks) = read_required_jobs(api_problem, props, coord_index, job_index, random);
-    let (conditional_jobs, conditional_locks) = read_conditional_jobs(api_problem, coord_index, job_index);
+    let (conditional_jobs, conditional_locks) = read_conditional_jobs(api_problem, props, coord_index, job_index);
 
     jobs.extend(conditional_jobs);
     locks.extend(conditional_locks);
@@ -73,7 +80,7 @@ pub fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Loc
                 .filter(|job| job.as_str() != "departure" && job.as_str() != "arrival")
                 .fold((HashMap::<String, _>::default(), vec![]), |(mut indexer, mut jobs), job| {
                     let job_id = match job.as_str() {
-                        "break" | "dispatch" | "reload" => {
+                        "break" | "dispatch" | "reload" | "recharge" | "relay" => {
                             let entry = indexer.entry(job.clone()).or_insert(1_usize);
                             let job_index = *entry;
                             *entry += 1;
@@ -111,6 +118,11 @@ fn read_required_jobs(
 ) -> (Vec<Job>, Vec<Arc<Lock>>) {
     let mut jobs = vec![];
     let has_multi_dimens = props.has_multi_dimen_capacity;
+    let job_blackouts = get_job_blackouts(api_problem);
+    let job_territories = get_job_territories(api_problem);
+    let mut job_areas = get_job_areas(api_problem);
+    let mut job_zones = get_job_zones(api_problem);
+    let relax_after = api_problem.plan.escalation.as_ref().and_then(|escalation| escalation.relax_after);
 
     let get_single_from_task = |task: &JobTask, activity_type: &str, is_static_demand: bool| {
         let absent = (empty(), empty());
@@ -125,13 +137,46 @@ fn read_required_jobs(
             _ => panic!("Invalid activity type."),
         };
 
+        // NOTE duration per unit is applied to the first demand dimension only as units of different
+        // dimensions, e.g. kilograms and pallets, cannot be summed up
+        let task_units = task.demand.as_ref().and_then(|demand| demand.first().cloned()).unwrap_or(0);
         let places = task
             .places
             .iter()
-            .map(|p| (Some(p.location.clone()), p.duration, parse_times(&p.times), p.tag.clone()))
+            .map(|p| {
+                // NOTE post service buffer is a part of activity duration in the model
+                let duration = p.duration
+                    + p.duration_per_unit.unwrap_or(0.) * task_units as f64
+                    + p.post_service_buffer.unwrap_or(0.);
+                (Some(p.location.clone()), duration, parse_times(&p.times), p.tag.clone())
+            })
             .collect();
 
-        get_single_with_extras(places, demand, &task.order, activity_type, has_multi_dimens, coord_index)
+        let mut single =
+            get_single_with_extras(places, demand, &task.order, activity_type, has_multi_dimens, coord_index);
+        single.places.iter_mut().zip(task.places.iter()).for_each(|(place, api_place)| {
+            place.lateness = api_place.lateness_penalty.map(LatenessPenalty::Linear);
+        });
+
+        let buffers = task
+            .places
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, place)| place.post_service_buffer.map(|buffer| (idx, buffer)))
+            .collect::<Vec<_>>();
+        single.dimens.set_place_buffers(if buffers.is_empty() { None } else { Some(buffers) });
+
+        // NOTE place specific duration per unit overrides vehicle's one which is applied on scheduling
+        let service_units = task
+            .places
+            .iter()
+            .map(|place| if place.duration_per_unit.is_some() { 0. } else { task_units as f64 })
+            .collect::<Vec<_>>();
+        if service_units.iter().any(|&units| units > 0.) {
+            single.dimens.set_service_units(service_units);
+        }
+
+        single
     };
 
     api_problem.plan.jobs.iter().for_each(|job| {
@@ -160,11 +205,95 @@ fn read_required_jobs(
 
         assert!(!singles.is_empty());
 
+        // NOTE blackouts are modeled by splitting place time windows, so vehicle waits till blackout end
+        let singles = if let Some(blackouts) = job_blackouts.get(&job.id) {
+            singles
+                .into_iter()
+                .map(|mut single| {
+                    single.places.iter_mut().for_each(|place| place.times = apply_blackouts(&place.times, blackouts));
+                    single
+                })
+                .collect()
+        } else {
+            singles
+        };
+
+        let singles = if let Some(product_class) = job.product_class.as_ref() {
+            singles
+                .into_iter()
+                .map(|mut single| {
+                    single.dimens.set_product_class(product_class.clone());
+                    single
+                })
+                .collect()
+        } else {
+            singles
+        };
+
+        // NOTE zones are set on each task, so they are available for any activity of the job
+        let singles = if let Some(zones) = job_zones.remove(&job.id) {
+            singles
+                .into_iter()
+                .map(|mut single| {
+                    single.dimens.set_job_zones(Some(zones.clone()));
+                    single
+                })
+                .collect()
+        } else {
+            singles
+        };
+
+        let singles = if job.truck_only.unwrap_or(false) {
+            singles
+                .into_iter()
+                .map(|mut single| {
+                    single.dimens.set_job_truck_only(Some(true));
+                    single
+                })
+                .collect()
+        } else {
+            singles
+        };
+
+        // NOTE placement makes sense only for items loaded at the depot
+        let singles = match job.placement.as_ref() {
+            Some(placement) if is_static_demand => singles
+                .into_iter()
+                .map(|mut single| {
+                    if single.dimens.get_job_type().is_some_and(|job_type| job_type == "delivery") {
+                        single.dimens.set_job_placement(Some(*placement as usize));
+                    }
+                    single
+                })
+                .collect(),
+            _ => singles,
+        };
+
+        // NOTE approach duration is modeled as place setup, so it is not paid again at the same location
+        let singles = if let Some(approach) = job.approach.as_ref() {
+            singles
+                .into_iter()
+                .map(|mut single| {
+                    single.places.iter_mut().for_each(|place| place.setup = approach.duration);
+                    single.dimens.set_job_approach_distance(approach.distance);
+                    single
+                })
+                .collect()
+        } else {
+            singles
+        };
+
+        // NOTE soft requirements of the job are ignored once it fails to be assigned too many times
+        let is_relaxed = relax_after.zip(job.attempts).is_some_and(|(relax_after, attempts)| attempts >= relax_after);
+        let territory = job_territories.get(&job.id).filter(|territory| !is_relaxed || territory.is_strict).cloned();
+        let skills = get_skills(&job.skills, is_relaxed);
+        let areas = job_areas.remove(&job.id);
+
         let problem_job = if singles.len() > 1 {
             let deliveries_start_index = job.pickups.as_ref().map_or(0, |p| p.len());
-            get_multi_job(job, singles, deliveries_start_index, random)
+            get_multi_job(job, singles, deliveries_start_index, territory, areas, skills, random)
         } else {
-            get_single_job(job, singles.into_iter().next().unwrap())
+            get_single_job(job, singles.into_iter().next().unwrap(), territory, areas, skills)
         };
 
         job_index.insert(job.id.clone(), problem_job.clone());
@@ -176,6 +305,7 @@ fn read_required_jobs(
 
 fn read_conditional_jobs(
     api_problem: &ApiProblem,
+    props: &ProblemProperties,
     coord_index: &CoordIndex,
     job_index: &mut JobIndex,
 ) -> (Vec<Job>, Vec<Arc<Lock>>) {
@@ -192,7 +322,31 @@ fn read_conditional_jobs(
             }
 
             if let Some(reloads) = &shift.reloads {
-                read_reloads(coord_index, job_index, &mut jobs, vehicle, shift_index, reloads);
+                read_reloads(
+                    coord_index,
+                    job_index,
+                    &mut jobs,
+                    vehicle,
+                    shift_index,
+                    reloads,
+                    props.has_multi_dimen_capacity,
+                );
+            }
+
+            if let Some(recharges) = &shift.recharges {
+                read_recharges(coord_index, job_index, &mut jobs, vehicle, shift_index, recharges);
+            }
+
+            if let Some(parkings) = &shift.trailer_parkings {
+                read_trailer_parkings(coord_index, job_index, &mut jobs, vehicle, shift_index, parkings);
+            }
+
+            if let Some(relays) = &shift.relays {
+                read_relays(coord_index, job_index, &mut jobs, vehicle, shift_index, relays);
+            }
+
+            if let (Some(unloading), Some(end)) = (&shift.unloading, &shift.end) {
+                read_unloading(coord_index, job_index, &mut jobs, vehicle, shift_index, unloading, end);
             }
         }
     });
@@ -306,6 +460,7 @@ fn read_reloads(
     vehicle: &VehicleType,
     shift_index: usize,
     reloads: &[VehicleReload],
+    has_multi_dimens: bool,
 ) {
     (1..)
         .zip(reloads.iter())
@@ -316,14 +471,149 @@ fn read_reloads(
                 .map(|vehicle_id| {
                     let job_id = format!("{}_reload_{}_{}", vehicle_id, shift_index, reload_idx);
                     let times = parse_times(&place.times);
+                    // NOTE alternative locations are modeled as job places, so the cheapest one is selected
+                    let places = std::iter::once(&place.location)
+                        .chain(place.alternatives.iter().flatten())
+                        .map(|location| (Some(location.clone()), place.duration, times.clone(), place.tag.clone()))
+                        .collect();
+
+                    let mut job =
+                        get_conditional_job(coord_index, vehicle_id.clone(), &job_id, "reload", shift_index, places);
+                    job.dimens.set_job_dock(place.dock_id.clone());
+
+                    match place.capacity.as_ref() {
+                        Some(capacity) if has_multi_dimens => {
+                            job.dimens.set_trip_capacity(MultiDimLoad::new(capacity.clone()));
+                        }
+                        Some(capacity) => {
+                            job.dimens.set_trip_capacity(SingleDimLoad::new(*capacity.first().unwrap()));
+                        }
+                        None => {}
+                    }
+
+                    (job_id, job)
+                })
+                .collect::<Vec<_>>()
+        })
+        .for_each(|(job_id, single)| add_conditional_job(job_index, jobs, job_id, single));
+}
+
+fn read_recharges(
+    coord_index: &CoordIndex,
+    job_index: &mut JobIndex,
+    jobs: &mut Vec<Job>,
+    vehicle: &VehicleType,
+    shift_index: usize,
+    recharges: &[VehicleRecharge],
+) {
+    (1..)
+        .zip(recharges.iter())
+        .flat_map(|(recharge_idx, place)| {
+            vehicle
+                .vehicle_ids
+                .iter()
+                .map(|vehicle_id| {
+                    let job_id = format!("{}_recharge_{}_{}", vehicle_id, shift_index, recharge_idx);
+                    let times = parse_times(&place.times);
+
+                    // NOTE recharge duration depends on energy level and is updated within energy constraint
+                    let mut job = get_conditional_job(
+                        coord_index,
+                        vehicle_id.clone(),
+                        &job_id,
+                        "recharge",
+                        shift_index,
+                        vec![(Some(place.location.clone()), 0., times, place.tag.clone())],
+                    );
+                    job.dimens.set_recharge_rate(place.rate);
+
+                    (job_id, job)
+                })
+                .collect::<Vec<_>>()
+        })
+        .for_each(|(job_id, single)| add_conditional_job(job_index, jobs, job_id, single));
+}
+
+fn read_trailer_parkings(
+    coord_index: &CoordIndex,
+    job_index: &mut JobIndex,
+    jobs: &mut Vec<Job>,
+    vehicle: &VehicleType,
+    shift_index: usize,
+    parkings: &[VehicleTrailerParking],
+) {
+    (1..)
+        .zip(parkings.iter())
+        .flat_map(|(parking_idx, parking)| {
+            vehicle
+                .vehicle_ids
+                .iter()
+                .map(|vehicle_id| {
+                    let job_id = format!("{}_trailer_{}_{}", vehicle_id, shift_index, parking_idx);
+                    let times = parse_times(&parking.times);
+
+                    // NOTE trailer is detached and attached back at the same parking in this order
+                    let singles = ["detach", "attach"]
+                        .iter()
+                        .map(|job_type| {
+                            Arc::new(get_conditional_job(
+                                coord_index,
+                                vehicle_id.clone(),
+                                &job_id,
+                                job_type,
+                                shift_index,
+                                vec![(
+                                    Some(parking.location.clone()),
+                                    parking.duration,
+                                    times.clone(),
+                                    parking.tag.clone(),
+                                )],
+                            ))
+                        })
+                        .collect();
+
+                    let mut dimens = Dimensions::default();
+                    dimens
+                        .set_job_id(job_id.clone())
+                        .set_job_type("trailer".to_string())
+                        .set_shift_index(shift_index)
+                        .set_vehicle_id(vehicle_id.clone());
+
+                    (job_id, Job::Multi(Multi::new_shared(singles, dimens)))
+                })
+                .collect::<Vec<_>>()
+        })
+        .for_each(|(job_id, job)| {
+            job_index.insert(job_id, job.clone());
+            jobs.push(job);
+        });
+}
+
+fn read_relays(
+    coord_index: &CoordIndex,
+    job_index: &mut JobIndex,
+    jobs: &mut Vec<Job>,
+    vehicle: &VehicleType,
+    shift_index: usize,
+    relays: &[VehicleRelay],
+) {
+    (1..)
+        .zip(relays.iter())
+        .flat_map(|(relay_idx, relay)| {
+            vehicle
+                .vehicle_ids
+                .iter()
+                .map(|vehicle_id| {
+                    let job_id = format!("{}_relay_{}_{}", vehicle_id, shift_index, relay_idx);
+                    let times = parse_times(&relay.times);
 
                     let job = get_conditional_job(
                         coord_index,
                         vehicle_id.clone(),
                         &job_id,
-                        "reload",
+                        "relay",
                         shift_index,
-                        vec![(Some(place.location.clone()), place.duration, times, place.tag.clone())],
+                        vec![(Some(relay.location.clone()), relay.duration, times, relay.tag.clone())],
                     );
 
                     (job_id, job)
@@ -333,6 +623,33 @@ fn read_reloads(
         .for_each(|(job_id, single)| add_conditional_job(job_index, jobs, job_id, single));
 }
 
+fn read_unloading(
+    coord_index: &CoordIndex,
+    job_index: &mut JobIndex,
+    jobs: &mut Vec<Job>,
+    vehicle: &VehicleType,
+    shift_index: usize,
+    unloading: &VehicleUnloading,
+    end: &ShiftEnd,
+) {
+    vehicle.vehicle_ids.iter().for_each(|vehicle_id| {
+        let job_id = format!("{}_unloading_{}", vehicle_id, shift_index);
+
+        // NOTE unloading happens at depot, so shift end location is used
+        let mut job = get_conditional_job(
+            coord_index,
+            vehicle_id.clone(),
+            &job_id,
+            "unloading",
+            shift_index,
+            vec![(Some(end.location.clone()), unloading.duration, parse_times(&None), unloading.tag.clone())],
+        );
+        job.dimens.set_job_dock(unloading.dock_id.clone());
+
+        add_conditional_job(job_index, jobs, job_id, job);
+    });
+}
+
 fn get_conditional_job(
     coord_index: &CoordIndex,
     vehicle_id: String,
@@ -372,6 +689,8 @@ fn get_single(places: Vec<PlaceData>, coord_index: &CoordIndex) -> Single {
             location: location.as_ref().and_then(|l| coord_index.get_by_loc(l)),
             duration,
             times,
+            setup: 0.,
+            lateness: None,
         })
         .collect();
 
@@ -406,15 +725,36 @@ fn get_single_with_extras(
     single
 }
 
-fn get_single_job(job: &ApiJob, single: Single) -> Job {
+fn get_single_job(
+    job: &ApiJob,
+    single: Single,
+    territory: Option<Arc<JobTerritory>>,
+    areas: Option<HashSet<String>>,
+    skills: Option<ConstraintJobSkills>,
+) -> Job {
     let mut single = single;
     single
         .dimens
         .set_job_id(job.id.clone())
         .set_job_value(job.value)
         .set_job_group(job.group.clone())
+        .set_job_bundle(job.bundle.clone())
         .set_job_compatibility(job.compatibility.clone())
-        .set_job_skills(get_skills(&job.skills));
+        .set_job_reservation(job.reservation)
+        .set_job_attempts(job.attempts)
+        .set_job_sync(get_sync(&job.sync))
+        .set_job_item(get_item(&job.item))
+        .set_job_territory(territory)
+        .set_job_areas(areas)
+        .set_job_skills(skills);
+
+    if let Some(partition) = &job.partition {
+        single.dimens.set_partition(partition.clone());
+    }
+
+    if let Some(penalty) = job.unassigned_penalty {
+        single.dimens.set_unassigned_penalty(penalty);
+    }
 
     Job::Single(Arc::new(single))
 }
@@ -423,6 +763,9 @@ fn get_multi_job(
     job: &ApiJob,
     singles: Vec<Single>,
     deliveries_start_index: usize,
+    territory: Option<Arc<JobTerritory>>,
+    areas: Option<HashSet<String>>,
+    skills: Option<ConstraintJobSkills>,
     random: &Arc<dyn Random + Send + Sync>,
 ) -> Job {
     let mut dimens: Dimensions = Default::default();
@@ -430,12 +773,29 @@ fn get_multi_job(
         .set_job_id(job.id.clone())
         .set_job_value(job.value)
         .set_job_group(job.group.clone())
+        .set_job_bundle(job.bundle.clone())
         .set_job_compatibility(job.compatibility.clone())
-        .set_job_skills(get_skills(&job.skills));
+        .set_job_reservation(job.reservation)
+        .set_job_attempts(job.attempts)
+        .set_job_visit_gap(job.visit_gap.as_ref().map(|gap| (gap.min, gap.max)))
+        .set_job_max_ride_duration(job.max_ride_duration)
+        .set_job_item(get_item(&job.item))
+        .set_job_territory(territory)
+        .set_job_areas(areas)
+        .set_job_skills(skills);
+
+    if let Some(partition) = &job.partition {
+        dimens.set_partition(partition.clone());
+    }
+
+    if let Some(penalty) = job.unassigned_penalty {
+        dimens.set_unassigned_penalty(penalty);
+    }
 
     let singles = singles.into_iter().map(Arc::new).collect::<Vec<_>>();
 
-    let multi = if singles.len() == 2 && deliveries_start_index == 1 {
+    // NOTE tasks of the job with visit gap are visited in the order they are defined
+    let multi = if (singles.len() == 2 && deliveries_start_index == 1) || job.visit_gap.is_some() {
         Multi::new_shared(singles, dimens)
     } else {
         let jobs_len = singles.len();
@@ -461,18 +821,170 @@ fn create_condition(vehicle_id: String, shift_index: usize) -> Arc<dyn Fn(&Actor
     })
 }
 
-fn get_skills(skills: &Option<FormatJobSkills>) -> Option<ConstraintJobSkills> {
+fn get_skills(skills: &Option<FormatJobSkills>, is_relaxed: bool) -> Option<ConstraintJobSkills> {
     skills.as_ref().map(|skills| ConstraintJobSkills {
         all_of: skills.all_of.as_ref().map(|all_of| all_of.iter().cloned().collect()),
         one_of: skills.one_of.as_ref().map(|any_of| any_of.iter().cloned().collect()),
         none_of: skills.none_of.as_ref().map(|none_of| none_of.iter().cloned().collect()),
+        preferred: skills
+            .preferred
+            .as_ref()
+            .filter(|_| !is_relaxed)
+            .map(|preferred| preferred.iter().map(|skill| (skill.skill.clone(), skill.penalty)).collect()),
+        counts: skills
+            .counts
+            .as_ref()
+            .map(|counts| counts.iter().map(|skill| (skill.skill.clone(), skill.count)).collect()),
     })
 }
 
+fn get_sync(sync: &Option<FormatJobSync>) -> Option<ConstraintJobSync> {
+    sync.as_ref().map(|sync| ConstraintJobSync { key: sync.key.clone(), offset: sync.offset.unwrap_or(0.) })
+}
+
+fn get_item(item: &Option<JobItem>) -> Option<ItemAttributes> {
+    item.as_ref().map(|item| ItemAttributes { weight: item.weight, length: item.length, height: item.height })
+}
+
 fn empty() -> MultiDimLoad {
     MultiDimLoad::default()
 }
 
+/// Returns service blackouts for jobs affected by at least one blackout.
+fn get_job_blackouts(api_problem: &ApiProblem) -> HashMap<String, Vec<TimeWindow>> {
+    let blackouts = api_problem.plan.blackouts.iter().flatten().collect::<Vec<_>>();
+    if blackouts.is_empty() {
+        return HashMap::default();
+    }
+
+    let area_jobs = api_problem
+        .plan
+        .areas
+        .iter()
+        .flatten()
+        .map(|area| (&area.id, area.jobs.iter().collect::<HashSet<_>>()))
+        .collect::<HashMap<_, _>>();
+
+    api_problem
+        .plan
+        .jobs
+        .iter()
+        .filter_map(|job| {
+            let job_blackouts = blackouts
+                .iter()
+                .filter(|blackout| match blackout.areas.as_ref() {
+                    Some(area_ids) => {
+                        area_ids.iter().any(|area_id| area_jobs.get(area_id).is_some_and(|jobs| jobs.contains(&job.id)))
+                    }
+                    None => true,
+                })
+                .map(|blackout| parse_time_window(&blackout.time))
+                .collect::<Vec<_>>();
+
+            if job_blackouts.is_empty() {
+                None
+            } else {
+                Some((job.id.clone(), job_blackouts))
+            }
+        })
+        .collect()
+}
+
+/// Returns territories of jobs which belong to at least one territory.
+fn get_job_territories(api_problem: &ApiProblem) -> HashMap<String, Arc<JobTerritory>> {
+    let area_jobs =
+        api_problem.plan.areas.iter().flatten().map(|area| (&area.id, &area.jobs)).collect::<HashMap<_, _>>();
+
+    api_problem
+        .plan
+        .territories
+        .iter()
+        .flatten()
+        .flat_map(|territory| {
+            let job_territory = Arc::new(JobTerritory {
+                id: territory.id.clone(),
+                vehicles: territory.vehicles.iter().cloned().collect(),
+                is_strict: territory.is_strict.unwrap_or(false),
+                cross_zone_penalty: territory.cross_zone_penalty,
+            });
+
+            territory
+                .areas
+                .iter()
+                .filter_map(|area_id| area_jobs.get(area_id))
+                .flat_map(|jobs| jobs.iter())
+                .map(move |job_id| (job_id.clone(), job_territory.clone()))
+        })
+        .collect()
+}
+
+/// Returns ids of plan areas for jobs which belong to at least one area. Areas are needed only
+/// when some vehicle has allowed areas.
+fn get_job_areas(api_problem: &ApiProblem) -> HashMap<String, HashSet<String>> {
+    let has_allowed_areas = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .any(|vehicle| vehicle.limits.as_ref().is_some_and(|limits| limits.allowed_areas.is_some()));
+
+    if !has_allowed_areas {
+        return HashMap::default();
+    }
+
+    api_problem.plan.areas.iter().flatten().fold(HashMap::default(), |mut acc, area| {
+        area.jobs.iter().for_each(|job_id| {
+            acc.entry(job_id.clone()).or_insert_with(HashSet::default).insert(area.id.clone());
+        });
+        acc
+    })
+}
+
+/// Returns zones of jobs which belong to at least one zone sequence: pairs of sequence index and
+/// position of the job's zone within the sequence. If the job belongs to several zones of the same
+/// sequence, the first one is used.
+fn get_job_zones(api_problem: &ApiProblem) -> HashMap<String, Vec<(usize, usize)>> {
+    let area_jobs =
+        api_problem.plan.areas.iter().flatten().map(|area| (&area.id, &area.jobs)).collect::<HashMap<_, _>>();
+
+    api_problem.plan.zone_sequences.iter().flatten().enumerate().fold(
+        HashMap::default(),
+        |mut acc, (sequence_idx, sequence)| {
+            sequence
+                .areas
+                .iter()
+                .enumerate()
+                .filter_map(|(position, area_id)| area_jobs.get(area_id).map(|jobs| (position, jobs)))
+                .for_each(|(position, jobs)| {
+                    jobs.iter().for_each(|job_id| {
+                        let zones = acc.entry(job_id.clone()).or_insert_with(Vec::default);
+                        if zones.iter().all(|(idx, _)| *idx != sequence_idx) {
+                            zones.push((sequence_idx, position));
+                        }
+                    });
+                });
+            acc
+        },
+    )
+}
+
+/// Removes blackout intervals from time windows, so service cannot be started strictly inside of them.
+fn apply_blackouts(times: &[TimeSpan], blackouts: &[TimeWindow]) -> Vec<TimeSpan> {
+    blackouts.iter().fold(times.to_vec(), |times, blackout| {
+        times
+            .into_iter()
+            .flat_map(|time| match time {
+                TimeSpan::Window(tw) if tw.start < blackout.end && blackout.start < tw.end => {
+                    let before = Some(TimeWindow::new(tw.start, blackout.start)).filter(|_| tw.start <= blackout.start);
+                    let after = Some(TimeWindow::new(blackout.end, tw.end)).filter(|_| blackout.end <= tw.end);
+
+                    before.into_iter().chain(after).map(TimeSpan::Window).collect::<Vec<_>>()
+                }
+                time => vec![time],
+            })
+            .collect()
+    })
+}
+
 fn parse_times(times: &Option<Vec<Vec<String>>>) -> Vec<TimeSpan> {
     times.as_ref().map_or(vec![TimeSpan::Window(TimeWindow::max())], |tws| {
         tws.iter().map(|tw| TimeSpan::Window(parse_time_window(tw))).collect()
@@ -0,0 +1,163 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/matrix_parser_test.rs"]
+mod matrix_parser_test;
+
+use super::Matrix;
+
+/// Parses routing matrix in json format directly from bytes. Routing matrix size grows quadratically
+/// with amount of locations, so its numeric arrays dominate input size: they are scanned in one pass
+/// without intermediate representation.
+///
+/// Returns `None` when input uses json features which are not supported here (e.g. escaped strings,
+/// unknown properties or non integer values), so the caller can fall back to generic json parser
+/// which also reports a proper error.
+pub(crate) fn parse_matrix(bytes: &[u8]) -> Option<Matrix> {
+    let mut parser = MatrixParser { bytes, position: 0 };
+
+    let mut matrix =
+        Matrix { profile: None, timestamp: None, travel_times: vec![], distances: vec![], error_codes: None };
+    let mut keys = Vec::with_capacity(5);
+
+    parser.expect(b'{')?;
+    if !parser.try_consume(b'}') {
+        loop {
+            let key = match parser.parse_str()? {
+                "durations" => "travelTimes",
+                key => key,
+            };
+            parser.expect(b':')?;
+
+            // NOTE duplicated keys are rejected by generic parser, so they are not accepted here too
+            if keys.contains(&key) {
+                return None;
+            }
+            keys.push(key);
+
+            match key {
+                "profile" => matrix.profile = parser.parse_optional(|parser| parser.parse_str().map(str::to_string))?,
+                "timestamp" => {
+                    matrix.timestamp = parser.parse_optional(|parser| parser.parse_str().map(str::to_string))?
+                }
+                "travelTimes" => matrix.travel_times = parser.parse_integers()?,
+                "distances" => matrix.distances = parser.parse_integers()?,
+                "errorCodes" => matrix.error_codes = parser.parse_optional(|parser| parser.parse_integers())?,
+                _ => return None,
+            }
+
+            if !parser.try_consume(b',') {
+                parser.expect(b'}')?;
+                break;
+            }
+        }
+    }
+
+    parser.skip_whitespace();
+
+    if keys.contains(&"travelTimes") && keys.contains(&"distances") && parser.position == bytes.len() {
+        Some(matrix)
+    } else {
+        None
+    }
+}
+
+struct MatrixParser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> MatrixParser<'a> {
+    fn skip_whitespace(&mut self) {
+        while matches!(self.bytes.get(self.position), Some(b' ' | b'\n' | b'\r' | b'\t')) {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.bytes.get(self.position).cloned()
+    }
+
+    fn try_consume(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        if self.try_consume(byte) {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn parse_optional<T>(&mut self, parse_fn: impl FnOnce(&mut Self) -> Option<T>) -> Option<Option<T>> {
+        if self.peek() == Some(b'n') {
+            return if self.bytes[self.position..].starts_with(b"null") {
+                self.position += 4;
+                Some(None)
+            } else {
+                None
+            };
+        }
+
+        parse_fn(self).map(Some)
+    }
+
+    /// Parses string without escape sequences borrowing it from the input.
+    fn parse_str(&mut self) -> Option<&'a str> {
+        self.expect(b'"')?;
+
+        let start = self.position;
+        let length = self.bytes[start..].iter().position(|&byte| byte == b'"' || byte == b'\\' || byte < 0x20)?;
+        self.position = start + length;
+
+        self.expect(b'"')?;
+
+        std::str::from_utf8(&self.bytes[start..start + length]).ok()
+    }
+
+    fn parse_integers(&mut self) -> Option<Vec<i64>> {
+        self.expect(b'[')?;
+
+        let mut values = vec![];
+        if self.try_consume(b']') {
+            return Some(values);
+        }
+
+        loop {
+            values.push(self.parse_integer()?);
+
+            if !self.try_consume(b',') {
+                self.expect(b']')?;
+                return Some(values);
+            }
+        }
+    }
+
+    fn parse_integer(&mut self) -> Option<i64> {
+        let is_negative = self.try_consume(b'-');
+
+        let start = self.position;
+        let mut value: i64 = 0;
+        while let Some(digit) = self.bytes.get(self.position).filter(|byte| byte.is_ascii_digit()) {
+            let digit = (digit - b'0') as i64;
+            value = value.checked_mul(10)?;
+            value = if is_negative { value.checked_sub(digit)? } else { value.checked_add(digit)? };
+            self.position += 1;
+        }
+
+        let length = self.position - start;
+        let has_leading_zero = length > 1 && self.bytes[start] == b'0';
+        let is_fraction = matches!(self.bytes.get(self.position), Some(b'.' | b'e' | b'E'));
+
+        if length == 0 || has_leading_zero || is_fraction {
+            None
+        } else {
+            Some(value)
+        }
+    }
+}
@@ -4,10 +4,21 @@
 mod model;
 pub use self::model::*;
 
+mod matrix_parser;
+
 mod reader;
 pub use self::reader::create_approx_matrices;
+pub use self::reader::create_overlaid_problem;
 pub use self::reader::PragmaticProblem;
 
+pub(crate) use self::reader::{
+    apply_metric_policies, create_order_fn, get_energy_demand_fn, get_non_reloadable_dimensions,
+    has_ambiguous_epoch_offset, infer_matrices, is_time_of_day_without_offset, is_valid_recurring_time_window,
+    normalize_areas, normalize_dimensions, normalize_times,
+};
+
+pub use crate::constraints::{RouteCheck, RouteCheckFn};
+
 pub(crate) fn get_job_tasks(job: &Job) -> impl Iterator<Item = &JobTask> {
     job.pickups.iter().chain(job.deliveries.iter()).chain(job.services.iter()).chain(job.replacements.iter()).flatten()
 }
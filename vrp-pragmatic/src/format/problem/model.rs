@@ -44,7 +44,54 @@ pub struct Area {
     /// An unique id of the area.
     pub id: String,
     /// List of job ids.
+    #[serde(default)]
     pub jobs: Vec<String>,
+    /// A polygon specified by its vertices as geo coordinates. Jobs with all their places inside of
+    /// the polygon belong to the area in addition to the jobs listed explicitly.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub polygon: Option<Vec<Location>>,
+}
+
+/// Specifies a service blackout: a time interval when service of jobs cannot be started, e.g. site
+/// lunch closure. Vehicles are still allowed to travel or wait during the blackout.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct ServiceBlackout {
+    /// A blackout time window with time specified in RFC3339 format or as time of day.
+    pub time: Vec<String>,
+    /// Ids of plan areas which jobs are affected by the blackout. If not specified, all jobs are affected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub areas: Option<Vec<String>>,
+}
+
+/// Specifies a service territory: a set of areas which jobs are preferred to be served by specific vehicles.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Territory {
+    /// An unique id of the territory.
+    pub id: String,
+    /// Ids of plan areas which jobs belong to the territory.
+    pub areas: Vec<String>,
+    /// Ids of vehicles preferred to serve territory jobs.
+    pub vehicles: Vec<String>,
+    /// If set to true, territory jobs cannot be served by other vehicles. Otherwise, serving them
+    /// by other vehicles is considered as a violation which is minimized by the objective.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_strict: Option<bool>,
+    /// If set, territory jobs are pinned to territory vehicles: they can be served by other vehicles
+    /// only when no feasible assignment exists, and such assignment is penalized by the given value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cross_zone_penalty: Option<f64>,
+}
+
+/// Specifies a preferred order of serving zones within a tour, e.g. chilled goods after ambient ones
+/// and frozen goods last. Zones are plan areas listed in preferred order of visits. Serving a job
+/// of a zone before a job of a preceding zone is a violation which is penalized.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct ZoneSequence {
+    /// Ids of plan areas in preferred order of visits.
+    pub areas: Vec<String>,
+    /// A penalty applied per each pair of jobs served in the wrong order.
+    pub penalty: f64,
 }
 
 /// A job skills limitation for a vehicle.
@@ -60,22 +107,77 @@ pub struct JobSkills {
     /// Vehicle should have none of these skills defined.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub none_of: Option<Vec<String>>,
+    /// Vehicle is preferred to have these skills, otherwise a penalty is applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred: Option<Vec<PreferredSkill>>,
+    /// Vehicle should provide at least given amount of units of these skills.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub counts: Option<Vec<SkillCount>>,
+}
+
+/// Specifies an amount of units of the skill, e.g. amount of certified installers.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct SkillCount {
+    /// A skill name.
+    pub skill: String,
+    /// An amount of skill units.
+    pub count: usize,
+}
+
+/// A soft skill requirement which is not enforced, but penalized when vehicle lacks it.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct PreferredSkill {
+    /// A skill name.
+    pub skill: String,
+    /// A penalty applied when vehicle does not have the skill.
+    pub penalty: f64,
 }
 
 /// Specifies a place for sub job.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct JobPlace {
     /// A job place location.
     pub location: Location,
     /// A job place duration (service time).
     pub duration: f64,
-    /// A list of job place time windows with time specified in RFC3339 format.
+    /// A list of job place time windows with time specified in RFC3339 format or as time of day
+    /// with optional utc offset, e.g. `22:00` or `22:00:00+02:00`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub times: Option<Vec<Vec<String>>>,
     /// A tag which will be propagated back within corresponding activity in solution.
     /// You can use it to identify used place in solution.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag: Option<String>,
+    /// An extra duration per unit of task demand, e.g. unload time per pallet. It is added to the
+    /// place duration multiplied by the demand of the first dimension. If set, it overrides
+    /// vehicle's `durationPerUnit` for this place.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_per_unit: Option<f64>,
+    /// A cost per time unit of late arrival. When specified, time windows of the place are soft:
+    /// arrival after time window end is allowed, but penalized.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lateness_penalty: Option<f64>,
+    /// A mandatory idle time after service completion, e.g. settling time or paperwork, during which
+    /// vehicle cannot depart. Unlike duration, it is reported as waiting time in the solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_service_buffer: Option<f64>,
+    /// A list of weekly recurring time windows. They are expanded over planning horizon and combined
+    /// with time windows specified by `times`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring_times: Option<Vec<RecurringTimeWindow>>,
+}
+
+/// Specifies a time window which recurs weekly on given days, e.g. every monday and thursday from
+/// 08:00 till 12:00.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct RecurringTimeWindow {
+    /// Days of week: `mon`, `tue`, `wed`, `thu`, `fri`, `sat` or `sun`.
+    pub days: Vec<String>,
+    /// Start time of day with optional utc offset, e.g. `08:00` or `08:00:00+02:00`.
+    pub start: String,
+    /// End time of day with optional utc offset. When it is not after start, time window crosses midnight.
+    pub end: String,
 }
 
 /// Specifies a job task.
@@ -89,6 +191,18 @@ pub struct JobTask {
     /// An order, bigger value - later assignment in the route.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order: Option<i32>,
+    /// Job place demand specified by named load dimensions declared on fleet.
+    #[serde(rename = "namedDemand", skip_serializing_if = "Option::is_none")]
+    pub named_demand: Option<Vec<NamedLoad>>,
+}
+
+/// Specifies an amount of load in named dimension.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct NamedLoad {
+    /// A load dimension name.
+    pub dimension: String,
+    /// An amount of load.
+    pub value: i32,
 }
 
 /// A customer job model. Actual tasks of the job specified by list of pickups and deliveries
@@ -96,6 +210,7 @@ pub struct JobTask {
 /// * all of them should be completed or none of them.
 /// * all pickups must be completed before any of deliveries.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Job {
     /// A job id.
     pub id: String,
@@ -128,9 +243,143 @@ pub struct Job {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub group: Option<String>,
 
+    /// Job bundle: jobs of the same bundle are all assigned, possibly to different tours, or all
+    /// left unassigned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bundle: Option<String>,
+
     /// A compatibility group: jobs with different compatibility cannot be assigned to the same tour.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compatibility: Option<String>,
+
+    /// Marks job as a reservation for forecast demand: it occupies vehicle capacity and time, but
+    /// it is displaced by real jobs as its unassignment costs less than unassignment of any real job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reservation: Option<bool>,
+
+    /// A time gap between consecutive visits of the job tasks which are served by the same vehicle.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visit_gap: Option<JobVisitGap>,
+
+    /// A product class which defines compartment requirement: products of different classes cannot
+    /// share the same vehicle compartment within one trip.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_class: Option<String>,
+
+    /// An item placement inside vehicle which restricts delivery order within a trip. It is
+    /// considered only for delivery tasks of jobs without pickups.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placement: Option<JobPlacement>,
+
+    /// Specifies that job location is not accessible by vehicle with trailer: such job can be
+    /// served only when vehicle has detached its trailer at one of trailer parkings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub truck_only: Option<bool>,
+
+    /// A maximum ride duration: time between completion of the first served pickup and start of
+    /// each delivery of the job, e.g. for perishable goods or passengers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_ride_duration: Option<f64>,
+
+    /// A synchronization requirement: jobs with the same sync key have to be served by different
+    /// vehicles at the same time or within given time offset, e.g. a two-technician installation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync: Option<JobSync>,
+
+    /// Ids of jobs which have to be completed before this job starts, even when they are served
+    /// by other vehicles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub predecessors: Option<Vec<String>>,
+
+    /// A final approach to the job location which is done differently from driving, e.g. on foot
+    /// after parking the vehicle (park-and-walk).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approach: Option<JobApproach>,
+
+    /// An amount of previous planning runs in which the job was left unassigned. It is supplied
+    /// by the caller on re-planning and used by `plan.escalation` to escalate the job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attempts: Option<usize>,
+
+    /// Physical attributes of job's items: they are matched against vehicle's physical limits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item: Option<JobItem>,
+
+    /// A partition hint: a user defined id of the problem part, e.g. a region. Jobs from different
+    /// partitions are not mixed when the solver decomposes the problem into smaller ones.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition: Option<String>,
+
+    /// A cost of leaving the job unassigned used by `minimize-unassigned` objective: jobs with higher
+    /// penalty are dropped last when not all jobs can be served. Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unassigned_penalty: Option<f64>,
+}
+
+/// Specifies physical attributes of job's items, e.g. of building materials.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobItem {
+    /// A weight of the heaviest item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+
+    /// A length of the longest item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<f64>,
+
+    /// A height of the highest item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<f64>,
+}
+
+/// Specifies synchronization of job with other jobs.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobSync {
+    /// A sync key: all jobs with the same key are served simultaneously by different vehicles.
+    pub key: String,
+
+    /// A maximum difference between service start times of synchronized jobs. Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<f64>,
+}
+
+/// Specifies final approach to the job location, e.g. walking segment of a courier.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobApproach {
+    /// An approach duration which is added to the travel time. It is shared by consecutive
+    /// activities at the same location.
+    pub duration: f64,
+
+    /// An approach distance which is charged by vehicle's distance cost on every job activity, but
+    /// not reported as a part of driven distance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<f64>,
+}
+
+/// Specifies item placement inside vehicle: items placed closer to the rear door are delivered first
+/// within a trip, items placed at the front of the truck are delivered last.
+#[derive(Clone, Copy, Deserialize, Debug, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum JobPlacement {
+    /// An item is placed near the rear door.
+    Rear,
+    /// An item is placed in the middle.
+    Middle,
+    /// An item is placed at the front of the truck.
+    Front,
+}
+
+/// Specifies time gap between consecutive visits of the job: it is measured from departure of
+/// the previous visit to arrival to the next one.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobVisitGap {
+    /// A minimum gap.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+
+    /// A maximum gap.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
 }
 
 // region Clustering
@@ -154,6 +403,19 @@ pub enum Clustering {
         /// Specifies filtering policy.
         filtering: Option<VicinityFilteringPolicy>,
     },
+    /// Time window clustering: groups close jobs with compatible time windows into serving sequences.
+    #[serde(rename(deserialize = "timeWindow", serialize = "timeWindow"))]
+    TimeWindow {
+        /// Specifies a vehicle profile used to calculate commute duration and distance between
+        /// activities in the serving sequence.
+        profile: VehicleProfile,
+        /// Specifies threshold information.
+        threshold: TimeWindowThresholdPolicy,
+        /// Specifies service time policy.
+        serving: VicinityServingPolicy,
+        /// Specifies filtering policy.
+        filtering: Option<VicinityFilteringPolicy>,
+    },
 }
 
 /// Defines a various thresholds to control cluster size.
@@ -172,6 +434,20 @@ pub struct VicinityThresholdPolicy {
     pub max_jobs_per_cluster: Option<usize>,
 }
 
+/// Defines thresholds for time window clustering.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeWindowThresholdPolicy {
+    /// Moving duration limit.
+    pub duration: f64,
+    /// Moving distance limit.
+    pub distance: f64,
+    /// Minimum ratio of shared time to the widest time window of two jobs. Default is 0.5.
+    pub min_overlap: Option<f64>,
+    /// The maximum amount of jobs per cluster.
+    pub max_jobs_per_cluster: Option<usize>,
+}
+
 /// Specifies cluster visiting policy.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -238,6 +514,80 @@ pub struct Plan {
     /// Specifies clustering parameters.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub clustering: Option<Clustering>,
+
+    /// List of service blackouts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blackouts: Option<Vec<ServiceBlackout>>,
+
+    /// List of service territories.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub territories: Option<Vec<Territory>>,
+
+    /// Specifies escalation of jobs which failed to be assigned in previous planning runs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub escalation: Option<JobEscalation>,
+
+    /// Specifies estimation of marginal costs of assigned jobs reported in solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marginal_costs: Option<MarginalCosts>,
+
+    /// List of preferred zone visit orders within a tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zone_sequences: Option<Vec<ZoneSequence>>,
+
+    /// Specifies classification of job activity ETAs reported in solution by their stability.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eta_confidence: Option<EtaConfidence>,
+
+    /// Requests feasibility certificates of routes: state transitions with times and loads which
+    /// can be checked by an independent verifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificates: Option<bool>,
+}
+
+/// Specifies how jobs with failed planning attempts are escalated on re-planning.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobEscalation {
+    /// An extra weight of the job in `minimize-unassigned` objective per each failed attempt:
+    /// unassigned job weighs `1 + weight * attempts`. Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+
+    /// An amount of failed attempts after which soft requirements of the job, such as preferred
+    /// skills and non-strict territory, are ignored. Default is no relaxation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relax_after: Option<usize>,
+}
+
+/// Specifies estimation of job marginal costs: a cost delta if the job were removed from its tour
+/// and the tour were repaired.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginalCosts {
+    /// Specifies accuracy of estimation. Default is `fast`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accuracy: Option<MarginalCostAccuracy>,
+}
+
+/// Specifies classification of job activity ETAs: an ETA is locked when it cannot be delayed without
+/// violating time windows of the rest of the tour or when the job is fixed by a strict or sequence relation.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EtaConfidence {
+    /// A max slack (in seconds) of an ETA which is still considered as locked.
+    pub max_slack: f64,
+}
+
+/// Specifies accuracy of marginal cost estimation.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub enum MarginalCostAccuracy {
+    /// The job is removed from its tour and the schedule is recalculated.
+    #[serde(rename = "fast")]
+    Fast,
+    /// Additionally to removal, the rest of tour jobs are reinserted at their best positions.
+    #[serde(rename = "precise")]
+    Precise,
 }
 
 // endregion
@@ -256,6 +606,11 @@ pub struct VehicleCosts {
 
     /// Cost per time unit.
     pub time: f64,
+
+    /// Cost per waiting time unit, e.g. when vehicle arrives before job's time window start.
+    /// If omitted, `time` cost is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waiting: Option<f64>,
 }
 
 /// Specifies vehicle shift start.
@@ -264,7 +619,7 @@ pub struct ShiftStart {
     /// Earliest possible departure date time in RFC3339 format.
     pub earliest: String,
 
-    /// Latest possible departure date time in RFC3339 format. If omitted, departure time
+    /// Latest possible departure date time in RFC3339 format or as time of day. If omitted, departure time
     /// theoretically can be shifted till arrival. Set this value, if you want to limit
     /// departure time optimization.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -276,17 +631,34 @@ pub struct ShiftStart {
 
 /// Specifies vehicle shift end.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ShiftEnd {
-    /// Earliest possible arrival date time in RFC3339 format.
+    /// Earliest possible arrival date time in RFC3339 format or as time of day.
     /// At the moment, not supported, reserved for future.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub earliest: Option<String>,
 
-    /// Latest possible arrival date time in RFC3339 format.
+    /// Latest possible arrival date time in RFC3339 format or as time of day. Time of day earlier
+    /// than shift start is considered as the next day, so the shift can cross midnight.
     pub latest: String,
 
     /// Shift end location.
     pub location: Location,
+
+    /// If set to true, vehicle doesn't return to the end location: the tour ends at the last served
+    /// job (open route), while latest time still limits arrival at it. Default is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_open: Option<bool>,
+}
+
+/// Specifies vehicle shift overtime: vehicle is allowed to arrive at shift end after its latest time.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleOvertime {
+    /// Max overtime duration after shift end latest time.
+    pub max_duration: f64,
+    /// A cost per hour of overtime.
+    pub cost_per_hour: f64,
 }
 
 /// Specifies vehicle shift.
@@ -313,6 +685,42 @@ pub struct VehicleShift {
     /// unloaded during single tour.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reloads: Option<Vec<VehicleReload>>,
+
+    /// Vehicle recharges which allows electric vehicle to restore its energy at charging station
+    /// during single tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recharges: Option<Vec<VehicleRecharge>>,
+
+    /// Trailer parkings where vehicle can detach its trailer to serve truck only jobs and attach
+    /// it back later.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailer_parkings: Option<Vec<VehicleTrailerParking>>,
+
+    /// Vehicle maintenance windows: time intervals inside the shift when vehicle is not available,
+    /// so neither travel nor service can happen during them. Time is specified in RFC3339 format
+    /// or as time of day.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maintenance: Option<Vec<Vec<String>>>,
+
+    /// Alternative depot locations. Vehicle starts and, if shift end is specified, ends at either shift
+    /// start location or one of these depots, the solver chooses the best one for the vehicle's tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depots: Option<Vec<Location>>,
+
+    /// Vehicle shift overtime: allows the tour to end after shift end latest time at extra cost.
+    /// Requires shift end to be specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overtime: Option<VehicleOvertime>,
+
+    /// Vehicle unloading at shift end: goods picked up at customers and brought back to the depot
+    /// (returns) are unloaded there before the tour ends. Requires closed shift end.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unloading: Option<VehicleUnloading>,
+
+    /// Relay places where driver can be changed while vehicle continues its tour. Requires
+    /// `driverShiftTime` limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relays: Option<Vec<VehicleRelay>>,
 }
 
 /// Specifies a dispatch place where vehicle can load cargo and start the tour.
@@ -334,9 +742,9 @@ pub struct VehicleDispatch {
 pub struct VehicleDispatchLimit {
     /// Max amount of vehicles which can be dispatched during given period.
     pub max: usize,
-    /// A dispatch start time in RFC3339 time format.
+    /// A dispatch start time in RFC3339 time format or as time of day.
     pub start: String,
-    /// A dispatch end time in RFC3339 time format.
+    /// A dispatch end time in RFC3339 time format or as time of day.
     pub end: String,
 }
 
@@ -350,7 +758,7 @@ pub struct VehicleReload {
     /// A total loading/reloading duration (service time).
     pub duration: f64,
 
-    /// A list of time windows with time specified in RFC3339 format.
+    /// A list of time windows with time specified in RFC3339 format or as time of day.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub times: Option<Vec<Vec<String>>>,
 
@@ -361,6 +769,92 @@ pub struct VehicleReload {
     /// A shared reload resource id.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource_id: Option<String>,
+
+    /// Alternative locations where the same reload can happen: the cheapest one is used for each trip.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alternatives: Option<Vec<Location>>,
+
+    /// A shared dock resource id: limits how many vehicles can reload simultaneously.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dock_id: Option<String>,
+
+    /// A max load of the trip which starts at this reload. Vehicle capacity is used when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<Vec<i32>>,
+}
+
+/// Specifies unloading of returns at shift end location.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleUnloading {
+    /// An unloading duration (service time).
+    pub duration: f64,
+
+    /// A tag which will be propagated back within corresponding activity in solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+
+    /// A shared dock resource id: limits how many vehicles can unload or reload simultaneously.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dock_id: Option<String>,
+}
+
+/// Specifies a charging station where electric vehicle can restore its energy.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleRecharge {
+    /// A place location.
+    pub location: Location,
+
+    /// A charging rate: amount of energy restored per second. Total recharge duration depends
+    /// on vehicle's energy level at arrival.
+    pub rate: f64,
+
+    /// A list of time windows with time specified in RFC3339 format or as time of day.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub times: Option<Vec<Vec<String>>>,
+
+    /// A tag which will be propagated back within corresponding activity in solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
+
+/// Specifies a trailer parking where vehicle can leave and later pick up its trailer.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleTrailerParking {
+    /// A place location.
+    pub location: Location,
+
+    /// A duration of trailer detaching or attaching.
+    pub duration: f64,
+
+    /// A list of time windows with time specified in RFC3339 format or as time of day.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub times: Option<Vec<Vec<String>>>,
+
+    /// A tag which will be propagated back within corresponding activities in solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
+
+/// Specifies a relay place where driver can be changed.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleRelay {
+    /// A place location.
+    pub location: Location,
+
+    /// A duration of driver change.
+    pub duration: f64,
+
+    /// A list of time windows with time specified in RFC3339 format or as time of day.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub times: Option<Vec<Vec<String>>>,
+
+    /// A tag which will be propagated back within corresponding activity in solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
 }
 
 /// Vehicle limits.
@@ -377,15 +871,79 @@ pub struct VehicleLimits {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shift_time: Option<f64>,
 
+    /// Max working time of a single driver. Driver can be changed at shift relay places, so
+    /// vehicle tour can be longer than this limit.
+    /// No driver time restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver_shift_time: Option<f64>,
+
     /// Max amount job activities.
     /// No job activities restrictions when omitted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tour_size: Option<usize>,
 
+    /// Min amount job activities: a tour with less job activities is not used and its jobs are
+    /// unassigned. No job activities restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_tour_size: Option<usize>,
+
+    /// Max consecutive driving between two stops of any kind.
+    /// No consecutive driving restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consecutive_driving: Option<ConsecutiveDrivingLimit>,
+
+    /// Driving time rules which require breaks and rests, e.g. hours of service regulations.
+    /// No driving time rules when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driving_time: Option<DrivingTimeLimit>,
+
     /// Specifies a list of area ids where vehicle can serve jobs.
     /// No area restrictions when omitted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub areas: Option<Vec<Vec<AreaLimit>>>,
+
+    /// Specifies plan areas where vehicle is allowed to serve jobs.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_areas: Option<AllowedAreas>,
+
+    /// Max total cost of the tour: fixed, distance and time costs.
+    /// No cost restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_cost: Option<f64>,
+}
+
+/// A consecutive driving limit. Any stop (job service, break, reload) resets driving counter.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsecutiveDrivingLimit {
+    /// Max distance driven without a stop.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_distance: Option<f64>,
+
+    /// Max duration of driving without a stop.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_duration: Option<f64>,
+}
+
+/// Driving time rules. Breaks and rests are taken automatically during travel once limits are reached.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DrivingTimeLimit {
+    /// Max cumulative driving time after which a break is required.
+    pub max_driving_time: f64,
+
+    /// Break duration. Any stop which lasts at least this duration also counts as a break.
+    pub break_duration: f64,
+
+    /// Max working time after which a rest is required. Working time includes driving, serving,
+    /// waiting and breaks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_working_time: Option<f64>,
+
+    /// Rest duration, it has to be specified together with max working time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rest_duration: Option<f64>,
 }
 
 /// An area limit.
@@ -398,11 +956,23 @@ pub struct AreaLimit {
     pub job_value: f64,
 }
 
+/// Plan areas where vehicle is allowed to serve jobs.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AllowedAreas {
+    /// Ids of plan areas.
+    pub areas: Vec<String>,
+    /// A penalty for each job served outside of allowed areas. When omitted, such jobs cannot be
+    /// served by the vehicle.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub penalty: Option<f64>,
+}
+
 /// Vehicle optional break time variant.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 #[serde(untagged)]
 pub enum VehicleOptionalBreakTime {
-    /// Break time is defined by a time window with time specified in RFC3339 format.
+    /// Break time is defined by a time window with time specified in RFC3339 format or as time of day.
     TimeWindow(Vec<String>),
     /// Break time is defined by a time offset range.
     TimeOffset(Vec<f64>),
@@ -412,7 +982,7 @@ pub enum VehicleOptionalBreakTime {
 #[derive(Clone, Deserialize, Debug, Serialize)]
 #[serde(untagged)]
 pub enum VehicleRequiredBreakTime {
-    /// Break time is defined by exact time in RFC3339 format.
+    /// Break time is defined by exact time in RFC3339 format or as time of day.
     ExactTime(String),
     /// Break time is defined by amount of seconds since driving time.
     OffsetTime(f64),
@@ -485,15 +1055,195 @@ pub struct VehicleType {
     pub shifts: Vec<VehicleShift>,
 
     /// Vehicle capacity.
+    #[serde(default)]
     pub capacity: Vec<i32>,
 
+    /// Vehicle capacity specified by named load dimensions declared on fleet.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub named_capacity: Option<Vec<NamedLoad>>,
+
     /// Vehicle skills.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub skills: Option<Vec<String>>,
 
+    /// Amount of units of each skill provided by vehicle. Skills listed in `skills` without
+    /// count provide one unit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skill_counts: Option<Vec<SkillCount>>,
+
     /// Vehicle limits.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limits: Option<VehicleLimits>,
+
+    /// Vehicle tier: vehicles of higher tier are used only when jobs cannot be served by vehicles of
+    /// lower tiers. Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tier: Option<usize>,
+
+    /// Vehicle loading parameters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loading: Option<VehicleLoading>,
+
+    /// Vehicle compartments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compartments: Option<Vec<VehicleCompartment>>,
+
+    /// Vehicle energy parameters. If set, vehicle is considered as electric one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub energy: Option<VehicleEnergy>,
+
+    /// Vehicle fuel consumption parameters used to estimate its CO2 emissions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emissions: Option<VehicleEmissions>,
+
+    /// Vehicle trailer. If set, vehicle's capacity includes trailer's one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailer: Option<VehicleTrailer>,
+
+    /// Vehicle physical limits: jobs with items which exceed them cannot be served by the vehicle.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub physical: Option<VehiclePhysical>,
+
+    /// If set to true, tour departure time is shifted forward to minimize waiting time while keeping
+    /// all time windows feasible. Default is false: tour starts at the earliest shift time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optimize_departure: Option<bool>,
+
+    /// A vehicle specific service duration per unit of job demand, e.g. unload time per pallet with
+    /// vehicle's equipment. It is applied to the demand of the first dimension at job places which have no
+    /// own `durationPerUnit`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_per_unit: Option<f64>,
+}
+
+/// Specifies physical limits of vehicle, e.g. of a truck with a flatbed or a crane.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehiclePhysical {
+    /// A maximum weight which can be put on a single axle: the heaviest job item cannot exceed it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_axle_weight: Option<f64>,
+
+    /// A maximum length of a job item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_item_length: Option<f64>,
+
+    /// A maximum height of a job item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_item_height: Option<f64>,
+}
+
+/// Specifies a trailer which can be detached from vehicle at trailer parking.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleTrailer {
+    /// A trailer capacity: it is a part of vehicle's capacity which is not available while
+    /// trailer is detached.
+    pub capacity: Vec<i32>,
+}
+
+/// Specifies vehicle fuel consumption parameters. Fuel burn on each leg depends on travelled distance
+/// and on load carried by the vehicle, so heavier legs emit more.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleEmissions {
+    /// An emission class of the vehicle which defines amount of CO2 emitted per fuel unit.
+    pub class: EmissionClass,
+
+    /// A fuel consumption per distance unit of empty vehicle.
+    pub consumption: f64,
+
+    /// An extra fuel consumption per distance unit and per unit of carried load (first
+    /// capacity dimension). Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load_consumption: Option<f64>,
+}
+
+/// Specifies vehicle emission class by fuel type.
+#[derive(Clone, Copy, Deserialize, Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum EmissionClass {
+    /// Diesel engine: 2.68 kg of CO2 per liter.
+    Diesel,
+    /// Petrol engine: 2.31 kg of CO2 per liter.
+    Petrol,
+    /// Liquefied petroleum gas engine: 1.51 kg of CO2 per liter.
+    Lpg,
+    /// Compressed natural gas engine: 2.54 kg of CO2 per kg.
+    Cng,
+    /// Electric engine: no tailpipe emissions.
+    Electric,
+}
+
+/// Specifies electric vehicle energy parameters. Vehicle starts its shift with full battery.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleEnergy {
+    /// A battery capacity.
+    pub capacity: f64,
+
+    /// An energy consumption per distance unit.
+    pub consumption: f64,
+
+    /// An extra energy consumption per distance unit and per unit of carried load (first
+    /// capacity dimension). Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load_consumption: Option<f64>,
+
+    /// A reserve energy level which vehicle should never go below. Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reserve: Option<f64>,
+}
+
+/// Specifies a vehicle compartment: a separate part of vehicle's capacity which can hold products
+/// of only one class within the same trip.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleCompartment {
+    /// A compartment id.
+    pub id: String,
+
+    /// A compartment capacity.
+    pub capacity: Vec<i32>,
+
+    /// Product classes allowed to be loaded into the compartment.
+    pub classes: Vec<String>,
+}
+
+/// Specifies vehicle loading parameters.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleLoading {
+    /// If set to true, a recommended loading manifest is returned for each vehicle's tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manifest: Option<bool>,
+
+    /// Loading order of pickup and delivery jobs, e.g. for rear-loaded trucks. If not set, jobs can
+    /// be picked up and delivered in any order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<VehicleLoadingOrder>,
+
+    /// Backhaul requirement: deliveries have to be served before pickups, e.g. for classic vehicle
+    /// routing problem with backhauls. If not set, pickups and deliveries can be served in any order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backhaul: Option<VehicleBackhaul>,
+}
+
+/// Specifies loading order of pickup and delivery jobs.
+#[derive(Clone, Copy, Deserialize, Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum VehicleLoadingOrder {
+    /// Last in, first out: the job picked up last has to be delivered first.
+    Lifo,
+    /// First in, first out: the job picked up first has to be delivered first.
+    Fifo,
+}
+
+/// Specifies backhaul requirement of the vehicle.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleBackhaul {
+    /// A penalty applied for each pickup served before delivery. If not set, pickups cannot be
+    /// served before deliveries at all.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub penalty: Option<f64>,
 }
 
 /// Specifies a vehicle profile.
@@ -518,6 +1268,61 @@ pub struct MatrixProfile {
     /// Default value is 10.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub speed: Option<f64>,
+
+    /// Specifies how to infer routing matrix of the profile from matrix of another profile. Used
+    /// only when routing matrix is specified, but it has no data for the profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub infer: Option<ProfileInference>,
+
+    /// Specifies how routing matrix values are scaled and rounded to match metrics of external
+    /// systems, e.g. router or billing engine. Default is to use matrix values as they are.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metric: Option<MetricPolicy>,
+}
+
+/// Specifies routing matrix profile inference by scaling matrix of another profile.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileInference {
+    /// A name of the profile which routing matrix is scaled.
+    pub profile: String,
+
+    /// Travel time scale factor. Default value is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_factor: Option<f64>,
+
+    /// Distance scale factor. Default value is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_factor: Option<f64>,
+}
+
+/// Specifies routing matrix metric policy: values are multiplied by factor and then rounded.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricPolicy {
+    /// Distance scale factor. Default value is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_factor: Option<f64>,
+
+    /// Travel time scale factor. Default value is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_factor: Option<f64>,
+
+    /// Rounding mode of scaled values. Default is `round`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rounding: Option<RoundingMode>,
+}
+
+/// Specifies rounding mode of routing matrix values.
+#[derive(Clone, Copy, Deserialize, Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RoundingMode {
+    /// Rounds to the nearest integer, half way cases away from zero.
+    Round,
+    /// Rounds up.
+    Ceil,
+    /// Rounds down.
+    Floor,
 }
 
 /// Specifies vehicle resource type.
@@ -532,6 +1337,15 @@ pub enum VehicleResource {
         /// A total resource capacity.
         capacity: Vec<i32>,
     },
+
+    /// A shared dock resource: limits amount of vehicles served at the same time.
+    #[serde(rename(deserialize = "dock", serialize = "dock"))]
+    Dock {
+        /// Resource id.
+        id: String,
+        /// Amount of docks: max amount of vehicles which can reload simultaneously.
+        capacity: usize,
+    },
 }
 
 /// Specifies fleet.
@@ -546,6 +1360,24 @@ pub struct Fleet {
     /// Specifies vehicle resources.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resources: Option<Vec<VehicleResource>>,
+
+    /// Specifies named load dimensions. Their order defines the order of values in anonymous
+    /// capacity and demand vectors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<Vec<LoadDimension>>,
+}
+
+/// Specifies a named load dimension, e.g. weight, volume or pallets.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadDimension {
+    /// A dimension name.
+    pub name: String,
+
+    /// Specifies whether load in this dimension can be loaded or unloaded at reload.
+    /// Default is true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_reloadable: Option<bool>,
 }
 
 // endregion
@@ -603,6 +1435,33 @@ pub enum Objective {
     #[serde(rename(deserialize = "minimize-arrival-time", serialize = "minimize-arrival-time"))]
     MinimizeArrivalTime,
 
+    /// An objective to minimize duration of the longest tour (minimax).
+    #[serde(rename(deserialize = "minimize-max-duration", serialize = "minimize-max-duration"))]
+    MinimizeMaxDuration,
+
+    /// An objective to minimize total waiting time of all tours: a time spent by vehicles idling
+    /// before job's time window starts.
+    #[serde(rename(deserialize = "minimize-waiting-time", serialize = "minimize-waiting-time"))]
+    MinimizeWaitingTime,
+
+    /// An objective to minimize the maximum customer waiting time: a delay of service start relative
+    /// to the start of job's time window.
+    #[serde(rename(deserialize = "minimize-max-waiting-time", serialize = "minimize-max-waiting-time"))]
+    MinimizeMaxWaitingTime {
+        /// A percentile of waiting times to be minimized instead of the maximum, e.g. 0.95.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        percentile: Option<f64>,
+    },
+
+    /// An objective to keep total cost of the solution within given budget. When budget is not
+    /// enough to serve all jobs, some of them are left unassigned.
+    #[serde(rename(deserialize = "limit-total-cost", serialize = "limit-total-cost"))]
+    LimitTotalCost {
+        /// A maximum total cost of the solution.
+        #[serde(rename = "maxCost")]
+        max_cost: f64,
+    },
+
     /// An objective to balance max load across all tours.
     #[serde(rename(deserialize = "balance-max-load", serialize = "balance-max-load"))]
     BalanceMaxLoad {
@@ -638,12 +1497,25 @@ pub enum Objective {
         options: Option<BalanceOptions>,
     },
 
+    /// An objective to balance total value of served jobs across all tours.
+    #[serde(rename(deserialize = "balance-value", serialize = "balance-value"))]
+    BalanceValue {
+        /// An options which can be used to specify minimum value of a tour before
+        /// it considered for balancing.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        options: Option<BalanceOptions>,
+    },
+
     /// An objective to control order of job activities in the tour.
     #[serde(rename(deserialize = "tour-order", serialize = "tour-order"))]
     TourOrder {
         /// If the property is set to true, then order is enforced as hard constraint.
         #[serde(rename = "isConstrained")]
         is_constrained: bool,
+        /// If the property is set to true, then violations are penalized by difference of order
+        /// values instead of their amount. Not used when order is enforced as hard constraint.
+        #[serde(rename = "isGraded", skip_serializing_if = "Option::is_none")]
+        is_graded: Option<bool>,
     },
 
     /// An objective to control distribution of the jobs across different areas.
@@ -659,6 +1531,26 @@ pub enum Objective {
         #[serde(rename = "isValuePreferred")]
         is_value_preferred: Option<bool>,
     },
+
+    /// An objective to minimize total penalty of preferred job skills missing in assigned vehicles.
+    #[serde(rename(deserialize = "minimize-skill-penalty", serialize = "minimize-skill-penalty"))]
+    MinimizeSkillPenalty,
+
+    /// An objective to minimize amount of territory jobs served by vehicles not preferred by the territory.
+    #[serde(rename(deserialize = "minimize-territory-violations", serialize = "minimize-territory-violations"))]
+    MinimizeTerritoryViolations,
+
+    /// An objective to minimize total penalty of jobs served outside of allowed vehicle areas.
+    #[serde(rename(deserialize = "minimize-area-penalty", serialize = "minimize-area-penalty"))]
+    MinimizeAreaPenalty,
+
+    /// An objective to minimize total CO2 emissions of vehicles with emission parameters.
+    #[serde(rename(deserialize = "minimize-emissions", serialize = "minimize-emissions"))]
+    MinimizeEmissions,
+
+    /// An objective to minimize geometric overlap between tours.
+    #[serde(rename(deserialize = "minimize-overlap", serialize = "minimize-overlap"))]
+    MinimizeOverlap,
 }
 
 /// Specifies balance objective options. At the moment, it uses coefficient of variation as
@@ -735,6 +1627,34 @@ pub fn deserialize_matrix<R: Read>(reader: BufReader<R>) -> Result<Matrix, Vec<F
     })
 }
 
+/// Deserializes problem in json format from bytes. It is considerably faster than reading from
+/// `BufReader` and allows to read problem from memory mapped file without extra copy.
+pub fn deserialize_problem_from_slice(bytes: &[u8]) -> Result<Problem, Vec<FormatError>> {
+    serde_json::from_slice(bytes).map_err(|err| {
+        vec![FormatError::new(
+            "E0000".to_string(),
+            "cannot deserialize problem".to_string(),
+            format!("check input json: '{}'", err),
+        )]
+    })
+}
+
+/// Deserializes routing matrix in json format from bytes. Numeric arrays of the matrix are parsed
+/// by a custom parser directly from the slice, generic json parser is used as a fallback.
+pub fn deserialize_matrix_from_slice(bytes: &[u8]) -> Result<Matrix, Vec<FormatError>> {
+    if let Some(matrix) = super::matrix_parser::parse_matrix(bytes) {
+        return Ok(matrix);
+    }
+
+    serde_json::from_slice(bytes).map_err(|err| {
+        vec![FormatError::new(
+            "E0001".to_string(),
+            "cannot deserialize matrix".to_string(),
+            format!("check input json: '{}'", err),
+        )]
+    })
+}
+
 /// Deserializes json list of locations from `BufReader`.
 pub fn deserialize_locations<R: Read>(reader: BufReader<R>) -> Result<Vec<Location>, Vec<FormatError>> {
     serde_json::from_reader(reader).map_err(|err| {
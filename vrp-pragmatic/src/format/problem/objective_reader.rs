@@ -2,19 +2,24 @@
 #[path = "../../../tests/unit/format/problem/objective_reader_test.rs"]
 mod objective_reader_test;
 
-use crate::constraints::{AreaModule, TOTAL_VALUE_KEY, TOUR_ORDER_KEY};
+use crate::constraints::{create_emission_objective, EmissionCalculator, EMISSIONS_KEY};
+use crate::constraints::{create_overlap_objective, LocationPointFn, OVERLAP_KEY};
+use crate::constraints::{AreaModule, AREA_PENALTY_KEY, SKILL_PENALTY_KEY, TERRITORY_VIOLATION_KEY};
+use crate::constraints::{TOTAL_COST_LIMIT_KEY, TOTAL_VALUE_KEY, TOUR_ORDER_KEY};
 use crate::extensions::{JobTie, VehicleTie};
-use crate::format::problem::reader::{ApiProblem, ProblemProperties};
+use crate::format::problem::reader::{get_energy_demand_fn, ApiProblem, ProblemProperties};
 use crate::format::problem::BalanceOptions;
 use crate::format::problem::Objective::TourOrder as FormatTourOrder;
 use crate::format::problem::Objective::*;
-use crate::format::{AREA_CONSTRAINT_CODE, TOUR_ORDER_CONSTRAINT_CODE};
+use crate::format::{CoordIndex, Location};
+use crate::format::{AREA_CONSTRAINT_CODE, TOTAL_COST_LIMIT_CONSTRAINT_CODE, TOUR_ORDER_CONSTRAINT_CODE};
 use std::sync::Arc;
 use vrp_core::construction::clustering::vicinity::ClusterDimension;
 use vrp_core::construction::constraints::{ConstraintPipeline, FleetUsageConstraintModule};
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
 use vrp_core::models::common::{MultiDimLoad, SingleDimLoad};
-use vrp_core::models::problem::Job;
-use vrp_core::models::problem::{ProblemObjective, Single, TargetConstraint, TargetObjective};
+use vrp_core::models::problem::{ActivityCost, Actor, Job};
+use vrp_core::models::problem::{ProblemObjective, Single, TargetConstraint, TargetObjective, TransportCost};
 use vrp_core::solver::objectives::MinimizeArrivalTime as CoreMinimizeArrivalTime;
 use vrp_core::solver::objectives::TourOrder as CoreTourOrder;
 use vrp_core::solver::objectives::*;
@@ -22,7 +27,10 @@ use vrp_core::solver::objectives::*;
 pub fn create_objective(
     api_problem: &ApiProblem,
     constraint: &mut ConstraintPipeline,
+    transport: &Arc<dyn TransportCost + Send + Sync>,
+    activity: &Arc<dyn ActivityCost + Send + Sync>,
     props: &ProblemProperties,
+    coord_index: &CoordIndex,
 ) -> Arc<ProblemObjective> {
     Arc::new(match &api_problem.objectives {
         Some(objectives) => ProblemObjective::new(
@@ -35,8 +43,9 @@ pub fn create_objective(
                         MinimizeDistance => core_objectives.push(TotalDistance::minimize()),
                         MinimizeDuration => core_objectives.push(TotalDuration::minimize()),
                         MinimizeTours => {
-                            constraint.add_module(Arc::new(FleetUsageConstraintModule::new_minimized()));
-                            core_objectives.push(Arc::new(TotalRoutes::new_minimized()))
+                            let (module, objective) = get_fleet_usage(props);
+                            constraint.add_module(module);
+                            core_objectives.push(objective)
                         }
                         MaximizeTours => {
                             constraint.add_module(Arc::new(FleetUsageConstraintModule::new_maximized()));
@@ -52,15 +61,40 @@ pub fn create_objective(
                         }
                         MinimizeUnassignedJobs { breaks } => {
                             if let Some(breaks) = *breaks {
-                                core_objectives.push(Arc::new(get_unassigned_objective(breaks)))
+                                core_objectives.push(Arc::new(get_unassigned_objective(breaks, props)))
                             } else {
-                                core_objectives.push(Arc::new(get_unassigned_objective(1.)))
+                                core_objectives.push(Arc::new(get_unassigned_objective(1., props)))
                             }
                         }
                         MinimizeArrivalTime => {
                             constraint.add_module(Arc::new(FleetUsageConstraintModule::new_earliest()));
                             core_objectives.push(Arc::new(CoreMinimizeArrivalTime::default()))
                         }
+                        MinimizeMaxDuration => {
+                            let (module, objective) = Minimax::new_duration_minimized();
+                            constraint.add_module(module);
+                            core_objectives.push(objective);
+                        }
+                        MinimizeWaitingTime => {
+                            let (module, objective) = TotalWaitingTime::minimize(activity.clone(), transport.clone());
+                            constraint.add_module(module);
+                            core_objectives.push(objective);
+                        }
+                        MinimizeMaxWaitingTime { percentile } => {
+                            let (module, objective) = Minimax::new_waiting_time_minimized(*percentile);
+                            constraint.add_module(module);
+                            core_objectives.push(objective);
+                        }
+                        LimitTotalCost { max_cost } => {
+                            let (module, objective) = TotalCostLimit::new_constrained(
+                                *max_cost,
+                                transport.clone(),
+                                TOTAL_COST_LIMIT_KEY,
+                                TOTAL_COST_LIMIT_CONSTRAINT_CODE,
+                            );
+                            constraint.add_module(module);
+                            core_objectives.push(objective);
+                        }
                         BalanceMaxLoad { options } => {
                             let (module, objective) = get_load_balance(props, options);
                             constraint.add_module(module);
@@ -84,8 +118,17 @@ pub fn create_objective(
                             constraint.add_module(module);
                             core_objectives.push(objective);
                         }
-                        FormatTourOrder { is_constrained } => {
-                            let (module, objective) = get_order(*is_constrained);
+                        BalanceValue { options } => {
+                            let threshold = unwrap_options(options);
+                            let (module, objective) = WorkBalance::new_value_balanced(
+                                threshold,
+                                Arc::new(|job| job.dimens().get_job_value().unwrap_or(0.)),
+                            );
+                            constraint.add_module(module);
+                            core_objectives.push(objective);
+                        }
+                        FormatTourOrder { is_constrained, is_graded } => {
+                            let (module, objective) = get_order(*is_constrained, is_graded.unwrap_or(false));
                             constraint.add_module(module);
                             core_objectives.push(objective);
                         }
@@ -97,18 +140,49 @@ pub fn create_objective(
                             constraint.add_module(module);
                             objectives.into_iter().for_each(|objective| core_objectives.push(objective));
                         }
+                        MinimizeSkillPenalty => {
+                            let (module, objective) = get_skill_penalty();
+                            constraint.add_module(module);
+                            core_objectives.push(objective);
+                        }
+                        MinimizeTerritoryViolations => {
+                            let (module, objective) = get_territory_violations();
+                            constraint.add_module(module);
+                            core_objectives.push(objective);
+                        }
+                        MinimizeAreaPenalty => {
+                            let (module, objective) = get_area_penalty();
+                            constraint.add_module(module);
+                            core_objectives.push(objective);
+                        }
+                        MinimizeEmissions => {
+                            let calculator = Arc::new(EmissionCalculator::new(
+                                transport.clone(),
+                                get_energy_demand_fn(props.has_multi_dimen_capacity),
+                            ));
+                            let (module, objective) = create_emission_objective(calculator, EMISSIONS_KEY);
+                            constraint.add_module(module);
+                            core_objectives.push(objective);
+                        }
+                        MinimizeOverlap => {
+                            let (module, objective) =
+                                create_overlap_objective(get_location_point_fn(coord_index), OVERLAP_KEY);
+                            constraint.add_module(module);
+                            core_objectives.push(objective);
+                        }
                     });
                     core_objectives
                 })
                 .collect(),
         ),
         None => {
+            let (fleet_module, fleet_objective) = get_fleet_usage(props);
             let mut objectives: Vec<Vec<TargetObjective>> = vec![
-                vec![Arc::new(get_unassigned_objective(1.))],
-                vec![Arc::new(TotalRoutes::default())],
+                vec![Arc::new(get_unassigned_objective(1., props))],
+                vec![fleet_objective],
                 vec![TotalCost::minimize()],
             ];
-            constraint.add_module(Arc::new(FleetUsageConstraintModule::new_minimized()));
+            constraint.add_module(fleet_module);
 
             if let Some(max_value) = props.max_job_value {
                 let (value_module, value_objective) = get_value(max_value, None, None);
@@ -117,16 +191,45 @@ pub fn create_objective(
             }
 
             if props.has_order {
-                let (order_module, order_objective) = get_order(true);
+                let (order_module, order_objective) = get_order(true, false);
                 constraint.add_module(order_module);
                 objectives.insert(if props.max_job_value.is_some() { 2 } else { 1 }, vec![order_objective]);
             }
 
+            if props.has_preferred_skills {
+                let (skill_module, skill_objective) = get_skill_penalty();
+                constraint.add_module(skill_module);
+                objectives.insert(objectives.len() - 1, vec![skill_objective]);
+            }
+
+            if props.has_territories {
+                let (territory_module, territory_objective) = get_territory_violations();
+                constraint.add_module(territory_module);
+                objectives.insert(objectives.len() - 1, vec![territory_objective]);
+            }
+
+            if props.has_area_penalty {
+                let (area_module, area_objective) = get_area_penalty();
+                constraint.add_module(area_module);
+                objectives.insert(objectives.len() - 1, vec![area_objective]);
+            }
+
             ProblemObjective::new(objectives)
         }
     })
 }
 
+fn get_location_point_fn(coord_index: &CoordIndex) -> LocationPointFn {
+    let points = (0..=coord_index.max_index().unwrap_or(0))
+        .map(|idx| match coord_index.get_by_idx(idx) {
+            Some(Location::Coordinate { lat, lng }) => Some((lat, lng)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    Arc::new(move |location| points.get(location).cloned().flatten())
+}
+
 fn unwrap_options(options: &Option<BalanceOptions>) -> Option<f64> {
     options.as_ref().and_then(|o| o.threshold)
 }
@@ -159,20 +262,25 @@ fn get_value(
     )
 }
 
-fn get_order(is_constrained: bool) -> (TargetConstraint, TargetObjective) {
-    let order_fn = OrderFn::Left(Arc::new(|single| {
+fn get_order(is_constrained: bool, is_graded: bool) -> (TargetConstraint, TargetObjective) {
+    let order_fn = create_order_fn();
+
+    match (is_constrained, is_graded) {
+        (true, _) => CoreTourOrder::new_constrained(order_fn, TOUR_ORDER_KEY, TOUR_ORDER_CONSTRAINT_CODE),
+        (false, true) => CoreTourOrder::new_graded(order_fn, TOUR_ORDER_KEY),
+        (false, false) => CoreTourOrder::new_unconstrained(order_fn, TOUR_ORDER_KEY),
+    }
+}
+
+/// Creates a tour order function which uses job order property.
+pub(crate) fn create_order_fn() -> OrderFn {
+    OrderFn::Left(Arc::new(|single| {
         single
             .dimens
             .get_job_order()
             .map(|order| OrderResult::Value(order as f64))
             .unwrap_or_else(|| get_default_order(single))
-    }));
-
-    if is_constrained {
-        CoreTourOrder::new_constrained(order_fn, TOUR_ORDER_KEY, TOUR_ORDER_CONSTRAINT_CODE)
-    } else {
-        CoreTourOrder::new_unconstrained(order_fn, TOUR_ORDER_KEY)
-    }
+    }))
 }
 
 fn get_area(
@@ -212,6 +320,62 @@ fn get_area(
     }
 }
 
+fn get_skill_penalty() -> (TargetConstraint, TargetObjective) {
+    let get_job_penalty = |actor: &Actor, job: &Job| {
+        job.dimens().get_job_skills().map_or(0., |skills| skills.get_penalty(actor.vehicle.dimens.get_vehicle_skills()))
+    };
+    let get_route_penalty = Arc::new(move |route_ctx: &RouteContext| {
+        route_ctx.route.tour.jobs().map(|job| get_job_penalty(&route_ctx.route.actor, &job)).sum::<f64>()
+    });
+
+    GenericValue::new_constrained_objective(
+        None,
+        Arc::new(|source, _| Ok(source)),
+        get_route_penalty.clone(),
+        Arc::new(move |ctx: &SolutionContext| ctx.routes.iter().map(|route_ctx| get_route_penalty(route_ctx)).sum()),
+        Arc::new(move |_, route_ctx, job, _| get_job_penalty(&route_ctx.route.actor, job)),
+        SKILL_PENALTY_KEY,
+    )
+}
+
+fn get_territory_violations() -> (TargetConstraint, TargetObjective) {
+    let get_job_violation = |actor: &Actor, job: &Job| {
+        job.dimens()
+            .get_job_territory()
+            .map_or(0., |territory| if territory.is_violated(&actor.vehicle.dimens) { 1. } else { 0. })
+    };
+    let get_route_violations = Arc::new(move |route_ctx: &RouteContext| {
+        route_ctx.route.tour.jobs().map(|job| get_job_violation(&route_ctx.route.actor, &job)).sum::<f64>()
+    });
+
+    GenericValue::new_constrained_objective(
+        None,
+        Arc::new(|source, _| Ok(source)),
+        get_route_violations.clone(),
+        Arc::new(move |ctx: &SolutionContext| ctx.routes.iter().map(|route_ctx| get_route_violations(route_ctx)).sum()),
+        Arc::new(move |_, route_ctx, job, _| get_job_violation(&route_ctx.route.actor, job)),
+        TERRITORY_VIOLATION_KEY,
+    )
+}
+
+fn get_area_penalty() -> (TargetConstraint, TargetObjective) {
+    let get_job_penalty = |actor: &Actor, job: &Job| {
+        actor.vehicle.dimens.get_vehicle_areas().map_or(0., |areas| areas.get_penalty(job.dimens()))
+    };
+    let get_route_penalty = Arc::new(move |route_ctx: &RouteContext| {
+        route_ctx.route.tour.jobs().map(|job| get_job_penalty(&route_ctx.route.actor, &job)).sum::<f64>()
+    });
+
+    GenericValue::new_constrained_objective(
+        None,
+        Arc::new(|source, _| Ok(source)),
+        get_route_penalty.clone(),
+        Arc::new(move |ctx: &SolutionContext| ctx.routes.iter().map(|route_ctx| get_route_penalty(route_ctx)).sum()),
+        Arc::new(move |_, route_ctx, job, _| get_job_penalty(&route_ctx.route.actor, job)),
+        AREA_PENALTY_KEY,
+    )
+}
+
 fn get_load_balance(
     props: &ProblemProperties,
     options: &Option<BalanceOptions>,
@@ -239,8 +403,33 @@ fn get_load_balance(
     }
 }
 
-fn get_unassigned_objective(break_value: f64) -> TotalUnassignedJobs {
-    TotalUnassignedJobs::new(Arc::new(move |_, job, _| get_unassigned_job_estimate(job, break_value, 1.)))
+fn get_fleet_usage(props: &ProblemProperties) -> (TargetConstraint, TargetObjective) {
+    if props.has_vehicle_tiers {
+        let tier_fn = Arc::new(|actor: &Actor| actor.vehicle.dimens.get_vehicle_tier().unwrap_or(0));
+
+        (Arc::new(FleetUsageConstraintModule::new_tiered(tier_fn.clone())), Arc::new(TotalRoutes::new_tiered(tier_fn)))
+    } else {
+        (Arc::new(FleetUsageConstraintModule::new_minimized()), Arc::new(TotalRoutes::new_minimized()))
+    }
+}
+
+/// An unassignment weight of a reservation job: it is positive, so reservations are assigned when capacity
+/// allows, but much lower than a weight of a real job, so real jobs displace them as they arrive.
+const RESERVATION_UNASSIGNED_WEIGHT: f64 = 0.01;
+
+fn get_unassigned_objective(break_value: f64, props: &ProblemProperties) -> TotalUnassignedJobs {
+    // NOTE jobs which failed to be assigned in previous planning runs weigh more
+    let escalation_weight = props.escalation_weight.unwrap_or(0.);
+
+    TotalUnassignedJobs::new(Arc::new(move |_, job, _| {
+        if job.dimens().get_job_reservation().unwrap_or(false) {
+            RESERVATION_UNASSIGNED_WEIGHT
+        } else {
+            let attempts = job.dimens().get_job_attempts().unwrap_or(0) as f64;
+            let penalty = job.dimens().get_unassigned_penalty().unwrap_or(1.);
+            get_unassigned_job_estimate(job, break_value, penalty) * (1. + escalation_weight * attempts)
+        }
+    }))
 }
 
 fn get_unassigned_job_estimate(job: &Job, break_value: f64, default_value: f64) -> f64 {
@@ -249,7 +438,7 @@ fn get_unassigned_job_estimate(job: &Job, break_value: f64, default_value: f64)
     } else {
         job.dimens().get_job_type().map_or(default_value, |job_type| match job_type.as_str() {
             "break" => break_value,
-            "reload" => 0.,
+            "reload" | "recharge" | "relay" | "trailer" | "unloading" => 0.,
             _ => default_value,
         })
     }
@@ -257,7 +446,8 @@ fn get_unassigned_job_estimate(job: &Job, break_value: f64, default_value: f64)
 
 fn get_default_order(single: &Single) -> OrderResult {
     match single.dimens.get_job_type().map(|v| v.as_str()) {
-        Some("break") | Some("reload") | Some("dispatch") => OrderResult::Ignored,
+        Some("break") | Some("reload") | Some("recharge") | Some("detach") | Some("attach") | Some("dispatch")
+        | Some("unloading") | Some("relay") => OrderResult::Ignored,
         _ => OrderResult::Default,
     }
 }
@@ -14,14 +14,31 @@ mod objective_reader;
 #[path = "./clustering_reader.rs"]
 mod clustering_reader;
 
+#[path = "./time_reader.rs"]
+mod time_reader;
+
+#[path = "./area_reader.rs"]
+mod area_reader;
+pub(crate) use self::area_reader::normalize_areas;
+
+#[path = "./dimension_reader.rs"]
+mod dimension_reader;
+pub(crate) use self::dimension_reader::{get_non_reloadable_dimensions, normalize_dimensions};
+
 use self::clustering_reader::create_cluster_config;
-use self::fleet_reader::{create_transport_costs, read_fleet};
+use self::fleet_reader::{create_transport_costs, get_profile_index_map, read_fleet};
 use self::job_reader::{read_jobs_with_extra_locks, read_locks};
 use self::objective_reader::create_objective;
+pub(crate) use self::objective_reader::create_order_fn;
+pub(crate) use self::time_reader::{
+    has_ambiguous_epoch_offset, is_time_of_day_without_offset, is_valid_recurring_time_window,
+};
+use self::time_reader::{normalize_job_times, normalize_shift_times};
 use crate::constraints::*;
-use crate::extensions::{get_route_modifier, OnlyVehicleActivityCost, VehicleTie};
+use crate::extensions::{get_route_modifier, JobTie, OnlyVehicleActivityCost, VehicleTie};
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::*;
+use crate::format::solution::map_reason_code;
 use crate::format::*;
 use crate::utils::get_approx_transportation;
 use crate::validation::ValidationContext;
@@ -34,13 +51,19 @@ use vrp_core::construction::constraints::*;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::*;
 use vrp_core::models::{Extras, Lock, Problem};
-use vrp_core::prelude::*;
+use vrp_core::prelude::{
+    compare_floats, ActivityCost, Actor, ConstraintPipeline, DefaultRandom, Demand, Jobs, MultiDimLoad, Random,
+    SingleDimLoad, TimeSpan, TimeWindow, TransportCost,
+};
 use vrp_core::rosomaxa::utils::CollectGroupBy;
-use vrp_core::solver::processing::VicinityDimension;
+use vrp_core::solver::processing::{MarginalCostDimension, VicinityDimension};
 
 pub type ApiProblem = crate::format::problem::Problem;
 pub type CoreFleet = vrp_core::models::problem::Fleet;
 
+/// An amount of repair passes used by precise marginal cost estimation.
+const PRECISE_REPAIR_PASSES: usize = 3;
+
 /// Reads specific problem definition from various sources.
 pub trait PragmaticProblem {
     /// Reads problem defined in pragmatic format.
@@ -56,7 +79,7 @@ impl<R: Read> PragmaticProblem for (BufReader<R>, Vec<BufReader<R>>) {
             matrices.push(deserialize_matrix(matrix)?);
         }
 
-        map_to_problem_with_matrices(problem, matrices)
+        map_to_problem_with_matrices(problem, matrices, None)
     }
 }
 
@@ -64,40 +87,52 @@ impl<R: Read> PragmaticProblem for BufReader<R> {
     fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
         let problem = deserialize_problem(self)?;
 
-        map_to_problem_with_approx(problem)
+        map_to_problem_with_approx(problem, None)
     }
 }
 
-impl PragmaticProblem for (String, Vec<String>) {
+impl PragmaticProblem for (&[u8], Vec<&[u8]>) {
     fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
-        let problem = deserialize_problem(BufReader::new(self.0.as_bytes()))?;
+        let problem = deserialize_problem_from_slice(self.0)?;
 
         let mut matrices = vec![];
         for matrix in self.1 {
-            matrices.push(deserialize_matrix(BufReader::new(matrix.as_bytes()))?);
+            matrices.push(deserialize_matrix_from_slice(matrix)?);
         }
 
-        map_to_problem_with_matrices(problem, matrices)
+        map_to_problem_with_matrices(problem, matrices, None)
     }
 }
 
-impl PragmaticProblem for String {
+impl PragmaticProblem for &[u8] {
+    fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
+        let problem = deserialize_problem_from_slice(self)?;
+
+        map_to_problem_with_approx(problem, None)
+    }
+}
+
+impl PragmaticProblem for (String, Vec<String>) {
     fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
-        let problem = deserialize_problem(BufReader::new(self.as_bytes()))?;
+        (self.0.as_bytes(), self.1.iter().map(|matrix| matrix.as_bytes()).collect()).read_pragmatic()
+    }
+}
 
-        map_to_problem_with_approx(problem)
+impl PragmaticProblem for String {
+    fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
+        self.as_bytes().read_pragmatic()
     }
 }
 
 impl PragmaticProblem for (ApiProblem, Vec<Matrix>) {
     fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
-        map_to_problem_with_matrices(self.0, self.1)
+        map_to_problem_with_matrices(self.0, self.1, None)
     }
 }
 
 impl PragmaticProblem for ApiProblem {
     fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
-        map_to_problem_with_approx(self)
+        map_to_problem_with_approx(self, None)
     }
 }
 
@@ -111,6 +146,34 @@ impl PragmaticProblem for (ApiProblem, Option<Vec<Matrix>>) {
     }
 }
 
+impl PragmaticProblem for (ApiProblem, Option<Vec<Matrix>>, RouteCheckFn) {
+    fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
+        let (problem, matrices, route_check) = self;
+
+        if let Some(matrices) = matrices {
+            map_to_problem_with_matrices(problem, matrices, Some(route_check))
+        } else {
+            map_to_problem_with_approx(problem, Some(route_check))
+        }
+    }
+}
+
+/// Creates a copy of the problem with hard constraints overridden for a specific solver run, e.g. to
+/// ignore skills in a what-if analysis without reading the problem again. Constraints are referenced
+/// by unassigned job reason codes, such as `SKILL_CONSTRAINT`. Please note, that a solution of such
+/// problem can violate overridden constraints, so it is not feasible for the original problem.
+pub fn create_overlaid_problem(problem: &Problem, overrides: &[(&str, ConstraintOverride)]) -> Result<Problem, String> {
+    let overlay = overrides.iter().try_fold(ConstraintOverlay::default(), |overlay, (reason, value)| {
+        match (map_reason_code(reason), value) {
+            (-1, _) => Err(format!("unknown constraint reason code: '{}'", reason)),
+            (code, ConstraintOverride::Disable) => Ok(overlay.with_disabled(code)),
+            (code, ConstraintOverride::Soften(penalty)) => Ok(overlay.with_softened(code, *penalty)),
+        }
+    })?;
+
+    Ok(Problem { constraint: Arc::new(overlay.apply(problem.constraint.as_ref())), ..problem.clone() })
+}
+
 pub struct ProblemProperties {
     has_multi_dimen_capacity: bool,
     has_breaks: bool,
@@ -120,11 +183,39 @@ pub struct ProblemProperties {
     has_reloads: bool,
     has_order: bool,
     has_group: bool,
+    has_bundle: bool,
+    has_unassigned_penalty: bool,
     has_compatibility: bool,
     has_tour_size_limits: bool,
     has_tour_travel_limits: bool,
+    has_consecutive_driving_limits: bool,
+    has_driving_time: bool,
+    has_vehicle_tiers: bool,
+    has_visit_gaps: bool,
+    has_ride_durations: bool,
+    has_sync: bool,
+    has_precedences: bool,
+    has_docks: bool,
+    has_loading_order: bool,
+    has_backhaul: bool,
+    has_zone_sequences: bool,
+    has_placements: bool,
+    has_preferred_skills: bool,
+    has_energy: bool,
+    has_trailers: bool,
+    has_territories: bool,
+    has_strict_territories: bool,
+    has_pinned_territories: bool,
+    has_area_restrictions: bool,
+    has_area_penalty: bool,
+    has_physical_limits: bool,
+    has_departure_optimization: bool,
+    has_overtime: bool,
+    has_unloading: bool,
+    has_relays: bool,
     max_job_value: Option<f64>,
     max_area_value: Option<f64>,
+    escalation_weight: Option<f64>,
 }
 
 /// Creates a matrices using approximation.
@@ -163,23 +254,140 @@ pub fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
         .collect()
 }
 
-fn map_to_problem_with_approx(problem: ApiProblem) -> Result<Problem, Vec<FormatError>> {
+/// Adds routing matrices for profiles which have no routing data, but can be inferred by scaling
+/// matrices of another profile. Returned matrices are ordered by profile definition.
+pub(crate) fn infer_matrices(problem: &ApiProblem, matrices: Vec<Matrix>) -> Vec<Matrix> {
+    let known_profiles = matrices.iter().filter_map(|matrix| matrix.profile.clone()).collect::<HashSet<_>>();
+    let scale = |value: i64, factor: Option<f64>| {
+        // NOTE keep negative values as they are used to mark unreachable locations
+        if value < 0 {
+            value
+        } else {
+            (value as f64 * factor.unwrap_or(1.)).round() as i64
+        }
+    };
+
+    let inferred = problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| !known_profiles.contains(&profile.name))
+        .filter_map(|profile| profile.infer.as_ref().map(|infer| (profile, infer)))
+        .flat_map(|(profile, infer)| {
+            matrices.iter().filter(move |matrix| matrix.profile.as_ref() == Some(&infer.profile)).map(move |matrix| {
+                Matrix {
+                    profile: Some(profile.name.clone()),
+                    timestamp: matrix.timestamp.clone(),
+                    travel_times: matrix
+                        .travel_times
+                        .iter()
+                        .map(|&value| scale(value, infer.duration_factor))
+                        .collect(),
+                    distances: matrix.distances.iter().map(|&value| scale(value, infer.distance_factor)).collect(),
+                    error_codes: matrix.error_codes.clone(),
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if inferred.is_empty() {
+        return matrices;
+    }
+
+    let profile_index = get_profile_index_map(problem);
+    let mut matrices = matrices.into_iter().chain(inferred).collect::<Vec<_>>();
+    matrices.sort_by_key(|matrix| matrix.profile.as_ref().and_then(|name| profile_index.get(name)).cloned());
+
+    matrices
+}
+
+/// Applies metric policies of profiles to their routing matrices: values are scaled and rounded,
+/// so costs and checker use exactly the same metrics as an external system.
+pub(crate) fn apply_metric_policies(problem: &ApiProblem, matrices: Vec<Matrix>) -> Vec<Matrix> {
+    let profiles = &problem.fleet.profiles;
+    if profiles.iter().all(|profile| profile.metric.is_none()) {
+        return matrices;
+    }
+
+    let apply = |value: i64, factor: Option<f64>, rounding: Option<RoundingMode>| {
+        // NOTE keep negative values as they are used to mark unreachable locations
+        if value < 0 {
+            return value;
+        }
+
+        let value = value as f64 * factor.unwrap_or(1.);
+        let value = match rounding.unwrap_or(RoundingMode::Round) {
+            RoundingMode::Round => value.round(),
+            RoundingMode::Ceil => value.ceil(),
+            RoundingMode::Floor => value.floor(),
+        };
+
+        value as i64
+    };
+
+    matrices
+        .into_iter()
+        .enumerate()
+        .map(|(idx, matrix)| {
+            let metric = match matrix.profile.as_ref() {
+                Some(name) => profiles.iter().find(|profile| profile.name == *name),
+                None => profiles.get(idx),
+            }
+            .and_then(|profile| profile.metric.as_ref());
+
+            match metric {
+                Some(metric) => Matrix {
+                    travel_times: matrix
+                        .travel_times
+                        .iter()
+                        .map(|&value| apply(value, metric.duration_factor, metric.rounding))
+                        .collect(),
+                    distances: matrix
+                        .distances
+                        .iter()
+                        .map(|&value| apply(value, metric.distance_factor, metric.rounding))
+                        .collect(),
+                    ..matrix
+                },
+                None => matrix,
+            }
+        })
+        .collect()
+}
+
+fn map_to_problem_with_approx(
+    problem: ApiProblem,
+    route_check: Option<RouteCheckFn>,
+) -> Result<Problem, Vec<FormatError>> {
     let coord_index = CoordIndex::new(&problem);
     let matrices = if coord_index.get_used_types().1 { vec![] } else { create_approx_matrices(&problem) };
-    map_to_problem(problem, matrices, coord_index)
+    map_to_problem(problem, matrices, coord_index, route_check)
 }
 
-fn map_to_problem_with_matrices(problem: ApiProblem, matrices: Vec<Matrix>) -> Result<Problem, Vec<FormatError>> {
+fn map_to_problem_with_matrices(
+    problem: ApiProblem,
+    matrices: Vec<Matrix>,
+    route_check: Option<RouteCheckFn>,
+) -> Result<Problem, Vec<FormatError>> {
     let coord_index = CoordIndex::new(&problem);
-    map_to_problem(problem, matrices, coord_index)
+    map_to_problem(problem, matrices, coord_index, route_check)
+}
+
+/// Normalizes job and vehicle shift times specified as time of day to absolute time in RFC3339 format.
+pub(crate) fn normalize_times(api_problem: ApiProblem) -> ApiProblem {
+    normalize_job_times(normalize_shift_times(api_problem))
 }
 
 fn map_to_problem(
     api_problem: ApiProblem,
     matrices: Vec<Matrix>,
     coord_index: CoordIndex,
+    route_check: Option<RouteCheckFn>,
 ) -> Result<Problem, Vec<FormatError>> {
+    let api_problem = normalize_dimensions(normalize_areas(normalize_times(api_problem)));
+
     ValidationContext::new(&api_problem, Some(&matrices), &coord_index).validate()?;
+    let matrices = apply_metric_policies(&api_problem, infer_matrices(&api_problem, matrices));
 
     let problem_props = get_problem_properties(&api_problem, &matrices);
 
@@ -240,7 +448,12 @@ fn map_to_problem(
         &locks,
     );
 
-    let objective = create_objective(&api_problem, &mut constraint, &problem_props);
+    if let Some(route_check) = route_check {
+        constraint.add_module(Arc::new(CustomRouteModule::new(route_check, CUSTOM_ROUTE_CONSTRAINT_CODE)));
+    }
+
+    let objective =
+        create_objective(&api_problem, &mut constraint, &transport, &activity, &problem_props, &coord_index);
     let constraint = Arc::new(constraint);
     let extras = Arc::new(
         create_extras(&api_problem, constraint.clone(), &problem_props, job_index, coord_index, reserved_times_index)
@@ -303,6 +516,15 @@ fn read_reserved_times_index(api_problem: &ApiProblem, fleet: &CoreFleet) -> Res
                         TimeSpan::Offset(TimeOffset::new(*offset, *offset + duration))
                     }
                 })
+                .chain(
+                    actor
+                        .vehicle
+                        .dimens
+                        .get_vehicle_maintenance()
+                        .iter()
+                        .flat_map(|maintenance| maintenance.iter())
+                        .map(|tw| TimeSpan::Window(tw.clone())),
+                )
                 .collect::<Vec<_>>();
 
             if times.is_empty() {
@@ -331,34 +553,176 @@ fn create_constraint_pipeline(
         constraint.add_module(Arc::new(ReachableModule::new(transport.clone(), REACHABLE_CONSTRAINT_CODE)));
     }
 
+    // NOTE energy module updates recharge durations, so it has to precede transport one
+    if props.has_energy {
+        constraint.add_module(Arc::new(EnergyModule::new(
+            transport.clone(),
+            get_energy_demand_fn(props.has_multi_dimen_capacity),
+            ENERGY_CONSTRAINT_CODE,
+            ENERGY_LEVEL_KEY,
+        )));
+    }
+
     constraint.add_module(Arc::new(TransportConstraintModule::new(
         transport.clone(),
         activity.clone(),
         TIME_CONSTRAINT_CODE,
     )));
 
+    // NOTE departure shift module reschedules routes, so it has to follow transport one
+    if props.has_departure_optimization {
+        constraint.add_module(Arc::new(DepartureShiftModule::new(
+            activity.clone(),
+            transport.clone(),
+            Arc::new(|actor| actor.vehicle.dimens.get_departure_optimization()),
+        )));
+    }
+
+    // NOTE sync and precedence modules reschedule routes, so they have to follow transport one
+    if props.has_sync {
+        add_sync_module(&mut constraint, api_problem, transport.clone(), activity.clone());
+    }
+
+    if props.has_precedences {
+        add_precedence_module(&mut constraint, api_problem, transport.clone(), activity.clone());
+    }
+
+    // NOTE overtime depends on tour end, so it has to follow modules which reschedule routes
+    if props.has_overtime {
+        constraint.add_module(Arc::new(OvertimeModule::new(activity.clone(), transport.clone())));
+    }
+
+    if props.has_relays {
+        constraint.add_module(Arc::new(RelayModule::new(transport.clone(), RELAY_CONSTRAINT_CODE, DRIVER_TIME_KEY)));
+    }
+
     add_capacity_reload_modules(&mut constraint, api_problem, jobs, job_index, props);
 
+    // NOTE dock module reschedules routes and removes reloads, so it follows transport and capacity ones
+    if props.has_docks {
+        add_dock_module(&mut constraint, api_problem, transport.clone(), activity.clone());
+    }
+
     if props.has_tour_travel_limits {
         add_tour_limit_module(&mut constraint, transport.clone(), api_problem);
     }
 
+    if props.has_consecutive_driving_limits {
+        add_consecutive_driving_module(&mut constraint, transport.clone(), api_problem);
+    }
+
+    if props.has_driving_time {
+        constraint.add_module(Arc::new(DrivingTimeModule::new(
+            transport.clone(),
+            activity.clone(),
+            DRIVING_TIME_CONSTRAINT_CODE,
+        )));
+    }
+
     if props.has_breaks {
         constraint.add_module(Arc::new(BreakModule::new(BREAK_CONSTRAINT_CODE)));
     }
 
+    if props.has_visit_gaps {
+        constraint.add_module(Arc::new(VisitGapModule::new(
+            activity.clone(),
+            transport.clone(),
+            VISIT_GAP_CONSTRAINT_CODE,
+            VISIT_GAP_KEY,
+        )));
+    }
+
+    if props.has_ride_durations {
+        constraint.add_module(Arc::new(RideDurationModule::new(
+            activity.clone(),
+            transport.clone(),
+            RIDE_DURATION_CONSTRAINT_CODE,
+            RIDE_DURATION_KEY,
+        )));
+    }
+
+    if props.has_loading_order {
+        constraint.add_module(Arc::new(LoadingOrderModule::new(LOADING_ORDER_CONSTRAINT_CODE)));
+    }
+
+    if props.has_backhaul {
+        constraint.add_module(Arc::new(BackhaulModule::new(BACKHAUL_CONSTRAINT_CODE)));
+    }
+
+    if props.has_zone_sequences {
+        let penalties = api_problem.plan.zone_sequences.iter().flatten().map(|sequence| sequence.penalty).collect();
+        constraint.add_module(Arc::new(ZoneSequenceModule::new(penalties)));
+    }
+
+    if props.has_placements {
+        constraint.add_module(Arc::new(PlacementModule::new(PLACEMENT_CONSTRAINT_CODE)));
+    }
+
+    if props.has_trailers {
+        if props.has_multi_dimen_capacity {
+            constraint.add_module(Arc::new(TrailerModule::<MultiDimLoad>::new(TRAILER_CONSTRAINT_CODE)));
+        } else {
+            constraint.add_module(Arc::new(TrailerModule::<SingleDimLoad>::new(TRAILER_CONSTRAINT_CODE)));
+        }
+    }
+
+    if props.has_unloading {
+        if props.has_multi_dimen_capacity {
+            constraint.add_module(Arc::new(UnloadingModule::<MultiDimLoad>::new(UNLOADING_CONSTRAINT_CODE)));
+        } else {
+            constraint.add_module(Arc::new(UnloadingModule::<SingleDimLoad>::new(UNLOADING_CONSTRAINT_CODE)));
+        }
+    }
+
     if props.has_compatibility {
-        constraint.add_module(Arc::new(CompatibilityModule::new(COMPATIBILITY_CONSTRAINT_CODE, COMPATIBILITY_KEY)));
+        constraint.add_module(Arc::new(CompatibilityModule::new(
+            COMPATIBILITY_CONSTRAINT_CODE,
+            COMPATIBILITY_KEY,
+            Arc::new(|job| job.dimens().get_job_compatibility()),
+            CompatibilityScope::Route,
+        )));
     }
 
     if props.has_group {
         constraint.add_module(Arc::new(GroupModule::new(jobs.size(), GROUP_CONSTRAINT_CODE, GROUP_KEY)));
     }
 
+    if props.has_bundle {
+        constraint.add_module(Arc::new(BundleModule::new(BUNDLE_CONSTRAINT_CODE)));
+    }
+
+    if props.has_unassigned_penalty {
+        constraint.add_module(Arc::new(UnassignedPenaltyModule::default()));
+    }
+
     if props.has_skills {
         constraint.add_module(Arc::new(SkillsModule::new(SKILL_CONSTRAINT_CODE)));
     }
 
+    if props.has_strict_territories || props.has_pinned_territories {
+        constraint.add_module(Arc::new(TerritoryModule::new(TERRITORY_CONSTRAINT_CODE)));
+    }
+
+    if props.has_pinned_territories {
+        constraint.add_module(Arc::new(PinnedLockingModule::new(
+            TERRITORY_CONSTRAINT_CODE,
+            TERRITORY_PINNING_KEY,
+            Arc::new(|actor, job| {
+                job.dimens().get_job_territory().and_then(|territory| {
+                    territory.cross_zone_penalty.filter(|_| territory.is_violated(&actor.vehicle.dimens))
+                })
+            }),
+        )));
+    }
+
+    if props.has_area_restrictions {
+        constraint.add_module(Arc::new(AreaModule::new_restricted(AREA_RESTRICTION_CONSTRAINT_CODE)));
+    }
+
+    if props.has_physical_limits {
+        constraint.add_module(Arc::new(PhysicalModule::new(PHYSICAL_CONSTRAINT_CODE)));
+    }
+
     if props.has_dispatch {
         constraint.add_module(Arc::new(DispatchModule::new(DISPATCH_CONSTRAINT_CODE)));
     }
@@ -393,6 +757,11 @@ fn add_capacity_reload_modules(
                 MultiDimLoad::new,
                 Box::new(move |capacity| *capacity * threshold),
             );
+
+            let non_reloadable = get_non_reloadable_dimensions(api_problem.fleet.dimensions.as_ref());
+            if !non_reloadable.is_empty() {
+                constraint.add_module(Arc::new(NonReloadableLoadModule::new(non_reloadable, CAPACITY_CONSTRAINT_CODE)));
+            }
         } else {
             add_capacity_with_reload::<SingleDimLoad>(
                 constraint,
@@ -443,9 +812,84 @@ fn add_capacity_with_reload<T: LoadOps + SharedResource>(
     };
 }
 
+pub(crate) fn get_energy_demand_fn(is_multi_dimen: bool) -> EnergyDemandFn {
+    fn get_demand<T: LoadOps>(demand: Option<&Demand<T>>, value_fn: fn(&T) -> i32) -> (f64, f64) {
+        demand.map_or((0., 0.), |demand| {
+            let pickup = value_fn(&demand.pickup.0) + value_fn(&demand.pickup.1);
+            let delivery = value_fn(&demand.delivery.0) + value_fn(&demand.delivery.1);
+
+            (value_fn(&demand.delivery.0) as f64, (pickup - delivery) as f64)
+        })
+    }
+
+    if is_multi_dimen {
+        Arc::new(|single| get_demand::<MultiDimLoad>(single.dimens.get_demand(), |load| load.load[0]))
+    } else {
+        Arc::new(|single| get_demand::<SingleDimLoad>(single.dimens.get_demand(), |load| load.value))
+    }
+}
+
+fn add_sync_module(
+    constraint: &mut ConstraintPipeline,
+    api_problem: &ApiProblem,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+) {
+    let sizes =
+        api_problem.plan.jobs.iter().filter_map(|job| job.sync.as_ref()).fold(HashMap::new(), |mut acc, sync| {
+            *acc.entry(sync.key.clone()).or_insert(0) += 1;
+            acc
+        });
+
+    constraint.add_module(Arc::new(SyncModule::new(transport, activity, sizes, SYNC_CONSTRAINT_CODE, SYNC_KEY)));
+}
+
+fn add_precedence_module(
+    constraint: &mut ConstraintPipeline,
+    api_problem: &ApiProblem,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+) {
+    let relations = api_problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| job.predecessors.iter().flatten().map(move |predecessor| (predecessor.clone(), job.id.clone())))
+        .collect();
+
+    constraint.add_module(Arc::new(PrecedenceModule::new(
+        transport,
+        activity,
+        relations,
+        PRECEDENCE_CONSTRAINT_CODE,
+        PRECEDENCE_KEY,
+    )));
+}
+
+fn add_dock_module(
+    constraint: &mut ConstraintPipeline,
+    api_problem: &ApiProblem,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+) {
+    let docks = api_problem
+        .fleet
+        .resources
+        .iter()
+        .flatten()
+        .filter_map(|resource| match resource {
+            VehicleResource::Dock { id, capacity } => Some((id.clone(), *capacity)),
+            VehicleResource::Reload { .. } => None,
+        })
+        .collect();
+
+    constraint.add_module(Arc::new(DockModule::new(transport, activity, docks, DOCK_CONSTRAINT_CODE, DOCK_KEY)));
+}
+
 fn add_tour_size_module(constraint: &mut ConstraintPipeline) {
     constraint.add_module(Arc::new(TourSizeModule::new(
         Arc::new(|actor| actor.vehicle.dimens.get_tour_size()),
+        Arc::new(|actor| actor.vehicle.dimens.get_min_tour_size()),
         TOUR_SIZE_CONSTRAINT_CODE,
     )));
 }
@@ -455,22 +899,29 @@ fn add_tour_limit_module(
     transport: Arc<dyn TransportCost + Send + Sync>,
     api_problem: &ApiProblem,
 ) {
-    let (distances, durations) = api_problem
+    let (distances, durations, costs) = api_problem
         .fleet
         .vehicles
         .iter()
         .filter_map(|vehicle| vehicle.limits.as_ref().map(|limits| (vehicle, limits)))
-        .fold((HashMap::new(), HashMap::new()), |(mut distances, mut durations), (vehicle, limits)| {
-            limits.max_distance.iter().for_each(|max_distance| {
-                distances.insert(vehicle.type_id.clone(), *max_distance);
-            });
-
-            limits.shift_time.iter().for_each(|shift_time| {
-                durations.insert(vehicle.type_id.clone(), *shift_time);
-            });
-
-            (distances, durations)
-        });
+        .fold(
+            (HashMap::new(), HashMap::new(), HashMap::new()),
+            |(mut distances, mut durations, mut costs), (vehicle, limits)| {
+                limits.max_distance.iter().for_each(|max_distance| {
+                    distances.insert(vehicle.type_id.clone(), *max_distance);
+                });
+
+                limits.shift_time.iter().for_each(|shift_time| {
+                    durations.insert(vehicle.type_id.clone(), *shift_time);
+                });
+
+                limits.max_cost.iter().for_each(|max_cost| {
+                    costs.insert(vehicle.type_id.clone(), *max_cost);
+                });
+
+                (distances, durations, costs)
+            },
+        );
 
     let get_limit = |limit_map: HashMap<String, f64>| {
         Arc::new(move |actor: &Actor| {
@@ -482,8 +933,37 @@ fn add_tour_limit_module(
         transport.clone(),
         get_limit(distances),
         get_limit(durations),
+        get_limit(costs),
         DISTANCE_LIMIT_CONSTRAINT_CODE,
         DURATION_LIMIT_CONSTRAINT_CODE,
+        COST_LIMIT_CONSTRAINT_CODE,
+    )));
+}
+
+fn add_consecutive_driving_module(
+    constraint: &mut ConstraintPipeline,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    api_problem: &ApiProblem,
+) {
+    let limits = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| {
+            vehicle
+                .limits
+                .as_ref()
+                .and_then(|limits| limits.consecutive_driving.as_ref())
+                .map(|driving| (vehicle.type_id.clone(), (driving.max_distance, driving.max_duration)))
+        })
+        .collect::<HashMap<_, _>>();
+
+    constraint.add_module(Arc::new(ConsecutiveDrivingModule::new(
+        transport,
+        Arc::new(move |actor: &Actor| {
+            actor.vehicle.dimens.get_vehicle_type().and_then(|v_type| limits.get(v_type)).cloned().unwrap_or_default()
+        }),
+        CONSECUTIVE_DRIVING_CONSTRAINT_CODE,
     )));
 }
 
@@ -509,6 +989,25 @@ fn create_extras(
         extras.set_cluster_config(config);
     }
 
+    if let Some(marginal_costs) = api_problem.plan.marginal_costs.as_ref() {
+        extras.set_marginal_cost_passes(match marginal_costs.accuracy {
+            Some(MarginalCostAccuracy::Precise) => PRECISE_REPAIR_PASSES,
+            Some(MarginalCostAccuracy::Fast) | None => 0,
+        });
+    }
+
+    if let Some(eta_confidence) = api_problem.plan.eta_confidence.as_ref() {
+        extras.insert("eta_confidence".to_owned(), Arc::new(eta_confidence.clone()));
+    }
+
+    if api_problem.plan.certificates.unwrap_or(false) {
+        extras.insert("certificates".to_owned(), Arc::new(true));
+    }
+
+    if let Some(dimensions) = api_problem.fleet.dimensions.as_ref() {
+        extras.insert("load_dimensions".to_owned(), Arc::new(dimensions.clone()));
+    }
+
     Ok(extras)
 }
 
@@ -519,7 +1018,8 @@ fn parse_time_window(tw: &[String]) -> TimeWindow {
 
 fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> ProblemProperties {
     let has_unreachable_locations = matrices.iter().any(|m| m.error_codes.is_some());
-    let has_multi_dimen_capacity = api_problem.fleet.vehicles.iter().any(|t| t.capacity.len() > 1)
+    let has_multi_dimen_capacity = api_problem.fleet.dimensions.is_some()
+        || api_problem.fleet.vehicles.iter().any(|t| t.capacity.len() > 1)
         || api_problem.plan.jobs.iter().any(|job| {
             job.pickups
                 .iter()
@@ -535,6 +1035,12 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .any(|shift| shift.breaks.as_ref().map_or(false, |b| !b.is_empty()));
 
     let has_skills = api_problem.plan.jobs.iter().any(|job| job.skills.is_some());
+    let has_preferred_skills = api_problem
+        .plan
+        .jobs
+        .iter()
+        .filter_map(|job| job.skills.as_ref())
+        .any(|skills| skills.preferred.as_ref().is_some_and(|preferred| !preferred.is_empty()));
     let max_job_value = api_problem
         .plan
         .jobs
@@ -574,15 +1080,99 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .any(|order| order > 0);
 
     let has_group = api_problem.plan.jobs.iter().any(|job| job.group.is_some());
+    let has_bundle = api_problem.plan.jobs.iter().any(|job| job.bundle.is_some());
+    let has_unassigned_penalty = api_problem.plan.jobs.iter().any(|job| job.unassigned_penalty.is_some());
     let has_compatibility = api_problem.plan.jobs.iter().any(|job| job.compatibility.is_some());
-    let has_tour_size_limits =
-        api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.tour_size.is_some()));
+    let has_tour_size_limits = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .any(|v| v.limits.as_ref().is_some_and(|l| l.tour_size.is_some() || l.min_tour_size.is_some()));
+
+    let has_vehicle_tiers = api_problem.fleet.vehicles.iter().any(|vehicle| vehicle.tier.unwrap_or(0) > 0);
+    let has_visit_gaps = api_problem.plan.jobs.iter().any(|job| job.visit_gap.is_some());
+    let has_ride_durations = api_problem.plan.jobs.iter().any(|job| job.max_ride_duration.is_some());
+    let has_sync = api_problem.plan.jobs.iter().any(|job| job.sync.is_some());
+    let has_precedences =
+        api_problem.plan.jobs.iter().any(|job| job.predecessors.as_ref().is_some_and(|ids| !ids.is_empty()));
+    let has_docks = api_problem.fleet.vehicles.iter().flat_map(|vehicle| vehicle.shifts.iter()).any(|shift| {
+        shift.reloads.iter().flatten().any(|reload| reload.dock_id.is_some())
+            || shift.unloading.as_ref().is_some_and(|unloading| unloading.dock_id.is_some())
+    });
+    let has_loading_order = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .any(|vehicle| vehicle.loading.as_ref().is_some_and(|loading| loading.order.is_some()));
+    let has_backhaul = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .any(|vehicle| vehicle.loading.as_ref().is_some_and(|loading| loading.backhaul.is_some()));
+    let has_zone_sequences = api_problem.plan.zone_sequences.as_ref().is_some_and(|sequences| !sequences.is_empty());
+    let has_placements = api_problem.plan.jobs.iter().any(|job| job.placement.is_some());
+    let has_energy = api_problem.fleet.vehicles.iter().any(|vehicle| vehicle.energy.is_some());
+    let has_trailers = api_problem.fleet.vehicles.iter().any(|vehicle| vehicle.trailer.is_some());
+    let has_territories = api_problem.plan.territories.as_ref().is_some_and(|territories| !territories.is_empty());
+    let has_strict_territories =
+        api_problem.plan.territories.iter().flatten().any(|territory| territory.is_strict.unwrap_or(false));
+    let has_pinned_territories =
+        api_problem.plan.territories.iter().flatten().any(|territory| territory.cross_zone_penalty.is_some());
+    let allowed_areas = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| vehicle.limits.as_ref().and_then(|limits| limits.allowed_areas.as_ref()))
+        .collect::<Vec<_>>();
+    let has_area_restrictions = !allowed_areas.is_empty();
+    let has_area_penalty = allowed_areas.iter().any(|allowed_areas| allowed_areas.penalty.is_some());
+    let has_physical_limits = api_problem.fleet.vehicles.iter().any(|vehicle| vehicle.physical.is_some())
+        && api_problem.plan.jobs.iter().any(|job| job.item.is_some());
+    let has_overtime = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .any(|shift| shift.end.is_some() && shift.overtime.is_some());
+    let has_unloading = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .any(|shift| shift.end.is_some() && shift.unloading.is_some());
+    let has_relays = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .any(|vehicle| vehicle.limits.as_ref().is_some_and(|limits| limits.driver_shift_time.is_some()));
+    let has_departure_optimization =
+        api_problem.fleet.vehicles.iter().any(|vehicle| vehicle.optimize_departure.unwrap_or(false));
+
+    let escalation_weight = api_problem
+        .plan
+        .escalation
+        .as_ref()
+        .filter(|_| api_problem.plan.jobs.iter().any(|job| job.attempts.is_some_and(|attempts| attempts > 0)))
+        .map(|escalation| escalation.weight.unwrap_or(1.));
 
     let has_tour_travel_limits = api_problem
         .fleet
         .vehicles
         .iter()
-        .any(|v| v.limits.as_ref().map_or(false, |l| l.shift_time.or(l.max_distance).is_some()));
+        .any(|v| v.limits.as_ref().is_some_and(|l| l.shift_time.or(l.max_distance).or(l.max_cost).is_some()));
+
+    let has_consecutive_driving_limits = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| vehicle.limits.as_ref().and_then(|limits| limits.consecutive_driving.as_ref()))
+        .any(|driving| driving.max_distance.or(driving.max_duration).is_some());
+
+    let has_driving_time = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .any(|vehicle| vehicle.limits.as_ref().is_some_and(|limits| limits.driving_time.is_some()));
 
     ProblemProperties {
         has_multi_dimen_capacity,
@@ -593,11 +1183,39 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         has_reloads,
         has_order,
         has_group,
+        has_bundle,
+        has_unassigned_penalty,
         has_compatibility,
         has_tour_size_limits,
         has_tour_travel_limits,
+        has_consecutive_driving_limits,
+        has_driving_time,
+        has_vehicle_tiers,
+        has_visit_gaps,
+        has_ride_durations,
+        has_sync,
+        has_precedences,
+        has_docks,
+        has_loading_order,
+        has_backhaul,
+        has_zone_sequences,
+        has_placements,
+        has_preferred_skills,
+        has_energy,
+        has_trailers,
+        has_territories,
+        has_strict_territories,
+        has_pinned_territories,
+        has_area_restrictions,
+        has_area_penalty,
+        has_physical_limits,
+        has_departure_optimization,
+        has_overtime,
+        has_unloading,
+        has_relays,
         max_job_value,
         max_area_value,
+        escalation_weight,
     }
 }
 
@@ -616,8 +1234,9 @@ where
         .as_ref()
         .iter()
         .flat_map(|resources| resources.iter())
-        .map(|resource| match resource {
-            VehicleResource::Reload { id, capacity } => (id.clone(), capacity.clone()),
+        .filter_map(|resource| match resource {
+            VehicleResource::Reload { id, capacity } => Some((id.clone(), capacity.clone())),
+            VehicleResource::Dock { .. } => None,
         })
         .collect::<Vec<_>>();
     let total_resources_specified = available_resources.len();
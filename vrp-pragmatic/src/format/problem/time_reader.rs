@@ -0,0 +1,335 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/time_reader_test.rs"]
+mod time_reader_test;
+
+use super::ApiProblem;
+use crate::format::problem::*;
+use crate::format_time;
+use hashbrown::HashSet;
+use time::format_description::well_known::Rfc3339;
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+use vrp_core::models::common::{TimeWindow, WeeklyTimeWindow};
+
+/// A time of day with optional explicit utc offset, e.g. `22:00` or `22:00:00+02:00`.
+type TimeOfDay = (Time, Option<UtcOffset>);
+
+/// Normalizes job time windows and service blackouts specified as time of day to absolute time in RFC3339 format.
+/// Time of day is resolved against the planning epoch which is the earliest vehicle shift start:
+/// its date is used and its utc offset is applied when time has no explicit offset.
+/// A time window with end earlier than start is considered as crossing midnight and a time window which
+/// ends before the planning epoch is moved to the next day, e.g. to be served within a night shift.
+/// Weekly recurring time windows are expanded over planning horizon and added to time windows of the place.
+/// Only fixed utc offsets are supported: time zone names are not and daylight saving time changes are not
+/// applied. So, when vehicle shifts start with different utc offsets, time of day without explicit offset
+/// is ambiguous and is not resolved.
+/// Values which cannot be parsed are kept as is, so they are reported by validation.
+pub(crate) fn normalize_job_times(mut api_problem: ApiProblem) -> ApiProblem {
+    let epoch = if let Some(epoch) = get_planning_epoch(&api_problem) { epoch } else { return api_problem };
+    let horizon = get_planning_horizon(&api_problem, &epoch);
+    let is_ambiguous = has_ambiguous_epoch_offset(&api_problem);
+
+    api_problem
+        .plan
+        .jobs
+        .iter_mut()
+        .flat_map(|job| {
+            job.pickups
+                .iter_mut()
+                .chain(job.deliveries.iter_mut())
+                .chain(job.replacements.iter_mut())
+                .chain(job.services.iter_mut())
+                .flat_map(|tasks: &mut Vec<JobTask>| tasks.iter_mut())
+        })
+        .flat_map(|task| task.places.iter_mut())
+        .for_each(|place| {
+            place.times.iter_mut().flatten().for_each(|tw| normalize_time_window(tw, &epoch, is_ambiguous));
+            expand_recurring_times(place, &epoch, &horizon, is_ambiguous);
+        });
+
+    api_problem
+        .plan
+        .blackouts
+        .iter_mut()
+        .flatten()
+        .for_each(|blackout| normalize_time_window(blackout.time.as_mut_slice(), &epoch, is_ambiguous));
+
+    api_problem
+}
+
+/// Normalizes vehicle shift times specified as time of day to absolute time in RFC3339 format.
+/// Time of day is resolved against the shift start: its date and utc offset are used and time earlier
+/// than the shift start is moved to the next day, so a shift together with its breaks, reloads, etc. can
+/// cross midnight. The same applies to the end of time window which is resolved against its start.
+/// The shift start itself has to be specified in RFC3339 format.
+pub(crate) fn normalize_shift_times(mut api_problem: ApiProblem) -> ApiProblem {
+    api_problem.fleet.vehicles.iter_mut().flat_map(|vehicle| vehicle.shifts.iter_mut()).for_each(|shift| {
+        let shift_start = if let Ok(start) = OffsetDateTime::parse(shift.start.earliest.as_str(), &Rfc3339) {
+            start
+        } else {
+            return;
+        };
+
+        let normalize_time = |time: &mut String| {
+            normalize_time_after(time, &shift_start);
+        };
+        let normalize_times = |times: &mut Vec<Vec<String>>| {
+            times.iter_mut().for_each(|tw| {
+                if let [start, end] = tw.as_mut_slice() {
+                    normalize_time_window_after(start, end, &shift_start);
+                }
+            })
+        };
+
+        shift.start.latest.iter_mut().for_each(normalize_time);
+        if let Some(end) = shift.end.as_mut() {
+            end.earliest.iter_mut().for_each(normalize_time);
+            normalize_time(&mut end.latest);
+        }
+
+        shift
+            .dispatch
+            .iter_mut()
+            .flatten()
+            .flat_map(|dispatch| dispatch.limits.iter_mut())
+            .for_each(|limit| normalize_time_window_after(&mut limit.start, &mut limit.end, &shift_start));
+
+        shift.breaks.iter_mut().flatten().for_each(|vehicle_break| match vehicle_break {
+            VehicleBreak::Optional { time: VehicleOptionalBreakTime::TimeWindow(tw), .. } => {
+                if let [start, end] = tw.as_mut_slice() {
+                    normalize_time_window_after(start, end, &shift_start);
+                }
+            }
+            VehicleBreak::Required { time: VehicleRequiredBreakTime::ExactTime(time), .. } => normalize_time(time),
+            _ => {}
+        });
+
+        shift.reloads.iter_mut().flatten().filter_map(|reload| reload.times.as_mut()).for_each(normalize_times);
+        shift.recharges.iter_mut().flatten().filter_map(|recharge| recharge.times.as_mut()).for_each(normalize_times);
+        shift
+            .trailer_parkings
+            .iter_mut()
+            .flatten()
+            .filter_map(|parking| parking.times.as_mut())
+            .for_each(normalize_times);
+        shift.maintenance.iter_mut().for_each(normalize_times);
+    });
+
+    api_problem
+}
+
+fn normalize_time_window(tw: &mut [String], epoch: &OffsetDateTime, is_ambiguous: bool) {
+    if let [start, end] = tw {
+        let parse = |value: &str| parse_time_of_day(value).filter(|(_, offset)| offset.is_some() || !is_ambiguous);
+        let start_time = parse(start.as_str()).map(|tod| resolve_time_of_day(tod, epoch));
+        let end_time =
+            parse(end.as_str()).map(|tod| resolve_time_of_day(tod, epoch)).map(|end_time| match start_time {
+                Some(start_time) if end_time <= start_time => end_time + Duration::DAY,
+                _ => end_time,
+            });
+
+        let (start_time, end_time) = match (start_time, end_time) {
+            (Some(start_time), Some(end_time)) if end_time < *epoch => {
+                (Some(start_time + Duration::DAY), Some(end_time + Duration::DAY))
+            }
+            times => times,
+        };
+
+        if let Some(start_time) = start_time {
+            *start = format_time(start_time.unix_timestamp() as f64);
+        }
+
+        if let Some(end_time) = end_time {
+            *end = format_time(end_time.unix_timestamp() as f64);
+        }
+    }
+}
+
+fn expand_recurring_times(place: &mut JobPlace, epoch: &OffsetDateTime, horizon: &TimeWindow, is_ambiguous: bool) {
+    let recurring_times = if let Some(recurring_times) = place.recurring_times.as_ref() {
+        recurring_times
+    } else {
+        return;
+    };
+
+    let time_windows = recurring_times
+        .iter()
+        .map(|recurring| parse_recurring_time_window(recurring, epoch, is_ambiguous))
+        .collect::<Option<Vec<_>>>()
+        .map(|weekly_tws| {
+            weekly_tws
+                .into_iter()
+                .flat_map(|(weekly_tw, week_start)| weekly_tw.expand(week_start, horizon))
+                .map(|tw| vec![format_time(tw.start), format_time(tw.end)])
+                .collect::<Vec<_>>()
+        });
+
+    // NOTE place without any time window is reported by validation
+    if let Some(time_windows) = time_windows.filter(|tws| !tws.is_empty()) {
+        place.times.get_or_insert_with(Vec::new).extend(time_windows);
+    }
+}
+
+/// Checks whether recurring time window has known days of week and valid times of day.
+pub(crate) fn is_valid_recurring_time_window(recurring: &RecurringTimeWindow) -> bool {
+    !recurring.days.is_empty()
+        && recurring.days.iter().all(|day| parse_day_of_week(day).is_some())
+        && parse_time_of_day(recurring.start.as_str()).is_some()
+        && parse_time_of_day(recurring.end.as_str()).is_some()
+}
+
+/// Parses recurring time window and returns it together with the start of the epoch's week.
+/// Times of day are resolved using utc offset of the start time or the epoch's one when it is not ambiguous.
+fn parse_recurring_time_window(
+    recurring: &RecurringTimeWindow,
+    epoch: &OffsetDateTime,
+    is_ambiguous: bool,
+) -> Option<(WeeklyTimeWindow, f64)> {
+    const DAY: f64 = 86400.;
+
+    let days = recurring.days.iter().map(|day| parse_day_of_week(day)).collect::<Option<Vec<_>>>()?;
+    let (start_time, start_offset) = parse_time_of_day(recurring.start.as_str())?;
+    let (end_time, end_offset) = parse_time_of_day(recurring.end.as_str())?;
+
+    let offset = match start_offset {
+        Some(offset) => offset,
+        None if is_ambiguous => return None,
+        None => epoch.offset(),
+    };
+    let end_offset = end_offset.unwrap_or(offset);
+
+    let get_seconds = |time: Time| time.hour() as f64 * 3600. + time.minute() as f64 * 60. + time.second() as f64;
+    let start = get_seconds(start_time);
+    let end = get_seconds(end_time) - (end_offset.whole_seconds() - offset.whole_seconds()) as f64;
+    let end = if end <= start { end + DAY } else { end };
+
+    let date = epoch.to_offset(offset).date();
+    let week_start_date = date - Duration::days(date.weekday().number_days_from_monday() as i64);
+    let week_start = PrimitiveDateTime::new(week_start_date, Time::MIDNIGHT).assume_offset(offset);
+
+    Some((WeeklyTimeWindow { days, start, end }, week_start.unix_timestamp() as f64))
+}
+
+fn parse_day_of_week(day: &str) -> Option<usize> {
+    match day {
+        "mon" => Some(0),
+        "tue" => Some(1),
+        "wed" => Some(2),
+        "thu" => Some(3),
+        "fri" => Some(4),
+        "sat" => Some(5),
+        "sun" => Some(6),
+        _ => None,
+    }
+}
+
+fn normalize_time_window_after(start: &mut String, end: &mut String, anchor: &OffsetDateTime) {
+    let start_time = normalize_time_after(start, anchor);
+    normalize_time_after(end, start_time.as_ref().unwrap_or(anchor));
+}
+
+/// Resolves time of day to the earliest absolute time which is not before the anchor and returns it.
+/// A value in RFC3339 format is kept as is.
+fn normalize_time_after(value: &mut String, anchor: &OffsetDateTime) -> Option<OffsetDateTime> {
+    if let Some(tod) = parse_time_of_day(value.as_str()) {
+        let time = resolve_time_of_day(tod, anchor);
+        let time = if time < *anchor { time + Duration::DAY } else { time };
+
+        *value = format_time(time.unix_timestamp() as f64);
+
+        Some(time)
+    } else {
+        OffsetDateTime::parse(value.as_str(), &Rfc3339).ok()
+    }
+}
+
+fn get_planning_epoch(api_problem: &ApiProblem) -> Option<OffsetDateTime> {
+    api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .filter_map(|shift| OffsetDateTime::parse(shift.start.earliest.as_str(), &Rfc3339).ok())
+        .min()
+}
+
+/// Returns true when vehicle shifts start with different utc offsets, e.g. planning horizon includes daylight
+/// saving time change or vehicles operate in different time zones. In this case, utc offset of the planning
+/// epoch cannot be used for time of day without explicit offset.
+pub(crate) fn has_ambiguous_epoch_offset(api_problem: &ApiProblem) -> bool {
+    let offsets = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .filter_map(|shift| OffsetDateTime::parse(shift.start.earliest.as_str(), &Rfc3339).ok())
+        .map(|start| start.offset())
+        .collect::<HashSet<_>>();
+
+    offsets.len() > 1
+}
+
+/// Checks whether value is a time of day without explicit utc offset.
+pub(crate) fn is_time_of_day_without_offset(value: &str) -> bool {
+    parse_time_of_day(value).is_some_and(|(_, offset)| offset.is_none())
+}
+
+/// Returns planning horizon which starts at the planning epoch and ends at the latest shift end.
+/// When no shift has an end, one week horizon is used.
+fn get_planning_horizon(api_problem: &ApiProblem, epoch: &OffsetDateTime) -> TimeWindow {
+    let start = epoch.unix_timestamp() as f64;
+    let end = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .filter_map(|shift| shift.end.as_ref())
+        .filter_map(|end| OffsetDateTime::parse(end.latest.as_str(), &Rfc3339).ok())
+        .max()
+        .map_or(start + 7. * 86400., |end| end.unix_timestamp() as f64);
+
+    TimeWindow::new(start, end.max(start))
+}
+
+fn resolve_time_of_day((time, offset): TimeOfDay, epoch: &OffsetDateTime) -> OffsetDateTime {
+    let offset = offset.unwrap_or_else(|| epoch.offset());
+    let date: Date = epoch.to_offset(offset).date();
+
+    PrimitiveDateTime::new(date, time).assume_offset(offset)
+}
+
+fn parse_time_of_day(value: &str) -> Option<TimeOfDay> {
+    let (time, offset) = if let Some(time) = value.strip_suffix('Z') {
+        (time, Some(UtcOffset::UTC))
+    } else if let Some(idx) = value.rfind(['+', '-']) {
+        let (time, offset) = value.split_at(idx);
+        let sign = if offset.starts_with('-') { -1 } else { 1 };
+        let (hours, minutes, _) = parse_components(&offset[1..])?;
+
+        (time, Some(UtcOffset::from_hms(sign * hours as i8, sign * minutes as i8, 0).ok()?))
+    } else {
+        (value, None)
+    };
+
+    let (hours, minutes, seconds) = parse_components(time)?;
+
+    Some((Time::from_hms(hours, minutes, seconds).ok()?, offset))
+}
+
+fn parse_components(value: &str) -> Option<(u8, u8, u8)> {
+    let components = value
+        .split(':')
+        .map(|component| {
+            if component.len() == 2 && component.chars().all(|c| c.is_ascii_digit()) {
+                component.parse::<u8>().ok()
+            } else {
+                None
+            }
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    match components.as_slice() {
+        [hours, minutes] => Some((*hours, *minutes, 0)),
+        [hours, minutes, seconds] => Some((*hours, *minutes, *seconds)),
+        _ => None,
+    }
+}
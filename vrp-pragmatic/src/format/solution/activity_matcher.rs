@@ -10,12 +10,13 @@ use std::cmp::Ordering;
 use std::iter::once;
 use std::sync::Arc;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Job, Single};
+use vrp_core::models::problem::{Job, Place as JobPlace, Single};
 use vrp_core::models::solution::{Activity, Place};
 use vrp_core::utils::compare_floats;
 
-/// Aggregates job specific information for a job activity.
-pub(crate) struct JobInfo(pub Job, pub Arc<Single>, pub Place, pub TimeWindow);
+/// Aggregates job specific information for a job activity: job, its single, index of matched place,
+/// activity place and time.
+pub(crate) struct JobInfo(pub Job, pub Arc<Single>, pub usize, pub Place, pub TimeWindow);
 
 /// Tries to match given activity to core job models. None is returned in case of
 /// non-job activity (departure, arrival).
@@ -61,21 +62,53 @@ pub(crate) fn try_match_point_job(
                     Box::new(multi.jobs.iter())
                 }
             };
-            let (single, place) = singles
+            let (single, (place_idx, place)) = singles
                 .filter_map(|single| match_place(single, true, &ctx).map(|place| (single, place)))
                 .next()
                 .ok_or_else(|| format!("cannot match job '{}'", activity.job_id))?;
 
-            Ok(Some(JobInfo(job.clone(), single.clone(), place, ctx.time)))
+            Ok(Some(JobInfo(job.clone(), single.clone(), place_idx, place, ctx.time)))
         }
-        "break" | "dispatch" | "reload" => Ok(Some(
+        "break" | "dispatch" | "reload" | "recharge" | "relay" => Ok(Some(
             (1..)
                 .map(|idx| format!("{}_{}_{}_{}", tour.vehicle_id, activity.activity_type, tour.shift_index, idx))
                 .map(|job_id| job_index.get(&job_id))
                 .take_while(|job| job.is_some())
                 .filter_map(|job| job.and_then(|job| job.as_single().map(|s| (job.clone(), s.clone()))))
                 .filter_map(|(job, single)| {
-                    match_place(&single, false, &ctx).map(|place| JobInfo(job, single, place, ctx.time.clone()))
+                    match_place(&single, false, &ctx)
+                        .map(|(place_idx, place)| JobInfo(job, single, place_idx, place, ctx.time.clone()))
+                })
+                .next()
+                .ok_or_else(|| format!("cannot match '{}' for '{}'", ctx.act_type, tour.vehicle_id))?,
+        )),
+        "unloading" => Ok(Some(
+            job_index
+                .get(&format!("{}_unloading_{}", tour.vehicle_id, tour.shift_index))
+                .and_then(|job| job.as_single().map(|single| (job.clone(), single.clone())))
+                .and_then(|(job, single)| {
+                    match_place(&single, false, &ctx)
+                        .map(|(place_idx, place)| JobInfo(job, single, place_idx, place, ctx.time.clone()))
+                })
+                .ok_or_else(|| format!("cannot match '{}' for '{}'", ctx.act_type, tour.vehicle_id))?,
+        )),
+        "detach" | "attach" => Ok(Some(
+            (1..)
+                .map(|idx| format!("{}_trailer_{}_{}", tour.vehicle_id, tour.shift_index, idx))
+                .map(|job_id| job_index.get(&job_id))
+                .take_while(|job| job.is_some())
+                .filter_map(|job| job.and_then(|job| job.as_multi().map(|multi| (job.clone(), multi.clone()))))
+                .flat_map(|(job, multi)| {
+                    multi
+                        .jobs
+                        .iter()
+                        .filter(|single| single.dimens.get_job_type() == Some(ctx.act_type))
+                        .map(|single| (job.clone(), single.clone()))
+                        .collect::<Vec<_>>()
+                })
+                .filter_map(|(job, single)| {
+                    match_place(&single, false, &ctx)
+                        .map(|(place_idx, place)| JobInfo(job, single, place_idx, place, ctx.time.clone()))
                 })
                 .next()
                 .ok_or_else(|| format!("cannot match '{}' for '{}'", ctx.act_type, tour.vehicle_id))?,
@@ -91,7 +124,63 @@ pub(crate) fn try_match_transit_activity(
     stop: &TransitStop,
     activity: &FormatActivity,
 ) -> Result<TimeWindow, String> {
-    try_match_break_activity(problem, tour, &stop.time, activity)
+    match activity.activity_type.as_str() {
+        "rest" => try_match_rest_activity(problem, tour, &stop.time, activity),
+        "maintenance" => try_match_maintenance_activity(problem, tour, &stop.time, activity),
+        _ => try_match_break_activity(problem, tour, &stop.time, activity),
+    }
+}
+
+/// Tries to match rest activity which is taken during travel due to driving time limit.
+fn try_match_rest_activity(
+    problem: &FormatProblem,
+    tour: &FormatTour,
+    stop_schedule: &FormatSchedule,
+    activity: &FormatActivity,
+) -> Result<TimeWindow, String> {
+    let activity_time = get_activity_time(activity, stop_schedule);
+
+    problem
+        .fleet
+        .vehicles
+        .iter()
+        .find(|vehicle| vehicle.vehicle_ids.contains(&tour.vehicle_id))
+        .and_then(|vehicle| vehicle.limits.as_ref())
+        .and_then(|limits| limits.driving_time.as_ref())
+        .filter(|limit| {
+            once(limit.break_duration)
+                .chain(limit.rest_duration)
+                .any(|duration| compare_floats(activity_time.duration(), duration) == Ordering::Equal)
+        })
+        .map(|_| activity_time)
+        .ok_or_else(|| "cannot match activity to driving time rest".to_string())
+}
+
+/// Tries to match maintenance activity to one of vehicle shift's maintenance windows.
+pub(crate) fn try_match_maintenance_activity(
+    problem: &FormatProblem,
+    tour: &FormatTour,
+    stop_schedule: &FormatSchedule,
+    activity: &FormatActivity,
+) -> Result<TimeWindow, String> {
+    let activity_time = get_activity_time(activity, stop_schedule);
+
+    problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter(|vehicle| vehicle.vehicle_ids.contains(&tour.vehicle_id))
+        .filter_map(|vehicle| vehicle.shifts.get(tour.shift_index))
+        .flat_map(|shift| shift.maintenance.iter().flatten())
+        .filter_map(|tw| match tw.as_slice() {
+            [start, end] => Some(TimeWindow::new(parse_time(start), parse_time(end))),
+            _ => None,
+        })
+        .find(|time| {
+            compare_floats(activity_time.start, time.start) == Ordering::Equal
+                && compare_floats(activity_time.end, time.end) == Ordering::Equal
+        })
+        .ok_or_else(|| "cannot match activity to vehicle maintenance".to_string())
 }
 
 /// Tries to match break activity.
@@ -137,36 +226,42 @@ struct ActivityContext<'a> {
     tag: Option<&'a String>,
 }
 
-fn match_place<'a>(single: &Arc<Single>, is_job_activity: bool, activity_ctx: &'a ActivityContext) -> Option<Place> {
+fn match_place<'a>(
+    single: &Arc<Single>,
+    is_job_activity: bool,
+    activity_ctx: &'a ActivityContext,
+) -> Option<(usize, Place)> {
     let job_id = get_job_id(single);
-    let job_tag =
-        get_job_tag(single, (activity_ctx.location, (activity_ctx.time.clone(), activity_ctx.route_start_time)));
+    let place_tags = single.dimens.get_place_tags();
+    let get_place_tag = |place_idx: usize| {
+        place_tags.and_then(|tags| tags.iter().find(|(idx, _)| *idx == place_idx)).map(|(_, tag)| tag)
+    };
 
     let is_same_ids = *activity_ctx.job_id == job_id;
-    let is_same_tags = match (job_tag, activity_ctx.tag) {
-        (Some(job_tag), Some(activity_tag)) => job_tag == activity_tag,
-        (None, None) => true,
-        _ => false,
-    };
 
-    match (is_same_tags, is_same_ids, is_job_activity) {
-        (true, false, true) => None,
-        (true, true, _) | (true, false, false) => single
+    match (is_same_ids, is_job_activity) {
+        (false, true) => None,
+        _ => single
             .places
             .iter()
-            .find(|place| {
+            .enumerate()
+            .find(|(place_idx, place)| {
+                // NOTE place tag explicitly identifies an alternative place used by the activity
+                let is_same_tag = get_place_tag(*place_idx) == activity_ctx.tag;
                 let is_same_location = place.location.map_or(true, |l| l == activity_ctx.location);
-                let is_proper_time =
-                    place.times.iter().any(|time| time.intersects(activity_ctx.route_start_time, &activity_ctx.time));
+                let is_proper_time = place
+                    .times
+                    .iter()
+                    .any(|time| is_proper_time(place, time, activity_ctx.route_start_time, &activity_ctx.time));
 
-                is_same_location && is_proper_time
+                is_same_tag && is_same_location && is_proper_time
             })
-            .map(|place| {
+            .map(|(place_idx, place)| {
                 // NOTE search for the latest occurrence assuming that times are sorted
                 let time = place
                     .times
                     .iter()
-                    .rfind(|time| time.intersects(activity_ctx.route_start_time, &activity_ctx.time))
+                    .rfind(|time| is_proper_time(place, time, activity_ctx.route_start_time, &activity_ctx.time))
                     .unwrap();
 
                 let time = match time {
@@ -176,32 +271,59 @@ fn match_place<'a>(single: &Arc<Single>, is_job_activity: bool, activity_ctx: &'
                     }
                 };
 
-                Place { location: activity_ctx.location, duration: place.duration, time }
+                let place = Place {
+                    location: activity_ctx.location,
+                    duration: place.duration,
+                    time,
+                    setup: place.setup,
+                    lateness: place.lateness.clone(),
+                };
+
+                (place_idx, place)
             }),
-        _ => None,
     }
 }
 
-pub(crate) fn get_job_tag(single: &Single, place: (Location, (TimeWindow, Timestamp))) -> Option<&String> {
-    let (location, (time_window, start_time)) = place;
-    single.dimens.get_place_tags().map(|tags| (tags, &single.places)).and_then(|(tags, places)| {
-        tags.iter()
-            .find(|(place_idx, _)| {
-                let place = places.get(*place_idx).expect("invalid tag place index");
+pub(crate) fn get_job_tag(single: &Single, place: (Location, Duration, (TimeWindow, Timestamp))) -> Option<&String> {
+    single.dimens.get_place_tags().and_then(|tags| find_place_value(single, tags.as_slice(), place))
+}
 
-                let is_correct_location = place.location.map_or(true, |l| location == l);
-                let is_correct_time = place
-                    .times
-                    .iter()
-                    .map(|time| time.to_time_window(start_time))
-                    .any(|time| time.intersects(&time_window));
+pub(crate) fn get_place_buffer(
+    single: &Single,
+    place: (Location, Duration, (TimeWindow, Timestamp)),
+) -> Option<Duration> {
+    single.dimens.get_place_buffers().and_then(|buffers| find_place_value(single, buffers.as_slice(), place)).cloned()
+}
 
-                // TODO check duration too?
+fn find_place_value<'a, T>(
+    single: &Single,
+    values: &'a [(usize, T)],
+    place: (Location, Duration, (TimeWindow, Timestamp)),
+) -> Option<&'a T> {
+    let place_idx = find_place_index(single, place)?;
 
-                is_correct_location && is_correct_time
-            })
-            .map(|(_, tag)| tag)
-    })
+    values.iter().find(|(idx, _)| *idx == place_idx).map(|(_, value)| value)
+}
+
+/// Finds index of the job place used by activity. Alternative places can share location and time,
+/// so duration is used to distinguish them. As activity duration can be adjusted, e.g. by vicinity
+/// clustering, the first place with matching location and time is used as a fallback.
+fn find_place_index(single: &Single, place: (Location, Duration, (TimeWindow, Timestamp))) -> Option<usize> {
+    let (location, duration, (time_window, start_time)) = place;
+
+    let is_matched = |place: &JobPlace, is_strict: bool| {
+        let is_correct_location = place.location.is_none_or(|l| location == l);
+        let is_correct_time = place.times.iter().any(|time| is_proper_time(place, time, start_time, &time_window));
+        let is_correct_duration = !is_strict || compare_floats(place.duration, duration) == Ordering::Equal;
+
+        is_correct_location && is_correct_time && is_correct_duration
+    };
+
+    single
+        .places
+        .iter()
+        .position(|place| is_matched(place, true))
+        .or_else(|| single.places.iter().position(|place| is_matched(place, false)))
 }
 
 pub(crate) fn get_extra_time(stop: &PointStop, activity: &FormatActivity, place: &Place) -> Option<f64> {
@@ -209,7 +331,7 @@ pub(crate) fn get_extra_time(stop: &PointStop, activity: &FormatActivity, place:
     stop.activities
         .iter()
         .filter_map(|a| {
-            if a.activity_type == "break" && a != activity {
+            if matches!(a.activity_type.as_str(), "break" | "maintenance") && a != activity {
                 a.time.as_ref().and_then(|time| {
                     let break_time = TimeWindow::new(parse_time(&time.start), parse_time(&time.end));
                     let activity_time = TimeWindow::new(activity_time.start, activity_time.start + place.duration);
@@ -225,9 +347,18 @@ pub(crate) fn get_extra_time(stop: &PointStop, activity: &FormatActivity, place:
         .next()
 }
 
+/// Checks whether activity time fits given place time: soft time window allows late arrival.
+fn is_proper_time(place: &JobPlace, time: &TimeSpan, start_time: Timestamp, activity_time: &TimeWindow) -> bool {
+    if place.lateness.is_some() {
+        time.to_time_window(start_time).start <= activity_time.end
+    } else {
+        time.intersects(start_time, activity_time)
+    }
+}
+
 fn get_job_id(single: &Arc<Single>) -> String {
     Activity {
-        place: Place { location: 0, duration: 0.0, time: TimeWindow::new(0., 0.) },
+        place: Place { location: 0, duration: 0.0, time: TimeWindow::new(0., 0.), setup: 0., lateness: None },
         schedule: Schedule { arrival: 0.0, departure: 0.0 },
         job: Some(single.clone()),
         commute: None,
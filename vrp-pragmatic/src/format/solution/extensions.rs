@@ -1,4 +1,4 @@
-use crate::format::solution::{Statistic, Timing};
+use crate::format::solution::{Emissions, Statistic, Timing};
 use std::ops::Add;
 
 impl Add for Statistic {
@@ -17,6 +17,12 @@ impl Add for Statistic {
                 commuting: self.times.commuting + rhs.times.commuting,
                 parking: self.times.parking + rhs.times.parking,
             },
+            // NOTE utilization is reported only per tour
+            utilization: None,
+            emissions: match (self.emissions, rhs.emissions) {
+                (Some(lhs), Some(rhs)) => Some(Emissions { fuel: lhs.fuel + rhs.fuel, co2: lhs.co2 + rhs.co2 }),
+                (lhs, rhs) => lhs.or(rhs),
+            },
         }
     }
 }
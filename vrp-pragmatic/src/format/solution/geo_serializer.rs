@@ -10,7 +10,7 @@ use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::io::{BufWriter, Error, ErrorKind, Write};
 use vrp_core::models::problem::Job;
-use vrp_core::prelude::*;
+use vrp_core::prelude::{compare_floats, Problem};
 
 #[derive(Clone, Debug, Serialize)]
 #[serde(tag = "type")]
@@ -121,7 +121,9 @@ fn get_marker_symbol(stop: &PointStop) -> String {
     let contains_activity_type =
         |activity_type: &&str| stop.activities.iter().any(|activity| activity.activity_type == *activity_type);
     match (
-        ["departure", "dispatch", "reload", "arrival"].iter().any(contains_activity_type),
+        ["departure", "dispatch", "reload", "recharge", "relay", "detach", "attach", "unloading", "arrival"]
+            .iter()
+            .any(contains_activity_type),
         contains_activity_type(&"break"),
     ) {
         (true, _) => "warehouse",
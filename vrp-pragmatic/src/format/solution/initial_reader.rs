@@ -15,10 +15,10 @@ use std::io::{BufReader, Read};
 use std::sync::Arc;
 use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Actor, Job, Single};
+use vrp_core::models::problem::{get_service_duration, Actor, Job, Single};
 use vrp_core::models::solution::Tour as CoreTour;
 use vrp_core::models::solution::{Activity, Place, Registry, Route};
-use vrp_core::prelude::*;
+use vrp_core::prelude::{Problem, Random, Solution, TimeWindow};
 
 type ActorKey = (String, String, usize);
 
@@ -34,15 +34,30 @@ pub fn read_init_solution<R: Read>(
     let mut registry = Registry::new(&problem.fleet, random);
     let mut added_jobs = HashSet::default();
 
-    let actor_index = registry.all().map(|actor| (get_actor_key(actor.as_ref()), actor)).collect::<HashMap<_, _>>();
+    let actor_index = registry.all().fold(HashMap::<_, Vec<_>>::default(), |mut acc, actor| {
+        acc.entry(get_actor_key(actor.as_ref())).or_default().push(actor);
+        acc
+    });
     let coord_index = get_coord_index(problem.as_ref());
     let job_index = get_job_index(problem.as_ref());
 
     let routes =
         solution.tours.iter().try_fold::<_, _, Result<_, String>>(Vec::<_>::default(), |mut routes, tour| {
             let actor_key = (tour.vehicle_id.clone(), tour.type_id.clone(), tour.shift_index);
-            let actor =
-                actor_index.get(&actor_key).ok_or_else(|| format!("cannot find vehicle for {:?}", actor_key))?.clone();
+            let actors =
+                actor_index.get(&actor_key).ok_or_else(|| format!("cannot find vehicle for {:?}", actor_key))?;
+            // NOTE vehicle can have alternative depots, so select the one used by the tour
+            let start = tour
+                .stops
+                .first()
+                .and_then(|stop| stop.as_point())
+                .and_then(|stop| coord_index.get_by_loc(&stop.location));
+            let actor = actors
+                .iter()
+                .find(|actor| actor.detail.start.as_ref().map(|start| start.location) == start)
+                .or_else(|| actors.first())
+                .cloned()
+                .ok_or_else(|| format!("cannot find vehicle for {:?}", actor_key))?;
             registry.use_actor(&actor);
 
             let mut core_route = create_core_route(actor, tour)?;
@@ -58,26 +73,28 @@ pub fn read_init_solution<R: Read>(
             Ok(routes)
         })?;
 
-    let mut unassigned = solution.unassigned.unwrap_or_default().iter().try_fold::<Vec<_>, _, Result<_, String>>(
-        Default::default(),
-        |mut acc, unassigned_job| {
-            let job = job_index
-                .get(&unassigned_job.job_id)
-                .cloned()
-                .ok_or_else(|| format!("cannot get job id for: {:?}", unassigned_job))?;
-            // NOTE we take the first reason only and map it to simple variant
-            let code = unassigned_job
-                .reasons
-                .first()
-                .map(|reason| UnassignmentInfo::Simple(map_reason_code(&reason.code)))
-                .ok_or_else(|| format!("cannot get reason for: {:?}", unassigned_job))?;
-
-            added_jobs.insert(job.clone());
-            acc.push((job, code));
-
-            Ok(acc)
-        },
-    )?;
+    let reservations = solution.extras.as_ref().and_then(|extras| extras.reservations.as_ref());
+    let mut unassigned =
+        solution.unassigned.iter().chain(reservations).flatten().try_fold::<Vec<_>, _, Result<_, String>>(
+            Default::default(),
+            |mut acc, unassigned_job| {
+                let job = job_index
+                    .get(&unassigned_job.job_id)
+                    .cloned()
+                    .ok_or_else(|| format!("cannot get job id for: {:?}", unassigned_job))?;
+                // NOTE we take the first reason only and map it to simple variant
+                let code = unassigned_job
+                    .reasons
+                    .first()
+                    .map(|reason| UnassignmentInfo::Simple(map_reason_code(&reason.code)))
+                    .ok_or_else(|| format!("cannot get reason for: {:?}", unassigned_job))?;
+
+                added_jobs.insert(job.clone());
+                acc.push((job, code));
+
+                Ok(acc)
+            },
+        )?;
 
     unassigned.extend(
         problem.jobs.all().filter(|job| added_jobs.get(job).is_none()).map(|job| (job, UnassignmentInfo::Unknown)),
@@ -104,9 +121,12 @@ fn try_insert_activity(
         FormatStop::Point(stop) => stop,
     };
 
-    if let Some(JobInfo(job, single, place, time)) = try_match_point_job(tour, stop, activity, job_index, coord_index)?
+    if let Some(JobInfo(job, single, place_idx, place, time)) =
+        try_match_point_job(tour, stop, activity, job_index, coord_index)?
     {
         added_jobs.insert(job);
+        let duration = get_service_duration(route.actor.as_ref(), single.as_ref(), place_idx, place.duration);
+        let place = Place { duration, ..place };
         insert_new_activity(route, single, place, time);
     } else if activity.activity_type != "departure" && activity.activity_type != "arrival" {
         return Err(format!("cannot match activity with job id '{}' in tour: '{}'", activity.job_id, tour.vehicle_id));
@@ -15,6 +15,7 @@ mod extensions;
 
 mod writer;
 pub use self::writer::create_solution;
+pub use self::writer::has_no_violations;
 pub use self::writer::PragmaticSolution;
 
 use super::*;
@@ -46,11 +47,52 @@ fn map_code_reason(code: i32) -> (&'static str, &'static str) {
         RELOAD_RESOURCE_CONSTRAINT_CODE => {
             ("RELOAD_RESOURCE_CONSTRAINT", "cannot be assigned due to reload resource constraint")
         }
+        TOTAL_COST_LIMIT_CONSTRAINT_CODE => {
+            ("TOTAL_COST_LIMIT_CONSTRAINT", "cannot be assigned due to total cost limit")
+        }
+        CUSTOM_ROUTE_CONSTRAINT_CODE => {
+            ("CUSTOM_ROUTE_CONSTRAINT", "cannot be assigned due to custom route constraint")
+        }
+        CONSECUTIVE_DRIVING_CONSTRAINT_CODE => {
+            ("CONSECUTIVE_DRIVING_CONSTRAINT", "cannot be assigned due to consecutive driving limit of vehicle")
+        }
+        VISIT_GAP_CONSTRAINT_CODE => ("VISIT_GAP_CONSTRAINT", "cannot be assigned due to visit gap of job"),
+        PLACEMENT_CONSTRAINT_CODE => ("PLACEMENT_CONSTRAINT", "cannot be assigned due to item placement order"),
+        ENERGY_CONSTRAINT_CODE => ("ENERGY_CONSTRAINT", "cannot be assigned due to vehicle energy limit"),
+        TRAILER_CONSTRAINT_CODE => ("TRAILER_CONSTRAINT", "cannot be assigned due to vehicle trailer constraint"),
+        RIDE_DURATION_CONSTRAINT_CODE => {
+            ("RIDE_DURATION_CONSTRAINT", "cannot be assigned due to max ride duration of job")
+        }
+        LOADING_ORDER_CONSTRAINT_CODE => {
+            ("LOADING_ORDER_CONSTRAINT", "cannot be assigned due to vehicle loading order")
+        }
+        BACKHAUL_CONSTRAINT_CODE => ("BACKHAUL_CONSTRAINT", "cannot be assigned due to vehicle backhaul requirement"),
+        SYNC_CONSTRAINT_CODE => ("SYNC_CONSTRAINT", "cannot be served simultaneously with synchronized jobs"),
+        PRECEDENCE_CONSTRAINT_CODE => ("PRECEDENCE_CONSTRAINT", "cannot be served after its predecessor jobs"),
+        DRIVING_TIME_CONSTRAINT_CODE => {
+            ("DRIVING_TIME_CONSTRAINT", "cannot be assigned due to vehicle driving time rules")
+        }
+        TERRITORY_CONSTRAINT_CODE => ("TERRITORY_CONSTRAINT", "cannot be served by vehicles of its strict territory"),
+        DOCK_CONSTRAINT_CODE => ("DOCK_CONSTRAINT", "cannot be assigned due to depot dock availability"),
+        AREA_RESTRICTION_CONSTRAINT_CODE => {
+            ("AREA_RESTRICTION_CONSTRAINT", "cannot be served within allowed areas of vehicles")
+        }
+        COST_LIMIT_CONSTRAINT_CODE => {
+            ("MAX_COST_CONSTRAINT", "cannot be assigned due to max cost constraint of vehicle")
+        }
+        PHYSICAL_CONSTRAINT_CODE => {
+            ("PHYSICAL_CONSTRAINT", "cannot be served due to item weight or dimensions limits of vehicles")
+        }
+        UNLOADING_CONSTRAINT_CODE => ("UNLOADING_CONSTRAINT", "cannot be assigned after unloading at depot"),
+        BUNDLE_CONSTRAINT_CODE => {
+            ("BUNDLE_CONSTRAINT", "cannot be assigned as other jobs of its bundle are unassigned")
+        }
+        RELAY_CONSTRAINT_CODE => ("DRIVER_SHIFT_TIME_CONSTRAINT", "cannot be assigned due to driver shift time limit"),
         _ => ("NO_REASON_FOUND", "unknown"),
     }
 }
 
-fn map_reason_code(reason: &str) -> i32 {
+pub(crate) fn map_reason_code(reason: &str) -> i32 {
     match reason {
         "SKILL_CONSTRAINT" => SKILL_CONSTRAINT_CODE,
         "TIME_WINDOW_CONSTRAINT" => TIME_CONSTRAINT_CODE,
@@ -67,6 +109,27 @@ fn map_reason_code(reason: &str) -> i32 {
         "GROUP_CONSTRAINT" => GROUP_CONSTRAINT_CODE,
         "COMPATIBILITY_CONSTRAINT" => COMPATIBILITY_CONSTRAINT_CODE,
         "RELOAD_RESOURCE_CONSTRAINT" => RELOAD_RESOURCE_CONSTRAINT_CODE,
+        "TOTAL_COST_LIMIT_CONSTRAINT" => TOTAL_COST_LIMIT_CONSTRAINT_CODE,
+        "CUSTOM_ROUTE_CONSTRAINT" => CUSTOM_ROUTE_CONSTRAINT_CODE,
+        "CONSECUTIVE_DRIVING_CONSTRAINT" => CONSECUTIVE_DRIVING_CONSTRAINT_CODE,
+        "VISIT_GAP_CONSTRAINT" => VISIT_GAP_CONSTRAINT_CODE,
+        "PLACEMENT_CONSTRAINT" => PLACEMENT_CONSTRAINT_CODE,
+        "ENERGY_CONSTRAINT" => ENERGY_CONSTRAINT_CODE,
+        "TRAILER_CONSTRAINT" => TRAILER_CONSTRAINT_CODE,
+        "RIDE_DURATION_CONSTRAINT" => RIDE_DURATION_CONSTRAINT_CODE,
+        "LOADING_ORDER_CONSTRAINT" => LOADING_ORDER_CONSTRAINT_CODE,
+        "BACKHAUL_CONSTRAINT" => BACKHAUL_CONSTRAINT_CODE,
+        "SYNC_CONSTRAINT" => SYNC_CONSTRAINT_CODE,
+        "PRECEDENCE_CONSTRAINT" => PRECEDENCE_CONSTRAINT_CODE,
+        "DRIVING_TIME_CONSTRAINT" => DRIVING_TIME_CONSTRAINT_CODE,
+        "TERRITORY_CONSTRAINT" => TERRITORY_CONSTRAINT_CODE,
+        "DOCK_CONSTRAINT" => DOCK_CONSTRAINT_CODE,
+        "AREA_RESTRICTION_CONSTRAINT" => AREA_RESTRICTION_CONSTRAINT_CODE,
+        "MAX_COST_CONSTRAINT" => COST_LIMIT_CONSTRAINT_CODE,
+        "PHYSICAL_CONSTRAINT" => PHYSICAL_CONSTRAINT_CODE,
+        "UNLOADING_CONSTRAINT" => UNLOADING_CONSTRAINT_CODE,
+        "BUNDLE_CONSTRAINT" => BUNDLE_CONSTRAINT_CODE,
+        "DRIVER_SHIFT_TIME_CONSTRAINT" => RELAY_CONSTRAINT_CODE,
         _ => -1,
     }
 }
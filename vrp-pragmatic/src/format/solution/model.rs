@@ -37,6 +37,35 @@ pub struct Statistic {
     pub duration: i64,
     /// Timing statistic.
     pub times: Timing,
+    /// Load utilization per named dimension, reported only for tours.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub utilization: Option<Vec<LoadUtilization>>,
+    /// Fuel burn and CO2 emissions, reported only when vehicles have emission parameters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emissions: Option<Emissions>,
+}
+
+/// Represents fuel burn and CO2 emissions.
+#[derive(Clone, Default, Deserialize, Serialize, PartialEq, Debug)]
+pub struct Emissions {
+    /// Total fuel burn.
+    pub fuel: f64,
+    /// Total CO2 emissions.
+    pub co2: f64,
+}
+
+/// Represents load utilization of named dimension.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadUtilization {
+    /// A load dimension name.
+    pub dimension: String,
+    /// Max vehicle load in this dimension.
+    pub max_load: i32,
+    /// Vehicle capacity in this dimension.
+    pub capacity: i32,
+    /// A ratio of max load to capacity.
+    pub ratio: f64,
 }
 
 /// Represents a schedule.
@@ -296,12 +325,176 @@ pub struct Population {
     pub individuals: Vec<Individual>,
 }
 
-/// Contains extra information.
+/// A recommended loading sequence of vehicle's tour: jobs loaded last are delivered first.
+#[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadingManifest {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// Job ids to be loaded at the depot in given order: one list per trip, a new trip starts at reload.
+    pub trips: Vec<Vec<String>>,
+}
+
+/// A territory job served by a vehicle which is not preferred by the territory.
+#[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TerritoryViolation {
+    /// Job id.
+    pub job_id: String,
+    /// Id of vehicle which serves the job.
+    pub vehicle_id: String,
+}
+
+/// A coverage report of service territory.
+#[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TerritoryReport {
+    /// Territory id.
+    pub territory_id: String,
+    /// Total amount of territory jobs.
+    pub jobs: usize,
+    /// Amount of served territory jobs.
+    pub served: usize,
+    /// Territory jobs served by not preferred vehicles.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub violations: Vec<TerritoryViolation>,
+}
+
+/// Tour order violations of a tour.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourOrderViolation {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// Amount of violations: consecutive jobs where job with larger order is served first.
+    pub count: usize,
+    /// Total magnitude of violations: a sum of order differences of violating jobs.
+    pub magnitude: f64,
+}
+
+/// A report of tour order violations: it is present only when some tours violate job order.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourOrderReport {
+    /// Total amount of violations.
+    pub count: usize,
+    /// Total magnitude of violations.
+    pub magnitude: f64,
+    /// Tours with violations.
+    pub tours: Vec<TourOrderViolation>,
+}
+
+/// An estimated marginal cost of assigned job.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginalCost {
+    /// Job id.
+    pub job_id: String,
+    /// Id of vehicle which serves the job.
+    pub vehicle_id: String,
+    /// A cost delta if the job were removed from the tour and the tour were repaired.
+    pub cost: f64,
+}
+
+/// A stability classification of activity ETA.
+#[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
+pub enum EtaStability {
+    /// ETA is locked by constraints: it cannot be delayed or the job is fixed in the tour.
+    #[serde(rename = "locked")]
+    Locked,
+    /// ETA is likely to shift, e.g. when the plan is changed.
+    #[serde(rename = "shiftable")]
+    Shiftable,
+}
+
+/// An ETA of job activity with its stability classification.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityEta {
+    /// Job id.
+    pub job_id: String,
+    /// Activity type.
+    #[serde(rename(serialize = "type", deserialize = "type"))]
+    pub activity_type: String,
+    /// Id of vehicle which serves the job.
+    pub vehicle_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// Estimated arrival time in RFC3339 format.
+    pub arrival: String,
+    /// A max delay of arrival which keeps the rest of the tour feasible.
+    pub slack: f64,
+    /// ETA stability.
+    pub stability: EtaStability,
+}
+
+/// A state transition of the route: an activity with its times and vehicle load after it.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StateTransition {
+    /// Job id or activity type for activities which are not jobs, e.g. departure or break.
+    pub job_id: String,
+    /// Activity type.
+    #[serde(rename(serialize = "type", deserialize = "type"))]
+    pub activity_type: String,
+    /// Job tag.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_tag: Option<String>,
+    /// Activity location.
+    pub location: Location,
+    /// Arrival time in RFC3339 format.
+    pub arrival: String,
+    /// Departure time in RFC3339 format.
+    pub departure: String,
+    /// Vehicle load after activity.
+    pub load: Vec<i32>,
+}
+
+/// A feasibility certificate of the route: a sequence of state transitions which can be checked
+/// against the problem and routing matrices without running the solver.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RouteCertificate {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// State transitions in the order of route activities.
+    pub transitions: Vec<StateTransition>,
+}
+
+/// Contains extra information.
+#[derive(Clone, Default, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct Extras {
     /// A telemetry metrics.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metrics: Option<Metrics>,
+    /// Loading manifests of tours which vehicles have it requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loading: Option<Vec<LoadingManifest>>,
+    /// Coverage reports of service territories.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub territories: Option<Vec<TerritoryReport>>,
+    /// A report of tour order violations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tour_order: Option<TourOrderReport>,
+    /// Estimated marginal costs of assigned jobs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marginal_costs: Option<Vec<MarginalCost>>,
+    /// ETAs of job activities with their stability classification.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub etas: Option<Vec<ActivityEta>>,
+    /// Feasibility certificates of routes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificates: Option<Vec<RouteCertificate>>,
+    /// Unassigned reservation jobs which are not reported as regular unassigned jobs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reservations: Option<Vec<UnassignedJob>>,
 }
 
 /// A VRP solution.
@@ -2,24 +2,34 @@
 #[path = "../../../tests/unit/format/solution/writer_test.rs"]
 mod writer_test;
 
+use crate::constraints::{mask_load, EmissionCalculator};
 use crate::extensions::{JobTie, VehicleTie};
 use crate::format::coord_index::CoordIndex;
-use crate::format::solution::activity_matcher::get_job_tag;
+use crate::format::problem::{
+    create_order_fn, get_energy_demand_fn, get_non_reloadable_dimensions, EtaConfidence, LoadDimension,
+};
+use crate::format::solution::activity_matcher::{get_job_tag, get_place_buffer};
 use crate::format::solution::model::Timing;
 use crate::format::solution::*;
 use crate::format::*;
 use crate::{format_time, parse_time};
+use hashbrown::{HashMap, HashSet};
 use std::cmp::Ordering;
 use std::io::{BufWriter, Write};
+use std::sync::Arc;
+use vrp_core::construction::constraints::{
+    DrivingRulesDimension, DrivingTime, ShiftOvertimeDimension, CURRENT_CAPACITY_KEY, LATEST_ARRIVAL_KEY,
+};
 use vrp_core::construction::extensions::route_intervals;
-use vrp_core::construction::heuristics::UnassignmentInfo;
+use vrp_core::construction::heuristics::{InsertionContext, RouteContext, RouteState, UnassignmentInfo};
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Multi, TravelTime};
+use vrp_core::models::problem::{Job, Multi, TravelTime, Vehicle};
 use vrp_core::models::solution::{Activity, Route};
-use vrp_core::models::{Problem, Solution};
+use vrp_core::models::{LockOrder, Problem, Solution};
 use vrp_core::prelude::compare_floats;
 use vrp_core::rosomaxa::evolution::TelemetryMetrics;
-use vrp_core::solver::processing::VicinityDimension;
+use vrp_core::solver::objectives::get_route_order_violations;
+use vrp_core::solver::processing::{MarginalCostDimension, VicinityDimension};
 use vrp_core::utils::CollectGroupBy;
 
 type ApiActivity = model::Activity;
@@ -38,6 +48,10 @@ pub trait PragmaticSolution<W: Write> {
     /// Serializes solution in pragmatic json format.
     fn write_pragmatic_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
 
+    /// Serializes solution in compact pragmatic json format incrementally: tours are created and
+    /// written one by one, so memory usage is bounded by the biggest tour instead of the whole solution.
+    fn write_pragmatic_json_streaming(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
+
     /// Serializes solution in pragmatic geo json format.
     fn write_geo_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
 }
@@ -47,6 +61,10 @@ impl<W: Write> PragmaticSolution<W> for (&Solution, f64) {
         write_pragmatic_json(problem, self.0, None, writer)
     }
 
+    fn write_pragmatic_json_streaming(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
+        write_pragmatic_json_streaming(problem, self.0, None, writer)
+    }
+
     fn write_geo_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
         write_geo_json(problem, self.0, writer)
     }
@@ -57,6 +75,10 @@ impl<W: Write> PragmaticSolution<W> for (&Solution, f64, &TelemetryMetrics) {
         write_pragmatic_json(problem, self.0, Some(self.2), writer)
     }
 
+    fn write_pragmatic_json_streaming(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
+        write_pragmatic_json_streaming(problem, self.0, Some(self.2), writer)
+    }
+
     fn write_geo_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
         write_geo_json(problem, self.0, writer)
     }
@@ -73,6 +95,51 @@ fn write_pragmatic_json<W: Write>(
     Ok(())
 }
 
+fn write_pragmatic_json_streaming<W: Write>(
+    problem: &Problem,
+    solution: &Solution,
+    metrics: Option<&TelemetryMetrics>,
+    mut writer: BufWriter<W>,
+) -> Result<(), String> {
+    let coord_index = get_coord_index(problem);
+    let reserved_times_index = get_reserved_times_index(problem);
+
+    // NOTE total statistic is known only when all tours are written, so it goes after them
+    writer.write_all(b"{\"tours\":[").map_err(|err| err.to_string())?;
+    let statistic = solution.routes.iter().enumerate().try_fold(Statistic::default(), |acc, (idx, route)| {
+        let tour = create_tour(problem, route, coord_index, reserved_times_index);
+
+        if idx > 0 {
+            writer.write_all(b",").map_err(|err| err.to_string())?;
+        }
+        serde_json::to_writer(&mut writer, &tour).map_err(|err| err.to_string())?;
+
+        Ok::<_, String>(acc + tour.statistic)
+    })?;
+    writer.write_all(b"]").map_err(|err| err.to_string())?;
+
+    write_json_field(&mut writer, "statistic", Some(&statistic))?;
+    write_json_field(&mut writer, "unassigned", create_unassigned(solution, false).as_ref())?;
+    write_json_field(&mut writer, "violations", create_violations(solution).as_ref())?;
+    write_json_field(&mut writer, "extras", create_extras(problem, solution, metrics).as_ref())?;
+
+    writer.write_all(b"}").map_err(|err| err.to_string())?;
+    writer.flush().map_err(|err| err.to_string())
+}
+
+fn write_json_field<W: Write, T: serde::Serialize>(
+    writer: &mut BufWriter<W>,
+    name: &str,
+    value: Option<&T>,
+) -> Result<(), String> {
+    if let Some(value) = value {
+        write!(writer, ",\"{}\":", name).map_err(|err| err.to_string())?;
+        serde_json::to_writer(writer, value).map_err(|err| err.to_string())?;
+    }
+
+    Ok(())
+}
+
 fn write_geo_json<W: Write>(problem: &Problem, solution: &Solution, writer: BufWriter<W>) -> Result<(), String> {
     let solution = create_solution(problem, solution, None);
     serialize_solution_as_geojson(writer, problem, &solution).map_err(|err| err.to_string())?;
@@ -108,10 +175,10 @@ pub fn create_solution(problem: &Problem, solution: &Solution, metrics: Option<&
 
     let statistic = tours.iter().fold(Statistic::default(), |acc, tour| acc + tour.statistic.clone());
 
-    let unassigned = create_unassigned(solution);
+    let unassigned = create_unassigned(solution, false);
     let violations = create_violations(solution);
 
-    let extras = create_extras(solution, metrics);
+    let extras = create_extras(problem, solution, metrics);
 
     ApiSolution { statistic, tours, unassigned, violations, extras }
 }
@@ -141,7 +208,23 @@ fn create_tour(
 
     let intervals = route_intervals(route, |a| get_activity_type(a).map_or(false, |t| t == "reload"));
 
-    let mut leg = intervals.into_iter().fold(Leg::empty(), |leg, (start_idx, end_idx)| {
+    // NOTE goods in non-reloadable dimensions are loaded at tour start and unloaded only at tour end
+    let non_reloadable = get_non_reloadable_dimensions(get_load_dimensions(problem));
+    let non_reloadable_deliveries = intervals
+        .iter()
+        .map(|&(start_idx, end_idx)| {
+            route.tour.activities_slice(start_idx, end_idx).iter().fold(MultiDimLoad::default(), |acc, activity| {
+                let delivery = activity
+                    .job
+                    .as_ref()
+                    .and_then(|job| get_capacity(&job.dimens, is_multi_dimen).map(|d| d.delivery.0))
+                    .unwrap_or_default();
+                acc + mask_load(delivery, non_reloadable.as_slice())
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut leg = intervals.into_iter().enumerate().fold(Leg::empty(), |leg, (interval_idx, (start_idx, end_idx))| {
         let (start_delivery, end_pickup) = route.tour.activities_slice(start_idx, end_idx).iter().fold(
             (leg.load.unwrap_or_default(), MultiDimLoad::default()),
             |acc, activity| {
@@ -154,6 +237,13 @@ fn create_tour(
             },
         );
 
+        let start_delivery = if interval_idx == 0 {
+            non_reloadable_deliveries.iter().skip(1).fold(start_delivery, |acc, delivery| acc + *delivery)
+        } else {
+            start_delivery - non_reloadable_deliveries[interval_idx]
+        };
+        let end_pickup = end_pickup - mask_load(end_pickup, non_reloadable.as_slice());
+
         let (start_idx, start) = if start_idx == 0 {
             let start = route.tour.start().unwrap();
             let (has_dispatch, is_same_location) = route.tour.get(1).map_or((false, false), |activity| {
@@ -211,8 +301,11 @@ fn create_tour(
                 let is_break = activity_type == "break";
 
                 let job_tag = act.job.as_ref().and_then(|single| {
-                    get_job_tag(single, (act.place.location, (act.place.time.clone(), start.schedule.departure)))
-                        .cloned()
+                    get_job_tag(
+                        single,
+                        (act.place.location, act.place.duration, (act.place.time.clone(), start.schedule.departure)),
+                    )
+                    .cloned()
                 });
                 let job_id = match activity_type.as_str() {
                     "pickup" | "delivery" | "replacement" | "service" => {
@@ -244,16 +337,30 @@ fn create_tour(
                         _ => 0.,
                     };
 
-                let activity_arrival = parking + act.schedule.arrival + commute.forward.duration;
-                let service_start = activity_arrival.max(act.place.time.start);
+                // NOTE setup (e.g. job approach) is paid before activity start and reported as serving time
+                let setup = act.place.setup_from(prev_location);
+                let activity_arrival = parking + act.schedule.arrival + setup + commute.forward.duration;
+                // NOTE time window is applied to the place arrival: parking and commute happen after waiting
+                let service_start = activity_arrival.max(act.place.time.start + parking + commute.forward.duration);
                 let waiting = service_start - activity_arrival;
-                let serving = act.place.duration - parking;
-                let service_end = service_start + serving;
+                // NOTE post service buffer is a part of activity duration, but it is reported as waiting time
+                let buffer = act.job.as_ref().map_or(0., |single| {
+                    get_place_buffer(
+                        single,
+                        (act.place.location, act.place.duration, (act.place.time.clone(), start.schedule.departure)),
+                    )
+                    .unwrap_or(0.)
+                });
+                let serving = act.place.duration - parking - buffer;
+                let service_end = service_start + serving + buffer;
                 let activity_departure = service_end;
 
                 // TODO: add better support of time based activity costs
                 let serving_cost = problem.activity.cost(route, act, service_start);
-                let total_cost = serving_cost + transport_cost + waiting * vehicle.costs.per_waiting_time;
+                let total_cost = serving_cost
+                    + transport_cost
+                    + setup * vehicle.costs.per_service_time
+                    + waiting * vehicle.costs.per_waiting_time;
 
                 let location_distance =
                     transport.distance(route, prev_location, act.place.location, TravelTime::Departure(prev_departure))
@@ -329,12 +436,15 @@ fn create_tour(
                         duration: leg.statistic.duration + act.schedule.departure as i64 - prev_departure as i64,
                         times: Timing {
                             driving: leg.statistic.times.driving + driving as i64,
-                            serving: leg.statistic.times.serving + (if is_break { 0 } else { serving as i64 }),
-                            waiting: leg.statistic.times.waiting + waiting as i64,
+                            serving: leg.statistic.times.serving
+                                + (if is_break { 0 } else { (setup + serving) as i64 }),
+                            waiting: leg.statistic.times.waiting + (waiting + buffer) as i64,
                             break_time: leg.statistic.times.break_time + (if is_break { serving as i64 } else { 0 }),
                             commuting: leg.statistic.times.commuting + commuting as i64,
                             parking: leg.statistic.times.parking + parking as i64,
                         },
+                        utilization: None,
+                        emissions: None,
                     },
                     load: Some(load),
                 }
@@ -347,9 +457,15 @@ fn create_tour(
     });
 
     leg.statistic.cost += vehicle.costs.fixed;
+    if let Some(overtime) = vehicle.dimens.get_shift_overtime() {
+        leg.statistic.cost += overtime.estimate(route.tour.end().map_or(0., |end| end.schedule.departure));
+    }
     tour.statistic = leg.statistic;
+    tour.statistic.utilization = create_load_utilization(problem, vehicle, &tour);
+    tour.statistic.emissions = create_emissions(problem, route, is_multi_dimen);
 
     insert_reserved_times(route, &mut tour, reserved_times_index);
+    insert_driving_rests(problem, route, &mut tour);
 
     // NOTE remove redundant info
     tour.stops
@@ -377,6 +493,7 @@ fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &
         .zip(route.tour.end())
         .map(|(start, end)| TimeWindow::new(start.schedule.departure, end.schedule.arrival))
         .expect("empty tour");
+    let maintenance = route.actor.vehicle.dimens.get_vehicle_maintenance();
 
     reserved_times_index
         .get(&route.actor)
@@ -425,6 +542,11 @@ fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &
             }
 
             let break_time = reserved_time.duration() as i64;
+            let activity_type = if maintenance.is_some_and(|maintenance| maintenance.contains(&reserved_time)) {
+                "maintenance"
+            } else {
+                "break"
+            };
 
             // NOTE insert activity
             tour.stops.iter_mut().for_each(|stop| {
@@ -464,8 +586,8 @@ fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &
                     activities.insert(
                         idx,
                         ApiActivity {
-                            job_id: "break".to_string(),
-                            activity_type: "break".to_string(),
+                            job_id: activity_type.to_string(),
+                            activity_type: activity_type.to_string(),
                             location: None,
                             time: Some(Interval {
                                 start: format_time(reserved_time.start),
@@ -497,6 +619,101 @@ fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &
         });
 }
 
+/// Inserts breaks and rests which are taken during travel due to vehicle's driving rules.
+fn insert_driving_rests(problem: &Problem, route: &Route, tour: &mut Tour) {
+    let rules = match route.actor.vehicle.dimens.get_driving_rules() {
+        Some(rules) => rules,
+        None => return,
+    };
+
+    let transport = problem.transport.as_ref();
+    let start = route.tour.start().expect("empty tour");
+    let init = ((start.place.location, start.schedule.departure, DrivingTime::default()), Vec::<TimeWindow>::new());
+
+    // NOTE replay the same logic as used to update route schedule
+    let (_, rests) = route.tour.all_activities().skip(1).fold(init, |((location, departure, time), mut rests), act| {
+        let duration = transport.duration(route, location, act.place.location, TravelTime::Departure(departure));
+        let (time, breaks) = rules.drive(time, departure, duration);
+        let time = rules.stop(time, act.schedule.departure - act.schedule.arrival);
+        rests.extend(breaks);
+
+        ((act.place.location, act.schedule.departure, time), rests)
+    });
+
+    rests.into_iter().for_each(|rest| {
+        let leg = tour.stops.windows(2).enumerate().find_map(|(leg_idx, stops)| match stops {
+            [prev, next] => {
+                let travel_tw =
+                    TimeWindow::new(parse_time(&prev.schedule().departure), parse_time(&next.schedule().arrival));
+
+                if compare_floats(travel_tw.start, rest.end) == Ordering::Less
+                    && compare_floats(rest.start, travel_tw.end) == Ordering::Less
+                {
+                    Some((leg_idx, prev.load().clone()))
+                } else {
+                    None
+                }
+            }
+            _ => unreachable!(),
+        });
+
+        if let Some((leg_idx, load)) = leg {
+            let time = Interval { start: format_time(rest.start), end: format_time(rest.end) };
+
+            tour.stops.insert(
+                leg_idx + 1,
+                Stop::Transit(TransitStop {
+                    time: ApiSchedule { arrival: time.start.clone(), departure: time.end.clone() },
+                    load,
+                    activities: vec![ApiActivity {
+                        job_id: "rest".to_string(),
+                        activity_type: "rest".to_string(),
+                        location: None,
+                        time: Some(time),
+                        job_tag: None,
+                        commute: None,
+                    }],
+                }),
+            );
+
+            tour.statistic.times.break_time += rest.duration() as i64;
+        }
+    });
+}
+
+fn get_load_dimensions(problem: &Problem) -> Option<&Vec<LoadDimension>> {
+    problem.extras.get("load_dimensions").and_then(|s| s.downcast_ref::<Vec<LoadDimension>>())
+}
+
+fn create_load_utilization(problem: &Problem, vehicle: &Vehicle, tour: &Tour) -> Option<Vec<LoadUtilization>> {
+    let dimensions = get_load_dimensions(problem)?;
+    let capacity: &MultiDimLoad = vehicle.dimens.get_capacity()?;
+
+    let max_load = tour
+        .stops
+        .iter()
+        .fold(MultiDimLoad::default(), |acc, stop| acc.max_load(MultiDimLoad::new(stop.load().clone())));
+
+    Some(
+        dimensions
+            .iter()
+            .enumerate()
+            .map(|(idx, dimension)| {
+                let (max_load, capacity) = (max_load.load[idx], capacity.load[idx]);
+                let ratio = if capacity > 0 { max_load as f64 / capacity as f64 } else { 0. };
+
+                LoadUtilization { dimension: dimension.name.clone(), max_load, capacity, ratio }
+            })
+            .collect(),
+    )
+}
+
+fn create_emissions(problem: &Problem, route: &Route, is_multi_dimen: bool) -> Option<Emissions> {
+    EmissionCalculator::new(problem.transport.clone(), get_energy_demand_fn(is_multi_dimen))
+        .calculate(route)
+        .map(|emissions| Emissions { fuel: emissions.fuel, co2: emissions.co2 })
+}
+
 fn format_schedule(schedule: &DomainSchedule) -> ApiSchedule {
     ApiSchedule { arrival: format_time(schedule.arrival), departure: format_time(schedule.departure) }
 }
@@ -507,7 +724,7 @@ fn calculate_load(current: MultiDimLoad, act: &Activity, is_multi_dimen: bool) -
     current - demand.delivery.0 - demand.delivery.1 + demand.pickup.0 + demand.pickup.1
 }
 
-fn create_unassigned(solution: &Solution) -> Option<Vec<UnassignedJob>> {
+fn create_unassigned(solution: &Solution, is_reservation: bool) -> Option<Vec<UnassignedJob>> {
     let create_simple_reasons = |code: i32| {
         let (code, reason) = map_code_reason(code);
         vec![UnassignedJobReason { code: code.to_string(), description: reason.to_string(), details: None }]
@@ -517,6 +734,7 @@ fn create_unassigned(solution: &Solution) -> Option<Vec<UnassignedJob>> {
         .unassigned
         .iter()
         .filter(|(job, _)| job.dimens().get_vehicle_id().is_none())
+        .filter(|(job, _)| job.dimens().get_job_reservation().unwrap_or(false) == is_reservation)
         .map(|(job, code)| {
             let job_id = job.dimens().get_job_id().expect("job id expected").clone();
 
@@ -566,12 +784,22 @@ fn create_unassigned(solution: &Solution) -> Option<Vec<UnassignedJob>> {
     }
 }
 
-fn create_violations(solution: &Solution) -> Option<Vec<Violation>> {
+/// Checks whether solution has no violations which are reported in the solution output, e.g. skipped
+/// breaks. It can be used as a feasibility check to keep the best known solution without violations.
+pub fn has_no_violations(insertion_ctx: &InsertionContext) -> bool {
+    !insertion_ctx.solution.unassigned.keys().any(is_violation)
+}
+
+fn is_violation(job: &Job) -> bool {
     // NOTE at the moment only break violation is mapped
+    job.dimens().get_job_type().is_some_and(|t| t == "break")
+}
+
+fn create_violations(solution: &Solution) -> Option<Vec<Violation>> {
     let violations = solution
         .unassigned
         .iter()
-        .filter(|(job, _)| job.dimens().get_job_type().map_or(false, |t| t == "break"))
+        .filter(|(job, _)| is_violation(job))
         .map(|(job, _)| Violation::Break {
             vehicle_id: job.dimens().get_vehicle_id().expect("vehicle id").clone(),
             shift_index: job.dimens().get_shift_index().expect("shift index"),
@@ -611,9 +839,29 @@ fn get_parking_time(extras: &DomainExtras) -> f64 {
     extras.get_cluster_config().map_or(0., |config| config.serving.get_parking())
 }
 
-fn create_extras(_solution: &Solution, metrics: Option<&TelemetryMetrics>) -> Option<Extras> {
-    metrics.map(|metrics| Extras {
-        metrics: Some(ApiMetrics {
+fn create_extras(problem: &Problem, solution: &Solution, metrics: Option<&TelemetryMetrics>) -> Option<Extras> {
+    let loading = create_loading_manifests(problem, solution);
+    let territories = create_territory_reports(problem, solution);
+    let tour_order = create_tour_order_report(problem, solution);
+    let marginal_costs = create_marginal_costs(solution);
+    let etas = create_activity_etas(problem, solution);
+    let certificates = create_route_certificates(problem, solution);
+    let reservations = create_unassigned(solution, true);
+
+    if metrics.is_none()
+        && loading.is_none()
+        && territories.is_none()
+        && tour_order.is_none()
+        && marginal_costs.is_none()
+        && etas.is_none()
+        && certificates.is_none()
+        && reservations.is_none()
+    {
+        return None;
+    }
+
+    Some(Extras {
+        metrics: metrics.map(|metrics| ApiMetrics {
             duration: metrics.duration,
             generations: metrics.generations,
             speed: metrics.speed,
@@ -637,5 +885,326 @@ fn create_extras(_solution: &Solution, metrics: Option<&TelemetryMetrics>) -> Op
                 })
                 .collect(),
         }),
+        loading,
+        territories,
+        tour_order,
+        marginal_costs,
+        etas,
+        certificates,
+        reservations,
     })
 }
+
+fn create_territory_reports(problem: &Problem, solution: &Solution) -> Option<Vec<TerritoryReport>> {
+    let mut reports = problem.jobs.all().fold(HashMap::<String, TerritoryReport>::new(), |mut acc, job| {
+        if let Some(territory) = job.dimens().get_job_territory() {
+            acc.entry(territory.id.clone())
+                .or_insert_with(|| TerritoryReport {
+                    territory_id: territory.id.clone(),
+                    jobs: 0,
+                    served: 0,
+                    violations: vec![],
+                })
+                .jobs += 1;
+        }
+
+        acc
+    });
+
+    if reports.is_empty() {
+        return None;
+    }
+
+    solution.routes.iter().for_each(|route| {
+        let vehicle_dimens = &route.actor.vehicle.dimens;
+        route.tour.jobs().for_each(|job| {
+            let dimens = job.dimens();
+            if let Some((territory, report)) =
+                dimens.get_job_territory().and_then(|territory| Some((territory, reports.get_mut(&territory.id)?)))
+            {
+                report.served += 1;
+                if territory.is_violated(vehicle_dimens) {
+                    report.violations.push(TerritoryViolation {
+                        job_id: dimens.get_job_id().unwrap().clone(),
+                        vehicle_id: vehicle_dimens.get_vehicle_id().unwrap().clone(),
+                    });
+                }
+            }
+        });
+    });
+
+    let mut reports = reports.into_values().collect::<Vec<_>>();
+    reports.sort_by(|a, b| a.territory_id.cmp(&b.territory_id));
+    reports.iter_mut().for_each(|report| report.violations.sort_by(|a, b| a.job_id.cmp(&b.job_id)));
+
+    Some(reports)
+}
+
+fn create_tour_order_report(problem: &Problem, solution: &Solution) -> Option<TourOrderReport> {
+    let has_order = problem.jobs.all().any(|job| match &job {
+        CoreJob::Single(single) => single.dimens.get_job_order().is_some(),
+        CoreJob::Multi(multi) => multi.jobs.iter().any(|single| single.dimens.get_job_order().is_some()),
+    });
+
+    if !has_order {
+        return None;
+    }
+
+    let order_fn = create_order_fn();
+    let tours = solution
+        .routes
+        .iter()
+        .map(|route| (route, get_route_order_violations(route, &order_fn)))
+        .filter(|(_, violations)| violations.count > 0)
+        .map(|(route, violations)| {
+            let dimens = &route.actor.vehicle.dimens;
+            TourOrderViolation {
+                vehicle_id: dimens.get_vehicle_id().unwrap().clone(),
+                shift_index: dimens.get_shift_index().unwrap(),
+                count: violations.count,
+                magnitude: violations.magnitude,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if tours.is_empty() {
+        return None;
+    }
+
+    Some(TourOrderReport {
+        count: tours.iter().map(|tour| tour.count).sum(),
+        magnitude: tours.iter().map(|tour| tour.magnitude).sum(),
+        tours,
+    })
+}
+
+fn create_marginal_costs(solution: &Solution) -> Option<Vec<MarginalCost>> {
+    let marginal_costs = solution.extras.get_marginal_costs()?;
+
+    let vehicle_ids = solution
+        .routes
+        .iter()
+        .flat_map(|route| {
+            let vehicle_id = route.actor.vehicle.dimens.get_vehicle_id().cloned().unwrap();
+            route.tour.jobs().map(move |job| (job, vehicle_id.clone()))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut marginal_costs = marginal_costs
+        .iter()
+        .filter(|(job, _)| job.dimens().get_vehicle_id().is_none())
+        .filter_map(|(job, cost)| {
+            Some(MarginalCost {
+                job_id: job.dimens().get_job_id()?.clone(),
+                vehicle_id: vehicle_ids.get(job)?.clone(),
+                cost: *cost,
+            })
+        })
+        .collect::<Vec<_>>();
+    marginal_costs.sort_by(|a, b| a.job_id.cmp(&b.job_id));
+
+    Some(marginal_costs)
+}
+
+fn create_activity_etas(problem: &Problem, solution: &Solution) -> Option<Vec<ActivityEta>> {
+    let eta_confidence = problem.extras.get("eta_confidence").and_then(|s| s.downcast_ref::<EtaConfidence>())?;
+
+    // NOTE jobs fixed by strict or sequence relations keep their place in the tour
+    let locked_jobs = problem
+        .locks
+        .iter()
+        .flat_map(|lock| lock.details.iter())
+        .filter(|detail| !matches!(detail.order, LockOrder::Any))
+        .flat_map(|detail| detail.jobs.iter().cloned())
+        .collect::<HashSet<_>>();
+
+    let route_ctxs = create_route_contexts(problem, solution);
+
+    let locked_jobs = &locked_jobs;
+    let mut etas = route_ctxs
+        .iter()
+        .flat_map(|route_ctx| {
+            let dimens = &route_ctx.route.actor.vehicle.dimens;
+            let vehicle_id = dimens.get_vehicle_id().cloned().unwrap();
+            let shift_index = dimens.get_shift_index().unwrap();
+
+            route_ctx.route.tour.all_activities().filter_map(move |activity| {
+                let single = activity.job.as_ref().filter(|single| single.dimens.get_vehicle_id().is_none())?;
+                let job = activity.retrieve_job()?;
+                let job_id = job.dimens().get_job_id()?.clone();
+
+                let latest_arrival = route_ctx
+                    .state
+                    .get_activity_state::<f64>(LATEST_ARRIVAL_KEY, activity)
+                    .cloned()
+                    .unwrap_or(activity.place.time.end);
+                let slack = (latest_arrival - activity.schedule.arrival).max(0.);
+                let stability = if slack <= eta_confidence.max_slack || locked_jobs.contains(&job) {
+                    EtaStability::Locked
+                } else {
+                    EtaStability::Shiftable
+                };
+
+                Some(ActivityEta {
+                    job_id,
+                    activity_type: single.dimens.get_job_type().cloned().unwrap_or_default(),
+                    vehicle_id: vehicle_id.clone(),
+                    shift_index,
+                    arrival: format_time(activity.schedule.arrival),
+                    slack,
+                    stability,
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+
+    etas.sort_by(|a, b| a.vehicle_id.cmp(&b.vehicle_id).then(a.shift_index.cmp(&b.shift_index)));
+
+    Some(etas)
+}
+
+fn create_route_certificates(problem: &Problem, solution: &Solution) -> Option<Vec<RouteCertificate>> {
+    problem.extras.get("certificates").and_then(|s| s.downcast_ref::<bool>()).filter(|&&enabled| enabled)?;
+
+    let coord_index = get_coord_index(problem);
+    let is_multi_dimen = has_multi_dim_demand(problem);
+    let route_ctxs = create_route_contexts(problem, solution);
+
+    let mut certificates = route_ctxs
+        .iter()
+        .map(|route_ctx| {
+            let route = &route_ctx.route;
+            let dimens = &route.actor.vehicle.dimens;
+            let start_departure = route.tour.start().map_or(0., |start| start.schedule.departure);
+            let last_idx = route.tour.total() - 1;
+
+            let transitions = route
+                .tour
+                .all_activities()
+                .enumerate()
+                .map(|(idx, activity)| {
+                    let activity_type = match (idx, activity.job.as_ref()) {
+                        (0, _) => "departure".to_string(),
+                        (_, None) if idx == last_idx => "arrival".to_string(),
+                        (_, single) => {
+                            single.and_then(|single| single.dimens.get_job_type().cloned()).unwrap_or_default()
+                        }
+                    };
+                    let job_id = match activity_type.as_str() {
+                        "pickup" | "delivery" | "replacement" | "service" => activity
+                            .retrieve_job()
+                            .and_then(|job| job.dimens().get_job_id().cloned())
+                            .unwrap_or_else(|| activity_type.clone()),
+                        _ => activity_type.clone(),
+                    };
+                    let job_tag = activity.job.as_ref().and_then(|single| {
+                        get_job_tag(
+                            single,
+                            (
+                                activity.place.location,
+                                activity.place.duration,
+                                (activity.place.time.clone(), start_departure),
+                            ),
+                        )
+                        .cloned()
+                    });
+                    let load = if is_multi_dimen {
+                        route_ctx
+                            .state
+                            .get_activity_state::<MultiDimLoad>(CURRENT_CAPACITY_KEY, activity)
+                            .map(|load| load.as_vec())
+                    } else {
+                        route_ctx
+                            .state
+                            .get_activity_state::<SingleDimLoad>(CURRENT_CAPACITY_KEY, activity)
+                            .map(|load| vec![load.value])
+                    };
+
+                    StateTransition {
+                        job_id,
+                        activity_type,
+                        job_tag,
+                        location: coord_index.get_by_idx(activity.place.location).unwrap(),
+                        arrival: format_time(activity.schedule.arrival),
+                        departure: format_time(activity.schedule.departure),
+                        load: load.unwrap_or_else(|| vec![0]),
+                    }
+                })
+                .collect();
+
+            RouteCertificate {
+                vehicle_id: dimens.get_vehicle_id().cloned().unwrap(),
+                shift_index: dimens.get_shift_index().unwrap(),
+                transitions,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    certificates.sort_by(|a, b| a.vehicle_id.cmp(&b.vehicle_id).then(a.shift_index.cmp(&b.shift_index)));
+
+    Some(certificates)
+}
+
+/// Creates route contexts with route state calculated by problem constraints, e.g. activity latest
+/// arrival or current load.
+fn create_route_contexts(problem: &Problem, solution: &Solution) -> Vec<RouteContext> {
+    solution
+        .routes
+        .iter()
+        .map(|route| {
+            let mut route_ctx =
+                RouteContext::new_with_state(Arc::new(route.deep_copy()), Arc::new(RouteState::default()));
+            problem.constraint.accept_route_state(&mut route_ctx);
+
+            route_ctx
+        })
+        .collect()
+}
+
+fn create_loading_manifests(problem: &Problem, solution: &Solution) -> Option<Vec<LoadingManifest>> {
+    let is_multi_dimen = has_multi_dim_demand(problem);
+
+    let manifests = solution
+        .routes
+        .iter()
+        .filter(|route| route.actor.vehicle.dimens.get_loading_manifest())
+        .map(|route| {
+            let dimens = &route.actor.vehicle.dimens;
+            let trips =
+                route_intervals(route, |a| get_activity_type(a).is_some_and(|t| t == "reload"))
+                    .into_iter()
+                    .map(|(start_idx, end_idx)| {
+                        // NOTE only static deliveries are loaded at the depot: the one delivered first is loaded last
+                        route
+                            .tour
+                            .activities_slice(start_idx, end_idx)
+                            .iter()
+                            .rev()
+                            .filter_map(|activity| activity.job.as_ref())
+                            .filter(|single| {
+                                get_capacity(&single.dimens, is_multi_dimen)
+                                    .is_some_and(|demand| demand.delivery.0.is_not_empty())
+                            })
+                            .map(|single| {
+                                single.dimens.get_job_id().cloned().unwrap_or_else(|| {
+                                    Multi::roots(single).unwrap().dimens.get_job_id().unwrap().clone()
+                                })
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .collect();
+
+            LoadingManifest {
+                vehicle_id: dimens.get_vehicle_id().unwrap().clone(),
+                shift_index: dimens.get_shift_index().unwrap(),
+                trips,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if manifests.is_empty() {
+        None
+    } else {
+        Some(manifests)
+    }
+}
@@ -33,6 +33,7 @@ mod utils;
 
 pub mod checker;
 pub mod format;
+pub mod scenario;
 pub mod validation;
 
 use crate::format::problem::Problem;
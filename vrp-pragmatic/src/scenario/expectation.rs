@@ -0,0 +1,201 @@
+#[cfg(test)]
+#[path = "../../tests/unit/scenario/expectation_test.rs"]
+mod expectation_test;
+
+use crate::format::solution::{Solution, Tour};
+
+/// Specifies an expected structure of a tour as a sequence of stop patterns.
+#[derive(Clone, Debug)]
+pub struct RoutePattern {
+    vehicle_id: Option<String>,
+    stops: Vec<StopPattern>,
+}
+
+#[derive(Clone, Debug)]
+enum StopPattern {
+    /// Matches any single stop.
+    Any,
+    /// Matches any amount of stops, including zero.
+    AnyMany,
+    /// Matches a stop which has activities of all given jobs.
+    Jobs(Vec<String>),
+}
+
+impl RoutePattern {
+    /// Creates a new route pattern from stop tokens. Each token is one of:
+    /// - `*`: any single stop
+    /// - `..`: any amount of stops, including zero
+    /// - comma separated job ids, e.g. `job1,job2`: a stop with activities of all these jobs.
+    ///   Special job ids, such as `departure`, `arrival`, `break` or `reload`, can be used too.
+    pub fn new(stops: &[&str]) -> Self {
+        let stops = stops
+            .iter()
+            .map(|token| match token.trim() {
+                "*" => StopPattern::Any,
+                ".." => StopPattern::AnyMany,
+                token => StopPattern::Jobs(token.split(',').map(|job_id| job_id.trim().to_string()).collect()),
+            })
+            .collect();
+
+        Self { vehicle_id: None, stops }
+    }
+
+    /// Restricts the pattern to a tour of given vehicle. By default, any vehicle is matched.
+    pub fn with_vehicle(mut self, vehicle_id: &str) -> Self {
+        self.vehicle_id = Some(vehicle_id.to_string());
+        self
+    }
+
+    /// Checks whether given tour matches the pattern.
+    pub fn matches(&self, tour: &Tour) -> bool {
+        if self.vehicle_id.as_ref().is_some_and(|vehicle_id| *vehicle_id != tour.vehicle_id) {
+            return false;
+        }
+
+        let stops = get_job_ids(tour);
+
+        match_stops(self.stops.as_slice(), stops.as_slice())
+    }
+}
+
+/// Specifies a solution metric.
+#[derive(Clone, Debug)]
+pub enum Metric {
+    /// Total cost.
+    Cost,
+    /// Total distance.
+    Distance,
+    /// Total duration.
+    Duration,
+    /// Total driving time.
+    Driving,
+    /// Total serving time.
+    Serving,
+    /// Total waiting time.
+    Waiting,
+    /// Amount of tours.
+    Tours,
+    /// Amount of unassigned jobs.
+    Unassigned,
+}
+
+impl Metric {
+    /// Gets metric value from the solution.
+    pub fn get_value(&self, solution: &Solution) -> f64 {
+        let statistic = &solution.statistic;
+        match self {
+            Metric::Cost => statistic.cost,
+            Metric::Distance => statistic.distance as f64,
+            Metric::Duration => statistic.duration as f64,
+            Metric::Driving => statistic.times.driving as f64,
+            Metric::Serving => statistic.times.serving as f64,
+            Metric::Waiting => statistic.times.waiting as f64,
+            Metric::Tours => solution.tours.len() as f64,
+            Metric::Unassigned => solution.unassigned.as_ref().map_or(0, |unassigned| unassigned.len()) as f64,
+        }
+    }
+}
+
+/// Specifies how much an actual metric value can deviate from the expected one.
+#[derive(Clone, Debug)]
+pub enum Tolerance {
+    /// An absolute deviation.
+    Absolute(f64),
+    /// A deviation relative to the expected value, e.g. `0.05` means five percent.
+    Relative(f64),
+}
+
+impl Tolerance {
+    /// Checks whether actual value is within tolerance of the expected one.
+    pub fn is_within(&self, expected: f64, actual: f64) -> bool {
+        let deviation = match self {
+            Tolerance::Absolute(value) => *value,
+            Tolerance::Relative(value) => expected.abs() * value,
+        };
+
+        (actual - expected).abs() <= deviation + f64::EPSILON
+    }
+}
+
+pub(super) fn check_routes(solution: &Solution, routes: &[RoutePattern]) -> Result<(), String> {
+    let mut used = vec![false; routes.len()];
+
+    if solution.tours.len() == routes.len() && assign_tours(solution.tours.as_slice(), routes, used.as_mut_slice()) {
+        Ok(())
+    } else {
+        Err(format!(
+            "tours do not match route patterns, tours: {:?}, patterns: {:?}",
+            solution.tours.iter().map(|tour| (tour.vehicle_id.as_str(), get_job_ids(tour))).collect::<Vec<_>>(),
+            routes
+        ))
+    }
+}
+
+pub(super) fn check_unassigned(solution: &Solution, job_ids: &[String]) -> Result<(), String> {
+    let mut expected = job_ids.to_vec();
+    expected.sort();
+
+    let mut actual = solution
+        .unassigned
+        .iter()
+        .flat_map(|unassigned| unassigned.iter().map(|job| job.job_id.clone()))
+        .collect::<Vec<_>>();
+    actual.sort();
+
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(format!("unassigned jobs are {:?}, expected {:?}", actual, expected))
+    }
+}
+
+pub(super) fn check_statistic(
+    solution: &Solution,
+    metric: &Metric,
+    expected: f64,
+    tolerance: &Tolerance,
+) -> Result<(), String> {
+    let actual = metric.get_value(solution);
+
+    if tolerance.is_within(expected, actual) {
+        Ok(())
+    } else {
+        Err(format!("{:?} is {}, expected {} with tolerance {:?}", metric, actual, expected, tolerance))
+    }
+}
+
+/// Assigns each tour to a distinct pattern using backtracking.
+fn assign_tours(tours: &[Tour], routes: &[RoutePattern], used: &mut [bool]) -> bool {
+    match tours.split_first() {
+        None => true,
+        Some((tour, rest)) => (0..routes.len()).any(|idx| {
+            if used[idx] || !routes[idx].matches(tour) {
+                return false;
+            }
+
+            used[idx] = true;
+            let is_assigned = assign_tours(rest, routes, used);
+            used[idx] = false;
+
+            is_assigned
+        }),
+    }
+}
+
+fn match_stops(patterns: &[StopPattern], stops: &[Vec<String>]) -> bool {
+    match (patterns.split_first(), stops.split_first()) {
+        (None, None) => true,
+        (Some((StopPattern::AnyMany, rest)), _) => {
+            match_stops(rest, stops) || (!stops.is_empty() && match_stops(patterns, &stops[1..]))
+        }
+        (Some((StopPattern::Any, rest)), Some((_, stops))) => match_stops(rest, stops),
+        (Some((StopPattern::Jobs(job_ids), rest)), Some((stop, stops))) => {
+            job_ids.iter().all(|job_id| stop.contains(job_id)) && match_stops(rest, stops)
+        }
+        _ => false,
+    }
+}
+
+fn get_job_ids(tour: &Tour) -> Vec<Vec<String>> {
+    tour.stops.iter().map(|stop| stop.activities().iter().map(|activity| activity.job_id.clone()).collect()).collect()
+}
@@ -0,0 +1,195 @@
+//! This module provides a small DSL to write end-to-end scenarios against pragmatic format:
+//! define a problem, solve it and assert route structure and solution statistic with tolerances.
+//!
+//! It is used by feature tests of this crate and can be used by integrators to test their problem
+//! definitions:
+//!
+//! ```
+//! use vrp_pragmatic::scenario::*;
+//!
+//! let solution = Scenario::default()
+//!     .add_job(delivery("job1", (1., 0.)))
+//!     .add_job(delivery("job2", (2., 0.)))
+//!     .add_vehicle(vehicle("car", (0., 0.), vec![10]))
+//!     .with_generations(10)
+//!     .expect_routes(vec![RoutePattern::new(&["departure", "..", "job2", "..", "arrival"])])
+//!     .expect_statistic(Metric::Tours, 1., Tolerance::Absolute(0.))
+//!     .assert();
+//!
+//! assert!(solution.unassigned.is_none());
+//! ```
+
+mod expectation;
+pub use self::expectation::*;
+
+mod problem;
+pub use self::problem::*;
+
+use crate::checker::CheckerContext;
+use crate::format::problem::{Area, Job, Matrix, Objective, PragmaticProblem, Problem, VehicleType, ZoneSequence};
+use crate::format::solution::{create_solution, Solution};
+use std::sync::Arc;
+use vrp_core::rosomaxa::evolution::TelemetryMode;
+use vrp_core::solver::{create_default_config_builder, Solver};
+use vrp_core::utils::Environment;
+
+/// Defines an end-to-end scenario: a problem to be solved and expectations about its solution.
+pub struct Scenario {
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    environment: Arc<Environment>,
+    generations: usize,
+    routes: Option<Vec<RoutePattern>>,
+    unassigned: Option<Vec<String>>,
+    statistics: Vec<(Metric, f64, Tolerance)>,
+}
+
+impl Default for Scenario {
+    fn default() -> Self {
+        Self::new(create_empty_problem())
+    }
+}
+
+impl Scenario {
+    /// Creates a new instance of `Scenario` for given problem.
+    pub fn new(problem: Problem) -> Self {
+        Self {
+            problem,
+            matrices: None,
+            environment: Arc::new(Environment::default()),
+            generations: 200,
+            routes: None,
+            unassigned: None,
+            statistics: vec![],
+        }
+    }
+
+    /// Adds a job to the problem.
+    pub fn add_job(mut self, job: Job) -> Self {
+        self.problem.plan.jobs.push(job);
+        self
+    }
+
+    /// Adds an area to the plan.
+    pub fn add_area(mut self, area: Area) -> Self {
+        self.problem.plan.areas.get_or_insert_with(Vec::new).push(area);
+        self
+    }
+
+    /// Adds a zone sequence to the plan.
+    pub fn add_zone_sequence(mut self, zone_sequence: ZoneSequence) -> Self {
+        self.problem.plan.zone_sequences.get_or_insert_with(Vec::new).push(zone_sequence);
+        self
+    }
+
+    /// Adds a vehicle type to the problem. A routing profile used by the vehicle type is added
+    /// to the fleet's profiles if it is not there yet.
+    pub fn add_vehicle(mut self, vehicle: VehicleType) -> Self {
+        let profiles = &mut self.problem.fleet.profiles;
+        if profiles.iter().all(|profile| profile.name != vehicle.profile.matrix) {
+            profiles.push(create_matrix_profile(vehicle.profile.matrix.as_str()));
+        }
+
+        self.problem.fleet.vehicles.push(vehicle);
+        self
+    }
+
+    /// Sets objectives of the problem.
+    pub fn with_objectives(mut self, objectives: Vec<Vec<Objective>>) -> Self {
+        self.problem.objectives = Some(objectives);
+        self
+    }
+
+    /// Sets routing matrices. When they are omitted, approximated ones are used.
+    pub fn with_matrices(mut self, matrices: Vec<Matrix>) -> Self {
+        self.matrices = Some(matrices);
+        self
+    }
+
+    /// Sets environment used by the solver, e.g. to have a seeded random or a time quota.
+    pub fn with_environment(mut self, environment: Arc<Environment>) -> Self {
+        self.environment = environment;
+        self
+    }
+
+    /// Sets max amount of generations run by the solver. Default is 200.
+    pub fn with_generations(mut self, generations: usize) -> Self {
+        self.generations = generations;
+        self
+    }
+
+    /// Expects that each tour of the solution matches exactly one of given route patterns.
+    pub fn expect_routes(mut self, routes: Vec<RoutePattern>) -> Self {
+        self.routes = Some(routes);
+        self
+    }
+
+    /// Expects that exactly given jobs are unassigned.
+    pub fn expect_unassigned(mut self, job_ids: &[&str]) -> Self {
+        self.unassigned = Some(job_ids.iter().map(|job_id| job_id.to_string()).collect());
+        self
+    }
+
+    /// Expects that solution's metric is equal to given value within tolerance.
+    pub fn expect_statistic(mut self, metric: Metric, expected: f64, tolerance: Tolerance) -> Self {
+        self.statistics.push((metric, expected, tolerance));
+        self
+    }
+
+    /// Solves the problem, checks feasibility of the solution and verifies all expectations.
+    /// Returns the solution or a list of errors.
+    pub fn run(self) -> Result<Solution, Vec<String>> {
+        let solution = self.solve()?;
+
+        let errors = self
+            .routes
+            .iter()
+            .filter_map(|routes| check_routes(&solution, routes).err())
+            .chain(self.unassigned.iter().filter_map(|job_ids| check_unassigned(&solution, job_ids).err()))
+            .chain(self.statistics.iter().filter_map(|(metric, expected, tolerance)| {
+                check_statistic(&solution, metric, *expected, tolerance).err()
+            }))
+            .collect::<Vec<_>>();
+
+        if errors.is_empty() {
+            Ok(solution)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Runs the scenario and panics if any expectation is not met.
+    pub fn assert(self) -> Solution {
+        self.run().unwrap_or_else(|errors| panic!("scenario failed:\n{}", errors.join("\n")))
+    }
+
+    fn solve(&self) -> Result<Solution, Vec<String>> {
+        let core_problem = Arc::new(
+            if let Some(matrices) = self.matrices.clone() {
+                (self.problem.clone(), matrices).read_pragmatic()
+            } else {
+                self.problem.clone().read_pragmatic()
+            }
+            .map_err(|errors| errors.iter().map(|err| err.to_string()).collect::<Vec<_>>())?,
+        );
+
+        let (core_solution, _, _) =
+            create_default_config_builder(core_problem.clone(), self.environment.clone(), TelemetryMode::None)
+                .with_max_generations(Some(self.generations))
+                .build()
+                .map(|config| Solver::new(core_problem.clone(), config))
+                .and_then(|solver| solver.solve())
+                .map_err(|err| vec![err])?;
+
+        let mut solution = create_solution(&core_problem, &core_solution, None);
+        solution.tours.sort_by(|a, b| a.vehicle_id.cmp(&b.vehicle_id));
+        if let Some(unassigned) = solution.unassigned.as_mut() {
+            unassigned.sort_by(|a, b| a.job_id.cmp(&b.job_id));
+        }
+
+        CheckerContext::new(core_problem, self.problem.clone(), self.matrices.clone(), solution.clone())
+            .and_then(|ctx| ctx.check())?;
+
+        Ok(solution)
+    }
+}
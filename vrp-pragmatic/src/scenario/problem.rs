@@ -0,0 +1,143 @@
+use crate::format::problem::*;
+use crate::format::Location;
+use crate::format_time;
+
+/// Creates a delivery job with one unit of demand and one second of service duration.
+pub fn delivery(id: &str, location: (f64, f64)) -> Job {
+    Job { deliveries: Some(vec![create_task(location, Some(vec![1]))]), ..create_job(id) }
+}
+
+/// Creates a pickup job with one unit of demand and one second of service duration.
+pub fn pickup(id: &str, location: (f64, f64)) -> Job {
+    Job { pickups: Some(vec![create_task(location, Some(vec![1]))]), ..create_job(id) }
+}
+
+/// Creates a service job without demand and with one second of service duration.
+pub fn service(id: &str, location: (f64, f64)) -> Job {
+    Job { services: Some(vec![create_task(location, None)]), ..create_job(id) }
+}
+
+/// Creates a pickup and delivery job with one unit of demand. Its tasks are tagged as `p1` and `d1`.
+pub fn pickup_delivery(id: &str, pickup: (f64, f64), delivery: (f64, f64)) -> Job {
+    let with_tag = |mut task: JobTask, tag: &str| {
+        task.places.iter_mut().for_each(|place| place.tag = Some(tag.to_string()));
+        task
+    };
+
+    Job {
+        pickups: Some(vec![with_tag(create_task(pickup, Some(vec![1])), "p1")]),
+        deliveries: Some(vec![with_tag(create_task(delivery, Some(vec![1])), "d1")]),
+        ..create_job(id)
+    }
+}
+
+/// Creates a vehicle type with a single vehicle `<type_id>_1` which starts and ends its one day
+/// shift at given depot location and uses `car` routing profile.
+pub fn vehicle(type_id: &str, depot: (f64, f64), capacity: Vec<i32>) -> VehicleType {
+    let location = Location::new_coordinate(depot.0, depot.1);
+
+    VehicleType {
+        type_id: type_id.to_string(),
+        vehicle_ids: vec![format!("{}_1", type_id)],
+        profile: VehicleProfile { matrix: "car".to_string(), scale: None },
+        costs: VehicleCosts { fixed: Some(10.), distance: 1., time: 1., waiting: None },
+        shifts: vec![VehicleShift {
+            start: ShiftStart { earliest: format_time(0.), latest: None, location: location.clone() },
+            end: Some(ShiftEnd { earliest: None, latest: format_time(86400.), location, is_open: None }),
+            dispatch: None,
+            breaks: None,
+            reloads: None,
+            recharges: None,
+            trailer_parkings: None,
+            maintenance: None,
+            depots: None,
+            overtime: None,
+            unloading: None,
+            relays: None,
+        }],
+        capacity,
+        skills: None,
+        skill_counts: None,
+        limits: None,
+        tier: None,
+        loading: None,
+        compartments: None,
+        energy: None,
+        emissions: None,
+        trailer: None,
+        physical: None,
+        optimize_departure: None,
+        duration_per_unit: None,
+        named_capacity: None,
+    }
+}
+
+pub(super) fn create_empty_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![],
+            relations: None,
+            areas: None,
+            clustering: None,
+            blackouts: None,
+            territories: None,
+            escalation: None,
+            marginal_costs: None,
+            zone_sequences: None,
+            eta_confidence: None,
+            certificates: None,
+        },
+        fleet: Fleet { vehicles: vec![], profiles: vec![], resources: None, dimensions: None },
+        objectives: None,
+    }
+}
+
+pub(super) fn create_matrix_profile(name: &str) -> MatrixProfile {
+    MatrixProfile { name: name.to_string(), speed: None, infer: None, metric: None }
+}
+
+fn create_job(id: &str) -> Job {
+    Job {
+        id: id.to_string(),
+        pickups: None,
+        deliveries: None,
+        replacements: None,
+        services: None,
+        skills: None,
+        value: None,
+        group: None,
+        compatibility: None,
+        reservation: None,
+        visit_gap: None,
+        product_class: None,
+        placement: None,
+        truck_only: None,
+        max_ride_duration: None,
+        sync: None,
+        predecessors: None,
+        approach: None,
+        attempts: None,
+        item: None,
+        partition: None,
+        unassigned_penalty: None,
+        bundle: None,
+    }
+}
+
+fn create_task(location: (f64, f64), demand: Option<Vec<i32>>) -> JobTask {
+    JobTask {
+        places: vec![JobPlace {
+            location: Location::new_coordinate(location.0, location.1),
+            duration: 1.,
+            times: None,
+            tag: None,
+            duration_per_unit: None,
+            lateness_penalty: None,
+            post_service_buffer: None,
+            recurring_times: None,
+        }],
+        demand,
+        order: None,
+        named_demand: None,
+    }
+}
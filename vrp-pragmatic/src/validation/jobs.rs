@@ -3,7 +3,9 @@
 mod jobs_test;
 
 use super::*;
+use crate::format::Location;
 use crate::utils::combine_error_results;
+use hashbrown::{HashMap, HashSet};
 use vrp_core::models::common::MultiDimLoad;
 
 /// Checks that plan has no jobs with duplicate ids.
@@ -137,10 +139,11 @@ fn check_e1106_negative_duration(ctx: &ValidationContext) -> Result<(), FormatEr
     let ids = ctx
         .jobs()
         .filter(|job| {
-            ctx.tasks(job)
-                .iter()
-                .flat_map(|task| task.places.iter().map(|place| place.duration))
-                .any(|duration| duration.is_sign_negative())
+            ctx.tasks(job).iter().flat_map(|task| task.places.iter()).any(|place| {
+                place.duration.is_sign_negative()
+                    || place.duration_per_unit.unwrap_or(0.).is_sign_negative()
+                    || place.post_service_buffer.unwrap_or(0.).is_sign_negative()
+            })
         })
         .map(|job| job.id.clone())
         .collect::<Vec<_>>();
@@ -179,6 +182,602 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
     }
 }
 
+/// Checks that job visit gap is defined for job with multiple tasks and has correct values.
+fn check_e1108_visit_gap(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.visit_gap.iter().any(|gap| {
+                let is_negative = gap.min.iter().chain(gap.max.iter()).any(|value| value.is_sign_negative());
+                let is_wrong_range = gap.min.zip(gap.max).iter().any(|(min, max)| min > max);
+                let is_empty = gap.min.is_none() && gap.max.is_none();
+
+                ctx.tasks(job).len() < 2 || is_negative || is_wrong_range || is_empty
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1108".to_string(),
+            "invalid job visit gap".to_string(),
+            format!(
+                "make sure that job has at least two tasks and visit gap has non negative min <= max: ids '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job places have no negative lateness penalty.
+fn check_e1109_negative_lateness_penalty(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job)
+                .iter()
+                .flat_map(|task| task.places.iter())
+                .any(|place| place.lateness_penalty.iter().any(|penalty| penalty.is_sign_negative()))
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1109".to_string(),
+            "job has negative lateness penalty".to_string(),
+            format!("fix negative lateness penalty in jobs with ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job preferred skills have no negative penalty.
+fn check_e1110_negative_preferred_skill_penalty(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.skills
+                .as_ref()
+                .and_then(|skills| skills.preferred.as_ref())
+                .is_some_and(|preferred| preferred.iter().any(|skill| skill.penalty.is_sign_negative()))
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1110".to_string(),
+            "job has negative preferred skill penalty".to_string(),
+            format!("fix negative preferred skill penalty in jobs with ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job max ride duration is defined for pickup and delivery job and is non negative.
+fn check_e1111_max_ride_duration(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.max_ride_duration.is_some_and(|max_ride_duration| {
+                let has_pickups = job.pickups.as_ref().is_some_and(|pickups| !pickups.is_empty());
+                let has_deliveries = job.deliveries.as_ref().is_some_and(|deliveries| !deliveries.is_empty());
+
+                !has_pickups || !has_deliveries || max_ride_duration.is_sign_negative()
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1111".to_string(),
+            "invalid job max ride duration".to_string(),
+            format!(
+                "make sure that job has pickups and deliveries and max ride duration is non negative: ids '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that service blackouts have valid time window and refer to existing areas.
+fn check_e1112_service_blackouts(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let area_ids = ctx.problem.plan.areas.iter().flatten().map(|area| &area.id).collect::<HashSet<_>>();
+
+    let indices = ctx
+        .problem
+        .plan
+        .blackouts
+        .iter()
+        .flatten()
+        .enumerate()
+        .filter(|(_, blackout)| {
+            let has_invalid_time = !check_raw_time_windows(std::slice::from_ref(&blackout.time), false);
+            let has_unknown_area =
+                blackout.areas.as_ref().is_some_and(|ids| ids.iter().any(|id| !area_ids.contains(id)));
+
+            has_invalid_time || has_unknown_area
+        })
+        .map(|(idx, _)| idx.to_string())
+        .collect::<Vec<_>>();
+
+    if indices.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1112".to_string(),
+            "invalid service blackout".to_string(),
+            format!(
+                "make sure that blackout time window is valid and its areas are defined in the plan: indices '{}'",
+                indices.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that synchronized job has a single task without lateness penalty and its sync key is
+/// shared with other jobs using the same non negative offset.
+fn check_e1113_job_sync(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let offsets = ctx.jobs().filter_map(|job| job.sync.as_ref()).fold(
+        HashMap::<&String, Vec<f64>>::default(),
+        |mut acc, sync| {
+            acc.entry(&sync.key).or_default().push(sync.offset.unwrap_or(0.));
+            acc
+        },
+    );
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.sync.as_ref().is_some_and(|sync| {
+                let tasks = ctx.tasks(job);
+                let has_single_task = tasks.len() == 1;
+                let has_lateness =
+                    tasks.iter().flat_map(|task| task.places.iter()).any(|place| place.lateness_penalty.is_some());
+                let offset = sync.offset.unwrap_or(0.);
+                let is_valid_group = offsets
+                    .get(&sync.key)
+                    .is_some_and(|offsets| offsets.len() > 1 && offsets.iter().all(|other| *other == offset));
+
+                !has_single_task || has_lateness || offset.is_sign_negative() || !is_valid_group
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1113".to_string(),
+            "invalid job sync".to_string(),
+            format!(
+                "make sure that synchronized job has one task without lateness penalty and its sync key \
+                 is used by other jobs with the same non negative offset: ids '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job predecessors refer to other existing jobs without cycles and all related jobs
+/// have a single task without lateness penalty.
+fn check_e1114_job_predecessors(ctx: &ValidationContext) -> Result<(), FormatError> {
+    fn get_predecessors(job: &Job) -> Vec<&str> {
+        job.predecessors.iter().flatten().map(|id| id.as_str()).collect()
+    }
+
+    let jobs = ctx.jobs().map(|job| (job.id.as_str(), job)).collect::<HashMap<_, _>>();
+    let is_simple_job = |job: &Job| {
+        let tasks = ctx.tasks(job);
+        tasks.len() == 1
+            && tasks.iter().flat_map(|task| task.places.iter()).all(|place| place.lateness_penalty.is_none())
+    };
+
+    let has_cycle = |job: &Job| {
+        let mut visited = HashSet::new();
+        let mut stack = get_predecessors(job);
+
+        while let Some(id) = stack.pop() {
+            if id == job.id {
+                return true;
+            }
+
+            if visited.insert(id) {
+                stack.extend(jobs.get(id).into_iter().flat_map(|job| get_predecessors(job)));
+            }
+        }
+
+        false
+    };
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| job.predecessors.is_some())
+        .filter(|job| {
+            let predecessors = get_predecessors(job);
+
+            !is_simple_job(job)
+                || predecessors.iter().any(|id| !jobs.get(id).is_some_and(|predecessor| is_simple_job(predecessor)))
+                || has_cycle(job)
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1114".to_string(),
+            "invalid job predecessors".to_string(),
+            format!(
+                "make sure that job predecessors are existing jobs without cycles and all related jobs have \
+                 one task without lateness penalty: ids '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that service territories have unique ids, refer to existing areas and vehicles, do not
+/// share jobs with each other and have valid cross zone penalty.
+fn check_e1115_territories(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let plan = &ctx.problem.plan;
+    let area_jobs = plan.areas.iter().flatten().map(|area| (&area.id, &area.jobs)).collect::<HashMap<_, _>>();
+    let vehicle_ids =
+        ctx.problem.fleet.vehicles.iter().flat_map(|vehicle| vehicle.vehicle_ids.iter()).collect::<HashSet<_>>();
+
+    let mut territory_ids = HashSet::new();
+    let mut job_ids = HashSet::new();
+
+    let ids = plan
+        .territories
+        .iter()
+        .flatten()
+        .filter(|territory| {
+            let is_duplicate = !territory_ids.insert(&territory.id);
+            let has_unknown_area = territory.areas.iter().any(|id| !area_jobs.contains_key(id));
+            let has_unknown_vehicle = territory.vehicles.iter().any(|id| !vehicle_ids.contains(id));
+            let has_invalid_penalty = territory
+                .cross_zone_penalty
+                .is_some_and(|penalty| penalty < 0. || territory.is_strict.unwrap_or(false));
+
+            let jobs = territory
+                .areas
+                .iter()
+                .filter_map(|id| area_jobs.get(id))
+                .flat_map(|jobs| jobs.iter())
+                .collect::<HashSet<_>>();
+            let has_shared_jobs = jobs.iter().any(|job_id| job_ids.contains(job_id));
+            job_ids.extend(jobs);
+
+            is_duplicate || has_unknown_area || has_unknown_vehicle || has_shared_jobs || has_invalid_penalty
+        })
+        .map(|territory| territory.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1115".to_string(),
+            "invalid territory".to_string(),
+            format!(
+                "make sure that territory ids are unique, its areas and vehicles are defined, territories \
+                 do not share jobs and cross zone penalty is non-negative and not used with strict territory: \
+                 ids '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job approach has no negative duration or distance.
+fn check_e1116_job_approach(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.approach.as_ref().is_some_and(|approach| {
+                approach.duration.is_sign_negative() || approach.distance.unwrap_or(0.).is_sign_negative()
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1116".to_string(),
+            "invalid job approach".to_string(),
+            format!("make sure that job approach duration and distance are not negative: ids '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job skill counts are positive and not duplicated.
+fn check_e1117_job_skill_counts(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.skills.as_ref().and_then(|skills| skills.counts.as_ref()).is_some_and(|counts| {
+                let unique = counts.iter().map(|count| &count.skill).collect::<HashSet<_>>();
+                unique.len() != counts.len() || counts.iter().any(|count| count.count == 0)
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1117".to_string(),
+            "invalid job skill counts".to_string(),
+            format!("make sure that job skill counts are positive and skills are unique: ids '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job escalation has no negative weight.
+fn check_e1118_job_escalation(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let weight = ctx.problem.plan.escalation.as_ref().and_then(|escalation| escalation.weight).unwrap_or(0.);
+
+    if weight.is_sign_negative() {
+        Err(FormatError::new(
+            "E1118".to_string(),
+            "invalid job escalation".to_string(),
+            "make sure that escalation weight is not negative".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that area polygons have at least three vertices specified as geo coordinates.
+fn check_e1119_area_polygons(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .problem
+        .plan
+        .areas
+        .iter()
+        .flatten()
+        .filter(|area| {
+            area.polygon.as_ref().is_some_and(|polygon| {
+                polygon.len() < 3 || polygon.iter().any(|location| matches!(location, Location::Reference { .. }))
+            })
+        })
+        .map(|area| area.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1119".to_string(),
+            "invalid area polygon".to_string(),
+            format!(
+                "make sure that area polygon has at least three vertices specified as geo coordinates: ids '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that alternative places of job task have unique tags.
+fn check_e1120_job_place_tags(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job).iter().any(|task| {
+                let tags = task.places.iter().filter_map(|place| place.tag.as_ref());
+                tags.clone().collect::<HashSet<_>>().len() != tags.count()
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1120".to_string(),
+            "duplicated job place tags".to_string(),
+            format!("make sure that alternative places of job task have unique tags: ids '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that zone sequences have at least two unique known areas and no negative penalty.
+fn check_e1121_zone_sequences(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let area_ids = ctx.problem.plan.areas.iter().flatten().map(|area| &area.id).collect::<HashSet<_>>();
+
+    let indices = ctx
+        .problem
+        .plan
+        .zone_sequences
+        .iter()
+        .flatten()
+        .enumerate()
+        .filter(|(_, sequence)| {
+            let unique = sequence.areas.iter().collect::<HashSet<_>>();
+
+            unique.len() < 2
+                || unique.len() != sequence.areas.len()
+                || unique.iter().any(|id| !area_ids.contains(id))
+                || sequence.penalty.is_sign_negative()
+        })
+        .map(|(idx, _)| idx.to_string())
+        .collect::<Vec<_>>();
+
+    if indices.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1121".to_string(),
+            "invalid zone sequence".to_string(),
+            format!(
+                "make sure that zone sequence has at least two unique areas defined in the plan and its penalty \
+                 is not negative: indices '{}'",
+                indices.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that recurring time windows are valid and produce at least one time window within planning horizon.
+fn check_e1122_recurring_time_windows(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job).iter().flat_map(|task| task.places.iter()).any(|place| {
+                place.recurring_times.as_ref().is_some_and(|recurring_times| {
+                    // NOTE recurring time windows are expanded into times before validation
+                    place.times.iter().flatten().next().is_none()
+                        || !recurring_times.iter().all(is_valid_recurring_time_window)
+                })
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1122".to_string(),
+            "invalid recurring time windows".to_string(),
+            format!(
+                "make sure that recurring time windows have valid days and times of day and recur within planning \
+                 horizon: ids '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job item attributes are positive.
+fn check_e1123_job_item(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.item
+                .as_ref()
+                .is_some_and(|item| [item.weight, item.length, item.height].iter().flatten().any(|value| *value <= 0.))
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1123".to_string(),
+            "invalid job item".to_string(),
+            format!("make sure that job item weight, length and height are positive: ids '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job named demand uses only load dimensions declared on fleet.
+fn check_e1124_job_named_demand(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let dimensions = ctx
+        .problem
+        .fleet
+        .dimensions
+        .iter()
+        .flat_map(|dimensions| dimensions.iter().map(|dimension| &dimension.name))
+        .collect::<HashSet<_>>();
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            get_job_tasks(job)
+                .filter_map(|task| task.named_demand.as_ref())
+                .flat_map(|named_demand| named_demand.iter())
+                .any(|load| !dimensions.contains(&load.dimension) || load.value < 0)
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1124".to_string(),
+            "invalid job named demand".to_string(),
+            format!(
+                "make sure that named demand uses only dimensions declared on fleet and is not negative: ids '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job unassigned penalty is not negative.
+fn check_e1125_job_unassigned_penalty(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| job.unassigned_penalty.is_some_and(|penalty| !penalty.is_finite() || penalty < 0.))
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1125".to_string(),
+            "invalid job unassigned penalty".to_string(),
+            format!("make sure that unassigned penalty is not negative: ids '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that time of day has explicit utc offset when utc offset of the planning epoch is ambiguous.
+fn check_e1126_ambiguous_time_of_day(ctx: &ValidationContext) -> Result<(), FormatError> {
+    if !has_ambiguous_epoch_offset(ctx.problem) {
+        return Ok(());
+    }
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job).iter().flat_map(|task| task.places.iter()).any(|place| {
+                // NOTE time of day without offset is not resolved to absolute time in this case
+                place.times.iter().flatten().flatten().any(|time| is_time_of_day_without_offset(time))
+                    || place.recurring_times.iter().flatten().any(|recurring| {
+                        is_time_of_day_without_offset(recurring.start.as_str())
+                            || is_time_of_day_without_offset(recurring.end.as_str())
+                    })
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1126".to_string(),
+            "ambiguous time of day".to_string(),
+            format!(
+                "vehicle shifts start with different utc offsets, specify explicit utc offset for time of day, \
+                 e.g. '22:00+02:00': ids '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     combine_error_results(&[
@@ -190,5 +789,24 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
         check_e1105_empty_jobs(ctx),
         check_e1106_negative_duration(ctx),
         check_e1107_negative_demand(ctx),
+        check_e1108_visit_gap(ctx),
+        check_e1109_negative_lateness_penalty(ctx),
+        check_e1110_negative_preferred_skill_penalty(ctx),
+        check_e1111_max_ride_duration(ctx),
+        check_e1112_service_blackouts(ctx),
+        check_e1113_job_sync(ctx),
+        check_e1114_job_predecessors(ctx),
+        check_e1115_territories(ctx),
+        check_e1116_job_approach(ctx),
+        check_e1117_job_skill_counts(ctx),
+        check_e1118_job_escalation(ctx),
+        check_e1119_area_polygons(ctx),
+        check_e1120_job_place_tags(ctx),
+        check_e1121_zone_sequences(ctx),
+        check_e1122_recurring_time_windows(ctx),
+        check_e1123_job_item(ctx),
+        check_e1124_job_named_demand(ctx),
+        check_e1125_job_unassigned_penalty(ctx),
+        check_e1126_ambiguous_time_of_day(ctx),
     ])
 }
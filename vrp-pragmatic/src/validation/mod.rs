@@ -87,5 +87,11 @@ impl<'a> ValidationContext<'a> {
 }
 
 fn is_reserved_job_id(job_id: &str) -> bool {
-    job_id == "departure" || job_id == "arrival" || job_id == "break" || job_id == "reload" || job_id == "dispatch"
+    job_id == "departure"
+        || job_id == "arrival"
+        || job_id == "break"
+        || job_id == "reload"
+        || job_id == "recharge"
+        || job_id == "relay"
+        || job_id == "dispatch"
 }
@@ -5,6 +5,7 @@ mod objectives_test;
 use super::*;
 use crate::format::problem::Objective::*;
 use crate::utils::combine_error_results;
+use hashbrown::HashSet;
 
 /// Checks that objective is not empty when specified.
 fn check_e1600_empty_objective(objectives: &[&Objective]) -> Result<(), FormatError> {
@@ -33,12 +34,22 @@ fn check_e1601_duplicate_objectives(objectives: &[&Objective]) -> Result<(), For
                 MaximizeValue { .. } => acc.entry("maximize-value"),
                 MinimizeUnassignedJobs { .. } => acc.entry("minimize-unassigned"),
                 MinimizeArrivalTime => acc.entry("minimize-arrival-time"),
+                MinimizeMaxDuration => acc.entry("minimize-max-duration"),
+                MinimizeWaitingTime => acc.entry("minimize-waiting-time"),
+                MinimizeMaxWaitingTime { .. } => acc.entry("minimize-max-waiting-time"),
+                LimitTotalCost { .. } => acc.entry("limit-total-cost"),
                 BalanceMaxLoad { .. } => acc.entry("balance-max-load"),
                 BalanceActivities { .. } => acc.entry("balance-activities"),
                 BalanceDistance { .. } => acc.entry("balance-distance"),
                 BalanceDuration { .. } => acc.entry("balance-duration"),
+                BalanceValue { .. } => acc.entry("balance-value"),
                 TourOrder { .. } => acc.entry("tour-order"),
                 AreaOrder { .. } => acc.entry("area-order"),
+                MinimizeSkillPenalty => acc.entry("minimize-skill-penalty"),
+                MinimizeTerritoryViolations => acc.entry("minimize-territory-violations"),
+                MinimizeAreaPenalty => acc.entry("minimize-area-penalty"),
+                MinimizeEmissions => acc.entry("minimize-emissions"),
+                MinimizeOverlap => acc.entry("minimize-overlap"),
             }
             .and_modify(|count| *count += 1)
             .or_insert(1_usize);
@@ -175,14 +186,16 @@ fn check_e1607_jobs_with_value_but_no_objective(
         return Ok(());
     }
 
-    let has_no_value_objective = !objectives.iter().any(|objective| matches!(objective, MaximizeValue { .. }));
+    let has_no_value_objective =
+        !objectives.iter().any(|objective| matches!(objective, MaximizeValue { .. } | BalanceValue { .. }));
     let has_jobs_with_vlue = ctx.problem.plan.jobs.iter().filter_map(|job| job.value).any(|value| value > 0.);
 
     if has_no_value_objective && has_jobs_with_vlue {
         Err(FormatError::new(
             "E1607".to_string(),
             "missing value objective".to_string(),
-            "specify 'maximize-value' objective, remove objectives property or remove value property from jobs"
+            "specify 'maximize-value' or 'balance-value' objective, remove objectives property or remove value \
+             property from jobs"
                 .to_string(),
         ))
     } else {
@@ -193,7 +206,16 @@ fn check_e1607_jobs_with_value_but_no_objective(
 /// Checks that order objective is specified when some jobs have order property set.
 fn check_e1608_areas_but_no_objective(ctx: &ValidationContext, objectives: &[&Objective]) -> Result<(), FormatError> {
     let has_no_area_objective = !objectives.iter().any(|objective| matches!(objective, AreaOrder { .. }));
-    let has_areas = ctx.problem.plan.areas.as_ref().map_or(false, |areas| !areas.is_empty());
+    // NOTE areas used only to define service blackouts or territories do not need area order objective
+    let plan = &ctx.problem.plan;
+    let other_areas = plan
+        .blackouts
+        .iter()
+        .flatten()
+        .flat_map(|blackout| blackout.areas.iter().flatten())
+        .chain(plan.territories.iter().flatten().flat_map(|territory| territory.areas.iter()))
+        .collect::<HashSet<_>>();
+    let has_areas = plan.areas.iter().flatten().any(|area| !other_areas.contains(&area.id));
 
     if has_no_area_objective && has_areas {
         Err(FormatError::new(
@@ -206,6 +228,96 @@ fn check_e1608_areas_but_no_objective(ctx: &ValidationContext, objectives: &[&Ob
     }
 }
 
+/// Checks that total cost limit is positive.
+fn check_e1609_non_positive_cost_limit(objectives: &[&Objective]) -> Result<(), FormatError> {
+    let has_invalid_limit =
+        objectives.iter().any(|objective| matches!(objective, LimitTotalCost { max_cost } if *max_cost <= 0.));
+
+    if has_invalid_limit {
+        Err(FormatError::new(
+            "E1609".to_string(),
+            "invalid total cost limit".to_string(),
+            "specify 'maxCost' of 'limit-total-cost' objective as positive value".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that waiting time percentile is in the valid range.
+fn check_e1610_invalid_waiting_time_percentile(objectives: &[&Objective]) -> Result<(), FormatError> {
+    let has_invalid_percentile = objectives.iter().any(|objective| {
+        matches!(objective, MinimizeMaxWaitingTime { percentile: Some(percentile) } if *percentile <= 0. || *percentile > 1.)
+    });
+
+    if has_invalid_percentile {
+        Err(FormatError::new(
+            "E1610".to_string(),
+            "invalid waiting time percentile".to_string(),
+            "specify 'percentile' of 'minimize-max-waiting-time' objective in (0, 1] range".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that emissions objective can be specified only when vehicle with emission parameters is used.
+fn check_e1611_no_vehicles_with_emissions_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_emissions_objective = objectives.iter().any(|objective| matches!(objective, MinimizeEmissions));
+    let has_no_vehicles_with_emissions = !ctx.vehicles().any(|vehicle| vehicle.emissions.is_some());
+
+    if has_emissions_objective && has_no_vehicles_with_emissions {
+        Err(FormatError::new(
+            "E1611".to_string(),
+            "redundant emissions objective".to_string(),
+            "specify emissions on at least one vehicle type or delete 'minimize-emissions' objective".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that overlap objective is specified only when all locations are defined by coordinates.
+fn check_e1612_overlap_objective_without_coordinates(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_overlap_objective = objectives.iter().any(|objective| matches!(objective, MinimizeOverlap));
+    let has_indices = ctx.coord_index.get_used_types().1;
+
+    if has_overlap_objective && has_indices {
+        Err(FormatError::new(
+            "E1612".to_string(),
+            "overlap objective requires coordinates".to_string(),
+            "use geo coordinates for all locations or delete 'minimize-overlap' objective".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that value balance objective can be specified only when job with value is used.
+fn check_e1613_no_jobs_with_value_balance_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_value_balance_objective = objectives.iter().any(|objective| matches!(objective, BalanceValue { .. }));
+    let has_no_jobs_with_value = !ctx.problem.plan.jobs.iter().filter_map(|job| job.value).any(|value| value > 0.);
+
+    if has_value_balance_objective && has_no_jobs_with_value {
+        Err(FormatError::new(
+            "E1613".to_string(),
+            "redundant value balance objective".to_string(),
+            "specify at least one non-zero valued job or delete 'balance-value' objective".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn get_objectives<'a>(ctx: &'a ValidationContext) -> Option<Vec<&'a Objective>> {
     ctx.problem.objectives.as_ref().map(|objectives| objectives.iter().flatten().collect())
 }
@@ -222,6 +334,11 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), Vec<FormatErro
             check_e1606_jobs_with_order_but_no_objective(ctx, &objectives),
             check_e1607_jobs_with_value_but_no_objective(ctx, &objectives),
             check_e1608_areas_but_no_objective(ctx, &objectives),
+            check_e1609_non_positive_cost_limit(&objectives),
+            check_e1610_invalid_waiting_time_percentile(&objectives),
+            check_e1611_no_vehicles_with_emissions_objective(ctx, &objectives),
+            check_e1612_overlap_objective_without_coordinates(ctx, &objectives),
+            check_e1613_no_jobs_with_value_balance_objective(ctx, &objectives),
         ])
     } else {
         Ok(())
@@ -181,7 +181,9 @@ fn check_e1206_relation_has_no_missing_shift_properties(
                 "break" => vehicle_shift.breaks.is_none(),
                 "dispatch" => vehicle_shift.dispatch.is_none(),
                 "reload" => vehicle_shift.reloads.is_none(),
-                "arrival" => vehicle_shift.end.is_none(),
+                "recharge" => vehicle_shift.recharges.is_none(),
+                "relay" => vehicle_shift.relays.is_none(),
+                "arrival" => !matches!(&vehicle_shift.end, Some(end) if !end.is_open.unwrap_or(false)),
                 _ => false,
             })
         })
@@ -104,7 +104,7 @@ fn check_e1505_profiles_exist(ctx: &ValidationContext) -> Result<(), FormatError
         .iter()
         .map(|vehicle| vehicle.profile.matrix.clone())
         .chain(ctx.problem.plan.clustering.iter().map(|clustering| match clustering {
-            Clustering::Vicinity { profile, .. } => profile.matrix.clone(),
+            Clustering::Vicinity { profile, .. } | Clustering::TimeWindow { profile, .. } => profile.matrix.clone(),
         }))
         .filter(|matrix| !known_matrix_profiles.contains(matrix))
         .collect::<HashSet<_>>();
@@ -121,6 +121,151 @@ fn check_e1505_profiles_exist(ctx: &ValidationContext) -> Result<(), FormatError
     }
 }
 
+/// Checks that all routing matrices are square and have the same dimension.
+fn check_e1506_matrix_dimensions(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let matrices = ctx.matrices.map_or(&[] as &[Matrix], |matrices| matrices.as_slice());
+    let expected_size = matrices.first().map_or(0, |matrix| matrix.travel_times.len());
+
+    let invalid = matrices
+        .iter()
+        .enumerate()
+        .filter(|(_, matrix)| {
+            let size = matrix.travel_times.len();
+            let dimension = (size as f64).sqrt().round() as usize;
+
+            size != expected_size
+                || dimension * dimension != size
+                || matrix.distances.len() != size
+                || matrix.error_codes.as_ref().is_some_and(|error_codes| error_codes.len() != size)
+        })
+        .map(|(idx, matrix)| matrix.profile.clone().unwrap_or_else(|| idx.to_string()))
+        .collect::<Vec<_>>();
+
+    if invalid.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1506".to_string(),
+            "invalid routing matrix dimensions".to_string(),
+            format!(
+                "make sure that travel times, distances and error codes of all matrices form square matrices \
+                 of the same size: matrices '{}'",
+                invalid.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that profile inference refers to another profile with routing matrix and uses positive factors.
+fn check_e1507_profile_inference(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let profiles = &ctx.problem.fleet.profiles;
+    let matrices = ctx.matrices.map_or(&[] as &[Matrix], |matrices| matrices.as_slice());
+    let has_unnamed_matrices = matrices.iter().any(|matrix| matrix.profile.is_none());
+
+    let invalid = profiles
+        .iter()
+        .filter(|profile| {
+            profile.infer.as_ref().is_some_and(|infer| {
+                let is_known_base = profiles.iter().any(|base| base.name == infer.profile && base.infer.is_none());
+                let is_positive = |factor: Option<f64>| factor.unwrap_or(1.) > 0.;
+
+                has_unnamed_matrices
+                    || infer.profile == profile.name
+                    || !is_known_base
+                    || !is_positive(infer.duration_factor)
+                    || !is_positive(infer.distance_factor)
+            })
+        })
+        .map(|profile| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if invalid.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1507".to_string(),
+            "invalid profile inference".to_string(),
+            format!(
+                "make sure that profile is inferred from another profile which is not inferred, factors are \
+                 positive and all matrices have profile names: profiles '{}'",
+                invalid.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that every profile has its routing matrix and every routing matrix has its profile.
+fn check_e1508_missing_profile_matrices(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let matrices = match ctx.matrices {
+        Some(matrices) if !matrices.is_empty() => matrices,
+        _ => return Ok(()),
+    };
+    let profiles = &ctx.problem.fleet.profiles;
+
+    let missing = if matrices.iter().all(|matrix| matrix.profile.is_some()) {
+        let matrix_profiles = matrices.iter().filter_map(|matrix| matrix.profile.as_ref()).collect::<HashSet<_>>();
+        let profile_names = profiles.iter().map(|profile| &profile.name).collect::<HashSet<_>>();
+
+        profiles
+            .iter()
+            .filter(|profile| profile.infer.is_none() && !matrix_profiles.contains(&profile.name))
+            .map(|profile| profile.name.clone())
+            .chain(matrix_profiles.iter().filter(|name| !profile_names.contains(*name)).map(|name| name.to_string()))
+            .collect::<Vec<_>>()
+    } else {
+        profiles.iter().skip(matrices.len()).map(|profile| profile.name.clone()).collect()
+    };
+
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1508".to_string(),
+            "profile and routing matrix mismatch".to_string(),
+            format!(
+                "make sure that each profile has a routing matrix or can be inferred and each matrix belongs to \
+                 a defined profile: profiles '{}'",
+                missing.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that metric policy of profile uses positive finite factors.
+fn check_e1509_metric_policy(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let is_valid = |factor: Option<f64>| {
+        let factor = factor.unwrap_or(1.);
+        factor.is_finite() && factor > 0.
+    };
+
+    let invalid = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| {
+            profile
+                .metric
+                .as_ref()
+                .is_some_and(|metric| !is_valid(metric.distance_factor) || !is_valid(metric.duration_factor))
+        })
+        .map(|profile| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if invalid.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1509".to_string(),
+            "invalid metric policy".to_string(),
+            format!(
+                "make sure that distance and duration factors of metric policy are positive: profiles '{}'",
+                invalid.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates routing rules.
 pub fn validate_routing(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     let location_types = ctx.coord_index.get_used_types();
@@ -132,5 +277,9 @@ pub fn validate_routing(ctx: &ValidationContext) -> Result<(), Vec<FormatError>>
         check_e1503_no_matrix_when_indices_used(ctx, location_types),
         check_e1504_index_size_mismatch(ctx),
         check_e1505_profiles_exist(ctx),
+        check_e1506_matrix_dimensions(ctx),
+        check_e1507_profile_inference(ctx),
+        check_e1508_missing_profile_matrices(ctx),
+        check_e1509_metric_policy(ctx),
     ])
 }
@@ -6,7 +6,7 @@ use super::*;
 use crate::utils::combine_error_results;
 use crate::validation::common::get_time_windows;
 use crate::{parse_time, parse_time_safe};
-use hashbrown::HashSet;
+use hashbrown::{HashMap, HashSet};
 use std::cmp::Ordering;
 use std::ops::Deref;
 use vrp_core::models::common::TimeWindow;
@@ -299,20 +299,18 @@ fn check_e1308_vehicle_required_break_rescheduling(ctx: &ValidationContext) -> R
 }
 
 fn check_e1309_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), FormatError> {
-    let reload_resource_ids = ctx
+    let resource_ids = ctx
         .problem
         .fleet
         .resources
         .iter()
         .flat_map(|resources| resources.iter())
         .map(|resource| match resource {
-            VehicleResource::Reload { id, .. } => id.to_string(),
+            VehicleResource::Reload { id, .. } | VehicleResource::Dock { id, .. } => id.to_string(),
         })
         .collect::<Vec<_>>();
 
-    let unique_resource_ids = reload_resource_ids.iter().cloned().collect::<HashSet<_>>();
-
-    if reload_resource_ids.len() != unique_resource_ids.len() {
+    if resource_ids.len() != resource_ids.iter().collect::<HashSet<_>>().len() {
         return Err(FormatError::new(
             "E1309".to_string(),
             "invalid vehicle reload resource".to_string(),
@@ -320,6 +318,18 @@ fn check_e1309_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), F
         ));
     }
 
+    let unique_resource_ids = ctx
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flat_map(|resources| resources.iter())
+        .filter_map(|resource| match resource {
+            VehicleResource::Reload { id, .. } => Some(id.to_string()),
+            VehicleResource::Dock { .. } => None,
+        })
+        .collect::<HashSet<_>>();
+
     let type_ids = get_invalid_type_ids(
         ctx,
         Box::new(move |_, shift, _| {
@@ -347,6 +357,579 @@ fn check_e1309_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), F
     }
 }
 
+/// Checks that optional break has at least one place.
+fn check_e1310_vehicle_break_places_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, _| {
+            shift.breaks.iter().flat_map(|breaks| breaks.iter()).all(|vehicle_break| match vehicle_break {
+                VehicleBreak::Optional { places, .. } => !places.is_empty(),
+                VehicleBreak::Required { .. } => true,
+            })
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1310".to_string(),
+            "invalid break places in vehicle shift".to_string(),
+            format!("ensure that optional break has at least one place, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle compartments are defined properly: capacity has the same dimensions as vehicle's one,
+/// compartment ids are unique and each compartment allows at least one product class.
+fn check_e1311_vehicle_compartments_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.compartments.as_ref().is_some_and(|compartments| {
+                let unique_ids = compartments.iter().map(|compartment| &compartment.id).collect::<HashSet<_>>();
+
+                unique_ids.len() != compartments.len()
+                    || compartments.iter().any(|compartment| {
+                        compartment.capacity.len() != vehicle.capacity.len()
+                            || compartment.capacity.iter().any(|value| *value < 0)
+                            || compartment.classes.is_empty()
+                    })
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1311".to_string(),
+            "invalid vehicle compartments".to_string(),
+            format!(
+                "ensure that compartment ids are unique, capacity has the same dimensions as vehicle capacity \
+                 without negative values and at least one product class is specified, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1312_vehicle_energy_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            let has_recharges = vehicle.shifts.iter().any(|shift| shift.recharges.is_some());
+            let has_invalid_rate = vehicle
+                .shifts
+                .iter()
+                .flat_map(|shift| shift.recharges.iter().flatten())
+                .any(|recharge| recharge.rate <= 0.);
+
+            let has_invalid_energy = vehicle.energy.as_ref().map_or(has_recharges, |energy| {
+                let reserve = energy.reserve.unwrap_or(0.);
+
+                energy.capacity <= 0.
+                    || energy.consumption < 0.
+                    || energy.load_consumption.unwrap_or(0.) < 0.
+                    || reserve < 0.
+                    || reserve >= energy.capacity
+            });
+
+            has_invalid_rate || has_invalid_energy
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1312".to_string(),
+            "invalid vehicle energy".to_string(),
+            format!(
+                "ensure that energy capacity is positive, consumption is not negative, reserve is less than \
+                 capacity, recharge rate is positive and recharges are defined only for vehicles with energy, \
+                 vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1313_vehicle_trailer_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            let has_parkings = vehicle.shifts.iter().any(|shift| shift.trailer_parkings.is_some());
+            let has_invalid_duration = vehicle
+                .shifts
+                .iter()
+                .flat_map(|shift| shift.trailer_parkings.iter().flatten())
+                .any(|parking| parking.duration < 0.);
+
+            let has_invalid_trailer = vehicle.trailer.as_ref().map_or(has_parkings, |trailer| {
+                trailer.capacity.len() != vehicle.capacity.len()
+                    || trailer
+                        .capacity
+                        .iter()
+                        .zip(vehicle.capacity.iter())
+                        .any(|(trailer, capacity)| *trailer < 0 || trailer > capacity)
+            });
+
+            has_invalid_duration || has_invalid_trailer
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1313".to_string(),
+            "invalid vehicle trailer".to_string(),
+            format!(
+                "ensure that trailer capacity has the same dimension as vehicle capacity, it is not negative and \
+                 not bigger than vehicle capacity, trailer parking duration is not negative and trailer parkings \
+                 are defined only for vehicles with trailer, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1314_vehicle_driving_time_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.limits.as_ref().and_then(|limits| limits.driving_time.as_ref()).is_some_and(|limit| {
+                let is_positive = |value: f64| value > 0.;
+
+                !is_positive(limit.max_driving_time)
+                    || !is_positive(limit.break_duration)
+                    || match (limit.max_working_time, limit.rest_duration) {
+                        (Some(max_working_time), Some(rest_duration)) => {
+                            !is_positive(max_working_time) || !is_positive(rest_duration)
+                        }
+                        (None, None) => false,
+                        _ => true,
+                    }
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1314".to_string(),
+            "invalid driving time limit".to_string(),
+            format!(
+                "ensure that max driving time and break duration are positive, max working time and rest duration \
+                 are positive and specified together, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle maintenance windows are correct.
+fn check_e1315_vehicle_maintenance_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, shift_time| {
+            shift
+                .maintenance
+                .as_ref()
+                .map(|maintenance| {
+                    // NOTE maintenance windows are reserved together with required breaks, so they should
+                    // not intersect and should use the same time type
+                    let breaks = shift
+                        .breaks
+                        .iter()
+                        .flatten()
+                        .filter_map(|vehicle_break| match vehicle_break {
+                            VehicleBreak::Required { time, duration } => Some((time, *duration)),
+                            VehicleBreak::Optional { .. } => None,
+                        })
+                        .map(|(time, duration)| match time {
+                            VehicleRequiredBreakTime::ExactTime(time) => {
+                                parse_time_safe(time).ok().map(|start| TimeWindow::new(start, start + duration))
+                            }
+                            VehicleRequiredBreakTime::OffsetTime(_) => None,
+                        });
+
+                    let tws = get_time_windows(maintenance);
+                    let are_correct = tws.iter().all(|tw| {
+                        tw.as_ref().is_some_and(|tw| {
+                            tw.start < tw.end
+                                && shift_time.as_ref().map(|shift_time| tw.intersects(shift_time)).unwrap_or(true)
+                        })
+                    });
+
+                    let has_no_intersections =
+                        tws.into_iter().chain(breaks).collect::<Option<Vec<_>>>().is_some_and(|mut tws| {
+                            tws.sort_by(|a, b| compare_floats(a.start, b.start));
+                            tws.windows(2).all(|pair| !pair[0].intersects(&pair[1]))
+                        });
+
+                    are_correct && has_no_intersections
+                })
+                .unwrap_or(true)
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1315".to_string(),
+            "invalid vehicle maintenance".to_string(),
+            format!(
+                "ensure that maintenance time windows are inside the shift and do not intersect each other or \
+                 required breaks, required breaks with offset time are not supported, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1316_vehicle_depots_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, _| {
+            !shift.depots.as_ref().is_some_and(|depots| depots.is_empty() || shift.dispatch.is_some())
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1316".to_string(),
+            "invalid vehicle depots".to_string(),
+            format!(
+                "ensure that alternative depots are not empty and not used together with dispatch, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1317_vehicle_reload_docks(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let docks = ctx
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flat_map(|resources| resources.iter())
+        .filter_map(|resource| match resource {
+            VehicleResource::Dock { id, capacity } => Some((id.to_string(), *capacity)),
+            VehicleResource::Reload { .. } => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(move |_, shift, _| {
+            shift
+                .reloads
+                .iter()
+                .flatten()
+                .filter_map(|reload| reload.dock_id.as_ref())
+                .chain(shift.unloading.iter().filter_map(|unloading| unloading.dock_id.as_ref()))
+                .all(|dock_id| docks.get(dock_id).is_some_and(|capacity| *capacity > 0))
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1317".to_string(),
+            "invalid vehicle reload dock".to_string(),
+            format!(
+                "make sure that fleet has all reload docks defined with positive capacity, check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1318_vehicle_allowed_areas(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let area_ids = ctx.problem.plan.areas.iter().flatten().map(|area| &area.id).collect::<HashSet<_>>();
+
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.limits.as_ref().and_then(|limits| limits.allowed_areas.as_ref()).is_some_and(|allowed_areas| {
+                allowed_areas.areas.is_empty()
+                    || allowed_areas.areas.iter().any(|area_id| !area_ids.contains(area_id))
+                    || allowed_areas.penalty.is_some_and(|penalty| penalty < 0.)
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1318".to_string(),
+            "invalid vehicle allowed areas".to_string(),
+            format!(
+                "make sure that allowed areas are not empty, defined in the plan and have non-negative penalty, \
+                 check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1319_vehicle_max_cost(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.limits.as_ref().and_then(|limits| limits.max_cost).is_some_and(|cost| cost <= 0.))
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1319".to_string(),
+            "invalid vehicle max cost".to_string(),
+            format!("make sure that max cost limit is positive, check vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+fn check_e1320_vehicle_min_tour_size(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.limits.as_ref().is_some_and(|limits| match (limits.min_tour_size, limits.tour_size) {
+                (Some(min_tour_size), tour_size) => {
+                    min_tour_size == 0 || tour_size.is_some_and(|max| min_tour_size > max)
+                }
+                (None, _) => false,
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1320".to_string(),
+            "invalid vehicle min tour size".to_string(),
+            format!(
+                "make sure that min tour size is positive and not greater than tour size, check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle physical limits are positive.
+fn check_e1321_vehicle_physical_limits(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.physical.as_ref().is_some_and(|physical| {
+                [physical.max_axle_weight, physical.max_item_length, physical.max_item_height]
+                    .iter()
+                    .flatten()
+                    .any(|value| *value <= 0.)
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1321".to_string(),
+            "invalid vehicle physical limits".to_string(),
+            format!(
+                "make sure that vehicle physical limits are positive, check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle overtime is used with shift end and its properties are not negative.
+fn check_e1322_vehicle_overtime(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, _| {
+            !shift.overtime.as_ref().is_some_and(|overtime| {
+                shift.end.is_none() || overtime.max_duration < 0. || overtime.cost_per_hour < 0.
+            })
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1322".to_string(),
+            "invalid vehicle overtime".to_string(),
+            format!(
+                "make sure that shift end is specified and overtime properties are not negative, check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle unloading is used with shift end and its duration is not negative.
+fn check_e1323_vehicle_unloading(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, _| {
+            !shift.unloading.as_ref().is_some_and(|unloading| shift.end.is_none() || unloading.duration < 0.)
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1323".to_string(),
+            "invalid vehicle unloading".to_string(),
+            format!(
+                "make sure that shift end is specified and unloading duration is not negative, check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that fleet load dimensions are unique and vehicle named capacity uses only them.
+fn check_e1324_vehicle_named_capacity(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let dimensions = ctx.problem.fleet.dimensions.iter().flat_map(|dimensions| dimensions.iter()).collect::<Vec<_>>();
+    let duplicates = get_duplicates(dimensions.iter().map(|dimension| &dimension.name));
+    let names = dimensions.iter().map(|dimension| &dimension.name).collect::<HashSet<_>>();
+
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle
+                .named_capacity
+                .iter()
+                .flat_map(|named_capacity| named_capacity.iter())
+                .any(|load| !names.contains(&load.dimension) || load.value < 0)
+                || vehicle.capacity.is_empty()
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if let Some(duplicates) = duplicates {
+        Err(FormatError::new(
+            "E1324".to_string(),
+            "invalid vehicle named capacity".to_string(),
+            format!("remove duplicated load dimensions: '{}'", duplicates.join(", ")),
+        ))
+    } else if !type_ids.is_empty() {
+        Err(FormatError::new(
+            "E1324".to_string(),
+            "invalid vehicle named capacity".to_string(),
+            format!(
+                "make sure that capacity is specified and named capacity uses only dimensions declared on fleet \
+                 and is not negative, check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that vehicle relays are used with positive driver shift time limit.
+fn check_e1325_vehicle_relays(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            let driver_shift_time = vehicle.limits.as_ref().and_then(|limits| limits.driver_shift_time);
+            let relays = vehicle.shifts.iter().flat_map(|shift| shift.relays.iter().flatten()).collect::<Vec<_>>();
+
+            driver_shift_time.map_or(!relays.is_empty(), |shift_time| shift_time <= 0.)
+                || relays.iter().any(|relay| relay.duration < 0.)
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1325".to_string(),
+            "invalid vehicle relays".to_string(),
+            format!(
+                "make sure that driver shift time limit is positive and specified when relays are used, relay \
+                 duration is not negative, check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1326_vehicle_reload_capacity(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|vehicle, shift, _| {
+            shift.reloads.iter().flatten().filter_map(|reload| reload.capacity.as_ref()).all(|capacity| {
+                capacity.len() == vehicle.capacity.len()
+                    && capacity.iter().zip(vehicle.capacity.iter()).all(|(trip, max)| *trip >= 0 && trip <= max)
+            })
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1326".to_string(),
+            "invalid vehicle reload capacity".to_string(),
+            format!(
+                "make sure that reload capacity has the same dimensions as vehicle capacity, is not negative and \
+                 does not exceed vehicle capacity, check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1327_vehicle_emissions(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids =
+        ctx.vehicles()
+            .filter(|vehicle| {
+                vehicle.emissions.as_ref().is_some_and(|emissions| {
+                    emissions.consumption < 0. || emissions.load_consumption.unwrap_or(0.) < 0.
+                })
+            })
+            .map(|vehicle| vehicle.type_id.clone())
+            .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1327".to_string(),
+            "invalid vehicle emissions".to_string(),
+            format!(
+                "ensure that fuel consumption and load consumption are not negative, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 type CheckShiftFn = Box<dyn Fn(&VehicleType, &VehicleShift, Option<TimeWindow>) -> bool>;
 
 fn get_invalid_type_ids(ctx: &ValidationContext, check_shift: CheckShiftFn) -> Vec<String> {
@@ -396,5 +979,23 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), Vec<FormatError>
         check_e1307_vehicle_has_no_zero_costs(ctx),
         check_e1308_vehicle_required_break_rescheduling(ctx),
         check_e1309_vehicle_reload_resources(ctx),
+        check_e1310_vehicle_break_places_are_correct(ctx),
+        check_e1311_vehicle_compartments_are_correct(ctx),
+        check_e1312_vehicle_energy_is_correct(ctx),
+        check_e1313_vehicle_trailer_is_correct(ctx),
+        check_e1314_vehicle_driving_time_is_correct(ctx),
+        check_e1315_vehicle_maintenance_is_correct(ctx),
+        check_e1316_vehicle_depots_are_correct(ctx),
+        check_e1317_vehicle_reload_docks(ctx),
+        check_e1318_vehicle_allowed_areas(ctx),
+        check_e1319_vehicle_max_cost(ctx),
+        check_e1320_vehicle_min_tour_size(ctx),
+        check_e1321_vehicle_physical_limits(ctx),
+        check_e1322_vehicle_overtime(ctx),
+        check_e1323_vehicle_unloading(ctx),
+        check_e1324_vehicle_named_capacity(ctx),
+        check_e1325_vehicle_relays(ctx),
+        check_e1326_vehicle_reload_capacity(ctx),
+        check_e1327_vehicle_emissions(ctx),
     ])
 }
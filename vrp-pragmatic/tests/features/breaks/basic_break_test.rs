@@ -43,6 +43,8 @@ fn can_assign_break_between_jobs() {
                 distance: 20,
                 duration: 24,
                 times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -96,6 +98,8 @@ fn can_assign_break_between_jobs() {
                     distance: 20,
                     duration: 24,
                     times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -14,7 +14,12 @@ fn can_assign_break_using_second_place() {
             vehicles: vec![VehicleType {
                 costs: create_default_vehicle_costs(),
                 shifts: vec![VehicleShift {
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (30., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (30., 0.).to_loc(),
+                        is_open: None,
+                    }),
                     breaks: Some(vec![VehicleBreak::Optional {
                         time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(10.), format_time(30.)]),
                         places: vec![
@@ -51,6 +56,8 @@ fn can_assign_break_using_second_place() {
                 distance: 30,
                 duration: 34,
                 times: Timing { driving: 30, serving: 2, break_time: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -104,9 +111,82 @@ fn can_assign_break_using_second_place() {
                     distance: 30,
                     duration: 34,
                     times: Timing { driving: 30, serving: 2, break_time: 2, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
         }
     );
 }
+
+parameterized_test! {can_assign_break_at_depot_or_rest_area, (break_time, places, expected_tag), {
+    let places = places.into_iter().map(|(location, tag)| VehicleOptionalBreakPlace {
+        duration: 2.0,
+        location: location.map(|location: (f64, f64)| location.to_loc()),
+        tag: Some(tag.to_string()),
+    }).collect();
+    can_assign_break_at_depot_or_rest_area_impl(break_time, places, expected_tag);
+}}
+
+can_assign_break_at_depot_or_rest_area! {
+    case01_depot: ((20., 25.), get_depot_and_rest_area_places(), "depot"),
+    case02_rest_area: ((12., 20.), get_depot_and_rest_area_places(), "rest_area"),
+    case03_mixed_places: ((12., 20.), vec![(Some((100., 0.)), "far_rest_area"), (None, "job_location")], "job_location"),
+}
+
+fn get_depot_and_rest_area_places() -> Vec<(Option<(f64, f64)>, &'static str)> {
+    vec![(Some((0., 0.)), "depot"), (Some((15., 0.)), "rest_area"), (Some((100., 0.)), "far_rest_area")]
+}
+
+fn can_assign_break_at_depot_or_rest_area_impl(
+    break_time: (f64, f64),
+    places: Vec<VehicleOptionalBreakPlace>,
+    expected_tag: &str,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (10., 0.)), create_delivery_job("job2", (-10., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: create_default_vehicle_costs(),
+                shifts: vec![VehicleShift {
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (0., 0.).to_loc(),
+                        is_open: None,
+                    }),
+                    breaks: Some(vec![VehicleBreak::Optional {
+                        time: VehicleOptionalBreakTime::TimeWindow(vec![
+                            format_time(break_time.0),
+                            format_time(break_time.1),
+                        ]),
+                        places,
+                        policy: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let break_tags = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "break")
+        .map(|activity| activity.job_tag.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(break_tags, vec![Some(expected_tag.to_string())]);
+}
@@ -1,8 +1,8 @@
 use crate::format::problem::Objective::{MinimizeCost, MinimizeUnassignedJobs};
 use crate::format::problem::*;
 use crate::format::solution::*;
-use crate::format_time;
 use crate::helpers::*;
+use crate::{format_time, parse_time};
 
 fn create_test_objectives() -> Option<Vec<Vec<Objective>>> {
     Some(vec![vec![MinimizeUnassignedJobs { breaks: Some(10.) }], vec![MinimizeCost]])
@@ -43,6 +43,8 @@ fn can_assign_interval_break_between_jobs() {
                 distance: 30,
                 duration: 34,
                 times: Timing { driving: 30, serving: 2, break_time: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -113,6 +115,8 @@ fn can_assign_interval_break_between_jobs() {
                     distance: 30,
                     duration: 34,
                     times: Timing { driving: 30, serving: 2, break_time: 2, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -140,7 +144,12 @@ fn can_assign_interval_break_with_reload() {
                         latest: Some(format_time(0.)),
                         location: (0., 0.).to_loc(),
                     },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (30., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (30., 0.).to_loc(),
+                        is_open: None,
+                    }),
                     dispatch: None,
                     breaks: Some(vec![VehicleBreak::Optional {
                         time: VehicleOptionalBreakTime::TimeOffset(vec![8., 12.]),
@@ -153,6 +162,13 @@ fn can_assign_interval_break_with_reload() {
                         duration: 3.0,
                         ..create_default_reload()
                     }]),
+                    recharges: None,
+                    trailer_parkings: None,
+                    maintenance: None,
+                    depots: None,
+                    overtime: None,
+                    unloading: None,
+                    relays: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -174,6 +190,8 @@ fn can_assign_interval_break_with_reload() {
                 distance: 60,
                 duration: 69,
                 times: Timing { driving: 60, serving: 7, break_time: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -268,6 +286,8 @@ fn can_assign_interval_break_with_reload() {
                     distance: 60,
                     duration: 69,
                     times: Timing { driving: 60, serving: 7, break_time: 2, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -276,7 +296,6 @@ fn can_assign_interval_break_with_reload() {
 }
 
 #[test]
-#[ignore]
 fn can_consider_departure_rescheduling() {
     let problem = Problem {
         plan: Plan {
@@ -310,3 +329,67 @@ fn can_consider_departure_rescheduling() {
     assert!(solution.violations.is_none());
     assert!(solution.unassigned.is_none());
 }
+
+fn create_problem_with_interval_break(jobs: Vec<Job>, offset: (f64, f64)) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak::Optional {
+                        time: VehicleOptionalBreakTime::TimeOffset(vec![offset.0, offset.1]),
+                        places: vec![VehicleOptionalBreakPlace { duration: 2.0, location: None, tag: None }],
+                        policy: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: create_test_objectives(),
+    }
+}
+
+fn get_break_end(tour: &Tour) -> Option<f64> {
+    tour.stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .find(|activity| activity.activity_type == "break")
+        .and_then(|activity| activity.time.as_ref())
+        .map(|interval| parse_time(&interval.end))
+}
+
+#[test]
+fn can_skip_interval_break_when_tour_finishes_before_offset() {
+    let problem = create_problem_with_interval_break(vec![create_delivery_job("job1", (5., 0.))], (100., 120.));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_break_end(&solution.tours[0]), None);
+}
+
+#[test]
+fn can_assign_interval_break_relative_to_shifted_departure() {
+    let problem = create_problem_with_interval_break(
+        vec![
+            create_delivery_job_with_times("job1", (10., 0.), vec![(100, 200)], 1.),
+            create_delivery_job("job2", (5., 0.)),
+        ],
+        (15., 20.),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let tour = &solution.tours[0];
+    let departure = parse_time(&tour.stops[0].schedule().departure);
+    let break_start = get_break_end(tour).expect("break should be assigned") - 2.;
+    assert!(departure > 0.);
+    assert!(break_start >= departure + 15. && break_start <= departure + 20.);
+}
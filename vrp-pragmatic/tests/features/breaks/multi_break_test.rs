@@ -54,6 +54,8 @@ fn can_use_two_breaks() {
                 distance: 198,
                 duration: 204,
                 times: Timing { driving: 198, serving: 2, break_time: 4, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -132,6 +134,8 @@ fn can_use_two_breaks() {
                     distance: 198,
                     duration: 204,
                     times: Timing { driving: 198, serving: 2, break_time: 4, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -28,6 +28,7 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
                             earliest: None,
                             latest: format_time(1000.),
                             location: (100., 0.).to_loc(),
+                            is_open: None,
                         }),
                         dispatch: None,
                         breaks: Some(vec![VehicleBreak::Optional {
@@ -40,6 +41,13 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
                             policy,
                         }]),
                         reloads: None,
+                        recharges: None,
+                        trailer_parkings: None,
+                        maintenance: None,
+                        depots: None,
+                        overtime: None,
+                        unloading: None,
+                        relays: None,
                     }],
                     ..create_default_vehicle_type()
                 },
@@ -61,6 +69,8 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
                 distance: 20,
                 duration: 22,
                 times: Timing { driving: 20, serving: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "vehicle_without_break_1".to_string(),
@@ -105,6 +115,8 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
                     distance: 20,
                     duration: 22,
                     times: Timing { driving: 20, serving: 2, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -157,6 +169,8 @@ fn can_skip_break_when_jobs_completed_impl(policy: Option<VehicleOptionalBreakPo
                 distance: 2,
                 duration: 12,
                 times: Timing { driving: 2, serving: 10, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -193,6 +207,8 @@ fn can_skip_break_when_jobs_completed_impl(policy: Option<VehicleOptionalBreakPo
                     distance: 2,
                     duration: 12,
                     times: Timing { driving: 2, serving: 10, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             violations: Some(vec![Violation::Break { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }]),
@@ -255,6 +271,8 @@ fn can_skip_second_break_when_jobs_completed_impl(policy: Option<VehicleOptional
                 distance: 20,
                 duration: 24,
                 times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -307,6 +325,8 @@ fn can_skip_second_break_when_jobs_completed_impl(policy: Option<VehicleOptional
                     distance: 20,
                     duration: 24,
                     times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -79,6 +79,8 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 2, break_time: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -131,6 +133,8 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 2, break_time: 2, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -158,6 +162,8 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 2, break_time: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -210,6 +216,8 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 2, break_time: 2, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -43,6 +43,8 @@ fn can_assign_break_during_travel() {
                 distance: 20,
                 duration: 24,
                 times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -102,6 +104,8 @@ fn can_assign_break_during_travel() {
                     distance: 20,
                     duration: 24,
                     times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -142,6 +146,8 @@ fn can_assign_break_during_activity() {
                 distance: 10,
                 duration: 15,
                 times: Timing { driving: 10, serving: 3, break_time: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -204,6 +210,8 @@ fn can_assign_break_during_activity() {
                     distance: 10,
                     duration: 15,
                     times: Timing { driving: 10, serving: 3, break_time: 2, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -100,6 +100,8 @@ fn create_statistic(data: (f64, i64, i64, (i64, i64, i64, i64))) -> Statistic {
             parking: data.3 .3,
             ..Timing::default()
         },
+        utilization: None,
+        emissions: None,
     }
 }
 
@@ -133,3 +135,4 @@ mod basic_vicinity_test;
 mod capacity_vicinity_test;
 mod profile_vicinity_test;
 mod specific_vicinity_test;
+mod time_window_clustering_test;
@@ -0,0 +1,89 @@
+use super::*;
+
+fn create_time_window_clustering(min_overlap: Option<f64>) -> Clustering {
+    Clustering::TimeWindow {
+        profile: VehicleProfile { matrix: "car".to_string(), scale: None },
+        threshold: TimeWindowThresholdPolicy { duration: 5., distance: 5., min_overlap, max_jobs_per_cluster: None },
+        serving: VicinityServingPolicy::Original { parking: 0. },
+        filtering: None,
+    }
+}
+
+fn get_clustered_job_ids(solution: &Solution) -> Vec<Vec<String>> {
+    let mut clusters = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .filter(|stop| stop.activities().len() > 1)
+        .map(|stop| {
+            let mut job_ids = stop.activities().iter().map(|activity| activity.job_id.clone()).collect::<Vec<_>>();
+            job_ids.sort();
+            job_ids
+        })
+        .collect::<Vec<_>>();
+    clusters.sort();
+
+    clusters
+}
+
+parameterized_test! {can_cluster_jobs_with_compatible_time_windows, (min_overlap, expected), {
+    can_cluster_jobs_with_compatible_time_windows_impl(min_overlap, expected);
+}}
+
+can_cluster_jobs_with_compatible_time_windows! {
+    case_01_default_overlap: (None, vec![vec!["job1", "job2"], vec!["job3", "job4"]]),
+    case_02_any_overlap: (Some(0.), vec![vec!["job1", "job2", "job3", "job4"]]),
+}
+
+fn can_cluster_jobs_with_compatible_time_windows_impl(min_overlap: Option<f64>, expected: Vec<Vec<&str>>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (1., 0.), vec![(0, 100)], 1.),
+                create_delivery_job_with_times("job2", (2., 0.), vec![(0, 100)], 1.),
+                create_delivery_job_with_times("job3", (3., 0.), vec![(0, 1000)], 1.),
+                create_delivery_job_with_times("job4", (4., 0.), vec![(0, 1000)], 1.),
+            ],
+            clustering: Some(create_time_window_clustering(min_overlap)),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_vehicle_with_capacity("my_vehicle", vec![10])
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_clustered_job_ids(&solution),
+        expected
+            .into_iter()
+            .map(|job_ids| job_ids.into_iter().map(|job_id| job_id.to_string()).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn can_reject_invalid_min_overlap() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.))],
+            clustering: Some(create_time_window_clustering(Some(1.5))),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let result = (problem, vec![matrix]).read_pragmatic();
+
+    assert!(result.is_err());
+}
@@ -39,6 +39,8 @@ fn can_assign_single_dispatch() {
                 distance: 14,
                 duration: 18,
                 times: Timing { driving: 14, serving: 4, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -91,6 +93,8 @@ fn can_assign_single_dispatch() {
                     distance: 14,
                     duration: 18,
                     times: Timing { driving: 14, serving: 4, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
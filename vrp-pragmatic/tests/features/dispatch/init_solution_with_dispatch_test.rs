@@ -47,6 +47,8 @@ fn can_use_init_solution_with_dispatch() {
             distance: 4,
             duration: 10,
             times: Timing { driving: 4, serving: 6, ..Timing::default() },
+            utilization: None,
+            emissions: None,
         },
         tours: vec![
             Tour {
@@ -110,6 +112,8 @@ fn can_use_init_solution_with_dispatch() {
                     distance: 2,
                     duration: 5,
                     times: Timing { driving: 2, serving: 3, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             },
             Tour {
@@ -173,6 +177,8 @@ fn can_use_init_solution_with_dispatch() {
                     distance: 2,
                     duration: 5,
                     times: Timing { driving: 2, serving: 3, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             },
         ],
@@ -19,12 +19,22 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                 shifts: vec![
                     VehicleShift {
                         start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                        end: Some(ShiftEnd { earliest: None, latest: format_time(99.), location: (0., 0.).to_loc() }),
+                        end: Some(ShiftEnd {
+                            earliest: None,
+                            latest: format_time(99.),
+                            location: (0., 0.).to_loc(),
+                            is_open: None,
+                        }),
                         ..create_default_vehicle_shift()
                     },
                     VehicleShift {
                         start: ShiftStart { earliest: format_time(100.), latest: None, location: (0., 0.).to_loc() },
-                        end: Some(ShiftEnd { earliest: None, latest: format_time(200.), location: (0., 0.).to_loc() }),
+                        end: Some(ShiftEnd {
+                            earliest: None,
+                            latest: format_time(200.),
+                            location: (0., 0.).to_loc(),
+                            is_open: None,
+                        }),
                         ..create_default_vehicle_shift()
                     },
                 ],
@@ -47,6 +57,8 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                 distance: 40,
                 duration: 42,
                 times: Timing { driving: 40, serving: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![
                 Tour {
@@ -84,6 +96,8 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         distance: 20,
                         duration: 21,
                         times: Timing { driving: 20, serving: 1, ..Timing::default() },
+                        utilization: None,
+                        emissions: None,
                     },
                 },
                 Tour {
@@ -121,6 +135,8 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         distance: 20,
                         duration: 21,
                         times: Timing { driving: 20, serving: 1, ..Timing::default() },
+                        utilization: None,
+                        emissions: None,
                     },
                 },
             ],
@@ -1,5 +1,6 @@
 use crate::format::problem::*;
 use crate::format::solution::*;
+use crate::format_time;
 use crate::helpers::*;
 
 #[test]
@@ -27,6 +28,8 @@ fn can_use_vehicle_with_open_end() {
                 distance: 1,
                 duration: 2,
                 times: Timing { driving: 1, serving: 1, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -55,9 +58,62 @@ fn can_use_vehicle_with_open_end() {
                     distance: 1,
                     duration: 2,
                     times: Timing { driving: 1, serving: 1, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
         }
     );
 }
+
+#[test]
+fn can_use_vehicle_with_open_end_flag_and_shift_time_limit() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (3., 0.)), create_delivery_job("job2", (20., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(10.),
+                        location: (0., 0.).to_loc(),
+                        is_open: Some(true),
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution.statistic,
+        Statistic {
+            cost: 17.,
+            distance: 3,
+            duration: 4,
+            times: Timing { driving: 3, serving: 1, ..Timing::default() },
+            utilization: None,
+            emissions: None,
+        }
+    );
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        solution.tours[0]
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(|activity| activity.activity_type.clone()))
+            .collect::<Vec<_>>(),
+        vec!["departure".to_string(), "delivery".to_string()]
+    );
+    assert_eq!(solution.unassigned.iter().flatten().map(|job| job.job_id.clone()).collect::<Vec<_>>(), vec!["job2"]);
+}
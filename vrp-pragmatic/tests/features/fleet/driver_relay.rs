@@ -0,0 +1,73 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_vehicle_with_relays(relays: Option<Vec<VehicleRelay>>) -> VehicleType {
+    VehicleType {
+        shifts: vec![VehicleShift { relays, ..create_default_vehicle_shift() }],
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            shift_time: None,
+            driver_shift_time: Some(30.),
+            tour_size: None,
+            min_tour_size: None,
+            areas: None,
+            consecutive_driving: None,
+            driving_time: None,
+            allowed_areas: None,
+            max_cost: None,
+        }),
+        ..create_default_vehicle_type()
+    }
+}
+
+fn create_test_problem(relays: Option<Vec<VehicleRelay>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (10., 0.)), create_delivery_job("job2", (20., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_relays(relays)], ..create_default_fleet() },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_use_relay_to_change_driver_on_long_tour() {
+    let problem = create_test_problem(Some(vec![VehicleRelay {
+        location: (20., 0.).to_loc(),
+        duration: 2.,
+        times: None,
+        tag: None,
+    }]));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let ids = get_ids_from_tour(&solution.tours[0]).into_iter().flatten().collect::<Vec<_>>();
+    assert_eq!(ids.len(), 5);
+    assert!(ids.contains(&"relay".to_string()));
+}
+
+#[test]
+fn can_skip_job_when_driver_shift_time_is_not_enough() {
+    let problem = create_test_problem(None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job2".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "DRIVER_SHIFT_TIME_CONSTRAINT".to_string(),
+                description: "cannot be assigned due to driver shift time limit".to_string(),
+                details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }])
+            }]
+        }])
+    );
+}
@@ -1,5 +1,16 @@
 mod basic_multi_shift;
 mod basic_open_end;
+mod driver_relay;
+mod multi_depot;
 mod multi_dimens;
+mod night_shift;
 mod profile_variation;
+mod shift_overtime;
 mod unreachable_jobs;
+mod vehicle_compartments;
+mod vehicle_emissions;
+mod vehicle_energy;
+mod vehicle_maintenance;
+mod vehicle_physical_limits;
+mod vehicle_tiers;
+mod vehicle_trailer;
@@ -0,0 +1,69 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn get_terminal_locations(problem: Problem) -> Vec<((f64, f64), (f64, f64))> {
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_iterations(problem, Some(vec![matrix]), 200);
+
+    assert!(solution.unassigned.is_none());
+    solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let get_location = |idx: usize| tour.stops[idx].as_point().unwrap().location.to_lat_lng();
+            (get_location(0), get_location(tour.stops.len() - 1))
+        })
+        .collect()
+}
+
+#[test]
+fn can_select_alternative_depot() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (11., 0.)), create_delivery_job("job2", (12., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    depots: Some(vec![(10., 0.).to_loc(), (-10., 0.).to_loc()]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let locations = get_terminal_locations(problem);
+
+    assert_eq!(locations, vec![((10., 0.), (10., 0.))]);
+}
+
+#[test]
+fn can_use_vehicle_only_with_one_depot() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (11., 0.)), create_delivery_job("job2", (-11., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string()],
+                shifts: vec![VehicleShift {
+                    depots: Some(vec![(10., 0.).to_loc(), (-10., 0.).to_loc()]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let locations = get_terminal_locations(problem);
+
+    assert_eq!(locations.len(), 1);
+}
@@ -34,6 +34,8 @@ fn can_use_two_dimensions() {
                 distance: 2,
                 duration: 4,
                 times: Timing { driving: 2, serving: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -70,6 +72,8 @@ fn can_use_two_dimensions() {
                     distance: 2,
                     duration: 4,
                     times: Timing { driving: 2, serving: 2, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -0,0 +1,49 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_serve_job_within_shift_crossing_midnight() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![JobPlace {
+                        times: Some(vec![vec!["01:00".to_string(), "02:00".to_string()]]),
+                        ..create_job_place((10., 0.), None)
+                    }],
+                    demand: Some(vec![1]),
+                    order: None,
+                    named_demand: None,
+                }]),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart { earliest: format_time(79200.), latest: None, location: (0., 0.).to_loc() },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: "06:00".to_string(),
+                        location: (0., 0.).to_loc(),
+                        is_open: None,
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_ids_from_tour(&solution.tours[0]), vec![vec!["departure"], vec!["job1"], vec!["arrival"]]);
+    assert_eq!(solution.tours[0].stops[1].schedule().departure, "1970-01-02T01:00:01Z");
+}
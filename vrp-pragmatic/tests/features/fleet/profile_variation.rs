@@ -30,3 +30,69 @@ fn can_use_scale() {
     assert_eq!(tour.statistic.distance, 20);
     assert_eq!(tour.statistic.duration, 11)
 }
+
+#[test]
+fn can_use_inferred_profile() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (10., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                profile: VehicleProfile { matrix: "bike".to_string(), scale: None },
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![
+                MatrixProfile { name: "car".to_string(), speed: None, infer: None, metric: None },
+                MatrixProfile {
+                    name: "bike".to_string(),
+                    speed: None,
+                    infer: Some(ProfileInference {
+                        profile: "car".to_string(),
+                        duration_factor: Some(2.),
+                        distance_factor: None,
+                    }),
+                    metric: None,
+                },
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = solution.tours.first().unwrap();
+    assert_eq!(tour.statistic.distance, 20);
+    assert_eq!(tour.statistic.duration, 41)
+}
+
+#[test]
+fn can_use_metric_policy() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (10., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_type("normal", None)],
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                infer: None,
+                metric: Some(MetricPolicy {
+                    distance_factor: Some(0.35),
+                    duration_factor: Some(0.25),
+                    rounding: Some(RoundingMode::Ceil),
+                }),
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = solution.tours.first().unwrap();
+    assert_eq!(tour.statistic.distance, 8);
+    assert_eq!(tour.statistic.duration, 7)
+}
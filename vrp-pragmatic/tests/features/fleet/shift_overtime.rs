@@ -0,0 +1,41 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_vehicle_with_overtime(overtime: Option<VehicleOvertime>) -> VehicleType {
+    let shift = create_default_vehicle_shift();
+    VehicleType {
+        shifts: vec![VehicleShift {
+            end: Some(ShiftEnd { latest: format_time(10.), ..shift.end.clone().unwrap() }),
+            overtime,
+            ..shift
+        }],
+        ..create_default_vehicle_type()
+    }
+}
+
+parameterized_test! {can_use_shift_overtime, (overtime, expected_unassigned, expected_cost), {
+    can_use_shift_overtime_impl(overtime, expected_unassigned, expected_cost);
+}}
+
+can_use_shift_overtime! {
+    case01_no_overtime: (None, 1, 0.),
+    case02_overtime_with_cost: (Some((100., 3600.)), 0, 62.),
+    case03_overtime_too_short: (Some((5., 3600.)), 1, 0.),
+}
+
+fn can_use_shift_overtime_impl(overtime: Option<(f64, f64)>, expected_unassigned: usize, expected_cost: f64) {
+    let overtime = overtime.map(|(max_duration, cost_per_hour)| VehicleOvertime { max_duration, cost_per_hour });
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (10., 0.))], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_overtime(overtime)], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.iter().flatten().count(), expected_unassigned);
+    // NOTE fixed 10 + distance 20 + duration 21 + overtime 11 seconds at 1 per second
+    assert_eq!(solution.statistic.cost, expected_cost);
+}
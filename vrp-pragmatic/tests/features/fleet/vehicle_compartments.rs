@@ -0,0 +1,60 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_job_with_class(id: &str, location: (f64, f64), demand: i32, class: &str) -> Job {
+    Job { product_class: Some(class.to_string()), ..create_delivery_job_with_demand(id, location, vec![demand]) }
+}
+
+parameterized_test! {can_keep_product_classes_in_separate_compartments, (vehicles_amount, expected_unassigned), {
+    can_keep_product_classes_in_separate_compartments_impl(vehicles_amount, expected_unassigned);
+}}
+
+can_keep_product_classes_in_separate_compartments! {
+    case01_single_vehicle: (1, 1),
+    case02_two_vehicles: (2, 0),
+}
+
+fn can_keep_product_classes_in_separate_compartments_impl(vehicles_amount: usize, expected_unassigned: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_class("frozen1", (1., 0.), 3, "frozen"),
+                create_job_with_class("frozen2", (2., 0.), 3, "frozen"),
+                create_job_with_class("ambient1", (3., 0.), 3, "ambient"),
+                create_job_with_class("ambient2", (4., 0.), 2, "ambient"),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: (1..=vehicles_amount).map(|idx| format!("my_vehicle_{}", idx)).collect(),
+                compartments: Some(vec![
+                    VehicleCompartment {
+                        id: "shared".to_string(),
+                        capacity: vec![5],
+                        classes: vec!["frozen".to_string(), "ambient".to_string()],
+                    },
+                    VehicleCompartment {
+                        id: "ambient".to_string(),
+                        capacity: vec![5],
+                        classes: vec!["ambient".to_string()],
+                    },
+                ]),
+                ..create_vehicle_with_capacity("my_vehicle", vec![20])
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.unwrap_or_default();
+    assert_eq!(unassigned.len(), expected_unassigned);
+    assert!(unassigned.iter().all(|job| job.job_id.starts_with("frozen")));
+    solution.tours.iter().for_each(|tour| {
+        let frozen = get_ids_from_tour(tour).into_iter().flatten().filter(|id| id.starts_with("frozen")).count();
+        assert!(frozen <= 1);
+    });
+}
@@ -0,0 +1,61 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_problem(objectives: Option<Vec<Vec<Objective>>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_demand("heavy", (5., 0.), vec![10]),
+                create_delivery_job_with_demand("light", (-5., 0.), vec![1]),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                capacity: vec![11],
+                emissions: Some(VehicleEmissions {
+                    class: EmissionClass::Diesel,
+                    consumption: 1.,
+                    load_consumption: Some(0.1),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives,
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_serve_heavy_job_first_to_minimize_emissions() {
+    let problem = create_problem(Some(vec![
+        vec![Objective::MinimizeUnassignedJobs { breaks: None }],
+        vec![Objective::MinimizeEmissions],
+        vec![Objective::MinimizeCost],
+    ]));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]),
+        vec![vec!["departure"], vec!["heavy"], vec!["light"], vec!["arrival"]]
+    );
+    let emissions = solution.tours[0].statistic.emissions.clone().expect("no emissions in tour statistic");
+    assert!((emissions.fuel - 26.5).abs() < 1E-6);
+    assert!((emissions.co2 - 26.5 * 2.68).abs() < 1E-6);
+    assert_eq!(solution.statistic.emissions, Some(emissions));
+}
+
+#[test]
+fn can_report_emissions_without_objective() {
+    let problem = create_problem(None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert!(solution.tours[0].statistic.emissions.as_ref().is_some_and(|emissions| emissions.co2 > 0.));
+}
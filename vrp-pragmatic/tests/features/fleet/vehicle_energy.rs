@@ -0,0 +1,65 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_vehicle_with_energy(recharges: Option<Vec<VehicleRecharge>>) -> VehicleType {
+    VehicleType {
+        shifts: vec![VehicleShift { recharges, ..create_default_vehicle_shift() }],
+        energy: Some(VehicleEnergy { capacity: 30., consumption: 1., load_consumption: None, reserve: None }),
+        ..create_default_vehicle_type()
+    }
+}
+
+#[test]
+fn can_use_recharge_to_serve_far_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (10., 0.)), create_delivery_job("job2", (20., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_energy(Some(vec![VehicleRecharge {
+                location: (15., 0.).to_loc(),
+                rate: 1.,
+                times: None,
+                tag: None,
+            }]))],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let ids = get_ids_from_tour(&solution.tours[0]).into_iter().flatten().collect::<Vec<_>>();
+    assert_eq!(ids.len(), 5);
+    assert!(ids.contains(&"recharge".to_string()));
+}
+
+#[test]
+fn can_skip_job_when_energy_is_not_enough() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (20., 0.))], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_energy(None)], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "ENERGY_CONSTRAINT".to_string(),
+                description: "cannot be assigned due to vehicle energy limit".to_string(),
+                details: None
+            }]
+        }])
+    );
+}
@@ -0,0 +1,77 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_vehicle_with_maintenance(start: f64, end: f64) -> VehicleType {
+    VehicleType {
+        shifts: vec![VehicleShift {
+            start: ShiftStart { earliest: format_time(0.), latest: Some(format_time(0.)), location: (0., 0.).to_loc() },
+            maintenance: Some(vec![vec![format_time(start), format_time(end)]]),
+            ..create_default_vehicle_shift()
+        }],
+        ..create_default_vehicle_type()
+    }
+}
+
+#[test]
+fn can_push_travel_past_maintenance() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (10., 0.))], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_maintenance(5., 10.)], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let tour = &solution.tours[0];
+    assert_eq!(get_ids_from_tour(tour), vec![vec!["departure"], vec!["maintenance"], vec!["job1"], vec!["arrival"]]);
+    assert!(matches!(tour.stops[1], Stop::Transit(_)));
+    assert_eq!(tour.stops[1].activities()[0].activity_type, "maintenance");
+    assert_eq!(tour.stops[2].schedule().arrival, format_time(15.));
+    assert_eq!(tour.statistic.times.break_time, 5);
+}
+
+#[test]
+fn can_reject_job_which_can_be_served_only_during_maintenance() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (1., 0.), vec![(5, 9)], 1.),
+                create_delivery_job("job2", (2., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_maintenance(5., 10.)], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job1"]);
+}
+
+#[test]
+fn can_interrupt_service_by_maintenance() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", (1., 0.), vec![(0, 100)], 10.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_maintenance(5., 10.)], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = &solution.tours[0];
+    assert_eq!(get_ids_from_tour(tour), vec![vec!["departure"], vec!["job1", "maintenance"], vec!["arrival"]]);
+    assert_eq!(tour.stops[1].schedule().departure, format_time(16.));
+}
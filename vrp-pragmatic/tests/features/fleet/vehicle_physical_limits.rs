@@ -0,0 +1,64 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_job_with_item(id: &str, location: (f64, f64), item: (Option<f64>, Option<f64>, Option<f64>)) -> Job {
+    Job { item: Some(JobItem { weight: item.0, length: item.1, height: item.2 }), ..create_delivery_job(id, location) }
+}
+
+fn create_vehicle_with_limits(type_id: &str, depot: (f64, f64), physical: VehiclePhysical) -> VehicleType {
+    VehicleType {
+        type_id: type_id.to_string(),
+        vehicle_ids: vec![format!("{}_1", type_id)],
+        shifts: vec![create_default_vehicle_shift_with_locations(depot, depot)],
+        physical: Some(physical),
+        ..create_default_vehicle_type()
+    }
+}
+
+#[test]
+fn can_assign_jobs_considering_item_attributes() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_item("long", (1., 0.), (Some(100.), Some(10.), None)),
+                create_job_with_item("heavy", (2., 0.), (Some(2000.), None, None)),
+                create_job_with_item("small", (3., 0.), (Some(10.), Some(1.), Some(1.))),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_with_limits(
+                    "van",
+                    (0., 0.),
+                    VehiclePhysical { max_axle_weight: Some(500.), max_item_length: Some(3.), max_item_height: None },
+                ),
+                create_vehicle_with_limits(
+                    "flatbed",
+                    (10., 0.),
+                    VehiclePhysical {
+                        max_axle_weight: Some(1000.),
+                        max_item_length: Some(12.),
+                        max_item_height: Some(3.),
+                    },
+                ),
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.as_ref().expect("should have unassigned job");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "heavy");
+    assert_eq!(unassigned[0].reasons[0].code, "PHYSICAL_CONSTRAINT");
+    let long_tour = solution
+        .tours
+        .iter()
+        .find(|tour| tour.stops.iter().any(|stop| stop.activities().iter().any(|activity| activity.job_id == "long")))
+        .expect("cannot find tour with long item");
+    assert_eq!(long_tour.vehicle_id, "flatbed_1");
+}
@@ -0,0 +1,43 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_use_premium_vehicle_only_when_needed, (jobs_amount, expected_premium_tours), {
+    can_use_premium_vehicle_only_when_needed_impl(jobs_amount, expected_premium_tours);
+}}
+
+can_use_premium_vehicle_only_when_needed! {
+    case01_enough_regular_vehicles: (4, 0),
+    case02_not_enough_regular_vehicles: (5, 1),
+}
+
+fn can_use_premium_vehicle_only_when_needed_impl(jobs_amount: usize, expected_premium_tours: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: (1..=jobs_amount).map(|idx| create_delivery_job(&format!("job{}", idx), (idx as f64, 0.))).collect(),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType {
+                    vehicle_ids: vec!["regular_1".to_string(), "regular_2".to_string()],
+                    costs: VehicleCosts { fixed: Some(100.), distance: 10., time: 10., waiting: None },
+                    ..create_vehicle_with_capacity("regular", vec![2])
+                },
+                VehicleType {
+                    vehicle_ids: vec!["premium_1".to_string(), "premium_2".to_string()],
+                    costs: VehicleCosts { fixed: Some(1.), distance: 1., time: 1., waiting: None },
+                    tier: Some(1),
+                    ..create_vehicle_with_capacity("premium", vec![4])
+                },
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.iter().filter(|tour| tour.type_id == "premium").count(), expected_premium_tours);
+}
@@ -0,0 +1,76 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_vehicle_with_trailer(trailer_capacity: i32) -> VehicleType {
+    VehicleType {
+        shifts: vec![VehicleShift {
+            trailer_parkings: Some(vec![VehicleTrailerParking {
+                location: (8., 0.).to_loc(),
+                duration: 5.,
+                times: None,
+                tag: None,
+            }]),
+            ..create_default_vehicle_shift()
+        }],
+        trailer: Some(VehicleTrailer { capacity: vec![trailer_capacity] }),
+        ..create_default_vehicle_type()
+    }
+}
+
+fn create_truck_only_delivery_job(id: &str, location: (f64, f64), demand: i32) -> Job {
+    Job {
+        truck_only: Some(true),
+        deliveries: Some(vec![JobTask { demand: Some(vec![demand]), ..create_task(location, None) }]),
+        ..create_job(id)
+    }
+}
+
+#[test]
+fn can_detach_trailer_to_serve_truck_only_job() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_truck_only_delivery_job("job1", (10., 0.), 1), create_delivery_job("job2", (3., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_trailer(5)], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let ids = get_ids_from_tour(&solution.tours[0])
+        .into_iter()
+        .flatten()
+        .filter(|id| id == "detach" || id == "job1" || id == "attach")
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["detach", "job1", "attach"]);
+}
+
+#[test]
+fn can_skip_truck_only_job_when_truck_capacity_is_not_enough() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_truck_only_delivery_job("job1", (10., 0.), 6)], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_trailer(5)], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "TRAILER_CONSTRAINT".to_string(),
+                description: "cannot be assigned due to vehicle trailer constraint".to_string(),
+                details: None
+            }]
+        }])
+    );
+}
@@ -0,0 +1,89 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_problem(eta_confidence: Option<EtaConfidence>, relations: Option<Vec<Relation>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (1., 0.), vec![(0, 100)], 0.),
+                create_delivery_job_with_times("job2", (2., 0.), vec![(0, 3)], 0.),
+                create_delivery_job_with_times("job3", (3., 0.), vec![], 0.),
+            ],
+            relations,
+            eta_confidence,
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_vehicle_shift_with_locations((0., 0.), (3., 0.))],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_activity_eta(job_id: &str, arrival: f64, slack: f64, stability: EtaStability) -> ActivityEta {
+    ActivityEta {
+        job_id: job_id.to_string(),
+        activity_type: "delivery".to_string(),
+        vehicle_id: "my_vehicle_1".to_string(),
+        shift_index: 0,
+        arrival: format_time(arrival),
+        slack,
+        stability,
+    }
+}
+
+parameterized_test! {can_report_eta_stability, (max_slack, relation_type, expected), {
+    can_report_eta_stability_impl(max_slack, relation_type, expected);
+}}
+
+can_report_eta_stability! {
+    case01_by_slack: (1., None, (EtaStability::Locked, EtaStability::Locked, EtaStability::Shiftable)),
+    case02_zero_slack: (0., None, (EtaStability::Shiftable, EtaStability::Shiftable, EtaStability::Shiftable)),
+    case03_strict_relation: (0., Some(RelationType::Strict), (EtaStability::Locked, EtaStability::Locked, EtaStability::Locked)),
+    case04_any_relation: (0., Some(RelationType::Any), (EtaStability::Shiftable, EtaStability::Shiftable, EtaStability::Shiftable)),
+}
+
+fn can_report_eta_stability_impl(
+    max_slack: f64,
+    relation_type: Option<RelationType>,
+    expected: (EtaStability, EtaStability, EtaStability),
+) {
+    let relations = relation_type.map(|type_field| {
+        vec![Relation {
+            type_field,
+            jobs: to_strings(vec!["departure", "job1", "job2", "job3"]),
+            vehicle_id: "my_vehicle_1".to_string(),
+            shift_index: None,
+        }]
+    });
+    let problem = create_problem(Some(EtaConfidence { max_slack }), relations);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let etas = solution.extras.and_then(|extras| extras.etas).expect("no etas");
+    assert_eq!(
+        etas,
+        vec![
+            create_activity_eta("job1", 1., 1., expected.0),
+            create_activity_eta("job2", 2., 1., expected.1),
+            create_activity_eta("job3", 3., 997., expected.2),
+        ]
+    );
+}
+
+#[test]
+fn can_skip_etas_when_not_requested() {
+    let problem = create_problem(None, None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.extras.and_then(|extras| extras.etas).is_none());
+}
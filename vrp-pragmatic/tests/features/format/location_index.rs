@@ -52,7 +52,9 @@ fn can_use_location_index() {
                 cost: 18.,
                 distance: 3,
                 duration: 5,
-                times: Timing { driving: 3, serving: 2, ..Timing::default() }
+                times: Timing { driving: 3, serving: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -100,7 +102,9 @@ fn can_use_location_index() {
                     cost: 18.,
                     distance: 3,
                     duration: 5,
-                    times: Timing { driving: 3, serving: 2, ..Timing::default() }
+                    times: Timing { driving: 3, serving: 2, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 }
             }],
             ..create_empty_solution()
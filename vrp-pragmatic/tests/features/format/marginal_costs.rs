@@ -0,0 +1,56 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_problem(marginal_costs: Option<MarginalCosts>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+            ],
+            marginal_costs,
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], ..create_default_fleet() },
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_report_marginal_costs, accuracy, {
+    can_report_marginal_costs_impl(accuracy);
+}}
+
+can_report_marginal_costs! {
+    case01_default: None,
+    case02_fast: Some(MarginalCostAccuracy::Fast),
+    case03_precise: Some(MarginalCostAccuracy::Precise),
+}
+
+fn can_report_marginal_costs_impl(accuracy: Option<MarginalCostAccuracy>) {
+    let problem = create_problem(Some(MarginalCosts { accuracy }));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let marginal_costs = solution.extras.and_then(|extras| extras.marginal_costs).expect("no marginal costs");
+    assert_eq!(
+        marginal_costs,
+        vec![
+            MarginalCost { job_id: "job1".to_string(), vehicle_id: "my_vehicle_1".to_string(), cost: 1. },
+            MarginalCost { job_id: "job2".to_string(), vehicle_id: "my_vehicle_1".to_string(), cost: 1. },
+            MarginalCost { job_id: "job3".to_string(), vehicle_id: "my_vehicle_1".to_string(), cost: 5. },
+        ]
+    );
+}
+
+#[test]
+fn can_skip_marginal_costs_when_not_requested() {
+    let problem = create_problem(None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.extras.and_then(|extras| extras.marginal_costs).is_none());
+}
@@ -1 +1,5 @@
+mod eta_confidence;
 mod location_index;
+mod marginal_costs;
+mod route_certificates;
+mod scenario;
@@ -0,0 +1,42 @@
+use crate::checker::verify_certificate;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_verify_route_certificates_of_solution() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (1., 0.), vec![(0, 100)], 1.),
+                create_pickup_job("job2", (2., 0.)),
+                create_pickup_delivery_job("job3", (3., 0.), (1., 1.)),
+                create_delivery_job("job4", (-2., 0.)),
+                create_delivery_job("job5", (-3., 1.)),
+            ],
+            certificates: Some(true),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                capacity: vec![2],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix.clone()]));
+
+    assert!(solution.unassigned.is_none());
+    let certificates = solution.extras.as_ref().and_then(|extras| extras.certificates.as_ref()).unwrap();
+    assert_eq!(certificates.len(), solution.tours.len());
+    certificates.iter().for_each(|certificate| {
+        let tour = solution.tours.iter().find(|tour| tour.vehicle_id == certificate.vehicle_id).unwrap();
+        let activities = tour.stops.iter().map(|stop| stop.activities().len()).sum::<usize>();
+        assert_eq!(certificate.transitions.len(), activities);
+        assert_eq!(verify_certificate(&problem, std::slice::from_ref(&matrix), certificate), Ok(()));
+    });
+}
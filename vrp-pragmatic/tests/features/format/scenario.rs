@@ -0,0 +1,38 @@
+use crate::scenario::*;
+
+#[test]
+fn can_run_scenario_with_route_patterns_and_statistic() {
+    Scenario::default()
+        .add_job(pickup_delivery("job1", (1., 0.), (2., 0.)))
+        .add_job(delivery("job2", (3., 0.)))
+        .add_vehicle(vehicle("car", (0., 0.), vec![2]))
+        .expect_routes(vec![RoutePattern::new(&["departure", "..", "job1", "..", "arrival"])])
+        .expect_unassigned(&[])
+        .expect_statistic(Metric::Tours, 1., Tolerance::Absolute(0.))
+        .expect_statistic(Metric::Serving, 3., Tolerance::Relative(0.))
+        .assert();
+}
+
+#[test]
+fn can_run_scenario_with_unassigned_jobs() {
+    Scenario::default()
+        .add_job(delivery("job1", (1., 0.)))
+        .add_job(delivery("job2", (2., 0.)))
+        .add_vehicle(vehicle("car", (0., 0.), vec![1]))
+        .expect_routes(vec![RoutePattern::new(&["departure", "job1", "arrival"]).with_vehicle("car_1")])
+        .expect_unassigned(&["job2"])
+        .assert();
+}
+
+#[test]
+fn can_report_unmet_expectations() {
+    let result = Scenario::default()
+        .add_job(delivery("job1", (1., 0.)))
+        .add_vehicle(vehicle("car", (0., 0.), vec![1]))
+        .with_generations(10)
+        .expect_routes(vec![RoutePattern::new(&["departure", "job2", "arrival"])])
+        .expect_statistic(Metric::Tours, 2., Tolerance::Absolute(0.5))
+        .run();
+
+    assert_eq!(result.err().map(|errors| errors.len()), Some(2));
+}
@@ -0,0 +1,80 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_bundle_job(id: &str, location: (f64, f64)) -> Job {
+    Job { bundle: Some("bundle1".to_string()), ..create_delivery_job(id, location) }
+}
+
+fn get_assigned_jobs(solution: &crate::format::solution::Solution) -> Vec<Vec<String>> {
+    let mut tours = solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let mut jobs = tour
+                .stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter())
+                .filter(|activity| activity.activity_type == "delivery")
+                .map(|activity| activity.job_id.clone())
+                .collect::<Vec<_>>();
+            jobs.sort();
+            jobs
+        })
+        .collect::<Vec<_>>();
+    tours.sort();
+
+    tours
+}
+
+#[test]
+fn can_assign_bundle_jobs_to_different_tours() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_bundle_job("job1", (1., 0.)), create_bundle_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                capacity: vec![1],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(get_assigned_jobs(&solution), vec![vec!["job1".to_string()], vec!["job2".to_string()]]);
+    assert!(solution.unassigned.is_none());
+}
+
+#[test]
+fn can_unassign_whole_bundle_when_one_job_does_not_fit() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_bundle_job("job1", (2., 0.)),
+                create_bundle_job("job2", (3., 0.)),
+                create_bundle_job("job3", (4., 0.)),
+                create_delivery_job("job4", (1., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { capacity: vec![2], ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(get_assigned_jobs(&solution), vec![vec!["job4".to_string()]]);
+    let mut unassigned = solution.unassigned.iter().flatten().map(|job| job.job_id.clone()).collect::<Vec<_>>();
+    unassigned.sort();
+    assert_eq!(unassigned, vec!["job1", "job2", "job3"]);
+}
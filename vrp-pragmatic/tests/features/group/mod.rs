@@ -1 +1,2 @@
+mod basic_bundle;
 mod basic_group;
@@ -0,0 +1,51 @@
+use crate::format::problem::*;
+use crate::format::Location;
+use crate::scenario::*;
+
+fn create_scenario(penalty: Option<f64>) -> Scenario {
+    let polygon = [(-0.01, -0.01), (0.03, -0.01), (0.03, 0.01), (-0.01, 0.01)]
+        .iter()
+        .map(|&(lat, lng)| Location::Coordinate { lat, lng })
+        .collect();
+
+    Scenario::default()
+        .add_area(Area { id: "area1".to_string(), jobs: vec![], polygon: Some(polygon) })
+        .add_job(delivery("job1", (0.01, 0.)))
+        .add_job(delivery("job2", (0.02, 0.)))
+        .add_job(delivery("job3", (0.05, 0.)))
+        .add_vehicle(VehicleType {
+            limits: Some(VehicleLimits {
+                max_distance: None,
+                shift_time: None,
+                driver_shift_time: None,
+                tour_size: None,
+                min_tour_size: None,
+                consecutive_driving: None,
+                driving_time: None,
+                areas: None,
+                allowed_areas: Some(AllowedAreas { areas: vec!["area1".to_string()], penalty }),
+                max_cost: None,
+            }),
+            ..vehicle("car", (0., 0.), vec![10])
+        })
+        .with_generations(50)
+}
+
+#[test]
+fn can_restrict_vehicle_to_polygon_area() {
+    let solution = create_scenario(None)
+        .expect_routes(vec![RoutePattern::new(&["departure", "..", "arrival"])])
+        .expect_unassigned(&["job3"])
+        .assert();
+
+    let reasons = &solution.unassigned.unwrap()[0].reasons;
+    assert_eq!(reasons[0].code, "AREA_RESTRICTION_CONSTRAINT");
+}
+
+#[test]
+fn can_serve_jobs_outside_polygon_area_with_penalty() {
+    create_scenario(Some(100.))
+        .expect_routes(vec![RoutePattern::new(&["departure", "..", "job3", "..", "arrival"])])
+        .expect_unassigned(&[])
+        .assert();
+}
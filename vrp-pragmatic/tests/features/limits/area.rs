@@ -14,8 +14,8 @@ fn can_use_constrained_areas() {
                 create_delivery_job("job5", (5., 0.)),
             ],
             areas: Some(vec![
-                Area { id: "area1".to_string(), jobs: to_strings(vec!["job1", "job5"]) },
-                Area { id: "area2".to_string(), jobs: to_strings(vec!["job2"]) },
+                Area { id: "area1".to_string(), jobs: to_strings(vec!["job1", "job5"]), polygon: None },
+                Area { id: "area2".to_string(), jobs: to_strings(vec!["job2"]), polygon: None },
             ]),
             ..create_empty_plan()
         },
@@ -30,11 +30,17 @@ fn can_use_constrained_areas() {
                 limits: Some(VehicleLimits {
                     max_distance: None,
                     shift_time: None,
+                    driver_shift_time: None,
                     tour_size: None,
+                    min_tour_size: None,
                     areas: Some(vec![
                         vec![AreaLimit { area_id: "area1".to_string(), job_value: 10. }],
                         vec![AreaLimit { area_id: "area2".to_string(), job_value: 1. }],
                     ]),
+                    consecutive_driving: None,
+                    driving_time: None,
+                    allowed_areas: None,
+                    max_cost: None,
                 }),
                 ..create_default_vehicle_type()
             }],
@@ -70,8 +76,8 @@ fn can_use_unconstrained_areas_impl(area1_job_value: f64, expected_job_ids: Vec<
                 create_delivery_job_with_demand("job5", (5., 0.), vec![2]),
             ],
             areas: Some(vec![
-                Area { id: "area1".to_string(), jobs: to_strings(vec!["job1", "job5"]) },
-                Area { id: "area2".to_string(), jobs: to_strings(vec!["job2", "job3", "job4"]) },
+                Area { id: "area1".to_string(), jobs: to_strings(vec!["job1", "job5"]), polygon: None },
+                Area { id: "area2".to_string(), jobs: to_strings(vec!["job2", "job3", "job4"]), polygon: None },
             ]),
             ..create_empty_plan()
         },
@@ -87,11 +93,17 @@ fn can_use_unconstrained_areas_impl(area1_job_value: f64, expected_job_ids: Vec<
                 limits: Some(VehicleLimits {
                     max_distance: None,
                     shift_time: None,
+                    driver_shift_time: None,
                     tour_size: None,
+                    min_tour_size: None,
                     areas: Some(vec![
                         vec![AreaLimit { area_id: "area1".to_string(), job_value: area1_job_value }],
                         vec![AreaLimit { area_id: "area2".to_string(), job_value: 1. }],
                     ]),
+                    consecutive_driving: None,
+                    driving_time: None,
+                    allowed_areas: None,
+                    max_cost: None,
                 }),
                 ..create_default_vehicle_type()
             }],
@@ -0,0 +1,57 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+#[test]
+fn can_limit_by_consecutive_driving_distance() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (5., 0.)),
+                create_delivery_job("job2", (10., 0.)),
+                create_delivery_job("job3", (15., 0.)),
+                create_delivery_job("job4", (25., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    driver_shift_time: None,
+                    tour_size: None,
+                    min_tour_size: None,
+                    consecutive_driving: Some(ConsecutiveDrivingLimit { max_distance: Some(6.), max_duration: None }),
+                    driving_time: None,
+                    areas: None,
+                    allowed_areas: None,
+                    max_cost: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]),
+        vec![vec!["departure"], vec!["job1"], vec!["job2"], vec!["job3"]]
+    );
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job4".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "CONSECUTIVE_DRIVING_CONSTRAINT".to_string(),
+                description: "cannot be assigned due to consecutive driving limit of vehicle".to_string(),
+                details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }]),
+            }]
+        }])
+    );
+}
@@ -0,0 +1,148 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_problem(jobs: Vec<Job>, driving_time: DrivingTimeLimit) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    driver_shift_time: None,
+                    tour_size: None,
+                    min_tour_size: None,
+                    consecutive_driving: None,
+                    driving_time: Some(driving_time),
+                    areas: None,
+                    allowed_areas: None,
+                    max_cost: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_take_break_during_travel() {
+    let problem = create_problem(
+        vec![create_delivery_job("job1", (15., 0.))],
+        DrivingTimeLimit { max_driving_time: 10., break_duration: 5., max_working_time: None, rest_duration: None },
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 41.,
+                distance: 15,
+                duration: 21,
+                times: Timing { driving: 15, serving: 1, break_time: 5, ..Timing::default() },
+                utilization: None,
+                emissions: None,
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        1,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0,
+                    ),
+                    Stop::Transit(TransitStop {
+                        time: Schedule {
+                            arrival: "1970-01-01T00:00:10Z".to_string(),
+                            departure: "1970-01-01T00:00:15Z".to_string(),
+                        },
+                        load: vec![1],
+                        activities: vec![Activity {
+                            job_id: "rest".to_string(),
+                            activity_type: "rest".to_string(),
+                            location: None,
+                            time: None,
+                            job_tag: None,
+                            commute: None,
+                        }],
+                    }),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (15., 0.),
+                        0,
+                        ("1970-01-01T00:00:20Z", "1970-01-01T00:00:21Z"),
+                        15,
+                    ),
+                ],
+                statistic: Statistic {
+                    cost: 41.,
+                    distance: 15,
+                    duration: 21,
+                    times: Timing { driving: 15, serving: 1, break_time: 5, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
+                },
+            }],
+            ..create_empty_solution()
+        }
+    );
+}
+
+#[test]
+fn can_take_break_and_rest_during_travel() {
+    let problem = create_problem(
+        vec![create_delivery_job("job1", (15., 0.))],
+        DrivingTimeLimit {
+            max_driving_time: 10.,
+            break_duration: 5.,
+            max_working_time: Some(12.),
+            rest_duration: Some(8.),
+        },
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]),
+        vec![vec!["departure"], vec!["rest"], vec!["rest"], vec!["job1"]]
+    );
+    assert_eq!(solution.tours[0].stops[3].schedule().arrival, "1970-01-01T00:00:28Z");
+    assert_eq!(solution.statistic.times.break_time, 13);
+}
+
+#[test]
+fn can_skip_job_which_is_late_due_to_break() {
+    let problem = create_problem(
+        vec![create_delivery_job_with_times("job1", (15., 0.), vec![(0, 18)], 1.)],
+        DrivingTimeLimit { max_driving_time: 10., break_duration: 5., max_working_time: None, rest_duration: None },
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "DRIVING_TIME_CONSTRAINT".to_string(),
+                description: "cannot be assigned due to vehicle driving time rules".to_string(),
+                details: None,
+            }]
+        }])
+    );
+}
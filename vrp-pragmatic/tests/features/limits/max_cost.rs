@@ -0,0 +1,45 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_limit_by_max_cost() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (10., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    driver_shift_time: None,
+                    tour_size: None,
+                    min_tour_size: None,
+                    areas: None,
+                    consecutive_driving: None,
+                    driving_time: None,
+                    allowed_areas: None,
+                    max_cost: Some(30.),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.cost, 20.);
+    let unassigned = solution.unassigned.expect("no unassigned jobs");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job3");
+    assert_eq!(unassigned[0].reasons[0].code, "MAX_COST_CONSTRAINT");
+}
@@ -9,7 +9,18 @@ fn can_limit_by_max_distance() {
         plan: Plan { jobs: vec![create_delivery_job("job1", (100., 0.))], ..create_empty_plan() },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                limits: Some(VehicleLimits { max_distance: Some(99.), shift_time: None, tour_size: None, areas: None }),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(99.),
+                    shift_time: None,
+                    driver_shift_time: None,
+                    tour_size: None,
+                    min_tour_size: None,
+                    areas: None,
+                    consecutive_driving: None,
+                    driving_time: None,
+                    allowed_areas: None,
+                    max_cost: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -52,10 +63,26 @@ fn can_handle_empty_route() {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (10., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.),
+                        location: (10., 0.).to_loc(),
+                        is_open: None,
+                    }),
                     ..create_default_open_vehicle_shift()
                 }],
-                limits: Some(VehicleLimits { max_distance: Some(9.), shift_time: None, tour_size: None, areas: None }),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(9.),
+                    shift_time: None,
+                    driver_shift_time: None,
+                    tour_size: None,
+                    min_tour_size: None,
+                    areas: None,
+                    consecutive_driving: None,
+                    driving_time: None,
+                    allowed_areas: None,
+                    max_cost: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -0,0 +1,68 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_test_problem(job_count: usize, min_tour_size: usize) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: (1..=job_count).map(|idx| create_delivery_job(&format!("job{}", idx), (idx as f64, 0.))).collect(),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                capacity: vec![2],
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    driver_shift_time: None,
+                    tour_size: None,
+                    min_tour_size: Some(min_tour_size),
+                    areas: None,
+                    consecutive_driving: None,
+                    driving_time: None,
+                    allowed_areas: None,
+                    max_cost: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_unassign_job_when_min_tour_size_cannot_be_reached() {
+    let problem = create_test_problem(1, 2);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    let unassigned = solution.unassigned.expect("should have unassigned job");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].reasons[0].code, "TOUR_SIZE_CONSTRAINT");
+}
+
+#[test]
+fn can_unassign_jobs_of_tour_below_min_tour_size() {
+    let problem = create_test_problem(3, 2);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].stops.len(), 4);
+    assert_eq!(solution.unassigned.map_or(0, |unassigned| unassigned.len()), 1);
+}
+
+#[test]
+fn can_use_multiple_tours_with_min_tour_size() {
+    let problem = create_test_problem(4, 2);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 2);
+    assert!(solution.unassigned.is_none());
+}
@@ -1,4 +1,10 @@
+mod allowed_areas;
 mod area;
+mod consecutive_driving;
+mod driving_time;
+mod max_cost;
 mod max_distance;
+mod min_tour_size;
 mod shift_time;
+mod total_cost;
 mod tour_size;
@@ -4,7 +4,18 @@ use crate::helpers::*;
 
 fn create_vehicle_type_with_shift_time_limit(shift_time: f64) -> VehicleType {
     VehicleType {
-        limits: Some(VehicleLimits { max_distance: None, shift_time: Some(shift_time), tour_size: None, areas: None }),
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            shift_time: Some(shift_time),
+            driver_shift_time: None,
+            tour_size: None,
+            min_tour_size: None,
+            areas: None,
+            consecutive_driving: None,
+            driving_time: None,
+            allowed_areas: None,
+            max_cost: None,
+        }),
         ..create_default_vehicle_type()
     }
 }
@@ -72,6 +83,8 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                 distance: 6,
                 duration: 36,
                 times: Timing { driving: 6, serving: 30, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -124,6 +137,8 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                     distance: 6,
                     duration: 36,
                     times: Timing { driving: 6, serving: 30, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             unassigned: Some(vec![
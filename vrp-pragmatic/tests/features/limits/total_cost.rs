@@ -0,0 +1,35 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_skip_job_exceeding_total_cost_limit() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (10., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        objectives: Some(vec![
+            vec![LimitTotalCost { max_cost: 30. }],
+            vec![MinimizeUnassignedJobs { breaks: None }],
+            vec![MinimizeCost],
+        ]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.statistic.cost <= 30.);
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        solution
+            .unassigned
+            .iter()
+            .flatten()
+            .map(|job| (job.job_id.as_str(), job.reasons[0].code.as_str()))
+            .collect::<Vec<_>>(),
+        vec![("job2", "TOTAL_COST_LIMIT_CONSTRAINT")]
+    );
+}
@@ -16,7 +16,18 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![create_default_open_vehicle_shift()],
-                limits: Some(VehicleLimits { max_distance: None, shift_time: None, areas: None, tour_size: Some(2) }),
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    driver_shift_time: None,
+                    areas: None,
+                    tour_size: Some(2),
+                    min_tour_size: None,
+                    consecutive_driving: None,
+                    driving_time: None,
+                    allowed_areas: None,
+                    max_cost: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -35,6 +46,8 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
                 distance: 2,
                 duration: 4,
                 times: Timing { driving: 2, serving: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -71,6 +84,8 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
                     distance: 2,
                     duration: 4,
                     times: Timing { driving: 2, serving: 2, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             unassigned: Some(vec![UnassignedJob {
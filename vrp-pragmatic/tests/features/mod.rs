@@ -14,7 +14,9 @@ mod pickdev;
 mod priorities;
 mod relations;
 mod reload;
+mod reservation;
 mod skills;
+mod territories;
 mod timing;
 mod unassigned;
 mod work_balance;
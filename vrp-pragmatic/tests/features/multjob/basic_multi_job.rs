@@ -31,6 +31,8 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                 distance: 16,
                 duration: 20,
                 times: Timing { driving: 16, serving: 4, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -94,6 +96,8 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                     distance: 16,
                     duration: 20,
                     times: Timing { driving: 16, serving: 4, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -127,6 +131,8 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                 distance: 12,
                 duration: 15,
                 times: Timing { driving: 12, serving: 3, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -182,6 +188,8 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                     distance: 12,
                     duration: 15,
                     times: Timing { driving: 12, serving: 3, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -17,7 +17,12 @@ fn can_assign_replacement_job() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (4., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (4., 0.).to_loc(),
+                        is_open: None,
+                    }),
                     ..create_default_vehicle_shift()
                 }],
                 ..create_default_vehicle_type()
@@ -38,6 +43,8 @@ fn can_assign_replacement_job() {
                 distance: 4,
                 duration: 7,
                 times: Timing { driving: 4, serving: 3, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -90,6 +97,8 @@ fn can_assign_replacement_job() {
                     distance: 4,
                     duration: 7,
                     times: Timing { driving: 4, serving: 3, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -0,0 +1,44 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_serve_jobs_in_loading_order, (order, expected), {
+    can_serve_jobs_in_loading_order_impl(order, expected);
+}}
+
+can_serve_jobs_in_loading_order! {
+    case01_lifo: (VehicleLoadingOrder::Lifo, vec!["job1", "job2", "job2", "job1"]),
+    case02_fifo: (VehicleLoadingOrder::Fifo, vec!["job1", "job2", "job1", "job2"]),
+}
+
+fn can_serve_jobs_in_loading_order_impl(order: VehicleLoadingOrder, expected: Vec<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job("job1", (1., 0.), (3., 0.)),
+                create_pickup_delivery_job("job2", (2., 0.), (4., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                loading: Some(VehicleLoading { manifest: None, order: Some(order), backhaul: None }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let ids = get_ids_from_tour(&solution.tours[0])
+        .into_iter()
+        .flatten()
+        .filter(|id| id.starts_with("job"))
+        .collect::<Vec<_>>();
+    assert_eq!(ids, expected);
+}
@@ -2,5 +2,8 @@ mod basic_multi_job;
 mod basic_replacement;
 mod basic_service;
 mod limited_capacity;
+mod loading_order;
+mod ride_duration;
 mod single_type_places;
 mod unassigned_multi_job;
+mod visit_gap;
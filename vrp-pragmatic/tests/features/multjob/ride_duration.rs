@@ -0,0 +1,65 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_job_with_max_ride_duration(id: &str, pickup: (f64, f64), delivery: (f64, f64), max_duration: f64) -> Job {
+    Job { max_ride_duration: Some(max_duration), ..create_pickup_delivery_job(id, pickup, delivery) }
+}
+
+#[test]
+fn can_serve_other_job_outside_of_ride() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_max_ride_duration("job1", (1., 0.), (3., 0.), 5.),
+                create_delivery_job_with_duration("job2", (2., 0.), 10.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let ids = get_ids_from_tour(&solution.tours[0]).into_iter().flatten().collect::<Vec<_>>();
+    let (pickup_idx, delivery_idx) =
+        (ids.iter().position(|id| id == "job1").unwrap(), ids.iter().rposition(|id| id == "job1").unwrap());
+    let other_idx = ids.iter().position(|id| id == "job2").unwrap();
+    assert!(other_idx < pickup_idx || other_idx > delivery_idx);
+}
+
+#[test]
+fn can_unassign_job_when_max_ride_duration_cannot_be_respected() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_job_with_max_ride_duration("job1", (1., 0.), (10., 0.), 5.)],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic::default(),
+            tours: vec![],
+            unassigned: Some(vec![UnassignedJob {
+                job_id: "job1".to_string(),
+                reasons: vec![UnassignedJobReason {
+                    code: "RIDE_DURATION_CONSTRAINT".to_string(),
+                    description: "cannot be assigned due to max ride duration of job".to_string(),
+                    details: None,
+                }]
+            }]),
+            ..create_empty_solution()
+        }
+    );
+}
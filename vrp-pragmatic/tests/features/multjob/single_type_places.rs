@@ -31,6 +31,8 @@ fn can_use_only_deliveries_as_static_demand() {
                 distance: 8,
                 duration: 11,
                 times: Timing { driving: 8, serving: 3, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -69,6 +71,8 @@ fn can_use_only_deliveries_as_static_demand() {
                     distance: 8,
                     duration: 11,
                     times: Timing { driving: 8, serving: 3, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -86,7 +90,12 @@ fn can_use_only_pickups_as_static_demand() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (10., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (10., 0.).to_loc(),
+                        is_open: None,
+                    }),
                     ..create_default_vehicle_shift()
                 }],
                 ..create_default_vehicle_type()
@@ -107,6 +116,8 @@ fn can_use_only_pickups_as_static_demand() {
                 distance: 10,
                 duration: 13,
                 times: Timing { driving: 10, serving: 3, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -153,6 +164,8 @@ fn can_use_only_pickups_as_static_demand() {
                     distance: 10,
                     duration: 13,
                     times: Timing { driving: 10, serving: 3, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
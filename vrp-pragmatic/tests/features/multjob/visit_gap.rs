@@ -0,0 +1,77 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_job_with_visit_gap(id: &str, location: (f64, f64), min: Option<f64>, max: Option<f64>) -> Job {
+    Job {
+        services: Some(vec![
+            JobTask { demand: None, ..create_task(location, Some("drop".to_string())) },
+            JobTask { demand: None, ..create_task(location, Some("collect".to_string())) },
+        ]),
+        visit_gap: Some(JobVisitGap { min, max }),
+        ..create_job(id)
+    }
+}
+
+#[test]
+fn can_serve_other_job_between_visits_with_min_gap() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_visit_gap("equipment", (1., 0.), Some(10.), None),
+                create_delivery_job_with_duration("job2", (5., 0.), 10.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]),
+        vec![
+            vec!["departure".to_string()],
+            vec!["equipment".to_string()],
+            vec!["job2".to_string()],
+            vec!["equipment".to_string()],
+            vec!["arrival".to_string()]
+        ]
+    );
+}
+
+#[test]
+fn can_unassign_job_when_visit_gap_cannot_be_respected() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_job_with_visit_gap("equipment", (1., 0.), Some(10.), Some(20.))],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic::default(),
+            tours: vec![],
+            unassigned: Some(vec![UnassignedJob {
+                job_id: "equipment".to_string(),
+                reasons: vec![UnassignedJobReason {
+                    code: "VISIT_GAP_CONSTRAINT".to_string(),
+                    description: "cannot be assigned due to visit gap of job".to_string(),
+                    details: None,
+                }]
+            }]),
+            ..create_empty_solution()
+        }
+    );
+}
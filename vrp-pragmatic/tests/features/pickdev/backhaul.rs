@@ -0,0 +1,58 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_problem(backhaul: Option<VehicleBackhaul>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_pickup_job("job1", (1., 0.)), create_delivery_job("job2", (5., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                loading: backhaul.map(|backhaul| VehicleLoading {
+                    manifest: None,
+                    order: None,
+                    backhaul: Some(backhaul),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_job_ids(solution: &Solution) -> Vec<String> {
+    solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| activity.job_id.clone())
+        .collect()
+}
+
+#[test]
+fn can_serve_deliveries_before_pickups_with_strict_backhaul() {
+    let problem = create_problem(Some(VehicleBackhaul { penalty: None }));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_job_ids(&solution), vec!["departure", "job2", "job1"]);
+    assert_eq!(solution.statistic.distance, 9);
+}
+
+#[test]
+fn can_serve_pickups_before_deliveries_without_backhaul() {
+    let problem = create_problem(None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_job_ids(&solution), vec!["departure", "job1", "job2"]);
+    assert_eq!(solution.statistic.distance, 5);
+}
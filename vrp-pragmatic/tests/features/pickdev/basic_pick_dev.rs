@@ -21,6 +21,8 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                 distance: 4,
                 duration: 6,
                 times: Timing { driving: 4, serving: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -67,6 +69,8 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                     distance: 4,
                     duration: 6,
                     times: Timing { driving: 4, serving: 2, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
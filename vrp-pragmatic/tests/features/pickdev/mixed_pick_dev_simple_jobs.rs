@@ -28,6 +28,8 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                 distance: 8,
                 duration: 12,
                 times: Timing { driving: 8, serving: 4, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -90,6 +92,8 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                     distance: 8,
                     duration: 12,
                     times: Timing { driving: 8, serving: 4, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 }
             }],
             ..create_empty_solution()
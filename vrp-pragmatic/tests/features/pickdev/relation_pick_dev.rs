@@ -39,6 +39,8 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                 distance: 50,
                 duration: 54,
                 times: Timing { driving: 50, serving: 4, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -103,6 +105,8 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                     distance: 50,
                     duration: 54,
                     times: Timing { driving: 50, serving: 4, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
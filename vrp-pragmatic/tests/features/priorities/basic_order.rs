@@ -15,14 +15,25 @@ fn create_test_plan_with_three_jobs() -> Plan {
 }
 
 fn create_test_limit() -> Option<VehicleLimits> {
-    Some(VehicleLimits { max_distance: Some(15.), shift_time: None, tour_size: None, areas: None })
+    Some(VehicleLimits {
+        max_distance: Some(15.),
+        shift_time: None,
+        driver_shift_time: None,
+        tour_size: None,
+        min_tour_size: None,
+        areas: None,
+        consecutive_driving: None,
+        driving_time: None,
+        allowed_areas: None,
+        max_cost: None,
+    })
 }
 
 fn create_order_objective(is_constrained: bool) -> Vec<Vec<Objective>> {
     vec![
         vec![Objective::MinimizeUnassignedJobs { breaks: None }],
         vec![Objective::MinimizeTours {}],
-        vec![Objective::TourOrder { is_constrained }],
+        vec![Objective::TourOrder { is_constrained, is_graded: None }],
         vec![Objective::MinimizeCost],
     ]
 }
@@ -55,6 +66,8 @@ fn can_follow_orders() {
                 distance: 20,
                 duration: 23,
                 times: Timing { driving: 20, serving: 3, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -107,6 +120,8 @@ fn can_follow_orders() {
                     distance: 20,
                     duration: 23,
                     times: Timing { driving: 20, serving: 3, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -126,6 +141,80 @@ fn can_assign_more_jobs_ignoring_order_when_is_not_constrained() {
     assert_eq!(solution.statistic.distance, 14);
 }
 
+#[test]
+fn can_report_order_violations_when_is_not_constrained() {
+    let problem = Problem {
+        objectives: Some(vec![
+            vec![Objective::MinimizeUnassignedJobs { breaks: None }],
+            vec![Objective::MinimizeTours {}],
+            vec![Objective::TourOrder { is_constrained: false, is_graded: Some(true) }],
+            vec![Objective::MinimizeCost],
+        ]),
+        ..create_problem(false, create_test_limit())
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        solution.extras.and_then(|extras| extras.tour_order),
+        Some(TourOrderReport {
+            count: 1,
+            magnitude: 1.,
+            tours: vec![TourOrderViolation {
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: 0,
+                count: 1,
+                magnitude: 1.
+            }]
+        })
+    );
+}
+
+parameterized_test! {can_use_graded_order_violations, (is_graded, expected_ids, expected_magnitude), {
+    can_use_graded_order_violations_impl(is_graded, expected_ids, expected_magnitude);
+}}
+
+can_use_graded_order_violations! {
+    case01_graded: (true, vec!["job1", "job3", "job2"], 4.),
+    case02_counted: (false, vec!["job1", "job2", "job3"], 9.),
+}
+
+fn can_use_graded_order_violations_impl(is_graded: bool, expected_ids: Vec<&str>, expected_magnitude: f64) {
+    // NOTE job1 has to be served first due to its time window, so any tour has one violation: either
+    // with job2 (order 10) or with job3 (order 1). The latter is cheaper by distance.
+    let mut first_job = create_delivery_job_with_times("job1", (1., 0.), vec![(0, 2)], 1.);
+    first_job.deliveries.iter_mut().flatten().for_each(|task| task.order = Some(5));
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                first_job,
+                create_delivery_job_with_order("job2", (2., 0.), 10),
+                create_delivery_job_with_order("job3", (-5., 0.), 1),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], ..create_default_fleet() },
+        objectives: Some(vec![
+            vec![Objective::MinimizeUnassignedJobs { breaks: None }],
+            vec![Objective::MinimizeTours {}],
+            vec![Objective::TourOrder { is_constrained: false, is_graded: Some(is_graded) }],
+            vec![Objective::MinimizeCost],
+        ]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let ids = get_ids_from_tour(&solution.tours[0]).into_iter().flatten().collect::<Vec<_>>();
+    assert_eq!(ids[1..ids.len() - 1].to_vec(), expected_ids);
+    let report = solution.extras.and_then(|extras| extras.tour_order).expect("no tour order report");
+    assert_eq!((report.count, report.magnitude), (1, expected_magnitude));
+}
+
 #[test]
 fn can_follow_order_with_default_objective() {
     let problem = Problem { objectives: None, ..create_problem(false, create_test_limit()) };
@@ -163,9 +252,19 @@ fn can_follow_order_when_prioritized_property_set() {
 fn can_handle_order_between_special_activities() {
     let create_test_job = |id: &str, location: (f64, f64), order: i32| Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: location.to_loc(), duration: 100., tag: None }],
+            places: vec![JobPlace {
+                times: None,
+                location: location.to_loc(),
+                duration: 100.,
+                tag: None,
+                duration_per_unit: None,
+                lateness_penalty: None,
+                post_service_buffer: None,
+                recurring_times: None,
+            }],
             demand: Some(vec![1]),
             order: Some(order),
+            named_demand: None,
         }]),
         ..create_job(id)
     };
@@ -177,7 +276,12 @@ fn can_handle_order_between_special_activities() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (10., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (10., 0.).to_loc(),
+                        is_open: None,
+                    }),
                     breaks: Some(vec![VehicleBreak::Optional {
                         time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(100.), format_time(200.)]),
                         places: vec![VehicleOptionalBreakPlace {
@@ -1,2 +1,4 @@
 mod basic_order;
 mod basic_value;
+mod unassigned_penalty;
+mod zone_sequence;
@@ -0,0 +1,50 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_prefer_jobs_with_higher_unassigned_penalty, (penalty, expected_assigned, expected_unassigned), {
+    can_prefer_jobs_with_higher_unassigned_penalty_impl(penalty, expected_assigned, expected_unassigned);
+}}
+
+can_prefer_jobs_with_higher_unassigned_penalty! {
+    case01_no_penalty: (None, vec!["job1"], vec!["job2"]),
+    case02_low_penalty: (Some(0.5), vec!["job1"], vec!["job2"]),
+    case03_high_penalty: (Some(5.), vec!["job2"], vec!["job1"]),
+}
+
+fn can_prefer_jobs_with_higher_unassigned_penalty_impl(
+    penalty: Option<f64>,
+    expected_assigned: Vec<&str>,
+    expected_unassigned: Vec<&str>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                Job { unassigned_penalty: penalty, ..create_delivery_job("job2", (2., 0.)) },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { capacity: vec![1], ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let mut assigned = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| activity.job_id.as_str())
+        .filter(|job_id| job_id.starts_with("job"))
+        .collect::<Vec<_>>();
+    assigned.sort();
+    let mut unassigned = solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>();
+    unassigned.sort();
+    assert_eq!(assigned, expected_assigned);
+    assert_eq!(unassigned, expected_unassigned);
+}
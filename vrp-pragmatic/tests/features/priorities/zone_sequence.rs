@@ -0,0 +1,27 @@
+use crate::format::problem::*;
+use crate::scenario::*;
+
+fn create_area(id: &str, job_id: &str) -> Area {
+    Area { id: id.to_string(), jobs: vec![job_id.to_string()], polygon: None }
+}
+
+#[test]
+fn can_serve_zones_in_preferred_order() {
+    // NOTE both directions of the tour have the same cost, so only zone sequence decides the order
+    Scenario::default()
+        .add_job(delivery("frozen_job", (0.01, 0.)))
+        .add_job(delivery("chilled_job", (0.02, 0.)))
+        .add_job(delivery("ambient_job", (0.03, 0.)))
+        .add_area(create_area("frozen", "frozen_job"))
+        .add_area(create_area("chilled", "chilled_job"))
+        .add_area(create_area("ambient", "ambient_job"))
+        .add_zone_sequence(ZoneSequence {
+            areas: vec!["ambient".to_string(), "chilled".to_string(), "frozen".to_string()],
+            penalty: 1000.,
+        })
+        .add_vehicle(vehicle("car", (0., 0.), vec![10]))
+        .with_generations(50)
+        .expect_routes(vec![RoutePattern::new(&["departure", "ambient_job", "chilled_job", "frozen_job", "arrival"])])
+        .expect_unassigned(&[])
+        .assert();
+}
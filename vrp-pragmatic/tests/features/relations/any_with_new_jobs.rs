@@ -45,6 +45,8 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                 distance: 3,
                 duration: 6,
                 times: Timing { driving: 3, serving: 3, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -89,6 +91,8 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                     distance: 3,
                     duration: 6,
                     times: Timing { driving: 3, serving: 3, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -46,6 +46,8 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                 distance: 18,
                 duration: 25,
                 times: Timing { driving: 18, serving: 7, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -130,6 +132,8 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                     distance: 18,
                     duration: 25,
                     times: Timing { driving: 18, serving: 7, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
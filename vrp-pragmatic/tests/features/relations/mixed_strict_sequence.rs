@@ -46,6 +46,8 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                 distance: 22,
                 duration: 29,
                 times: Timing { driving: 22, serving: 7, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -130,6 +132,8 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                     distance: 22,
                     duration: 29,
                     times: Timing { driving: 22, serving: 7, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -202,6 +206,8 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                 distance: 26,
                 duration: 34,
                 times: Timing { driving: 26, serving: 8, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![
                 Tour {
@@ -255,6 +261,8 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                         distance: 13,
                         duration: 17,
                         times: Timing { driving: 13, serving: 4, ..Timing::default() },
+                        utilization: None,
+                        emissions: None,
                     },
                 },
                 Tour {
@@ -308,6 +316,8 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                         distance: 13,
                         duration: 17,
                         times: Timing { driving: 13, serving: 4, ..Timing::default() },
+                        utilization: None,
+                        emissions: None,
                     },
                 }
             ],
@@ -43,6 +43,8 @@ fn can_use_sequence_relation_with_strict_time_windows() {
                 distance: 100,
                 duration: 160,
                 times: Timing { driving: 100, serving: 50, waiting: 10, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -111,6 +113,8 @@ fn can_use_sequence_relation_with_strict_time_windows() {
                     distance: 100,
                     duration: 160,
                     times: Timing { driving: 100, serving: 50, waiting: 10, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -56,6 +56,8 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                 distance: 42,
                 duration: 52,
                 times: Timing { driving: 42, serving: 10, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![
                 Tour {
@@ -125,6 +127,8 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                         distance: 22,
                         duration: 27,
                         times: Timing { driving: 22, serving: 5, ..Timing::default() },
+                        utilization: None,
+                        emissions: None,
                     },
                 },
                 Tour {
@@ -194,6 +198,8 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                         distance: 20,
                         duration: 25,
                         times: Timing { driving: 20, serving: 5, ..Timing::default() },
+                        utilization: None,
+                        emissions: None,
                     },
                 }
             ],
@@ -54,6 +54,8 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                 distance: 34,
                 duration: 42,
                 times: Timing { driving: 34, serving: 8, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![
                 Tour {
@@ -115,6 +117,8 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                         distance: 20,
                         duration: 24,
                         times: Timing { driving: 20, serving: 4, ..Timing::default() },
+                        utilization: None,
+                        emissions: None,
                     },
                 },
                 Tour {
@@ -176,6 +180,8 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                         distance: 14,
                         duration: 18,
                         times: Timing { driving: 14, serving: 4, ..Timing::default() },
+                        utilization: None,
+                        emissions: None,
                     },
                 }
             ],
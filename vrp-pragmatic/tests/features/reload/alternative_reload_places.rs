@@ -0,0 +1,63 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_use_the_cheapest_alternative_reload_location() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (10., 0.)),
+                create_delivery_job("job2", (11., 0.)),
+                create_delivery_job("job3", (20., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (22., 0.).to_loc(),
+                        is_open: None,
+                    }),
+                    dispatch: None,
+                    breaks: None,
+                    reloads: Some(vec![VehicleReload {
+                        location: (50., 0.).to_loc(),
+                        duration: 2.0,
+                        alternatives: Some(vec![(12., 0.).to_loc()]),
+                        ..create_default_reload()
+                    }]),
+                    recharges: None,
+                    trailer_parkings: None,
+                    maintenance: None,
+                    depots: None,
+                    overtime: None,
+                    unloading: None,
+                    relays: None,
+                }],
+                capacity: vec![2],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let reload_locations = solution.tours[0]
+        .stops
+        .iter()
+        .filter_map(|stop| stop.as_point())
+        .filter(|stop| stop.activities.iter().any(|activity| activity.activity_type == "reload"))
+        .map(|stop| stop.location.to_lat_lng())
+        .collect::<Vec<_>>();
+    assert_eq!(reload_locations, vec![(12., 0.)]);
+}
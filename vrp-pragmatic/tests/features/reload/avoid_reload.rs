@@ -29,7 +29,12 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (0., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.),
+                        location: (0., 0.).to_loc(),
+                        is_open: None,
+                    }),
                     dispatch: None,
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
@@ -37,6 +42,13 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                         duration: 2.0,
                         ..create_default_reload()
                     }]),
+                    recharges: None,
+                    trailer_parkings: None,
+                    maintenance: None,
+                    depots: None,
+                    overtime: None,
+                    unloading: None,
+                    relays: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -57,6 +69,8 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                 distance: 16,
                 duration: 20,
                 times: Timing { driving: 16, serving: 4, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -120,6 +134,8 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                     distance: 16,
                     duration: 20,
                     times: Timing { driving: 16, serving: 4, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -36,7 +36,12 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (0., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.),
+                        location: (0., 0.).to_loc(),
+                        is_open: None,
+                    }),
                     dispatch: None,
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
@@ -44,6 +49,13 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                         duration: 2.0,
                         ..create_default_reload()
                     }]),
+                    recharges: None,
+                    trailer_parkings: None,
+                    maintenance: None,
+                    depots: None,
+                    overtime: None,
+                    unloading: None,
+                    relays: None,
                 }],
                 capacity: vec![1],
                 ..create_default_vehicle_type()
@@ -64,6 +76,8 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 4, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -116,6 +130,8 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 4, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             unassigned,
@@ -19,7 +19,12 @@ fn can_use_reloads_with_different_locations() {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (32., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (32., 0.).to_loc(),
+                        is_open: None,
+                    }),
                     dispatch: None,
                     breaks: None,
                     reloads: Some(vec![
@@ -36,6 +41,13 @@ fn can_use_reloads_with_different_locations() {
                             ..create_default_reload()
                         },
                     ]),
+                    recharges: None,
+                    trailer_parkings: None,
+                    maintenance: None,
+                    depots: None,
+                    overtime: None,
+                    unloading: None,
+                    relays: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
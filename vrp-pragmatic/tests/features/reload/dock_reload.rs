@@ -0,0 +1,60 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+use crate::parse_time;
+
+#[test]
+fn can_limit_simultaneous_reloads_by_docks() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (5., 0.)),
+                create_delivery_job("job2", (6., 0.)),
+                create_delivery_job("job3", (7., 0.)),
+                create_delivery_job("job4", (8., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                shifts: vec![VehicleShift {
+                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(60.),
+                        location: (0., 0.).to_loc(),
+                        is_open: None,
+                    }),
+                    reloads: Some(vec![VehicleReload {
+                        location: (0., 0.).to_loc(),
+                        duration: 10.,
+                        dock_id: Some("depot_docks".to_string()),
+                        ..create_default_reload()
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                capacity: vec![1],
+                ..create_default_vehicle_type()
+            }],
+            resources: Some(vec![VehicleResource::Dock { id: "depot_docks".to_string(), capacity: 1 }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    let reloads = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .filter(|stop| stop.activities().iter().any(|activity| activity.activity_type == "reload"))
+        .map(|stop| parse_time(&stop.schedule().departure))
+        .collect::<Vec<_>>();
+    assert_eq!(reloads.len(), 2);
+    assert!((reloads[0] - reloads[1]).abs() >= 10.);
+}
@@ -0,0 +1,69 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_delivery_job_with_placement(id: &str, location: (f64, f64), placement: JobPlacement) -> Job {
+    Job { placement: Some(placement), ..create_delivery_job(id, location) }
+}
+
+#[test]
+fn can_deliver_rear_placed_items_first() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_placement("job1", (1., 0.), JobPlacement::Front),
+                create_delivery_job_with_placement("job2", (2., 0.), JobPlacement::Middle),
+                create_delivery_job_with_placement("job3", (3., 0.), JobPlacement::Rear),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]),
+        vec![vec!["departure"], vec!["job3"], vec!["job2"], vec!["job1"]]
+    );
+}
+
+#[test]
+fn can_reorder_items_with_same_placement_freely() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_placement("job1", (1., 0.), JobPlacement::Rear),
+                create_delivery_job_with_placement("job2", (2., 0.), JobPlacement::Rear),
+                create_delivery_job_with_placement("job3", (3., 0.), JobPlacement::Front),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]),
+        vec![vec!["departure"], vec!["job1"], vec!["job2"], vec!["job3"]]
+    );
+}
@@ -0,0 +1,62 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_return_loading_manifest_for_trips, (manifest, expected), {
+    can_return_loading_manifest_for_trips_impl(manifest, expected);
+}}
+
+can_return_loading_manifest_for_trips! {
+    case01_requested: (Some(true), true),
+    case02_not_requested: (Some(false), false),
+    case03_not_specified: (None, false),
+}
+
+fn can_return_loading_manifest_for_trips_impl(manifest: Option<bool>, expected: bool) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: (1..=4).map(|idx| create_delivery_job(&format!("job{}", idx), (idx as f64, 0.))).collect(),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![VehicleReload {
+                        location: (0., 0.).to_loc(),
+                        duration: 2.0,
+                        ..create_default_reload()
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                capacity: vec![2],
+                loading: manifest.map(|manifest| VehicleLoading {
+                    manifest: Some(manifest),
+                    order: None,
+                    backhaul: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let manifests = solution.extras.as_ref().and_then(|extras| extras.loading.clone());
+    if !expected {
+        assert!(manifests.is_none());
+        return;
+    }
+
+    let manifests = manifests.expect("no loading manifests");
+    assert_eq!(manifests.len(), 1);
+    assert_eq!(manifests[0].vehicle_id, "my_vehicle_1");
+    let deliveries = get_ids_from_tour(&solution.tours[0])
+        .split(|stop| stop.iter().any(|id| id == "reload"))
+        .map(|trip| trip.iter().flatten().filter(|id| id.starts_with("job")).rev().cloned().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(manifests[0].trips, deliveries);
+    assert_eq!(manifests[0].trips.iter().map(|trip| trip.len()).collect::<Vec<_>>(), vec![2, 2]);
+}
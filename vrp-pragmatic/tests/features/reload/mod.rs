@@ -1,8 +1,15 @@
+mod alternative_reload_places;
 mod avoid_reload;
 mod basic_reload;
 mod diff_reload_places;
+mod dock_reload;
+mod job_placement;
+mod loading_manifest;
 mod multi_dim_reload;
 mod multi_job_reload;
 mod multi_vehicle_reload;
+mod named_dimensions_reload;
 mod picks_devs_reload;
+mod returns_unloading;
 mod shared_reload;
+mod trip_capacity_reload;
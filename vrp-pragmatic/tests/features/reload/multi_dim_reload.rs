@@ -17,7 +17,12 @@ fn can_use_multi_dim_capacity() {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (0., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.),
+                        location: (0., 0.).to_loc(),
+                        is_open: None,
+                    }),
                     reloads: Some(vec![VehicleReload {
                         location: (0., 0.).to_loc(),
                         duration: 2.0,
@@ -44,6 +49,8 @@ fn can_use_multi_dim_capacity() {
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 4, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -96,6 +103,8 @@ fn can_use_multi_dim_capacity() {
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 4, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -24,7 +24,12 @@ fn can_serve_multi_job_and_delivery_with_reload() {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (10., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.),
+                        location: (10., 0.).to_loc(),
+                        is_open: None,
+                    }),
                     dispatch: None,
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
@@ -32,6 +37,13 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                         duration: 2.0,
                         ..create_default_reload()
                     }]),
+                    recharges: None,
+                    trailer_parkings: None,
+                    maintenance: None,
+                    depots: None,
+                    overtime: None,
+                    unloading: None,
+                    relays: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -52,6 +64,8 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                 distance: 14,
                 duration: 22,
                 times: Timing { driving: 14, serving: 8, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -139,6 +153,8 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                     distance: 14,
                     duration: 22,
                     times: Timing { driving: 14, serving: 8, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -163,7 +179,7 @@ fn can_properly_handle_load_without_capacity_violation() {
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: Some(20.0), distance: 0.002, time: 0.003 },
+                costs: VehicleCosts { fixed: Some(20.0), distance: 0.002, time: 0.003, waiting: None },
                 shifts: vec![VehicleShift {
                     reloads: Some(vec![
                         VehicleReload {
@@ -15,7 +15,12 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (0., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.),
+                        location: (0., 0.).to_loc(),
+                        is_open: None,
+                    }),
                     dispatch: None,
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
@@ -23,6 +28,13 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                         duration: 2.0,
                         ..create_default_reload()
                     }]),
+                    recharges: None,
+                    trailer_parkings: None,
+                    maintenance: None,
+                    depots: None,
+                    overtime: None,
+                    unloading: None,
+                    relays: None,
                 }],
                 capacity: vec![1],
                 ..create_default_vehicle_type()
@@ -43,6 +55,8 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 4, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -95,6 +109,8 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 4, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -0,0 +1,99 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_named_loads(weight: i32, pallets: i32) -> Option<Vec<NamedLoad>> {
+    Some(vec![
+        NamedLoad { dimension: "weight".to_string(), value: weight },
+        NamedLoad { dimension: "pallets".to_string(), value: pallets },
+    ])
+}
+
+fn create_named_delivery_job(id: &str, location: (f64, f64)) -> Job {
+    Job {
+        deliveries: Some(vec![JobTask {
+            demand: None,
+            named_demand: create_named_loads(1, 1),
+            ..create_task(location, None)
+        }]),
+        ..create_job(id)
+    }
+}
+
+parameterized_test! {can_reload_only_reloadable_dimensions, (pallets, is_reloadable, expected_unassigned), {
+    can_reload_only_reloadable_dimensions_impl(pallets, is_reloadable, expected_unassigned);
+}}
+
+can_reload_only_reloadable_dimensions! {
+    case01_enough_non_reloadable: (2, false, 0),
+    case02_not_enough_non_reloadable: (1, false, 1),
+    case03_not_enough_reloadable: (1, true, 0),
+}
+
+fn can_reload_only_reloadable_dimensions_impl(pallets: i32, is_reloadable: bool, expected_unassigned: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_named_delivery_job("job1", (1., 0.)), create_named_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![VehicleReload { location: (0., 0.).to_loc(), ..create_default_reload() }]),
+                    ..create_default_vehicle_shift()
+                }],
+                capacity: vec![],
+                named_capacity: create_named_loads(1, pallets),
+                ..create_default_vehicle_type()
+            }],
+            dimensions: Some(vec![
+                LoadDimension { name: "weight".to_string(), is_reloadable: None },
+                LoadDimension { name: "pallets".to_string(), is_reloadable: Some(is_reloadable) },
+            ]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.map_or(0, |unassigned| unassigned.len()), expected_unassigned);
+    assert_eq!(solution.tours.len(), 1);
+    assert!(solution.statistic.utilization.is_none());
+}
+
+#[test]
+fn can_report_utilization_per_dimension() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_named_delivery_job("job1", (1., 0.)), create_named_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                capacity: vec![],
+                named_capacity: create_named_loads(4, 8),
+                ..create_default_vehicle_type()
+            }],
+            dimensions: Some(vec![
+                LoadDimension { name: "weight".to_string(), is_reloadable: None },
+                LoadDimension { name: "pallets".to_string(), is_reloadable: None },
+            ]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        solution.tours[0].statistic.utilization,
+        Some(vec![
+            LoadUtilization { dimension: "weight".to_string(), max_load: 2, capacity: 4, ratio: 0.5 },
+            LoadUtilization { dimension: "pallets".to_string(), max_load: 2, capacity: 8, ratio: 0.25 },
+        ])
+    );
+}
@@ -20,7 +20,12 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (6., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.),
+                        location: (6., 0.).to_loc(),
+                        is_open: None,
+                    }),
                     dispatch: None,
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
@@ -28,6 +33,13 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                         duration: 2.0,
                         ..create_default_reload()
                     }]),
+                    recharges: None,
+                    trailer_parkings: None,
+                    maintenance: None,
+                    depots: None,
+                    overtime: None,
+                    unloading: None,
+                    relays: None,
                 }],
                 capacity: vec![1],
                 ..create_default_vehicle_type()
@@ -109,6 +121,8 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                 distance: 6,
                 duration: 12,
                 times: Timing { driving: 6, serving: 6, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
         }]
     );
@@ -119,6 +133,8 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
             distance: 6,
             duration: 12,
             times: Timing { driving: 6, serving: 6, ..Timing::default() },
+            utilization: None,
+            emissions: None,
         }
     );
     assert!(solution.violations.is_none());
@@ -0,0 +1,49 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_unload_returns_at_shift_end, (is_pickup, expected_duration), {
+    can_unload_returns_at_shift_end_impl(is_pickup, expected_duration);
+}}
+
+can_unload_returns_at_shift_end! {
+    case01_pickup: (true, 27),
+    case02_delivery: (false, 21),
+}
+
+fn can_unload_returns_at_shift_end_impl(is_pickup: bool, expected_duration: i64) {
+    let job = if is_pickup { create_pickup_job("job1", (10., 0.)) } else { create_delivery_job("job1", (10., 0.)) };
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    unloading: Some(VehicleUnloading { duration: 6., tag: None, dock_id: None }),
+                    relays: None,
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let activity_types = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| activity.activity_type.as_str())
+        .collect::<Vec<_>>();
+    let expected_types = if is_pickup {
+        vec!["departure", "pickup", "unloading", "arrival"]
+    } else {
+        vec!["departure", "delivery", "arrival"]
+    };
+    assert_eq!(activity_types, expected_types);
+    assert_eq!(solution.statistic.duration, expected_duration);
+}
@@ -49,6 +49,7 @@ fn can_consume_limited_resource_with_single_vehicle_impl(
                         (0..reloads)
                             .map(|_| VehicleReload {
                                 resource_id: Some("resource_1".to_string()),
+                                alternatives: None,
                                 ..create_default_reload()
                             })
                             .collect(),
@@ -111,6 +112,7 @@ fn can_consume_limited_resource_with_two_vehicles_impl(
                         end: if is_open_shift { None } else { create_default_vehicle_shift().end },
                         reloads: Some(vec![VehicleReload {
                             resource_id: Some(id.to_string()),
+                            alternatives: None,
                             ..create_default_reload()
                         }]),
                         ..create_default_vehicle_shift()
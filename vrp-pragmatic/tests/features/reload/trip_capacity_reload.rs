@@ -0,0 +1,54 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_limit_trip_capacity_after_reload, (trip_capacity, expected_assigned), {
+    can_limit_trip_capacity_after_reload_impl(trip_capacity, expected_assigned);
+}}
+
+can_limit_trip_capacity_after_reload! {
+    case01_no_trip_capacity: (None, 4),
+    case02_limited_trip_capacity: (Some(vec![1]), 3),
+}
+
+fn can_limit_trip_capacity_after_reload_impl(trip_capacity: Option<Vec<i32>>, expected_assigned: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+                create_delivery_job("job4", (4., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![VehicleReload {
+                        location: (0., 0.).to_loc(),
+                        duration: 2.0,
+                        capacity: trip_capacity,
+                        ..create_default_reload()
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                capacity: vec![2],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let assigned = solution
+        .tours
+        .iter()
+        .flat_map(|tour| get_ids_from_tour(tour).into_iter().flatten())
+        .filter(|id| id.starts_with("job"))
+        .count();
+    assert_eq!(assigned, expected_assigned);
+    assert_eq!(solution.unassigned.map_or(0, |unassigned| unassigned.len()), 4 - expected_assigned);
+}
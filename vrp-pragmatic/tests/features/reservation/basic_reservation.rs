@@ -0,0 +1,129 @@
+use crate::checker::CheckerContext;
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use std::cmp::Ordering;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::models::Problem as CoreProblem;
+use vrp_core::rosomaxa::prelude::Objective;
+use vrp_core::utils::Environment;
+
+fn create_reservation_job(id: &str, location: (f64, f64)) -> Job {
+    Job { reservation: Some(true), ..create_delivery_job(id, location) }
+}
+
+parameterized_test! {can_displace_reservation_jobs_by_real_ones, (capacity, expected_reservations), {
+    can_displace_reservation_jobs_by_real_ones_impl(capacity, expected_reservations);
+}}
+
+can_displace_reservation_jobs_by_real_ones! {
+    case01_no_capacity_left: (2, vec!["reservation1", "reservation2"]),
+    case02_partial_capacity_left: (3, vec!["reservation2"]),
+    case03_enough_capacity: (4, vec![]),
+}
+
+fn can_displace_reservation_jobs_by_real_ones_impl(capacity: i32, expected_reservations: Vec<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_reservation_job("reservation1", (1., 0.)),
+                create_reservation_job("reservation2", (10., 0.)),
+                create_delivery_job("job1", (5., 0.)),
+                create_delivery_job("job2", (8., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { capacity: vec![capacity], ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_iterations(problem, Some(vec![matrix]), 1000);
+
+    assert!(solution.unassigned.is_none());
+    let mut reservations = solution
+        .extras
+        .iter()
+        .flat_map(|extras| extras.reservations.iter().flatten())
+        .map(|job| job.job_id.as_str())
+        .collect::<Vec<_>>();
+    reservations.sort_unstable();
+    assert_eq!(reservations, expected_reservations);
+}
+
+fn create_test_problem() -> (Problem, Matrix) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_reservation_job("reservation1", (1., 0.)), create_delivery_job("job1", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { capacity: vec![1], ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    (problem, matrix)
+}
+
+fn create_test_solution(job_id: &str, location: (f64, f64)) -> Solution {
+    let time = ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z");
+
+    Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity("departure", "departure", (0., 0.), 1, time, 0),
+                create_stop_with_activity(job_id, "delivery", location, 0, time, 0),
+                create_stop_with_activity("arrival", "arrival", (0., 0.), 0, time, 0),
+            ],
+            statistic: Default::default(),
+        }],
+        ..create_empty_solution()
+    }
+}
+
+fn create_insertion_ctx(core_problem: Arc<CoreProblem>, solution: &Solution) -> InsertionContext {
+    let environment = Arc::new(Environment::default());
+    let core_solution = to_core_solution(solution, core_problem.clone(), environment.random.clone())
+        .expect("cannot create core solution");
+
+    InsertionContext::new_from_solution(core_problem, (core_solution, None), environment)
+}
+
+#[test]
+fn can_prefer_real_job_over_reservation() {
+    let (problem, matrix) = create_test_problem();
+    let core_problem = Arc::new((problem, vec![matrix]).read_pragmatic().unwrap());
+
+    let with_real_job = create_insertion_ctx(core_problem.clone(), &create_test_solution("job1", (2., 0.)));
+    let with_reservation = create_insertion_ctx(core_problem.clone(), &create_test_solution("reservation1", (1., 0.)));
+
+    assert_eq!(core_problem.objective.total_order(&with_real_job, &with_reservation), Ordering::Less);
+}
+
+#[test]
+fn can_report_unassigned_reservation_separately() {
+    let (problem, matrix) = create_test_problem();
+    let core_problem = Arc::new((problem.clone(), vec![matrix.clone()]).read_pragmatic().unwrap());
+    let insertion_ctx = create_insertion_ctx(core_problem.clone(), &create_test_solution("job1", (2., 0.)));
+
+    let solution =
+        create_solution(core_problem.as_ref(), &insertion_ctx.solution.to_solution(core_problem.extras.clone()), None);
+
+    assert!(solution.unassigned.is_none());
+    let reservations =
+        solution.extras.as_ref().and_then(|extras| extras.reservations.as_ref()).expect("no reservations reported");
+    assert_eq!(reservations.len(), 1);
+    assert_eq!(reservations[0].job_id, "reservation1");
+    let result = CheckerContext::new(core_problem, problem, Some(vec![matrix]), solution).and_then(|ctx| ctx.check());
+    assert_eq!(result, Ok(()));
+}
@@ -40,6 +40,8 @@ fn can_wait_for_job_start() {
                 distance: 18,
                 duration: 19,
                 times: Timing { driving: 18, serving: 1, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "vehicle_with_skill_1".to_string(),
@@ -76,6 +78,8 @@ fn can_wait_for_job_start() {
                     distance: 18,
                     duration: 19,
                     times: Timing { driving: 18, serving: 1, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
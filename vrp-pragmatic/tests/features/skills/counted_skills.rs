@@ -0,0 +1,73 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_installer_vehicle(type_id: &str, location: (f64, f64), skill_counts: Option<usize>) -> VehicleType {
+    VehicleType {
+        type_id: type_id.to_string(),
+        vehicle_ids: vec![format!("{}_1", type_id)],
+        shifts: vec![create_default_vehicle_shift_with_locations(location, location)],
+        skills: Some(vec!["installer".to_string()]),
+        skill_counts: skill_counts.map(|count| vec![SkillCount { skill: "installer".to_string(), count }]),
+        ..create_default_vehicle_type()
+    }
+}
+
+#[test]
+fn can_assign_job_to_vehicle_with_enough_skill_units() {
+    let skills = JobSkills {
+        all_of: None,
+        one_of: None,
+        none_of: None,
+        preferred: None,
+        counts: Some(vec![SkillCount { skill: "installer".to_string(), count: 2 }]),
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_skills("job1", (1., 0.), skills.clone()),
+                create_delivery_job_with_skills("job2", (2., 0.), skills),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_installer_vehicle("single_installer", (0., 0.), None),
+                create_installer_vehicle("two_installers", (10., 0.), Some(2)),
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].vehicle_id, "two_installers_1");
+}
+
+#[test]
+fn can_leave_job_unassigned_when_vehicle_has_not_enough_skill_units() {
+    let skills = JobSkills {
+        all_of: None,
+        one_of: None,
+        none_of: None,
+        preferred: None,
+        counts: Some(vec![SkillCount { skill: "installer".to_string(), count: 3 }]),
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job_with_skills("job1", (1., 0.), skills)], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_installer_vehicle("two_installers", (0., 0.), Some(2))],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(solution.unassigned.map_or(0, |unassigned| unassigned.len()), 1);
+}
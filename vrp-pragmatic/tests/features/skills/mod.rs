@@ -1,2 +1,5 @@
 mod basic_skill;
+mod counted_skills;
+mod preferred_skills;
+mod skill_overlay;
 mod unassigned_due_to_skills;
@@ -0,0 +1,45 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_use_preferred_skills, (penalty, expected_vehicle_id), {
+    can_use_preferred_skills_impl(penalty, expected_vehicle_id);
+}}
+
+can_use_preferred_skills! {
+    case01_high_penalty: (100., "vehicle_with_skill_1"),
+    case02_zero_penalty: (0., "my_vehicle_1"),
+}
+
+fn can_use_preferred_skills_impl(penalty: f64, expected_vehicle_id: &str) {
+    let skills = JobSkills {
+        all_of: None,
+        one_of: None,
+        none_of: None,
+        preferred: Some(vec![PreferredSkill { skill: "german".to_string(), penalty }]),
+        counts: None,
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job_with_skills("job1", (1., 0.), skills)], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("my_vehicle"),
+                VehicleType {
+                    type_id: "vehicle_with_skill".to_string(),
+                    vehicle_ids: vec!["vehicle_with_skill_1".to_string()],
+                    shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
+                    skills: Some(vec!["german".to_string()]),
+                    ..create_default_vehicle_type()
+                },
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].vehicle_id, expected_vehicle_id);
+}
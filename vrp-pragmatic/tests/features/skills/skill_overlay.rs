@@ -0,0 +1,84 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::construction::constraints::ConstraintOverride;
+use vrp_core::models::Problem as CoreProblem;
+use vrp_core::rosomaxa::evolution::TelemetryMode;
+use vrp_core::solver::{create_default_config_builder, Solver};
+use vrp_core::utils::Environment;
+
+fn create_test_problem() -> (Problem, Matrix) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_skills("job1", (1., 0.), all_of_skills(vec!["unique_skill".to_string()])),
+                create_delivery_job("job2", (2., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle_without_skill")], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    (problem, matrix)
+}
+
+fn solve_core_problem(core_problem: CoreProblem) -> Solution {
+    let core_problem = Arc::new(core_problem);
+    let environment = Arc::new(Environment::default());
+
+    let (core_solution, _, _) = create_default_config_builder(core_problem.clone(), environment, TelemetryMode::None)
+        .with_max_generations(Some(100))
+        .build()
+        .map(|config| Solver::new(core_problem.clone(), config))
+        .and_then(|solver| solver.solve())
+        .expect("cannot solve the problem");
+
+    create_solution(&core_problem, &core_solution, None)
+}
+
+parameterized_test! {can_override_skill_constraint, (value, expected_cost), {
+    can_override_skill_constraint_impl(value, expected_cost);
+}}
+
+can_override_skill_constraint! {
+    case01_disable: (ConstraintOverride::Disable, 20.),
+    case02_soften: (ConstraintOverride::Soften(1000.), 20.),
+}
+
+fn can_override_skill_constraint_impl(value: ConstraintOverride, expected_cost: f64) {
+    let (problem, matrix) = create_test_problem();
+    let core_problem = (problem, vec![matrix]).read_pragmatic().unwrap();
+
+    let core_problem = create_overlaid_problem(&core_problem, &[("SKILL_CONSTRAINT", value)]).unwrap();
+    let solution = solve_core_problem(core_problem);
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.cost, expected_cost);
+}
+
+#[test]
+fn can_keep_skill_constraint_when_other_one_is_overridden() {
+    let (problem, matrix) = create_test_problem();
+    let core_problem = (problem, vec![matrix]).read_pragmatic().unwrap();
+
+    let core_problem =
+        create_overlaid_problem(&core_problem, &[("CAPACITY_CONSTRAINT", ConstraintOverride::Disable)]).unwrap();
+    let solution = solve_core_problem(core_problem);
+
+    assert_eq!(solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job1"]);
+    assert_eq!(solution.unassigned.unwrap()[0].reasons[0].code, "SKILL_CONSTRAINT");
+}
+
+#[test]
+fn can_reject_unknown_constraint_code() {
+    let (problem, matrix) = create_test_problem();
+    let core_problem = (problem, vec![matrix]).read_pragmatic().unwrap();
+
+    let result = create_overlaid_problem(&core_problem, &[("UNKNOWN_CONSTRAINT", ConstraintOverride::Disable)]);
+
+    assert_eq!(result.err(), Some("unknown constraint reason code: 'UNKNOWN_CONSTRAINT'".to_string()));
+}
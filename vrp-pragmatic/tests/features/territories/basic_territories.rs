@@ -0,0 +1,104 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_problem(capacity: i32, is_strict: Option<bool>, cross_zone_penalty: Option<f64>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            areas: Some(vec![Area { id: "area1".to_string(), jobs: vec!["job1".to_string()], polygon: None }]),
+            territories: Some(vec![Territory {
+                id: "territory1".to_string(),
+                areas: vec!["area1".to_string()],
+                vehicles: vec!["territory_vehicle_1".to_string()],
+                is_strict,
+                cross_zone_penalty,
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("my_vehicle"),
+                VehicleType {
+                    type_id: "territory_vehicle".to_string(),
+                    vehicle_ids: vec!["territory_vehicle_1".to_string()],
+                    shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
+                    capacity: vec![capacity],
+                    ..create_default_vehicle_type()
+                },
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_territory_report(solution: &Solution) -> TerritoryReport {
+    solution.extras.as_ref().and_then(|extras| extras.territories.as_ref()).expect("no territory report")[0].clone()
+}
+
+parameterized_test! {can_prefer_territory_vehicle, (is_strict, cross_zone_penalty), {
+    can_prefer_territory_vehicle_impl(is_strict, cross_zone_penalty);
+}}
+
+can_prefer_territory_vehicle! {
+    case01_not_strict: (None, None),
+    case02_strict: (Some(true), None),
+    case03_pinned: (None, Some(1000.)),
+}
+
+fn can_prefer_territory_vehicle_impl(is_strict: Option<bool>, cross_zone_penalty: Option<f64>) {
+    let problem = create_problem(10, is_strict, cross_zone_penalty);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let territory_tour = solution.tours.iter().find(|tour| tour.vehicle_id == "territory_vehicle_1").unwrap();
+    assert!(get_ids_from_tour(territory_tour).iter().flatten().any(|id| id == "job1"));
+    assert_eq!(
+        get_territory_report(&solution),
+        TerritoryReport { territory_id: "territory1".to_string(), jobs: 1, served: 1, violations: vec![] }
+    );
+}
+
+parameterized_test! {can_report_territory_violation, cross_zone_penalty, {
+    can_report_territory_violation_impl(cross_zone_penalty);
+}}
+
+can_report_territory_violation! {
+    case01_not_pinned: None,
+    case02_pinned: Some(1000.),
+}
+
+fn can_report_territory_violation_impl(cross_zone_penalty: Option<f64>) {
+    let problem = create_problem(0, None, cross_zone_penalty);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_territory_report(&solution),
+        TerritoryReport {
+            territory_id: "territory1".to_string(),
+            jobs: 1,
+            served: 1,
+            violations: vec![TerritoryViolation { job_id: "job1".to_string(), vehicle_id: "my_vehicle_1".to_string() }],
+        }
+    );
+}
+
+#[test]
+fn can_keep_job_unassigned_in_strict_territory() {
+    let problem = create_problem(0, Some(true), None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job1"]);
+    assert_eq!(
+        get_territory_report(&solution),
+        TerritoryReport { territory_id: "territory1".to_string(), jobs: 1, served: 0, violations: vec![] }
+    );
+}
@@ -0,0 +1,76 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format::Location;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_alternative_place(location: (f64, f64), duration: f64, times: Option<(f64, f64)>, tag: &str) -> JobPlace {
+    JobPlace {
+        duration,
+        times: times.map(|(start, end)| vec![vec![format_time(start), format_time(end)]]),
+        ..create_job_place(location, Some(tag.to_string()))
+    }
+}
+
+fn create_problem(places: Vec<JobPlace>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask { places, demand: Some(vec![1]), order: None, named_demand: None }]),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], ..create_default_fleet() },
+        ..create_empty_problem()
+    }
+}
+
+fn get_job_activity(solution: &Solution) -> (Activity, Location) {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .filter_map(|stop| match stop {
+            Stop::Point(point) => Some(point),
+            Stop::Transit(_) => None,
+        })
+        .flat_map(|point| point.activities.iter().map(move |activity| (activity, &point.location)))
+        .find(|(activity, _)| activity.job_id == "job1")
+        .map(|(activity, location)| (activity.clone(), location.clone()))
+        .expect("cannot find job activity")
+}
+
+parameterized_test! {can_use_alternative_place, (places, expected_tag, expected_location), {
+    can_use_alternative_place_impl(places, expected_tag, expected_location);
+}}
+
+can_use_alternative_place! {
+    case01_cheapest_place: (
+        vec![create_alternative_place((10., 0.), 1., None, "home"), create_alternative_place((2., 0.), 1., None, "locker")],
+        "locker", (2., 0.)
+    ),
+    case02_feasible_place: (
+        vec![
+            create_alternative_place((10., 0.), 1., None, "home"),
+            create_alternative_place((2., 0.), 1., Some((0., 1.)), "locker"),
+        ],
+        "home", (10., 0.)
+    ),
+    case03_same_location_shorter_duration: (
+        vec![create_alternative_place((5., 0.), 100., None, "door"), create_alternative_place((5., 0.), 10., None, "reception")],
+        "reception", (5., 0.)
+    ),
+}
+
+fn can_use_alternative_place_impl(places: Vec<JobPlace>, expected_tag: &str, expected_location: (f64, f64)) {
+    let problem = create_problem(places);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let (activity, location) = get_job_activity(&solution);
+    assert_eq!(activity.job_tag, Some(expected_tag.to_string()));
+    assert_eq!(location, expected_location.to_loc());
+}
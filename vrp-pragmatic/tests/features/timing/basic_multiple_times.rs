@@ -30,6 +30,8 @@ fn can_use_multiple_times() {
                 distance: 100,
                 duration: 130,
                 times: Timing { driving: 100, serving: 0, waiting: 30, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -98,6 +100,8 @@ fn can_use_multiple_times() {
                     distance: 100,
                     duration: 130,
                     times: Timing { driving: 100, serving: 0, waiting: 30, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -28,6 +28,8 @@ fn can_wait_for_job_start() {
                 distance: 4,
                 duration: 12,
                 times: Timing { driving: 4, serving: 0, waiting: 8, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -72,6 +74,8 @@ fn can_wait_for_job_start() {
                     distance: 4,
                     duration: 12,
                     times: Timing { driving: 4, serving: 0, waiting: 8, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -101,6 +105,8 @@ fn can_skip_initial_waiting() {
                 distance: 2,
                 duration: 12,
                 times: Timing { driving: 2, serving: 10, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -137,6 +143,8 @@ fn can_skip_initial_waiting() {
                     distance: 2,
                     duration: 12,
                     times: Timing { driving: 2, serving: 10, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -179,6 +187,8 @@ fn can_consider_latest_departure_time() {
                 distance: 2,
                 duration: 16,
                 times: Timing { driving: 2, serving: 10, waiting: 4, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -215,9 +225,47 @@ fn can_consider_latest_departure_time() {
                     distance: 2,
                     duration: 16,
                     times: Timing { driving: 2, serving: 10, waiting: 4, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
         }
     );
 }
+
+parameterized_test! {can_use_waiting_cost, (waiting_cost, expected_cost), {
+    can_use_waiting_cost_impl(waiting_cost, expected_cost);
+}}
+
+can_use_waiting_cost! {
+    case01_default: (None, 26.),
+    case02_expensive: (Some(3.), 42.),
+    case03_free: (Some(0.), 18.),
+}
+
+fn can_use_waiting_cost_impl(waiting_cost: Option<f64>, expected_cost: f64) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (1., 0.), vec![(0, 1)], 0.),
+                create_delivery_job_with_times("job2", (2., 0.), vec![(10, 20)], 0.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { waiting: waiting_cost, ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.statistic.times.waiting, 8);
+    assert_eq!(solution.statistic.cost, expected_cost);
+}
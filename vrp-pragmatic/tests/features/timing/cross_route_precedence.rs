@@ -0,0 +1,76 @@
+use crate::format::problem::*;
+use crate::format::solution::Solution;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_job(id: &str, location: (f64, f64), end: Option<f64>, predecessors: Vec<&str>) -> Job {
+    let mut job = create_service_job(id, location);
+    job.services.as_mut().unwrap()[0].places[0].times = end.map(|end| vec![vec![format_time(0.), format_time(end)]]);
+
+    Job {
+        predecessors: if predecessors.is_empty() {
+            None
+        } else {
+            Some(predecessors.into_iter().map(|id| id.to_string()).collect())
+        },
+        ..job
+    }
+}
+
+fn create_precedence_problem(jobs: Vec<Job>) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+            resources: None,
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_departure(solution: &Solution, job_id: &str) -> Option<String> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .filter_map(|stop| stop.as_point())
+        .find(|stop| stop.activities.iter().any(|activity| activity.job_id == job_id))
+        .map(|stop| stop.time.departure.clone())
+}
+
+#[test]
+fn can_start_job_after_predecessor_served_by_another_vehicle() {
+    let problem = create_precedence_problem(vec![
+        create_job("job1", (5., 0.), None, vec![]),
+        create_job("job2", (1., 0.), Some(8.), vec!["job1"]),
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    assert_eq!(get_departure(&solution, "job1"), Some(format_time(6.)));
+    assert_eq!(get_departure(&solution, "job2"), Some(format_time(7.)));
+}
+
+#[test]
+fn can_unassign_job_when_it_cannot_start_after_predecessor() {
+    let problem = create_precedence_problem(vec![
+        create_job("job1", (5., 0.), None, vec![]),
+        create_job("job2", (1., 0.), Some(5.), vec!["job1"]),
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_departure(&solution, "job1"), Some(format_time(6.)));
+    let unassigned = solution.unassigned.unwrap().into_iter().map(|job| job.job_id).collect::<Vec<_>>();
+    assert_eq!(unassigned, vec!["job2".to_string()]);
+}
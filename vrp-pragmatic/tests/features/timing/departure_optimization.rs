@@ -0,0 +1,28 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_optimize_departure_time() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (1., 0.), vec![(0, 100)], 0.),
+                create_delivery_job_with_times("job2", (2., 0.), vec![(10, 20)], 0.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { optimize_departure: Some(true), ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].stops.first().unwrap().schedule().departure, "1970-01-01T00:00:08Z");
+    assert_eq!(solution.statistic.times.waiting, 0);
+}
@@ -0,0 +1,60 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+#[test]
+fn can_share_job_approach_at_the_same_location() {
+    let approach = || Some(JobApproach { duration: 2., distance: Some(10.) });
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { approach: approach(), ..create_delivery_job_with_duration("job1", (1., 0.), 1.) },
+                Job { approach: approach(), ..create_delivery_job_with_duration("job2", (1., 0.), 1.) },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        solution.statistic,
+        Statistic {
+            cost: 38.,
+            distance: 2,
+            duration: 6,
+            times: Timing { driving: 2, serving: 4, ..Timing::default() },
+            utilization: None,
+            emissions: None,
+        }
+    );
+    let stop = solution.tours[0].stops[1].as_point().unwrap();
+    assert_eq!(stop.time.arrival, "1970-01-01T00:00:01Z");
+    assert_eq!(stop.time.departure, "1970-01-01T00:00:05Z");
+    assert_eq!(stop.activities[0].time.as_ref().unwrap().start, "1970-01-01T00:00:03Z");
+}
+
+#[test]
+fn can_respect_job_approach_in_time_window() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                approach: Some(JobApproach { duration: 2., distance: None }),
+                ..create_delivery_job_with_times("job1", (1., 0.), vec![(0, 2)], 1.)
+            }],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 0);
+    assert_eq!(solution.unassigned.map_or(0, |unassigned| unassigned.len()), 1);
+}
@@ -1,4 +1,15 @@
+mod alternative_places;
 mod basic_multiple_times;
 mod basic_waiting_time;
+mod cross_route_precedence;
+mod departure_optimization;
+mod job_approach;
+mod post_service_buffer;
+mod recurring_times;
+mod service_blackout;
+mod soft_time_window;
 mod strict_leads_to_unassigned;
 mod strict_split_into_two_tours;
+mod synchronized_visits;
+mod time_dependent_routing;
+mod unload_rate;
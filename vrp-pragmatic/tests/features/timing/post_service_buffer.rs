@@ -0,0 +1,82 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+#[test]
+fn can_consider_post_service_buffer_in_schedule() {
+    let mut job1 = create_delivery_job_with_duration("job1", (1., 0.), 1.);
+    job1.deliveries.as_mut().unwrap()[0].places[0].post_service_buffer = Some(3.);
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![job1, create_delivery_job_with_times("job2", (2., 0.), vec![(0, 5)], 1.)],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 23.,
+                distance: 4,
+                duration: 9,
+                times: Timing { driving: 4, serving: 2, waiting: 3, ..Timing::default() },
+                utilization: None,
+                emissions: None,
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        2,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0
+                    ),
+                    create_stop_with_activity(
+                        "job2",
+                        "delivery",
+                        (2., 0.),
+                        1,
+                        ("1970-01-01T00:00:02Z", "1970-01-01T00:00:03Z"),
+                        2
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (1., 0.),
+                        0,
+                        ("1970-01-01T00:00:04Z", "1970-01-01T00:00:08Z"),
+                        3
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:00:09Z", "1970-01-01T00:00:09Z"),
+                        4
+                    ),
+                ],
+                statistic: Statistic {
+                    cost: 23.,
+                    distance: 4,
+                    duration: 9,
+                    times: Timing { driving: 4, serving: 2, waiting: 3, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
+                },
+            }],
+            ..create_empty_solution()
+        }
+    );
+}
@@ -0,0 +1,53 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_job_with_recurring_times(id: &str, location: (f64, f64), days: &[&str], times: (&str, &str)) -> Job {
+    let mut job = create_delivery_job(id, location);
+    job.deliveries.as_mut().unwrap()[0].places[0].recurring_times = Some(vec![RecurringTimeWindow {
+        days: days.iter().map(|day| day.to_string()).collect(),
+        start: times.0.to_string(),
+        end: times.1.to_string(),
+    }]);
+
+    job
+}
+
+#[test]
+fn can_use_recurring_times() {
+    let mut shift = create_default_vehicle_shift();
+    shift.end.as_mut().unwrap().latest = format_time(2. * 86400.);
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_recurring_times("job1", (1., 0.), &["fri", "sat"], ("00:00:10", "00:00:20")),
+                create_job_with_recurring_times("job2", (2., 0.), &["mon", "thu"], ("00:00:05", "00:00:08")),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { shifts: vec![shift], ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    // NOTE service takes one second and starts within time window, but a vehicle can arrive earlier
+    let get_departure = |job_id: &str| {
+        solution.tours[0]
+            .stops
+            .iter()
+            .find(|stop| stop.activities().iter().any(|activity| activity.job_id == job_id))
+            .map(|stop| stop.schedule().departure.clone())
+            .unwrap()
+    };
+    let job1 = get_departure("job1");
+    let job2 = get_departure("job2");
+    assert!(("1970-01-02T00:00:11Z".to_string().."1970-01-02T00:00:22Z".to_string()).contains(&job1));
+    assert!(("1970-01-01T00:00:06Z".to_string().."1970-01-01T00:00:10Z".to_string()).contains(&job2));
+}
@@ -0,0 +1,33 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_wait_for_service_blackout_end() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (15., 0.)),
+                create_delivery_job_with_times("job2", (15., 0.), vec![(12, 18)], 1.),
+            ],
+            blackouts: Some(vec![ServiceBlackout { time: vec![format_time(10.), format_time(20.)], areas: None }]),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let stop = solution.tours[0].stops.get(1).and_then(|stop| stop.as_point()).unwrap();
+    assert_eq!(stop.activities.len(), 1);
+    assert_eq!(stop.activities[0].job_id, "job1");
+    assert_eq!(stop.time, Schedule { arrival: format_time(20.), departure: format_time(21.) });
+
+    let unassigned = solution.unassigned.unwrap();
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job2");
+}
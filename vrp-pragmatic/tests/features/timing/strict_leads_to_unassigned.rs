@@ -30,6 +30,8 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                 distance: 80,
                 duration: 80,
                 times: Timing { driving: 80, serving: 0, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -90,6 +92,8 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                     distance: 80,
                     duration: 80,
                     times: Timing { driving: 80, serving: 0, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             unassigned: Some(vec![UnassignedJob {
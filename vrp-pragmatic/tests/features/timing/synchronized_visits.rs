@@ -0,0 +1,81 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_sync_job(id: &str, location: (f64, f64), times: Option<(f64, f64)>) -> Job {
+    let mut job = create_service_job(id, location);
+    job.services.as_mut().unwrap()[0].places[0].times =
+        times.map(|(start, end)| vec![vec![format_time(start), format_time(end)]]);
+
+    Job { sync: Some(JobSync { key: "install".to_string(), offset: None }), ..job }
+}
+
+fn create_sync_problem(jobs: Vec<Job>) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+            resources: None,
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_serve_synchronized_jobs_by_different_vehicles_at_the_same_time() {
+    let problem = create_sync_problem(vec![
+        create_sync_job("job1", (3., 0.), None),
+        create_sync_job("job2", (6., 0.), None),
+        create_delivery_job("job3", (1., 0.)),
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    let departures = ["job1", "job2"]
+        .iter()
+        .map(|job_id| {
+            solution
+                .tours
+                .iter()
+                .flat_map(|tour| tour.stops.iter())
+                .filter_map(|stop| stop.as_point())
+                .find(|stop| stop.activities.iter().any(|activity| activity.job_id == *job_id))
+                .map(|stop| stop.time.departure.clone())
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(departures, vec![format_time(7.), format_time(7.)]);
+}
+
+#[test]
+fn can_unassign_synchronized_jobs_when_they_cannot_be_aligned() {
+    let problem = create_sync_problem(vec![
+        create_sync_job("job1", (3., 0.), Some((0., 4.))),
+        create_sync_job("job2", (8., 0.), None),
+        create_delivery_job("job3", (1., 0.)),
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let mut unassigned = solution
+        .unassigned
+        .unwrap()
+        .into_iter()
+        .map(|job| (job.job_id, job.reasons.first().map(|reason| reason.code.clone()).unwrap()))
+        .collect::<Vec<_>>();
+    unassigned.sort();
+    assert_eq!(
+        unassigned,
+        vec![("job1".to_string(), "SYNC_CONSTRAINT".to_string()), ("job2".to_string(), "SYNC_CONSTRAINT".to_string())]
+    );
+}
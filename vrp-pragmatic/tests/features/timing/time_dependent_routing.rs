@@ -0,0 +1,85 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_time_aware_matrix(timestamp: &str, travel_times: Vec<i64>) -> Matrix {
+    Matrix {
+        profile: Some("car".to_string()),
+        timestamp: Some(timestamp.to_string()),
+        travel_times,
+        distances: vec![0, 1, 1, 0],
+        error_codes: None,
+    }
+}
+
+#[test]
+fn can_use_travel_time_of_rush_hour() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", (1., 0.), vec![(200, 200)], 0.)],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrices = vec![
+        create_time_aware_matrix("1970-01-01T00:00:00Z", vec![0, 1, 1, 0]),
+        create_time_aware_matrix("1970-01-01T00:01:40Z", vec![0, 10, 10, 0]),
+    ];
+
+    let solution = solve_with_metaheuristic(problem, Some(matrices));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 32.,
+                distance: 2,
+                duration: 20,
+                times: Timing { driving: 20, ..Timing::default() },
+                utilization: None,
+                emissions: None,
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        1,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:03:10Z"),
+                        0
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (1., 0.),
+                        0,
+                        ("1970-01-01T00:03:20Z", "1970-01-01T00:03:20Z"),
+                        1
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:03:30Z", "1970-01-01T00:03:30Z"),
+                        2
+                    )
+                ],
+                statistic: Statistic {
+                    cost: 32.,
+                    distance: 2,
+                    duration: 20,
+                    times: Timing { driving: 20, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
+                },
+            }],
+            ..create_empty_solution()
+        }
+    );
+}
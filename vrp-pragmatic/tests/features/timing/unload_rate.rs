@@ -0,0 +1,86 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+fn create_job_with_demand(id: &str, location: (f64, f64), demand: i32) -> Job {
+    let mut job = create_delivery_job_with_duration(id, location, 1.);
+    job.deliveries.as_mut().unwrap()[0].demand = Some(vec![demand]);
+
+    job
+}
+
+fn get_service_time(tour: &Tour) -> f64 {
+    let time = tour.stops[1].schedule();
+
+    parse_time(&time.departure) - parse_time(&time.arrival)
+}
+
+#[test]
+fn can_use_vehicle_specific_duration_per_unit() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_job_with_demand("job1", (1., 0.), 2), create_job_with_demand("job2", (-1., 0.), 2)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType { duration_per_unit: Some(10.), ..create_vehicle_with_capacity("slow", vec![2]) },
+                VehicleType { duration_per_unit: Some(1.), ..create_vehicle_with_capacity("fast", vec![2]) },
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    let get_tour = |type_id: &str| solution.tours.iter().find(|tour| tour.type_id == type_id).unwrap();
+    assert_eq!(get_service_time(get_tour("slow")), 21.);
+    assert_eq!(get_service_time(get_tour("fast")), 3.);
+}
+
+#[test]
+fn can_prefer_job_duration_per_unit_over_vehicle_one() {
+    let mut job = create_job_with_demand("job1", (1., 0.), 2);
+    job.deliveries.as_mut().unwrap()[0].places[0].duration_per_unit = Some(2.);
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { duration_per_unit: Some(10.), ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(get_service_time(&solution.tours[0]), 5.);
+}
+
+#[test]
+fn can_apply_duration_per_unit_to_first_dimension_only() {
+    let mut job = create_delivery_job_with_duration("job1", (1., 0.), 1.);
+    job.deliveries.as_mut().unwrap()[0].demand = Some(vec![2, 3]);
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                duration_per_unit: Some(10.),
+                ..create_vehicle_with_capacity("v", vec![2, 3])
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_service_time(&solution.tours[0]), 21.);
+}
@@ -0,0 +1,62 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::solution::Tour;
+use crate::helpers::*;
+
+fn get_tour_value(tour: &Tour) -> f64 {
+    tour.stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| match activity.job_id.as_str() {
+            "big" => 4.,
+            "departure" | "arrival" => 0.,
+            _ => 1.,
+        })
+        .sum()
+}
+
+#[test]
+fn can_balance_value() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_value("big", (1., 0.), 4.),
+                create_delivery_job_with_value("small1", (2., 0.), 1.),
+                create_delivery_job_with_value("small2", (2., 0.), 1.),
+                create_delivery_job_with_value("small3", (9., 0.), 1.),
+                create_delivery_job_with_value("small4", (9., 0.), 1.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType {
+                    vehicle_ids: vec!["my_vehicle1".to_string()],
+                    capacity: vec![4],
+                    ..create_default_vehicle_type()
+                },
+                VehicleType {
+                    type_id: "my_vehicle2".to_string(),
+                    vehicle_ids: vec!["my_vehicle2".to_string()],
+                    shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
+                    capacity: vec![4],
+                    ..create_default_vehicle_type()
+                },
+            ],
+            ..create_default_fleet()
+        },
+        objectives: Some(vec![
+            vec![MinimizeUnassignedJobs { breaks: None }],
+            vec![BalanceValue { options: None }],
+            vec![MinimizeCost],
+        ]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    assert!(solution.tours.iter().all(|tour| get_tour_value(tour) == 4.));
+}
@@ -0,0 +1,73 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+fn create_test_problem(objectives: Vec<Vec<Objective>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (1., 0.), vec![(0, 100)], 1.),
+                create_delivery_job_with_times("job2", (2., 0.), vec![(0, 100)], 1.),
+                create_delivery_job_with_times("job3", (3., 0.), vec![(0, 100)], 1.),
+                create_delivery_job_with_times("job4", (4., 0.), vec![(0, 100)], 1.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                shifts: vec![create_default_open_vehicle_shift()],
+                capacity: vec![4],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    }
+}
+
+fn get_max_arrival(solution: &Solution) -> f64 {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter().skip(1))
+        .map(|stop| parse_time(&stop.schedule().arrival))
+        .fold(0., f64::max)
+}
+
+parameterized_test! {can_minimize_max_waiting_time, (percentile, expected_tours, expected_max_arrival), {
+    can_minimize_max_waiting_time_impl(percentile, expected_tours, expected_max_arrival);
+}}
+
+can_minimize_max_waiting_time! {
+    case01_without_objective: (None, 1, 7.),
+    case02_max: (Some(None), 2, 5.),
+    case03_percentile: (Some(Some(1.)), 2, 5.),
+}
+
+fn can_minimize_max_waiting_time_impl(
+    percentile: Option<Option<f64>>,
+    expected_tours: usize,
+    expected_max_arrival: f64,
+) {
+    let objectives = percentile
+        .map(|percentile| {
+            vec![
+                vec![MinimizeUnassignedJobs { breaks: None }],
+                vec![MinimizeMaxWaitingTime { percentile }],
+                vec![MinimizeCost],
+            ]
+        })
+        .unwrap_or_else(|| vec![vec![MinimizeUnassignedJobs { breaks: None }], vec![MinimizeCost]]);
+    let problem = create_test_problem(objectives);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), expected_tours);
+    assert_eq!(get_max_arrival(&solution), expected_max_arrival);
+}
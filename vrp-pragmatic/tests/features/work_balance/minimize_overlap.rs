@@ -0,0 +1,48 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_serve_clusters_by_separate_tours_when_minimizing_overlap() {
+    let clusters = [("west", -5.), ("east", 5.)];
+    let problem = Problem {
+        plan: Plan {
+            jobs: clusters
+                .iter()
+                .flat_map(|&(name, x)| {
+                    vec![(x, -1.), (x, 1.), (x * 1.2, -1.), (x * 1.2, 1.)]
+                        .into_iter()
+                        .enumerate()
+                        .map(move |(idx, location)| create_delivery_job(&format!("{}{}", name, idx), location))
+                })
+                .collect(),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                capacity: vec![4],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(vec![
+            vec![MinimizeUnassignedJobs { breaks: None }],
+            vec![MinimizeOverlap],
+            vec![MinimizeCost],
+        ]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 2);
+    assert!(solution.unassigned.is_none());
+    solution.tours.iter().for_each(|tour| {
+        let job_ids = get_ids_from_tour(tour).into_iter().flatten().filter(|id| id != "departure" && id != "arrival");
+        let clusters = job_ids.map(|id| id.starts_with("west")).collect::<Vec<_>>();
+
+        assert!(clusters.windows(2).all(|pair| pair[0] == pair[1]), "tour mixes clusters: {:?}", tour);
+    });
+}
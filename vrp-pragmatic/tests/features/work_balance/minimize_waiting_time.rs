@@ -0,0 +1,45 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_minimize_waiting_time, (objectives, expected_tours, expected_waiting), {
+    can_minimize_waiting_time_impl(objectives, expected_tours, expected_waiting);
+}}
+
+can_minimize_waiting_time! {
+    case01_without_objective: (vec![vec![MinimizeUnassignedJobs { breaks: None }], vec![MinimizeCost]], 1, 17),
+    case02_with_objective: (vec![
+        vec![MinimizeUnassignedJobs { breaks: None }],
+        vec![MinimizeWaitingTime],
+        vec![MinimizeCost],
+    ], 2, 0),
+}
+
+fn can_minimize_waiting_time_impl(objectives: Vec<Vec<Objective>>, expected_tours: usize, expected_waiting: i64) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (1., 0.), vec![(0, 2)], 0.),
+                create_delivery_job_with_times("job2", (2., 0.), vec![(20, 30)], 0.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), expected_tours);
+    assert_eq!(solution.statistic.times.waiting, expected_waiting);
+}
@@ -1,3 +1,8 @@
 mod balance_activities;
 mod balance_max_load;
 mod balance_transport;
+mod balance_value;
+mod minimax_duration;
+mod minimax_waiting_time;
+mod minimize_overlap;
+mod minimize_waiting_time;
@@ -83,8 +83,8 @@ pub fn default_job_prototype() -> impl Strategy<Value = Job> {
 
 pub fn default_costs_prototype() -> impl Strategy<Value = VehicleCosts> {
     from_costs(vec![
-        VehicleCosts { fixed: Some(20.), distance: 0.0020, time: 0.003 },
-        VehicleCosts { fixed: Some(30.), distance: 0.0015, time: 0.005 },
+        VehicleCosts { fixed: Some(20.), distance: 0.0020, time: 0.003, waiting: None },
+        VehicleCosts { fixed: Some(30.), distance: 0.0015, time: 0.005, waiting: None },
     ])
 }
 
@@ -92,7 +92,7 @@ pub fn default_shift_places_prototype() -> impl Strategy<Value = (ShiftStart, Op
     generate_location(&DEFAULT_BOUNDING_BOX).prop_flat_map(|location| {
         Just((
             ShiftStart { earliest: default_time_plus_offset(9), latest: None, location: location.clone() },
-            Some(ShiftEnd { earliest: None, latest: default_time_plus_offset(18), location }),
+            Some(ShiftEnd { earliest: None, latest: default_time_plus_offset(18), location, is_open: None }),
         ))
     })
 }
@@ -75,7 +75,7 @@ prop_compose! {
                         tag: Some("p1".to_owned()),
                         ..pickup
                     }
-                ], demand: demand.clone(), order }
+                ], demand: demand.clone(), order, named_demand: None }
             ]),
             deliveries: Some(vec![
              JobTask { places: vec![
@@ -83,14 +83,28 @@ prop_compose! {
                         tag: Some("d1".to_owned()),
                         ..delivery
                     }
-                ], demand, order: None }
+                ], demand, order: None, named_demand: None }
             ]),
             replacements: None,
             services: None,
             skills,
             value,
             group,
-            compatibility
+            compatibility,
+            reservation: None,
+            visit_gap: None,
+            product_class: None,
+            placement: None,
+            truck_only: None,
+            max_ride_duration: None,
+            sync: None,
+            predecessors: None,
+            approach: None,
+            attempts: None,
+            item: None,
+            partition: None,
+            unassigned_penalty: None,
+            bundle: None,
         }
     }
 }
@@ -137,6 +151,20 @@ prop_compose! {
             value,
             group,
             compatibility,
+            reservation: None,
+            visit_gap: None,
+            product_class: None,
+            placement: None,
+            truck_only: None,
+            max_ride_duration: None,
+            sync: None,
+            predecessors: None,
+            approach: None,
+            attempts: None,
+            item: None,
+            partition: None,
+            unassigned_penalty: None,
+            bundle: None,
         }
     }
 }
@@ -152,7 +180,7 @@ prop_compose! {
      demand in demand_proto,
      order in order_proto,
     ) -> JobTask {
-       JobTask { places: vec![place], demand, order }
+       JobTask { places: vec![place], demand, order, named_demand: None }
     }
 }
 
@@ -169,7 +197,7 @@ prop_compose! {
      times in time_windows,
      tag in tags
     ) -> JobPlace {
-      JobPlace { times, location, duration, tag }
+      JobPlace { times, location, duration, tag, duration_per_unit: None, lateness_penalty: None, post_service_buffer: None, recurring_times: None }
     }
 }
 
@@ -32,7 +32,18 @@ prop_compose! {
             shifts,
             capacity,
             skills,
+            skill_counts: None,
             limits,
+            tier: None,
+            loading: None,
+            compartments: None,
+            energy: None,
+            emissions: None,
+            trailer: None,
+            physical: None,
+            optimize_departure: None,
+            duration_per_unit: None,
+            named_capacity: None,
         }
     }
 }
@@ -56,6 +67,9 @@ prop_compose! {
           duration,
           tag,
           resource_id: None,
+          alternatives: None,
+          dock_id: None,
+          capacity: None,
         }
     }
 }
@@ -86,7 +100,14 @@ prop_compose! {
           end: places.1,
           dispatch,
           breaks,
-          reloads
+          reloads,
+          recharges: None,
+          trailer_parkings: None,
+          maintenance: None,
+          depots: None,
+          overtime: None,
+          unloading: None,
+          relays: None,
         }
     }
 }
@@ -107,7 +128,7 @@ prop_compose! {
      vehicles in vehicles_proto,
      profiles in profiles_proto
     ) -> Fleet {
-        Fleet { vehicles, profiles, resources: None }
+        Fleet { vehicles, profiles, resources: None, dimensions: None }
     }
 }
 
@@ -32,6 +32,7 @@ pub fn test_vehicle(id: &str) -> Vehicle {
         details: vec![VehicleDetail {
             start: Some(VehiclePlace { location: 0, time: Default::default() }),
             end: Some(VehiclePlace { location: 0, time: Default::default() }),
+            is_open: false,
         }],
     }
 }
@@ -65,6 +66,8 @@ pub fn create_activity_at_location(location: Location) -> Activity {
             location,
             duration: DEFAULT_JOB_DURATION,
             time: DEFAULT_ACTIVITY_TIME_WINDOW,
+            setup: 0.,
+            lateness: None,
         },
         schedule: DEFAULT_ACTIVITY_SCHEDULE,
         job: None,
@@ -96,6 +99,8 @@ pub fn create_single_with_location(location: Option<Location>) -> Single {
             location,
             duration: DEFAULT_JOB_DURATION,
             times: vec![DEFAULT_JOB_TIME_SPAN],
+            setup: 0.,
+            lateness: None,
         }],
         dimens: Default::default(),
     }
@@ -5,11 +5,20 @@ use crate::format_time;
 use crate::helpers::ToLocation;
 
 pub fn create_job_place(location: (f64, f64), tag: Option<String>) -> JobPlace {
-    JobPlace { times: None, location: location.to_loc(), duration: 1., tag }
+    JobPlace {
+        times: None,
+        location: location.to_loc(),
+        duration: 1.,
+        tag,
+        duration_per_unit: None,
+        lateness_penalty: None,
+        post_service_buffer: None,
+        recurring_times: None,
+    }
 }
 
 pub fn create_task(location: (f64, f64), tag: Option<String>) -> JobTask {
-    JobTask { places: vec![create_job_place(location, tag)], demand: Some(vec![1]), order: None }
+    JobTask { places: vec![create_job_place(location, tag)], demand: Some(vec![1]), order: None, named_demand: None }
 }
 
 pub fn create_job(id: &str) -> Job {
@@ -23,6 +32,20 @@ pub fn create_job(id: &str) -> Job {
         value: None,
         group: None,
         compatibility: None,
+        reservation: None,
+        visit_gap: None,
+        product_class: None,
+        placement: None,
+        truck_only: None,
+        max_ride_duration: None,
+        sync: None,
+        predecessors: None,
+        approach: None,
+        attempts: None,
+        item: None,
+        partition: None,
+        unassigned_penalty: None,
+        bundle: None,
     }
 }
 
@@ -36,6 +59,7 @@ pub fn create_delivery_job_with_order(id: &str, location: (f64, f64), order: i32
             places: vec![create_job_place(location, None)],
             demand: Some(vec![1]),
             order: Some(order),
+            named_demand: None,
         }]),
         ..create_job(id)
     }
@@ -47,6 +71,7 @@ pub fn create_delivery_job_with_group(id: &str, location: (f64, f64), group: &st
             places: vec![create_job_place(location, None)],
             demand: Some(vec![1]),
             order: None,
+            named_demand: None,
         }]),
         group: Some(group.to_string()),
         ..create_job(id)
@@ -59,6 +84,7 @@ pub fn create_delivery_job_with_compatibility(id: &str, location: (f64, f64), co
             places: vec![create_job_place(location, None)],
             demand: Some(vec![1]),
             order: None,
+            named_demand: None,
         }]),
         compatibility: Some(compatibility.to_string()),
         ..create_job(id)
@@ -79,6 +105,7 @@ pub fn create_delivery_job_with_duration(id: &str, location: (f64, f64), duratio
             places: vec![JobPlace { duration, ..create_job_place(location, None) }],
             demand: Some(vec![1]),
             order: None,
+            named_demand: None,
         }]),
         ..create_job(id)
     }
@@ -90,6 +117,7 @@ pub fn create_delivery_job_with_times(id: &str, location: (f64, f64), times: Vec
             places: vec![JobPlace { duration, times: convert_times(&times), ..create_job_place(location, None) }],
             demand: Some(vec![1]),
             order: None,
+            named_demand: None,
         }]),
         ..create_job(id)
     }
@@ -138,6 +166,7 @@ pub fn create_pickup_delivery_job_with_params(
             }],
             demand: Some(demand.clone()),
             order: None,
+            named_demand: None,
         }]),
         deliveries: Some(vec![JobTask {
             places: vec![JobPlace {
@@ -147,6 +176,7 @@ pub fn create_pickup_delivery_job_with_params(
             }],
             demand: Some(demand),
             order: None,
+            named_demand: None,
         }]),
 
         ..create_job(id)
@@ -156,9 +186,19 @@ pub fn create_pickup_delivery_job_with_params(
 pub fn create_delivery_job_with_index(id: &str, index: usize) -> Job {
     Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: Location::Reference { index }, duration: 1., tag: None }],
+            places: vec![JobPlace {
+                times: None,
+                location: Location::Reference { index },
+                duration: 1.,
+                tag: None,
+                duration_per_unit: None,
+                lateness_penalty: None,
+                post_service_buffer: None,
+                recurring_times: None,
+            }],
             demand: Some(vec![1]),
             order: None,
+            named_demand: None,
         }]),
         ..create_job(id)
     }
@@ -180,6 +220,7 @@ pub fn create_multi_job(
                 }],
                 demand: Some(demand),
                 order: None,
+                named_demand: None,
             })
             .collect::<Vec<_>>();
 
@@ -194,7 +235,16 @@ pub fn create_multi_job(
 }
 
 pub fn create_default_reload() -> VehicleReload {
-    VehicleReload { times: None, location: (0., 0.).to_loc(), duration: 2.0, tag: None, resource_id: None }
+    VehicleReload {
+        times: None,
+        location: (0., 0.).to_loc(),
+        duration: 2.0,
+        tag: None,
+        resource_id: None,
+        alternatives: None,
+        dock_id: None,
+        capacity: None,
+    }
 }
 
 pub fn create_default_vehicle_shift() -> VehicleShift {
@@ -208,21 +258,40 @@ pub fn create_default_open_vehicle_shift() -> VehicleShift {
         dispatch: None,
         breaks: None,
         reloads: None,
+        recharges: None,
+        trailer_parkings: None,
+        maintenance: None,
+        depots: None,
+        overtime: None,
+        unloading: None,
+        relays: None,
     }
 }
 
 pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64, f64)) -> VehicleShift {
     VehicleShift {
         start: ShiftStart { earliest: format_time(0.), latest: None, location: (start.0, start.1).to_loc() },
-        end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (end.0, end.1).to_loc() }),
+        end: Some(ShiftEnd {
+            earliest: None,
+            latest: format_time(1000.),
+            location: (end.0, end.1).to_loc(),
+            is_open: None,
+        }),
         dispatch: None,
         breaks: None,
         reloads: None,
+        recharges: None,
+        trailer_parkings: None,
+        maintenance: None,
+        depots: None,
+        overtime: None,
+        unloading: None,
+        relays: None,
     }
 }
 
 pub fn create_default_vehicle_costs() -> VehicleCosts {
-    VehicleCosts { fixed: Some(10.), distance: 1., time: 1. }
+    VehicleCosts { fixed: Some(10.), distance: 1., time: 1., waiting: None }
 }
 
 pub fn create_default_vehicle_profile() -> VehicleProfile {
@@ -250,16 +319,32 @@ pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType
         shifts: vec![create_default_vehicle_shift()],
         capacity,
         skills: None,
+        skill_counts: None,
         limits: None,
+        tier: None,
+        loading: None,
+        compartments: None,
+        energy: None,
+        emissions: None,
+        trailer: None,
+        physical: None,
+        optimize_departure: None,
+        duration_per_unit: None,
+        named_capacity: None,
     }
 }
 
 pub fn create_default_fleet() -> Fleet {
-    Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles(), resources: None }
+    Fleet {
+        vehicles: vec![create_default_vehicle_type()],
+        profiles: create_default_matrix_profiles(),
+        resources: None,
+        dimensions: None,
+    }
 }
 
 pub fn create_default_matrix_profiles() -> Vec<MatrixProfile> {
-    vec![MatrixProfile { name: "car".to_string(), speed: None }]
+    vec![MatrixProfile { name: "car".to_string(), speed: None, infer: None, metric: None }]
 }
 
 pub fn create_min_jobs_cost_objective() -> Option<Vec<Vec<Objective>>> {
@@ -267,13 +352,25 @@ pub fn create_min_jobs_cost_objective() -> Option<Vec<Vec<Objective>>> {
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, areas: None, clustering: None }
+    Plan {
+        jobs: vec![],
+        relations: None,
+        areas: None,
+        clustering: None,
+        blackouts: None,
+        territories: None,
+        escalation: None,
+        marginal_costs: None,
+        zone_sequences: None,
+        eta_confidence: None,
+        certificates: None,
+    }
 }
 
 pub fn create_empty_problem() -> Problem {
     Problem {
         plan: create_empty_plan(),
-        fleet: Fleet { vehicles: vec![], profiles: vec![], resources: None },
+        fleet: Fleet { vehicles: vec![], profiles: vec![], resources: None, dimensions: None },
         objectives: None,
     }
 }
@@ -315,7 +412,7 @@ pub fn to_strings(data: Vec<&str>) -> Vec<String> {
 }
 
 pub fn all_of_skills(skills: Vec<String>) -> JobSkills {
-    JobSkills { all_of: Some(skills), one_of: None, none_of: None }
+    JobSkills { all_of: Some(skills), one_of: None, none_of: None, preferred: None, counts: None }
 }
 
 fn convert_times(times: &Vec<(i32, i32)>) -> Option<Vec<Vec<String>>> {
@@ -24,9 +24,14 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T13:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                duration_per_unit: None,
+                                lateness_penalty: None,
+                                post_service_buffer: None,
+                                recurring_times: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
+                            named_demand: None,
                         }]),
                         ..create_job("job1")
                     },
@@ -40,9 +45,14 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T11:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                duration_per_unit: None,
+                                lateness_penalty: None,
+                                post_service_buffer: None,
+                                recurring_times: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
+                            named_demand: None,
                         }]),
                         ..create_job("job2")
                     },
@@ -56,9 +66,14 @@ fn can_handle_properly_invalid_break_removal() {
                                     vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()],
                                 ]),
                                 tag: None,
+                                duration_per_unit: None,
+                                lateness_penalty: None,
+                                post_service_buffer: None,
+                                recurring_times: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
+                            named_demand: None,
                         }]),
                         ..create_job("job3")
                     },
@@ -72,9 +87,14 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T16:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                duration_per_unit: None,
+                                lateness_penalty: None,
+                                post_service_buffer: None,
+                                recurring_times: None,
                             }],
                             demand: Some(vec![2]),
                             order: None,
+                            named_demand: None,
                         }]),
                         ..create_job("job4")
                     },
@@ -88,9 +108,14 @@ fn can_handle_properly_invalid_break_removal() {
                                     vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()],
                                 ]),
                                 tag: None,
+                                duration_per_unit: None,
+                                lateness_penalty: None,
+                                post_service_buffer: None,
+                                recurring_times: None,
                             }],
                             demand: Some(vec![3]),
                             order: None,
+                            named_demand: None,
                         }]),
                         ..create_job("job5")
                     },
@@ -104,9 +129,14 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T18:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                duration_per_unit: None,
+                                lateness_penalty: None,
+                                post_service_buffer: None,
+                                recurring_times: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
+                            named_demand: None,
                         }]),
                         ..create_job("job6")
                     },
@@ -118,7 +148,7 @@ fn can_handle_properly_invalid_break_removal() {
                     type_id: "vehicle1".to_string(),
                     vehicle_ids: vec!["vehicle1_1".to_string()],
                     profile: VehicleProfile { matrix: "car".to_string(), scale: None },
-                    costs: VehicleCosts { fixed: Some(20.), distance: 0.002, time: 0.003 },
+                    costs: VehicleCosts { fixed: Some(20.), distance: 0.002, time: 0.003, waiting: None },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
                             earliest: "2020-07-04T09:00:00Z".to_string(),
@@ -129,6 +159,7 @@ fn can_handle_properly_invalid_break_removal() {
                             earliest: None,
                             latest: "2020-07-04T18:00:00Z".to_string(),
                             location: Location::Coordinate { lat: 52.44105158292253, lng: 13.424429791168873 },
+                            is_open: None,
                         }),
                         dispatch: None,
                         breaks: Some(vec![VehicleBreak::Optional {
@@ -140,10 +171,28 @@ fn can_handle_properly_invalid_break_removal() {
                             policy: None,
                         }]),
                         reloads: None,
+                        recharges: None,
+                        trailer_parkings: None,
+                        maintenance: None,
+                        depots: None,
+                        overtime: None,
+                        unloading: None,
+                        relays: None,
                     }],
                     capacity: vec![5],
                     skills: None,
+                    skill_counts: None,
                     limits: None,
+                    tier: None,
+                    loading: None,
+                    compartments: None,
+                    energy: None,
+                    emissions: None,
+                    trailer: None,
+                    physical: None,
+                    optimize_departure: None,
+                    duration_per_unit: None,
+                    named_capacity: None,
                 }],
                 ..create_default_fleet()
             },
@@ -133,9 +133,14 @@ fn check_jobs_impl(
                     duration: 0.0,
                     times: None,
                     tag: Some(format!("{}{}", tgt, idx)),
+                    duration_per_unit: None,
+                    lateness_penalty: None,
+                    post_service_buffer: None,
+                    recurring_times: None,
                 }],
                 demand: if tgt != "service" { Some(vec![1]) } else { None },
                 order: None,
+                named_demand: None,
             })
             .collect()
     };
@@ -199,6 +204,8 @@ fn can_detect_time_window_violation() {
             distance: 2,
             duration: 3,
             times: Timing { driving: 2, serving: 1, ..Timing::default() },
+            utilization: None,
+            emissions: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -235,6 +242,8 @@ fn can_detect_time_window_violation() {
                 distance: 2,
                 duration: 3,
                 times: Timing { driving: 2, serving: 1, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
         }],
         ..create_empty_solution()
@@ -263,6 +272,8 @@ fn can_detect_job_duration_violation() {
             distance: 2,
             duration: 6,
             times: Timing { driving: 2, serving: 2, waiting: 2, ..Timing::default() },
+            utilization: None,
+            emissions: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -299,6 +310,8 @@ fn can_detect_job_duration_violation() {
                 distance: 2,
                 duration: 6,
                 times: Timing { driving: 2, serving: 2, waiting: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
         }],
         ..create_empty_solution()
@@ -432,3 +445,580 @@ fn can_detect_group_violations() {
 
     assert_eq!(result, Err("job groups are not respected: 'group1'".to_owned()));
 }
+
+parameterized_test! {can_detect_bundle_violations, (assignments, expected), {
+    can_detect_bundle_violations_impl(assignments, expected);
+}}
+
+can_detect_bundle_violations! {
+    case01_all_assigned: (vec![("v1", "job1"), ("v2", "job2")], Ok(())),
+    case02_none_assigned: (vec![], Ok(())),
+    case03_partially_assigned: (vec![("v1", "job1")], Err("job bundles are partially assigned: 'bundle1'".to_string())),
+}
+
+fn can_detect_bundle_violations_impl(assignments: Vec<(&str, &str)>, expected: Result<(), String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { bundle: Some("bundle1".to_string()), ..create_delivery_job("job1", (1., 0.)) },
+                Job { bundle: Some("bundle1".to_string()), ..create_delivery_job("job2", (1., 0.)) },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let create_tour = |vehicle_id: &str, job_id: &str| Tour {
+        vehicle_id: vehicle_id.to_string(),
+        type_id: "my_vehicle".to_string(),
+        stops: vec![
+            create_stop_with_activity(
+                "departure",
+                "departure",
+                (0., 0.),
+                1,
+                ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                0,
+            ),
+            create_stop_with_activity(
+                job_id,
+                "delivery",
+                (1., 0.),
+                0,
+                ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                1,
+            ),
+            create_stop_with_activity(
+                "arrival",
+                "arrival",
+                (0., 0.),
+                0,
+                ("1970-01-01T00:00:03Z", "1970-01-01T00:00:03Z"),
+                2,
+            ),
+        ],
+        ..create_empty_tour()
+    };
+    let solution = Solution {
+        tours: assignments.into_iter().map(|(vehicle_id, job_id)| create_tour(vehicle_id, job_id)).collect(),
+        ..create_empty_solution()
+    };
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_bundles(&ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_detect_visit_gap_violations, (min, max, expected), {
+    can_detect_visit_gap_violations_impl(min, max, expected);
+}}
+
+can_detect_visit_gap_violations! {
+    case01: (Some(5.), Some(10.), Ok(())),
+    case02: (Some(10.), None, Err("job visit gaps are not respected: 'job1'".to_string())),
+    case03: (None, Some(4.), Err("job visit gaps are not respected: 'job1'".to_string())),
+}
+
+fn can_detect_visit_gap_violations_impl(min: Option<f64>, max: Option<f64>, expected: Result<(), String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                services: Some(vec![
+                    JobTask { demand: None, ..create_task((1., 0.), Some("drop".to_string())) },
+                    JobTask { demand: None, ..create_task((1., 0.), Some("collect".to_string())) },
+                ]),
+                visit_gap: Some(JobVisitGap { min, max }),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    0,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity_with_tag(
+                    "job1",
+                    "service",
+                    (1., 0.),
+                    0,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                    "drop",
+                ),
+                create_stop_with_activity_with_tag(
+                    "job1",
+                    "service",
+                    (1., 0.),
+                    0,
+                    ("1970-01-01T00:00:09Z", "1970-01-01T00:00:10Z"),
+                    1,
+                    "collect",
+                ),
+            ],
+            ..create_empty_tour()
+        }],
+        ..create_empty_solution()
+    };
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_visit_gaps(&ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_detect_ride_duration_violations, (max_ride_duration, delivery_times, expected), {
+    can_detect_ride_duration_violations_impl(max_ride_duration, delivery_times, expected);
+}}
+
+can_detect_ride_duration_violations! {
+    case01_within_limit: (10., vec![], Ok(())),
+    case02_exceeds_limit: (5., vec![], Err("job max ride durations are not respected: 'job1'".to_string())),
+    case03_exceeds_limit_with_waiting: (10., vec![(15, 100)], Err("job max ride durations are not respected: 'job1'".to_string())),
+}
+
+fn can_detect_ride_duration_violations_impl(
+    max_ride_duration: f64,
+    delivery_times: Vec<(i32, i32)>,
+    expected: Result<(), String>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                max_ride_duration: Some(max_ride_duration),
+                ..create_pickup_delivery_job_with_params(
+                    "job1",
+                    vec![1],
+                    ((1., 0.), 1., vec![]),
+                    ((2., 0.), 1., delivery_times),
+                )
+            }],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    0,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity_with_tag(
+                    "job1",
+                    "pickup",
+                    (1., 0.),
+                    1,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                    "p1",
+                ),
+                create_stop_with_activity_with_tag(
+                    "job1",
+                    "delivery",
+                    (2., 0.),
+                    0,
+                    ("1970-01-01T00:00:09Z", "1970-01-01T00:00:10Z"),
+                    2,
+                    "d1",
+                ),
+            ],
+            ..create_empty_tour()
+        }],
+        ..create_empty_solution()
+    };
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_ride_durations(&ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_detect_placement_violations, (placements, expected), {
+    can_detect_placement_violations_impl(placements, expected);
+}}
+
+can_detect_placement_violations! {
+    case01: ((JobPlacement::Rear, JobPlacement::Front), Ok(())),
+    case02: ((JobPlacement::Rear, JobPlacement::Rear), Ok(())),
+    case03: ((JobPlacement::Front, JobPlacement::Rear), Err("job placements are not respected in tours: 'my_vehicle_1'".to_string())),
+}
+
+fn can_detect_placement_violations_impl(placements: (JobPlacement, JobPlacement), expected: Result<(), String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { placement: Some(placements.0), ..create_delivery_job("job1", (1., 0.)) },
+                Job { placement: Some(placements.1), ..create_delivery_job("job2", (2., 0.)) },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    2,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (1., 0.),
+                    1,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+                create_stop_with_activity(
+                    "job2",
+                    "delivery",
+                    (2., 0.),
+                    0,
+                    ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
+                    2,
+                ),
+            ],
+            ..create_empty_tour()
+        }],
+        ..create_empty_solution()
+    };
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_placements(&ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_detect_loading_order_violations, (order, deliveries, expected), {
+    can_detect_loading_order_violations_impl(order, deliveries, expected);
+}}
+
+can_detect_loading_order_violations! {
+    case01_lifo_valid: (VehicleLoadingOrder::Lifo, ("job2", "job1"), Ok(())),
+    case02_lifo_invalid: (VehicleLoadingOrder::Lifo, ("job1", "job2"), Err("vehicle loading order is not respected in tours: 'my_vehicle_1'".to_string())),
+    case03_fifo_valid: (VehicleLoadingOrder::Fifo, ("job1", "job2"), Ok(())),
+    case04_fifo_invalid: (VehicleLoadingOrder::Fifo, ("job2", "job1"), Err("vehicle loading order is not respected in tours: 'my_vehicle_1'".to_string())),
+}
+
+fn can_detect_loading_order_violations_impl(
+    order: VehicleLoadingOrder,
+    deliveries: (&str, &str),
+    expected: Result<(), String>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job("job1", (1., 0.), (3., 0.)),
+                create_pickup_delivery_job("job2", (2., 0.), (3., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                loading: Some(VehicleLoading { manifest: None, order: Some(order), backhaul: None }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    0,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "pickup",
+                    (1., 0.),
+                    1,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+                create_stop_with_activity(
+                    "job2",
+                    "pickup",
+                    (2., 0.),
+                    2,
+                    ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
+                    2,
+                ),
+                create_stop_with_activity(
+                    deliveries.0,
+                    "delivery",
+                    (3., 0.),
+                    1,
+                    ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
+                    3,
+                ),
+                create_stop_with_activity(
+                    deliveries.1,
+                    "delivery",
+                    (3., 0.),
+                    0,
+                    ("1970-01-01T00:00:06Z", "1970-01-01T00:00:07Z"),
+                    3,
+                ),
+            ],
+            ..create_empty_tour()
+        }],
+        ..create_empty_solution()
+    };
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_loading_order(&ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_detect_backhaul_violations, (penalty, activities, expected), {
+    can_detect_backhaul_violations_impl(penalty, activities, expected);
+}}
+
+can_detect_backhaul_violations! {
+    case01_strict_valid: (None, (("job1", "delivery"), ("job2", "pickup")), Ok(())),
+    case02_strict_invalid: (None, (("job2", "pickup"), ("job1", "delivery")), Err("vehicle backhaul requirement is not respected in tours: 'my_vehicle_1'".to_string())),
+    case03_soft_invalid: (Some(10.), (("job2", "pickup"), ("job1", "delivery")), Ok(())),
+}
+
+fn can_detect_backhaul_violations_impl(
+    penalty: Option<f64>,
+    activities: ((&str, &str), (&str, &str)),
+    expected: Result<(), String>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_pickup_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                loading: Some(VehicleLoading {
+                    manifest: None,
+                    order: None,
+                    backhaul: Some(VehicleBackhaul { penalty }),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    1,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    (activities.0).0,
+                    (activities.0).1,
+                    (1., 0.),
+                    1,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+                create_stop_with_activity(
+                    (activities.1).0,
+                    (activities.1).1,
+                    (2., 0.),
+                    1,
+                    ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
+                    2,
+                ),
+            ],
+            ..create_empty_tour()
+        }],
+        ..create_empty_solution()
+    };
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_backhaul(&ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_detect_sync_violations, (job2_time, expected), {
+    can_detect_sync_violations_impl(job2_time, expected);
+}}
+
+can_detect_sync_violations! {
+    case01_aligned: (Some(("1970-01-01T00:00:02Z", "1970-01-01T00:00:03Z")), Ok(())),
+    case02_waiting: (Some(("1970-01-01T00:00:01Z", "1970-01-01T00:00:03Z")), Ok(())),
+    case03_misaligned: (Some(("1970-01-01T00:00:04Z", "1970-01-01T00:00:05Z")), Err("job sync requirement is not respected for sync keys: 'install'".to_string())),
+    case04_partial: (None, Err("job sync requirement is not respected for sync keys: 'install'".to_string())),
+}
+
+fn can_detect_sync_violations_impl(job2_time: Option<(&str, &str)>, expected: Result<(), String>) {
+    let create_sync_job = |id: &str, location: (f64, f64)| {
+        let mut job = create_service_job(id, location);
+        job.services.as_mut().unwrap()[0].places[0].times =
+            Some(vec![vec!["1970-01-01T00:00:02Z".to_string(), "1970-01-01T00:00:10Z".to_string()]]);
+
+        Job { sync: Some(JobSync { key: "install".to_string(), offset: None }), ..job }
+    };
+    let create_tour = |vehicle_id: &str, job_id: &str, location: (f64, f64), time: (&str, &str)| Tour {
+        vehicle_id: vehicle_id.to_string(),
+        type_id: "my_vehicle".to_string(),
+        stops: vec![
+            create_stop_with_activity(
+                "departure",
+                "departure",
+                (0., 0.),
+                0,
+                ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                0,
+            ),
+            create_stop_with_activity(job_id, "service", location, 0, time, 1),
+        ],
+        ..create_empty_tour()
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_sync_job("job1", (2., 0.)), create_sync_job("job2", (1., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: std::iter::once(create_tour("v1", "job1", (2., 0.), ("1970-01-01T00:00:02Z", "1970-01-01T00:00:03Z")))
+            .chain(job2_time.map(|time| create_tour("v2", "job2", (1., 0.), time)))
+            .collect(),
+        ..create_empty_solution()
+    };
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_sync(&ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_detect_precedence_violations, (job1_time, job2_time, expected), {
+    can_detect_precedence_violations_impl(job1_time, job2_time, expected);
+}}
+
+can_detect_precedence_violations! {
+    case01_after_predecessor: (Some(("1970-01-01T00:00:02Z", "1970-01-01T00:00:03Z")), ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"), Ok(())),
+    case02_waiting: (Some(("1970-01-01T00:00:02Z", "1970-01-01T00:00:03Z")), ("1970-01-01T00:00:01Z", "1970-01-01T00:00:04Z"), Ok(())),
+    case03_too_early: (Some(("1970-01-01T00:00:04Z", "1970-01-01T00:00:05Z")), ("1970-01-01T00:00:01Z", "1970-01-01T00:00:04Z"), Err("job precedence is not respected for jobs: 'job2'".to_string())),
+    case04_missing_predecessor: (None, ("1970-01-01T00:00:01Z", "1970-01-01T00:00:04Z"), Err("job precedence is not respected for jobs: 'job2'".to_string())),
+}
+
+fn can_detect_precedence_violations_impl(
+    job1_time: Option<(&str, &str)>,
+    job2_time: (&str, &str),
+    expected: Result<(), String>,
+) {
+    let create_tour = |vehicle_id: &str, job_id: &str, location: (f64, f64), time: (&str, &str)| Tour {
+        vehicle_id: vehicle_id.to_string(),
+        type_id: "my_vehicle".to_string(),
+        stops: vec![
+            create_stop_with_activity(
+                "departure",
+                "departure",
+                (0., 0.),
+                0,
+                ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                0,
+            ),
+            create_stop_with_activity(job_id, "service", location, 0, time, 1),
+        ],
+        ..create_empty_tour()
+    };
+    let mut job2 = create_service_job("job2", (1., 0.));
+    job2.services.as_mut().unwrap()[0].places[0].times =
+        Some(vec![vec!["1970-01-01T00:00:03Z".to_string(), "1970-01-01T00:00:10Z".to_string()]]);
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_service_job("job1", (2., 0.)),
+                Job { predecessors: Some(vec!["job1".to_string()]), ..job2 },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: job1_time
+            .map(|time| create_tour("v1", "job1", (2., 0.), time))
+            .into_iter()
+            .chain(std::iter::once(create_tour("v2", "job2", (1., 0.), job2_time)))
+            .collect(),
+        ..create_empty_solution()
+    };
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_precedences(&ctx);
+
+    assert_eq!(result, expected);
+}
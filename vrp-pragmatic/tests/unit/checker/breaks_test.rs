@@ -79,7 +79,12 @@ fn can_check_breaks_impl(
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (0., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (0., 0.).to_loc(),
+                        is_open: None,
+                    }),
                     dispatch: None,
                     breaks: Some(vec![VehicleBreak::Optional {
                         time: break_times,
@@ -87,6 +92,13 @@ fn can_check_breaks_impl(
                         policy: None,
                     }]),
                     reloads: None,
+                    recharges: None,
+                    trailer_parkings: None,
+                    maintenance: None,
+                    depots: None,
+                    overtime: None,
+                    unloading: None,
+                    relays: None,
                 }],
                 capacity: vec![5],
                 ..create_default_vehicle_type()
@@ -121,6 +133,8 @@ fn can_check_breaks_impl(
             distance: 4,
             duration: 8,
             times: Timing { driving: 4, serving: 2, break_time: 2, ..Timing::default() },
+            utilization: None,
+            emissions: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -168,6 +182,8 @@ fn can_check_breaks_impl(
                 distance: 4,
                 duration: 8,
                 times: Timing { driving: 4, serving: 2, break_time: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
         }],
         violations,
@@ -3,25 +3,32 @@ use crate::format_time;
 use crate::helpers::*;
 use vrp_core::models::examples::create_example_problem;
 
-parameterized_test! {can_check_load, (stop_loads, expected_result), {
-    can_check_load_impl(stop_loads, expected_result);
+parameterized_test! {can_check_load, (stop_loads, trip_capacity, expected_result), {
+    can_check_load_impl(stop_loads, trip_capacity, expected_result);
 }}
 
 can_check_load! {
-    case00: ( vec![1, 1, 3, 1, 2, 1, 0], Ok(())),
+    case00: ( vec![1, 1, 3, 1, 2, 1, 0], None, Ok(())),
 
-    case01: ( vec![1, 2, 3, 1, 2, 1, 0], Err(vec!["load mismatch at stop 1 in tour 'my_vehicle_1'".to_owned()])),
-    case02: ( vec![1, 1, 2, 1, 2, 1, 0], Err(vec!["load mismatch at stops 2, 3 in tour 'my_vehicle_1'".to_owned()])),
-    case03: ( vec![1, 1, 3, 2, 2, 1, 0], Err(vec!["load mismatch at stop 3 in tour 'my_vehicle_1'".to_owned()])),
-    case04: ( vec![1, 1, 3, 1, 1, 1, 0], Err(vec!["load mismatch at stop 4 in tour 'my_vehicle_1'".to_owned()])),
-    case05: ( vec![1, 1, 3, 1, 2, 2, 0], Err(vec!["load mismatch at stop 5 in tour 'my_vehicle_1'".to_owned()])),
+    case01: ( vec![1, 2, 3, 1, 2, 1, 0], None, Err(vec!["load mismatch at stop 1 in tour 'my_vehicle_1'".to_owned()])),
+    case02: ( vec![1, 1, 2, 1, 2, 1, 0], None, Err(vec!["load mismatch at stops 2, 3 in tour 'my_vehicle_1'".to_owned()])),
+    case03: ( vec![1, 1, 3, 2, 2, 1, 0], None, Err(vec!["load mismatch at stop 3 in tour 'my_vehicle_1'".to_owned()])),
+    case04: ( vec![1, 1, 3, 1, 1, 1, 0], None, Err(vec!["load mismatch at stop 4 in tour 'my_vehicle_1'".to_owned()])),
+    case05: ( vec![1, 1, 3, 1, 2, 2, 0], None, Err(vec!["load mismatch at stop 5 in tour 'my_vehicle_1'".to_owned()])),
 
-    case06_1: ( vec![10, 1, 3, 1, 2, 1, 0], Err(vec!["load exceeds capacity in tour 'my_vehicle_1'".to_owned()])),
-    case06_2: ( vec![1, 1, 30, 1, 2, 1, 0], Err(vec!["load exceeds capacity in tour 'my_vehicle_1'".to_owned()])),
-    case06_3: ( vec![1, 1, 3, 1, 20, 1, 0], Err(vec!["load exceeds capacity in tour 'my_vehicle_1'".to_owned()])),
+    case06_1: ( vec![10, 1, 3, 1, 2, 1, 0], None, Err(vec!["load exceeds capacity in tour 'my_vehicle_1'".to_owned()])),
+    case06_2: ( vec![1, 1, 30, 1, 2, 1, 0], None, Err(vec!["load exceeds capacity in tour 'my_vehicle_1'".to_owned()])),
+    case06_3: ( vec![1, 1, 3, 1, 20, 1, 0], None, Err(vec!["load exceeds capacity in tour 'my_vehicle_1'".to_owned()])),
+
+    case07_1: ( vec![1, 1, 3, 1, 2, 1, 0], Some(vec![3]), Ok(())),
+    case07_2: ( vec![1, 1, 3, 1, 2, 1, 0], Some(vec![2]), Err(vec!["load exceeds capacity in tour 'my_vehicle_1'".to_owned()])),
 }
 
-fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<String>>) {
+fn can_check_load_impl(
+    stop_loads: Vec<i32>,
+    trip_capacity: Option<Vec<i32>>,
+    expected_result: Result<(), Vec<String>>,
+) {
     let problem = Problem {
         plan: Plan {
             jobs: vec![
@@ -37,14 +44,27 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (0., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (0., 0.).to_loc(),
+                        is_open: None,
+                    }),
                     dispatch: None,
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         location: (0., 0.).to_loc(),
                         duration: 2.0,
+                        capacity: trip_capacity,
                         ..create_default_reload()
                     }]),
+                    recharges: None,
+                    trailer_parkings: None,
+                    maintenance: None,
+                    depots: None,
+                    overtime: None,
+                    unloading: None,
+                    relays: None,
                 }],
                 capacity: vec![5],
                 ..create_default_vehicle_type()
@@ -59,6 +79,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
             distance: 1,
             duration: 2,
             times: Timing { driving: 1, serving: 1, ..Timing::default() },
+            utilization: None,
+            emissions: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -184,6 +206,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                 distance: 1,
                 duration: 2,
                 times: Timing { driving: 1, serving: 1, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
         }],
         ..create_empty_solution()
@@ -209,6 +233,8 @@ fn can_check_load_when_departure_has_other_activity() {
             distance: 2,
             duration: 4,
             times: Timing { driving: 2, serving: 2, ..Timing::default() },
+            utilization: None,
+            emissions: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -266,6 +292,8 @@ fn can_check_load_when_departure_has_other_activity() {
                 distance: 2,
                 duration: 4,
                 times: Timing { driving: 2, serving: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
         }],
         ..create_empty_solution()
@@ -294,6 +322,7 @@ fn can_check_resource_consumption() {
                     reloads: Some(vec![VehicleReload {
                         location: (4., 0.).to_loc(),
                         resource_id: Some("resource_1".to_string()),
+                        alternatives: None,
                         ..create_default_reload()
                     }]),
                     ..create_default_open_vehicle_shift()
@@ -311,6 +340,8 @@ fn can_check_resource_consumption() {
             distance: 6,
             duration: 11,
             times: Timing { driving: 6, serving: 5, ..Timing::default() },
+            utilization: None,
+            emissions: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -363,6 +394,8 @@ fn can_check_resource_consumption() {
                 distance: 6,
                 duration: 11,
                 times: Timing { driving: 6, serving: 5, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
         }],
         ..create_empty_solution()
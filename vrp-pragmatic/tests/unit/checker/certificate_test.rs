@@ -0,0 +1,72 @@
+use super::*;
+use crate::format::solution::Solution;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (1., 0.), vec![(0, 100)], 1.),
+                create_delivery_job("job2", (2., 0.)),
+            ],
+            certificates: Some(true),
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], ..create_default_fleet() },
+        ..create_empty_problem()
+    }
+}
+
+fn get_certificate(solution: &Solution) -> RouteCertificate {
+    let certificates = solution.extras.as_ref().and_then(|extras| extras.certificates.as_ref()).unwrap();
+    assert_eq!(certificates.len(), 1);
+
+    certificates[0].clone()
+}
+
+parameterized_test! {can_verify_certificate, (modify, expected), {
+    can_verify_certificate_impl(modify, expected);
+}}
+
+can_verify_certificate! {
+    case01_valid: (|_: &mut StateTransition| {}, None),
+    case02_too_early_arrival: (|t: &mut StateTransition| {
+        t.arrival = format_time(0.);
+    }, Some("arrival is earlier than travel")),
+    case03_wrong_load: (|t: &mut StateTransition| {
+        t.load = vec![5];
+    }, Some("load change does not match demand")),
+    case04_over_capacity: (|t: &mut StateTransition| {
+        t.load = vec![20];
+    }, Some("load is out of vehicle capacity")),
+    case05_wrong_location: (|t: &mut StateTransition| {
+        t.location = (3., 0.).to_loc();
+    }, Some("has no delivery task at given location")),
+    case06_late_service: (|t: &mut StateTransition| {
+        t.arrival = format_time(200.);
+        t.departure = format_time(201.);
+    }, Some("is not served at its place and time")),
+    case07_unknown_job: (|t: &mut StateTransition| {
+        t.job_id = "job3".to_string();
+    }, Some("unknown job 'job3'")),
+}
+
+fn can_verify_certificate_impl(modify: fn(&mut StateTransition), expected: Option<&str>) {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix.clone()]));
+    let mut certificate = get_certificate(&solution);
+    assert_eq!(certificate.transitions.len(), 4);
+    modify(certificate.transitions.iter_mut().find(|transition| transition.job_id == "job1").unwrap());
+
+    let result = verify_certificate(&problem, &[matrix], &certificate);
+
+    match expected {
+        Some(expected) => {
+            let errors = result.expect_err("expected verification error");
+            assert!(errors.iter().any(|err| err.contains(expected)), "unexpected errors: {:?}", errors);
+        }
+        None => assert_eq!(result, Ok(())),
+    }
+}
@@ -60,7 +60,18 @@ pub fn can_check_shift_and_distance_limit_impl(
     actual: i64,
     expected: Result<(), String>,
 ) {
-    let problem = create_test_problem(Some(VehicleLimits { max_distance, shift_time, tour_size: None, areas: None }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance,
+        shift_time,
+        driver_shift_time: None,
+        tour_size: None,
+        min_tour_size: None,
+        areas: None,
+        consecutive_driving: None,
+        driving_time: None,
+        allowed_areas: None,
+        max_cost: None,
+    }));
     let solution =
         create_test_solution(Statistic { distance: actual, duration: actual, ..Statistic::default() }, vec![]);
     let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
@@ -70,13 +81,67 @@ pub fn can_check_shift_and_distance_limit_impl(
     assert_eq!(result, expected);
 }
 
-#[test]
-pub fn can_check_tour_size_limit() {
+parameterized_test! {can_check_max_cost_limit, (max_cost, actual, expected), {
+    can_check_max_cost_limit_impl(max_cost, actual, expected);
+}}
+
+can_check_max_cost_limit! {
+    case_01: (30., 10, None),
+    case_02: (29., 10, Some(30.)),
+    case_03: (31., 10, None),
+}
+
+pub fn can_check_max_cost_limit_impl(max_cost: f64, actual: i64, expected: Option<f64>) {
     let problem = create_test_problem(Some(VehicleLimits {
         max_distance: None,
         shift_time: None,
-        tour_size: Some(2),
+        driver_shift_time: None,
+        tour_size: None,
+        min_tour_size: None,
         areas: None,
+        consecutive_driving: None,
+        driving_time: None,
+        allowed_areas: None,
+        max_cost: Some(max_cost),
+    }));
+    let solution =
+        create_test_solution(Statistic { distance: actual, duration: actual, ..Statistic::default() }, vec![]);
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_shift_limits(&ctx);
+
+    assert_eq!(
+        result,
+        expected.map_or(Ok(()), |cost| Err(format!(
+            "max cost limit violation, expected: not more than {}, got: {}, vehicle id 'some_real_vehicle', shift index: 0",
+            max_cost, cost
+        )))
+    );
+}
+
+parameterized_test! {can_check_tour_size_limit, (tour_size, min_tour_size, expected), {
+    can_check_tour_size_limit_impl(tour_size, min_tour_size, expected);
+}}
+
+can_check_tour_size_limit! {
+    case01_max_violation: (Some(2), None, Some("tour size limit violation, expected: not more than 2, got: 3")),
+    case02_max_ok: (Some(3), None, None),
+    case03_min_violation: (None, Some(4), Some("min tour size limit violation, expected: not less than 4, got: 3")),
+    case04_min_ok: (Some(3), Some(3), None),
+}
+
+fn can_check_tour_size_limit_impl(tour_size: Option<usize>, min_tour_size: Option<usize>, expected: Option<&str>) {
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        shift_time: None,
+        driver_shift_time: None,
+        tour_size,
+        min_tour_size,
+        areas: None,
+        consecutive_driving: None,
+        driving_time: None,
+        allowed_areas: None,
+        max_cost: None,
     }));
     let solution = create_test_solution(
         Statistic::default(),
@@ -129,8 +194,7 @@ pub fn can_check_tour_size_limit() {
 
     assert_eq!(
         result,
-        Err("tour size limit violation, expected: not more than 2, got: 3, vehicle id 'some_real_vehicle', shift index: 0"
-            .to_string())
+        expected.map_or(Ok(()), |message| Err(format!("{}, vehicle id 'some_real_vehicle', shift index: 0", message)))
     );
 }
 
@@ -145,7 +209,12 @@ fn can_check_shift_time() {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(5.), location: (0., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(5.),
+                        location: (0., 0.).to_loc(),
+                        is_open: None,
+                    }),
                     ..create_default_vehicle_shift()
                 }],
                 ..create_default_vehicle_type()
@@ -160,6 +229,8 @@ fn can_check_shift_time() {
             distance: 2,
             duration: 5,
             times: Timing { driving: 2, serving: 1, waiting: 2, ..Timing::default() },
+            utilization: None,
+            emissions: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -196,6 +267,8 @@ fn can_check_shift_time() {
                 distance: 2,
                 duration: 5,
                 times: Timing { driving: 2, serving: 1, waiting: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
         }],
         ..create_empty_solution()
@@ -207,3 +280,325 @@ fn can_check_shift_time() {
 
     assert_eq!(result, Err("tour time is outside shift time, vehicle id 'my_vehicle_1', shift index: 0".to_owned()));
 }
+
+parameterized_test! {can_check_consecutive_driving_limit, (max_distance, max_duration, expected), {
+    can_check_consecutive_driving_limit_impl(max_distance, max_duration, expected);
+}}
+
+can_check_consecutive_driving_limit! {
+    case_01: (Some(5.), None, Ok(())),
+    case_02: (Some(4.), None, Err("distance: 5, duration: 5")),
+    case_03: (None, Some(5.), Ok(())),
+    case_04: (None, Some(2.), Err("distance: 4, duration: 4")),
+}
+
+fn can_check_consecutive_driving_limit_impl(
+    max_distance: Option<f64>,
+    max_duration: Option<f64>,
+    expected: Result<(), &str>,
+) {
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        shift_time: None,
+        driver_shift_time: None,
+        tour_size: None,
+        min_tour_size: None,
+        areas: None,
+        consecutive_driving: Some(ConsecutiveDrivingLimit { max_distance, max_duration }),
+        driving_time: None,
+        allowed_areas: None,
+        max_cost: None,
+    }));
+    let solution = create_test_solution(
+        Statistic::default(),
+        vec![
+            create_stop_with_activity(
+                "departure",
+                "departure",
+                (0., 0.),
+                2,
+                (format_time(0.).as_str(), format_time(0.).as_str()),
+                0,
+            ),
+            create_stop_with_activity(
+                "job1",
+                "delivery",
+                (1., 0.),
+                1,
+                (format_time(1.).as_str(), format_time(2.).as_str()),
+                1,
+            ),
+            create_stop_with_activity(
+                "job2",
+                "delivery",
+                (5., 0.),
+                0,
+                (format_time(6.).as_str(), format_time(6.).as_str()),
+                5,
+            ),
+            create_stop_with_activity(
+                "arrival",
+                "arrival",
+                (0., 0.),
+                0,
+                (format_time(11.).as_str(), format_time(11.).as_str()),
+                10,
+            ),
+        ],
+    );
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_consecutive_driving(&ctx);
+
+    assert_eq!(
+        result,
+        expected.map_err(|msg| format!(
+            "consecutive driving limit violation, {}, vehicle id 'some_real_vehicle', shift index: 0",
+            msg
+        ))
+    );
+}
+
+parameterized_test! {can_check_driving_time_limit, (max_driving_time, break_duration, working, expected), {
+    can_check_driving_time_limit_impl(max_driving_time, break_duration, working, expected);
+}}
+
+can_check_driving_time_limit! {
+    case_01: (10., 2., None, Ok(())),
+    case_02: (5., 2., None, Err("driving: 10, working: 11")),
+    case_03: (6., 1., None, Ok(())),
+    case_04: (20., 2., Some((5., 10.)), Err("driving: 10, working: 11")),
+}
+
+fn can_check_driving_time_limit_impl(
+    max_driving_time: f64,
+    break_duration: f64,
+    working: Option<(f64, f64)>,
+    expected: Result<(), &str>,
+) {
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        shift_time: None,
+        driver_shift_time: None,
+        tour_size: None,
+        min_tour_size: None,
+        areas: None,
+        consecutive_driving: None,
+        driving_time: Some(DrivingTimeLimit {
+            max_driving_time,
+            break_duration,
+            max_working_time: working.map(|(time, _)| time),
+            rest_duration: working.map(|(_, duration)| duration),
+        }),
+        allowed_areas: None,
+        max_cost: None,
+    }));
+    let solution = create_test_solution(
+        Statistic::default(),
+        vec![
+            create_stop_with_activity(
+                "departure",
+                "departure",
+                (0., 0.),
+                2,
+                (format_time(0.).as_str(), format_time(0.).as_str()),
+                0,
+            ),
+            create_stop_with_activity(
+                "job1",
+                "delivery",
+                (1., 0.),
+                1,
+                (format_time(1.).as_str(), format_time(2.).as_str()),
+                1,
+            ),
+            create_stop_with_activity(
+                "job2",
+                "delivery",
+                (5., 0.),
+                0,
+                (format_time(6.).as_str(), format_time(6.).as_str()),
+                5,
+            ),
+            create_stop_with_activity(
+                "arrival",
+                "arrival",
+                (0., 0.),
+                0,
+                (format_time(11.).as_str(), format_time(11.).as_str()),
+                10,
+            ),
+        ],
+    );
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_driving_time(&ctx);
+
+    assert_eq!(
+        result,
+        expected.map_err(|msg| format!(
+            "driving time limit violation, {}, vehicle id 'some_real_vehicle', shift index: 0",
+            msg
+        ))
+    );
+}
+
+parameterized_test! {can_check_energy_limit, (capacity, recharge_duration, expected), {
+    can_check_energy_limit_impl(capacity, recharge_duration, expected);
+}}
+
+can_check_energy_limit! {
+    case_01: (12., 5., Ok(())),
+    case_02: (12., 2., Err("recharge duration violation, expected: not less than 4, got: 2")),
+    case_03: (8., 5., Err("energy limit violation, expected: not less than 0, got: -3")),
+}
+
+fn can_check_energy_limit_impl(capacity: f64, recharge_duration: f64, expected: Result<(), &str>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["some_real_vehicle".to_string()],
+                shifts: vec![VehicleShift {
+                    recharges: Some(vec![VehicleRecharge {
+                        location: (5., 0.).to_loc(),
+                        rate: 1.,
+                        times: None,
+                        tag: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                energy: Some(VehicleEnergy { capacity, consumption: 1., load_consumption: None, reserve: None }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = create_test_solution(
+        Statistic::default(),
+        vec![
+            create_stop_with_activity(
+                "departure",
+                "departure",
+                (0., 0.),
+                1,
+                (format_time(0.).as_str(), format_time(0.).as_str()),
+                0,
+            ),
+            create_stop_with_activity(
+                "recharge",
+                "recharge",
+                (5., 0.),
+                1,
+                (format_time(5.).as_str(), format_time(5. + recharge_duration).as_str()),
+                5,
+            ),
+            create_stop_with_activity(
+                "job1",
+                "delivery",
+                (8., 0.),
+                0,
+                (format_time(13.).as_str(), format_time(14.).as_str()),
+                8,
+            ),
+            create_stop_with_activity(
+                "arrival",
+                "arrival",
+                (0., 0.),
+                0,
+                (format_time(22.).as_str(), format_time(22.).as_str()),
+                16,
+            ),
+        ],
+    );
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_energy(&ctx);
+
+    assert_eq!(result, expected.map_err(|msg| format!("{}, vehicle id 'some_real_vehicle', shift index: 0", msg)));
+}
+
+parameterized_test! {can_check_driver_shift_time_limit, (driver_shift_time, expected), {
+    can_check_driver_shift_time_limit_impl(driver_shift_time, expected);
+}}
+
+can_check_driver_shift_time_limit! {
+    case_01: (12., Ok(())),
+    case_02: (10., Err("driver shift time limit violation, expected: not more than 10, got: 12")),
+}
+
+fn can_check_driver_shift_time_limit_impl(driver_shift_time: f64, expected: Result<(), &str>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["some_real_vehicle".to_string()],
+                shifts: vec![VehicleShift {
+                    relays: Some(vec![VehicleRelay {
+                        location: (5., 0.).to_loc(),
+                        duration: 2.,
+                        times: None,
+                        tag: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    driver_shift_time: Some(driver_shift_time),
+                    tour_size: None,
+                    min_tour_size: None,
+                    areas: None,
+                    consecutive_driving: None,
+                    driving_time: None,
+                    allowed_areas: None,
+                    max_cost: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = create_test_solution(
+        Statistic::default(),
+        vec![
+            create_stop_with_activity(
+                "departure",
+                "departure",
+                (0., 0.),
+                1,
+                (format_time(0.).as_str(), format_time(0.).as_str()),
+                0,
+            ),
+            create_stop_with_activity(
+                "relay",
+                "relay",
+                (5., 0.),
+                1,
+                (format_time(5.).as_str(), format_time(7.).as_str()),
+                5,
+            ),
+            create_stop_with_activity(
+                "job1",
+                "delivery",
+                (8., 0.),
+                0,
+                (format_time(10.).as_str(), format_time(11.).as_str()),
+                8,
+            ),
+            create_stop_with_activity(
+                "arrival",
+                "arrival",
+                (0., 0.),
+                0,
+                (format_time(19.).as_str(), format_time(19.).as_str()),
+                16,
+            ),
+        ],
+    );
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_driver_shift_time(&ctx);
+
+    assert_eq!(result, expected.map_err(|msg| format!("{}, vehicle id 'some_real_vehicle', shift index: 0", msg)));
+}
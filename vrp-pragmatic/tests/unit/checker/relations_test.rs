@@ -88,7 +88,12 @@ mod single {
                     costs: create_default_vehicle_costs(),
                     shifts: vec![VehicleShift {
                         start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                        end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (0., 0.).to_loc() }),
+                        end: Some(ShiftEnd {
+                            earliest: None,
+                            latest: format_time(1000.),
+                            location: (0., 0.).to_loc(),
+                            is_open: None,
+                        }),
                         dispatch: None,
                         breaks: Some(vec![VehicleBreak::Optional {
                             time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(0.), format_time(1000.)]),
@@ -100,10 +105,28 @@ mod single {
                             duration: 2.0,
                             ..create_default_reload()
                         }]),
+                        recharges: None,
+                        trailer_parkings: None,
+                        maintenance: None,
+                        depots: None,
+                        overtime: None,
+                        unloading: None,
+                        relays: None,
                     }],
                     capacity: vec![5],
                     skills: None,
+                    skill_counts: None,
                     limits: None,
+                    tier: None,
+                    loading: None,
+                    compartments: None,
+                    energy: None,
+                    emissions: None,
+                    trailer: None,
+                    physical: None,
+                    optimize_departure: None,
+                    duration_per_unit: None,
+                    named_capacity: None,
                 }],
                 ..create_default_fleet()
             },
@@ -115,6 +138,8 @@ mod single {
                 distance: 16,
                 duration: 25,
                 times: Timing { driving: 16, serving: 9, break_time: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![
                 VehicleTour {
@@ -212,6 +237,8 @@ mod single {
                         distance: 16,
                         duration: 25,
                         times: Timing { driving: 16, serving: 9, break_time: 2, ..Timing::default() },
+                        utilization: None,
+                        emissions: None,
                     },
                 },
                 VehicleTour {
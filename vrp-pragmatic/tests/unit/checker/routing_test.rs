@@ -15,7 +15,14 @@ fn create_test_problem() -> Problem {
 }
 
 fn create_test_statistic() -> Statistic {
-    Statistic { cost: 10., distance: 4, duration: 6, times: Timing { driving: 4, serving: 2, ..Timing::default() } }
+    Statistic {
+        cost: 10.,
+        distance: 4,
+        duration: 6,
+        times: Timing { driving: 4, serving: 2, ..Timing::default() },
+        utilization: None,
+        emissions: None,
+    }
 }
 
 fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> Solution {
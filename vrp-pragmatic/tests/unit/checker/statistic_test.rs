@@ -0,0 +1,189 @@
+use super::*;
+use crate::format::problem::PragmaticProblem;
+use crate::helpers::*;
+use std::sync::Arc;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_statistic() -> Statistic {
+    Statistic {
+        cost: 10.,
+        distance: 4,
+        duration: 6,
+        times: Timing { driving: 4, serving: 2, ..Timing::default() },
+        utilization: None,
+        emissions: None,
+    }
+}
+
+fn create_test_solution(statistic: Statistic) -> Solution {
+    Solution {
+        statistic: statistic.clone(),
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    2,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (1., 0.),
+                    1,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+                create_stop_with_activity(
+                    "job2",
+                    "delivery",
+                    (2., 0.),
+                    0,
+                    ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
+                    2,
+                ),
+                create_stop_with_activity(
+                    "arrival",
+                    "arrival",
+                    (0., 0.),
+                    0,
+                    ("1970-01-01T00:00:06Z", "1970-01-01T00:00:06Z"),
+                    4,
+                ),
+            ],
+            statistic,
+        }],
+        ..create_empty_solution()
+    }
+}
+
+parameterized_test! {can_check_tour_statistic, (statistic, expected_result), {
+    can_check_tour_statistic_impl(statistic, expected_result);
+}}
+
+can_check_tour_statistic! {
+    case_01: (create_test_statistic(), Ok(())),
+
+    case_02: (Statistic {
+        times: Timing { driving: 4, serving: 2, waiting: 6, ..Timing::default() },
+        ..create_test_statistic()
+    }, Err(vec![
+        "timing mismatch for tour statistic: my_vehicle_1, expected duration: '6', got sum of times: '12'".to_string(),
+        "waiting time mismatch for tour statistic: my_vehicle_1, expected: '0', got: '6'".to_string(),
+    ])),
+
+    case_03: (Statistic {
+        duration: 12,
+        times: Timing { driving: 10, serving: 2, ..Timing::default() },
+        ..create_test_statistic()
+    }, Err(vec!["driving time mismatch for tour statistic: my_vehicle_1, expected: '4', got: '10'".to_string()])),
+
+    case_04: (Statistic {
+        duration: 12,
+        times: Timing { driving: 4, serving: 2, waiting: 6, ..Timing::default() },
+        ..create_test_statistic()
+    }, Err(vec!["waiting time mismatch for tour statistic: my_vehicle_1, expected: '0', got: '6'".to_string()])),
+
+    case_05: (Statistic {
+        distance: 10,
+        ..create_test_statistic()
+    }, Err(vec!["distance mismatch for tour statistic: my_vehicle_1, expected: '4', got: '10'".to_string()])),
+}
+
+fn create_checker_context(problem: Problem, solution: Solution) -> CheckerContext {
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = Arc::new((problem.clone(), vec![matrix.clone()]).read_pragmatic().unwrap());
+
+    CheckerContext::new(core_problem, problem, Some(vec![matrix]), solution).unwrap()
+}
+
+fn can_check_tour_statistic_impl(statistic: Statistic, expected_result: Result<(), Vec<String>>) {
+    let ctx = create_checker_context(create_test_problem(), create_test_solution(statistic));
+
+    let result = check_statistic(&ctx);
+
+    assert_eq!(result, expected_result);
+}
+
+#[test]
+fn can_check_solution_timing() {
+    let mut solution = create_test_solution(create_test_statistic());
+    solution.statistic.times.serving = 3;
+    let ctx = create_checker_context(create_test_problem(), solution);
+
+    let result = check_statistic(&ctx);
+
+    assert_eq!(
+        result,
+        Err(vec!["solution timing mismatch, expected: 'Timing { driving: 4, serving: 2, waiting: 0, break_time: 0, \
+             commuting: 0, parking: 0 }', got: 'Timing { driving: 4, serving: 3, waiting: 0, break_time: 0, \
+             commuting: 0, parking: 0 }'"
+            .to_string()])
+    );
+}
+
+#[test]
+fn can_check_stop_load() {
+    let mut solution = create_test_solution(create_test_statistic());
+    if let Stop::Point(stop) = &mut solution.tours[0].stops[1] {
+        stop.load = vec![2];
+    }
+    let ctx = create_checker_context(create_test_problem(), solution);
+
+    let result = check_statistic(&ctx);
+
+    assert_eq!(
+        result,
+        Err(vec!["load mismatch for tour statistic: my_vehicle_1, stop: 1, expected: '[1]', got: '[2]'".to_string()])
+    );
+}
+
+#[test]
+fn can_check_stop_distance() {
+    let mut solution = create_test_solution(create_test_statistic());
+    if let Stop::Point(stop) = &mut solution.tours[0].stops[2] {
+        stop.distance = 5;
+    }
+    let ctx = create_checker_context(create_test_problem(), solution);
+
+    let result = check_statistic(&ctx);
+
+    assert_eq!(
+        result,
+        Err(vec!["distance mismatch for tour statistic: my_vehicle_1, stop: 2, expected: '2', got: '5'".to_string()])
+    );
+}
+
+#[test]
+fn can_check_stop_travel_time() {
+    let mut solution = create_test_solution(create_test_statistic());
+    if let Stop::Point(stop) = &mut solution.tours[0].stops[3] {
+        stop.time.arrival = "1970-01-01T00:00:09Z".to_string();
+        stop.time.departure = "1970-01-01T00:00:09Z".to_string();
+    }
+    let ctx = create_checker_context(create_test_problem(), solution);
+
+    let result = check_statistic(&ctx);
+
+    assert_eq!(
+        result,
+        Err(
+            vec!["travel time mismatch for tour statistic: my_vehicle_1, stop: 3, expected: '2', got: '5'".to_string()]
+        )
+    );
+}
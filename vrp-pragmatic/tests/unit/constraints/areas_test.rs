@@ -0,0 +1,94 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::problem::Vehicle;
+
+const VIOLATION_CODE: i32 = 1;
+
+fn create_job(areas: Option<Vec<&str>>) -> Job {
+    let mut single = create_single_with_location(None);
+    single.dimens.set_job_areas(areas.map(|areas| areas.into_iter().map(|id| id.to_string()).collect()));
+
+    Job::Single(Arc::new(single))
+}
+
+fn create_vehicle(id: &str, penalty: Option<f64>) -> Arc<Vehicle> {
+    let mut vehicle = test_vehicle(id);
+    vehicle.dimens.set_vehicle_areas(VehicleAreas { areas: vec!["a1".to_string()].into_iter().collect(), penalty });
+
+    Arc::new(vehicle)
+}
+
+parameterized_test! {can_evaluate_job, (vehicle_id, job_areas, expected), {
+    can_evaluate_job_impl(vehicle_id, job_areas, expected);
+}}
+
+can_evaluate_job! {
+    case01_allowed_area: ("v1", Some(vec!["a1"]), None),
+    case02_one_of_areas: ("v1", Some(vec!["a2", "a1"]), None),
+    case03_other_area: ("v1", Some(vec!["a2"]), Some(VIOLATION_CODE)),
+    case04_no_area: ("v1", None, Some(VIOLATION_CODE)),
+    case05_soft_restriction: ("v2", Some(vec!["a2"]), None),
+    case06_no_restriction: ("v3", None, None),
+}
+
+fn can_evaluate_job_impl(vehicle_id: &str, job_areas: Option<Vec<&str>>, expected: Option<i32>) {
+    let fleet = test_fleet_with_vehicles(vec![
+        create_vehicle("v1", None),
+        create_vehicle("v2", Some(10.)),
+        Arc::new(test_vehicle("v3")),
+    ]);
+    let solution_ctx = create_solution_context_for_fleet(&fleet);
+    let route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&fleet, vehicle_id, vec![])),
+        Arc::new(RouteState::default()),
+    );
+    let module = AreaModule::new_restricted(VIOLATION_CODE);
+
+    let result = module
+        .get_constraints()
+        .find_map(|constraint| match constraint {
+            ConstraintVariant::HardRoute(constraint) => Some(constraint.clone()),
+            _ => None,
+        })
+        .unwrap()
+        .evaluate_job(&solution_ctx, &route_ctx, &create_job(job_areas))
+        .map(|violation| violation.code);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_get_penalty, (penalty, job_areas, expected), {
+    can_get_penalty_impl(penalty, job_areas, expected);
+}}
+
+can_get_penalty! {
+    case01_inside: (Some(10.), Some(vec!["a1"]), 0.),
+    case02_outside: (Some(10.), Some(vec!["a2"]), 10.),
+    case03_no_area: (Some(10.), None, 10.),
+    case04_hard: (None, Some(vec!["a2"]), 0.),
+}
+
+fn can_get_penalty_impl(penalty: Option<f64>, job_areas: Option<Vec<&str>>, expected: f64) {
+    let areas = VehicleAreas { areas: vec!["a1".to_string()].into_iter().collect(), penalty };
+
+    assert_eq!(areas.get_penalty(create_job(job_areas).dimens()), expected);
+}
+
+parameterized_test! {can_merge_jobs, (source, candidate, expected), {
+    can_merge_jobs_impl(source, candidate, expected);
+}}
+
+can_merge_jobs! {
+    case01_no_areas: (None, None, Ok(())),
+    case02_same_areas: (Some(vec!["a1"]), Some(vec!["a1"]), Ok(())),
+    case03_different_areas: (Some(vec!["a1"]), Some(vec!["a2"]), Err(VIOLATION_CODE)),
+    case04_source_only: (Some(vec!["a1"]), None, Err(VIOLATION_CODE)),
+}
+
+fn can_merge_jobs_impl(source: Option<Vec<&str>>, candidate: Option<Vec<&str>>, expected: Result<(), i32>) {
+    let result =
+        AreaModule::new_restricted(VIOLATION_CODE).merge(create_job(source), create_job(candidate)).map(|_| ());
+
+    assert_eq!(result, expected);
+}
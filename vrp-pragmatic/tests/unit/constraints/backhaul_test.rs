@@ -0,0 +1,120 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::{Dimensions, Location};
+use vrp_core::models::problem::Fleet;
+
+const VIOLATION_CODE: i32 = 1;
+
+fn create_fleet(policy: Option<BackhaulPolicy>) -> Fleet {
+    let mut vehicle = test_vehicle("v1");
+    if let Some(policy) = policy {
+        vehicle.dimens.set_backhaul_policy(policy);
+    }
+
+    test_fleet_with_vehicles(vec![Arc::new(vehicle)])
+}
+
+fn create_single(id: &str, location: Location, job_type: &str) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(location));
+    single.dimens.set_job_id(id.to_string()).set_job_type(job_type.to_string());
+
+    Arc::new(single)
+}
+
+fn create_activity(id: &str, location: Location, job_type: &str) -> Activity {
+    create_activity_with_job_at_location(create_single(id, location, job_type), location)
+}
+
+/// Returns pickup and delivery activities with their multi job which has to be kept alive.
+fn create_pickup_delivery(id: &str, locations: (Location, Location)) -> (Activity, Activity, Arc<Multi>) {
+    let mut dimens = Dimensions::default();
+    dimens.set_job_id(id.to_string());
+    let multi = Multi::new_shared(
+        vec![create_single(id, locations.0, "pickup"), create_single(id, locations.1, "delivery")],
+        dimens,
+    );
+
+    (
+        create_activity_with_job_at_location(multi.jobs[0].clone(), locations.0),
+        create_activity_with_job_at_location(multi.jobs[1].clone(), locations.1),
+        multi,
+    )
+}
+
+fn create_route_ctx(policy: Option<BackhaulPolicy>, activities: Vec<Activity>) -> RouteContext {
+    RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&create_fleet(policy), "v1", activities)),
+        Arc::new(RouteState::default()),
+    )
+}
+
+fn evaluate(route_ctx: &RouteContext, target: &Activity, index: usize) -> (Option<i32>, f64) {
+    let tour = &route_ctx.route.tour;
+    let activity_ctx = ActivityContext { index, prev: tour.get(index).unwrap(), target, next: tour.get(index + 1) };
+    let module = BackhaulModule::new(VIOLATION_CODE);
+
+    let hard = module.get_constraints().find_map(|constraint| match constraint {
+        ConstraintVariant::HardActivity(constraint) => Some(constraint.clone()),
+        _ => None,
+    });
+    let soft = module.get_constraints().find_map(|constraint| match constraint {
+        ConstraintVariant::SoftActivity(constraint) => Some(constraint.clone()),
+        _ => None,
+    });
+
+    (
+        hard.unwrap().evaluate_activity(route_ctx, &activity_ctx).map(|violation| violation.code),
+        soft.unwrap().estimate_activity(route_ctx, &activity_ctx),
+    )
+}
+
+parameterized_test! {can_evaluate_insertion, (policy, job_type, index, expected), {
+    can_evaluate_insertion_impl(policy, job_type, index, expected);
+}}
+
+can_evaluate_insertion! {
+    case01_strict_pickup_after_deliveries: (Some(BackhaulPolicy::Strict), "pickup", 2, (None, 0.)),
+    case02_strict_pickup_between_deliveries: (Some(BackhaulPolicy::Strict), "pickup", 1, (Some(VIOLATION_CODE), 0.)),
+    case03_strict_delivery_before_pickups: (Some(BackhaulPolicy::Strict), "delivery", 1, (None, 0.)),
+    case04_strict_delivery_after_pickup: (Some(BackhaulPolicy::Strict), "delivery", 3, (Some(VIOLATION_CODE), 0.)),
+    case05_strict_service: (Some(BackhaulPolicy::Strict), "service", 0, (None, 0.)),
+
+    case06_soft_pickup_before_deliveries: (Some(BackhaulPolicy::Soft(10.)), "pickup", 0, (None, 20.)),
+    case07_soft_pickup_after_deliveries: (Some(BackhaulPolicy::Soft(10.)), "pickup", 2, (None, 0.)),
+    case08_soft_delivery_after_pickups: (Some(BackhaulPolicy::Soft(10.)), "delivery", 4, (None, 20.)),
+
+    case09_no_policy: (None, "pickup", 0, (None, 0.)),
+}
+
+fn can_evaluate_insertion_impl(
+    policy: Option<BackhaulPolicy>,
+    job_type: &str,
+    index: usize,
+    expected: (Option<i32>, f64),
+) {
+    let route_ctx = create_route_ctx(
+        policy,
+        vec![
+            create_activity("job1", 1, "delivery"),
+            create_activity("job2", 2, "delivery"),
+            create_activity("job3", 3, "pickup"),
+            create_activity("job4", 4, "pickup"),
+        ],
+    );
+    let target = create_activity("job5", 5, job_type);
+
+    let result = evaluate(&route_ctx, &target, index);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_ignore_pickup_delivery_jobs() {
+    let (pickup, delivery, _multi) = create_pickup_delivery("job2", (2, 3));
+    let route_ctx =
+        create_route_ctx(Some(BackhaulPolicy::Strict), vec![create_activity("job1", 1, "delivery"), pickup]);
+
+    assert_eq!(evaluate(&route_ctx, &delivery, 2), (None, 0.));
+    assert_eq!(evaluate(&route_ctx, &create_activity("job3", 3, "pickup"), 0), (Some(VIOLATION_CODE), 0.));
+}
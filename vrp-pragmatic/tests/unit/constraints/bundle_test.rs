@@ -0,0 +1,122 @@
+use super::*;
+use crate::helpers::*;
+use hashbrown::HashMap;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::problem::{Fleet, Single};
+
+const VIOLATION_CODE: i32 = 1;
+
+fn create_fleet() -> Fleet {
+    test_fleet_with_vehicles(vec![Arc::new(test_vehicle("v1")), Arc::new(test_vehicle("v2"))])
+}
+
+fn create_job(id: &str, bundle: Option<&str>) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_job_id(id.to_string()).set_job_bundle(bundle.map(|bundle| bundle.to_string()));
+
+    Arc::new(single)
+}
+
+fn create_route_ctx(fleet: &Fleet, vehicle_id: &str, jobs: Vec<Arc<Single>>) -> RouteContext {
+    let activities = jobs.into_iter().map(|job| create_activity_with_job_at_location(job, 1)).collect();
+
+    RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(fleet, vehicle_id, activities)),
+        Arc::new(RouteState::default()),
+    )
+}
+
+fn get_job_ids<'a>(jobs: impl Iterator<Item = &'a Job>) -> Vec<String> {
+    let mut ids = jobs.map(|job| job.dimens().get_job_id().unwrap().clone()).collect::<Vec<_>>();
+    ids.sort();
+
+    ids
+}
+
+parameterized_test! {can_eject_incomplete_bundle, (partner_state, expected_required, expected_unassigned, expected_routes), {
+    can_eject_incomplete_bundle_impl(partner_state, expected_required, expected_unassigned, expected_routes);
+}}
+
+can_eject_incomplete_bundle! {
+    case01_partner_assigned: ("assigned", vec![], vec![], vec![vec!["job1", "job3"], vec!["job2"]]),
+    case02_partner_required: ("required", vec!["job1", "job2"], vec![], vec![vec!["job3"]]),
+    case03_partner_unassigned: ("unassigned", vec![], vec!["job1", "job2"], vec![vec!["job3"]]),
+    case04_partner_retried: ("retried", vec!["job1", "job2"], vec!["job2"], vec![vec!["job3"]]),
+}
+
+fn can_eject_incomplete_bundle_impl(
+    partner_state: &str,
+    expected_required: Vec<&str>,
+    expected_unassigned: Vec<&str>,
+    expected_routes: Vec<Vec<&str>>,
+) {
+    let fleet = create_fleet();
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    let partner = create_job("job2", Some("bundle1"));
+    solution_ctx.routes.push(create_route_ctx(
+        &fleet,
+        "v1",
+        vec![create_job("job1", Some("bundle1")), create_job("job3", None)],
+    ));
+    match partner_state {
+        "assigned" => solution_ctx.routes.push(create_route_ctx(&fleet, "v2", vec![partner])),
+        "required" => solution_ctx.required.push(Job::Single(partner)),
+        "unassigned" => {
+            solution_ctx.unassigned.insert(Job::Single(partner), UnassignmentInfo::Unknown);
+        }
+        "retried" => {
+            solution_ctx.required.push(Job::Single(partner.clone()));
+            solution_ctx.unassigned.insert(Job::Single(partner), UnassignmentInfo::Unknown);
+        }
+        _ => unreachable!(),
+    }
+
+    BundleModule::new(VIOLATION_CODE).accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(get_job_ids(solution_ctx.required.iter()), expected_required);
+    assert_eq!(get_job_ids(solution_ctx.unassigned.keys()), expected_unassigned);
+    let mut actual_routes = solution_ctx
+        .routes
+        .iter()
+        .map(|route_ctx| get_job_ids(route_ctx.route.tour.jobs().collect::<Vec<_>>().iter()))
+        .collect::<Vec<_>>();
+    actual_routes.sort();
+    assert_eq!(actual_routes, expected_routes);
+}
+
+#[test]
+fn can_unassign_bundle_jobs_with_bundle_code() {
+    let fleet = create_fleet();
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    solution_ctx.routes.push(create_route_ctx(&fleet, "v1", vec![create_job("job1", Some("bundle1"))]));
+    solution_ctx.unassigned.insert(Job::Single(create_job("job2", Some("bundle1"))), UnassignmentInfo::Simple(2));
+
+    BundleModule::new(VIOLATION_CODE).accept_solution_state(&mut solution_ctx);
+
+    let codes = solution_ctx
+        .unassigned
+        .iter()
+        .map(|(job, code)| {
+            let code = match code {
+                UnassignmentInfo::Simple(code) => *code,
+                _ => unreachable!(),
+            };
+            (job.dimens().get_job_id().unwrap().clone(), code)
+        })
+        .collect::<HashMap<_, _>>();
+    assert_eq!(codes.get("job1"), Some(&VIOLATION_CODE));
+    assert_eq!(codes.get("job2"), Some(&2));
+    assert!(solution_ctx.routes.is_empty());
+}
+
+#[test]
+fn can_merge_only_non_bundle_jobs() {
+    let module = BundleModule::new(VIOLATION_CODE);
+    let job = Job::Single(create_job("job1", None));
+    let bundle = Job::Single(create_job("job2", Some("bundle1")));
+
+    assert!(module.merge(job.clone(), job.clone()).is_ok());
+    assert_eq!(module.merge(job.clone(), bundle.clone()).map(|_| ()), Err(VIOLATION_CODE));
+    assert_eq!(module.merge(bundle, job).map(|_| ()), Err(VIOLATION_CODE));
+}
@@ -0,0 +1,57 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::problem::Single;
+
+fn create_break_job() -> Arc<Single> {
+    let mut single = create_single_with_location(None);
+    single.dimens.set_job_id("break".to_string()).set_job_type("break".to_string()).set_vehicle_id("v1".to_string());
+
+    Arc::new(single)
+}
+
+fn create_route_check() -> RouteCheckFn {
+    // NOTE job3 cannot be served together with job1 and job2 cannot be served at all
+    Arc::new(|route: &RouteCheck| {
+        assert_eq!(route.vehicle_id, "v1");
+        assert_eq!(route.shift_index, 0);
+        assert!(!route.job_ids.iter().any(|id| id == "break"));
+
+        match route.job_id.as_str() {
+            "job2" => false,
+            "job3" => !route.job_ids.iter().any(|id| id == "job1"),
+            _ => true,
+        }
+    })
+}
+
+parameterized_test! {can_evaluate_custom_route_check, (route_jobs, job, expected), {
+    can_evaluate_custom_route_check_impl(route_jobs, job, expected);
+}}
+
+can_evaluate_custom_route_check! {
+    case01: (vec![], create_single("job1"), None),
+    case02: (vec![], create_single("job2"), Some(1)),
+    case03: (vec![], create_single("job3"), None),
+    case04: (vec![create_single("job1")], create_single("job3"), Some(1)),
+    case05: (vec![create_single("job4"), create_break_job()], create_single("job3"), None),
+    case06: (vec![create_single("job1")], create_break_job(), None),
+}
+
+fn can_evaluate_custom_route_check_impl(route_jobs: Vec<Arc<Single>>, job: Arc<Single>, expected: Option<i32>) {
+    let fleet = test_fleet();
+    let route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(
+            &fleet,
+            "v1",
+            route_jobs.into_iter().map(|single| create_activity_with_job_at_location(single, 1)).collect(),
+        )),
+        Arc::new(RouteState::default()),
+    );
+
+    let actual = ConstraintPipeline::default()
+        .add_module(Arc::new(CustomRouteModule::new(create_route_check(), 1)))
+        .evaluate_hard_route(&create_solution_context_for_fleet(&fleet), &route_ctx, &Job::Single(job));
+
+    assert_eq!(actual.map(|violation| violation.code), expected);
+}
@@ -0,0 +1,170 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::{Location, TimeSpan};
+use vrp_core::models::problem::{create_matrix_transport_cost, Fleet, MatrixData, SimpleActivityCost, Single};
+
+const VIOLATION_CODE: i32 = 1;
+const STATE_KEY: i32 = 2;
+
+fn create_transport() -> Arc<dyn TransportCost + Send + Sync> {
+    let size = 11;
+    let durations = (0..size)
+        .flat_map(|from: usize| (0..size).map(move |to: usize| (from as f64 - to as f64).abs()))
+        .collect::<Vec<_>>();
+
+    create_matrix_transport_cost(vec![MatrixData::new(0, None, durations.clone(), durations)]).unwrap()
+}
+
+fn create_module(capacity: usize) -> DockModule {
+    DockModule::new(
+        create_transport(),
+        Arc::new(SimpleActivityCost::default()),
+        vec![("dock".to_string(), capacity)].into_iter().collect(),
+        VIOLATION_CODE,
+        STATE_KEY,
+    )
+}
+
+fn create_fleet() -> Fleet {
+    test_fleet_with_vehicles(vec![Arc::new(test_vehicle("v1")), Arc::new(test_vehicle("v2"))])
+}
+
+fn create_reload(vehicle: &str, location: Location, duration: Duration, time: (f64, f64)) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(location));
+    single.places[0].duration = duration;
+    single.places[0].times = vec![TimeSpan::Window(TimeWindow::new(time.0, time.1))];
+    single
+        .dimens
+        .set_job_id(format!("{}_reload", vehicle))
+        .set_job_type("reload".to_string())
+        .set_job_dock(Some("dock".to_string()))
+        .set_vehicle_id(vehicle.to_string());
+
+    Arc::new(single)
+}
+
+fn create_job(location: Location, time: (f64, f64)) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(location));
+    single.places[0].times = vec![TimeSpan::Window(TimeWindow::new(time.0, time.1))];
+    single.dimens.set_job_id(format!("job{}", location)).set_job_type("delivery".to_string());
+
+    Arc::new(single)
+}
+
+fn create_activity(single: Arc<Single>) -> Activity {
+    let place = &single.places[0];
+    let mut activity = create_activity_with_job_at_location(single.clone(), place.location.unwrap());
+    activity.place.duration = place.duration;
+    activity.place.time = place.times[0].to_time_window(0.);
+
+    activity
+}
+
+fn create_route_ctx(fleet: &Fleet, vehicle: &str, singles: Vec<Arc<Single>>) -> RouteContext {
+    let activities = singles.into_iter().map(create_activity).collect();
+
+    let mut route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(fleet, vehicle, activities)),
+        Arc::new(RouteState::default()),
+    );
+    TransportConstraintModule::new(create_transport(), Arc::new(SimpleActivityCost::default()), VIOLATION_CODE)
+        .accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+fn get_service_start(route_ctx: &RouteContext, index: usize) -> Timestamp {
+    let activity = route_ctx.route.tour.get(index).unwrap();
+    activity.schedule.arrival.max(activity.place.time.start)
+}
+
+parameterized_test! {can_delay_reload_when_docks_are_occupied, (capacity, expected), {
+    can_delay_reload_when_docks_are_occupied_impl(capacity, expected);
+}}
+
+can_delay_reload_when_docks_are_occupied! {
+    case01_single_dock: (1, (2., 5.)),
+    case02_two_docks: (2, (2., 2.)),
+}
+
+fn can_delay_reload_when_docks_are_occupied_impl(capacity: usize, expected: (Timestamp, Timestamp)) {
+    let fleet = create_fleet();
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    solution_ctx.routes = vec![
+        create_route_ctx(&fleet, "v1", vec![create_reload("v1", 2, 3., (0., 100.)), create_job(4, (0., 100.))]),
+        create_route_ctx(&fleet, "v2", vec![create_reload("v2", 2, 3., (0., 100.)), create_job(5, (0., 100.))]),
+    ];
+
+    create_module(capacity).accept_solution_state(&mut solution_ctx);
+
+    assert!(solution_ctx.unassigned.is_empty());
+    assert_eq!(get_service_start(&solution_ctx.routes[0], 1), expected.0);
+    assert_eq!(get_service_start(&solution_ctx.routes[1], 1), expected.1);
+}
+
+parameterized_test! {can_eject_reload_which_cannot_wait, (reload_end, job_end, expected_routes), {
+    can_eject_reload_which_cannot_wait_impl(reload_end, job_end, expected_routes);
+}}
+
+can_eject_reload_which_cannot_wait! {
+    case01_can_wait: (100., 100., 2),
+    case02_reload_time_window: (3., 100., 1),
+    case03_next_job_time_window: (100., 8., 1),
+}
+
+fn can_eject_reload_which_cannot_wait_impl(reload_end: f64, job_end: f64, expected_routes: usize) {
+    let fleet = create_fleet();
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    solution_ctx.routes = vec![
+        create_route_ctx(&fleet, "v1", vec![create_reload("v1", 2, 3., (0., 100.)), create_job(4, (0., 100.))]),
+        create_route_ctx(
+            &fleet,
+            "v2",
+            vec![create_reload("v2", 2, 3., (0., reload_end)), create_job(5, (0., job_end))],
+        ),
+    ];
+
+    create_module(1).accept_solution_state(&mut solution_ctx);
+
+    let expected_ejected = 2 - expected_routes;
+    assert_eq!(solution_ctx.routes.len(), expected_routes);
+    assert_eq!(solution_ctx.unassigned.len(), expected_ejected);
+    assert_eq!(solution_ctx.ignored.len(), expected_ejected);
+}
+
+parameterized_test! {can_evaluate_reload_insertion, (capacity, usage, expected), {
+    can_evaluate_reload_insertion_impl(capacity, usage, expected);
+}}
+
+can_evaluate_reload_insertion! {
+    case01_free_dock: (1, (10., 12.), None),
+    case02_wait_for_dock: (1, (2., 5.), None),
+    case03_wait_violates_next: (1, (2., 12.), Some(VIOLATION_CODE)),
+    case04_two_docks: (2, (2., 12.), None),
+}
+
+fn can_evaluate_reload_insertion_impl(capacity: usize, usage: (f64, f64), expected: Option<i32>) {
+    let fleet = create_fleet();
+    let other = fleet.actors.iter().find(|actor| actor.vehicle.dimens.get_vehicle_id().unwrap() == "v2").unwrap();
+    let mut route_ctx = create_route_ctx(&fleet, "v1", vec![create_job(4, (0., 10.))]);
+    route_ctx.state_mut().put_route_state(
+        STATE_KEY,
+        DockUsage::from_iter(vec![("dock".to_string(), vec![(other.clone(), TimeWindow::new(usage.0, usage.1))])]),
+    );
+    let target = create_activity(create_reload("v1", 3, 2., (0., 100.)));
+    let tour = &route_ctx.route.tour;
+    let activity_ctx = ActivityContext { index: 0, prev: tour.get(0).unwrap(), target: &target, next: tour.get(1) };
+
+    let result = create_module(capacity)
+        .get_constraints()
+        .find_map(|constraint| match constraint {
+            ConstraintVariant::HardActivity(constraint) => Some(constraint.clone()),
+            _ => None,
+        })
+        .unwrap()
+        .evaluate_activity(&route_ctx, &activity_ctx)
+        .map(|violation| violation.code);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,79 @@
+use super::*;
+use crate::extensions::JobTie;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::{Demand, DemandDimension, Location, SingleDimLoad};
+use vrp_core::models::problem::{create_matrix_transport_cost, MatrixData};
+use vrp_core::models::solution::Activity;
+
+fn create_calculator() -> EmissionCalculator {
+    let size = 21;
+    let distances = (0..size)
+        .flat_map(|from: usize| (0..size).map(move |to: usize| (from as f64 - to as f64).abs()))
+        .collect::<Vec<_>>();
+    let transport = create_matrix_transport_cost(vec![MatrixData::new(0, None, distances.clone(), distances)]).unwrap();
+
+    EmissionCalculator::new(
+        transport,
+        Arc::new(|single| {
+            single.dimens.get_demand().map_or((0., 0.), |demand: &Demand<SingleDimLoad>| {
+                (demand.delivery.0.value as f64, (demand.pickup.0.value - demand.delivery.0.value) as f64)
+            })
+        }),
+    )
+}
+
+fn create_route_ctx(profile: Option<EmissionProfile>, activities: Vec<Activity>) -> RouteContext {
+    let mut vehicle = test_vehicle("v1");
+    if let Some(profile) = profile {
+        vehicle.dimens.set_vehicle_emissions(profile);
+    }
+    let fleet = test_fleet_with_vehicles(vec![Arc::new(vehicle)]);
+
+    RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&fleet, "v1", activities)),
+        Arc::new(RouteState::default()),
+    )
+}
+
+fn create_job_activity(location: Location, delivery: i32) -> Activity {
+    let demand = Demand::<SingleDimLoad> {
+        pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
+        delivery: (SingleDimLoad::new(delivery), SingleDimLoad::default()),
+    };
+    let mut single = create_single_with_location(Some(location));
+    single.dimens.set_job_id("job".to_string()).set_job_type("delivery".to_string()).set_demand(demand);
+
+    create_activity_with_job_at_location(Arc::new(single), location)
+}
+
+parameterized_test! {can_calculate_route_emissions, (load_consumption, deliveries, expected), {
+    can_calculate_route_emissions_impl(load_consumption, deliveries, expected);
+}}
+
+can_calculate_route_emissions! {
+    case01_no_load_consumption: (0., (10, 1), (20., 40.)),
+    case02_heavy_job_first: (0.1, (10, 1), (26., 52.)),
+    case03_light_job_first: (0.1, (1, 10), (30.5, 61.)),
+}
+
+fn can_calculate_route_emissions_impl(load_consumption: f64, deliveries: (i32, i32), expected: (f64, f64)) {
+    let profile = EmissionProfile { consumption: 1., load_consumption, co2_factor: 2. };
+    let route_ctx = create_route_ctx(
+        Some(profile),
+        vec![create_job_activity(5, deliveries.0), create_job_activity(10, deliveries.1)],
+    );
+
+    let result = create_calculator().calculate(&route_ctx.route);
+
+    assert_eq!(result, Some(RouteEmissions { fuel: expected.0, co2: expected.1 }));
+}
+
+#[test]
+fn can_skip_vehicle_without_emission_profile() {
+    let route_ctx = create_route_ctx(None, vec![create_job_activity(5, 1)]);
+
+    let result = create_calculator().calculate(&route_ctx.route);
+
+    assert!(result.is_none());
+}
@@ -0,0 +1,164 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::{Demand, DemandDimension, Location, SingleDimLoad};
+use vrp_core::models::problem::{create_matrix_transport_cost, MatrixData};
+
+const VIOLATION_CODE: i32 = 1;
+const STATE_KEY: i32 = 2;
+
+fn create_energy_module() -> EnergyModule {
+    let size = 21;
+    let distances = (0..size)
+        .flat_map(|from: usize| (0..size).map(move |to: usize| (from as f64 - to as f64).abs()))
+        .collect::<Vec<_>>();
+    let transport = create_matrix_transport_cost(vec![MatrixData::new(0, None, distances.clone(), distances)]).unwrap();
+
+    EnergyModule::new(
+        transport,
+        Arc::new(|single| {
+            single.dimens.get_demand().map_or((0., 0.), |demand: &Demand<SingleDimLoad>| {
+                (demand.delivery.0.value as f64, (demand.pickup.0.value - demand.delivery.0.value) as f64)
+            })
+        }),
+        VIOLATION_CODE,
+        STATE_KEY,
+    )
+}
+
+fn create_route_ctx(profile: EnergyProfile, activities: Vec<Activity>) -> RouteContext {
+    let mut vehicle = test_vehicle("v1");
+    vehicle.dimens.set_vehicle_energy(profile);
+    let fleet = test_fleet_with_vehicles(vec![Arc::new(vehicle)]);
+
+    RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&fleet, "v1", activities)),
+        Arc::new(RouteState::default()),
+    )
+}
+
+fn create_profile(reserve: f64, load_consumption: f64) -> EnergyProfile {
+    EnergyProfile { capacity: 20., consumption: 1., load_consumption, reserve }
+}
+
+fn create_job_activity(location: Location, delivery: i32) -> Activity {
+    let demand = Demand::<SingleDimLoad> {
+        pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
+        delivery: (SingleDimLoad::new(delivery), SingleDimLoad::default()),
+    };
+    let mut single = create_single_with_location(Some(location));
+    single.dimens.set_job_id("job".to_string()).set_job_type("delivery".to_string()).set_demand(demand);
+
+    create_activity_with_job_at_location(Arc::new(single), location)
+}
+
+fn create_recharge_activity(location: Location, rate: f64) -> Activity {
+    let mut single = create_single_with_location(Some(location));
+    single
+        .dimens
+        .set_job_id("v1_recharge_0_1".to_string())
+        .set_job_type("recharge".to_string())
+        .set_vehicle_id("v1".to_string())
+        .set_shift_index(0)
+        .set_recharge_rate(rate);
+
+    create_activity_with_job_at_location(Arc::new(single), location)
+}
+
+fn evaluate(route_ctx: &RouteContext, target: &Activity, index: usize) -> Option<i32> {
+    let tour = &route_ctx.route.tour;
+    let activity_ctx = ActivityContext { index, prev: tour.get(index).unwrap(), target, next: tour.get(index + 1) };
+
+    create_energy_module()
+        .get_constraints()
+        .find_map(|constraint| match constraint {
+            ConstraintVariant::HardActivity(constraint) => Some(constraint.clone()),
+            _ => None,
+        })
+        .unwrap()
+        .evaluate_activity(route_ctx, &activity_ctx)
+        .map(|violation| violation.code)
+}
+
+parameterized_test! {can_check_energy_level_on_insertion, (has_recharge, reserve, load_consumption, target_location, expected), {
+    can_check_energy_level_on_insertion_impl(has_recharge, reserve, load_consumption, target_location, expected);
+}}
+
+can_check_energy_level_on_insertion! {
+    case01_enough_energy: (false, 0., 0., 8, None),
+    case02_enough_energy_above_reserve: (false, 5., 0., 7, None),
+    case03_below_reserve: (false, 5., 0., 8, Some(VIOLATION_CODE)),
+    case04_not_enough_energy: (false, 0., 0., 11, Some(VIOLATION_CODE)),
+    case05_recharge_restores_energy: (true, 0., 0., 11, None),
+    case06_load_consumption: (false, 0., 0.1, 8, Some(VIOLATION_CODE)),
+    case07_load_consumption_with_recharge: (true, 0., 0.1, 8, None),
+}
+
+fn can_check_energy_level_on_insertion_impl(
+    has_recharge: bool,
+    reserve: f64,
+    load_consumption: f64,
+    target_location: Location,
+    expected: Option<i32>,
+) {
+    let activities = if has_recharge {
+        vec![create_job_activity(5, 10), create_recharge_activity(5, 1.)]
+    } else {
+        vec![create_job_activity(5, 10)]
+    };
+    let route_ctx = create_route_ctx(create_profile(reserve, load_consumption), activities);
+    let target = create_job_activity(target_location, 0);
+    let index = route_ctx.route.tour.total() - 2;
+
+    let result = evaluate(&route_ctx, &target, index);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_update_recharge_duration, (rate, load_consumption, expected_duration, expected_level), {
+    can_update_recharge_duration_impl(rate, load_consumption, expected_duration, expected_level);
+}}
+
+can_update_recharge_duration! {
+    case01_simple: (1., 0., 10., 10.),
+    case02_faster_rate: (4., 0., 3., 10.),
+    case03_load_consumption: (1., 0.1, 15., 5.),
+}
+
+fn can_update_recharge_duration_impl(rate: f64, load_consumption: f64, expected_duration: f64, expected_level: f64) {
+    let mut route_ctx = create_route_ctx(
+        create_profile(0., load_consumption),
+        vec![create_job_activity(5, 10), create_recharge_activity(10, rate)],
+    );
+
+    create_energy_module().accept_route_state(&mut route_ctx);
+
+    assert_eq!(route_ctx.route.tour.get(2).unwrap().place.duration, expected_duration);
+    assert_eq!(route_ctx.state.get_route_state::<f64>(STATE_KEY).cloned(), Some(expected_level));
+}
+
+#[test]
+fn can_remove_obsolete_recharge() {
+    let fleet = test_fleet();
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    solution_ctx.routes.push(create_route_ctx(
+        create_profile(0., 0.),
+        vec![create_job_activity(2, 0), create_recharge_activity(3, 1.), create_job_activity(4, 0)],
+    ));
+
+    create_energy_module().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.routes[0].route.tour.job_activity_count(), 2);
+    assert_eq!(solution_ctx.ignored.len(), 1);
+}
+
+#[test]
+fn can_merge_only_non_recharge_jobs() {
+    let module = create_energy_module();
+    let job = Job::Single(create_job_activity(1, 0).job.unwrap());
+    let recharge = Job::Single(create_recharge_activity(1, 1.).job.unwrap());
+
+    assert!(module.merge(job.clone(), job.clone()).is_ok());
+    assert_eq!(module.merge(job.clone(), recharge.clone()).map(|_| ()), Err(VIOLATION_CODE));
+    assert_eq!(module.merge(recharge, job).map(|_| ()), Err(VIOLATION_CODE));
+}
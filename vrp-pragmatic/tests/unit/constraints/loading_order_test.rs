@@ -0,0 +1,125 @@
+use super::*;
+use crate::extensions::JobTie;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::{Dimensions, Location};
+use vrp_core::models::problem::{Fleet, Multi, Single};
+
+const VIOLATION_CODE: i32 = 1;
+
+fn create_fleet(order: Option<LoadingOrder>) -> Fleet {
+    let mut vehicle = test_vehicle("v1");
+    if let Some(order) = order {
+        vehicle.dimens.set_loading_order(order);
+    }
+
+    test_fleet_with_vehicles(vec![Arc::new(vehicle)])
+}
+
+fn create_single(id: &str, location: Location, job_type: &str) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(location));
+    single.dimens.set_job_id(id.to_string()).set_job_type(job_type.to_string());
+
+    Arc::new(single)
+}
+
+/// Returns pickup and delivery activities with their multi job which has to be kept alive.
+fn create_pickup_delivery(id: &str, locations: (Location, Location)) -> (Activity, Activity, Arc<Multi>) {
+    let mut dimens = Dimensions::default();
+    dimens.set_job_id(id.to_string());
+    let multi = Multi::new_shared(
+        vec![create_single(id, locations.0, "pickup"), create_single(id, locations.1, "delivery")],
+        dimens,
+    );
+
+    (
+        create_activity_with_job_at_location(multi.jobs[0].clone(), locations.0),
+        create_activity_with_job_at_location(multi.jobs[1].clone(), locations.1),
+        multi,
+    )
+}
+
+fn evaluate(route_ctx: &RouteContext, target: &Activity, index: usize) -> Option<i32> {
+    let tour = &route_ctx.route.tour;
+    let activity_ctx = ActivityContext { index, prev: tour.get(index).unwrap(), target, next: tour.get(index + 1) };
+
+    LoadingOrderModule::new(VIOLATION_CODE)
+        .get_constraints()
+        .find_map(|constraint| match constraint {
+            ConstraintVariant::HardActivity(constraint) => Some(constraint.clone()),
+            _ => None,
+        })
+        .unwrap()
+        .evaluate_activity(route_ctx, &activity_ctx)
+        .map(|violation| violation.code)
+}
+
+parameterized_test! {can_evaluate_delivery_insertion, (order, pickup_index, delivery_index, expected), {
+    can_evaluate_delivery_insertion_impl(order, pickup_index, delivery_index, expected);
+}}
+
+can_evaluate_delivery_insertion! {
+    case01_lifo_before: (Some(LoadingOrder::Lifo), 0, 1, None),
+    case02_lifo_around: (Some(LoadingOrder::Lifo), 0, 3, None),
+    case03_lifo_crossing: (Some(LoadingOrder::Lifo), 0, 2, Some(VIOLATION_CODE)),
+    case04_lifo_inside: (Some(LoadingOrder::Lifo), 1, 2, None),
+    case05_lifo_crossing_after: (Some(LoadingOrder::Lifo), 1, 3, Some(VIOLATION_CODE)),
+    case06_lifo_after: (Some(LoadingOrder::Lifo), 2, 3, None),
+
+    case07_fifo_before: (Some(LoadingOrder::Fifo), 0, 1, None),
+    case08_fifo_around: (Some(LoadingOrder::Fifo), 0, 3, Some(VIOLATION_CODE)),
+    case09_fifo_crossing: (Some(LoadingOrder::Fifo), 0, 2, None),
+    case10_fifo_inside: (Some(LoadingOrder::Fifo), 1, 2, Some(VIOLATION_CODE)),
+    case11_fifo_crossing_after: (Some(LoadingOrder::Fifo), 1, 3, None),
+    case12_fifo_after: (Some(LoadingOrder::Fifo), 2, 3, None),
+
+    case13_no_order_around: (None, 0, 3, None),
+    case14_no_order_crossing: (None, 0, 2, None),
+}
+
+fn can_evaluate_delivery_insertion_impl(
+    order: Option<LoadingOrder>,
+    pickup_index: usize,
+    delivery_index: usize,
+    expected: Option<i32>,
+) {
+    let (pickup1, delivery1, _multi1) = create_pickup_delivery("job1", (1, 2));
+    let (pickup2, delivery2, _multi2) = create_pickup_delivery("job2", (3, 4));
+    let mut route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&create_fleet(order), "v1", vec![pickup1, delivery1])),
+        Arc::new(RouteState::default()),
+    );
+
+    assert_eq!(evaluate(&route_ctx, &pickup2, pickup_index), None);
+    route_ctx.route_mut().tour.insert_at(pickup2, pickup_index + 1);
+    let result = evaluate(&route_ctx, &delivery2, delivery_index);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_ignore_jobs_with_multiple_pickups() {
+    let (pickup1, delivery1, _multi1) = create_pickup_delivery("job1", (1, 2));
+    let mut dimens = Dimensions::default();
+    dimens.set_job_id("job2".to_string());
+    let multi2 = Multi::new_shared(
+        vec![
+            create_single("job2", 3, "pickup"),
+            create_single("job2", 4, "pickup"),
+            create_single("job2", 5, "delivery"),
+        ],
+        dimens,
+    );
+    let pickup2 = create_activity_with_job_at_location(multi2.jobs[0].clone(), 3);
+    let delivery2 = create_activity_with_job_at_location(multi2.jobs[2].clone(), 5);
+    let route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(
+            &create_fleet(Some(LoadingOrder::Lifo)),
+            "v1",
+            vec![pickup2, pickup1, delivery1],
+        )),
+        Arc::new(RouteState::default()),
+    );
+
+    assert_eq!(evaluate(&route_ctx, &delivery2, 2), None);
+}
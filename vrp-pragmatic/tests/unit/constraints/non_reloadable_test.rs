@@ -0,0 +1,94 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::problem::Fleet;
+use vrp_core::models::solution::Activity;
+
+const VIOLATION_CODE: i32 = 1;
+
+fn create_fleet(pallets: i32) -> Fleet {
+    let mut vehicle = test_vehicle("v1");
+    vehicle.dimens.set_capacity(MultiDimLoad::new(vec![10, pallets]));
+
+    test_fleet_with_vehicles(vec![Arc::new(vehicle)])
+}
+
+fn create_job_activity(location: Location, delivery: Vec<i32>) -> Activity {
+    let demand = Demand::<MultiDimLoad> {
+        pickup: (MultiDimLoad::default(), MultiDimLoad::default()),
+        delivery: (MultiDimLoad::new(delivery), MultiDimLoad::default()),
+    };
+    let mut single = create_single_with_location(Some(location));
+    single.dimens.set_job_id(format!("job{}", location)).set_job_type("delivery".to_string()).set_demand(demand);
+
+    create_activity_with_job_at_location(Arc::new(single), location)
+}
+
+fn create_reload_activity() -> Activity {
+    let mut single = create_single_with_location(Some(0));
+    single.dimens.set_job_id("v1_reload_0".to_string()).set_job_type("reload".to_string());
+
+    create_activity_with_job_at_location(Arc::new(single), 0)
+}
+
+fn create_route_ctx(fleet: &Fleet, has_reload: bool) -> RouteContext {
+    let activities = if has_reload {
+        vec![create_job_activity(1, vec![1, 1]), create_reload_activity(), create_job_activity(2, vec![1, 1])]
+    } else {
+        vec![create_job_activity(1, vec![1, 1]), create_job_activity(2, vec![1, 1])]
+    };
+
+    let mut route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(fleet, "v1", activities)),
+        Arc::new(RouteState::default()),
+    );
+    create_module().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+fn create_module() -> NonReloadableLoadModule {
+    NonReloadableLoadModule::new(vec![1], VIOLATION_CODE)
+}
+
+parameterized_test! {can_evaluate_non_reloadable_load, (pallets, has_reload, index, expected), {
+    can_evaluate_non_reloadable_load_impl(pallets, has_reload, index, expected);
+}}
+
+can_evaluate_non_reloadable_load! {
+    case01_enough_capacity_before_reload: (3, true, 0, None),
+    case02_enough_capacity_after_reload: (3, true, 3, None),
+    case03_not_enough_capacity_before_reload: (2, true, 0, Some(VIOLATION_CODE)),
+    case04_not_enough_capacity_after_reload: (2, true, 3, Some(VIOLATION_CODE)),
+    case05_no_reload: (2, false, 0, None),
+}
+
+fn can_evaluate_non_reloadable_load_impl(pallets: i32, has_reload: bool, index: usize, expected: Option<i32>) {
+    let fleet = create_fleet(pallets);
+    let route_ctx = create_route_ctx(&fleet, has_reload);
+    let tour = &route_ctx.route.tour;
+    let target = create_job_activity(3, vec![0, 1]);
+    let activity_ctx =
+        ActivityContext { index, prev: tour.get(index).unwrap(), target: &target, next: tour.get(index + 1) };
+
+    let result = create_module()
+        .get_constraints()
+        .find_map(|constraint| match constraint {
+            ConstraintVariant::HardActivity(constraint) => Some(constraint.clone()),
+            _ => None,
+        })
+        .unwrap()
+        .evaluate_activity(&route_ctx, &activity_ctx)
+        .map(|violation| violation.code);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_mask_load() {
+    let load = MultiDimLoad::new(vec![1, 2, 3]);
+
+    let masked = mask_load(load, &[0, 2]);
+
+    assert_eq!(masked.as_vec(), vec![1, 0, 3]);
+}
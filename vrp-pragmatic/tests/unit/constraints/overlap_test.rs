@@ -0,0 +1,105 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::problem::Fleet;
+use vrp_core::models::solution::Activity;
+
+fn create_square(x: f64, y: f64, size: f64) -> Vec<Point> {
+    vec![(x, y), (x + size, y), (x + size, y + size), (x, y + size)]
+}
+
+fn create_point_fn() -> LocationPointFn {
+    // NOTE location index encodes point on the grid: x * 100 + y
+    Arc::new(|location| Some(((location / 100) as f64, (location % 100) as f64)))
+}
+
+fn create_route_ctx(fleet: &Fleet, vehicle_id: &str, locations: &[Location]) -> RouteContext {
+    let activities = locations
+        .iter()
+        .map(|&location| {
+            create_activity_with_job_at_location(Arc::new(create_single_with_location(Some(location))), location)
+        })
+        .collect::<Vec<Activity>>();
+
+    RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(fleet, vehicle_id, activities)),
+        Arc::new(RouteState::default()),
+    )
+}
+
+#[test]
+fn can_build_convex_hull() {
+    let points = vec![(0., 0.), (2., 0.), (1., 1.), (2., 2.), (0., 2.), (1., 0.), (2., 2.)];
+
+    let hull = get_convex_hull(points);
+
+    assert_eq!(hull, vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.)]);
+}
+
+parameterized_test! {can_check_point_inside_convex_hull, (point, expected), {
+    can_check_point_inside_convex_hull_impl(point, expected);
+}}
+
+can_check_point_inside_convex_hull! {
+    case01_inside: ((1., 1.), true),
+    case02_outside: ((3., 1.), false),
+    case03_on_border: ((2., 1.), false),
+}
+
+fn can_check_point_inside_convex_hull_impl(point: Point, expected: bool) {
+    assert_eq!(is_inside_convex_hull(point, &create_square(0., 0., 2.)), expected);
+}
+
+parameterized_test! {can_calculate_intersection_area, (subject, clip, expected), {
+    can_calculate_intersection_area_impl(subject, clip, expected);
+}}
+
+can_calculate_intersection_area! {
+    case01_partial: (create_square(0., 0., 2.), create_square(1., 1., 2.), 1.),
+    case02_inner: (create_square(0., 0., 4.), create_square(1., 1., 2.), 4.),
+    case03_disjoint: (create_square(0., 0., 2.), create_square(3., 3., 2.), 0.),
+    case04_touching: (create_square(0., 0., 2.), create_square(2., 0., 2.), 0.),
+    case05_triangle: (create_square(0., 0., 2.), vec![(0., 1.), (2., 1.), (0., 3.)], 1.5),
+    case06_degenerate: (create_square(0., 0., 2.), vec![(0., 0.), (2., 2.)], 0.),
+}
+
+fn can_calculate_intersection_area_impl(subject: Vec<Point>, clip: Vec<Point>, expected: f64) {
+    let result = get_intersection_area(subject.as_slice(), clip.as_slice());
+
+    assert!((result - expected).abs() < 1E-9, "expected {}, got {}", expected, result);
+}
+
+#[test]
+fn can_calculate_hulls_overlap() {
+    let hulls = vec![create_square(0., 0., 2.), create_square(1., 1., 2.), create_square(10., 10., 2.)];
+
+    let result = get_hulls_overlap(hulls.as_slice());
+
+    assert!((result - 1. / 12.).abs() < 1E-9);
+}
+
+#[test]
+fn can_estimate_job_inside_other_route_hull() {
+    let (constraint, _) = create_overlap_objective(create_point_fn(), OVERLAP_KEY);
+    let fleet = test_fleet_with_vehicles(vec![Arc::new(test_vehicle("v1")), Arc::new(test_vehicle("v2"))]);
+    let mut solution_ctx = SolutionContext {
+        routes: vec![
+            create_route_ctx(&fleet, "v1", &[0, 4, 404, 400]),
+            create_route_ctx(&fleet, "v2", &[1000, 1004, 1404]),
+        ],
+        ..create_solution_context_for_fleet(&fleet)
+    };
+    solution_ctx.routes.iter_mut().for_each(|route_ctx| constraint.accept_route_state(route_ctx));
+    let soft_constraint = match constraint.get_constraints().next() {
+        Some(ConstraintVariant::SoftRoute(soft_constraint)) => soft_constraint.clone(),
+        _ => unreachable!(),
+    };
+    let estimate = |route_idx: usize, location: Location| {
+        let job = Job::Single(Arc::new(create_single_with_location(Some(location))));
+        soft_constraint.estimate_job(&solution_ctx, &solution_ctx.routes[route_idx], &job)
+    };
+
+    assert!(estimate(1, 202) > 0.);
+    assert_eq!(estimate(0, 202), 0.);
+    assert_eq!(estimate(1, 2000), 0.);
+}
@@ -0,0 +1,32 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+
+parameterized_test! {can_estimate_job_by_unassigned_penalty, (penalty, expected), {
+    can_estimate_job_by_unassigned_penalty_impl(penalty, expected);
+}}
+
+can_estimate_job_by_unassigned_penalty! {
+    case01_no_penalty: (None, 0.),
+    case02_default_penalty: (Some(1.), 0.),
+    case03_low_penalty: (Some(0.5), 500.),
+    case04_high_penalty: (Some(5.), -4000.),
+}
+
+fn can_estimate_job_by_unassigned_penalty_impl(penalty: Option<f64>, expected: f64) {
+    let fleet = test_fleet();
+    let route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&fleet, "v1", vec![])),
+        Arc::new(RouteState::default()),
+    );
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    if let Some(penalty) = penalty {
+        single.dimens.set_unassigned_penalty(penalty);
+    }
+    let job = Job::Single(Arc::new(single));
+    let solution_ctx = create_solution_context_for_fleet(&fleet);
+
+    let result = UnassignedPenaltySoftRouteConstraint {}.estimate_job(&solution_ctx, &route_ctx, &job);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,83 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::problem::Fleet;
+
+type Item = (Option<f64>, Option<f64>, Option<f64>);
+
+fn create_job_with_item(item: Option<Item>) -> Job {
+    let mut single = create_single_with_location(None);
+    single.dimens.set_job_item(item.map(|(weight, length, height)| ItemAttributes { weight, length, height }));
+
+    Job::Single(Arc::new(single))
+}
+
+fn create_fleet_with_limits(limits: Option<Item>) -> Fleet {
+    let mut vehicle = test_vehicle("v1");
+    if let Some((max_axle_weight, max_item_length, max_item_height)) = limits {
+        vehicle.dimens.set_vehicle_physical_limits(VehiclePhysicalLimits {
+            max_axle_weight,
+            max_item_length,
+            max_item_height,
+        });
+    }
+
+    Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    )
+}
+
+parameterized_test! {can_check_physical_limits, (item, limits, expected), {
+    can_check_physical_limits_impl(item, limits, expected);
+}}
+
+can_check_physical_limits! {
+    case01_no_item: (None, Some((Some(10.), None, None)), None),
+    case02_no_limits: (Some((Some(20.), None, None)), None, None),
+    case03_weight_fits: (Some((Some(10.), None, None)), Some((Some(10.), None, None)), None),
+    case04_weight_exceeds: (Some((Some(11.), None, None)), Some((Some(10.), None, None)), Some(1)),
+    case05_length_exceeds: (Some((None, Some(6.), None)), Some((Some(10.), Some(5.), None)), Some(1)),
+    case06_height_exceeds: (Some((Some(1.), Some(1.), Some(3.))), Some((Some(10.), Some(5.), Some(2.))), Some(1)),
+    case07_unlimited_dimension: (Some((None, Some(100.), None)), Some((Some(10.), None, None)), None),
+    case08_all_fit: (Some((Some(5.), Some(4.), Some(2.))), Some((Some(10.), Some(5.), Some(2.))), None),
+}
+
+fn can_check_physical_limits_impl(item: Option<Item>, limits: Option<Item>, expected: Option<i32>) {
+    let fleet = create_fleet_with_limits(limits);
+    let route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&fleet, "v1", vec![])),
+        Arc::new(RouteState::default()),
+    );
+
+    let actual = ConstraintPipeline::default().add_module(Arc::new(PhysicalModule::new(1))).evaluate_hard_route(
+        &create_solution_context_for_fleet(&fleet),
+        &route_ctx,
+        &create_job_with_item(item),
+    );
+
+    assert_eq!(actual, expected.map(|code| RouteConstraintViolation { code }));
+}
+
+parameterized_test! {can_merge_items, (source, candidate, expected), {
+    can_merge_items_impl(source, candidate, expected);
+}}
+
+can_merge_items! {
+    case01_no_items: (None, None, Ok(())),
+    case02_no_candidate_item: (Some((Some(1.), None, None)), None, Ok(())),
+    case03_no_source_item: (None, Some((Some(1.), None, None)), Err(1)),
+    case04_smaller_candidate: (Some((Some(2.), Some(2.), None)), Some((Some(1.), Some(2.), None)), Ok(())),
+    case05_heavier_candidate: (Some((Some(2.), None, None)), Some((Some(3.), None, None)), Err(1)),
+    case06_extra_dimension: (Some((Some(2.), None, None)), Some((Some(1.), Some(1.), None)), Err(1)),
+}
+
+fn can_merge_items_impl(source: Option<Item>, candidate: Option<Item>, expected: Result<(), i32>) {
+    let module = PhysicalModule::new(1);
+
+    let result = module.merge(create_job_with_item(source), create_job_with_item(candidate)).map(|_| ());
+
+    assert_eq!(result, expected);
+}
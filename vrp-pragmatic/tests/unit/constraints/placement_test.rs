@@ -0,0 +1,87 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+
+const VIOLATION_CODE: i32 = 1;
+
+fn create_job_activity(id: &str, placement: Option<usize>) -> Activity {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_job_id(id.to_string()).set_job_type("delivery".to_string()).set_job_placement(placement);
+
+    create_activity_with_job_at_location(Arc::new(single), DEFAULT_JOB_LOCATION)
+}
+
+fn create_reload_activity() -> Activity {
+    create_activity_with_job_at_location(create_single_with_type("reload", "reload"), DEFAULT_JOB_LOCATION)
+}
+
+fn evaluate(activities: Vec<Activity>, target: &Activity, index: usize) -> Option<i32> {
+    let route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&test_fleet(), "v1", activities)),
+        Arc::new(RouteState::default()),
+    );
+    let tour = &route_ctx.route.tour;
+    let activity_ctx = ActivityContext { index, prev: tour.get(index).unwrap(), target, next: tour.get(index + 1) };
+
+    PlacementHardActivityConstraint { code: VIOLATION_CODE }
+        .evaluate_activity(&route_ctx, &activity_ctx)
+        .map(|violation| violation.code)
+}
+
+parameterized_test! {can_check_placement_order, (placement, index, expected), {
+    can_check_placement_order_impl(placement, index, expected);
+}}
+
+can_check_placement_order! {
+    case01_before_rear: (Some(1), 0, Some(VIOLATION_CODE)),
+    case02_between: (Some(1), 1, None),
+    case03_after_front: (Some(1), 3, Some(VIOLATION_CODE)),
+    case04_same_as_rear: (Some(0), 0, None),
+    case05_no_placement: (None, 0, None),
+    case06_no_placement: (None, 3, None),
+}
+
+fn can_check_placement_order_impl(placement: Option<usize>, index: usize, expected: Option<i32>) {
+    let activities = vec![
+        create_job_activity("job1", Some(0)),
+        create_job_activity("job2", None),
+        create_job_activity("job3", Some(2)),
+    ];
+    let target = create_job_activity("job4", placement);
+
+    let result = evaluate(activities, &target, index);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_placement_order_within_trip, (index, expected), {
+    can_check_placement_order_within_trip_impl(index, expected);
+}}
+
+can_check_placement_order_within_trip! {
+    case01_first_trip_before_front: (0, None),
+    case02_first_trip_after_front: (1, Some(VIOLATION_CODE)),
+    case03_second_trip_before_front: (2, None),
+    case04_second_trip_after_front: (3, Some(VIOLATION_CODE)),
+}
+
+fn can_check_placement_order_within_trip_impl(index: usize, expected: Option<i32>) {
+    let activities =
+        vec![create_job_activity("job1", Some(2)), create_reload_activity(), create_job_activity("job2", Some(2))];
+    let target = create_job_activity("job3", Some(0));
+
+    let result = evaluate(activities, &target, index);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_merge_jobs_with_same_placement() {
+    let create_job = |placement: Option<usize>| Job::Single(create_job_activity("job", placement).job.unwrap());
+    let module = PlacementModule::new(VIOLATION_CODE);
+
+    assert_eq!(module.merge(create_job(Some(1)), create_job(Some(1))).map(|_| ()), Ok(()));
+    assert_eq!(module.merge(create_job(None), create_job(None)).map(|_| ()), Ok(()));
+    assert_eq!(module.merge(create_job(Some(1)), create_job(Some(2))).map(|_| ()), Err(VIOLATION_CODE));
+    assert_eq!(module.merge(create_job(None), create_job(Some(2))).map(|_| ()), Err(VIOLATION_CODE));
+}
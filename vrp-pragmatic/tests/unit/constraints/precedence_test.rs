@@ -0,0 +1,237 @@
+use super::*;
+use crate::extensions::VehicleTie;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::{Duration, Location, TimeSpan};
+use vrp_core::models::problem::{create_matrix_transport_cost, Fleet, MatrixData, SimpleActivityCost};
+
+const VIOLATION_CODE: i32 = 1;
+const STATE_KEY: i32 = 2;
+const SERVICE_TIME: Duration = 1.;
+
+fn create_transport() -> Arc<dyn TransportCost + Send + Sync> {
+    let size = 11;
+    let durations = (0..size)
+        .flat_map(|from: usize| (0..size).map(move |to: usize| (from as f64 - to as f64).abs()))
+        .collect::<Vec<_>>();
+
+    create_matrix_transport_cost(vec![MatrixData::new(0, None, durations.clone(), durations)]).unwrap()
+}
+
+fn create_module() -> PrecedenceModule {
+    PrecedenceModule::new(
+        create_transport(),
+        Arc::new(SimpleActivityCost::default()),
+        vec![("job1".to_string(), "job2".to_string())],
+        VIOLATION_CODE,
+        STATE_KEY,
+    )
+}
+
+fn create_fleet() -> Fleet {
+    test_fleet_with_vehicles(vec![Arc::new(test_vehicle("v1")), Arc::new(test_vehicle("v2"))])
+}
+
+fn create_single(id: &str, location: Location, time: (f64, f64)) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(location));
+    single.places[0].duration = SERVICE_TIME;
+    single.places[0].times = vec![TimeSpan::Window(TimeWindow::new(time.0, time.1))];
+    single.dimens.set_job_id(id.to_string()).set_job_type("service".to_string());
+
+    Arc::new(single)
+}
+
+fn create_activity(single: Arc<Single>) -> Activity {
+    let place = single.places[0].clone();
+    let mut activity = create_activity_with_job_at_location(single, place.location.unwrap());
+    activity.place.duration = place.duration;
+    activity.place.time = place.times[0].to_time_window(0.);
+
+    activity
+}
+
+fn create_route_ctx(fleet: &Fleet, vehicle: &str, singles: Vec<Arc<Single>>) -> RouteContext {
+    let activities = singles.into_iter().map(create_activity).collect::<Vec<_>>();
+
+    let mut route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(fleet, vehicle, activities)),
+        Arc::new(RouteState::default()),
+    );
+    TransportConstraintModule::new(create_transport(), Arc::new(SimpleActivityCost::default()), VIOLATION_CODE)
+        .accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+fn get_service_start(route_ctx: &RouteContext, index: usize) -> Timestamp {
+    let activity = route_ctx.route.tour.get(index).unwrap();
+    activity.schedule.arrival.max(activity.place.time.start)
+}
+
+parameterized_test! {can_align_successor_with_predecessor, (successor_location, expected), {
+    can_align_successor_with_predecessor_impl(successor_location, expected);
+}}
+
+can_align_successor_with_predecessor! {
+    case01_wait_for_predecessor: (2, 6.),
+    case02_arrive_after_predecessor: (8, 8.),
+}
+
+fn can_align_successor_with_predecessor_impl(successor_location: Location, expected: Timestamp) {
+    let fleet = create_fleet();
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    solution_ctx.routes = vec![
+        create_route_ctx(&fleet, "v1", vec![create_single("job1", 5, (0., 100.))]),
+        create_route_ctx(&fleet, "v2", vec![create_single("job2", successor_location, (0., 100.))]),
+    ];
+
+    create_module().accept_solution_state(&mut solution_ctx);
+
+    assert!(solution_ctx.unassigned.is_empty());
+    assert_eq!(get_service_start(&solution_ctx.routes[0], 1), 5.);
+    assert_eq!(get_service_start(&solution_ctx.routes[1], 1), expected);
+    assert_eq!(solution_ctx.routes[0].route.tour.get(1).unwrap().place.time.end, expected - SERVICE_TIME);
+}
+
+parameterized_test! {can_eject_broken_relation, (successor_time, expected_unassigned), {
+    can_eject_broken_relation_impl(successor_time, expected_unassigned);
+}}
+
+can_eject_broken_relation! {
+    case01_feasible: ((0., 100.), 0),
+    case02_successor_too_early: ((0., 3.), 1),
+}
+
+fn can_eject_broken_relation_impl(successor_time: (f64, f64), expected_unassigned: usize) {
+    let fleet = create_fleet();
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    solution_ctx.routes = vec![
+        create_route_ctx(&fleet, "v1", vec![create_single("job1", 5, (0., 100.))]),
+        create_route_ctx(&fleet, "v2", vec![create_single("job2", 2, successor_time)]),
+    ];
+
+    create_module().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.unassigned.len(), expected_unassigned);
+    assert_eq!(solution_ctx.routes.len(), 2 - expected_unassigned);
+}
+
+parameterized_test! {can_eject_successor_without_predecessor, (is_pending, expected_unassigned), {
+    can_eject_successor_without_predecessor_impl(is_pending, expected_unassigned);
+}}
+
+can_eject_successor_without_predecessor! {
+    case01_pending_predecessor: (true, 0),
+    case02_missing_predecessor: (false, 1),
+}
+
+fn can_eject_successor_without_predecessor_impl(is_pending: bool, expected_unassigned: usize) {
+    let fleet = create_fleet();
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    solution_ctx.routes = vec![create_route_ctx(&fleet, "v1", vec![create_single("job2", 2, (0., 100.))])];
+    if is_pending {
+        solution_ctx.required.push(Job::Single(create_single("job1", 5, (0., 100.))));
+    }
+
+    create_module().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.unassigned.len(), expected_unassigned);
+    assert_eq!(solution_ctx.routes.len(), 1 - expected_unassigned);
+}
+
+#[test]
+fn can_eject_successor_served_before_predecessor_in_same_route() {
+    let fleet = create_fleet();
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    solution_ctx.routes = vec![create_route_ctx(
+        &fleet,
+        "v1",
+        vec![create_single("job2", 2, (0., 100.)), create_single("job1", 5, (0., 100.))],
+    )];
+
+    create_module().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.unassigned.len(), 1);
+    assert_eq!(solution_ctx.unassigned.keys().next().unwrap().dimens().get_job_id().unwrap(), "job2");
+}
+
+parameterized_test! {can_evaluate_activity_insertion, (target_time, vehicle, index, next_time, expected), {
+    can_evaluate_activity_insertion_impl(target_time, vehicle, index, next_time, expected);
+}}
+
+can_evaluate_activity_insertion! {
+    case01_wait_for_predecessor: ((0., 100.), "v2", 0, (0., 100.), None),
+    case02_cannot_wait_for_predecessor: ((0., 4.), "v2", 0, (0., 100.), Some(VIOLATION_CODE)),
+    case03_after_predecessor_in_same_route: ((0., 100.), "v1", 1, (0., 100.), None),
+    case04_before_predecessor_in_same_route: ((0., 100.), "v1", 0, (0., 100.), Some(VIOLATION_CODE)),
+    case05_wait_violates_next: ((0., 100.), "v2", 0, (0., 5.), Some(VIOLATION_CODE)),
+}
+
+fn can_evaluate_activity_insertion_impl(
+    target_time: (f64, f64),
+    vehicle: &str,
+    index: usize,
+    next_time: (f64, f64),
+    expected: Option<i32>,
+) {
+    let fleet = create_fleet();
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    solution_ctx.routes = vec![
+        create_route_ctx(&fleet, "v1", vec![create_single("job1", 5, (0., 100.))]),
+        create_route_ctx(&fleet, "v2", vec![create_single("job3", 1, next_time)]),
+    ];
+    let module = create_module();
+    module.accept_solution_state(&mut solution_ctx);
+    let route_ctx = solution_ctx
+        .routes
+        .iter()
+        .find(|route_ctx| route_ctx.route.actor.vehicle.dimens.get_vehicle_id().unwrap() == vehicle)
+        .unwrap();
+    let target = create_activity(create_single("job2", 3, target_time));
+    let tour = &route_ctx.route.tour;
+    let activity_ctx =
+        ActivityContext { index, prev: tour.get(index).unwrap(), target: &target, next: tour.get(index + 1) };
+
+    let result = module
+        .get_constraints()
+        .find_map(|constraint| match constraint {
+            ConstraintVariant::HardActivity(constraint) => Some(constraint.clone()),
+            _ => None,
+        })
+        .unwrap()
+        .evaluate_activity(route_ctx, &activity_ctx)
+        .map(|violation| violation.code);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_job_in_new_route, (successor_time, expected), {
+    can_evaluate_job_in_new_route_impl(successor_time, expected);
+}}
+
+can_evaluate_job_in_new_route! {
+    case01_feasible: ((0., 100.), None),
+    case02_too_early: ((0., 4.), Some(VIOLATION_CODE)),
+}
+
+fn can_evaluate_job_in_new_route_impl(successor_time: (f64, f64), expected: Option<i32>) {
+    let fleet = create_fleet();
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    solution_ctx.routes = vec![create_route_ctx(&fleet, "v1", vec![create_single("job1", 5, (0., 100.))])];
+    let module = create_module();
+    module.accept_solution_state(&mut solution_ctx);
+    let route_ctx = create_route_ctx(&fleet, "v2", vec![]);
+    let job = Job::Single(create_single("job2", 2, successor_time));
+
+    let result = module
+        .get_constraints()
+        .find_map(|constraint| match constraint {
+            ConstraintVariant::HardRoute(constraint) => Some(constraint.clone()),
+            _ => None,
+        })
+        .unwrap()
+        .evaluate_job(&solution_ctx, &route_ctx, &job)
+        .map(|violation| violation.code);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,136 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::{Location, Schedule, Timestamp};
+use vrp_core::models::problem::{create_matrix_transport_cost, MatrixData};
+use vrp_core::models::solution::Activity;
+
+const VIOLATION_CODE: i32 = 1;
+const STATE_KEY: i32 = 2;
+
+fn create_relay_module() -> RelayModule {
+    let size = 41;
+    let distances = (0..size)
+        .flat_map(|from: usize| (0..size).map(move |to: usize| (from as f64 - to as f64).abs()))
+        .collect::<Vec<_>>();
+    let transport = create_matrix_transport_cost(vec![MatrixData::new(0, None, distances.clone(), distances)]).unwrap();
+
+    RelayModule::new(transport, VIOLATION_CODE, STATE_KEY)
+}
+
+fn create_job_activity(location: Location) -> Activity {
+    let mut single = create_single_with_location(Some(location));
+    single.dimens.set_job_id("job".to_string()).set_job_type("delivery".to_string());
+
+    create_activity_with_job_at_location(Arc::new(single), location)
+}
+
+fn create_relay_activity(location: Location) -> Activity {
+    let mut single = create_single_with_location(Some(location));
+    single
+        .dimens
+        .set_job_id("v1_relay_0_1".to_string())
+        .set_job_type("relay".to_string())
+        .set_vehicle_id("v1".to_string())
+        .set_shift_index(0);
+
+    create_activity_with_job_at_location(Arc::new(single), location)
+}
+
+/// Creates route context where activities are given as (location, is relay) and schedules include tour start and end.
+fn create_route_ctx(
+    driver_shift_time: Duration,
+    activities: Vec<(Location, bool)>,
+    schedules: Vec<(Timestamp, Timestamp)>,
+) -> RouteContext {
+    let mut vehicle = test_vehicle("v1");
+    vehicle.dimens.set_driver_shift_time(driver_shift_time);
+    let fleet = test_fleet_with_vehicles(vec![Arc::new(vehicle)]);
+
+    let activities = activities
+        .into_iter()
+        .map(|(location, is_relay)| match is_relay {
+            true => create_relay_activity(location),
+            false => create_job_activity(location),
+        })
+        .collect();
+
+    let mut route = create_route_with_activities(&fleet, "v1", activities);
+    route.tour.all_activities_mut().zip(schedules).for_each(|(activity, (arrival, departure))| {
+        activity.schedule = Schedule::new(arrival, departure);
+    });
+
+    RouteContext::new_with_state(Arc::new(route), Arc::new(RouteState::default()))
+}
+
+fn evaluate(route_ctx: &RouteContext, target: &Activity, index: usize) -> Option<i32> {
+    let tour = &route_ctx.route.tour;
+    let activity_ctx = ActivityContext { index, prev: tour.get(index).unwrap(), target, next: tour.get(index + 1) };
+
+    create_relay_module()
+        .get_constraints()
+        .find_map(|constraint| match constraint {
+            ConstraintVariant::HardActivity(constraint) => Some(constraint.clone()),
+            _ => None,
+        })
+        .unwrap()
+        .evaluate_activity(route_ctx, &activity_ctx)
+        .map(|violation| violation.code)
+}
+
+#[test]
+fn can_split_tour_into_driver_shifts() {
+    let route_ctx = create_route_ctx(
+        20.,
+        vec![(5, false), (10, true), (15, false)],
+        vec![(0., 0.), (5., 5.), (10., 12.), (17., 17.), (32., 32.)],
+    );
+    let tour = &route_ctx.route.tour;
+
+    let shifts = get_driver_shifts(tour);
+
+    assert_eq!(shifts, vec![(0, 2), (2, 4)]);
+    assert_eq!(get_driver_shift_duration(tour, 0, 2), 10.);
+    assert_eq!(get_driver_shift_duration(tour, 2, 4), 20.);
+}
+
+parameterized_test! {can_evaluate_driver_shift_time_with_relay, (target, index, expected), {
+    can_evaluate_driver_shift_time_with_relay_impl(target, index, expected);
+}}
+
+can_evaluate_driver_shift_time_with_relay! {
+    case01_first_shift: (7, 1, None),
+    case02_second_shift_fits: (15, 3, None),
+    case03_second_shift_exceeds: (18, 3, Some(VIOLATION_CODE)),
+}
+
+fn can_evaluate_driver_shift_time_with_relay_impl(target: Location, index: usize, expected: Option<i32>) {
+    let route_ctx = create_route_ctx(
+        20.,
+        vec![(5, false), (10, true), (15, false)],
+        vec![(0., 0.), (5., 5.), (10., 12.), (17., 17.), (32., 32.)],
+    );
+
+    let result = evaluate(&route_ctx, &create_job_activity(target), index);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_relay_insertion, (is_relay, expected), {
+    can_evaluate_relay_insertion_impl(is_relay, expected);
+}}
+
+can_evaluate_relay_insertion! {
+    case01_relay: (true, None),
+    case02_job: (false, Some(VIOLATION_CODE)),
+}
+
+fn can_evaluate_relay_insertion_impl(is_relay: bool, expected: Option<i32>) {
+    let route_ctx =
+        create_route_ctx(30., vec![(10, false), (20, false)], vec![(0., 0.), (10., 10.), (20., 20.), (40., 40.)]);
+    let target = if is_relay { create_relay_activity(20) } else { create_job_activity(20) };
+
+    let result = evaluate(&route_ctx, &target, 2);
+
+    assert_eq!(result, expected);
+}
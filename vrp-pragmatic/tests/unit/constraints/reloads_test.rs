@@ -232,3 +232,40 @@ fn can_handle_multi_trip_needed_for_multi_dim_load_impl(
 
     assert_eq!(result, expected);
 }
+
+parameterized_test! {can_evaluate_demand_with_trip_capacity, (trip_capacity, insert_idx, expected), {
+    can_evaluate_demand_with_trip_capacity_impl(trip_capacity, insert_idx, expected);
+}}
+
+can_evaluate_demand_with_trip_capacity! {
+    case01_first_trip: (Some(1), 1, None),
+    case02_second_trip_limited: (Some(1), 3, Some(1)),
+    case03_second_trip_not_limited: (None, 3, None),
+}
+
+fn can_evaluate_demand_with_trip_capacity_impl(trip_capacity: Option<i32>, insert_idx: usize, expected: Option<i32>) {
+    let mut reload = reload("r1");
+    if let Some(trip_capacity) = trip_capacity {
+        let single = Arc::get_mut(reload.job.as_mut().unwrap()).unwrap();
+        single.dimens.set_trip_capacity(MultiDimLoad::new(vec![trip_capacity]));
+    }
+    let (mut route_ctx, _) =
+        create_route_context_with_fleet(vec![2], vec![delivery("d1", (1, 0)), reload, delivery("d2", (1, 0))]);
+    let mut pipeline = ConstraintPipeline::default();
+    pipeline.add_module(Arc::new(CapacityConstraintModule::new_with_multi_trip(
+        1,
+        Arc::new(create_simple_reload_multi_trip::<MultiDimLoad>(Box::new(|capacity| *capacity))),
+    )));
+    pipeline.accept_route_state(&mut route_ctx);
+    let target = delivery("d3", (1, 0));
+    let activity_ctx = ActivityContext {
+        index: insert_idx,
+        prev: route_ctx.route.tour.get(insert_idx).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(insert_idx + 1),
+    };
+
+    let result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx).map(|violation| violation.code);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,134 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::{Dimensions, Location, Schedule, TimeWindow};
+use vrp_core::models::problem::{create_matrix_transport_cost, MatrixData, Multi, SimpleActivityCost, Single};
+
+const VIOLATION_CODE: i32 = 1;
+const STATE_KEY: i32 = 2;
+
+fn create_module() -> RideDurationModule {
+    let size = 11;
+    let durations = (0..size)
+        .flat_map(|from: usize| (0..size).map(move |to: usize| (from as f64 - to as f64).abs()))
+        .collect::<Vec<_>>();
+
+    RideDurationModule::new(
+        Arc::new(SimpleActivityCost::default()),
+        create_matrix_transport_cost(vec![MatrixData::new(0, None, durations.clone(), durations)]).unwrap(),
+        VIOLATION_CODE,
+        STATE_KEY,
+    )
+}
+
+fn create_single(location: Location, job_type: &str) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(location));
+    single.dimens.set_job_id("job".to_string()).set_job_type(job_type.to_string());
+
+    Arc::new(single)
+}
+
+fn create_pickup_delivery(locations: (Location, Location), max_ride_duration: Duration) -> Arc<Multi> {
+    let mut dimens = Dimensions::default();
+    dimens.set_job_id("multi".to_string()).set_job_max_ride_duration(Some(max_ride_duration));
+
+    Multi::new_shared(vec![create_single(locations.0, "pickup"), create_single(locations.1, "delivery")], dimens)
+}
+
+/// Creates activity assuming that vehicle moves from location zero at time zero with unit speed and no waiting.
+fn create_activity(single: Arc<Single>, location: Location) -> Activity {
+    let time = location as f64;
+    Activity {
+        schedule: Schedule { arrival: time, departure: time },
+        ..create_activity_with_job_at_location(single, location)
+    }
+}
+
+fn create_route_ctx(activities: Vec<Activity>) -> RouteContext {
+    let mut route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&test_fleet(), "v1", activities)),
+        Arc::new(RouteState::default()),
+    );
+
+    create_module().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+fn evaluate(route_ctx: &RouteContext, target: &Activity, index: usize) -> Option<i32> {
+    let tour = &route_ctx.route.tour;
+    let activity_ctx = ActivityContext { index, prev: tour.get(index).unwrap(), target, next: tour.get(index + 1) };
+
+    create_module()
+        .get_constraints()
+        .find_map(|constraint| match constraint {
+            ConstraintVariant::HardActivity(constraint) => Some(constraint.clone()),
+            _ => None,
+        })
+        .unwrap()
+        .evaluate_activity(route_ctx, &activity_ctx)
+        .map(|violation| violation.code)
+}
+
+parameterized_test! {can_check_insertion_between_pickup_and_delivery, (location, index, expected), {
+    can_check_insertion_between_pickup_and_delivery_impl(location, index, expected);
+}}
+
+can_check_insertion_between_pickup_and_delivery! {
+    case01_before_pickup: (10, 0, None),
+    case02_no_delay: (3, 1, None),
+    case03_delay_after_pickup: (1, 1, Some(VIOLATION_CODE)),
+    case04_no_delay_before_delivery: (5, 2, None),
+    case05_delay_before_delivery: (7, 2, Some(VIOLATION_CODE)),
+    case06_after_delivery: (10, 3, None),
+}
+
+fn can_check_insertion_between_pickup_and_delivery_impl(location: Location, index: usize, expected: Option<i32>) {
+    let multi = create_pickup_delivery((2, 6), 5.);
+    let route_ctx = create_route_ctx(vec![
+        create_activity(multi.jobs[0].clone(), 2),
+        create_activity(create_single(4, "delivery"), 4),
+        create_activity(multi.jobs[1].clone(), 6),
+    ]);
+    let target = create_activity_with_job_at_location(create_single(location, "delivery"), location);
+
+    let result = evaluate(&route_ctx, &target, index);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_delivery_insertion, (location, time_start, expected), {
+    can_check_delivery_insertion_impl(location, time_start, expected);
+}}
+
+can_check_delivery_insertion! {
+    case01_within_limit: (6, 0., None),
+    case02_exceeds_limit: (8, 0., Some(VIOLATION_CODE)),
+    case03_exceeds_limit_with_waiting: (6, 9., Some(VIOLATION_CODE)),
+}
+
+fn can_check_delivery_insertion_impl(location: Location, time_start: f64, expected: Option<i32>) {
+    let multi = create_pickup_delivery((2, location), 5.);
+    let route_ctx = create_route_ctx(vec![
+        create_activity(multi.jobs[0].clone(), 2),
+        create_activity(create_single(4, "delivery"), 4),
+    ]);
+    let mut target = create_activity_with_job_at_location(multi.jobs[1].clone(), location);
+    target.place.time = TimeWindow::new(time_start, f64::MAX);
+
+    let result = evaluate(&route_ctx, &target, 2);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_merge_only_jobs_without_ride_duration() {
+    let module = create_module();
+    let job = Job::Single(create_single(1, "delivery"));
+    let multi = create_pickup_delivery((1, 2), 5.);
+    let ride_job = Job::Multi(multi);
+
+    assert!(module.merge(job.clone(), job.clone()).is_ok());
+    assert_eq!(module.merge(job.clone(), ride_job.clone()).map(|_| ()), Err(VIOLATION_CODE));
+    assert_eq!(module.merge(ride_job, job).map(|_| ()), Err(VIOLATION_CODE));
+}
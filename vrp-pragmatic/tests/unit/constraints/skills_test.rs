@@ -1,7 +1,7 @@
 use crate::constraints::{JobSkills, SkillsModule};
 use crate::extensions::{create_typed_actor_groups, JobTie, VehicleTie};
 use crate::helpers::*;
-use hashbrown::HashSet;
+use hashbrown::{HashMap, HashSet};
 use std::iter::FromIterator;
 use std::sync::Arc;
 use vrp_core::construction::constraints::ConstraintModule;
@@ -15,6 +15,8 @@ fn create_job_with_skills(all_of: Option<Vec<&str>>, one_of: Option<Vec<&str>>,
         all_of: all_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
         one_of: one_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
         none_of: none_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
+        preferred: None,
+        counts: None,
     }));
 
     Job::Single(Arc::new(single))
@@ -30,6 +32,26 @@ fn create_vehicle_with_skills(skills: Option<Vec<&str>>) -> Vehicle {
     vehicle
 }
 
+fn create_job_with_skill_counts(counts: Vec<(&str, usize)>) -> Job {
+    let mut single = create_single_with_location(None);
+    single.dimens.set_job_skills(Some(JobSkills {
+        all_of: None,
+        one_of: None,
+        none_of: None,
+        preferred: None,
+        counts: Some(counts.into_iter().map(|(skill, count)| (skill.to_string(), count)).collect()),
+    }));
+
+    Job::Single(Arc::new(single))
+}
+
+fn create_route_ctx(fleet: &Fleet) -> RouteContext {
+    RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(fleet, "v1", vec![])),
+        Arc::new(RouteState::default()),
+    )
+}
+
 fn failure() -> Option<RouteConstraintViolation> {
     Some(RouteConstraintViolation { code: 0 })
 }
@@ -83,10 +105,7 @@ fn can_check_skills_impl(
         vec![Arc::new(create_vehicle_with_skills(vehicle_skills))],
         Box::new(|actors| create_typed_actor_groups(actors)),
     );
-    let route_ctx = RouteContext::new_with_state(
-        Arc::new(create_route_with_activities(&fleet, "v1", vec![])),
-        Arc::new(RouteState::default()),
-    );
+    let route_ctx = create_route_ctx(&fleet);
 
     let actual = ConstraintPipeline::default().add_module(Arc::new(SkillsModule::new(0))).evaluate_hard_route(
         &create_solution_context_for_fleet(&fleet),
@@ -97,6 +116,49 @@ fn can_check_skills_impl(
     assert_eq!(actual, expected)
 }
 
+parameterized_test! {can_check_skill_counts, (job_counts, vehicle_skills, vehicle_counts, expected), {
+    can_check_skill_counts_impl(job_counts, vehicle_skills, vehicle_counts, expected);
+}}
+
+can_check_skill_counts! {
+    case01_no_vehicle_skills: (vec![("s1", 1)], None, None, failure()),
+    case02_skill_without_count: (vec![("s1", 1)], Some(vec!["s1"]), None, None),
+    case03_skill_without_enough_count: (vec![("s1", 2)], Some(vec!["s1"]), None, failure()),
+    case04_enough_count: (vec![("s1", 2)], None, Some(vec![("s1", 2)]), None),
+    case05_more_than_enough_count: (vec![("s1", 2)], None, Some(vec![("s1", 3)]), None),
+    case06_not_enough_count: (vec![("s1", 3)], Some(vec!["s1"]), Some(vec![("s1", 2)]), failure()),
+    case07_multiple_skills: (vec![("s1", 2), ("s2", 1)], Some(vec!["s2"]), Some(vec![("s1", 2)]), None),
+    case08_zero_count: (vec![("s1", 0)], None, None, None),
+}
+
+fn can_check_skill_counts_impl(
+    job_counts: Vec<(&str, usize)>,
+    vehicle_skills: Option<Vec<&str>>,
+    vehicle_counts: Option<Vec<(&str, usize)>>,
+    expected: Option<RouteConstraintViolation>,
+) {
+    let mut vehicle = create_vehicle_with_skills(vehicle_skills);
+    if let Some(vehicle_counts) = vehicle_counts {
+        vehicle.dimens.set_vehicle_skill_counts(
+            vehicle_counts.into_iter().map(|(skill, count)| (skill.to_string(), count)).collect::<HashMap<_, _>>(),
+        );
+    }
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let route_ctx = create_route_ctx(&fleet);
+
+    let actual = ConstraintPipeline::default().add_module(Arc::new(SkillsModule::new(0))).evaluate_hard_route(
+        &create_solution_context_for_fleet(&fleet),
+        &route_ctx,
+        &create_job_with_skill_counts(job_counts),
+    );
+
+    assert_eq!(actual, expected)
+}
+
 parameterized_test! {can_merge_skills, (source, candidate, expected), {
     can_merge_skills_impl(source, candidate, expected);
 }}
@@ -116,6 +178,11 @@ can_merge_skills! {
     case_09: (create_job_with_skills(Some(vec!["skill"]), None, None), create_job_with_skills(None, Some(vec!["skill"]), None), Err(1)),
     case_10: (create_job_with_skills(Some(vec!["skill1", "skill2"]), None, None), create_job_with_skills(Some(vec!["skill1"]), None, None), Ok(())),
     case_11: (create_job_with_skills(Some(vec!["skill1"]), None, None), create_job_with_skills(Some(vec!["skill1", "skill2"]), None, None), Err(1)),
+
+    case_12: (create_job_with_skill_counts(vec![("skill", 2)]), create_job_with_skill_counts(vec![("skill", 1)]), Ok(())),
+    case_13: (create_job_with_skill_counts(vec![("skill", 1)]), create_job_with_skill_counts(vec![("skill", 2)]), Err(1)),
+    case_14: (create_job_with_skill_counts(vec![("skill1", 1)]), create_job_with_skill_counts(vec![("skill2", 1)]), Err(1)),
+    case_15: (create_job_with_skills(None, None, None), create_job_with_skill_counts(vec![("skill", 1)]), Err(1)),
 }
 
 fn can_merge_skills_impl(source: Job, candidate: Job, expected: Result<(), i32>) {
@@ -125,3 +192,82 @@ fn can_merge_skills_impl(source: Job, candidate: Job, expected: Result<(), i32>)
 
     assert_eq!(result, expected);
 }
+
+fn create_job_with_preferred_skills(preferred: Vec<(&str, f64)>) -> Job {
+    let mut single = create_single_with_location(None);
+    single.dimens.set_job_skills(Some(JobSkills {
+        all_of: None,
+        one_of: None,
+        none_of: None,
+        preferred: Some(preferred.into_iter().map(|(skill, penalty)| (skill.to_string(), penalty)).collect()),
+        counts: None,
+    }));
+
+    Job::Single(Arc::new(single))
+}
+
+parameterized_test! {can_get_preferred_skills_penalty, (preferred, vehicle_skills, expected), {
+    can_get_preferred_skills_penalty_impl(preferred, vehicle_skills, expected);
+}}
+
+can_get_preferred_skills_penalty! {
+    case01_no_vehicle_skills: (vec![("s1", 10.)], None, 10.),
+    case02_has_skill: (vec![("s1", 10.)], Some(vec!["s1"]), 0.),
+    case03_lacks_skill: (vec![("s1", 10.)], Some(vec!["s2"]), 10.),
+    case04_lacks_one_skill: (vec![("s1", 10.), ("s2", 5.)], Some(vec!["s1"]), 5.),
+    case05_lacks_all_skills: (vec![("s1", 10.), ("s2", 5.)], Some(vec!["s3"]), 15.),
+}
+
+fn can_get_preferred_skills_penalty_impl(
+    preferred: Vec<(&str, f64)>,
+    vehicle_skills: Option<Vec<&str>>,
+    expected: f64,
+) {
+    let job = create_job_with_preferred_skills(preferred);
+    let vehicle = create_vehicle_with_skills(vehicle_skills);
+
+    let penalty = job.dimens().get_job_skills().unwrap().get_penalty(vehicle.dimens.get_vehicle_skills());
+
+    assert_eq!(penalty, expected);
+}
+
+#[test]
+fn can_ignore_preferred_skills_in_hard_constraint() {
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(create_vehicle_with_skills(None))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&fleet, "v1", vec![])),
+        Arc::new(RouteState::default()),
+    );
+
+    let actual = ConstraintPipeline::default().add_module(Arc::new(SkillsModule::new(0))).evaluate_hard_route(
+        &create_solution_context_for_fleet(&fleet),
+        &route_ctx,
+        &create_job_with_preferred_skills(vec![("s1", 10.)]),
+    );
+
+    assert_eq!(actual, None)
+}
+
+parameterized_test! {can_merge_preferred_skills, (source, candidate, expected), {
+    can_merge_preferred_skills_impl(source, candidate, expected);
+}}
+
+can_merge_preferred_skills! {
+    case_01: (vec![("s1", 10.)], vec![("s1", 5.)], Ok(())),
+    case_02: (vec![("s1", 10.), ("s2", 5.)], vec![("s1", 5.)], Ok(())),
+    case_03: (vec![("s1", 10.)], vec![("s1", 5.), ("s2", 5.)], Err(1)),
+}
+
+fn can_merge_preferred_skills_impl(source: Vec<(&str, f64)>, candidate: Vec<(&str, f64)>, expected: Result<(), i32>) {
+    let constraint = SkillsModule::new(1);
+
+    let result = constraint
+        .merge(create_job_with_preferred_skills(source), create_job_with_preferred_skills(candidate))
+        .map(|_| ());
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,246 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::{Location, TimeSpan};
+use vrp_core::models::problem::{create_matrix_transport_cost, Fleet, MatrixData, SimpleActivityCost};
+
+const VIOLATION_CODE: i32 = 1;
+const STATE_KEY: i32 = 2;
+
+fn create_transport() -> Arc<dyn TransportCost + Send + Sync> {
+    let size = 11;
+    let durations = (0..size)
+        .flat_map(|from: usize| (0..size).map(move |to: usize| (from as f64 - to as f64).abs()))
+        .collect::<Vec<_>>();
+
+    create_matrix_transport_cost(vec![MatrixData::new(0, None, durations.clone(), durations)]).unwrap()
+}
+
+fn create_module(sizes: Vec<(&str, usize)>) -> SyncModule {
+    SyncModule::new(
+        create_transport(),
+        Arc::new(SimpleActivityCost::default()),
+        sizes.into_iter().map(|(key, size)| (key.to_string(), size)).collect(),
+        VIOLATION_CODE,
+        STATE_KEY,
+    )
+}
+
+fn create_fleet() -> Fleet {
+    test_fleet_with_vehicles(vec![Arc::new(test_vehicle("v1")), Arc::new(test_vehicle("v2"))])
+}
+
+fn create_single(id: &str, location: Location, key: &str, offset: Duration) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(location));
+    single
+        .dimens
+        .set_job_id(id.to_string())
+        .set_job_type("service".to_string())
+        .set_job_sync(Some(JobSync { key: key.to_string(), offset }));
+
+    Arc::new(single)
+}
+
+fn create_route_ctx(fleet: &Fleet, vehicle: &str, singles: Vec<(Arc<Single>, Location)>) -> RouteContext {
+    let activities = singles
+        .into_iter()
+        .map(|(single, location)| {
+            let mut activity = create_activity_with_job_at_location(single.clone(), location);
+            activity.place.time = single.places[0].times[0].to_time_window(0.);
+            activity
+        })
+        .collect::<Vec<_>>();
+
+    let mut route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(fleet, vehicle, activities)),
+        Arc::new(RouteState::default()),
+    );
+    TransportConstraintModule::new(create_transport(), Arc::new(SimpleActivityCost::default()), VIOLATION_CODE)
+        .accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+fn get_service_start(route_ctx: &RouteContext, index: usize) -> Timestamp {
+    let activity = route_ctx.route.tour.get(index).unwrap();
+    activity.schedule.arrival.max(activity.place.time.start)
+}
+
+parameterized_test! {can_align_synchronized_activities, (offset, expected), {
+    can_align_synchronized_activities_impl(offset, expected);
+}}
+
+can_align_synchronized_activities! {
+    case01_no_offset: (0., (5., 5.)),
+    case02_small_offset: (2., (3., 5.)),
+    case03_large_offset: (5., (2., 5.)),
+}
+
+fn can_align_synchronized_activities_impl(offset: Duration, expected: (Timestamp, Timestamp)) {
+    let fleet = create_fleet();
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    solution_ctx.routes = vec![
+        create_route_ctx(&fleet, "v1", vec![(create_single("job1", 2, "sync", offset), 2)]),
+        create_route_ctx(&fleet, "v2", vec![(create_single("job2", 5, "sync", offset), 5)]),
+    ];
+
+    create_module(vec![("sync", 2)]).accept_solution_state(&mut solution_ctx);
+
+    assert!(solution_ctx.unassigned.is_empty());
+    assert_eq!(get_service_start(&solution_ctx.routes[0], 1), expected.0);
+    assert_eq!(get_service_start(&solution_ctx.routes[1], 1), expected.1);
+}
+
+#[test]
+fn can_keep_sync_bounds_in_solution_state() {
+    let fleet = create_fleet();
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    solution_ctx.routes = vec![create_route_ctx(&fleet, "v1", vec![(create_single("job1", 2, "sync", 1.), 2)])];
+    solution_ctx.required.push(Job::Single(create_single("job2", 5, "sync", 1.)));
+
+    create_module(vec![("sync", 2)]).accept_solution_state(&mut solution_ctx);
+
+    let bounds = solution_ctx.state.get(&STATE_KEY).and_then(|bounds| bounds.downcast_ref::<SyncBounds>()).unwrap();
+    let route_bounds = solution_ctx.routes[0].state.get_route_state::<SyncBounds>(STATE_KEY).unwrap();
+    assert_eq!(bounds.get("sync"), route_bounds.get("sync"));
+    assert!(bounds.get("sync").is_some());
+}
+
+parameterized_test! {can_eject_partially_assigned_group, (is_pending, expected_unassigned), {
+    can_eject_partially_assigned_group_impl(is_pending, expected_unassigned);
+}}
+
+can_eject_partially_assigned_group! {
+    case01_pending_partner: (true, 0),
+    case02_missing_partner: (false, 1),
+}
+
+fn can_eject_partially_assigned_group_impl(is_pending: bool, expected_unassigned: usize) {
+    let fleet = create_fleet();
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    solution_ctx.routes = vec![create_route_ctx(&fleet, "v1", vec![(create_single("job1", 2, "sync", 0.), 2)])];
+    if is_pending {
+        solution_ctx.required.push(Job::Single(create_single("job2", 5, "sync", 0.)));
+    }
+
+    create_module(vec![("sync", 2)]).accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.unassigned.len(), expected_unassigned);
+    assert_eq!(solution_ctx.routes.len(), 1 - expected_unassigned);
+}
+
+parameterized_test! {can_evaluate_activity_insertion, (location, bounds, expected), {
+    can_evaluate_activity_insertion_impl(location, bounds, expected);
+}}
+
+can_evaluate_activity_insertion! {
+    case01_within_bounds: (3, (2., 4.), None),
+    case02_wait_for_partner: (3, (5., 8.), None),
+    case03_late_for_partner: (3, (0., 2.), Some(VIOLATION_CODE)),
+    case04_wait_violates_next: (3, (10., 12.), Some(VIOLATION_CODE)),
+}
+
+fn can_evaluate_activity_insertion_impl(location: Location, bounds: (f64, f64), expected: Option<i32>) {
+    let fleet = create_fleet();
+    let mut next = create_single_with_location(Some(4));
+    next.places[0].times = vec![TimeSpan::Window(TimeWindow::new(0., 10.))];
+    let mut route_ctx = create_route_ctx(&fleet, "v1", vec![(Arc::new(next), 4)]);
+    route_ctx.state_mut().put_route_state(
+        STATE_KEY,
+        SyncBounds::from_iter(vec![("sync".to_string(), TimeWindow::new(bounds.0, bounds.1))]),
+    );
+    let target = create_activity_with_job_at_location(create_single("job1", location, "sync", 0.), location);
+    let tour = &route_ctx.route.tour;
+    let activity_ctx = ActivityContext { index: 0, prev: tour.get(0).unwrap(), target: &target, next: tour.get(1) };
+
+    let result = create_module(vec![("sync", 2)])
+        .get_constraints()
+        .find_map(|constraint| match constraint {
+            ConstraintVariant::HardActivity(constraint) => Some(constraint.clone()),
+            _ => None,
+        })
+        .unwrap()
+        .evaluate_activity(&route_ctx, &activity_ctx)
+        .map(|violation| violation.code);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_reject_route_with_partner() {
+    let fleet = create_fleet();
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    solution_ctx.routes = vec![create_route_ctx(&fleet, "v1", vec![(create_single("job1", 2, "sync", 0.), 2)])];
+    let route_constraint = create_module(vec![("sync", 2)])
+        .get_constraints()
+        .find_map(|constraint| match constraint {
+            ConstraintVariant::HardRoute(constraint) => Some(constraint.clone()),
+            _ => None,
+        })
+        .unwrap();
+
+    let partner = Job::Single(create_single("job2", 5, "sync", 0.));
+    let other = Job::Single(create_single("job3", 5, "other", 0.));
+
+    let route_ctx = &solution_ctx.routes[0];
+    assert_eq!(
+        route_constraint.evaluate_job(&solution_ctx, route_ctx, &partner).map(|violation| violation.code),
+        Some(VIOLATION_CODE)
+    );
+    assert_eq!(route_constraint.evaluate_job(&solution_ctx, route_ctx, &other).map(|violation| violation.code), None);
+}
+
+/// Keeps departure time from the last activity as a route state.
+struct DepartureStateModule {
+    keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl ConstraintModule for DepartureStateModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let departure = route_ctx.route.tour.all_activities().last().unwrap().schedule.departure;
+        route_ctx.state_mut().put_route_state(self.keys[0], departure);
+    }
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+#[test]
+fn can_recalculate_route_states_of_other_modules_after_align() {
+    let departure_key = STATE_KEY + 1;
+    let departure_module = DepartureStateModule { keys: vec![departure_key], constraints: vec![] };
+    let fleet = create_fleet();
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    solution_ctx.routes = vec![
+        create_route_ctx(&fleet, "v1", vec![(create_single("job1", 2, "sync", 0.), 2)]),
+        create_route_ctx(&fleet, "v2", vec![(create_single("job2", 5, "sync", 0.), 5)]),
+    ];
+    solution_ctx.routes.iter_mut().for_each(|route_ctx| departure_module.accept_route_state(route_ctx));
+    let mut pipeline = ConstraintPipeline::default();
+    pipeline.add_module(Arc::new(departure_module));
+    pipeline.add_module(Arc::new(create_module(vec![("sync", 2)])));
+
+    pipeline.accept_solution_state(&mut solution_ctx);
+
+    assert!(solution_ctx.unassigned.is_empty());
+    solution_ctx.routes.iter().for_each(|route_ctx| {
+        let expected = route_ctx.route.tour.all_activities().last().unwrap().schedule.departure;
+        assert_eq!(route_ctx.state.get_route_state::<f64>(departure_key), Some(&expected));
+        assert!(!route_ctx.state.has_flag(state_flags::RESCHEDULED));
+    });
+    assert_eq!(get_service_start(&solution_ctx.routes[0], 1), 5.);
+}
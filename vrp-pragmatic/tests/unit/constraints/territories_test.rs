@@ -0,0 +1,73 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+
+const VIOLATION_CODE: i32 = 1;
+
+fn create_job(territory: Option<(&str, bool)>) -> Job {
+    let mut single = create_single_with_location(None);
+    single.dimens.set_job_territory(territory.map(|(id, is_strict)| {
+        Arc::new(JobTerritory {
+            id: id.to_string(),
+            vehicles: vec!["v1".to_string()].into_iter().collect(),
+            is_strict,
+            cross_zone_penalty: None,
+        })
+    }));
+
+    Job::Single(Arc::new(single))
+}
+
+parameterized_test! {can_evaluate_job, (vehicle_id, territory, expected), {
+    can_evaluate_job_impl(vehicle_id, territory, expected);
+}}
+
+can_evaluate_job! {
+    case01_no_territory: ("v2", None, None),
+    case02_preferred_vehicle: ("v1", Some(("t1", true)), None),
+    case03_other_vehicle_strict: ("v2", Some(("t1", true)), Some(VIOLATION_CODE)),
+    case04_other_vehicle_not_strict: ("v2", Some(("t1", false)), None),
+}
+
+fn can_evaluate_job_impl(vehicle_id: &str, territory: Option<(&str, bool)>, expected: Option<i32>) {
+    let fleet = test_fleet_with_vehicles(vec![Arc::new(test_vehicle("v1")), Arc::new(test_vehicle("v2"))]);
+    let solution_ctx = create_solution_context_for_fleet(&fleet);
+    let route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&fleet, vehicle_id, vec![])),
+        Arc::new(RouteState::default()),
+    );
+    let module = TerritoryModule::new(VIOLATION_CODE);
+
+    let result = module
+        .get_constraints()
+        .find_map(|constraint| match constraint {
+            ConstraintVariant::HardRoute(constraint) => Some(constraint.clone()),
+            _ => None,
+        })
+        .unwrap()
+        .evaluate_job(&solution_ctx, &route_ctx, &create_job(territory))
+        .map(|violation| violation.code);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_merge_jobs, (source, candidate, expected), {
+    can_merge_jobs_impl(source, candidate, expected);
+}}
+
+can_merge_jobs! {
+    case01_no_territories: (None, None, Ok(())),
+    case02_same_territory: (Some("t1"), Some("t1"), Ok(())),
+    case03_different_territories: (Some("t1"), Some("t2"), Err(VIOLATION_CODE)),
+    case04_source_only: (Some("t1"), None, Err(VIOLATION_CODE)),
+    case05_candidate_only: (None, Some("t1"), Err(VIOLATION_CODE)),
+}
+
+fn can_merge_jobs_impl(source: Option<&str>, candidate: Option<&str>, expected: Result<(), i32>) {
+    let source = create_job(source.map(|id| (id, true)));
+    let candidate = create_job(candidate.map(|id| (id, true)));
+
+    let result = TerritoryModule::new(VIOLATION_CODE).merge(source, candidate).map(|_| ());
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,201 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::{Demand, DemandDimension, Location, SingleDimLoad};
+use vrp_core::models::problem::Fleet;
+use vrp_core::models::solution::Activity;
+
+const VIOLATION_CODE: i32 = 1;
+
+fn create_trailer_module() -> TrailerModule<SingleDimLoad> {
+    TrailerModule::new(VIOLATION_CODE)
+}
+
+fn create_fleet(truck_capacity: Option<i32>) -> Fleet {
+    let mut vehicle = test_vehicle("v1");
+    if let Some(truck_capacity) = truck_capacity {
+        vehicle.dimens.set_truck_capacity(SingleDimLoad::new(truck_capacity));
+    }
+
+    test_fleet_with_vehicles(vec![Arc::new(vehicle)])
+}
+
+fn create_route_ctx(fleet: &Fleet, activities: Vec<Activity>) -> RouteContext {
+    RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(fleet, "v1", activities)),
+        Arc::new(RouteState::default()),
+    )
+}
+
+fn create_job_activity(location: Location, delivery: i32, truck_only: bool) -> Activity {
+    let demand = Demand::<SingleDimLoad> {
+        pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
+        delivery: (SingleDimLoad::new(delivery), SingleDimLoad::default()),
+    };
+    let mut single = create_single_with_location(Some(location));
+    single
+        .dimens
+        .set_job_id("job".to_string())
+        .set_job_type("delivery".to_string())
+        .set_job_truck_only(Some(truck_only))
+        .set_demand(demand);
+
+    create_activity_with_job_at_location(Arc::new(single), location)
+}
+
+/// Returns detach and attach activities with their multi job which has to be kept alive.
+fn create_trailer_activities(location: Location) -> (Activity, Activity, Arc<Multi>) {
+    let singles = ["detach", "attach"]
+        .iter()
+        .map(|job_type| {
+            let mut single = create_single_with_location(Some(location));
+            single
+                .dimens
+                .set_job_id("v1_trailer_0_1".to_string())
+                .set_job_type(job_type.to_string())
+                .set_vehicle_id("v1".to_string())
+                .set_shift_index(0);
+            Arc::new(single)
+        })
+        .collect::<Vec<_>>();
+
+    let mut dimens = Dimensions::default();
+    dimens.set_job_id("v1_trailer_0_1".to_string()).set_job_type("trailer".to_string());
+    let multi = Multi::new_shared(singles, dimens);
+
+    (
+        create_activity_with_job_at_location(multi.jobs[0].clone(), location),
+        create_activity_with_job_at_location(multi.jobs[1].clone(), location),
+        multi,
+    )
+}
+
+fn evaluate(route_ctx: &RouteContext, target: &Activity, index: usize) -> Option<i32> {
+    let tour = &route_ctx.route.tour;
+    let activity_ctx = ActivityContext { index, prev: tour.get(index).unwrap(), target, next: tour.get(index + 1) };
+
+    create_trailer_module()
+        .get_constraints()
+        .find_map(|constraint| match constraint {
+            ConstraintVariant::HardActivity(constraint) => Some(constraint.clone()),
+            _ => None,
+        })
+        .unwrap()
+        .evaluate_activity(route_ctx, &activity_ctx)
+        .map(|violation| violation.code)
+}
+
+parameterized_test! {can_evaluate_job_insertion, (truck_capacity, demand, truck_only, index, expected), {
+    can_evaluate_job_insertion_impl(truck_capacity, demand, truck_only, index, expected);
+}}
+
+can_evaluate_job_insertion! {
+    case01_truck_only_with_trailer: (Some(5), 1, true, 0, Some(VIOLATION_CODE)),
+    case02_truck_only_without_trailer: (Some(5), 1, true, 2, None),
+    case03_truck_only_after_trailer_attached: (Some(5), 1, true, 4, Some(VIOLATION_CODE)),
+    case04_regular_job_with_trailer: (Some(5), 10, false, 0, None),
+    case05_regular_job_without_trailer: (Some(5), 3, false, 1, None),
+    case06_truck_capacity_exceeded: (Some(5), 4, false, 1, Some(VIOLATION_CODE)),
+    case07_vehicle_has_no_trailer: (None, 1, true, 0, None),
+}
+
+fn can_evaluate_job_insertion_impl(
+    truck_capacity: Option<i32>,
+    demand: i32,
+    truck_only: bool,
+    index: usize,
+    expected: Option<i32>,
+) {
+    let (detach, attach, _multi) = create_trailer_activities(5);
+    let route_ctx = create_route_ctx(
+        &create_fleet(truck_capacity),
+        vec![detach, create_job_activity(6, 2, true), attach, create_job_activity(8, 2, false)],
+    );
+    let target = create_job_activity(7, demand, truck_only);
+
+    let result = evaluate(&route_ctx, &target, index);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_trailer_insertion, (has_other_trailer, detach_index, attach_index, expected), {
+    can_evaluate_trailer_insertion_impl(has_other_trailer, detach_index, attach_index, expected);
+}}
+
+can_evaluate_trailer_insertion! {
+    case01_valid_order: (false, Some(0), Some(2), None),
+    case02_attach_before_detach: (false, Some(0), Some(0), Some(VIOLATION_CODE)),
+    case03_nested_detach: (true, Some(1), None, Some(VIOLATION_CODE)),
+    case04_attach_without_detach: (false, None, Some(0), Some(VIOLATION_CODE)),
+    case05_attach_to_other_trailer: (true, None, Some(1), Some(VIOLATION_CODE)),
+}
+
+fn can_evaluate_trailer_insertion_impl(
+    has_other_trailer: bool,
+    detach_index: Option<usize>,
+    attach_index: Option<usize>,
+    expected: Option<i32>,
+) {
+    let (activities, _other_multi) = if has_other_trailer {
+        let (detach, attach, multi) = create_trailer_activities(3);
+        (vec![detach, create_job_activity(6, 2, true), attach], Some(multi))
+    } else {
+        (vec![create_job_activity(6, 2, false)], None)
+    };
+    let mut route_ctx = create_route_ctx(&create_fleet(Some(5)), activities);
+    let (detach, attach, _multi) = create_trailer_activities(5);
+
+    let result = match (detach_index, attach_index) {
+        (Some(detach_index), Some(attach_index)) => {
+            assert_eq!(evaluate(&route_ctx, &detach, detach_index), None);
+            route_ctx.route_mut().tour.insert_at(detach, detach_index + 1);
+            evaluate(&route_ctx, &attach, attach_index)
+        }
+        (Some(detach_index), None) => evaluate(&route_ctx, &detach, detach_index),
+        (None, Some(attach_index)) => evaluate(&route_ctx, &attach, attach_index),
+        (None, None) => unreachable!(),
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_remove_obsolete_trailer_job() {
+    let fleet = create_fleet(Some(5));
+    let (detach, attach, _multi) = create_trailer_activities(5);
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    solution_ctx.routes.push(create_route_ctx(
+        &fleet,
+        vec![create_job_activity(2, 1, false), detach, create_job_activity(6, 1, false), attach],
+    ));
+
+    create_trailer_module().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.routes[0].route.tour.job_activity_count(), 2);
+    assert_eq!(solution_ctx.ignored.len(), 1);
+    assert!(solution_ctx.required.is_empty());
+}
+
+#[test]
+fn can_move_stray_truck_only_job_to_required() {
+    let fleet = create_fleet(Some(5));
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    solution_ctx.routes.push(create_route_ctx(&fleet, vec![create_job_activity(6, 1, true)]));
+
+    create_trailer_module().accept_solution_state(&mut solution_ctx);
+
+    assert!(solution_ctx.routes.is_empty());
+    assert_eq!(solution_ctx.required.len(), 1);
+}
+
+#[test]
+fn can_merge_only_non_trailer_jobs() {
+    let module = create_trailer_module();
+    let job = Job::Single(create_job_activity(1, 0, false).job.unwrap());
+    let (detach, _, _multi) = create_trailer_activities(1);
+    let trailer = detach.retrieve_job().unwrap();
+
+    assert!(module.merge(job.clone(), job.clone()).is_ok());
+    assert_eq!(module.merge(job.clone(), trailer.clone()).map(|_| ()), Err(VIOLATION_CODE));
+    assert_eq!(module.merge(trailer, job).map(|_| ()), Err(VIOLATION_CODE));
+}
@@ -0,0 +1,163 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::{Demand, DemandDimension, Location, SingleDimLoad};
+use vrp_core::models::problem::Fleet;
+use vrp_core::models::solution::Activity;
+
+const VIOLATION_CODE: i32 = 1;
+
+fn create_unloading_module() -> UnloadingModule<SingleDimLoad> {
+    UnloadingModule::new(VIOLATION_CODE)
+}
+
+fn create_fleet() -> Fleet {
+    test_fleet_with_vehicles(vec![Arc::new(test_vehicle("v1")), Arc::new(test_vehicle("v2"))])
+}
+
+fn create_route_ctx(fleet: &Fleet, vehicle_id: &str, activities: Vec<Activity>) -> RouteContext {
+    RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(fleet, vehicle_id, activities)),
+        Arc::new(RouteState::default()),
+    )
+}
+
+fn create_job_activity(location: Location, pickup: i32) -> Activity {
+    let demand = Demand::<SingleDimLoad> {
+        pickup: (SingleDimLoad::new(pickup), SingleDimLoad::default()),
+        delivery: (SingleDimLoad::default(), SingleDimLoad::new(1)),
+    };
+    let mut single = create_single_with_location(Some(location));
+    single.dimens.set_job_id(format!("job{}", location)).set_job_type("single".to_string()).set_demand(demand);
+
+    create_activity_with_job_at_location(Arc::new(single), location)
+}
+
+fn create_unloading_activity(vehicle_id: &str) -> Activity {
+    let mut single = create_single_with_location(Some(0));
+    single
+        .dimens
+        .set_job_id(format!("{}_unloading_0", vehicle_id))
+        .set_job_type("unloading".to_string())
+        .set_vehicle_id(vehicle_id.to_string())
+        .set_shift_index(0);
+
+    create_activity_with_job_at_location(Arc::new(single), 0)
+}
+
+fn evaluate(route_ctx: &RouteContext, target: &Activity, index: usize) -> Option<i32> {
+    let tour = &route_ctx.route.tour;
+    let activity_ctx = ActivityContext { index, prev: tour.get(index).unwrap(), target, next: tour.get(index + 1) };
+
+    create_unloading_module()
+        .get_constraints()
+        .find_map(|constraint| match constraint {
+            ConstraintVariant::HardActivity(constraint) => Some(constraint.clone()),
+            _ => None,
+        })
+        .unwrap()
+        .evaluate_activity(route_ctx, &activity_ctx)
+        .map(|violation| violation.code)
+}
+
+parameterized_test! {can_evaluate_activity_insertion, (is_unloading, index, expected), {
+    can_evaluate_activity_insertion_impl(is_unloading, index, expected);
+}}
+
+can_evaluate_activity_insertion! {
+    case01_unloading_at_end: (true, 2, None),
+    case02_unloading_in_the_middle: (true, 1, Some(VIOLATION_CODE)),
+    case03_job_before_unloading: (false, 1, None),
+    case04_job_after_unloading: (false, 3, Some(VIOLATION_CODE)),
+}
+
+fn can_evaluate_activity_insertion_impl(is_unloading: bool, index: usize, expected: Option<i32>) {
+    let fleet = create_fleet();
+    let mut activities = vec![create_job_activity(1, 1), create_job_activity(2, 0)];
+    if !is_unloading {
+        activities.push(create_unloading_activity("v1"));
+    }
+    let route_ctx = create_route_ctx(&fleet, "v1", activities);
+    let target = if is_unloading { create_unloading_activity("v1") } else { create_job_activity(3, 1) };
+
+    let result = evaluate(&route_ctx, &target, index);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_route_for_unloading, (vehicle_id, expected), {
+    can_evaluate_route_for_unloading_impl(vehicle_id, expected);
+}}
+
+can_evaluate_route_for_unloading! {
+    case01_own_vehicle: ("v1", None),
+    case02_other_vehicle: ("v2", Some(VIOLATION_CODE)),
+}
+
+fn can_evaluate_route_for_unloading_impl(vehicle_id: &str, expected: Option<i32>) {
+    let fleet = create_fleet();
+    let solution_ctx = create_solution_context_for_fleet(&fleet);
+    let route_ctx = create_route_ctx(&fleet, vehicle_id, vec![create_job_activity(1, 1)]);
+    let job = Job::Single(create_unloading_activity("v1").job.unwrap());
+
+    let result = create_unloading_module()
+        .get_constraints()
+        .find_map(|constraint| match constraint {
+            ConstraintVariant::HardRoute(constraint) => Some(constraint.clone()),
+            _ => None,
+        })
+        .unwrap()
+        .evaluate_job(&solution_ctx, &route_ctx, &job)
+        .map(|violation| violation.code);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_promote_unloading_to_required, (pickup, expected_required), {
+    can_promote_unloading_to_required_impl(pickup, expected_required);
+}}
+
+can_promote_unloading_to_required! {
+    case01_with_returns: (1, 1),
+    case02_without_returns: (0, 0),
+}
+
+fn can_promote_unloading_to_required_impl(pickup: i32, expected_required: usize) {
+    let fleet = create_fleet();
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    solution_ctx.routes.push(create_route_ctx(&fleet, "v1", vec![create_job_activity(1, pickup)]));
+    solution_ctx.ignored.push(Job::Single(create_unloading_activity("v1").job.unwrap()));
+
+    create_unloading_module().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.required.len(), expected_required);
+    assert_eq!(solution_ctx.ignored.len(), 1 - expected_required);
+}
+
+#[test]
+fn can_remove_obsolete_unloading_job() {
+    let fleet = create_fleet();
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    solution_ctx.routes.push(create_route_ctx(
+        &fleet,
+        "v1",
+        vec![create_job_activity(1, 0), create_unloading_activity("v1")],
+    ));
+
+    create_unloading_module().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.routes[0].route.tour.job_activity_count(), 1);
+    assert_eq!(solution_ctx.ignored.len(), 1);
+    assert!(solution_ctx.required.is_empty());
+}
+
+#[test]
+fn can_merge_only_non_unloading_jobs() {
+    let module = create_unloading_module();
+    let job = Job::Single(create_job_activity(1, 1).job.unwrap());
+    let unloading = Job::Single(create_unloading_activity("v1").job.unwrap());
+
+    assert!(module.merge(job.clone(), job.clone()).is_ok());
+    assert_eq!(module.merge(job.clone(), unloading.clone()).map(|_| ()), Err(VIOLATION_CODE));
+    assert_eq!(module.merge(unloading, job).map(|_| ()), Err(VIOLATION_CODE));
+}
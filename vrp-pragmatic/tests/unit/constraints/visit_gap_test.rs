@@ -0,0 +1,107 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::{Dimensions, Location, Schedule};
+use vrp_core::models::problem::{create_matrix_transport_cost, MatrixData, Multi, SimpleActivityCost, Single};
+
+const VIOLATION_CODE: i32 = 1;
+const STATE_KEY: i32 = 2;
+
+fn create_constraint() -> VisitGapHardActivityConstraint {
+    let size = 11;
+    let durations = (0..size)
+        .flat_map(|from: usize| (0..size).map(move |to: usize| (from as f64 - to as f64).abs()))
+        .collect::<Vec<_>>();
+
+    VisitGapHardActivityConstraint {
+        code: VIOLATION_CODE,
+        state_key: STATE_KEY,
+        activity: Arc::new(SimpleActivityCost::default()),
+        transport: create_matrix_transport_cost(vec![MatrixData::new(0, None, durations.clone(), durations)]).unwrap(),
+    }
+}
+
+fn create_multi_job(min: Option<Duration>, max: Option<Duration>) -> Arc<Multi> {
+    let mut dimens = Dimensions::default();
+    dimens.set_job_id("multi".to_string()).set_job_visit_gap(Some((min, max)));
+
+    Multi::new_shared(vec![create_single("s1"), create_single("s2")], dimens)
+}
+
+fn create_activity(single: Arc<Single>, location: Location, time: Timestamp) -> Activity {
+    Activity {
+        schedule: Schedule { arrival: time, departure: time },
+        ..create_activity_with_job_at_location(single, location)
+    }
+}
+
+fn create_route_ctx(activities: Vec<Activity>) -> RouteContext {
+    let mut state = RouteState::default();
+    state.put_route_state(STATE_KEY, true);
+
+    RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&test_fleet(), "v1", activities)),
+        Arc::new(state),
+    )
+}
+
+fn evaluate(route_ctx: &RouteContext, target: &Activity, index: usize) -> Option<i32> {
+    let tour = &route_ctx.route.tour;
+    let activity_ctx = ActivityContext { index, prev: tour.get(index).unwrap(), target, next: tour.get(index + 1) };
+
+    create_constraint().evaluate_activity(route_ctx, &activity_ctx).map(|violation| violation.code)
+}
+
+parameterized_test! {can_check_gap_when_second_visit_is_inserted, (min, max, index, expected), {
+    can_check_gap_when_second_visit_is_inserted_impl(min, max, index, expected);
+}}
+
+can_check_gap_when_second_visit_is_inserted! {
+    case01_min_violated: (Some(5.), None, 1, Some(VIOLATION_CODE)),
+    case02_min_satisfied: (Some(5.), None, 2, None),
+    case03_max_violated: (None, Some(10.), 2, Some(VIOLATION_CODE)),
+    case04_max_satisfied: (None, Some(10.), 1, None),
+    case05_both_satisfied: (Some(10.), Some(20.), 2, None),
+}
+
+fn can_check_gap_when_second_visit_is_inserted_impl(
+    min: Option<Duration>,
+    max: Option<Duration>,
+    index: usize,
+    expected: Option<i32>,
+) {
+    let multi = create_multi_job(min, max);
+    let route_ctx = create_route_ctx(vec![
+        create_activity(multi.jobs[0].clone(), 1, 1.),
+        create_activity(create_single("job1"), 10, 10.),
+    ]);
+    let target = create_activity(multi.jobs[1].clone(), 2, 0.);
+
+    // NOTE gap is 1 when inserted right after the first visit, and 17 when inserted after job1
+    let result = evaluate(&route_ctx, &target, index);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_gap_when_other_job_is_inserted_between_visits, (max, expected), {
+    can_check_gap_when_other_job_is_inserted_between_visits_impl(max, expected);
+}}
+
+can_check_gap_when_other_job_is_inserted_between_visits! {
+    case01_max_violated: (10., Some(VIOLATION_CODE)),
+    case02_max_satisfied: (20., None),
+}
+
+fn can_check_gap_when_other_job_is_inserted_between_visits_impl(max: Duration, expected: Option<i32>) {
+    let multi = create_multi_job(None, Some(max));
+    let route_ctx = create_route_ctx(vec![
+        create_activity(multi.jobs[0].clone(), 1, 1.),
+        create_activity(multi.jobs[1].clone(), 2, 2.),
+    ]);
+    let target = create_activity(create_single("job1"), 10, 0.);
+
+    // NOTE second visit is shifted: gap becomes 17
+    let result = evaluate(&route_ctx, &target, 1);
+
+    assert_eq!(result, expected);
+}
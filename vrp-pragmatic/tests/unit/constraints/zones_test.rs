@@ -0,0 +1,65 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::Location;
+use vrp_core::models::problem::Single;
+
+fn create_single(id: &str, location: Location, zones: Option<Vec<(usize, usize)>>) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(location));
+    single.dimens.set_job_id(id.to_string()).set_job_zones(zones);
+
+    Arc::new(single)
+}
+
+fn create_activity(id: &str, location: Location, zones: Option<Vec<(usize, usize)>>) -> Activity {
+    create_activity_with_job_at_location(create_single(id, location, zones), location)
+}
+
+parameterized_test! {can_estimate_zone_sequence_violations, (zones, index, expected), {
+    can_estimate_zone_sequence_violations_impl(zones, index, expected);
+}}
+
+can_estimate_zone_sequence_violations! {
+    case01_same_zone_in_order: (Some(vec![(0, 1)]), 1, 0.),
+    case02_same_zone_after_same_zone: (Some(vec![(0, 1)]), 2, 0.),
+    case03_before_preceding_zone: (Some(vec![(0, 1)]), 0, 10.),
+    case04_after_following_zone: (Some(vec![(0, 1)]), 3, 10.),
+    case05_first_zone_at_end: (Some(vec![(0, 0)]), 3, 20.),
+    case06_last_zone_at_start: (Some(vec![(0, 2)]), 0, 20.),
+    case07_last_zone_at_end: (Some(vec![(0, 2)]), 3, 0.),
+    case08_no_zones: (None, 0, 0.),
+    case09_other_sequence: (Some(vec![(1, 0)]), 3, 0.),
+    case10_several_sequences: (Some(vec![(0, 0), (1, 0)]), 3, 20.),
+}
+
+fn can_estimate_zone_sequence_violations_impl(zones: Option<Vec<(usize, usize)>>, index: usize, expected: f64) {
+    let fleet = test_fleet();
+    let route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(
+            &fleet,
+            "v1",
+            vec![
+                create_activity("job1", 1, Some(vec![(0, 0)])),
+                create_activity("job2", 2, Some(vec![(0, 1)])),
+                create_activity("job3", 3, Some(vec![(0, 2)])),
+            ],
+        )),
+        Arc::new(RouteState::default()),
+    );
+    let target = create_activity("job4", 4, zones);
+    let tour = &route_ctx.route.tour;
+    let activity_ctx =
+        ActivityContext { index, prev: tour.get(index).unwrap(), target: &target, next: tour.get(index + 1) };
+    let module = ZoneSequenceModule::new(vec![10., 5.]);
+
+    let result = module
+        .get_constraints()
+        .find_map(|constraint| match constraint {
+            ConstraintVariant::SoftActivity(constraint) => Some(constraint.clone()),
+            _ => None,
+        })
+        .unwrap()
+        .estimate_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,58 @@
+use super::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_detect_point_inside_polygon, (point, expected), {
+    can_detect_point_inside_polygon_impl(point, expected);
+}}
+
+can_detect_point_inside_polygon! {
+    case01_inside: ((0.5, 0.5), true),
+    case02_outside: ((3., 1.), false),
+    case03_inside_concave_part: ((0.5, 1.5), true),
+    case04_outside_concave_part: ((1.5, 1.5), false),
+    case05_far_away: ((-10., -10.), false),
+}
+
+fn can_detect_point_inside_polygon_impl(point: (f64, f64), expected: bool) {
+    // NOTE L-shaped polygon
+    let polygon = [(0., 0.), (2., 0.), (2., 1.), (1., 1.), (1., 2.), (0., 2.)];
+
+    assert_eq!(is_inside_polygon(point, &polygon), expected);
+}
+
+#[test]
+fn can_ignore_degenerated_polygon() {
+    assert!(!is_inside_polygon((0., 0.), &[(-1., -1.), (1., 1.)]));
+}
+
+#[test]
+fn can_normalize_areas_with_polygons() {
+    let to_loc = |(lat, lng): (f64, f64)| Location::Coordinate { lat, lng };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 1.)),
+                create_delivery_job("job2", (3., 1.)),
+                create_pickup_delivery_job("job3", (1., 1.), (3., 1.)),
+                create_pickup_delivery_job("job4", (0.5, 0.5), (1.5, 1.5)),
+            ],
+            areas: Some(vec![
+                Area {
+                    id: "area1".to_string(),
+                    jobs: vec!["job2".to_string()],
+                    polygon: Some(vec![to_loc((0., 0.)), to_loc((2., 0.)), to_loc((2., 2.)), to_loc((0., 2.))]),
+                },
+                Area { id: "area2".to_string(), jobs: vec!["job3".to_string()], polygon: None },
+            ]),
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let problem = normalize_areas(problem);
+
+    let areas = problem.plan.areas.unwrap();
+    assert_eq!(areas[0].jobs, vec!["job2".to_string(), "job1".to_string(), "job4".to_string()]);
+    assert_eq!(areas[1].jobs, vec!["job3".to_string()]);
+}
@@ -0,0 +1,82 @@
+use super::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_named_loads(loads: &[(&str, i32)]) -> Option<Vec<NamedLoad>> {
+    Some(loads.iter().map(|(dimension, value)| NamedLoad { dimension: dimension.to_string(), value: *value }).collect())
+}
+
+fn create_load_dimensions(dimensions: &[(&str, Option<bool>)]) -> Option<Vec<LoadDimension>> {
+    Some(
+        dimensions
+            .iter()
+            .map(|(name, is_reloadable)| LoadDimension { name: name.to_string(), is_reloadable: *is_reloadable })
+            .collect(),
+    )
+}
+
+#[test]
+fn can_normalize_named_dimensions() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    deliveries: Some(vec![JobTask {
+                        named_demand: create_named_loads(&[("volume", 2), ("weight", 3)]),
+                        ..create_task((1., 0.), None)
+                    }]),
+                    ..create_job("job1")
+                },
+                create_delivery_job_with_demand("job2", (2., 0.), vec![1, 2]),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                named_capacity: create_named_loads(&[("weight", 10)]),
+                ..create_default_vehicle_type()
+            }],
+            dimensions: create_load_dimensions(&[("weight", None), ("volume", None), ("pallets", None)]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let problem = normalize_dimensions(problem);
+
+    assert_eq!(problem.fleet.vehicles[0].capacity, vec![10, 0, 0]);
+    let demands = problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| job.deliveries.iter().flatten())
+        .map(|task| task.demand.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(demands, vec![Some(vec![3, 2, 0]), Some(vec![1, 2])]);
+}
+
+#[test]
+fn can_skip_normalization_without_dimensions() {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                named_capacity: create_named_loads(&[("weight", 10)]),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let problem = normalize_dimensions(problem);
+
+    assert_eq!(problem.fleet.vehicles[0].capacity, vec![10]);
+}
+
+#[test]
+fn can_get_non_reloadable_dimensions() {
+    let dimensions = create_load_dimensions(&[("weight", None), ("volume", Some(false)), ("pallets", Some(true))]);
+
+    assert_eq!(get_non_reloadable_dimensions(dimensions.as_ref()), vec![1]);
+    assert!(get_non_reloadable_dimensions(None).is_empty());
+}
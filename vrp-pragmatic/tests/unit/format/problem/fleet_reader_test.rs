@@ -31,7 +31,10 @@ fn wrong_matrix(profile: Option<&str>, timestamp: Option<String>) -> Matrix {
 fn create_problem(profiles: &[&str]) -> Problem {
     Problem {
         fleet: Fleet {
-            profiles: profiles.iter().map(|p| MatrixProfile { name: p.to_string(), speed: None }).collect(),
+            profiles: profiles
+                .iter()
+                .map(|p| MatrixProfile { name: p.to_string(), speed: None, infer: None, metric: None })
+                .collect(),
             ..create_default_fleet()
         },
         ..create_empty_problem()
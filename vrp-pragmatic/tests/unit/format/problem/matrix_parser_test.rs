@@ -0,0 +1,57 @@
+use super::*;
+use crate::helpers::SIMPLE_MATRIX;
+
+#[test]
+fn can_parse_matrix_same_as_generic_parser() {
+    let expected: Matrix = serde_json::from_str(SIMPLE_MATRIX).unwrap();
+
+    let matrix = parse_matrix(SIMPLE_MATRIX.as_bytes()).expect("cannot parse matrix");
+
+    assert_eq!(matrix.profile, expected.profile);
+    assert_eq!(matrix.timestamp, expected.timestamp);
+    assert_eq!(matrix.travel_times, expected.travel_times);
+    assert_eq!(matrix.distances, expected.distances);
+    assert_eq!(matrix.error_codes, expected.error_codes);
+}
+
+#[test]
+fn can_parse_matrix_with_all_properties() {
+    let json = r#" {
+        "profile": "car", "timestamp": null,
+        "durations": [0, -1, 9223372036854775807],
+        "distances": [ ],
+        "errorCodes": [0, 1]
+    } "#;
+
+    let matrix = parse_matrix(json.as_bytes()).expect("cannot parse matrix");
+
+    assert_eq!(matrix.profile, Some("car".to_string()));
+    assert_eq!(matrix.timestamp, None);
+    assert_eq!(matrix.travel_times, vec![0, -1, i64::MAX]);
+    assert!(matrix.distances.is_empty());
+    assert_eq!(matrix.error_codes, Some(vec![0, 1]));
+}
+
+parameterized_test! {can_fall_back_on_unsupported_input, json, {
+    can_fall_back_on_unsupported_input_impl(json);
+}}
+
+can_fall_back_on_unsupported_input! {
+    case01_escaped_string: r#"{ "profile": "c\"ar", "travelTimes": [1], "distances": [1] }"#,
+    case02_unknown_property: r#"{ "travelTimes": [1], "distances": [1], "speed": 10 }"#,
+    case03_fraction: r#"{ "travelTimes": [1.5], "distances": [1] }"#,
+    case04_exponent: r#"{ "travelTimes": [1e3], "distances": [1] }"#,
+    case05_leading_zero: r#"{ "travelTimes": [01], "distances": [1] }"#,
+    case06_overflow: r#"{ "travelTimes": [9223372036854775808], "distances": [1] }"#,
+    case07_missing_distances: r#"{ "travelTimes": [1] }"#,
+    case08_duplicate_alias: r#"{ "travelTimes": [1], "durations": [1], "distances": [1] }"#,
+    case09_trailing_comma: r#"{ "travelTimes": [1,], "distances": [1] }"#,
+    case10_trailing_content: r#"{ "travelTimes": [1], "distances": [1] } {"#,
+    case11_truncated: r#"{ "travelTimes": [1], "distances": [1"#,
+}
+
+fn can_fall_back_on_unsupported_input_impl(json: &str) {
+    let result = parse_matrix(json.as_bytes());
+
+    assert!(result.is_none());
+}
@@ -64,3 +64,34 @@ fn can_deserialize_matrix() {
     assert_eq!(matrix.distances.len(), 16);
     assert_eq!(matrix.travel_times.len(), 16);
 }
+
+#[test]
+fn can_deserialize_problem_and_matrix_from_slice() {
+    let problem = deserialize_problem_from_slice(SIMPLE_PROBLEM.as_bytes()).ok().unwrap();
+    let matrix = deserialize_matrix_from_slice(SIMPLE_MATRIX.as_bytes()).ok().unwrap();
+
+    assert_eq!(problem.plan.jobs.len(), 2);
+    assert_eq!(problem.fleet.vehicles.len(), 1);
+    assert_eq!(matrix.distances.len(), 16);
+    assert_eq!(matrix.travel_times.len(), 16);
+}
+
+#[test]
+fn can_return_error_when_deserializing_invalid_slice() {
+    let result = deserialize_problem_from_slice("{ \"plan\": ".as_bytes());
+
+    assert_eq!(result.err().unwrap().first().unwrap().code, "E0000");
+}
+
+#[test]
+fn can_deserialize_job_properties_in_camel_case() {
+    let job = r#"{
+        "id": "job1",
+        "deliveries": [{ "places": [{ "location": { "index": 0 }, "duration": 1 }] }],
+        "visitGap": { "max": 10 }
+    }"#;
+
+    let job: Job = serde_json::from_str(job).unwrap();
+
+    assert_eq!(job.visit_gap.and_then(|gap| gap.max), Some(10.));
+}
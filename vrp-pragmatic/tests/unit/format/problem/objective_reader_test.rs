@@ -1,14 +1,17 @@
-use crate::constraints::{TOTAL_VALUE_KEY, TOUR_ORDER_KEY};
+use crate::constraints::{SKILL_PENALTY_KEY, TOTAL_VALUE_KEY, TOUR_ORDER_KEY};
+use crate::extensions::JobTie;
 use crate::format::problem::reader::objective_reader::*;
 use crate::format::problem::reader::ProblemProperties;
+use crate::format::CoordIndex;
 use crate::helpers::create_empty_problem;
-use crate::helpers::{create_empty_insertion_context, create_single_with_type};
+use crate::helpers::{create_empty_insertion_context, create_single_with_location, create_single_with_type};
 use std::sync::Arc;
 use vrp_core::construction::constraints::ConstraintPipeline;
-use vrp_core::construction::heuristics::InsertionContext;
-use vrp_core::models::problem::Job;
+use vrp_core::construction::heuristics::{InsertionContext, UnassignmentInfo};
+use vrp_core::models::problem::SimpleActivityCost;
+use vrp_core::models::problem::{create_matrix_transport_cost, ActivityCost, Job, MatrixData, TransportCost};
 use vrp_core::rosomaxa::prelude::MultiObjective;
-use vrp_core::solver::objectives::OrderResult;
+use vrp_core::solver::objectives::{OrderResult, OrderViolations};
 
 fn create_problem_props() -> ProblemProperties {
     ProblemProperties {
@@ -20,14 +23,54 @@ fn create_problem_props() -> ProblemProperties {
         has_reloads: false,
         has_order: false,
         has_group: false,
+        has_bundle: false,
+        has_unassigned_penalty: false,
         has_compatibility: false,
         has_tour_size_limits: false,
         has_tour_travel_limits: false,
+        has_consecutive_driving_limits: false,
+        has_driving_time: false,
+        has_vehicle_tiers: false,
+        has_visit_gaps: false,
+        has_ride_durations: false,
+        has_sync: false,
+        has_precedences: false,
+        has_docks: false,
+        has_loading_order: false,
+        has_backhaul: false,
+        has_zone_sequences: false,
+        has_placements: false,
+        has_preferred_skills: false,
+        has_energy: false,
+        has_trailers: false,
+        has_territories: false,
+        has_strict_territories: false,
+        has_pinned_territories: false,
+        has_area_restrictions: false,
+        has_area_penalty: false,
+        has_physical_limits: false,
+        has_departure_optimization: false,
+        has_overtime: false,
+        has_unloading: false,
+        has_relays: false,
         max_job_value: None,
         max_area_value: None,
+        escalation_weight: None,
     }
 }
 
+fn create_transport() -> Arc<dyn TransportCost + Send + Sync> {
+    create_matrix_transport_cost(vec![MatrixData::new(0, None, vec![0.], vec![0.])]).unwrap()
+}
+
+fn create_activity() -> Arc<dyn ActivityCost + Send + Sync> {
+    Arc::new(SimpleActivityCost::default())
+}
+
+fn create_order_violations(count: usize) -> OrderViolations {
+    OrderViolations { count, magnitude: count as f64 }
+}
+
 fn create_solution_with_state_value<T: Send + Sync + 'static>(state_key: i32, value: T) -> InsertionContext {
     let mut insertion_ctx = create_empty_insertion_context();
     insertion_ctx.solution.state.insert(state_key, Arc::new(value));
@@ -41,7 +84,14 @@ fn can_define_proper_place_for_value_objective_by_default() {
     let mut constraint = ConstraintPipeline::default();
     let props = ProblemProperties { max_job_value: Some(1.), ..create_problem_props() };
 
-    let objective_cost = create_objective(&problem, &mut constraint, &props);
+    let objective_cost = create_objective(
+        &problem,
+        &mut constraint,
+        &create_transport(),
+        &create_activity(),
+        &props,
+        &CoordIndex::new(&problem),
+    );
     let objectives = objective_cost.objectives().collect::<Vec<_>>();
 
     assert_eq!(objectives[0].fitness(&create_solution_with_state_value(TOTAL_VALUE_KEY, 1234.)), 1234.);
@@ -53,10 +103,20 @@ fn can_define_proper_place_for_order_objective_by_default() {
     let mut constraint = ConstraintPipeline::default();
     let props = ProblemProperties { has_order: true, ..create_problem_props() };
 
-    let objective_cost = create_objective(&problem, &mut constraint, &props);
+    let objective_cost = create_objective(
+        &problem,
+        &mut constraint,
+        &create_transport(),
+        &create_activity(),
+        &props,
+        &CoordIndex::new(&problem),
+    );
     let objectives = objective_cost.objectives().collect::<Vec<_>>();
 
-    assert_eq!(objectives[1].fitness(&create_solution_with_state_value(TOUR_ORDER_KEY, 1234_usize)), 1234.);
+    assert_eq!(
+        objectives[1].fitness(&create_solution_with_state_value(TOUR_ORDER_KEY, create_order_violations(1234))),
+        1234.
+    );
 }
 
 #[test]
@@ -65,17 +125,44 @@ fn can_define_proper_places_for_mixed_priority_and_order_objectives_by_default()
     let mut constraint = ConstraintPipeline::default();
     let mut insertion_ctx = create_empty_insertion_context();
     insertion_ctx.solution.state.insert(TOTAL_VALUE_KEY, Arc::new(123.));
-    insertion_ctx.solution.state.insert(TOUR_ORDER_KEY, Arc::new(321_usize));
+    insertion_ctx.solution.state.insert(TOUR_ORDER_KEY, Arc::new(create_order_violations(321)));
 
     let props = ProblemProperties { max_job_value: Some(1.), has_order: true, ..create_problem_props() };
 
-    let objective_cost = create_objective(&problem, &mut constraint, &props);
+    let objective_cost = create_objective(
+        &problem,
+        &mut constraint,
+        &create_transport(),
+        &create_activity(),
+        &props,
+        &CoordIndex::new(&problem),
+    );
     let objectives = objective_cost.objectives().collect::<Vec<_>>();
 
     assert_eq!(objectives[0].fitness(&insertion_ctx), 123.);
     assert_eq!(objectives[2].fitness(&insertion_ctx), 321.);
 }
 
+#[test]
+fn can_define_proper_place_for_skill_penalty_objective_by_default() {
+    let problem = create_empty_problem();
+    let mut constraint = ConstraintPipeline::default();
+    let props = ProblemProperties { has_preferred_skills: true, ..create_problem_props() };
+
+    let objective_cost = create_objective(
+        &problem,
+        &mut constraint,
+        &create_transport(),
+        &create_activity(),
+        &props,
+        &CoordIndex::new(&problem),
+    );
+    let objectives = objective_cost.objectives().collect::<Vec<_>>();
+
+    assert_eq!(objectives.len(), 4);
+    assert_eq!(objectives[2].fitness(&create_solution_with_state_value(SKILL_PENALTY_KEY, 1234.)), 1234.);
+}
+
 #[test]
 fn can_get_default_unassigned_job_estimate() {
     let estimate_for_type = |activity_type: &str| {
@@ -90,6 +177,74 @@ fn can_get_default_unassigned_job_estimate() {
     assert_eq!(estimate_for_type("service"), 1.);
 }
 
+#[test]
+fn can_estimate_unassigned_reservation_jobs_lower_than_real_one() {
+    let problem = create_empty_problem();
+    let mut constraint = ConstraintPipeline::default();
+    let props = create_problem_props();
+    let mut insertion_ctx = create_empty_insertion_context();
+    let mut reservation = create_single_with_location(Some(0));
+    reservation.dimens.set_job_id("job1".to_string()).set_job_reservation(Some(true));
+    insertion_ctx.solution.unassigned.insert(Job::Single(Arc::new(reservation)), UnassignmentInfo::Unknown);
+    insertion_ctx
+        .solution
+        .unassigned
+        .insert(Job::Single(create_single_with_type("job2", "delivery")), UnassignmentInfo::Unknown);
+
+    let objective_cost = create_objective(
+        &problem,
+        &mut constraint,
+        &create_transport(),
+        &create_activity(),
+        &props,
+        &CoordIndex::new(&problem),
+    );
+    let objectives = objective_cost.objectives().collect::<Vec<_>>();
+
+    assert_eq!(objectives[0].fitness(&insertion_ctx), 1.01);
+}
+
+parameterized_test! {can_escalate_unassigned_jobs_with_failed_attempts, (escalation_weight, attempts, expected), {
+    can_escalate_unassigned_jobs_with_failed_attempts_impl(escalation_weight, attempts, expected);
+}}
+
+can_escalate_unassigned_jobs_with_failed_attempts! {
+    case01_no_escalation: (None, Some(2), 2.),
+    case02_no_attempts: (Some(1.), None, 2.),
+    case03_default_weight: (Some(1.), Some(2), 4.),
+    case04_custom_weight: (Some(0.5), Some(2), 3.),
+}
+
+fn can_escalate_unassigned_jobs_with_failed_attempts_impl(
+    escalation_weight: Option<f64>,
+    attempts: Option<usize>,
+    expected: f64,
+) {
+    let problem = create_empty_problem();
+    let mut constraint = ConstraintPipeline::default();
+    let props = ProblemProperties { escalation_weight, ..create_problem_props() };
+    let mut insertion_ctx = create_empty_insertion_context();
+    let mut escalated = create_single_with_location(Some(0));
+    escalated.dimens.set_job_id("job1".to_string()).set_job_type("delivery".to_string()).set_job_attempts(attempts);
+    insertion_ctx.solution.unassigned.insert(Job::Single(Arc::new(escalated)), UnassignmentInfo::Unknown);
+    insertion_ctx
+        .solution
+        .unassigned
+        .insert(Job::Single(create_single_with_type("job2", "delivery")), UnassignmentInfo::Unknown);
+
+    let objective_cost = create_objective(
+        &problem,
+        &mut constraint,
+        &create_transport(),
+        &create_activity(),
+        &props,
+        &CoordIndex::new(&problem),
+    );
+    let objectives = objective_cost.objectives().collect::<Vec<_>>();
+
+    assert_eq!(objectives[0].fitness(&insertion_ctx), expected);
+}
+
 #[test]
 fn can_get_default_order() {
     let compare_for_type = |activity_type: &str, expected: OrderResult| {
@@ -1,12 +1,13 @@
 use super::create_approx_matrices;
 use crate::extensions::{JobTie, VehicleTie};
 use crate::format::problem::*;
+use crate::format_time;
 use crate::helpers::*;
 use hashbrown::HashSet;
 use std::iter::FromIterator;
 use std::sync::Arc;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Jobs, Multi, Place, Single};
+use vrp_core::models::problem::{Jobs, Multi, Place, ServiceRateDimension, Single};
 
 fn get_job(index: usize, jobs: &Jobs) -> vrp_core::models::problem::Job {
     jobs.all().collect::<Vec<_>>().get(index).unwrap().clone()
@@ -78,9 +79,14 @@ fn can_read_complex_problem() {
                             location: (52.48325, 13.4436).to_loc(),
                             duration: 100.0,
                             tag: Some("my_delivery".to_string()),
+                            duration_per_unit: None,
+                            lateness_penalty: None,
+                            post_service_buffer: None,
+                            recurring_times: None,
                         }],
                         demand: Some(vec![0, 1]),
                         order: None,
+                        named_demand: None,
                     }]),
                     skills: Some(all_of_skills(vec!["unique".to_string()])),
                     ..create_job("delivery_job")
@@ -95,9 +101,14 @@ fn can_read_complex_problem() {
                             location: (52.48300, 13.4420).to_loc(),
                             duration: 110.0,
                             tag: None,
+                            duration_per_unit: None,
+                            lateness_penalty: None,
+                            post_service_buffer: None,
+                            recurring_times: None,
                         }],
                         demand: Some(vec![2]),
                         order: None,
+                        named_demand: None,
                     }]),
                     deliveries: Some(vec![JobTask {
                         places: vec![JobPlace {
@@ -108,9 +119,14 @@ fn can_read_complex_problem() {
                             location: (52.48325, 13.4436).to_loc(),
                             duration: 120.0,
                             tag: None,
+                            duration_per_unit: None,
+                            lateness_penalty: None,
+                            post_service_buffer: None,
+                            recurring_times: None,
                         }],
                         demand: Some(vec![2]),
                         order: None,
+                        named_demand: None,
                     }]),
                     ..create_job("pickup_delivery_job")
                 },
@@ -124,9 +140,14 @@ fn can_read_complex_problem() {
                             location: (52.48321, 13.4438).to_loc(),
                             duration: 90.0,
                             tag: None,
+                            duration_per_unit: None,
+                            lateness_penalty: None,
+                            post_service_buffer: None,
+                            recurring_times: None,
                         }],
                         demand: Some(vec![3]),
                         order: None,
+                        named_demand: None,
                     }]),
                     skills: Some(all_of_skills(vec!["unique2".to_string()])),
                     ..create_job("pickup_job")
@@ -139,7 +160,7 @@ fn can_read_complex_problem() {
                 type_id: "my_vehicle".to_string(),
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 profile: create_default_vehicle_profile(),
-                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2. },
+                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2., waiting: None },
                 shifts: vec![VehicleShift {
                     start: ShiftStart {
                         earliest: "1970-01-01T00:00:00Z".to_string(),
@@ -150,6 +171,7 @@ fn can_read_complex_problem() {
                         earliest: None,
                         latest: "1970-01-01T00:01:40Z".to_string(),
                         location: (52.4862, 13.45148).to_loc(),
+                        is_open: None,
                     }),
                     dispatch: None,
                     breaks: Some(vec![VehicleBreak::Optional {
@@ -165,15 +187,39 @@ fn can_read_complex_problem() {
                         policy: None,
                     }]),
                     reloads: None,
+                    recharges: None,
+                    trailer_parkings: None,
+                    maintenance: None,
+                    depots: None,
+                    overtime: None,
+                    unloading: None,
+                    relays: None,
                 }],
                 capacity: vec![10, 1],
                 skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
+                skill_counts: None,
                 limits: Some(VehicleLimits {
                     max_distance: Some(123.1),
                     shift_time: Some(100.),
+                    driver_shift_time: None,
                     tour_size: Some(3),
+                    min_tour_size: None,
                     areas: None,
+                    consecutive_driving: None,
+                    driving_time: None,
+                    allowed_areas: None,
+                    max_cost: None,
                 }),
+                tier: None,
+                loading: None,
+                compartments: None,
+                energy: None,
+                emissions: None,
+                trailer: None,
+                physical: None,
+                optimize_departure: None,
+                duration_per_unit: None,
+                named_capacity: None,
             }],
             ..create_default_fleet()
         },
@@ -285,6 +331,186 @@ fn can_deserialize_minimal_problem_and_matrix() {
     );
 }
 
+#[test]
+fn can_read_duration_per_unit_of_demand() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![JobPlace {
+                        duration: 10.,
+                        duration_per_unit: Some(5.),
+                        ..create_job_place((1., 0.), None)
+                    }],
+                    demand: Some(vec![2, 3]),
+                    order: None,
+                    named_demand: None,
+                }]),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
+
+    let job = get_single_job(0, &problem.jobs);
+    assert_eq!(get_single_place(job.as_ref()).duration, 20.);
+    assert!(job.dimens.get_service_units().is_none());
+}
+
+#[test]
+fn can_read_duration_per_unit_for_each_place() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![
+                        JobPlace { duration: 10., duration_per_unit: Some(5.), ..create_job_place((1., 0.), None) },
+                        JobPlace { duration: 10., ..create_job_place((2., 0.), None) },
+                    ],
+                    demand: Some(vec![2, 3]),
+                    order: None,
+                    named_demand: None,
+                }]),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
+
+    let job = get_single_job(0, &problem.jobs);
+    assert_eq!(job.places.iter().map(|place| place.duration).collect::<Vec<_>>(), vec![20., 10.]);
+    assert_eq!(job.dimens.get_service_units(), Some(&vec![0., 2.]));
+}
+
+#[test]
+fn can_read_vehicle_duration_per_unit() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![JobPlace { duration: 10., ..create_job_place((1., 0.), None) }],
+                    demand: Some(vec![2, 3]),
+                    order: None,
+                    named_demand: None,
+                }]),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { duration_per_unit: Some(5.), ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
+
+    let job = get_single_job(0, &problem.jobs);
+    assert_eq!(get_single_place(job.as_ref()).duration, 10.);
+    assert_eq!(job.dimens.get_service_units(), Some(&vec![2.]));
+    let vehicle = problem.fleet.vehicles.first().unwrap();
+    assert_eq!(vehicle.dimens.get_service_rate(), Some(5.));
+}
+
+parameterized_test! {can_apply_service_blackouts_to_time_windows, (times, blackout, expected), {
+    can_apply_service_blackouts_to_time_windows_impl(times, blackout, expected);
+}}
+
+can_apply_service_blackouts_to_time_windows! {
+    case01_no_times: (None, (10, 20), vec![(0., 10.), (20., f64::MAX)]),
+    case02_split: (Some(vec![(0, 100)]), (10, 20), vec![(0., 10.), (20., 100.)]),
+    case03_cut_end: (Some(vec![(0, 15)]), (10, 20), vec![(0., 10.)]),
+    case04_cut_start: (Some(vec![(15, 30)]), (10, 20), vec![(20., 30.)]),
+    case05_no_intersection: (Some(vec![(0, 10), (20, 30)]), (10, 20), vec![(0., 10.), (20., 30.)]),
+    case06_inside: (Some(vec![(0, 5), (12, 18)]), (10, 20), vec![(0., 5.)]),
+}
+
+fn can_apply_service_blackouts_to_time_windows_impl(
+    times: Option<Vec<(i32, i32)>>,
+    blackout: (i32, i32),
+    expected: Vec<(f64, f64)>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![JobPlace {
+                        times: times.map(|times| {
+                            times
+                                .into_iter()
+                                .map(|(start, end)| vec![format_time(start as f64), format_time(end as f64)])
+                                .collect()
+                        }),
+                        ..create_job_place((1., 0.), None)
+                    }],
+                    demand: Some(vec![1]),
+                    order: None,
+                    named_demand: None,
+                }]),
+                ..create_job("job1")
+            }],
+            blackouts: Some(vec![ServiceBlackout {
+                time: vec![format_time(blackout.0 as f64), format_time(blackout.1 as f64)],
+                areas: None,
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
+
+    assert_time_spans(&get_single_place(get_single_job(0, &problem.jobs).as_ref()).times, expected);
+}
+
+#[test]
+fn can_apply_service_blackout_only_to_area_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            areas: Some(vec![Area { id: "area1".to_string(), jobs: vec!["job2".to_string()], polygon: None }]),
+            blackouts: Some(vec![ServiceBlackout {
+                time: vec![format_time(10.), format_time(20.)],
+                areas: Some(vec!["area1".to_string()]),
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
+
+    let get_times = |job_id: &str| {
+        problem
+            .jobs
+            .all()
+            .find(|job| job.dimens().get_job_id().is_some_and(|id| id.as_str() == job_id))
+            .unwrap()
+            .to_single()
+            .places[0]
+            .times
+            .clone()
+    };
+    assert_time_spans(&get_times("job1"), vec![(0., f64::MAX)]);
+    assert_time_spans(&get_times("job2"), vec![(0., 10.), (20., f64::MAX)]);
+}
+
 #[test]
 fn can_create_approximation_matrices() {
     let problem = Problem {
@@ -298,10 +524,10 @@ fn can_create_approximation_matrices() {
         fleet: Fleet {
             vehicles: vec![],
             profiles: vec![
-                MatrixProfile { name: "car1".to_string(), speed: Some(8.) },
-                MatrixProfile { name: "car2".to_string(), speed: Some(10.) },
-                MatrixProfile { name: "car3".to_string(), speed: Some(5.) },
-                MatrixProfile { name: "car4".to_string(), speed: None },
+                MatrixProfile { name: "car1".to_string(), speed: Some(8.), infer: None, metric: None },
+                MatrixProfile { name: "car2".to_string(), speed: Some(10.), infer: None, metric: None },
+                MatrixProfile { name: "car3".to_string(), speed: Some(5.), infer: None, metric: None },
+                MatrixProfile { name: "car4".to_string(), speed: None, infer: None, metric: None },
             ],
             ..create_default_fleet()
         },
@@ -321,3 +547,40 @@ fn can_create_approximation_matrices() {
         assert_eq!(matrix.travel_times, &[0, duration, duration, 0]);
     }
 }
+
+parameterized_test! {can_apply_metric_policy, (rounding, expected), {
+    can_apply_metric_policy_impl(rounding, expected);
+}}
+
+can_apply_metric_policy! {
+    case01_round: (None, (vec![0, 2, -1, 3], vec![0, 2, -1, 4])),
+    case02_ceil: (Some(RoundingMode::Ceil), (vec![0, 2, -1, 4], vec![0, 2, -1, 4])),
+    case03_floor: (Some(RoundingMode::Floor), (vec![0, 1, -1, 3], vec![0, 1, -1, 3])),
+}
+
+fn can_apply_metric_policy_impl(rounding: Option<RoundingMode>, expected: (Vec<i64>, Vec<i64>)) {
+    let problem = Problem {
+        fleet: Fleet {
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                infer: None,
+                metric: Some(MetricPolicy { distance_factor: Some(0.5), duration_factor: Some(0.25), rounding }),
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = Matrix {
+        profile: Some("car".to_string()),
+        timestamp: None,
+        travel_times: vec![0, 6, -1, 13],
+        distances: vec![0, 3, -1, 7],
+        error_codes: None,
+    };
+
+    let matrices = apply_metric_policies(&problem, vec![matrix]);
+
+    assert_eq!(matrices.len(), 1);
+    assert_eq!((matrices[0].travel_times.clone(), matrices[0].distances.clone()), expected);
+}
@@ -0,0 +1,194 @@
+use super::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_test_problem(shift_start: &str, times: Vec<Vec<String>>) -> ApiProblem {
+    let mut job = create_delivery_job("job1", (1., 0.));
+    job.deliveries.as_mut().unwrap().first_mut().unwrap().places.first_mut().unwrap().times = Some(times);
+
+    let shift = create_default_vehicle_shift();
+    let shift = VehicleShift { start: ShiftStart { earliest: shift_start.to_string(), ..shift.start }, ..shift };
+
+    Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { shifts: vec![shift], ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_normalize_job_times, (shift_start, time_window, expected), {
+    can_normalize_job_times_impl(shift_start, time_window, expected);
+}}
+
+can_normalize_job_times! {
+    case01_midnight: ("2020-07-04T08:00:00Z", ("22:00", "02:00"), ("2020-07-04T22:00:00Z", "2020-07-05T02:00:00Z")),
+    case02_same_day: ("2020-07-04T08:00:00Z", ("10:00", "12:30:15"), ("2020-07-04T10:00:00Z", "2020-07-04T12:30:15Z")),
+    case03_epoch_offset: ("2020-07-04T01:00:00+02:00", ("22:00", "23:00"), ("2020-07-04T20:00:00Z", "2020-07-04T21:00:00Z")),
+    case04_explicit_offset: ("2020-07-04T08:00:00Z", ("10:00+02:00", "12:00-01:00"), ("2020-07-04T08:00:00Z", "2020-07-04T13:00:00Z")),
+    case05_rfc3339: ("2020-07-04T08:00:00Z", ("2020-07-04T10:00:00Z", "2020-07-04T12:00:00Z"), ("2020-07-04T10:00:00Z", "2020-07-04T12:00:00Z")),
+    case06_invalid: ("2020-07-04T08:00:00Z", ("25:00", "12:00"), ("25:00", "2020-07-04T12:00:00Z")),
+    case07_no_epoch: ("invalid", ("10:00", "12:00"), ("10:00", "12:00")),
+    case08_before_epoch: ("2020-07-04T22:00:00Z", ("01:00", "03:00"), ("2020-07-05T01:00:00Z", "2020-07-05T03:00:00Z")),
+}
+
+fn can_normalize_job_times_impl(shift_start: &str, time_window: (&str, &str), expected: (&str, &str)) {
+    let problem = create_test_problem(shift_start, vec![vec![time_window.0.to_string(), time_window.1.to_string()]]);
+
+    let problem = normalize_job_times(problem);
+
+    let times = problem.plan.jobs[0].deliveries.as_ref().unwrap()[0].places[0].times.clone();
+    assert_eq!(times, Some(vec![vec![expected.0.to_string(), expected.1.to_string()]]));
+}
+
+fn create_night_shift(earliest: &str) -> VehicleShift {
+    VehicleShift {
+        start: ShiftStart {
+            earliest: earliest.to_string(),
+            latest: Some("23:00".to_string()),
+            location: (0., 0.).to_loc(),
+        },
+        end: Some(ShiftEnd { earliest: None, latest: "06:00".to_string(), location: (0., 0.).to_loc(), is_open: None }),
+        breaks: Some(vec![
+            VehicleBreak::Optional {
+                time: VehicleOptionalBreakTime::TimeWindow(vec!["23:30".to_string(), "00:30".to_string()]),
+                places: vec![VehicleOptionalBreakPlace { duration: 1800., location: None, tag: None }],
+                policy: None,
+            },
+            VehicleBreak::Required { time: VehicleRequiredBreakTime::ExactTime("03:00".to_string()), duration: 600. },
+        ]),
+        reloads: Some(vec![VehicleReload {
+            location: (0., 0.).to_loc(),
+            duration: 600.,
+            times: Some(vec![vec!["01:00".to_string(), "02:00".to_string()]]),
+            tag: None,
+            resource_id: None,
+            alternatives: None,
+            dock_id: None,
+            capacity: None,
+        }]),
+        maintenance: Some(vec![vec!["04:00".to_string(), "04:30".to_string()]]),
+        ..create_default_vehicle_shift()
+    }
+}
+
+parameterized_test! {can_normalize_shift_times, (shift_start, expected), {
+    can_normalize_shift_times_impl(shift_start, expected);
+}}
+
+can_normalize_shift_times! {
+    case01_night_shift: ("2020-07-04T22:00:00Z", vec![
+        "2020-07-04T23:00:00Z", "2020-07-05T06:00:00Z", "2020-07-04T23:30:00Z", "2020-07-05T00:30:00Z",
+        "2020-07-05T03:00:00Z", "2020-07-05T01:00:00Z", "2020-07-05T02:00:00Z", "2020-07-05T04:00:00Z",
+        "2020-07-05T04:30:00Z",
+    ]),
+    case02_shift_offset: ("2020-07-05T00:00:00+02:00", vec![
+        "2020-07-05T21:00:00Z", "2020-07-05T04:00:00Z", "2020-07-05T21:30:00Z", "2020-07-05T22:30:00Z",
+        "2020-07-05T01:00:00Z", "2020-07-04T23:00:00Z", "2020-07-05T00:00:00Z", "2020-07-05T02:00:00Z",
+        "2020-07-05T02:30:00Z",
+    ]),
+    case03_invalid_start: ("invalid", vec!["23:00", "06:00", "23:30", "00:30", "03:00", "01:00", "02:00", "04:00", "04:30"]),
+}
+
+fn can_normalize_shift_times_impl(shift_start: &str, expected: Vec<&str>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_night_shift(shift_start)],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let problem = normalize_shift_times(problem);
+
+    let shift = &problem.fleet.vehicles[0].shifts[0];
+    let breaks = shift.breaks.as_ref().unwrap();
+    let reload = &shift.reloads.as_ref().unwrap()[0];
+    let times = shift
+        .start
+        .latest
+        .iter()
+        .chain(shift.end.iter().map(|end| &end.latest))
+        .chain(match &breaks[0] {
+            VehicleBreak::Optional { time: VehicleOptionalBreakTime::TimeWindow(tw), .. } => tw.iter(),
+            _ => unreachable!(),
+        })
+        .chain(match &breaks[1] {
+            VehicleBreak::Required { time: VehicleRequiredBreakTime::ExactTime(time), .. } => Some(time),
+            _ => None,
+        })
+        .chain(reload.times.iter().flatten().flatten())
+        .chain(shift.maintenance.iter().flatten().flatten())
+        .map(|time| time.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(times, expected);
+}
+
+parameterized_test! {can_expand_recurring_times, (days, time_window, expected), {
+    can_expand_recurring_times_impl(days, time_window, expected);
+}}
+
+can_expand_recurring_times! {
+    case01_single_day: (&["mon"], ("09:00", "11:00"), vec![("2020-07-06T09:00:00Z", "2020-07-06T11:00:00Z")]),
+    case02_many_days: (&["sat", "tue"], ("09:00", "11:00"), vec![
+        ("2020-07-04T09:00:00Z", "2020-07-04T11:00:00Z"),
+        ("2020-07-07T09:00:00Z", "2020-07-07T11:00:00Z"),
+    ]),
+    case03_midnight: (&["sun"], ("22:00", "02:00"), vec![("2020-07-05T22:00:00Z", "2020-07-06T02:00:00Z")]),
+    case04_clipped_by_horizon: (&["tue"], ("19:00", "23:00"), vec![("2020-07-07T19:00:00Z", "2020-07-07T20:00:00Z")]),
+    case05_outside_horizon: (&["wed"], ("09:00", "11:00"), vec![]),
+    case06_explicit_offset: (&["mon"], ("09:00+02:00", "11:00+02:00"), vec![("2020-07-06T07:00:00Z", "2020-07-06T09:00:00Z")]),
+}
+
+fn can_expand_recurring_times_impl(days: &[&str], time_window: (&str, &str), expected: Vec<(&str, &str)>) {
+    let mut problem = create_test_problem("2020-07-04T08:00:00Z", vec![]);
+    let place = &mut problem.plan.jobs[0].deliveries.as_mut().unwrap()[0].places[0];
+    place.times = None;
+    place.recurring_times = Some(vec![RecurringTimeWindow {
+        days: days.iter().map(|day| day.to_string()).collect(),
+        start: time_window.0.to_string(),
+        end: time_window.1.to_string(),
+    }]);
+    problem.fleet.vehicles[0].shifts[0].end.as_mut().unwrap().latest = "2020-07-07T20:00:00Z".to_string();
+
+    let problem = normalize_job_times(problem);
+
+    let times = problem.plan.jobs[0].deliveries.as_ref().unwrap()[0].places[0].times.clone();
+    let expected = Some(expected)
+        .filter(|expected| !expected.is_empty())
+        .map(|expected| expected.into_iter().map(|(start, end)| vec![start.to_string(), end.to_string()]).collect());
+    assert_eq!(times, expected);
+}
+
+parameterized_test! {can_keep_ambiguous_time_of_day, (time_window, expected), {
+    can_keep_ambiguous_time_of_day_impl(time_window, expected);
+}}
+
+can_keep_ambiguous_time_of_day! {
+    case01_no_offset: (("10:00", "12:00"), ("10:00", "12:00")),
+    case02_explicit_offset: (("10:00+01:00", "12:00+01:00"), ("2020-03-28T09:00:00Z", "2020-03-28T11:00:00Z")),
+}
+
+fn can_keep_ambiguous_time_of_day_impl(time_window: (&str, &str), expected: (&str, &str)) {
+    // NOTE shifts start before and after daylight saving time change
+    let mut problem = create_test_problem(
+        "2020-03-28T08:00:00+01:00",
+        vec![vec![time_window.0.to_string(), time_window.1.to_string()]],
+    );
+    let shift = problem.fleet.vehicles[0].shifts[0].clone();
+    problem.fleet.vehicles[0].shifts.push(VehicleShift {
+        start: ShiftStart { earliest: "2020-03-30T08:00:00+02:00".to_string(), ..shift.start.clone() },
+        ..shift
+    });
+    assert!(has_ambiguous_epoch_offset(&problem));
+
+    let problem = normalize_job_times(problem);
+
+    let times = problem.plan.jobs[0].deliveries.as_ref().unwrap()[0].places[0].times.clone();
+    assert_eq!(times, Some(vec![vec![expected.0.to_string(), expected.1.to_string()]]));
+}
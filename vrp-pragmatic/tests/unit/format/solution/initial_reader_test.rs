@@ -86,6 +86,8 @@ fn can_read_basic_init_solution() {
             distance: 8,
             duration: 14,
             times: Timing { driving: 8, serving: 4, break_time: 2, ..Timing::default() },
+            utilization: None,
+            emissions: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -174,6 +176,8 @@ fn can_read_basic_init_solution() {
                 distance: 8,
                 duration: 14,
                 times: Timing { driving: 8, serving: 4, break_time: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
         }],
         unassigned: create_unassigned_jobs(&["job3"]),
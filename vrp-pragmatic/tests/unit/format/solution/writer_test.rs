@@ -3,10 +3,15 @@ use crate::format::solution::writer::create_tour;
 use crate::format::solution::*;
 use crate::helpers::*;
 use std::cmp::Ordering;
+use std::io::{BufReader, BufWriter};
 use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::common::{TimeSpan, TimeWindow};
 use vrp_core::models::examples::create_example_problem;
-use vrp_core::utils::compare_floats;
+use vrp_core::rosomaxa::evolution::TelemetryMode;
+use vrp_core::solver::search::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::{create_elitism_population, RefinementContext};
+use vrp_core::utils::{compare_floats, Environment};
 
 type DomainProblem = vrp_core::models::Problem;
 type DomainActivity = vrp_core::models::solution::Activity;
@@ -48,6 +53,8 @@ fn can_create_solution() {
                 distance: 20,
                 duration: 22,
                 times: Timing { driving: 20, serving: 2, ..Timing::default() },
+                utilization: None,
+                emissions: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -92,6 +99,8 @@ fn can_create_solution() {
                     distance: 20,
                     duration: 22,
                     times: Timing { driving: 20, serving: 2, ..Timing::default() },
+                    utilization: None,
+                    emissions: None,
                 },
             }],
             ..create_empty_solution()
@@ -120,6 +129,8 @@ fn can_merge_activities_with_same_location_in_one_stop() {
             distance: 10,
             duration: 12,
             times: Timing { driving: 10, serving: 2, ..Timing::default() },
+            utilization: None,
+            emissions: None,
         }
     );
     assert_eq!(solution.tours.len(), 1);
@@ -209,6 +220,31 @@ fn can_merge_activities_with_commute_in_one_stop_impl(
     });
 }
 
+#[test]
+fn can_report_waiting_time_of_clustered_activity_before_commute() {
+    let (problem, mut coord_index) = create_test_problem_and_coord_index();
+    coord_index.add(&Location::Reference { index: 1 });
+    let mut activity = DomainActivity {
+        schedule: DomainSchedule { arrival: 5., departure: 15. },
+        commute: Some(DomainCommute {
+            forward: DomainCommuteInfo { location: 0, distance: 2., duration: 2. },
+            backward: DomainCommuteInfo { location: 0, distance: 2., duration: 2. },
+        }),
+        ..create_activity_with_job_at_location(create_single("job1"), 1)
+    };
+    activity.place.time = TimeWindow::new(10., 100.);
+    let route = create_route_with_activities(&problem.fleet, "v1", vec![activity]);
+
+    let tour = create_tour(&problem, &route, &coord_index, &Default::default());
+
+    // NOTE vehicle waits for time window start at the stop and commutes to the job location after that
+    let activity = tour.stops.iter().flat_map(|stop| stop.activities()).find(|a| a.job_id == "job1").unwrap();
+    assert_eq!(activity.time.as_ref().map(|time| time.start.as_str()), Some("1970-01-01T00:00:07Z"));
+    assert_eq!(activity.time.as_ref().map(|time| time.end.as_str()), Some("1970-01-01T00:00:12Z"));
+    assert_eq!(tour.statistic.times.waiting, 5);
+    assert_eq!(tour.statistic.times.commuting, 4);
+}
+
 #[test]
 fn can_merge_required_break_on_stop_arrival_time_properly() {
     let (problem, mut coord_index) = create_test_problem_and_coord_index();
@@ -227,3 +263,47 @@ fn can_merge_required_break_on_stop_arrival_time_properly() {
     assert_eq!(tour.stops.len(), 3);
     assert_eq!(get_ids_from_tour(&tour).into_iter().flatten().filter(|id| id == "break").count(), 1);
 }
+
+#[test]
+fn can_write_solution_incrementally() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (5., 0.)),
+                create_delivery_job("job2", (10., 0.)),
+                create_delivery_job("job3", (-5., 0.)),
+                create_delivery_job_with_demand("job4", (1., 0.), vec![100]),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                capacity: vec![2],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let environment = Arc::new(Environment::default());
+    let core_problem = Arc::new((problem, vec![matrix]).read_pragmatic().unwrap());
+    let population = create_elitism_population(core_problem.objective.clone(), environment.clone());
+    let refinement_ctx =
+        RefinementContext::new(core_problem.clone(), population, TelemetryMode::None, environment.clone());
+    let core_solution = RecreateWithCheapest::new(environment.random.clone())
+        .run(&refinement_ctx, InsertionContext::new(core_problem.clone(), environment.clone()))
+        .solution
+        .to_solution(core_problem.extras.clone());
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+
+    (&core_solution, 0.).write_pragmatic_json_streaming(&core_problem, writer).expect("cannot write solution");
+
+    let actual = deserialize_solution(BufReader::new(buffer.as_bytes())).expect("cannot read solution");
+    let expected = create_solution(&core_problem, &core_solution, None);
+    assert_eq!(actual.tours.len(), 2);
+    assert_eq!(actual.unassigned.as_ref().map(|jobs| jobs.len()), Some(1));
+    assert_eq!(actual, expected);
+}
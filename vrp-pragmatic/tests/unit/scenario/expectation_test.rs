@@ -0,0 +1,84 @@
+use super::*;
+use crate::format::solution::{Activity, Statistic, Stop};
+use crate::helpers::*;
+
+fn create_stop(job_ids: &[&str]) -> Stop {
+    let mut stop = create_stop_with_activity(job_ids[0], "delivery", (0., 0.), 0, ("", ""), 0);
+    if let Stop::Point(point) = &mut stop {
+        let activity = point.activities[0].clone();
+        point
+            .activities
+            .extend(job_ids.iter().skip(1).map(|job_id| Activity { job_id: job_id.to_string(), ..activity.clone() }));
+    }
+
+    stop
+}
+
+fn create_tour(vehicle_id: &str, stops: &[&[&str]]) -> Tour {
+    Tour {
+        vehicle_id: vehicle_id.to_string(),
+        stops: stops.iter().map(|job_ids| create_stop(job_ids)).collect(),
+        ..create_empty_tour()
+    }
+}
+
+parameterized_test! {can_match_route_pattern, (pattern, expected), {
+    can_match_route_pattern_impl(pattern, expected);
+}}
+
+can_match_route_pattern! {
+    case01_exact: (RoutePattern::new(&["departure", "job1", "job2,job3", "arrival"]), true),
+    case02_wrong_order: (RoutePattern::new(&["departure", "job2,job3", "job1", "arrival"]), false),
+    case03_any_stop: (RoutePattern::new(&["departure", "*", "job3", "arrival"]), true),
+    case04_any_stops: (RoutePattern::new(&["departure", "..", "arrival"]), true),
+    case05_any_stops_empty: (RoutePattern::new(&["departure", "job1", "..", "job2", "arrival"]), true),
+    case06_too_short: (RoutePattern::new(&["departure", "job1", "arrival"]), false),
+    case07_any_stops_only: (RoutePattern::new(&[".."]), true),
+    case08_vehicle: (RoutePattern::new(&[".."]).with_vehicle("v1"), true),
+    case09_wrong_vehicle: (RoutePattern::new(&[".."]).with_vehicle("v2"), false),
+}
+
+fn can_match_route_pattern_impl(pattern: RoutePattern, expected: bool) {
+    let tour = create_tour("v1", &[&["departure"], &["job1"], &["job2", "job3"], &["arrival"]]);
+
+    assert_eq!(pattern.matches(&tour), expected);
+}
+
+#[test]
+fn can_match_tours_to_distinct_patterns() {
+    let solution = Solution {
+        tours: vec![create_tour("v1", &[&["job1"], &["job2"]]), create_tour("v2", &[&["job3"]])],
+        ..create_empty_solution()
+    };
+
+    assert!(check_routes(&solution, &[RoutePattern::new(&["job3"]), RoutePattern::new(&[".."])]).is_ok());
+    assert!(check_routes(&solution, &[RoutePattern::new(&[".."]), RoutePattern::new(&[".."])]).is_ok());
+    assert!(check_routes(&solution, &[RoutePattern::new(&["job3"]), RoutePattern::new(&["job3"])]).is_err());
+    assert!(check_routes(&solution, &[RoutePattern::new(&[".."])]).is_err());
+}
+
+parameterized_test! {can_check_statistic_with_tolerance, (metric, expected, tolerance, is_ok), {
+    can_check_statistic_with_tolerance_impl(metric, expected, tolerance, is_ok);
+}}
+
+can_check_statistic_with_tolerance! {
+    case01_exact_cost: (Metric::Cost, 100., Tolerance::Absolute(0.), true),
+    case02_absolute_within: (Metric::Distance, 45., Tolerance::Absolute(5.), true),
+    case03_absolute_outside: (Metric::Distance, 44., Tolerance::Absolute(5.), false),
+    case04_relative_within: (Metric::Cost, 95., Tolerance::Relative(0.1), true),
+    case05_relative_outside: (Metric::Cost, 80., Tolerance::Relative(0.1), false),
+    case06_tours: (Metric::Tours, 1., Tolerance::Absolute(0.), true),
+    case07_unassigned: (Metric::Unassigned, 0., Tolerance::Absolute(0.), true),
+}
+
+fn can_check_statistic_with_tolerance_impl(metric: Metric, expected: f64, tolerance: Tolerance, is_ok: bool) {
+    let solution = Solution {
+        statistic: Statistic { cost: 100., distance: 50, ..Statistic::default() },
+        tours: vec![create_empty_tour()],
+        ..create_empty_solution()
+    };
+
+    let result = check_statistic(&solution, &metric, expected, &tolerance);
+
+    assert_eq!(result.is_ok(), is_ok);
+}
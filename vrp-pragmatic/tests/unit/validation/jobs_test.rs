@@ -60,6 +60,55 @@ fn can_detect_negative_duration() {
     assert_result("E1106", "job1", result);
 }
 
+#[test]
+fn can_detect_negative_post_service_buffer() {
+    let mut job = create_delivery_job("job1", (1., 0.));
+    job.deliveries.as_mut().unwrap()[0].places[0].post_service_buffer = Some(-1.);
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1106_negative_duration(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_result("E1106", "job1", result);
+}
+
+#[test]
+fn can_detect_negative_lateness_penalty() {
+    let mut job = create_delivery_job("job1", (1., 0.));
+    job.deliveries.as_mut().unwrap()[0].places[0].lateness_penalty = Some(-1.);
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1109_negative_lateness_penalty(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    assert_result("E1109", "job1", result);
+}
+
+#[test]
+fn can_detect_negative_preferred_skill_penalty() {
+    let job = Job {
+        skills: Some(JobSkills {
+            all_of: None,
+            one_of: None,
+            none_of: None,
+            preferred: Some(vec![PreferredSkill { skill: "s1".to_string(), penalty: -1. }]),
+            counts: None,
+        }),
+        ..create_delivery_job("job1", (1., 0.))
+    };
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result = check_e1110_negative_preferred_skill_penalty(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ))
+    .err();
+
+    assert_result("E1110", "job1", result);
+}
+
 #[test]
 fn can_detect_negative_demand() {
     let problem = Problem {
@@ -74,3 +123,619 @@ fn can_detect_negative_demand() {
 
     assert_result("E1107", "job1", result);
 }
+
+parameterized_test! {can_detect_invalid_visit_gap, (tasks, min, max, expected), {
+    can_detect_invalid_visit_gap_impl(tasks, min, max, expected);
+}}
+
+can_detect_invalid_visit_gap! {
+    case01: (2, Some(10.), Some(20.), None),
+    case02: (2, Some(10.), None, None),
+    case03: (1, Some(10.), None, Some(())),
+    case04: (2, Some(20.), Some(10.), Some(())),
+    case05: (2, Some(-1.), None, Some(())),
+    case06: (2, None, None, Some(())),
+}
+
+fn can_detect_invalid_visit_gap_impl(tasks: usize, min: Option<f64>, max: Option<f64>, expected: Option<()>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                services: Some((0..tasks).map(|_| JobTask { demand: None, ..create_task((1., 0.), None) }).collect()),
+                visit_gap: Some(JobVisitGap { min, max }),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1108_visit_gap(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if expected.is_some() {
+        assert_result("E1108", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_max_ride_duration, (is_pickup_delivery, max_ride_duration, expected), {
+    can_detect_invalid_max_ride_duration_impl(is_pickup_delivery, max_ride_duration, expected);
+}}
+
+can_detect_invalid_max_ride_duration! {
+    case01: (true, 10., None),
+    case02: (true, 0., None),
+    case03: (true, -1., Some(())),
+    case04: (false, 10., Some(())),
+}
+
+fn can_detect_invalid_max_ride_duration_impl(is_pickup_delivery: bool, max_ride_duration: f64, expected: Option<()>) {
+    let job = if is_pickup_delivery {
+        create_pickup_delivery_job("job1", (1., 0.), (2., 0.))
+    } else {
+        create_delivery_job("job1", (1., 0.))
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![Job { max_ride_duration: Some(max_ride_duration), ..job }], ..create_empty_plan() },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1111_max_ride_duration(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if expected.is_some() {
+        assert_result("E1111", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_service_blackout, (time, areas, expected), {
+    can_detect_invalid_service_blackout_impl(time, areas, expected);
+}}
+
+can_detect_invalid_service_blackout! {
+    case01_valid: (("1970-01-01T00:00:10Z", "1970-01-01T00:00:20Z"), None, None),
+    case02_valid_area: (("1970-01-01T00:00:10Z", "1970-01-01T00:00:20Z"), Some(vec!["area1"]), None),
+    case03_invalid_time: (("1970-01-01T00:00:20Z", "1970-01-01T00:00:10Z"), None, Some("E1112")),
+    case04_unknown_area: (("1970-01-01T00:00:10Z", "1970-01-01T00:00:20Z"), Some(vec!["area2"]), Some("E1112")),
+}
+
+fn can_detect_invalid_service_blackout_impl(time: (&str, &str), areas: Option<Vec<&str>>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.))],
+            areas: Some(vec![Area { id: "area1".to_string(), jobs: vec!["job1".to_string()], polygon: None }]),
+            blackouts: Some(vec![ServiceBlackout {
+                time: vec![time.0.to_string(), time.1.to_string()],
+                areas: areas.map(|areas| areas.into_iter().map(|area| area.to_string()).collect()),
+            }]),
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1112_service_blackouts(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_job_sync, (keys, offsets, is_pickup_delivery, expected), {
+    can_detect_invalid_job_sync_impl(keys, offsets, is_pickup_delivery, expected);
+}}
+
+can_detect_invalid_job_sync! {
+    case01_valid: (("key1", "key1"), (None, Some(0.)), false, None),
+    case02_single_job_key: (("key1", "key2"), (None, None), false, Some("E1113")),
+    case03_negative_offset: (("key1", "key1"), (Some(-1.), Some(-1.)), false, Some("E1113")),
+    case04_different_offsets: (("key1", "key1"), (Some(1.), Some(2.)), false, Some("E1113")),
+    case05_multiple_tasks: (("key1", "key1"), (None, None), true, Some("E1113")),
+}
+
+fn can_detect_invalid_job_sync_impl(
+    keys: (&str, &str),
+    offsets: (Option<f64>, Option<f64>),
+    is_pickup_delivery: bool,
+    expected: Option<&str>,
+) {
+    let job1 = if is_pickup_delivery {
+        create_pickup_delivery_job("job1", (1., 0.), (2., 0.))
+    } else {
+        create_service_job("job1", (1., 0.))
+    };
+    let job2 = create_service_job("job2", (2., 0.));
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { sync: Some(JobSync { key: keys.0.to_string(), offset: offsets.0 }), ..job1 },
+                Job { sync: Some(JobSync { key: keys.1.to_string(), offset: offsets.1 }), ..job2 },
+            ],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1113_job_sync(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_job_predecessors, (predecessors, is_pickup_delivery, expected), {
+    can_detect_invalid_job_predecessors_impl(predecessors, is_pickup_delivery, expected);
+}}
+
+can_detect_invalid_job_predecessors! {
+    case01_valid: ((None, Some(vec!["job1"])), false, None),
+    case02_unknown_job: ((None, Some(vec!["job3"])), false, Some("E1114")),
+    case03_self_reference: ((None, Some(vec!["job2"])), false, Some("E1114")),
+    case04_cycle: ((Some(vec!["job2"]), Some(vec!["job1"])), false, Some("E1114")),
+    case05_multiple_tasks: ((None, Some(vec!["job1"])), true, Some("E1114")),
+}
+
+fn can_detect_invalid_job_predecessors_impl(
+    predecessors: (Option<Vec<&str>>, Option<Vec<&str>>),
+    is_pickup_delivery: bool,
+    expected: Option<&str>,
+) {
+    let to_ids = |ids: Option<Vec<&str>>| ids.map(|ids| ids.into_iter().map(|id| id.to_string()).collect());
+    let job1 = if is_pickup_delivery {
+        create_pickup_delivery_job("job1", (1., 0.), (2., 0.))
+    } else {
+        create_service_job("job1", (1., 0.))
+    };
+    let job2 = create_service_job("job2", (2., 0.));
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { predecessors: to_ids(predecessors.0), ..job1 },
+                Job { predecessors: to_ids(predecessors.1), ..job2 },
+            ],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1114_job_predecessors(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_territory, (territories, expected), {
+    can_detect_invalid_territory_impl(territories, expected);
+}}
+
+can_detect_invalid_territory! {
+    case01_valid: (vec![("t1", vec!["area1"], vec!["my_vehicle_1"]), ("t2", vec!["area2"], vec![])], None),
+    case02_duplicate_id: (vec![("t1", vec!["area1"], vec![]), ("t1", vec!["area2"], vec![])], Some("E1115")),
+    case03_unknown_area: (vec![("t1", vec!["area3"], vec![])], Some("E1115")),
+    case04_unknown_vehicle: (vec![("t1", vec!["area1"], vec!["my_vehicle_2"])], Some("E1115")),
+    case05_shared_jobs: (vec![("t1", vec!["area1"], vec![]), ("t2", vec!["area1"], vec![])], Some("E1115")),
+    case06_same_area_twice: (vec![("t1", vec!["area1", "area1"], vec![])], None),
+}
+
+fn can_detect_invalid_territory_impl(territories: Vec<(&str, Vec<&str>, Vec<&str>)>, expected: Option<&str>) {
+    let to_strings = |values: Vec<&str>| values.into_iter().map(|value| value.to_string()).collect::<Vec<_>>();
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            areas: Some(vec![
+                Area { id: "area1".to_string(), jobs: vec!["job1".to_string()], polygon: None },
+                Area { id: "area2".to_string(), jobs: vec!["job2".to_string()], polygon: None },
+            ]),
+            territories: Some(
+                territories
+                    .into_iter()
+                    .map(|(id, areas, vehicles)| Territory {
+                        id: id.to_string(),
+                        areas: to_strings(areas),
+                        vehicles: to_strings(vehicles),
+                        is_strict: None,
+                        cross_zone_penalty: None,
+                    })
+                    .collect(),
+            ),
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1115_territories(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_territory_penalty, (is_strict, penalty, expected), {
+    can_detect_invalid_territory_penalty_impl(is_strict, penalty, expected);
+}}
+
+can_detect_invalid_territory_penalty! {
+    case01_valid: (None, Some(100.), None),
+    case02_negative: (None, Some(-1.), Some("E1115")),
+    case03_strict: (Some(true), Some(100.), Some("E1115")),
+    case04_strict_no_penalty: (Some(true), None, None),
+}
+
+fn can_detect_invalid_territory_penalty_impl(is_strict: Option<bool>, penalty: Option<f64>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.))],
+            areas: Some(vec![Area { id: "area1".to_string(), jobs: vec!["job1".to_string()], polygon: None }]),
+            territories: Some(vec![Territory {
+                id: "t1".to_string(),
+                areas: vec!["area1".to_string()],
+                vehicles: vec!["my_vehicle_1".to_string()],
+                is_strict,
+                cross_zone_penalty: penalty,
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1115_territories(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_job_approach, (duration, distance, expected), {
+    can_detect_invalid_job_approach_impl(duration, distance, expected);
+}}
+
+can_detect_invalid_job_approach! {
+    case01_valid: (60., Some(100.), None),
+    case02_valid_without_distance: (60., None, None),
+    case03_negative_duration: (-1., None, Some("E1116")),
+    case04_negative_distance: (60., Some(-1.), Some("E1116")),
+}
+
+fn can_detect_invalid_job_approach_impl(duration: f64, distance: Option<f64>, expected: Option<&str>) {
+    let job = Job { approach: Some(JobApproach { duration, distance }), ..create_delivery_job("job1", (1., 0.)) };
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result = check_e1116_job_approach(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_job_skill_counts, (counts, expected), {
+    can_detect_invalid_job_skill_counts_impl(counts, expected);
+}}
+
+can_detect_invalid_job_skill_counts! {
+    case01_valid: (vec![("s1", 2), ("s2", 1)], None),
+    case02_zero_count: (vec![("s1", 0)], Some("E1117")),
+    case03_duplicate_skill: (vec![("s1", 1), ("s1", 2)], Some("E1117")),
+}
+
+fn can_detect_invalid_job_skill_counts_impl(counts: Vec<(&str, usize)>, expected: Option<&str>) {
+    let job = Job {
+        skills: Some(JobSkills {
+            all_of: None,
+            one_of: None,
+            none_of: None,
+            preferred: None,
+            counts: Some(
+                counts.into_iter().map(|(skill, count)| SkillCount { skill: skill.to_string(), count }).collect(),
+            ),
+        }),
+        ..create_delivery_job("job1", (1., 0.))
+    };
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1117_job_skill_counts(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_job_escalation, (weight, expected), {
+    can_detect_invalid_job_escalation_impl(weight, expected);
+}}
+
+can_detect_invalid_job_escalation! {
+    case01_default: (None, None),
+    case02_positive: (Some(2.), None),
+    case03_negative: (Some(-1.), Some("E1118")),
+}
+
+fn can_detect_invalid_job_escalation_impl(weight: Option<f64>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { attempts: Some(1), ..create_delivery_job("job1", (1., 0.)) }],
+            escalation: Some(JobEscalation { weight, relax_after: None }),
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1118_job_escalation(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_area_polygon, (polygon, expected), {
+    can_detect_invalid_area_polygon_impl(polygon, expected);
+}}
+
+can_detect_invalid_area_polygon! {
+    case01_no_polygon: (None, None),
+    case02_triangle: (Some(vec![(0., 0.), (1., 0.), (0., 1.)]), None),
+    case03_two_vertices: (Some(vec![(0., 0.), (1., 0.)]), Some("E1119")),
+}
+
+fn can_detect_invalid_area_polygon_impl(polygon: Option<Vec<(f64, f64)>>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.))],
+            areas: Some(vec![Area {
+                id: "area1".to_string(),
+                jobs: vec![],
+                polygon: polygon.map(|polygon| polygon.into_iter().map(|point| point.to_loc()).collect()),
+            }]),
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1119_area_polygons(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+#[test]
+fn can_detect_area_polygon_with_location_index() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.))],
+            areas: Some(vec![Area {
+                id: "area1".to_string(),
+                jobs: vec![],
+                polygon: Some(vec![(0., 0.).to_loc(), (1., 0.).to_loc(), Location::Reference { index: 0 }]),
+            }]),
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1119_area_polygons(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), Some("E1119".to_string()));
+}
+
+parameterized_test! {can_detect_duplicated_job_place_tags, (tags, expected), {
+    can_detect_duplicated_job_place_tags_impl(tags, expected);
+}}
+
+can_detect_duplicated_job_place_tags! {
+    case01_unique: (vec![Some("home"), Some("locker")], None),
+    case02_without_tags: (vec![None, None], None),
+    case03_duplicated: (vec![Some("home"), Some("home")], Some("E1120")),
+}
+
+fn can_detect_duplicated_job_place_tags_impl(tags: Vec<Option<&str>>, expected: Option<&str>) {
+    let places = tags.into_iter().map(|tag| create_job_place((1., 0.), tag.map(|tag| tag.to_string()))).collect();
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask { places, demand: Some(vec![1]), order: None, named_demand: None }]),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1120_job_place_tags(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_zone_sequence, (areas, penalty, expected), {
+    can_detect_invalid_zone_sequence_impl(areas, penalty, expected);
+}}
+
+can_detect_invalid_zone_sequence! {
+    case01_valid: (vec!["area1", "area2"], 10., None),
+    case02_single_area: (vec!["area1"], 10., Some("E1121")),
+    case03_duplicated_area: (vec!["area1", "area1"], 10., Some("E1121")),
+    case04_unknown_area: (vec!["area1", "area3"], 10., Some("E1121")),
+    case05_negative_penalty: (vec!["area1", "area2"], -1., Some("E1121")),
+}
+
+fn can_detect_invalid_zone_sequence_impl(areas: Vec<&str>, penalty: f64, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            areas: Some(vec![
+                Area { id: "area1".to_string(), jobs: vec!["job1".to_string()], polygon: None },
+                Area { id: "area2".to_string(), jobs: vec!["job2".to_string()], polygon: None },
+            ]),
+            zone_sequences: Some(vec![ZoneSequence {
+                areas: areas.into_iter().map(|area| area.to_string()).collect(),
+                penalty,
+            }]),
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1121_zone_sequences(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_recurring_time_windows, (days, start, end, expected), {
+    can_detect_invalid_recurring_time_windows_impl(days, start, end, expected);
+}}
+
+can_detect_invalid_recurring_time_windows! {
+    case01_valid: (vec!["thu"], "00:00", "00:10", None),
+    case02_unknown_day: (vec!["thursday"], "00:00", "00:10", Some("E1122")),
+    case03_no_days: (vec![], "00:00", "00:10", Some("E1122")),
+    case04_invalid_time: (vec!["thu"], "24:00", "00:10", Some("E1122")),
+    case05_outside_horizon: (vec!["mon"], "00:00", "00:10", Some("E1122")),
+}
+
+fn can_detect_invalid_recurring_time_windows_impl(days: Vec<&str>, start: &str, end: &str, expected: Option<&str>) {
+    let mut job = create_delivery_job("job1", (1., 0.));
+    job.deliveries.as_mut().unwrap()[0].places[0].recurring_times = Some(vec![RecurringTimeWindow {
+        days: days.into_iter().map(|day| day.to_string()).collect(),
+        start: start.to_string(),
+        end: end.to_string(),
+    }]);
+    // NOTE default vehicle shift starts on thursday, 1970-01-01T00:00:00Z, and lasts 1000 seconds
+    let problem = normalize_times(Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], ..create_default_fleet() },
+        ..create_empty_problem()
+    });
+
+    let result =
+        check_e1122_recurring_time_windows(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_job_item, (weight, height, expected), {
+    can_detect_invalid_job_item_impl(weight, height, expected);
+}}
+
+can_detect_invalid_job_item! {
+    case01_valid: (Some(10.), Some(2.), None),
+    case02_partial: (None, Some(2.), None),
+    case03_zero_weight: (Some(0.), None, Some(())),
+    case04_negative_height: (Some(10.), Some(-2.), Some(())),
+}
+
+fn can_detect_invalid_job_item_impl(weight: Option<f64>, height: Option<f64>, expected: Option<()>) {
+    let job = create_delivery_job("job1", (1., 0.));
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { item: Some(JobItem { weight, length: None, height }), ..job }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1123_job_item(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if expected.is_some() {
+        assert_result("E1123", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_named_demand, (dimension, value, expected), {
+    can_detect_invalid_named_demand_impl(dimension, value, expected);
+}}
+
+can_detect_invalid_named_demand! {
+    case01_valid: ("weight", 1, None),
+    case02_unknown_dimension: ("volume", 1, Some(())),
+    case03_negative_value: ("weight", -1, Some(())),
+}
+
+fn can_detect_invalid_named_demand_impl(dimension: &str, value: i32, expected: Option<()>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    named_demand: Some(vec![NamedLoad { dimension: dimension.to_string(), value }]),
+                    ..create_task((1., 0.), None)
+                }]),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            dimensions: Some(vec![LoadDimension { name: "weight".to_string(), is_reloadable: None }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1124_job_named_demand(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if expected.is_some() {
+        assert_result("E1124", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_unassigned_penalty, (penalty, expected), {
+    can_detect_invalid_unassigned_penalty_impl(penalty, expected);
+}}
+
+can_detect_invalid_unassigned_penalty! {
+    case01_valid: (Some(10.), None),
+    case02_zero: (Some(0.), None),
+    case03_not_set: (None, None),
+    case04_negative: (Some(-1.), Some(())),
+}
+
+fn can_detect_invalid_unassigned_penalty_impl(penalty: Option<f64>, expected: Option<()>) {
+    let job = create_delivery_job("job1", (1., 0.));
+    let problem = Problem {
+        plan: Plan { jobs: vec![Job { unassigned_penalty: penalty, ..job }], ..create_empty_plan() },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1125_job_unassigned_penalty(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if expected.is_some() {
+        assert_result("E1125", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_ambiguous_time_of_day, (shift_starts, time_window, expected), {
+    can_detect_ambiguous_time_of_day_impl(shift_starts, time_window, expected);
+}}
+
+can_detect_ambiguous_time_of_day! {
+    case01_same_offsets: (vec!["2020-03-28T08:00:00+01:00", "2020-03-29T08:00:00+01:00"], ("10:00", "12:00"), None),
+    case02_different_offsets: (vec!["2020-03-28T08:00:00+01:00", "2020-03-30T08:00:00+02:00"], ("10:00", "12:00"), Some(())),
+    case03_explicit_offset: (vec!["2020-03-28T08:00:00+01:00", "2020-03-30T08:00:00+02:00"], ("10:00+01:00", "12:00+01:00"), None),
+    case04_rfc3339: (vec!["2020-03-28T08:00:00+01:00", "2020-03-30T08:00:00+02:00"], ("2020-03-28T10:00:00Z", "2020-03-28T12:00:00Z"), None),
+}
+
+fn can_detect_ambiguous_time_of_day_impl(shift_starts: Vec<&str>, time_window: (&str, &str), expected: Option<()>) {
+    let mut job = create_delivery_job("job1", (1., 0.));
+    job.deliveries.as_mut().unwrap()[0].places[0].times =
+        Some(vec![vec![time_window.0.to_string(), time_window.1.to_string()]]);
+    let shift = create_default_vehicle_shift();
+    let shifts = shift_starts
+        .into_iter()
+        .map(|earliest| VehicleShift {
+            start: ShiftStart { earliest: earliest.to_string(), ..shift.start.clone() },
+            ..shift.clone()
+        })
+        .collect();
+    let problem = normalize_times(Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { shifts, ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    });
+
+    let result =
+        check_e1126_ambiguous_time_of_day(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if expected.is_some() {
+        assert_result("E1126", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
@@ -109,12 +109,31 @@ fn can_detect_missing_value_jobs() {
     assert_eq!(result.err().unwrap().code, "E1603".to_string());
 }
 
+#[test]
+fn can_detect_missing_value_jobs_for_balance() {
+    let problem = Problem {
+        objectives: Some(vec![
+            vec![MinimizeUnassignedJobs { breaks: None }],
+            vec![BalanceValue { options: None }],
+            vec![MinimizeCost],
+        ]),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1613_no_jobs_with_value_balance_objective(&ctx, &objectives);
+
+    assert_eq!(result.err().unwrap().code, "E1613".to_string());
+}
+
 #[test]
 fn can_detect_missing_order_jobs() {
     let problem = Problem {
         objectives: Some(vec![
             vec![MinimizeUnassignedJobs { breaks: None }],
-            vec![TourOrder { is_constrained: false }],
+            vec![TourOrder { is_constrained: false, is_graded: None }],
             vec![MinimizeCost],
         ]),
         ..create_empty_problem()
@@ -170,7 +189,7 @@ can_detect_missing_order_objective! {
             ]), Some("E1606".to_string())),
     case02: (Some(vec![
                 vec![MinimizeUnassignedJobs { breaks: None }],
-                vec![TourOrder { is_constrained: true }],
+                vec![TourOrder { is_constrained: true, is_graded: None }],
                 vec![MinimizeCost],
             ]), None),
     case03: (None, None),
@@ -212,6 +231,11 @@ can_detect_missing_value_objective! {
                 vec![MinimizeCost],
             ]), None),
     case03: (None, None),
+    case04: (Some(vec![
+                vec![MinimizeUnassignedJobs { breaks: None }],
+                vec![BalanceValue { options: None }],
+                vec![MinimizeCost],
+            ]), None),
 }
 
 fn can_detect_missing_value_objective_impl(objectives: Option<Vec<Vec<Objective>>>, expected: Option<String>) {
@@ -239,7 +263,10 @@ fn can_detect_missing_value_objective_impl(objectives: Option<Vec<Vec<Objective>
 #[test]
 fn can_detect_missing_area_objective() {
     let problem = Problem {
-        plan: Plan { areas: Some(vec![Area { id: "area1".to_string(), jobs: vec![] }]), ..create_empty_plan() },
+        plan: Plan {
+            areas: Some(vec![Area { id: "area1".to_string(), jobs: vec![], polygon: None }]),
+            ..create_empty_plan()
+        },
         objectives: Some(vec![vec![MinimizeUnassignedJobs { breaks: None }], vec![MinimizeCost]]),
         ..create_empty_problem()
     };
@@ -251,3 +278,131 @@ fn can_detect_missing_area_objective() {
 
     assert_eq!(result.err().unwrap().code, "E1608".to_string());
 }
+
+#[test]
+fn can_skip_missing_area_objective_for_blackout_areas() {
+    let problem = Problem {
+        plan: Plan {
+            areas: Some(vec![Area { id: "area1".to_string(), jobs: vec![], polygon: None }]),
+            blackouts: Some(vec![ServiceBlackout {
+                time: vec!["1970-01-01T00:00:10Z".to_string(), "1970-01-01T00:00:20Z".to_string()],
+                areas: Some(vec!["area1".to_string()]),
+            }]),
+            ..create_empty_plan()
+        },
+        objectives: Some(vec![vec![MinimizeUnassignedJobs { breaks: None }], vec![MinimizeCost]]),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1608_areas_but_no_objective(&ctx, &objectives);
+
+    assert!(result.is_ok());
+}
+
+parameterized_test! {can_detect_non_positive_cost_limit, (max_cost, expected), {
+    can_detect_non_positive_cost_limit_impl(max_cost, expected);
+}}
+
+can_detect_non_positive_cost_limit! {
+    case01: (100., None),
+    case02: (0., Some("E1609".to_string())),
+    case03: (-1., Some("E1609".to_string())),
+}
+
+fn can_detect_non_positive_cost_limit_impl(max_cost: f64, expected: Option<String>) {
+    let problem = Problem {
+        objectives: Some(vec![vec![LimitTotalCost { max_cost }], vec![MinimizeCost]]),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1609_non_positive_cost_limit(&objectives);
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_waiting_time_percentile, (percentile, expected), {
+    can_detect_invalid_waiting_time_percentile_impl(percentile, expected);
+}}
+
+can_detect_invalid_waiting_time_percentile! {
+    case01_none: (None, None),
+    case02_valid: (Some(0.95), None),
+    case03_max: (Some(1.), None),
+    case04_zero: (Some(0.), Some("E1610".to_string())),
+    case05_too_big: (Some(95.), Some("E1610".to_string())),
+}
+
+fn can_detect_invalid_waiting_time_percentile_impl(percentile: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        objectives: Some(vec![vec![MinimizeMaxWaitingTime { percentile }], vec![MinimizeCost]]),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1610_invalid_waiting_time_percentile(&objectives);
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
+
+parameterized_test! {can_detect_emissions_objective_without_vehicle_emissions, (has_emissions, expected), {
+    can_detect_emissions_objective_without_vehicle_emissions_impl(has_emissions, expected);
+}}
+
+can_detect_emissions_objective_without_vehicle_emissions! {
+    case01_with_emissions: (true, None),
+    case02_without_emissions: (false, Some("E1611".to_string())),
+}
+
+fn can_detect_emissions_objective_without_vehicle_emissions_impl(has_emissions: bool, expected: Option<String>) {
+    let emissions = VehicleEmissions { class: EmissionClass::Petrol, consumption: 1., load_consumption: None };
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                emissions: if has_emissions { Some(emissions) } else { None },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(vec![vec![MinimizeEmissions], vec![MinimizeCost]]),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1611_no_vehicles_with_emissions_objective(&ctx, &objectives);
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
+
+parameterized_test! {can_detect_overlap_objective_without_coordinates, (job, expected), {
+    can_detect_overlap_objective_without_coordinates_impl(job, expected);
+}}
+
+can_detect_overlap_objective_without_coordinates! {
+    case01_coordinates: (create_delivery_job("job1", (1., 0.)), None),
+    case02_indices: (create_delivery_job_with_index("job1", 0), Some("E1612".to_string())),
+}
+
+fn can_detect_overlap_objective_without_coordinates_impl(job: Job, expected: Option<String>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        objectives: Some(vec![vec![MinimizeOverlap], vec![MinimizeCost]]),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1612_overlap_objective_without_coordinates(&ctx, &objectives);
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
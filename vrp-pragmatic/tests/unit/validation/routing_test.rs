@@ -6,8 +6,8 @@ fn can_detect_duplicates() {
     let problem = Problem {
         fleet: Fleet {
             profiles: vec![
-                MatrixProfile { name: "my_vehicle".to_string(), speed: None },
-                MatrixProfile { name: "my_vehicle".to_string(), speed: None },
+                MatrixProfile { name: "my_vehicle".to_string(), speed: None, infer: None, metric: None },
+                MatrixProfile { name: "my_vehicle".to_string(), speed: None, infer: None, metric: None },
             ],
             ..create_default_fleet()
         },
@@ -100,7 +100,7 @@ fn can_detect_missing_profile() {
                 VehicleType { profile: create_vehicle_profile_with_name("car"), ..create_default_vehicle_type() },
                 VehicleType { profile: create_vehicle_profile_with_name("truck"), ..create_default_vehicle_type() },
             ],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, infer: None, metric: None }],
             ..create_default_fleet()
         },
         ..create_empty_problem()
@@ -112,3 +112,150 @@ fn can_detect_missing_profile() {
 
     assert_eq!(result.err().map(|err| err.code), Some("E1505".to_string()));
 }
+
+fn create_matrix(profile: Option<&str>, size: usize, distances_size: usize) -> Matrix {
+    Matrix {
+        profile: profile.map(|profile| profile.to_string()),
+        timestamp: None,
+        travel_times: vec![1; size],
+        distances: vec![1; distances_size],
+        error_codes: None,
+    }
+}
+
+fn create_profile(name: &str, infer: Option<(&str, Option<f64>)>) -> MatrixProfile {
+    MatrixProfile {
+        name: name.to_string(),
+        speed: None,
+        infer: infer.map(|(profile, duration_factor)| ProfileInference {
+            profile: profile.to_string(),
+            duration_factor,
+            distance_factor: None,
+        }),
+        metric: None,
+    }
+}
+
+parameterized_test! {can_detect_invalid_matrix_dimensions, (sizes, expected), {
+    can_detect_invalid_matrix_dimensions_impl(sizes, expected);
+}}
+
+can_detect_invalid_matrix_dimensions! {
+    case01_valid: (vec![(4, 4), (4, 4)], None),
+    case02_not_square: (vec![(3, 3)], Some("E1506")),
+    case03_different_distances: (vec![(4, 9)], Some("E1506")),
+    case04_different_sizes: (vec![(4, 4), (9, 9)], Some("E1506")),
+}
+
+fn can_detect_invalid_matrix_dimensions_impl(sizes: Vec<(usize, usize)>, expected: Option<&str>) {
+    let problem = create_empty_problem();
+    let matrices = sizes.into_iter().map(|(size, distances_size)| create_matrix(None, size, distances_size)).collect();
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1506_matrix_dimensions(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_profile_inference, (infer, matrix_profile, expected), {
+    can_detect_invalid_profile_inference_impl(infer, matrix_profile, expected);
+}}
+
+can_detect_invalid_profile_inference! {
+    case01_valid: (("car", Some(2.)), Some("car"), None),
+    case02_unknown_base: (("truck", None), Some("car"), Some("E1507")),
+    case03_self_reference: (("bike", None), Some("car"), Some("E1507")),
+    case04_negative_factor: (("car", Some(-1.)), Some("car"), Some("E1507")),
+    case05_unnamed_matrices: (("car", None), None, Some("E1507")),
+}
+
+fn can_detect_invalid_profile_inference_impl(
+    infer: (&str, Option<f64>),
+    matrix_profile: Option<&str>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            profiles: vec![create_profile("car", None), create_profile("bike", Some(infer))],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrices = vec![create_matrix(matrix_profile, 4, 4)];
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1507_profile_inference(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_missing_profile_matrices, (has_inference, matrix_profiles, expected), {
+    can_detect_missing_profile_matrices_impl(has_inference, matrix_profiles, expected);
+}}
+
+can_detect_missing_profile_matrices! {
+    case01_all_defined: (false, vec![Some("car"), Some("bike")], None),
+    case02_inferred: (true, vec![Some("car")], None),
+    case03_missing: (false, vec![Some("car")], Some("E1508")),
+    case04_unknown_matrix: (true, vec![Some("car"), Some("truck")], Some("E1508")),
+    case05_unnamed_enough: (false, vec![None, None], None),
+    case06_unnamed_missing: (false, vec![None], Some("E1508")),
+}
+
+fn can_detect_missing_profile_matrices_impl(
+    has_inference: bool,
+    matrix_profiles: Vec<Option<&str>>,
+    expected: Option<&str>,
+) {
+    let infer = if has_inference { Some(("car", None)) } else { None };
+    let problem = Problem {
+        fleet: Fleet {
+            profiles: vec![create_profile("car", None), create_profile("bike", infer)],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrices = matrix_profiles.into_iter().map(|profile| create_matrix(profile, 4, 4)).collect();
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1508_missing_profile_matrices(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_metric_policy, (distance_factor, duration_factor, expected), {
+    can_detect_invalid_metric_policy_impl(distance_factor, duration_factor, expected);
+}}
+
+can_detect_invalid_metric_policy! {
+    case01_valid: (Some(0.001), None, None),
+    case02_zero_distance_factor: (Some(0.), None, Some("E1509")),
+    case03_negative_duration_factor: (None, Some(-1.), Some("E1509")),
+    case04_infinite_factor: (Some(f64::INFINITY), None, Some("E1509")),
+}
+
+fn can_detect_invalid_metric_policy_impl(
+    distance_factor: Option<f64>,
+    duration_factor: Option<f64>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            profiles: vec![MatrixProfile {
+                metric: Some(MetricPolicy { distance_factor, duration_factor, rounding: None }),
+                ..create_profile("car", None)
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let result = check_e1509_metric_policy(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
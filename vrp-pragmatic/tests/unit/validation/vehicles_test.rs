@@ -56,6 +56,7 @@ fn can_detect_invalid_area_impl(
                     .map(|(area_id, job_ids)| Area {
                         id: area_id.to_string(),
                         jobs: job_ids.iter().map(|job_id| job_id.to_string()).collect(),
+                        polygon: None,
                     })
                     .collect()
             }),
@@ -66,13 +67,19 @@ fn can_detect_invalid_area_impl(
                 limits: Some(VehicleLimits {
                     max_distance: None,
                     shift_time: None,
+                    driver_shift_time: None,
                     tour_size: None,
+                    min_tour_size: None,
                     areas: area_ids.map(|area_ids| {
                         vec![area_ids
                             .iter()
                             .map(|area_id| AreaLimit { area_id: area_id.to_string(), job_value: 1. })
                             .collect()]
                     }),
+                    consecutive_driving: None,
+                    driving_time: None,
+                    allowed_areas: None,
+                    max_cost: None,
                 }),
                 ..create_default_vehicle_type()
             }],
@@ -147,7 +154,7 @@ fn can_detect_zero_costs_impl(costs: (f64, f64), expected: Option<String>) {
     let problem = Problem {
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: None, distance, time },
+                costs: VehicleCosts { fixed: None, distance, time, waiting: None },
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -220,6 +227,7 @@ fn can_handle_reload_resources_impl(resources: Option<Vec<&str>>, expected: Opti
                 shifts: vec![VehicleShift {
                     reloads: Some(vec![VehicleReload {
                         resource_id: Some("r1".to_string()),
+                        alternatives: None,
                         ..create_default_reload()
                     }]),
                     ..create_default_vehicle_shift()
@@ -239,3 +247,736 @@ fn can_handle_reload_resources_impl(resources: Option<Vec<&str>>, expected: Opti
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+parameterized_test! {can_detect_invalid_break_places, (locations, expected), {
+    can_detect_invalid_break_places_impl(locations, expected);
+}}
+
+can_detect_invalid_break_places! {
+    case01_no_locations: (vec![None, None], None),
+    case02_all_locations: (vec![Some((0., 0.)), Some((1., 0.))], None),
+    case03_mixed_locations: (vec![Some((0., 0.)), None], None),
+    case04_no_places: (vec![], Some("E1310".to_string())),
+}
+
+fn can_detect_invalid_break_places_impl(locations: Vec<Option<(f64, f64)>>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak::Optional {
+                        time: VehicleOptionalBreakTime::TimeOffset(vec![10., 20.]),
+                        places: locations
+                            .into_iter()
+                            .map(|location| VehicleOptionalBreakPlace {
+                                duration: 2.,
+                                location: location.map(|location| location.to_loc()),
+                                tag: None,
+                            })
+                            .collect(),
+                        policy: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1310_vehicle_break_places_are_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_compartments, (compartments, expected), {
+    can_detect_invalid_compartments_impl(compartments, expected);
+}}
+
+can_detect_invalid_compartments! {
+    case01_correct: (vec![("c1", vec![5], vec!["frozen"]), ("c2", vec![5], vec!["ambient"])], None),
+    case02_duplicate_ids: (vec![("c1", vec![5], vec!["frozen"]), ("c1", vec![5], vec!["ambient"])], Some("E1311".to_string())),
+    case03_wrong_dimensions: (vec![("c1", vec![5, 1], vec!["frozen"])], Some("E1311".to_string())),
+    case04_negative_capacity: (vec![("c1", vec![-1], vec!["frozen"])], Some("E1311".to_string())),
+    case05_no_classes: (vec![("c1", vec![5], vec![])], Some("E1311".to_string())),
+}
+
+fn can_detect_invalid_compartments_impl(compartments: Vec<(&str, Vec<i32>, Vec<&str>)>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                compartments: Some(
+                    compartments
+                        .into_iter()
+                        .map(|(id, capacity, classes)| VehicleCompartment {
+                            id: id.to_string(),
+                            capacity,
+                            classes: classes.into_iter().map(|class| class.to_string()).collect(),
+                        })
+                        .collect(),
+                ),
+                ..create_vehicle_with_capacity("my_vehicle", vec![10])
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1311_vehicle_compartments_are_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_energy, (energy, rate, expected), {
+    can_detect_invalid_energy_impl(energy, rate, expected);
+}}
+
+can_detect_invalid_energy! {
+    case01_correct: (Some((100., 1., 10.)), Some(1.), None),
+    case02_no_recharges: (Some((100., 1., 10.)), None, None),
+    case03_zero_capacity: (Some((0., 1., 0.)), None, Some("E1312".to_string())),
+    case04_negative_consumption: (Some((100., -1., 10.)), None, Some("E1312".to_string())),
+    case05_reserve_above_capacity: (Some((100., 1., 100.)), None, Some("E1312".to_string())),
+    case06_zero_rate: (Some((100., 1., 10.)), Some(0.), Some("E1312".to_string())),
+    case07_recharges_without_energy: (None, Some(1.), Some("E1312".to_string())),
+}
+
+fn can_detect_invalid_energy_impl(energy: Option<(f64, f64, f64)>, rate: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    recharges: rate.map(|rate| {
+                        vec![VehicleRecharge { location: (1., 0.).to_loc(), rate, times: None, tag: None }]
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                energy: energy.map(|(capacity, consumption, reserve)| VehicleEnergy {
+                    capacity,
+                    consumption,
+                    load_consumption: None,
+                    reserve: Some(reserve),
+                }),
+                ..create_default_vehicle("my_vehicle")
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1312_vehicle_energy_is_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_trailer, (trailer_capacity, duration, expected), {
+    can_detect_invalid_trailer_impl(trailer_capacity, duration, expected);
+}}
+
+can_detect_invalid_trailer! {
+    case01_correct: (Some(vec![5]), Some(10.), None),
+    case02_no_parkings: (Some(vec![5]), None, None),
+    case03_wrong_dimension: (Some(vec![5, 1]), Some(10.), Some("E1313".to_string())),
+    case04_negative_capacity: (Some(vec![-1]), Some(10.), Some("E1313".to_string())),
+    case05_capacity_above_vehicle: (Some(vec![11]), Some(10.), Some("E1313".to_string())),
+    case06_negative_duration: (Some(vec![5]), Some(-1.), Some("E1313".to_string())),
+    case07_parkings_without_trailer: (None, Some(10.), Some("E1313".to_string())),
+}
+
+fn can_detect_invalid_trailer_impl(
+    trailer_capacity: Option<Vec<i32>>,
+    duration: Option<f64>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    trailer_parkings: duration.map(|duration| {
+                        vec![VehicleTrailerParking { location: (1., 0.).to_loc(), duration, times: None, tag: None }]
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                capacity: vec![10],
+                trailer: trailer_capacity.map(|capacity| VehicleTrailer { capacity }),
+                ..create_default_vehicle("my_vehicle")
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1313_vehicle_trailer_is_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_driving_time, (max_driving_time, break_duration, working, expected), {
+    can_detect_invalid_driving_time_impl(max_driving_time, break_duration, working, expected);
+}}
+
+can_detect_invalid_driving_time! {
+    case01_correct: (100., 10., (Some(200.), Some(50.)), None),
+    case02_correct_without_rest: (100., 10., (None, None), None),
+    case03_zero_max_driving: (0., 10., (None, None), Some("E1314".to_string())),
+    case04_negative_break: (100., -1., (None, None), Some("E1314".to_string())),
+    case05_no_rest_duration: (100., 10., (Some(200.), None), Some("E1314".to_string())),
+    case06_no_max_working: (100., 10., (None, Some(50.)), Some("E1314".to_string())),
+    case07_zero_rest_duration: (100., 10., (Some(200.), Some(0.)), Some("E1314".to_string())),
+}
+
+fn can_detect_invalid_driving_time_impl(
+    max_driving_time: f64,
+    break_duration: f64,
+    working: (Option<f64>, Option<f64>),
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    driver_shift_time: None,
+                    tour_size: None,
+                    min_tour_size: None,
+                    areas: None,
+                    consecutive_driving: None,
+                    driving_time: Some(DrivingTimeLimit {
+                        max_driving_time,
+                        break_duration,
+                        max_working_time: working.0,
+                        rest_duration: working.1,
+                    }),
+                    allowed_areas: None,
+                    max_cost: None,
+                }),
+                ..create_default_vehicle("my_vehicle")
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1314_vehicle_driving_time_is_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_maintenance, (maintenance, required_break, expected), {
+    can_detect_invalid_maintenance_impl(maintenance, required_break, expected);
+}}
+
+can_detect_invalid_maintenance! {
+    case01_correct: (vec![(10., 20.), (30., 40.)], None, None),
+    case02_correct_with_break: (vec![(10., 20.)], Some(VehicleRequiredBreakTime::ExactTime(format_time(30.))), None),
+    case03_invalid_window: (vec![(20., 10.)], None, Some("E1315".to_string())),
+    case04_outside_shift: (vec![(2000., 2010.)], None, Some("E1315".to_string())),
+    case05_intersects_each_other: (vec![(10., 20.), (15., 30.)], None, Some("E1315".to_string())),
+    case06_intersects_break: (vec![(10., 20.)], Some(VehicleRequiredBreakTime::ExactTime(format_time(15.))), Some("E1315".to_string())),
+    case07_offset_break: (vec![(10., 20.)], Some(VehicleRequiredBreakTime::OffsetTime(30.)), Some("E1315".to_string())),
+}
+
+fn can_detect_invalid_maintenance_impl(
+    maintenance: Vec<(f64, f64)>,
+    required_break: Option<VehicleRequiredBreakTime>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: required_break.map(|time| vec![VehicleBreak::Required { time, duration: 5. }]),
+                    maintenance: Some(
+                        maintenance
+                            .into_iter()
+                            .map(|(start, end)| vec![format_time(start), format_time(end)])
+                            .collect(),
+                    ),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle("my_vehicle")
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1315_vehicle_maintenance_is_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_depots, (depots, has_dispatch, expected), {
+    can_detect_invalid_depots_impl(depots, has_dispatch, expected);
+}}
+
+can_detect_invalid_depots! {
+    case01_correct: (Some(vec![(1., 0.)]), false, None),
+    case02_no_depots: (None, true, None),
+    case03_empty: (Some(vec![]), false, Some("E1316".to_string())),
+    case04_with_dispatch: (Some(vec![(1., 0.)]), true, Some("E1316".to_string())),
+}
+
+fn can_detect_invalid_depots_impl(depots: Option<Vec<(f64, f64)>>, has_dispatch: bool, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    dispatch: if has_dispatch {
+                        Some(vec![VehicleDispatch {
+                            location: (0., 0.).to_loc(),
+                            limits: vec![VehicleDispatchLimit {
+                                max: 1,
+                                start: format_time(10.),
+                                end: format_time(20.),
+                            }],
+                            tag: None,
+                        }])
+                    } else {
+                        None
+                    },
+                    depots: depots.map(|depots| depots.into_iter().map(|depot| depot.to_loc()).collect()),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle("my_vehicle")
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1316_vehicle_depots_are_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_reload_docks, (docks, expected), {
+    can_detect_invalid_reload_docks_impl(docks, expected);
+}}
+
+can_detect_invalid_reload_docks! {
+    case01_correct: (vec![("d1", 2)], None),
+    case02_missing_dock: (vec![("d2", 2)], Some("E1317".to_string())),
+    case03_zero_capacity: (vec![("d1", 0)], Some("E1317".to_string())),
+}
+
+fn can_detect_invalid_reload_docks_impl(docks: Vec<(&str, usize)>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![VehicleReload { dock_id: Some("d1".to_string()), ..create_default_reload() }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            resources: Some(
+                docks
+                    .into_iter()
+                    .map(|(id, capacity)| VehicleResource::Dock { id: id.to_string(), capacity })
+                    .collect(),
+            ),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1317_vehicle_reload_docks(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_allowed_areas, (areas, penalty, expected), {
+    can_detect_invalid_allowed_areas_impl(areas, penalty, expected);
+}}
+
+can_detect_invalid_allowed_areas! {
+    case01_correct: (vec!["area1"], None, None),
+    case02_correct_with_penalty: (vec!["area1"], Some(10.), None),
+    case03_unknown_area: (vec!["area2"], None, Some("E1318".to_string())),
+    case04_empty: (vec![], None, Some("E1318".to_string())),
+    case05_negative_penalty: (vec!["area1"], Some(-1.), Some("E1318".to_string())),
+}
+
+fn can_detect_invalid_allowed_areas_impl(areas: Vec<&str>, penalty: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.))],
+            areas: Some(vec![Area { id: "area1".to_string(), jobs: vec!["job1".to_string()], polygon: None }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    driver_shift_time: None,
+                    tour_size: None,
+                    min_tour_size: None,
+                    consecutive_driving: None,
+                    driving_time: None,
+                    areas: None,
+                    allowed_areas: Some(AllowedAreas {
+                        areas: areas.into_iter().map(|id| id.to_string()).collect(),
+                        penalty,
+                    }),
+                    max_cost: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1318_vehicle_allowed_areas(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_max_cost, (max_cost, expected), {
+    can_detect_invalid_max_cost_impl(max_cost, expected);
+}}
+
+can_detect_invalid_max_cost! {
+    case01_positive: (100., None),
+    case02_zero: (0., Some("E1319".to_string())),
+    case03_negative: (-1., Some("E1319".to_string())),
+}
+
+fn can_detect_invalid_max_cost_impl(max_cost: f64, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    driver_shift_time: None,
+                    tour_size: None,
+                    min_tour_size: None,
+                    areas: None,
+                    consecutive_driving: None,
+                    driving_time: None,
+                    allowed_areas: None,
+                    max_cost: Some(max_cost),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1319_vehicle_max_cost(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_min_tour_size, (tour_size, min_tour_size, expected), {
+    can_detect_invalid_min_tour_size_impl(tour_size, min_tour_size, expected);
+}}
+
+can_detect_invalid_min_tour_size! {
+    case01_positive: (None, 2, None),
+    case02_zero: (None, 0, Some("E1320".to_string())),
+    case03_less_than_max: (Some(3), 2, None),
+    case04_equal_to_max: (Some(3), 3, None),
+    case05_greater_than_max: (Some(3), 4, Some("E1320".to_string())),
+}
+
+fn can_detect_invalid_min_tour_size_impl(tour_size: Option<usize>, min_tour_size: usize, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    driver_shift_time: None,
+                    tour_size,
+                    min_tour_size: Some(min_tour_size),
+                    areas: None,
+                    consecutive_driving: None,
+                    driving_time: None,
+                    allowed_areas: None,
+                    max_cost: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1320_vehicle_min_tour_size(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_physical_limits, (max_axle_weight, max_item_length, expected), {
+    can_detect_invalid_physical_limits_impl(max_axle_weight, max_item_length, expected);
+}}
+
+can_detect_invalid_physical_limits! {
+    case01_valid: (Some(10.), Some(5.), None),
+    case02_partial: (Some(10.), None, None),
+    case03_zero_weight: (Some(0.), Some(5.), Some("E1321".to_string())),
+    case04_negative_length: (None, Some(-1.), Some("E1321".to_string())),
+}
+
+fn can_detect_invalid_physical_limits_impl(
+    max_axle_weight: Option<f64>,
+    max_item_length: Option<f64>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                physical: Some(VehiclePhysical { max_axle_weight, max_item_length, max_item_height: None }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1321_vehicle_physical_limits(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_overtime, (has_end, max_duration, cost_per_hour, expected), {
+    can_detect_invalid_overtime_impl(has_end, max_duration, cost_per_hour, expected);
+}}
+
+can_detect_invalid_overtime! {
+    case01_valid: (true, 3600., 10., None),
+    case02_no_end: (false, 3600., 10., Some("E1322".to_string())),
+    case03_negative_duration: (true, -1., 10., Some("E1322".to_string())),
+    case04_negative_cost: (true, 3600., -10., Some("E1322".to_string())),
+}
+
+fn can_detect_invalid_overtime_impl(has_end: bool, max_duration: f64, cost_per_hour: f64, expected: Option<String>) {
+    let shift = create_default_vehicle_shift();
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: if has_end { shift.end.clone() } else { None },
+                    overtime: Some(VehicleOvertime { max_duration, cost_per_hour }),
+                    ..shift
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1322_vehicle_overtime(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_unloading, (has_end, duration, expected), {
+    can_detect_invalid_unloading_impl(has_end, duration, expected);
+}}
+
+can_detect_invalid_unloading! {
+    case01_valid: (true, 600., None),
+    case02_no_end: (false, 600., Some("E1323".to_string())),
+    case03_negative_duration: (true, -1., Some("E1323".to_string())),
+}
+
+fn can_detect_invalid_unloading_impl(has_end: bool, duration: f64, expected: Option<String>) {
+    let shift = create_default_vehicle_shift();
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: if has_end { shift.end.clone() } else { None },
+                    unloading: Some(VehicleUnloading { duration, tag: None, dock_id: None }),
+                    relays: None,
+                    ..shift
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1323_vehicle_unloading(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_named_capacity, (dimensions, capacity, named_capacity, expected), {
+    can_detect_invalid_named_capacity_impl(dimensions, capacity, named_capacity, expected);
+}}
+
+can_detect_invalid_named_capacity! {
+    case01_valid: (vec!["weight", "volume"], vec![10], Some(("weight", 10)), None),
+    case02_no_named_capacity: (vec!["weight"], vec![10], None, None),
+    case03_unknown_dimension: (vec!["weight"], vec![10], Some(("volume", 10)), Some("E1324".to_string())),
+    case04_negative_value: (vec!["weight"], vec![10], Some(("weight", -1)), Some("E1324".to_string())),
+    case05_duplicate_dimensions: (vec!["weight", "weight"], vec![10], None, Some("E1324".to_string())),
+    case06_no_capacity: (vec![], vec![], None, Some("E1324".to_string())),
+}
+
+fn can_detect_invalid_named_capacity_impl(
+    dimensions: Vec<&str>,
+    capacity: Vec<i32>,
+    named_capacity: Option<(&str, i32)>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                capacity,
+                named_capacity: named_capacity
+                    .map(|(dimension, value)| vec![NamedLoad { dimension: dimension.to_string(), value }]),
+                ..create_default_vehicle_type()
+            }],
+            dimensions: Some(
+                dimensions
+                    .into_iter()
+                    .map(|name| LoadDimension { name: name.to_string(), is_reloadable: None })
+                    .collect(),
+            ),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1324_vehicle_named_capacity(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_relays, (driver_shift_time, duration, expected), {
+    can_detect_invalid_relays_impl(driver_shift_time, duration, expected);
+}}
+
+can_detect_invalid_relays! {
+    case01_valid: (Some(100.), Some(10.), None),
+    case02_no_relays: (Some(100.), None, None),
+    case03_no_limit: (None, Some(10.), Some("E1325".to_string())),
+    case04_zero_limit: (Some(0.), Some(10.), Some("E1325".to_string())),
+    case05_negative_duration: (Some(100.), Some(-1.), Some("E1325".to_string())),
+}
+
+fn can_detect_invalid_relays_impl(driver_shift_time: Option<f64>, duration: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    relays: duration.map(|duration| {
+                        vec![VehicleRelay { location: (1., 0.).to_loc(), duration, times: None, tag: None }]
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                limits: driver_shift_time.map(|driver_shift_time| VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    driver_shift_time: Some(driver_shift_time),
+                    tour_size: None,
+                    min_tour_size: None,
+                    areas: None,
+                    consecutive_driving: None,
+                    driving_time: None,
+                    allowed_areas: None,
+                    max_cost: None,
+                }),
+                ..create_default_vehicle("my_vehicle")
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1325_vehicle_relays(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_reload_capacity, (capacity, expected), {
+    can_detect_invalid_reload_capacity_impl(capacity, expected);
+}}
+
+can_detect_invalid_reload_capacity! {
+    case01_valid: (Some(vec![5]), None),
+    case02_no_capacity: (None, None),
+    case03_equal_to_vehicle: (Some(vec![10]), None),
+    case04_exceeds_vehicle: (Some(vec![11]), Some("E1326".to_string())),
+    case05_negative: (Some(vec![-1]), Some("E1326".to_string())),
+    case06_wrong_dimensions: (Some(vec![5, 5]), Some("E1326".to_string())),
+}
+
+fn can_detect_invalid_reload_capacity_impl(capacity: Option<Vec<i32>>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![VehicleReload { capacity, ..create_default_reload() }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1326_vehicle_reload_capacity(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_emissions, (consumption, load_consumption, expected), {
+    can_detect_invalid_emissions_impl(consumption, load_consumption, expected);
+}}
+
+can_detect_invalid_emissions! {
+    case01_valid: (1., None, None),
+    case02_valid_with_load: (1., Some(0.1), None),
+    case03_negative_consumption: (-1., None, Some("E1327".to_string())),
+    case04_negative_load_consumption: (1., Some(-0.1), Some("E1327".to_string())),
+}
+
+fn can_detect_invalid_emissions_impl(consumption: f64, load_consumption: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                emissions: Some(VehicleEmissions { class: EmissionClass::Diesel, consumption, load_consumption }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1327_vehicle_emissions(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
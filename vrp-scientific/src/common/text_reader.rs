@@ -73,6 +73,7 @@ pub(crate) fn create_fleet_with_distance_costs(
                             location,
                             time: TimeInterval { earliest: None, latest: Some(time.end) },
                         }),
+                        is_open: false,
                     }],
                 })
             })
@@ -111,3 +112,14 @@ pub(crate) fn skip_lines<R: Read>(count: usize, reader: &mut BufReader<R>, buffe
 
     Ok(())
 }
+
+pub(crate) fn parse_int(data: &str, err_msg: &str) -> Result<i32, String> {
+    data.parse::<f64>()
+        // NOTE observed that some input files might have coordinates like 28.00000
+        .map(|value| value.round() as i32)
+        .map_err(|err| format!("{}: '{}'", err_msg, err))
+}
+
+pub(crate) fn parse_float(data: &str, err_msg: &str) -> Result<f64, String> {
+    data.parse::<f64>().map_err(|err| format!("{}: '{}'", err_msg, err))
+}
@@ -6,6 +6,8 @@
 //!
 //! - **solomon**: see [Solomon benchmark](https://www.sintef.no/projectweb/top/vrptw/solomon-benchmark)
 //! - **lilim**: see [Li&Lim benchmark](https://www.sintef.no/projectweb/top/pdptw/li-lim-benchmark)
+//! - **tsplib**: see [TSPLIB95](http://comopt.ifi.uni-heidelberg.de/software/TSPLIB95/)
+//! - **lkh3**: CVRPTW problem and tour formats used by [LKH-3](http://webhotel4.ruc.dk/~keld/research/LKH-3/)
 
 #![warn(missing_docs)]
 
@@ -22,5 +24,6 @@ pub use vrp_core as core;
 
 pub mod common;
 pub mod lilim;
+pub mod lkh3;
 pub mod solomon;
 pub mod tsplib;
@@ -138,6 +138,8 @@ impl<R: Read> LilimReader<R> {
                 location: Some(self.coord_index.collect(customer.location)),
                 duration: customer.service as f64,
                 times: vec![TimeSpan::Window(customer.tw.clone())],
+                setup: 0.,
+                lateness: None,
             }],
             dimens: Default::default(),
         })
@@ -0,0 +1,93 @@
+#[cfg(test)]
+#[path = "../../tests/unit/lkh3/init_solution_reader_test.rs"]
+mod init_solution_reader_test;
+
+use crate::common::read_line;
+use std::collections::HashMap;
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::models::common::*;
+use vrp_core::models::solution::{Activity, Registry, Route, Tour};
+use vrp_core::prelude::{Problem, Random, Single, Solution};
+
+/// Reads initial solution from a tour in the format used by LKH-3 solver.
+/// NOTE: Solution feasibility is not checked.
+pub fn read_init_solution<R: Read>(
+    mut reader: BufReader<R>,
+    problem: Arc<Problem>,
+    random: Arc<dyn Random + Send + Sync>,
+) -> Result<Solution, String> {
+    let mut buffer = String::new();
+
+    loop {
+        if read_line(&mut reader, &mut buffer)? == 0 {
+            return Err("cannot find TOUR_SECTION".to_string());
+        }
+
+        if buffer.trim() == "TOUR_SECTION" {
+            break;
+        }
+    }
+
+    let id_map = problem.jobs.all().fold(HashMap::<String, Arc<Single>>::new(), |mut acc, job| {
+        let single = job.to_single().clone();
+        acc.insert(single.dimens.get_id().unwrap().to_string(), single);
+        acc
+    });
+
+    // NOTE depot has node id 1, its copies have ids above problem dimension and separate routes
+    let dimension = problem.jobs.size() + 1;
+    let mut tours: Vec<Vec<Arc<Single>>> = vec![vec![]];
+
+    'tour: while read_line(&mut reader, &mut buffer)? > 0 {
+        for node in buffer.split_whitespace() {
+            let node = node.parse::<i64>().map_err(|err| format!("cannot parse node id: '{}'", err))?;
+
+            match node {
+                -1 => break 'tour,
+                node if node == 1 || node as usize > dimension => tours.push(vec![]),
+                node if node > 1 => {
+                    let single =
+                        id_map.get(&(node - 1).to_string()).ok_or_else(|| format!("unknown node id: '{}'", node))?;
+                    tours.last_mut().unwrap().push(single.clone());
+                }
+                _ => return Err(format!("invalid node id: '{}'", node)),
+            }
+        }
+    }
+
+    let mut solution = Solution {
+        registry: Registry::new(&problem.fleet, random),
+        routes: vec![],
+        unassigned: Default::default(),
+        extras: problem.extras.clone(),
+    };
+
+    tours.into_iter().filter(|singles| !singles.is_empty()).try_for_each(|singles| {
+        let actor = solution.registry.next().next().ok_or_else(|| "not enough vehicles".to_string())?;
+        let mut tour = Tour::new(&actor);
+
+        singles.into_iter().for_each(|single| {
+            let place = single.places.first().unwrap();
+            tour.insert_last(Activity {
+                place: vrp_core::models::solution::Place {
+                    location: place.location.unwrap(),
+                    duration: place.duration,
+                    time: place.times.first().and_then(|span| span.as_time_window()).unwrap(),
+                    setup: 0.,
+                    lateness: None,
+                },
+                schedule: Schedule::new(0.0, 0.0),
+                job: Some(single),
+                commute: None,
+            });
+        });
+
+        solution.registry.use_actor(&actor);
+        solution.routes.push(Route { actor, tour });
+
+        Ok::<_, String>(())
+    })?;
+
+    Ok(solution)
+}
@@ -0,0 +1,10 @@
+//! Contains functionality to read CVRPTW problem in the format used by LKH-3 solver and to read/write its tour.
+
+mod initial_reader;
+pub use self::initial_reader::read_init_solution;
+
+mod reader;
+pub use self::reader::Lkh3Problem;
+
+mod writer;
+pub use self::writer::Lkh3Solution;
@@ -0,0 +1,258 @@
+#[cfg(test)]
+#[path = "../../tests/unit/lkh3/reader_test.rs"]
+mod reader_test;
+
+use crate::common::*;
+use std::collections::HashMap;
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::models::common::*;
+use vrp_core::models::problem::*;
+use vrp_core::models::{Extras, Problem};
+
+/// A trait to read CVRPTW problem in the format used by LKH-3 solver. Please note that only euclidean
+/// distances and a single depot with id `1` are supported.
+pub trait Lkh3Problem {
+    /// Reads LKH-3 problem.
+    fn read_lkh3(self, is_rounded: bool) -> Result<Problem, String>;
+}
+
+impl<R: Read> Lkh3Problem for BufReader<R> {
+    fn read_lkh3(self, is_rounded: bool) -> Result<Problem, String> {
+        Lkh3Reader::new(self).read_problem(is_rounded)
+    }
+}
+
+impl Lkh3Problem for String {
+    fn read_lkh3(self, is_rounded: bool) -> Result<Problem, String> {
+        Lkh3Reader::new(BufReader::new(self.as_bytes())).read_problem(is_rounded)
+    }
+}
+
+const DEPOT_ID: i32 = 1;
+
+#[derive(Default)]
+struct NodeData {
+    coordinates: HashMap<i32, (i32, i32)>,
+    demands: HashMap<i32, i32>,
+    time_windows: HashMap<i32, TimeWindow>,
+    services: HashMap<i32, f64>,
+}
+
+struct Lkh3Reader<R: Read> {
+    buffer: String,
+    reader: BufReader<R>,
+    dimension: Option<usize>,
+    vehicles: Option<usize>,
+    vehicle_capacity: Option<usize>,
+    service_time: f64,
+    coord_index: CoordIndex,
+}
+
+impl<R: Read> TextReader for Lkh3Reader<R> {
+    fn read_definitions(&mut self) -> Result<(Vec<Job>, Fleet), String> {
+        let section = self.read_meta()?;
+        let nodes = self.read_node_data(section)?;
+
+        let dimension = self.dimension.unwrap();
+        let get_time_window = |id: i32| nodes.time_windows.get(&id).cloned().unwrap_or_else(TimeWindow::max);
+
+        let mut ids = nodes.coordinates.keys().filter(|&&id| id != DEPOT_ID).cloned().collect::<Vec<_>>();
+        ids.sort_unstable();
+
+        let jobs =
+            ids.into_iter().try_fold::<_, _, Result<_, String>>(Vec::with_capacity(dimension), |mut jobs, id| {
+                let demand =
+                    nodes.demands.get(&id).cloned().ok_or_else(|| format!("cannot find demand for id: '{}'", id))?;
+                let service = nodes.services.get(&id).cloned().unwrap_or(self.service_time);
+
+                jobs.push(self.create_job(
+                    &(id - 1).to_string(),
+                    nodes.coordinates[&id],
+                    demand,
+                    get_time_window(id),
+                    service,
+                ));
+
+                Ok(jobs)
+            })?;
+
+        let depot_coord = *nodes
+            .coordinates
+            .get(&DEPOT_ID)
+            .ok_or_else(|| format!("cannot find coordinate for depot id: '{}'", DEPOT_ID))?;
+
+        let fleet = create_fleet_with_distance_costs(
+            self.vehicles.unwrap_or(dimension),
+            self.vehicle_capacity.unwrap(),
+            self.coord_index.collect(depot_coord),
+            get_time_window(DEPOT_ID),
+        );
+
+        Ok((jobs, fleet))
+    }
+
+    fn create_transport(&self, is_rounded: bool) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
+        self.coord_index.create_transport(is_rounded)
+    }
+
+    fn create_extras(&self) -> Extras {
+        get_extras(self.coord_index.clone())
+    }
+}
+
+impl<R: Read> Lkh3Reader<R> {
+    fn new(reader: BufReader<R>) -> Self {
+        Self {
+            buffer: String::new(),
+            reader,
+            dimension: None,
+            vehicles: None,
+            vehicle_capacity: None,
+            service_time: 0.,
+            coord_index: CoordIndex::default(),
+        }
+    }
+
+    /// Reads specification part and returns a name of the first data section.
+    fn read_meta(&mut self) -> Result<String, String> {
+        loop {
+            let line = self.read_line()?.trim().to_string();
+
+            if line.ends_with("_SECTION") {
+                if self.dimension.is_none() {
+                    return Err("DIMENSION is not specified".to_string());
+                }
+
+                if self.vehicle_capacity.is_none() {
+                    return Err("CAPACITY is not specified".to_string());
+                }
+
+                return Ok(line);
+            }
+
+            let (key, value) = line
+                .split_once(':')
+                .map(|(key, value)| (key.trim(), value.trim()))
+                .ok_or_else(|| format!("expected colon separated string, got: '{}'", line))?;
+
+            match key {
+                "NAME" | "COMMENT" => {}
+                "TYPE" if value != "CVRPTW" => return Err(format!("expecting 'CVRPTW' as TYPE, got '{}'", value)),
+                "TYPE" => {}
+                "DIMENSION" => self.dimension = Some(parse_int(value, "cannot parse DIMENSION")? as usize),
+                "VEHICLES" => self.vehicles = Some(parse_int(value, "cannot parse VEHICLES")? as usize),
+                "CAPACITY" => self.vehicle_capacity = Some(parse_int(value, "cannot parse CAPACITY")? as usize),
+                "SERVICE_TIME" => self.service_time = parse_float(value, "cannot parse SERVICE_TIME")?,
+                "EDGE_WEIGHT_TYPE" if value != "EUC_2D" => {
+                    return Err(format!("expecting 'EUC_2D' as EDGE_WEIGHT_TYPE, got '{}'", value))
+                }
+                "EDGE_WEIGHT_TYPE" => {}
+                _ => return Err(format!("unsupported key: '{}'", key)),
+            }
+        }
+    }
+
+    fn read_node_data(&mut self, first_section: String) -> Result<NodeData, String> {
+        let mut nodes = NodeData::default();
+        let mut section = first_section;
+
+        loop {
+            match section.as_str() {
+                "NODE_COORD_SECTION" => {
+                    for data in self.read_section_lines(3, "coord")? {
+                        let coord = (
+                            parse_int(&data[1], "cannot parse coord.0")?,
+                            parse_int(&data[2], "cannot parse coord.1")?,
+                        );
+                        nodes.coordinates.insert(parse_int(&data[0], "cannot parse id")?, coord);
+                    }
+                }
+                "DEMAND_SECTION" => {
+                    for data in self.read_section_lines(2, "demand")? {
+                        nodes.demands.insert(
+                            parse_int(&data[0], "cannot parse id")?,
+                            parse_int(&data[1], "cannot parse demand")?,
+                        );
+                    }
+                }
+                "TIME_WINDOW_SECTION" => {
+                    for data in self.read_section_lines(3, "time window")? {
+                        let start = parse_float(&data[1], "cannot parse time window start")?;
+                        let end = parse_float(&data[2], "cannot parse time window end")?;
+                        nodes.time_windows.insert(parse_int(&data[0], "cannot parse id")?, TimeWindow::new(start, end));
+                    }
+                }
+                "SERVICE_TIME_SECTION" => {
+                    for data in self.read_section_lines(2, "service time")? {
+                        let service = parse_float(&data[1], "cannot parse service time")?;
+                        nodes.services.insert(parse_int(&data[0], "cannot parse id")?, service);
+                    }
+                }
+                "DEPOT_SECTION" => self.read_depot_data()?,
+                "EOF" | "" => break,
+                _ => return Err(format!("unsupported section: '{}'", section)),
+            }
+
+            section = self.read_line()?.trim().to_string();
+        }
+
+        if nodes.coordinates.is_empty() {
+            return Err("NODE_COORD_SECTION is not specified".to_string());
+        }
+
+        Ok(nodes)
+    }
+
+    fn read_section_lines(&mut self, size: usize, name: &str) -> Result<Vec<Vec<String>>, String> {
+        (0..self.dimension.unwrap())
+            .map(|_| {
+                let line = self.read_line()?.trim();
+                let data = line.split_whitespace().map(|value| value.to_string()).collect::<Vec<_>>();
+
+                if data.len() != size {
+                    Err(format!("unexpected {} data: '{}'", name, line))
+                } else {
+                    Ok(data)
+                }
+            })
+            .collect()
+    }
+
+    fn read_depot_data(&mut self) -> Result<(), String> {
+        let depot_id = parse_int(self.read_line()?.trim(), "cannot parse depot id")?;
+        if depot_id != DEPOT_ID {
+            return Err(format!("expecting '{}' as depot id, got '{}'", DEPOT_ID, depot_id));
+        }
+
+        let line = self.read_line()?.trim();
+        if line != "-1" {
+            return Err(format!("expecting single depot terminated by -1, got: '{}'", line));
+        }
+
+        Ok(())
+    }
+
+    fn read_line(&mut self) -> Result<&String, String> {
+        read_line(&mut self.reader, &mut self.buffer)?;
+        Ok(&self.buffer)
+    }
+
+    fn create_job(&mut self, id: &str, location: (i32, i32), demand: i32, time: TimeWindow, duration: f64) -> Job {
+        let mut dimens = create_dimens_with_id("", id);
+        dimens.set_demand(Demand::<SingleDimLoad> {
+            pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
+            delivery: (SingleDimLoad::new(demand), SingleDimLoad::default()),
+        });
+        Job::Single(Arc::new(Single {
+            places: vec![Place {
+                location: Some(self.coord_index.collect(location)),
+                duration,
+                times: vec![TimeSpan::Window(time)],
+                setup: 0.,
+                lateness: None,
+            }],
+            dimens,
+        }))
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+#[path = "../../tests/unit/lkh3/writer_test.rs"]
+mod writer_test;
+
+use std::io::{BufWriter, Error, ErrorKind, Write};
+use vrp_core::models::common::IdDimension;
+use vrp_core::models::Solution;
+
+/// A trait to write solution as a tour in the format used by LKH-3 solver.
+pub trait Lkh3Solution<W: Write> {
+    /// Writes LKH-3 tour.
+    fn write_lkh3(&self, writer: BufWriter<W>) -> Result<(), String>;
+}
+
+impl<W: Write> Lkh3Solution<W> for (&Solution, f64) {
+    fn write_lkh3(&self, writer: BufWriter<W>) -> Result<(), String> {
+        write_lkh3_tour(writer, self.0, self.1).map_err(|err| err.to_string())?;
+        Ok(())
+    }
+}
+
+fn write_lkh3_tour<W: Write>(writer: BufWriter<W>, solution: &Solution, cost: f64) -> Result<(), Error> {
+    let mut writer = writer;
+
+    if !solution.unassigned.is_empty() {
+        return Err(Error::new(ErrorKind::InvalidInput, "cannot write lkh3 tour with unassigned jobs."));
+    }
+
+    let routes = solution
+        .routes
+        .iter()
+        .map(|route| {
+            route
+                .tour
+                .all_activities()
+                .filter_map(|activity| activity.retrieve_job())
+                .map(|job| {
+                    // NOTE job id is node id minus one as depot has node id 1
+                    job.dimens()
+                        .get_id()
+                        .and_then(|id| id.parse::<usize>().ok())
+                        .map(|id| id + 1)
+                        .ok_or_else(|| Error::new(ErrorKind::InvalidData, "cannot get node id from job id."))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // NOTE LKH-3 uses depot copies with node ids above problem dimension to separate routes
+    let dimension = routes.iter().map(|nodes| nodes.len()).sum::<usize>() + 1;
+
+    writer.write_all(format!("NAME : solution\nCOMMENT : Length = {:.2}\nTYPE : TOUR\n", cost).as_bytes())?;
+    writer.write_all(format!("DIMENSION : {}\nTOUR_SECTION\n", dimension + routes.len().max(1) - 1).as_bytes())?;
+
+    routes.iter().enumerate().try_for_each(|(idx, nodes)| {
+        let depot = if idx == 0 { 1 } else { dimension + idx };
+        writer.write_all(format!("{}\n", depot).as_bytes())?;
+        nodes.iter().try_for_each(|node| writer.write_all(format!("{}\n", node).as_bytes()))
+    })?;
+
+    writer.write_all("-1\nEOF\n".as_bytes())?;
+
+    Ok(())
+}
@@ -7,9 +7,8 @@ use std::collections::HashMap;
 use std::io::{BufReader, Read};
 use std::sync::Arc;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::*;
 use vrp_core::models::solution::{Activity, Registry, Route, Tour};
-use vrp_core::prelude::*;
+use vrp_core::prelude::{Problem, Random, Single, Solution};
 
 /// Reads initial solution from a buffer.
 /// NOTE: Solution feasibility is not checked.
@@ -52,6 +51,8 @@ pub fn read_init_solution<R: Read>(
                             location: place.location.unwrap(),
                             duration: place.duration,
                             time: place.times.first().and_then(|span| span.as_time_window()).unwrap(),
+                            setup: 0.,
+                            lateness: None,
                         },
                         schedule: Schedule::new(0.0, 0.0),
                         job: Some(single.clone()),
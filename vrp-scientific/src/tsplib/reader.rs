@@ -211,15 +211,10 @@ impl<R: Read> TsplibReader<R> {
                 location: Some(self.coord_index.collect(location)),
                 duration: 0.,
                 times: vec![TimeSpan::Window(TimeWindow::max())],
+                setup: 0.,
+                lateness: None,
             }],
             dimens,
         }))
     }
 }
-
-fn parse_int(data: &str, err_msg: &str) -> Result<i32, String> {
-    data.parse::<f64>()
-        // NOTE observed that some input files might have coordinates like 28.00000
-        .map(|value| value.round() as i32)
-        .map_err(|err| format!("{}: '{}'", err_msg, err))
-}
@@ -0,0 +1,32 @@
+use super::*;
+use crate::helpers::get_test_resource;
+use crate::lkh3::Lkh3Problem;
+use vrp_core::utils::Environment;
+
+#[test]
+pub fn can_read_init_solution() {
+    let environment = Arc::new(Environment::default());
+    let problem = Arc::new(
+        BufReader::new(get_test_resource("../../examples/data/scientific/lkh3/example.txt").unwrap())
+            .read_lkh3(false)
+            .unwrap(),
+    );
+    let file = get_test_resource("../../examples/data/scientific/lkh3/example.tour").unwrap();
+
+    let solution = read_init_solution(BufReader::new(file), problem, environment.random.clone())
+        .expect("cannot read initial solution");
+
+    let routes = solution
+        .routes
+        .iter()
+        .map(|route| {
+            route
+                .tour
+                .all_activities()
+                .filter_map(|activity| activity.retrieve_job())
+                .map(|job| job.dimens().get_id().unwrap().clone())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(routes, vec![vec!["3", "1"], vec!["2", "5"], vec!["4"]]);
+}
@@ -0,0 +1,97 @@
+use crate::helpers::get_test_resource;
+use crate::lkh3::reader::Lkh3Reader;
+use crate::lkh3::Lkh3Problem;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use vrp_core::models::common::{IdDimension, TimeWindow};
+
+fn get_example_problem_string() -> String {
+    let mut buffer = "".to_string();
+
+    get_test_resource("../../examples/data/scientific/lkh3/example.txt")
+        .expect("cannot open file")
+        .read_to_string(&mut buffer)
+        .expect("cannot read file");
+
+    buffer
+}
+
+fn get_example_problem_reader() -> BufReader<File> {
+    BufReader::new(get_test_resource("../../examples/data/scientific/lkh3/example.txt").expect("cannot open file"))
+}
+
+#[test]
+fn can_read_meta_errors() {
+    for (from, to, error) in &[
+        ("CVRPTW", "CVRP", "expecting 'CVRPTW' as TYPE, got 'CVRP'"),
+        ("DIMENSION : 6", "DIMENSION : asd", "cannot parse DIMENSION: 'invalid float literal'"),
+        ("DIMENSION : 6", "NAME : 6", "DIMENSION is not specified"),
+        ("EUC_2D", "ASD", "expecting 'EUC_2D' as EDGE_WEIGHT_TYPE, got 'ASD'"),
+        ("CAPACITY : 30", "CAPACITY : asd", "cannot parse CAPACITY: 'invalid float literal'"),
+        ("VEHICLES : 3", "DISTANCE : 3", "unsupported key: 'DISTANCE'"),
+    ] {
+        let content = get_example_problem_string().replace(from, to);
+        let mut reader = Lkh3Reader::new(BufReader::new(content.as_bytes()));
+
+        let result = reader.read_meta();
+
+        assert_eq!(result, Err(error.to_string()));
+    }
+}
+
+#[test]
+fn can_read_meta() {
+    let mut reader = Lkh3Reader::new(get_example_problem_reader());
+
+    let section = reader.read_meta().expect("cannot read meta");
+
+    assert_eq!(section, "NODE_COORD_SECTION");
+    assert_eq!(reader.dimension, Some(6));
+    assert_eq!(reader.vehicles, Some(3));
+    assert_eq!(reader.vehicle_capacity, Some(30));
+}
+
+#[test]
+fn can_read_node_data() {
+    let mut reader = Lkh3Reader::new(get_example_problem_reader());
+    let section = reader.read_meta().expect("cannot read meta");
+
+    let nodes = reader.read_node_data(section).expect("cannot read node data");
+
+    assert_eq!(nodes.coordinates.len(), 6);
+    assert_eq!(nodes.demands.len(), 6);
+    assert_eq!(nodes.time_windows.get(&3), Some(&TimeWindow::new(50., 500.)));
+    assert_eq!(nodes.services.get(&4), Some(&5.));
+}
+
+#[test]
+fn can_read_node_data_errors() {
+    for (from, to, error) in &[
+        ("DEPOT_SECTION\n1", "DEPOT_SECTION\n2", "expecting '1' as depot id, got '2'"),
+        ("1\n-1", "1\n2\n-1", "expecting single depot terminated by -1, got: '2'"),
+        ("3 50 500", "3 50", "unexpected time window data: '3 50'"),
+        ("SERVICE_TIME_SECTION", "PICKUP_AND_DELIVERY_SECTION", "unsupported section: 'PICKUP_AND_DELIVERY_SECTION'"),
+    ] {
+        let content = get_example_problem_string().replace(from, to);
+        let mut reader = Lkh3Reader::new(BufReader::new(content.as_bytes()));
+        let section = reader.read_meta().expect("cannot read meta");
+
+        let result = reader.read_node_data(section).map(|_| ());
+
+        assert_eq!(result, Err(error.to_string()));
+    }
+}
+
+#[test]
+fn can_read_problem() {
+    let reader = get_example_problem_reader();
+
+    let problem = reader.read_lkh3(false).expect("cannot read problem");
+
+    assert_eq!(problem.jobs.size(), 5);
+    assert_eq!(problem.fleet.actors.len(), 3);
+    let job = problem.jobs.all().find(|job| job.dimens().get_id().map(|id| id.as_str()) == Some("2")).unwrap();
+    let place = job.to_single().places.first().unwrap();
+    assert_eq!(place.duration, 10.);
+    assert_eq!(place.times.first().and_then(|span| span.as_time_window()), Some(TimeWindow::new(50., 500.)));
+}
@@ -0,0 +1,29 @@
+use super::*;
+use crate::helpers::get_test_resource;
+use crate::lkh3::{read_init_solution, Lkh3Problem};
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::utils::Environment;
+
+#[test]
+fn can_write_lkh3_tour() {
+    let environment = Arc::new(Environment::default());
+    let problem = Arc::new(
+        BufReader::new(get_test_resource("../../examples/data/scientific/lkh3/example.txt").unwrap())
+            .read_lkh3(false)
+            .unwrap(),
+    );
+    let mut expected = String::new();
+    get_test_resource("../../examples/data/scientific/lkh3/example.tour")
+        .unwrap()
+        .read_to_string(&mut expected)
+        .unwrap();
+    let file = get_test_resource("../../examples/data/scientific/lkh3/example.tour").unwrap();
+    let solution = read_init_solution(BufReader::new(file), problem, environment.random.clone()).unwrap();
+
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+    (&solution, 237.).write_lkh3(writer).unwrap();
+
+    assert_eq!(buffer, expected.replace("toy.vrptw.tour", "solution").replace("237", "237.00"));
+}
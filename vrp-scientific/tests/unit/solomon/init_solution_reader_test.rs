@@ -1,6 +1,7 @@
 use super::*;
 use crate::helpers::{create_c101_100_problem, get_test_resource};
 use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::models::problem::ProblemObjective;
 use vrp_core::rosomaxa::prelude::Objective;
 use vrp_core::utils::Environment;
 